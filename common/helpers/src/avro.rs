@@ -1,5 +1,9 @@
 use crate::types::*;
-use apache_avro::{from_avro_datum, schema::Schema, to_avro_datum, types::Record, Codec, Writer};
+use apache_avro::{
+	from_avro_datum, schema::Schema, schema_compatibility::SchemaCompatibility, to_avro_datum,
+	types::Record, Codec, Writer,
+};
+use common_primitives::schema::SchemaCompatibilityMode;
 use std::{collections::HashMap, io::Cursor, str};
 
 /// Represents error types returned by the `avro` module.
@@ -202,3 +206,29 @@ pub fn validate_raw_avro_schema(json_schema: &Vec<u8>) -> Result<(), AvroError>
 		},
 	}
 }
+
+/// Function to check that a new version of a schema is compatible with the previous version
+/// of the schema in the given compatibility mode.
+///
+/// # Examples
+/// ```
+/// use common_helpers::avro;
+/// use common_primitives::schema::SchemaCompatibilityMode;
+/// let previous = avro::fingerprint_raw_schema(r#"{"type": "record", "name": "User", "fields": [{"name": "name", "type": "string"}]}"#).unwrap().0;
+/// let new = avro::fingerprint_raw_schema(r#"{"type": "record", "name": "User", "fields": [{"name": "name", "type": "string"}, {"name": "age", "type": "int", "default": 0}]}"#).unwrap().0;
+/// assert!(avro::is_compatible_schema(&previous, &new, SchemaCompatibilityMode::Full));
+/// ```
+pub fn is_compatible_schema(
+	previous_schema: &Schema,
+	new_schema: &Schema,
+	mode: SchemaCompatibilityMode,
+) -> bool {
+	match mode {
+		SchemaCompatibilityMode::Backward =>
+			SchemaCompatibility::can_read(previous_schema, new_schema),
+		SchemaCompatibilityMode::Forward =>
+			SchemaCompatibility::can_read(new_schema, previous_schema),
+		SchemaCompatibilityMode::Full =>
+			SchemaCompatibility::mutual_read(previous_schema, new_schema),
+	}
+}
@@ -2,6 +2,7 @@ use std::collections::HashMap;
 
 use crate::avro;
 use apache_avro::types::Record;
+use common_primitives::schema::SchemaCompatibilityMode;
 
 pub type SchemaValue = apache_avro::types::Value;
 
@@ -418,3 +419,43 @@ fn test_json_serialized_bad_avro_schema() {
 	let validation_res = avro::validate_raw_avro_schema(&serialized_bytes);
 	assert!(validation_res.is_err());
 }
+
+#[test]
+fn test_schema_compatibility_modes() {
+	let previous = avro::fingerprint_raw_schema(
+		r#"{"type": "record", "name": "test", "fields": [{"name": "a", "type": "long"}]}"#,
+	)
+	.unwrap()
+	.0;
+	let with_default_field = avro::fingerprint_raw_schema(
+		r#"{"type": "record", "name": "test", "fields": [{"name": "a", "type": "long"}, {"name": "b", "type": "string", "default": ""}]}"#,
+	)
+	.unwrap()
+	.0;
+	let with_required_field = avro::fingerprint_raw_schema(
+		r#"{"type": "record", "name": "test", "fields": [{"name": "a", "type": "long"}, {"name": "b", "type": "string"}]}"#,
+	)
+	.unwrap()
+	.0;
+
+	assert!(avro::is_compatible_schema(
+		&previous,
+		&with_default_field,
+		SchemaCompatibilityMode::Full
+	));
+	assert!(avro::is_compatible_schema(
+		&previous,
+		&with_required_field,
+		SchemaCompatibilityMode::Forward
+	));
+	assert!(!avro::is_compatible_schema(
+		&previous,
+		&with_required_field,
+		SchemaCompatibilityMode::Backward
+	));
+	assert!(!avro::is_compatible_schema(
+		&previous,
+		&with_required_field,
+		SchemaCompatibilityMode::Full
+	));
+}
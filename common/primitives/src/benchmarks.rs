@@ -23,6 +23,14 @@ pub trait MsaBenchmarkHelper<AccountId> {
 
 	/// Sets a public key to an MSA.
 	fn add_key(msa_id: MessageSourceId, key: AccountId) -> DispatchResult;
+
+	/// Sets a delegation from Delegator that Provider only holds through the longest chain of
+	/// sub-delegations, found after every other sub-delegation that may be examined.
+	fn set_sub_delegated_relationship(
+		provider: ProviderId,
+		delegator: DelegatorId,
+		schemas: Vec<SchemaId>,
+	) -> DispatchResult;
 }
 
 impl<AccountId> MsaBenchmarkHelper<AccountId> for () {
@@ -39,6 +47,15 @@ impl<AccountId> MsaBenchmarkHelper<AccountId> for () {
 	fn add_key(_msa_id: MessageSourceId, _key: AccountId) -> DispatchResult {
 		Ok(())
 	}
+
+	/// Sets a delegation that Provider holds through sub-delegations.
+	fn set_sub_delegated_relationship(
+		_provider: ProviderId,
+		_delegator: DelegatorId,
+		_schemas: Vec<SchemaId>,
+	) -> DispatchResult {
+		Ok(())
+	}
 }
 
 /// A trait for Schema pallet helping setup state for running benchmarks.
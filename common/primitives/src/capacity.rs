@@ -1,6 +1,13 @@
 use crate::msa::MessageSourceId;
 use frame_support::traits::tokens::Balance;
-use sp_runtime::DispatchError;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::{DispatchError, RuntimeDebug};
+
+/// The type used to number Capacity Epochs
+pub type EpochNumber = u32;
 
 /// A trait for checking that a target MSA can be staked to.
 pub trait TargetValidator {
@@ -47,3 +54,39 @@ pub trait Replenishable {
 	/// Checks if an account can be replenished.
 	fn can_replenish(msa_id: MessageSourceId) -> bool;
 }
+
+/// RPC Response for the Capacity of a Provider
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, Eq)]
+pub struct CapacityDetailsResponse<Balance, EpochNumber> {
+	/// The Capacity remaining for the `last_replenished_epoch`
+	pub remaining_capacity: Balance,
+	/// The amount of tokens staked to the Provider
+	pub total_tokens_staked: Balance,
+	/// The total Capacity issued to the Provider
+	pub total_capacity_issued: Balance,
+	/// The last Epoch in which the Provider was replenished with Capacity
+	pub last_replenished_epoch: EpochNumber,
+}
+
+/// RPC Response for an account's stake to a single Provider
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, Eq)]
+pub struct StakingTargetResponse<Balance> {
+	/// The MSA Id of the staked Provider
+	pub target: MessageSourceId,
+	/// The amount of tokens staked to the Provider
+	pub amount: Balance,
+	/// The Capacity the stake generates for the Provider
+	pub capacity: Balance,
+}
+
+/// RPC Response for a pending unlock chunk
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, Eq)]
+pub struct UnlockChunkResponse<Balance, EpochNumber> {
+	/// The amount of tokens to be unlocked
+	pub value: Balance,
+	/// The Epoch at which the tokens thaw and may be withdrawn
+	pub thaw_at: EpochNumber,
+}
@@ -1,12 +1,16 @@
-use crate::msa::MessageSourceId;
 #[cfg(feature = "std")]
 use crate::utils::*;
+use crate::{
+	msa::MessageSourceId,
+	signatures::{eip_712_encode_bytes, eip_712_encode_uint, eip_712_hash_struct, EIP712Encode},
+};
 use frame_support::BoundedVec;
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 use sp_core::ConstU32;
+use sp_runtime::traits::UniqueSaturatedInto;
 use sp_std::vec::Vec;
 
 /// The minimum base and canonical handle (not including suffix or delimiter) length in characters
@@ -57,6 +61,20 @@ impl<BlockNumber> ClaimHandlePayload<BlockNumber> {
 	}
 }
 
+impl<BlockNumber: UniqueSaturatedInto<u64> + Copy> EIP712Encode
+	for ClaimHandlePayload<BlockNumber>
+{
+	fn eip_712_hash_struct(&self) -> [u8; 32] {
+		eip_712_hash_struct(
+			b"ClaimHandlePayload(string baseHandle,uint32 expiration)",
+			&[
+				eip_712_encode_bytes(&self.base_handle),
+				eip_712_encode_uint(self.expiration.unique_saturated_into()),
+			],
+		)
+	}
+}
+
 /// RPC Response form for a Handle
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
@@ -0,0 +1,255 @@
+use scale_info::prelude::string::{String, ToString};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use sp_std::{collections::btree_set::BTreeSet, prelude::*};
+
+/// The only JSON Schema dialect accepted in `$schema`
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// The primitive types of `type`
+const JSON_SCHEMA_TYPES: [&str; 7] =
+	["null", "boolean", "object", "array", "number", "string", "integer"];
+/// Keywords whose value is a schema
+const SCHEMA_KEYWORDS: [&str; 11] = [
+	"not",
+	"if",
+	"then",
+	"else",
+	"items",
+	"contains",
+	"additionalProperties",
+	"propertyNames",
+	"unevaluatedItems",
+	"unevaluatedProperties",
+	"contentSchema",
+];
+/// Keywords whose value is a non-empty array of schemas
+const SCHEMA_ARRAY_KEYWORDS: [&str; 4] = ["allOf", "anyOf", "oneOf", "prefixItems"];
+/// Keywords whose value is an object of schemas
+const SCHEMA_MAP_KEYWORDS: [&str; 4] =
+	["$defs", "properties", "patternProperties", "dependentSchemas"];
+/// Keywords whose value is a string
+const STRING_KEYWORDS: [&str; 10] = [
+	"$id",
+	"$ref",
+	"$dynamicRef",
+	"$comment",
+	"title",
+	"description",
+	"pattern",
+	"format",
+	"contentEncoding",
+	"contentMediaType",
+];
+/// Keywords whose value is a non-negative integer
+const NON_NEGATIVE_INTEGER_KEYWORDS: [&str; 8] = [
+	"maxLength",
+	"minLength",
+	"maxItems",
+	"minItems",
+	"maxContains",
+	"minContains",
+	"maxProperties",
+	"minProperties",
+];
+/// Keywords whose value is a number
+const NUMBER_KEYWORDS: [&str; 4] = ["maximum", "minimum", "exclusiveMaximum", "exclusiveMinimum"];
+/// Keywords whose value is a boolean
+const BOOLEAN_KEYWORDS: [&str; 4] = ["uniqueItems", "deprecated", "readOnly", "writeOnly"];
+
+/// Errors of an invalid JSON Schema model. `path` is the JSON Pointer of the subschema
+#[derive(Clone, PartialEq, Debug, Eq, Serialize, Deserialize)]
+pub enum JsonSchemaModelError {
+	/// The model is not JSON
+	Malformed,
+	/// `$schema` is set to a dialect other than [`JSON_SCHEMA_DIALECT`]
+	UnsupportedDialect {
+		/// Path of the subschema
+		path: String,
+	},
+	/// The schema is neither an object nor a boolean
+	InvalidSchema {
+		/// Path of the subschema
+		path: String,
+	},
+	/// The value of a keyword does not match the draft 2020-12 meta-schema
+	InvalidKeyword {
+		/// Path of the subschema
+		path: String,
+		/// The keyword
+		keyword: String,
+	},
+}
+
+/// Validates a JSON Schema model against the draft 2020-12 meta-schema. Unknown keywords are
+/// allowed, as in the specification.
+pub fn validate_json_schema_model(model: &[u8]) -> Result<(), JsonSchemaModelError> {
+	let schema: Value =
+		serde_json::from_slice(model).map_err(|_| JsonSchemaModelError::Malformed)?;
+	validate_schema(&schema, "")
+}
+
+fn validate_schema(schema: &Value, path: &str) -> Result<(), JsonSchemaModelError> {
+	let keywords = match schema {
+		Value::Bool(_) => return Ok(()),
+		Value::Object(keywords) => keywords,
+		_ => return Err(JsonSchemaModelError::InvalidSchema { path: path.into() }),
+	};
+	if let Some(dialect) = keywords.get("$schema") {
+		if dialect.as_str() != Some(JSON_SCHEMA_DIALECT) {
+			return Err(JsonSchemaModelError::UnsupportedDialect { path: path.into() })
+		}
+	}
+
+	for (keyword, value) in keywords {
+		let keyword = keyword.as_str();
+		let invalid =
+			|| JsonSchemaModelError::InvalidKeyword { path: path.into(), keyword: keyword.into() };
+		let valid = match keyword {
+			"type" => is_valid_type(value),
+			"enum" | "examples" => value.is_array(),
+			"required" => is_unique_strings(value),
+			"dependentRequired" =>
+				value.as_object().map_or(false, |object| object.values().all(is_unique_strings)),
+			"multipleOf" => value.as_f64().map_or(false, |number| number > 0.0),
+			"$anchor" | "$dynamicAnchor" => value.as_str().map_or(false, is_anchor),
+			"$vocabulary" =>
+				value.as_object().map_or(false, |object| object.values().all(Value::is_boolean)),
+			keyword if SCHEMA_KEYWORDS.contains(&keyword) => {
+				validate_schema(value, &child_path(path, &[keyword]))?;
+				true
+			},
+			keyword if SCHEMA_ARRAY_KEYWORDS.contains(&keyword) => {
+				let schemas = value.as_array().filter(|schemas| !schemas.is_empty());
+				let schemas = schemas.ok_or_else(invalid)?;
+				for (index, subschema) in schemas.iter().enumerate() {
+					validate_schema(
+						subschema,
+						&child_path(path, &[keyword, index.to_string().as_str()]),
+					)?;
+				}
+				true
+			},
+			keyword if SCHEMA_MAP_KEYWORDS.contains(&keyword) => {
+				let schemas: &Map<String, Value> = value.as_object().ok_or_else(invalid)?;
+				for (name, subschema) in schemas {
+					validate_schema(subschema, &child_path(path, &[keyword, name.as_str()]))?;
+				}
+				true
+			},
+			keyword if STRING_KEYWORDS.contains(&keyword) => value.is_string(),
+			keyword if NON_NEGATIVE_INTEGER_KEYWORDS.contains(&keyword) => value.is_u64(),
+			keyword if NUMBER_KEYWORDS.contains(&keyword) => value.is_number(),
+			keyword if BOOLEAN_KEYWORDS.contains(&keyword) => value.is_boolean(),
+			_ => true,
+		};
+		if !valid {
+			return Err(invalid())
+		}
+	}
+	Ok(())
+}
+
+/// `type` is one of the primitive types, or a non-empty array of unique primitive types
+fn is_valid_type(value: &Value) -> bool {
+	let is_primitive =
+		|value: &Value| value.as_str().map_or(false, |name| JSON_SCHEMA_TYPES.contains(&name));
+	match value {
+		Value::Array(types) =>
+			!types.is_empty() && types.iter().all(is_primitive) && is_unique_strings(value),
+		_ => is_primitive(value),
+	}
+}
+
+/// An array of strings without duplicates
+fn is_unique_strings(value: &Value) -> bool {
+	let Some(values) = value.as_array() else { return false };
+	let mut seen = BTreeSet::new();
+	values
+		.iter()
+		.all(|value| value.as_str().map_or(false, |value| seen.insert(value)))
+}
+
+/// Anchors are plain names: `^[A-Za-z_][-A-Za-z0-9._]*$`
+fn is_anchor(name: &str) -> bool {
+	let mut chars = name.chars();
+	chars.next().map_or(false, |first| first.is_ascii_alphabetic() || first == '_') &&
+		chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '_'))
+}
+
+/// Appends reference tokens to a JSON Pointer, escaping `~` and `/`
+fn child_path(path: &str, tokens: &[&str]) -> String {
+	let mut child = String::from(path);
+	for token in tokens {
+		child.push('/');
+		child.push_str(&token.replace('~', "~0").replace('/', "~1"));
+	}
+	child
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn validate(model: &str) -> Result<(), JsonSchemaModelError> {
+		validate_json_schema_model(model.as_bytes())
+	}
+
+	fn invalid_keyword(path: &str, keyword: &str) -> Result<(), JsonSchemaModelError> {
+		Err(JsonSchemaModelError::InvalidKeyword { path: path.into(), keyword: keyword.into() })
+	}
+
+	#[test]
+	fn validate_json_schema_model_with_valid_model_succeeds() {
+		let model = r##"{
+			"$schema": "https://json-schema.org/draft/2020-12/schema",
+			"$id": "https://example.com/broadcast.json",
+			"type": "object",
+			"properties": {
+				"announcementType": {"type": "integer", "minimum": 0},
+				"url": {"type": "string", "format": "uri", "maxLength": 2048},
+				"tags": {"type": "array", "items": {"$ref": "#/$defs/tag"}, "uniqueItems": true},
+				"extra": true
+			},
+			"required": ["announcementType", "url"],
+			"additionalProperties": false,
+			"$defs": {"tag": {"type": ["string", "null"], "x-custom": 1}}
+		}"##;
+
+		assert_eq!(validate(model), Ok(()));
+		assert_eq!(validate("true"), Ok(()));
+	}
+
+	#[test]
+	fn validate_json_schema_model_with_malformed_model_fails() {
+		assert_eq!(validate("{"), Err(JsonSchemaModelError::Malformed));
+		assert_eq!(validate("[]"), Err(JsonSchemaModelError::InvalidSchema { path: "".into() }));
+		assert_eq!(
+			validate(r#"{"properties": {"a/b": 1}}"#),
+			Err(JsonSchemaModelError::InvalidSchema { path: "/properties/a~1b".into() })
+		);
+	}
+
+	#[test]
+	fn validate_json_schema_model_with_other_dialect_fails() {
+		assert_eq!(
+			validate(r#"{"$schema": "http://json-schema.org/draft-07/schema#"}"#),
+			Err(JsonSchemaModelError::UnsupportedDialect { path: "".into() })
+		);
+	}
+
+	#[test]
+	fn validate_json_schema_model_with_invalid_keywords_fails() {
+		assert_eq!(validate(r#"{"type": "text"}"#), invalid_keyword("", "type"));
+		assert_eq!(validate(r#"{"type": ["string", "string"]}"#), invalid_keyword("", "type"));
+		assert_eq!(validate(r#"{"required": ["a", 1]}"#), invalid_keyword("", "required"));
+		assert_eq!(validate(r#"{"minLength": -1}"#), invalid_keyword("", "minLength"));
+		assert_eq!(validate(r#"{"multipleOf": 0}"#), invalid_keyword("", "multipleOf"));
+		assert_eq!(validate(r#"{"anyOf": []}"#), invalid_keyword("", "anyOf"));
+		assert_eq!(validate(r##"{"$anchor": "#a"}"##), invalid_keyword("", "$anchor"));
+		assert_eq!(
+			validate(r#"{"allOf": [true, {"items": {"maxItems": "3"}}]}"#),
+			invalid_keyword("/allOf/1/items", "maxItems")
+		);
+	}
+}
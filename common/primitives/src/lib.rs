@@ -13,6 +13,8 @@
 )]
 /// Types for the Handles pallet
 pub mod handles;
+/// Validation of JSON Schema models
+pub mod json_schema;
 /// macros
 pub mod macros;
 /// Structs and traits for the Messages pallet.
@@ -23,10 +25,14 @@ pub mod msa;
 pub mod node;
 /// Structs and traits for parquet
 pub mod parquet;
+/// Validation of Protobuf models
+pub mod protobuf;
 /// Structs and traits for better RPCs
 pub mod rpc;
 /// Structs and traits for the Schema pallet
 pub mod schema;
+/// Signature verification, including EIP-712 typed data signed by Ethereum keys
+pub mod signatures;
 /// Types for the Stateful Storage pallet
 pub mod stateful_storage;
 /// Structs and traits for the utility package.
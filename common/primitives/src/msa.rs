@@ -1,8 +1,14 @@
-use frame_support::{dispatch::DispatchResult, traits::Get, BoundedBTreeMap, BoundedVec};
+#[cfg(feature = "std")]
+use crate::utils::*;
+use frame_support::{
+	dispatch::DispatchResult, traits::Get, weights::Weight, BoundedBTreeMap, BoundedVec,
+	CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
 use parity_scale_codec::{Decode, Encode, EncodeLike, Error, MaxEncodedLen};
 use scale_info::TypeInfo;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
+use sp_core::H256;
 use sp_runtime::{
 	traits::{AtLeast32BitUnsigned, Zero},
 	DispatchError, MultiSignature, RuntimeDebug,
@@ -99,6 +105,52 @@ where
 	}
 }
 
+/// A kind of action a Provider may take on behalf of a Delegator with a granted schema
+#[derive(TypeInfo, RuntimeDebug, Clone, Copy, Decode, Encode, MaxEncodedLen, PartialEq, Eq)]
+pub enum SchemaGrantPermission {
+	/// Announce messages or add new stateful storage items and pages
+	Create,
+	/// Overwrite existing stateful storage pages
+	Update,
+	/// Delete stateful storage items and pages
+	Delete,
+}
+
+/// The set of [`SchemaGrantPermission`]s a Delegator has granted to a Provider on a schema
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(TypeInfo, RuntimeDebug, Clone, Copy, Decode, Encode, MaxEncodedLen, PartialEq, Eq)]
+pub struct SchemaPermissions {
+	/// Whether [`SchemaGrantPermission::Create`] is granted
+	pub create: bool,
+	/// Whether [`SchemaGrantPermission::Update`] is granted
+	pub update: bool,
+	/// Whether [`SchemaGrantPermission::Delete`] is granted
+	pub delete: bool,
+}
+
+impl SchemaPermissions {
+	/// A set containing every permission
+	pub fn all() -> Self {
+		Self { create: true, update: true, delete: true }
+	}
+
+	/// Returns true if `permission` is in the set
+	pub fn contains(&self, permission: SchemaGrantPermission) -> bool {
+		match permission {
+			SchemaGrantPermission::Create => self.create,
+			SchemaGrantPermission::Update => self.update,
+			SchemaGrantPermission::Delete => self.delete,
+		}
+	}
+}
+
+impl Default for SchemaPermissions {
+	/// Schema grants without an explicit permission set allow every action
+	fn default() -> Self {
+		Self::all()
+	}
+}
+
 /// Struct for the information of the relationship between an MSA and a Provider
 #[derive(TypeInfo, RuntimeDebug, Clone, Decode, Encode, MaxEncodedLen, Eq)]
 #[scale_info(skip_type_params(MaxSchemaGrantsPerDelegation))]
@@ -106,7 +158,8 @@ pub struct Delegation<SchemaId, BlockNumber, MaxSchemaGrantsPerDelegation>
 where
 	MaxSchemaGrantsPerDelegation: Get<u32>,
 {
-	/// Block number the grant will be revoked.
+	/// Block number the grant will be revoked (0 = not revoked).
+	/// A delegation granted with an expiry block is revoked at that block.
 	pub revoked_at: BlockNumber,
 	/// Schemas that the provider is allowed to use for a delegated message.
 	pub schema_permissions: BoundedBTreeMap<SchemaId, BlockNumber, MaxSchemaGrantsPerDelegation>,
@@ -194,6 +247,72 @@ where
 	pub provider_name: BoundedVec<u8, T>,
 }
 
+/// The metadata a provider publishes about itself, which providers may update at any time
+#[derive(MaxEncodedLen, TypeInfo, Debug, Clone, Decode, Encode, PartialEq, Eq)]
+#[scale_info(skip_type_params(FieldSize, SchemaCount))]
+pub struct ProviderMetadata<FieldSize, SchemaCount>
+where
+	FieldSize: Get<u32>,
+	SchemaCount: Get<u32>,
+{
+	/// The provider's homepage URL
+	pub homepage_url: BoundedVec<u8, FieldSize>,
+	/// The content identifier (CID) of the provider's logo
+	pub logo_cid: BoundedVec<u8, FieldSize>,
+	/// How to contact the provider
+	pub contact: BoundedVec<u8, FieldSize>,
+	/// The hash of the provider's terms of service, if any
+	pub terms_of_service_hash: Option<H256>,
+	/// The schemas the provider supports
+	pub supported_schema_ids: BoundedVec<SchemaId, SchemaCount>,
+}
+
+/// Restrictions on what a key of an MSA may do.
+/// Keys without a scope have full power over their MSA.
+#[derive(
+	MaxEncodedLen,
+	TypeInfo,
+	RuntimeDebugNoBound,
+	CloneNoBound,
+	Decode,
+	Encode,
+	PartialEqNoBound,
+	EqNoBound,
+)]
+#[scale_info(skip_type_params(MaxSchemaIds))]
+pub struct KeyScope<MaxSchemaIds>
+where
+	MaxSchemaIds: Get<u32>,
+{
+	/// Whether the key may manage the MSA: add and delete keys, grant and revoke delegations,
+	/// claim or change its handle, and retire it
+	pub can_manage_msa: bool,
+	/// The schemas the key may write stateful storage for. `None` allows every schema.
+	pub schema_ids: Option<BoundedVec<SchemaId, MaxSchemaIds>>,
+}
+
+impl<MaxSchemaIds: Get<u32>> KeyScope<MaxSchemaIds> {
+	/// Whether the scope allows the key to write data of `schema_id`
+	pub fn allows_schema(&self, schema_id: SchemaId) -> bool {
+		self.schema_ids
+			.as_ref()
+			.map_or(true, |schema_ids| schema_ids.contains(&schema_id))
+	}
+
+	/// Whether the scope grants nothing beyond `other`
+	pub fn is_within(&self, other: &Self) -> bool {
+		if self.can_manage_msa && !other.can_manage_msa {
+			return false
+		}
+		match (&self.schema_ids, &other.schema_ids) {
+			(_, None) => true,
+			(None, Some(_)) => false,
+			(Some(schema_ids), Some(_)) =>
+				schema_ids.iter().all(|schema_id| other.allows_schema(*schema_id)),
+		}
+	}
+}
+
 /// The pointer value for the Signature Registry
 #[derive(MaxEncodedLen, TypeInfo, Debug, Clone, Decode, Encode, PartialEq, Eq)]
 pub struct SignatureRegistryPointer<BlockNumber> {
@@ -228,6 +347,14 @@ pub trait MsaValidator {
 	/// Check that a key is associated to an MSA and returns key information.
 	/// Returns a [`DispatchError`] if there is no MSA associated with the key
 	fn ensure_valid_msa_key(key: &Self::AccountId) -> Result<MessageSourceId, DispatchError>;
+
+	/// Check that the scope of a key, if any, allows it to manage its MSA.
+	/// Returns a [`DispatchError`] if the key is scoped without that power
+	fn ensure_key_can_manage_msa(key: &Self::AccountId) -> DispatchResult;
+
+	/// Check that the scope of a key, if any, allows it to write data of `schema_id`.
+	/// Returns a [`DispatchError`] if the key is scoped to other schemas
+	fn ensure_key_allows_schema(key: &Self::AccountId, schema_id: SchemaId) -> DispatchResult;
 }
 
 /// A behavior that allows for looking up delegator-provider relationships
@@ -268,15 +395,82 @@ pub trait DelegationValidator {
 
 /// A behavior that allows for validating a schema grant
 pub trait SchemaGrantValidator<BlockNumber> {
-	/// Validates if the provider is allowed to use the particular schema id currently
+	/// Validates if the provider is allowed to take the `permission` action with the particular schema id currently
 	fn ensure_valid_schema_grant(
 		provider_id: ProviderId,
 		delegator_id: DelegatorId,
 		schema_id: SchemaId,
 		block_number: BlockNumber,
+		permission: SchemaGrantPermission,
 	) -> DispatchResult;
 }
 
+/// A behavior that allows for deleting the data stored for a retired MSA
+pub trait MsaDataPurger {
+	/// Deletes as much of the data stored for `msa_id` as fits in `remaining_weight`.
+	/// Returns the weight used, and whether all of the data has been deleted.
+	fn purge_msa_data(msa_id: MessageSourceId, remaining_weight: Weight) -> (Weight, bool);
+}
+
+/// Blanket implementation for testing.
+impl MsaDataPurger for () {
+	fn purge_msa_data(_msa_id: MessageSourceId, _remaining_weight: Weight) -> (Weight, bool) {
+		(Weight::zero(), true)
+	}
+}
+
+/// Purges with `A` and then, once all of its data is deleted, with `B`
+impl<A: MsaDataPurger, B: MsaDataPurger> MsaDataPurger for (A, B) {
+	fn purge_msa_data(msa_id: MessageSourceId, remaining_weight: Weight) -> (Weight, bool) {
+		let (used_weight, complete) = A::purge_msa_data(msa_id, remaining_weight);
+		if !complete {
+			return (used_weight, false)
+		}
+		let (more_used_weight, complete) =
+			B::purge_msa_data(msa_id, remaining_weight.saturating_sub(used_weight));
+		(used_weight.saturating_add(more_used_weight), complete)
+	}
+}
+
+/// A behavior that allows for moving the data stored for an MSA merged into another MSA
+pub trait MsaDataMerger {
+	/// Moves up to `limit` items of the data stored for `source_msa_id` to `target_msa_id`.
+	/// Returns the number of items moved or dropped, and whether no data is left to move.
+	fn merge_msa_data(
+		source_msa_id: MessageSourceId,
+		target_msa_id: MessageSourceId,
+		limit: u32,
+	) -> (u32, bool);
+}
+
+/// Blanket implementation for testing.
+impl MsaDataMerger for () {
+	fn merge_msa_data(
+		_source_msa_id: MessageSourceId,
+		_target_msa_id: MessageSourceId,
+		_limit: u32,
+	) -> (u32, bool) {
+		(0, true)
+	}
+}
+
+/// Merges with `A` and then, once all of its data is moved, with `B`
+impl<A: MsaDataMerger, B: MsaDataMerger> MsaDataMerger for (A, B) {
+	fn merge_msa_data(
+		source_msa_id: MessageSourceId,
+		target_msa_id: MessageSourceId,
+		limit: u32,
+	) -> (u32, bool) {
+		let (moved, complete) = A::merge_msa_data(source_msa_id, target_msa_id, limit);
+		if !complete {
+			return (moved, false)
+		}
+		let (more_moved, complete) =
+			B::merge_msa_data(source_msa_id, target_msa_id, limit.saturating_sub(moved));
+		(moved.saturating_add(more_moved), complete)
+	}
+}
+
 /// RPC Response for getting getting MSA keys
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(TypeInfo, Debug, Clone, Decode, Encode, PartialEq, Default, MaxEncodedLen)]
@@ -287,6 +481,191 @@ pub struct KeyInfoResponse<AccountId> {
 	pub msa_keys: Vec<AccountId>,
 }
 
+/// RPC Response for getting a provider's registry entry and metadata
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(TypeInfo, Debug, Clone, Decode, Encode, PartialEq, Eq, Default)]
+pub struct ProviderInfoResponse {
+	/// The provider's MSA id
+	pub provider_id: ProviderId,
+	/// The provider's name
+	#[cfg_attr(feature = "std", serde(with = "as_string"))]
+	pub provider_name: Vec<u8>,
+	/// The provider's homepage URL
+	#[cfg_attr(feature = "std", serde(with = "as_string"))]
+	pub homepage_url: Vec<u8>,
+	/// The content identifier (CID) of the provider's logo
+	#[cfg_attr(feature = "std", serde(with = "as_string"))]
+	pub logo_cid: Vec<u8>,
+	/// How to contact the provider
+	#[cfg_attr(feature = "std", serde(with = "as_string"))]
+	pub contact: Vec<u8>,
+	/// The hash of the provider's terms of service, if any
+	pub terms_of_service_hash: Option<H256>,
+	/// The schemas the provider supports
+	pub supported_schema_ids: Vec<SchemaId>,
+}
+
+/// Whether a delegation or schema grant is still in effect
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(TypeInfo, Debug, Clone, Copy, Decode, Encode, PartialEq, Eq)]
+pub enum DelegationStatus {
+	/// Not revoked, or revoked at a future block
+	Active,
+	/// Revoked or expired
+	Revoked,
+}
+
+/// A type for requesting a page of the Delegators of a Provider
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(TypeInfo, Debug, Clone, Decode, Encode, PartialEq, Eq, Default)]
+pub struct DelegatorsPaginationRequest {
+	/// Only include Delegators that have granted this schema
+	pub schema_id: Option<SchemaId>,
+	/// Only include Delegators whose delegation (or schema grant, if `schema_id` is set) has this status
+	pub status: Option<DelegationStatus>,
+	/// Start after this Delegator, as returned in `next_after` of the previous page
+	pub after: Option<DelegatorId>,
+	/// The number of Delegators to examine for this page
+	pub page_size: u32,
+}
+
+impl DelegatorsPaginationRequest {
+	/// Hard limit on the number of Delegators examined for a page
+	pub const MAX_PAGE_SIZE: u32 = 1000;
+
+	/// Helper function for request validation.
+	/// * Page size should be between 1 and MAX_PAGE_SIZE.
+	pub fn validate(&self) -> bool {
+		self.page_size > 0 && self.page_size <= Self::MAX_PAGE_SIZE
+	}
+}
+
+/// RPC response for a Delegator of a Provider
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(TypeInfo, Debug, Clone, Decode, Encode, PartialEq, Eq)]
+pub struct DelegatorResponse<SchemaId, BlockNumber> {
+	/// The MSA id of the Delegator
+	pub delegator_id: DelegatorId,
+	/// The block the delegation was revoked at (0 if currently not revoked)
+	pub revoked_at: BlockNumber,
+	/// The list of schema permissions grants
+	pub permissions: Vec<SchemaGrant<SchemaId, BlockNumber>>,
+}
+
+/// A type for responding with a page of the Delegators of a Provider
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(TypeInfo, Debug, Clone, Decode, Encode, PartialEq, Eq)]
+pub struct DelegatorsPaginationResponse<SchemaId, BlockNumber> {
+	/// The Delegators in this page that match the request
+	pub content: Vec<DelegatorResponse<SchemaId, BlockNumber>>,
+	/// Flag to indicate there are more Delegators to examine
+	pub has_next: bool,
+	#[cfg_attr(feature = "std", serde(skip_serializing_if = "Option::is_none"))]
+	/// The `after` of the request for the next page
+	pub next_after: Option<DelegatorId>,
+}
+
+impl<SchemaId, BlockNumber> DelegatorsPaginationResponse<SchemaId, BlockNumber> {
+	/// Generates a new empty page
+	pub const fn new() -> Self {
+		Self { content: Vec::new(), has_next: false, next_after: None }
+	}
+}
+
+/// RPC response for a delegation requested by a Provider and waiting for the Delegator's answer
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(TypeInfo, Debug, Clone, Decode, Encode, PartialEq, Eq)]
+pub struct DelegationRequestResponse<SchemaId, BlockNumber> {
+	/// The MSA id of the Provider that requested the delegation
+	pub provider_id: ProviderId,
+	/// The schemas the Provider is requesting to be granted
+	pub schema_ids: Vec<SchemaId>,
+	/// The block at which the request was made
+	pub requested_at: BlockNumber,
+	/// The block after which the request can no longer be accepted
+	pub expiration: BlockNumber,
+	/// The block at which the delegation expires once accepted, if any
+	pub delegation_expires_at: Option<BlockNumber>,
+}
+
+/// Maximum number of public keys that can be resolved to their MSAs in one RPC call
+pub const MAX_KEYS_PER_MSA_LOOKUP: u32 = 1000;
+
+/// RPC response for the MSA that a public key belongs to
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(TypeInfo, Debug, Clone, Decode, Encode, PartialEq, Eq)]
+pub struct KeyOwnerResponse {
+	/// The MSA id the key belongs to
+	pub msa_id: MessageSourceId,
+	/// The number of public keys of the MSA
+	pub public_key_count: u8,
+}
+
+/// A change to the keys or delegations of an MSA, as kept in its offchain history
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(TypeInfo, Debug, Clone, Decode, Encode, PartialEq, Eq)]
+pub enum MsaHistoryEvent<AccountId> {
+	/// The MSA delegated to a provider
+	DelegationGranted {
+		/// The provider delegated to
+		provider_id: ProviderId,
+	},
+	/// The schema grants of a delegation of the MSA changed
+	DelegationUpdated {
+		/// The provider of the delegation
+		provider_id: ProviderId,
+	},
+	/// A delegation of the MSA was revoked by the MSA or by the provider
+	DelegationRevoked {
+		/// The provider of the delegation
+		provider_id: ProviderId,
+	},
+	/// A key was added to the MSA
+	PublicKeyAdded {
+		/// The key added
+		key: AccountId,
+	},
+	/// A key was removed from the MSA
+	PublicKeyDeleted {
+		/// The key removed
+		key: AccountId,
+	},
+}
+
+/// RPC response for an entry of the offchain history of an MSA
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(TypeInfo, Debug, Clone, Decode, Encode, PartialEq, Eq)]
+pub struct MsaHistoryRecord<AccountId> {
+	/// The block the change was made in
+	pub block_number: crate::node::BlockNumber,
+	/// The change
+	pub event: MsaHistoryEvent<AccountId>,
+}
+
+/// A difference between the offchain index of the keys of an MSA and its keys in the chain state
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(TypeInfo, Debug, Clone, Decode, Encode, PartialEq, Eq)]
+pub struct OffchainIndexMismatch<AccountId> {
+	/// The MSA whose indexed keys differ
+	pub msa_id: MessageSourceId,
+	/// Keys of the MSA in the chain state that are missing from the index
+	pub missing_keys: Vec<AccountId>,
+	/// Keys in the index that the MSA does not have in the chain state
+	pub unexpected_keys: Vec<AccountId>,
+}
+
+/// RPC response for a check or rebuild of the offchain index of MSA keys
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(TypeInfo, Debug, Clone, Decode, Encode, PartialEq, Eq)]
+pub struct OffchainIndexReport<AccountId> {
+	/// The block whose chain state the index was compared with
+	pub block_number: crate::node::BlockNumber,
+	/// The number of MSAs compared
+	pub checked_msa_count: u64,
+	/// The MSAs whose indexed keys differ from the chain state
+	pub mismatches: Vec<OffchainIndexMismatch<AccountId>>,
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -318,4 +697,45 @@ mod tests {
 		let decoded = DelegatorId::decode(&mut &val[..]);
 		assert_eq!(decoded, Ok(DelegatorId(42)))
 	}
+
+	#[test]
+	fn key_scope_allows_only_listed_schemas() {
+		let scoped = KeyScope::<frame_support::traits::ConstU32<4>> {
+			can_manage_msa: false,
+			schema_ids: Some(BoundedVec::try_from(vec![1, 2]).unwrap()),
+		};
+		let unscoped = KeyScope::<frame_support::traits::ConstU32<4>> {
+			can_manage_msa: false,
+			schema_ids: None,
+		};
+
+		assert!(scoped.allows_schema(2));
+		assert!(!scoped.allows_schema(3));
+		assert!(unscoped.allows_schema(3));
+	}
+
+	#[test]
+	fn key_scope_is_within_only_narrower_scopes() {
+		let scope = |can_manage_msa, schema_ids: Option<Vec<SchemaId>>| KeyScope::<
+			frame_support::traits::ConstU32<4>,
+		> {
+			can_manage_msa,
+			schema_ids: schema_ids.map(|ids| BoundedVec::try_from(ids).unwrap()),
+		};
+
+		assert!(scope(false, Some(vec![1])).is_within(&scope(true, Some(vec![1, 2]))));
+		assert!(scope(true, None).is_within(&scope(true, None)));
+		assert!(!scope(true, Some(vec![1])).is_within(&scope(false, Some(vec![1]))));
+		assert!(!scope(false, Some(vec![3])).is_within(&scope(true, Some(vec![1, 2]))));
+		assert!(!scope(false, None).is_within(&scope(true, Some(vec![1, 2]))));
+	}
+
+	#[test]
+	fn schema_permissions_contains_only_granted_permissions() {
+		let permissions = SchemaPermissions { create: true, update: false, delete: false };
+		assert!(permissions.contains(SchemaGrantPermission::Create));
+		assert!(!permissions.contains(SchemaGrantPermission::Update));
+		assert!(!permissions.contains(SchemaGrantPermission::Delete));
+		assert_eq!(SchemaPermissions::default(), SchemaPermissions::all());
+	}
 }
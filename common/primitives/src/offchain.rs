@@ -30,6 +30,14 @@ pub const MSA_ACCOUNT_LOCK_TIMEOUT_EXPIRATION_MS: u64 = 50;
 pub const MSA_ACCOUNT_LOCK_NAME_PREFIX: &[u8; 16] = b"Msa::ofw::lock::";
 /// Offchain storage prefix for msa account
 pub const MSA_ACCOUNT_STORAGE_NAME_PREFIX: &[u8; 16] = b"Msa::ofw::keys::";
+/// Offchain storage prefix for msa history
+pub const MSA_HISTORY_STORAGE_NAME_PREFIX: &[u8; 19] = b"Msa::ofw::history::";
+/// Maximum number of records in each chunk of the offchain history of an msa
+pub const MSA_HISTORY_CHUNK_SIZE: u32 = 100;
+/// Offchain storage name for whether the initial state of msa pallet was indexed
+pub const MSA_INITIAL_INDEXED_STORAGE_NAME: &[u8; 25] = b"Msa::ofw::initial-indexed";
+/// Offchain storage name for the last block whose msa events were indexed
+pub const LAST_PROCESSED_BLOCK_STORAGE_NAME: &[u8; 30] = b"Msa::ofw::last-processed-block";
 /// msa account lock name
 pub fn get_msa_account_lock_name(msa_id: MessageSourceId) -> Vec<u8> {
 	let mut buff = [0u8; 30];
@@ -40,6 +48,26 @@ pub fn get_msa_account_storage_key_name(msa_id: MessageSourceId) -> Vec<u8> {
 	let mut buff = [0u8; 30];
 	vec![MSA_ACCOUNT_STORAGE_NAME_PREFIX, msa_id.numtoa(10, &mut buff)].concat()
 }
+/// msa history storage key name, under which the number of history chunks is stored
+pub fn get_msa_history_storage_key_name(msa_id: MessageSourceId) -> Vec<u8> {
+	let mut buff = [0u8; 30];
+	vec![MSA_HISTORY_STORAGE_NAME_PREFIX, msa_id.numtoa(10, &mut buff)].concat()
+}
+/// msa history chunk storage key name
+pub fn get_msa_history_chunk_storage_key_name(
+	msa_id: MessageSourceId,
+	chunk_index: u32,
+) -> Vec<u8> {
+	let mut buff = [0u8; 30];
+	let mut chunk_buff = [0u8; 30];
+	vec![
+		MSA_HISTORY_STORAGE_NAME_PREFIX,
+		msa_id.numtoa(10, &mut buff),
+		b"::",
+		chunk_index.numtoa(10, &mut chunk_buff),
+	]
+	.concat()
+}
 
 /// Locks the execution of the function
 #[derive(Debug)]
@@ -85,6 +113,20 @@ mod tests {
 		assert_eq!(result, b"Msa::ofw::keys::2000000".to_vec());
 	}
 
+	#[test]
+	fn get_msa_history_storage_name_should_return_expected_value() {
+		let msa_id: MessageSourceId = 2_000_000;
+		let result = get_msa_history_storage_key_name(msa_id);
+		assert_eq!(result, b"Msa::ofw::history::2000000".to_vec());
+	}
+
+	#[test]
+	fn get_msa_history_chunk_storage_name_should_return_expected_value() {
+		let msa_id: MessageSourceId = 2_000_000;
+		let result = get_msa_history_chunk_storage_key_name(msa_id, 3);
+		assert_eq!(result, b"Msa::ofw::history::2000000::3".to_vec());
+	}
+
 	#[test]
 	fn get_index_for_not_set_should_return_none() {
 		let (offchain, _state) = testing::TestOffchainExt::new();
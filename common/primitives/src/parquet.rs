@@ -6,6 +6,8 @@ pub mod base;
 pub mod column;
 /// Importing all compression codec types
 pub mod column_compression_codec;
+/// Importing all nested types
+pub mod group;
 /// Importing all numeric types
 pub mod numeric;
 /// Importing all string types
@@ -14,6 +16,8 @@ pub mod string;
 pub mod temporal;
 /// Importing all labels
 pub mod types;
+/// Importing model validation
+pub mod validation;
 
 use crate::parquet::column::ParquetColumn;
 
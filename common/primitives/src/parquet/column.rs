@@ -9,13 +9,13 @@ use crate::parquet::{column_compression_codec::ColumnCompressionCodec, types::Pa
 #[derive(Clone, PartialEq, Debug, Eq, Serialize, Deserialize)]
 pub struct ParquetColumn {
 	/// The label for what this column represents
-	name: String,
+	pub(crate) name: String,
 	/// Parquet type labels
-	column_type: ParquetType,
+	pub(crate) column_type: ParquetType,
 	/// Compression for column
-	compression: ColumnCompressionCodec,
+	pub(crate) compression: ColumnCompressionCodec,
 	/// Whether or not to use a bloom filter
-	bloom_filter: bool,
+	pub(crate) bloom_filter: bool,
 }
 
 impl ParquetColumn {
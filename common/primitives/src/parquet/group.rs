@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+use sp_std::{boxed::Box, prelude::*};
+
+use crate::parquet::{column::ParquetColumn, types::ParquetType};
+
+/// Parquet nested types: <https://github.com/apache/parquet-format/blob/master/LogicalTypes.md#nested-types>
+#[derive(Clone, PartialEq, Debug, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ParquetGroupType {
+	/// A group of named columns
+	Group(Vec<ParquetColumn>),
+	/// A repeated element of the given type
+	List(Box<ParquetType>),
+}
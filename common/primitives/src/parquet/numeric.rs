@@ -23,8 +23,8 @@ pub struct ParquetInteger {
 /// Parquet Decimals
 #[derive(Clone, PartialEq, Debug, Eq, Serialize, Deserialize)]
 pub struct ParquetDecimal {
-	scale: u8,
+	pub(crate) scale: u8,
 
 	// Note: in theory, precision is unbounded. But a u8 should be fine for practical cases
-	precision: u8,
+	pub(crate) precision: u8,
 }
@@ -4,7 +4,8 @@ use sp_std::prelude::*;
 use crate::parquet::base::ParquetBaseType;
 
 use crate::parquet::{
-	numeric::ParquetNumericType, string::ParquetStringType, temporal::ParquetTemporalType,
+	group::ParquetGroupType, numeric::ParquetNumericType, string::ParquetStringType,
+	temporal::ParquetTemporalType,
 };
 
 /// Encapsulates label types for Parquet
@@ -19,6 +20,8 @@ pub enum ParquetType {
 	NumericType(ParquetNumericType),
 	/// Time
 	TemporalType(ParquetTemporalType),
+	/// Nested columns
+	GroupType(ParquetGroupType),
 }
 
 impl Default for ParquetType {
@@ -0,0 +1,395 @@
+use scale_info::prelude::string::{String, ToString};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sp_std::{collections::btree_set::BTreeSet, prelude::*};
+
+use crate::parquet::{
+	column::ParquetColumn, column_compression_codec::ColumnCompressionCodec,
+	group::ParquetGroupType, numeric::ParquetNumericType, types::ParquetType, ParquetModel,
+};
+
+/// The maximum length of a Parquet column name in bytes
+pub const PARQUET_COLUMN_NAME_MAX: usize = 64;
+/// The maximum precision of a Parquet decimal
+pub const PARQUET_DECIMAL_PRECISION_MAX: u8 = 38;
+/// The maximum depth of nested Parquet groups and lists
+pub const PARQUET_NESTING_DEPTH_MAX: u8 = 8;
+/// The bit widths allowed for Parquet integers
+const PARQUET_INTEGER_BIT_WIDTHS: [u8; 4] = [8, 16, 32, 64];
+/// The units of Parquet `TIME` and `TIMESTAMP` columns
+const PARQUET_TIME_UNITS: [&str; 3] = ["MILLIS", "MICROS", "NANOS"];
+
+/// Errors of an invalid Parquet model. `column` is the path of the column, with the names of
+/// nested columns separated by `.`
+#[derive(Clone, PartialEq, Debug, Eq, Serialize, Deserialize)]
+pub enum ParquetModelError {
+	/// The model is not a list of Parquet columns
+	Malformed,
+	/// The model or a group has no columns. The `column` of the model itself is empty
+	NoColumns {
+		/// Path of the group
+		column: String,
+	},
+	/// The column name is empty, too long, does not start with an ASCII letter or `_`, or has
+	/// characters other than ASCII letters, digits and `_`
+	InvalidColumnName {
+		/// Path of the column
+		column: String,
+	},
+	/// Another column of the same group has the same name
+	DuplicateColumnName {
+		/// Path of the column
+		column: String,
+	},
+	/// Integers must be 8, 16, 32 or 64 bits wide
+	InvalidIntegerBitWidth {
+		/// Path of the column
+		column: String,
+		/// The declared bit width
+		bit_width: u8,
+	},
+	/// Decimal precision must be between 1 and [`PARQUET_DECIMAL_PRECISION_MAX`]
+	InvalidDecimalPrecision {
+		/// Path of the column
+		column: String,
+		/// The declared precision
+		precision: u8,
+	},
+	/// Decimal scale can not be larger than the precision
+	InvalidDecimalScale {
+		/// Path of the column
+		column: String,
+		/// The declared scale
+		scale: u8,
+		/// The declared precision
+		precision: u8,
+	},
+	/// Groups and lists are nested deeper than [`PARQUET_NESTING_DEPTH_MAX`]
+	ExceedsMaxNestingDepth {
+		/// Path of the column
+		column: String,
+	},
+	/// Groups and lists hold no data of their own, so compression is set on their leaf columns
+	CompressedGroup {
+		/// Path of the column
+		column: String,
+	},
+	/// Groups and lists hold no data of their own, so bloom filters are set on their leaf columns
+	BloomFilterOnGroup {
+		/// Path of the column
+		column: String,
+	},
+	/// The unit of a `TIME` or `TIMESTAMP` column is not `MILLIS`, `MICROS` or `NANOS`
+	InvalidTimeUnit {
+		/// Path of the column
+		column: String,
+		/// The declared unit
+		unit: String,
+	},
+}
+
+/// Deserializes and validates a Parquet model
+pub fn parse_parquet_model(model: &[u8]) -> Result<ParquetModel, ParquetModelError> {
+	let columns: ParquetModel = serde_json::from_slice(model).map_err(|_| {
+		// deserialization does not tell which column failed, so look for an invalid time unit
+		serde_json::from_slice::<Value>(model)
+			.ok()
+			.and_then(|columns| find_invalid_time_unit(&columns, ""))
+			.unwrap_or(ParquetModelError::Malformed)
+	})?;
+	validate_parquet_model(&columns)?;
+	Ok(columns)
+}
+
+/// Validates the structure of a Parquet model beyond what deserialization guarantees
+pub fn validate_parquet_model(model: &ParquetModel) -> Result<(), ParquetModelError> {
+	validate_columns(model, "", 0)
+}
+
+fn validate_columns(
+	columns: &[ParquetColumn],
+	parent: &str,
+	depth: u8,
+) -> Result<(), ParquetModelError> {
+	if columns.is_empty() {
+		return Err(ParquetModelError::NoColumns { column: parent.into() })
+	}
+	let mut names = BTreeSet::new();
+	for column in columns {
+		let path = column_path(parent, &column.name);
+		if !is_valid_column_name(&column.name) {
+			return Err(ParquetModelError::InvalidColumnName { column: path })
+		}
+		if !names.insert(column.name.as_str()) {
+			return Err(ParquetModelError::DuplicateColumnName { column: path })
+		}
+		validate_column_type(&column.column_type, &path, depth)?;
+		if let ParquetType::GroupType(_) = column.column_type {
+			if column.compression != ColumnCompressionCodec::Uncompressed {
+				return Err(ParquetModelError::CompressedGroup { column: path })
+			}
+			if column.bloom_filter {
+				return Err(ParquetModelError::BloomFilterOnGroup { column: path })
+			}
+		}
+	}
+	Ok(())
+}
+
+fn validate_column_type(
+	column_type: &ParquetType,
+	path: &str,
+	depth: u8,
+) -> Result<(), ParquetModelError> {
+	match column_type {
+		ParquetType::NumericType(ParquetNumericType::Integer(integer)) =>
+			if !PARQUET_INTEGER_BIT_WIDTHS.contains(&integer.bit_width) {
+				return Err(ParquetModelError::InvalidIntegerBitWidth {
+					column: path.into(),
+					bit_width: integer.bit_width,
+				})
+			},
+		ParquetType::NumericType(ParquetNumericType::Decimal(decimal)) => {
+			if decimal.precision == 0 || decimal.precision > PARQUET_DECIMAL_PRECISION_MAX {
+				return Err(ParquetModelError::InvalidDecimalPrecision {
+					column: path.into(),
+					precision: decimal.precision,
+				})
+			}
+			if decimal.scale > decimal.precision {
+				return Err(ParquetModelError::InvalidDecimalScale {
+					column: path.into(),
+					scale: decimal.scale,
+					precision: decimal.precision,
+				})
+			}
+		},
+		ParquetType::GroupType(group) => {
+			if depth >= PARQUET_NESTING_DEPTH_MAX {
+				return Err(ParquetModelError::ExceedsMaxNestingDepth { column: path.into() })
+			}
+			match group {
+				ParquetGroupType::Group(columns) => validate_columns(columns, path, depth + 1)?,
+				ParquetGroupType::List(element) => validate_column_type(element, path, depth + 1)?,
+			}
+		},
+		// time units are checked by deserialization, see `find_invalid_time_unit`
+		ParquetType::BaseType(_) | ParquetType::StringType(_) | ParquetType::TemporalType(_) => {},
+	}
+	Ok(())
+}
+
+/// Finds a `TIME` or `TIMESTAMP` column of a JSON model whose unit is not one of
+/// [`PARQUET_TIME_UNITS`]
+fn find_invalid_time_unit(columns: &Value, parent: &str) -> Option<ParquetModelError> {
+	columns.as_array()?.iter().find_map(|column| {
+		let name = column.get("name").and_then(Value::as_str).unwrap_or_default();
+		find_invalid_time_unit_in_type(column.get("column_type")?, &column_path(parent, name))
+	})
+}
+
+fn find_invalid_time_unit_in_type(column_type: &Value, path: &str) -> Option<ParquetModelError> {
+	let column_type = column_type.as_object()?;
+	if let Some(columns) = column_type.get("GROUP") {
+		return find_invalid_time_unit(columns, path)
+	}
+	if let Some(element) = column_type.get("LIST") {
+		return find_invalid_time_unit_in_type(element, path)
+	}
+	let unit = column_type.get("TIME").or_else(|| column_type.get("TIMESTAMP"))?.get("unit")?;
+	match unit.as_str() {
+		Some(unit) if PARQUET_TIME_UNITS.contains(&unit) => None,
+		_ => Some(ParquetModelError::InvalidTimeUnit {
+			column: path.into(),
+			unit: unit.as_str().map_or_else(|| unit.to_string(), Into::into),
+		}),
+	}
+}
+
+/// The path of a column, with the names of nested columns separated by `.`
+fn column_path(parent: &str, name: &str) -> String {
+	let mut path = String::from(parent);
+	if !path.is_empty() {
+		path.push('.');
+	}
+	path.push_str(name);
+	path
+}
+
+fn is_valid_column_name(name: &str) -> bool {
+	let mut chars = name.chars();
+	name.len() <= PARQUET_COLUMN_NAME_MAX &&
+		chars.next().map_or(false, |first| first.is_ascii_alphabetic() || first == '_') &&
+		chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn parse(model: &str) -> Result<ParquetModel, ParquetModelError> {
+		parse_parquet_model(model.as_bytes())
+	}
+
+	#[test]
+	fn parse_parquet_model_with_valid_model_succeeds() {
+		let model = r#"[
+			{"name": "announcementType", "column_type": {"INTEGER": {"bit_width": 32, "sign": true}}, "compression": "GZIP", "bloom_filter": false},
+			{"name": "amount", "column_type": {"DECIMAL": {"scale": 2, "precision": 10}}, "compression": "SNAPPY", "bloom_filter": false},
+			{"name": "createdAt", "column_type": {"TIMESTAMP": {"is_adjusted_to_utc": true, "unit": "MILLIS"}}, "compression": "ZSTD", "bloom_filter": false},
+			{"name": "author", "column_type": {"GROUP": [
+				{"name": "id", "column_type": {"INTEGER": {"bit_width": 64, "sign": false}}, "compression": "GZIP", "bloom_filter": true},
+				{"name": "tags", "column_type": {"LIST": "STRING"}, "compression": "UNCOMPRESSED", "bloom_filter": false}
+			]}, "compression": "UNCOMPRESSED", "bloom_filter": false}
+		]"#;
+
+		assert!(parse(model).is_ok());
+	}
+
+	#[test]
+	fn parse_parquet_model_with_malformed_model_fails() {
+		assert_eq!(parse(r#"{"name": "a"}"#), Err(ParquetModelError::Malformed));
+		assert_eq!(
+			parse(
+				r#"[{"name": "a", "column_type": "NOT A TYPE", "compression": "GZIP", "bloom_filter": false}]"#
+			),
+			Err(ParquetModelError::Malformed)
+		);
+	}
+
+	#[test]
+	fn parse_parquet_model_with_every_time_unit_succeeds() {
+		for temporal_type in ["TIME", "TIMESTAMP"] {
+			for unit in PARQUET_TIME_UNITS {
+				let model = format!(
+					r#"[{{"name": "a", "column_type": {{"{}": {{"is_adjusted_to_utc": false, "unit": "{}"}}}}, "compression": "GZIP", "bloom_filter": false}}]"#,
+					temporal_type, unit
+				);
+				assert!(parse(&model).is_ok());
+			}
+		}
+	}
+
+	#[test]
+	fn parse_parquet_model_with_invalid_time_unit_fails() {
+		assert_eq!(
+			parse(
+				r#"[{"name": "a", "column_type": {"TIME": {"is_adjusted_to_utc": true, "unit": "SECONDS"}}, "compression": "GZIP", "bloom_filter": false}]"#
+			),
+			Err(ParquetModelError::InvalidTimeUnit { column: "a".into(), unit: "SECONDS".into() })
+		);
+		assert_eq!(
+			parse(
+				r#"[{"name": "a", "column_type": {"GROUP": [
+					{"name": "b", "column_type": {"LIST": {"TIMESTAMP": {"is_adjusted_to_utc": true, "unit": 3}}}, "compression": "UNCOMPRESSED", "bloom_filter": false}
+				]}, "compression": "UNCOMPRESSED", "bloom_filter": false}]"#
+			),
+			Err(ParquetModelError::InvalidTimeUnit { column: "a.b".into(), unit: "3".into() })
+		);
+	}
+
+	#[test]
+	fn parse_parquet_model_with_no_columns_fails() {
+		assert_eq!(parse("[]"), Err(ParquetModelError::NoColumns { column: "".into() }));
+		assert_eq!(
+			parse(
+				r#"[{"name": "a", "column_type": {"GROUP": []}, "compression": "UNCOMPRESSED", "bloom_filter": false}]"#
+			),
+			Err(ParquetModelError::NoColumns { column: "a".into() })
+		);
+	}
+
+	#[test]
+	fn parse_parquet_model_with_invalid_column_names_fails() {
+		let long_name = "a".repeat(PARQUET_COLUMN_NAME_MAX + 1);
+		for name in ["", "1a", "a b", "a.b", "a-b", long_name.as_str()] {
+			let model = format!(
+				r#"[{{"name": "{}", "column_type": "STRING", "compression": "GZIP", "bloom_filter": false}}]"#,
+				name
+			);
+			assert_eq!(
+				parse(&model),
+				Err(ParquetModelError::InvalidColumnName { column: name.into() })
+			);
+		}
+	}
+
+	#[test]
+	fn parse_parquet_model_with_duplicate_column_names_fails() {
+		let model = r#"[
+			{"name": "a", "column_type": {"GROUP": [
+				{"name": "b", "column_type": "STRING", "compression": "GZIP", "bloom_filter": false},
+				{"name": "b", "column_type": "BOOLEAN", "compression": "GZIP", "bloom_filter": false}
+			]}, "compression": "UNCOMPRESSED", "bloom_filter": false}
+		]"#;
+
+		assert_eq!(
+			parse(model),
+			Err(ParquetModelError::DuplicateColumnName { column: "a.b".into() })
+		);
+	}
+
+	#[test]
+	fn parse_parquet_model_with_invalid_numeric_types_fails() {
+		let column = |column_type: &str| {
+			format!(
+				r#"[{{"name": "a", "column_type": {}, "compression": "GZIP", "bloom_filter": false}}]"#,
+				column_type
+			)
+		};
+
+		assert_eq!(
+			parse(&column(r#"{"INTEGER": {"bit_width": 12, "sign": true}}"#)),
+			Err(ParquetModelError::InvalidIntegerBitWidth { column: "a".into(), bit_width: 12 })
+		);
+		assert_eq!(
+			parse(&column(r#"{"DECIMAL": {"scale": 0, "precision": 0}}"#)),
+			Err(ParquetModelError::InvalidDecimalPrecision { column: "a".into(), precision: 0 })
+		);
+		assert_eq!(
+			parse(&column(r#"{"DECIMAL": {"scale": 0, "precision": 39}}"#)),
+			Err(ParquetModelError::InvalidDecimalPrecision { column: "a".into(), precision: 39 })
+		);
+		assert_eq!(
+			parse(&column(r#"{"DECIMAL": {"scale": 5, "precision": 4}}"#)),
+			Err(ParquetModelError::InvalidDecimalScale {
+				column: "a".into(),
+				scale: 5,
+				precision: 4
+			})
+		);
+	}
+
+	#[test]
+	fn parse_parquet_model_with_compressed_or_filtered_group_fails() {
+		assert_eq!(
+			parse(
+				r#"[{"name": "a", "column_type": {"LIST": "STRING"}, "compression": "GZIP", "bloom_filter": false}]"#
+			),
+			Err(ParquetModelError::CompressedGroup { column: "a".into() })
+		);
+		assert_eq!(
+			parse(
+				r#"[{"name": "a", "column_type": {"LIST": "STRING"}, "compression": "UNCOMPRESSED", "bloom_filter": true}]"#
+			),
+			Err(ParquetModelError::BloomFilterOnGroup { column: "a".into() })
+		);
+	}
+
+	#[test]
+	fn parse_parquet_model_with_deep_nesting_fails() {
+		let mut column_type = String::from(r#""STRING""#);
+		for _ in 0..=PARQUET_NESTING_DEPTH_MAX {
+			column_type = format!(r#"{{"LIST": {}}}"#, column_type);
+		}
+		let model = format!(
+			r#"[{{"name": "a", "column_type": {}, "compression": "UNCOMPRESSED", "bloom_filter": false}}]"#,
+			column_type
+		);
+
+		assert_eq!(
+			parse(&model),
+			Err(ParquetModelError::ExceedsMaxNestingDepth { column: "a".into() })
+		);
+	}
+}
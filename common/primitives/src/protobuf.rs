@@ -0,0 +1,680 @@
+use scale_info::prelude::string::String;
+use serde::{Deserialize, Serialize};
+use sp_std::{collections::btree_set::BTreeSet, prelude::*};
+
+/// The highest field number allowed by Protobuf
+pub const PROTOBUF_FIELD_NUMBER_MAX: i64 = 536_870_911;
+/// Field numbers reserved for the Protobuf implementation
+pub const PROTOBUF_RESERVED_FIELD_NUMBERS: (i64, i64) = (19_000, 19_999);
+/// The maximum depth of nested message declarations
+pub const PROTOBUF_NESTING_DEPTH_MAX: u8 = 32;
+
+/// Label values of `FieldDescriptorProto.label` (optional, required and repeated)
+const PROTOBUF_LABELS: (u64, u64) = (1, 3);
+/// Type values of `FieldDescriptorProto.type` (double through sint64)
+const PROTOBUF_FIELD_TYPES: (u64, u64) = (1, 18);
+const TYPE_GROUP: u64 = 10;
+const TYPE_MESSAGE: u64 = 11;
+const TYPE_ENUM: u64 = 14;
+
+/// Errors of an invalid Protobuf model. Names of declarations are fully qualified, without the
+/// leading `.`
+#[derive(Clone, PartialEq, Debug, Eq, Serialize, Deserialize)]
+pub enum ProtobufModelError {
+	/// The model is not a serialized `google.protobuf.FileDescriptorSet`
+	Malformed,
+	/// Message declarations are nested deeper than [`PROTOBUF_NESTING_DEPTH_MAX`]
+	ExceedsMaxNestingDepth,
+	/// The descriptor set has no files
+	NoFiles,
+	/// A file has no name, or the same name as another file of the set
+	InvalidFileName {
+		/// The file name, empty when it is missing
+		file: String,
+	},
+	/// The syntax of the file is neither `proto2` nor `proto3`
+	UnsupportedSyntax {
+		/// The file name
+		file: String,
+	},
+	/// The name of a package, message, field, enum or enum value is not an identifier
+	InvalidName {
+		/// The name of the declaration
+		name: String,
+	},
+	/// Another declaration in the same scope has the same name
+	DuplicateName {
+		/// The name of the declaration
+		name: String,
+	},
+	/// Field numbers must be between 1 and [`PROTOBUF_FIELD_NUMBER_MAX`], outside of
+	/// [`PROTOBUF_RESERVED_FIELD_NUMBERS`]
+	InvalidFieldNumber {
+		/// The name of the field
+		field: String,
+		/// The declared number, 0 when it is missing
+		number: i64,
+	},
+	/// Another field of the same message has the same number
+	DuplicateFieldNumber {
+		/// The name of the field
+		field: String,
+		/// The declared number
+		number: i64,
+	},
+	/// The field label or type is unknown, or a message or enum field has no type name
+	InvalidFieldType {
+		/// The name of the field
+		field: String,
+	},
+	/// The type name of a field is not a message or enum of the descriptor set
+	UnresolvedTypeName {
+		/// The name of the field
+		field: String,
+		/// The referenced type name
+		type_name: String,
+	},
+	/// An enum has no values
+	EmptyEnum {
+		/// The name of the enum
+		name: String,
+	},
+}
+
+/// Validates a serialized `google.protobuf.FileDescriptorSet`, as written by
+/// `protoc --include_imports --descriptor_set_out`. The set must hold every file it references.
+pub fn validate_protobuf_model(model: &[u8]) -> Result<(), ProtobufModelError> {
+	let files = parse_descriptor_set(model)?;
+	if files.is_empty() {
+		return Err(ProtobufModelError::NoFiles)
+	}
+
+	let mut file_names = BTreeSet::new();
+	let mut messages = BTreeSet::new();
+	let mut enums = BTreeSet::new();
+	for file in files.iter() {
+		if file.name.is_empty() || !file_names.insert(file.name) {
+			return Err(ProtobufModelError::InvalidFileName { file: file.name.into() })
+		}
+		if !matches!(file.syntax, None | Some("proto2") | Some("proto3")) {
+			return Err(ProtobufModelError::UnsupportedSyntax { file: file.name.into() })
+		}
+		if !file.package.is_empty() && !file.package.split('.').all(is_identifier) {
+			return Err(ProtobufModelError::InvalidName { name: file.package.into() })
+		}
+		let scope = package_scope(file.package);
+		collect_names(&scope, &file.messages, &file.enums, &mut messages, &mut enums)?;
+	}
+
+	for file in files.iter() {
+		let scope = package_scope(file.package);
+		for message in file.messages.iter() {
+			validate_message(&scope, message, &messages, &enums)?;
+		}
+	}
+	Ok(())
+}
+
+/// The parts of a `FileDescriptorProto` which are validated
+#[derive(Default)]
+struct FileDescriptor<'a> {
+	name: &'a str,
+	package: &'a str,
+	syntax: Option<&'a str>,
+	messages: Vec<MessageDescriptor<'a>>,
+	enums: Vec<EnumDescriptor<'a>>,
+}
+
+/// The parts of a `DescriptorProto` which are validated
+#[derive(Default)]
+struct MessageDescriptor<'a> {
+	name: &'a str,
+	fields: Vec<FieldDescriptor<'a>>,
+	messages: Vec<MessageDescriptor<'a>>,
+	enums: Vec<EnumDescriptor<'a>>,
+}
+
+/// The parts of a `FieldDescriptorProto` which are validated
+#[derive(Default)]
+struct FieldDescriptor<'a> {
+	name: &'a str,
+	number: Option<i64>,
+	label: Option<u64>,
+	field_type: Option<u64>,
+	type_name: Option<&'a str>,
+}
+
+/// The parts of an `EnumDescriptorProto` which are validated
+#[derive(Default)]
+struct EnumDescriptor<'a> {
+	name: &'a str,
+	values: Vec<&'a str>,
+}
+
+/// Registers the fully qualified names of the messages and enums declared in a scope
+fn collect_names(
+	scope: &str,
+	declared_messages: &[MessageDescriptor],
+	declared_enums: &[EnumDescriptor],
+	messages: &mut BTreeSet<String>,
+	enums: &mut BTreeSet<String>,
+) -> Result<(), ProtobufModelError> {
+	for message in declared_messages {
+		let name = declare(scope, message.name, messages, enums)?;
+		messages.insert(name.clone());
+		collect_names(&name, &message.messages, &message.enums, messages, enums)?;
+	}
+	for declared_enum in declared_enums {
+		let name = declare(scope, declared_enum.name, messages, enums)?;
+		if declared_enum.values.is_empty() {
+			return Err(ProtobufModelError::EmptyEnum { name: display(&name) })
+		}
+		let mut values = BTreeSet::new();
+		for value in declared_enum.values.iter() {
+			let value_name = qualify(&name, value);
+			if !is_identifier(value) {
+				return Err(ProtobufModelError::InvalidName { name: display(&value_name) })
+			}
+			if !values.insert(value) {
+				return Err(ProtobufModelError::DuplicateName { name: display(&value_name) })
+			}
+		}
+		enums.insert(name);
+	}
+	Ok(())
+}
+
+/// Returns the fully qualified name of a new declaration
+fn declare(
+	scope: &str,
+	name: &str,
+	messages: &BTreeSet<String>,
+	enums: &BTreeSet<String>,
+) -> Result<String, ProtobufModelError> {
+	let full_name = qualify(scope, name);
+	if !is_identifier(name) {
+		return Err(ProtobufModelError::InvalidName { name: display(&full_name) })
+	}
+	if messages.contains(&full_name) || enums.contains(&full_name) {
+		return Err(ProtobufModelError::DuplicateName { name: display(&full_name) })
+	}
+	Ok(full_name)
+}
+
+fn validate_message(
+	scope: &str,
+	message: &MessageDescriptor,
+	messages: &BTreeSet<String>,
+	enums: &BTreeSet<String>,
+) -> Result<(), ProtobufModelError> {
+	let message_name = qualify(scope, message.name);
+	let mut field_names = BTreeSet::new();
+	let mut field_numbers = BTreeSet::new();
+	for field in message.fields.iter() {
+		let field_name = qualify(&message_name, field.name);
+		if !is_identifier(field.name) {
+			return Err(ProtobufModelError::InvalidName { name: display(&field_name) })
+		}
+		if !field_names.insert(field.name) {
+			return Err(ProtobufModelError::DuplicateName { name: display(&field_name) })
+		}
+
+		let number = field.number.unwrap_or_default();
+		if !(1..=PROTOBUF_FIELD_NUMBER_MAX).contains(&number) ||
+			(PROTOBUF_RESERVED_FIELD_NUMBERS.0..=PROTOBUF_RESERVED_FIELD_NUMBERS.1)
+				.contains(&number)
+		{
+			return Err(ProtobufModelError::InvalidFieldNumber {
+				field: display(&field_name),
+				number,
+			})
+		}
+		if !field_numbers.insert(number) {
+			return Err(ProtobufModelError::DuplicateFieldNumber {
+				field: display(&field_name),
+				number,
+			})
+		}
+
+		let known_label = field
+			.label
+			.map_or(true, |label| (PROTOBUF_LABELS.0..=PROTOBUF_LABELS.1).contains(&label));
+		let known_type = field.field_type.map_or(field.type_name.is_some(), |field_type| {
+			(PROTOBUF_FIELD_TYPES.0..=PROTOBUF_FIELD_TYPES.1).contains(&field_type)
+		});
+		if !known_label || !known_type {
+			return Err(ProtobufModelError::InvalidFieldType { field: display(&field_name) })
+		}
+		let candidates = match field.field_type {
+			Some(TYPE_GROUP) | Some(TYPE_MESSAGE) => Some((messages, None)),
+			Some(TYPE_ENUM) => Some((enums, None)),
+			Some(_) => None,
+			None => Some((messages, Some(enums))),
+		};
+		if let Some((declared, other_declared)) = candidates {
+			let Some(type_name) = field.type_name else {
+				return Err(ProtobufModelError::InvalidFieldType { field: display(&field_name) })
+			};
+			let resolved = resolve_type_name(&message_name, type_name, declared) ||
+				other_declared
+					.map_or(false, |other| resolve_type_name(&message_name, type_name, other));
+			if !resolved {
+				return Err(ProtobufModelError::UnresolvedTypeName {
+					field: display(&field_name),
+					type_name: type_name.into(),
+				})
+			}
+		}
+	}
+
+	for nested in message.messages.iter() {
+		validate_message(&message_name, nested, messages, enums)?;
+	}
+	Ok(())
+}
+
+/// Type names are either fully qualified with a leading `.`, or relative to an enclosing scope
+fn resolve_type_name(scope: &str, type_name: &str, declared: &BTreeSet<String>) -> bool {
+	if type_name.starts_with('.') {
+		return declared.contains(type_name)
+	}
+	let mut scope = scope;
+	loop {
+		if declared.contains(&qualify(scope, type_name)) {
+			return true
+		}
+		match scope.rfind('.') {
+			Some(index) => scope = &scope[..index],
+			None => return false,
+		}
+	}
+}
+
+/// The scope of the declarations of a file
+fn package_scope(package: &str) -> String {
+	match package.is_empty() {
+		true => String::new(),
+		false => qualify("", package),
+	}
+}
+
+/// The fully qualified name of a declaration in a scope
+fn qualify(scope: &str, name: &str) -> String {
+	let mut full_name = String::from(scope);
+	full_name.push('.');
+	full_name.push_str(name);
+	full_name
+}
+
+fn is_identifier(name: &str) -> bool {
+	let mut chars = name.chars();
+	chars.next().map_or(false, |first| first.is_ascii_alphabetic() || first == '_') &&
+		chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Names in errors do not have the leading `.` of fully qualified names
+fn display(full_name: &str) -> String {
+	full_name.trim_start_matches('.').into()
+}
+
+/// A value of the Protobuf wire format: <https://protobuf.dev/programming-guides/encoding/>
+enum WireValue<'a> {
+	Varint(u64),
+	Fixed64,
+	LengthDelimited(&'a [u8]),
+	Fixed32,
+}
+
+/// Reads the fields of a serialized Protobuf message
+struct WireReader<'a> {
+	bytes: &'a [u8],
+}
+
+impl<'a> WireReader<'a> {
+	fn new(bytes: &'a [u8]) -> Self {
+		Self { bytes }
+	}
+
+	fn varint(&mut self) -> Result<u64, ProtobufModelError> {
+		let mut value = 0u64;
+		for index in 0..10 {
+			let (byte, rest) = self.bytes.split_first().ok_or(ProtobufModelError::Malformed)?;
+			self.bytes = rest;
+			value |= u64::from(byte & 0x7f) << (7 * index);
+			if byte & 0x80 == 0 {
+				return Ok(value)
+			}
+		}
+		Err(ProtobufModelError::Malformed)
+	}
+
+	fn take(&mut self, length: u64) -> Result<&'a [u8], ProtobufModelError> {
+		let length = usize::try_from(length).map_err(|_| ProtobufModelError::Malformed)?;
+		if length > self.bytes.len() {
+			return Err(ProtobufModelError::Malformed)
+		}
+		let (value, rest) = self.bytes.split_at(length);
+		self.bytes = rest;
+		Ok(value)
+	}
+
+	/// Returns the number and value of the next field, or `None` after the last field
+	fn next_field(&mut self) -> Result<Option<(u64, WireValue<'a>)>, ProtobufModelError> {
+		if self.bytes.is_empty() {
+			return Ok(None)
+		}
+		let key = self.varint()?;
+		let number = key >> 3;
+		if number == 0 {
+			return Err(ProtobufModelError::Malformed)
+		}
+		let value = match key & 0x7 {
+			0 => WireValue::Varint(self.varint()?),
+			1 => self.take(8).map(|_| WireValue::Fixed64)?,
+			2 => {
+				let length = self.varint()?;
+				WireValue::LengthDelimited(self.take(length)?)
+			},
+			5 => self.take(4).map(|_| WireValue::Fixed32)?,
+			// groups are deprecated and never used by descriptors
+			_ => return Err(ProtobufModelError::Malformed),
+		};
+		Ok(Some((number, value)))
+	}
+}
+
+fn as_str<'a>(value: WireValue<'a>) -> Result<&'a str, ProtobufModelError> {
+	match value {
+		WireValue::LengthDelimited(bytes) =>
+			core::str::from_utf8(bytes).map_err(|_| ProtobufModelError::Malformed),
+		_ => Err(ProtobufModelError::Malformed),
+	}
+}
+
+fn as_bytes<'a>(value: WireValue<'a>) -> Result<&'a [u8], ProtobufModelError> {
+	match value {
+		WireValue::LengthDelimited(bytes) => Ok(bytes),
+		_ => Err(ProtobufModelError::Malformed),
+	}
+}
+
+fn as_varint(value: WireValue<'_>) -> Result<u64, ProtobufModelError> {
+	match value {
+		WireValue::Varint(value) => Ok(value),
+		_ => Err(ProtobufModelError::Malformed),
+	}
+}
+
+/// `FileDescriptorSet`: `repeated FileDescriptorProto file = 1`
+fn parse_descriptor_set(bytes: &[u8]) -> Result<Vec<FileDescriptor<'_>>, ProtobufModelError> {
+	let mut reader = WireReader::new(bytes);
+	let mut files = Vec::new();
+	while let Some((number, value)) = reader.next_field()? {
+		if number == 1 {
+			files.push(parse_file(as_bytes(value)?)?);
+		}
+	}
+	Ok(files)
+}
+
+fn parse_file(bytes: &[u8]) -> Result<FileDescriptor<'_>, ProtobufModelError> {
+	let mut reader = WireReader::new(bytes);
+	let mut file = FileDescriptor::default();
+	while let Some((number, value)) = reader.next_field()? {
+		match number {
+			1 => file.name = as_str(value)?,
+			2 => file.package = as_str(value)?,
+			4 => file.messages.push(parse_message(as_bytes(value)?, 1)?),
+			5 => file.enums.push(parse_enum(as_bytes(value)?)?),
+			12 => file.syntax = Some(as_str(value)?),
+			_ => {},
+		}
+	}
+	Ok(file)
+}
+
+fn parse_message(bytes: &[u8], depth: u8) -> Result<MessageDescriptor<'_>, ProtobufModelError> {
+	if depth > PROTOBUF_NESTING_DEPTH_MAX {
+		return Err(ProtobufModelError::ExceedsMaxNestingDepth)
+	}
+	let mut reader = WireReader::new(bytes);
+	let mut message = MessageDescriptor::default();
+	while let Some((number, value)) = reader.next_field()? {
+		match number {
+			1 => message.name = as_str(value)?,
+			2 => message.fields.push(parse_field(as_bytes(value)?)?),
+			3 => message.messages.push(parse_message(as_bytes(value)?, depth + 1)?),
+			4 => message.enums.push(parse_enum(as_bytes(value)?)?),
+			_ => {},
+		}
+	}
+	Ok(message)
+}
+
+fn parse_field(bytes: &[u8]) -> Result<FieldDescriptor<'_>, ProtobufModelError> {
+	let mut reader = WireReader::new(bytes);
+	let mut field = FieldDescriptor::default();
+	while let Some((number, value)) = reader.next_field()? {
+		match number {
+			1 => field.name = as_str(value)?,
+			// int32 values are sign extended to 64 bits
+			3 => field.number = Some(as_varint(value)? as i64),
+			4 => field.label = Some(as_varint(value)?),
+			5 => field.field_type = Some(as_varint(value)?),
+			6 => field.type_name = Some(as_str(value)?),
+			_ => {},
+		}
+	}
+	Ok(field)
+}
+
+fn parse_enum(bytes: &[u8]) -> Result<EnumDescriptor<'_>, ProtobufModelError> {
+	let mut reader = WireReader::new(bytes);
+	let mut declared_enum = EnumDescriptor::default();
+	while let Some((number, value)) = reader.next_field()? {
+		match number {
+			1 => declared_enum.name = as_str(value)?,
+			2 => {
+				let mut value_reader = WireReader::new(as_bytes(value)?);
+				let mut value_name = "";
+				while let Some((value_number, value)) = value_reader.next_field()? {
+					if value_number == 1 {
+						value_name = as_str(value)?;
+					}
+				}
+				declared_enum.values.push(value_name);
+			},
+			_ => {},
+		}
+	}
+	Ok(declared_enum)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn bytes_field(number: u64, bytes: &[u8]) -> Vec<u8> {
+		let mut encoded = varint((number << 3) | 2);
+		encoded.extend(varint(bytes.len() as u64));
+		encoded.extend_from_slice(bytes);
+		encoded
+	}
+
+	fn varint_field(number: u64, value: u64) -> Vec<u8> {
+		let mut encoded = varint(number << 3);
+		encoded.extend(varint(value));
+		encoded
+	}
+
+	fn varint(mut value: u64) -> Vec<u8> {
+		let mut encoded = Vec::new();
+		while value >= 0x80 {
+			encoded.push((value as u8) | 0x80);
+			value >>= 7;
+		}
+		encoded.push(value as u8);
+		encoded
+	}
+
+	fn field(name: &str, number: u64, field_type: u64, type_name: Option<&str>) -> Vec<u8> {
+		let mut encoded = bytes_field(1, name.as_bytes());
+		encoded.extend(varint_field(3, number));
+		encoded.extend(varint_field(4, 1));
+		encoded.extend(varint_field(5, field_type));
+		if let Some(type_name) = type_name {
+			encoded.extend(bytes_field(6, type_name.as_bytes()));
+		}
+		bytes_field(2, &encoded)
+	}
+
+	fn message(name: &str, fields: &[Vec<u8>]) -> Vec<u8> {
+		let mut encoded = bytes_field(1, name.as_bytes());
+		fields.iter().for_each(|field| encoded.extend_from_slice(field));
+		bytes_field(4, &encoded)
+	}
+
+	fn enumeration(name: &str, values: &[&str]) -> Vec<u8> {
+		let mut encoded = bytes_field(1, name.as_bytes());
+		for (number, value) in values.iter().enumerate() {
+			let mut value_encoded = bytes_field(1, value.as_bytes());
+			value_encoded.extend(varint_field(2, number as u64));
+			encoded.extend(bytes_field(2, &value_encoded));
+		}
+		bytes_field(5, &encoded)
+	}
+
+	fn file(name: &str, declarations: &[Vec<u8>]) -> Vec<u8> {
+		let mut encoded = bytes_field(1, name.as_bytes());
+		encoded.extend(bytes_field(2, b"dsnp"));
+		encoded.extend(bytes_field(12, b"proto3"));
+		declarations
+			.iter()
+			.for_each(|declaration| encoded.extend_from_slice(declaration));
+		bytes_field(1, &encoded)
+	}
+
+	#[test]
+	fn validate_protobuf_model_with_valid_model_succeeds() {
+		let model = file(
+			"broadcast.proto",
+			&[
+				enumeration("AnnouncementType", &["TOMBSTONE", "BROADCAST"]),
+				message(
+					"Broadcast",
+					&[
+						field("announcement_type", 1, TYPE_ENUM, Some(".dsnp.AnnouncementType")),
+						field("from_id", 2, 4, None),
+						field("url", 3, 9, None),
+						field("reply", 4, TYPE_MESSAGE, Some("Broadcast")),
+					],
+				),
+			],
+		);
+
+		assert_eq!(validate_protobuf_model(&model), Ok(()));
+	}
+
+	#[test]
+	fn validate_protobuf_model_with_malformed_model_fails() {
+		assert_eq!(validate_protobuf_model(b"{}"), Err(ProtobufModelError::Malformed));
+		assert_eq!(
+			validate_protobuf_model(&[0x0a, 0x05, 0x01]),
+			Err(ProtobufModelError::Malformed)
+		);
+		assert_eq!(validate_protobuf_model(&[]), Err(ProtobufModelError::NoFiles));
+	}
+
+	#[test]
+	fn validate_protobuf_model_with_invalid_files_fails() {
+		let message = message("Broadcast", &[field("url", 1, 9, None)]);
+		let mut model = file("broadcast.proto", &[message.clone()]);
+		model.extend(file("broadcast.proto", &[]));
+		assert_eq!(
+			validate_protobuf_model(&model),
+			Err(ProtobufModelError::InvalidFileName { file: "broadcast.proto".into() })
+		);
+
+		let mut model = file("broadcast.proto", &[message.clone()]);
+		model.extend(file("reply.proto", &[message]));
+		assert_eq!(
+			validate_protobuf_model(&model),
+			Err(ProtobufModelError::DuplicateName { name: "dsnp.Broadcast".into() })
+		);
+	}
+
+	#[test]
+	fn validate_protobuf_model_with_invalid_fields_fails() {
+		let cases = [
+			(
+				field("from-id", 1, 4, None),
+				ProtobufModelError::InvalidName { name: "dsnp.Broadcast.from-id".into() },
+			),
+			(
+				field("from_id", 0, 4, None),
+				ProtobufModelError::InvalidFieldNumber {
+					field: "dsnp.Broadcast.from_id".into(),
+					number: 0,
+				},
+			),
+			(
+				field("from_id", 19_000, 4, None),
+				ProtobufModelError::InvalidFieldNumber {
+					field: "dsnp.Broadcast.from_id".into(),
+					number: 19_000,
+				},
+			),
+			(
+				field("from_id", 2, 19, None),
+				ProtobufModelError::InvalidFieldType { field: "dsnp.Broadcast.from_id".into() },
+			),
+			(
+				field("from_id", 2, TYPE_MESSAGE, None),
+				ProtobufModelError::InvalidFieldType { field: "dsnp.Broadcast.from_id".into() },
+			),
+			(
+				field("from_id", 2, TYPE_MESSAGE, Some(".google.protobuf.Timestamp")),
+				ProtobufModelError::UnresolvedTypeName {
+					field: "dsnp.Broadcast.from_id".into(),
+					type_name: ".google.protobuf.Timestamp".into(),
+				},
+			),
+		];
+
+		for (invalid_field, expected) in cases {
+			let model = file(
+				"broadcast.proto",
+				&[message("Broadcast", &[field("url", 1, 9, None), invalid_field])],
+			);
+			assert_eq!(validate_protobuf_model(&model), Err(expected));
+		}
+
+		let model = file(
+			"broadcast.proto",
+			&[message("Broadcast", &[field("url", 1, 9, None), field("from_id", 1, 4, None)])],
+		);
+		assert_eq!(
+			validate_protobuf_model(&model),
+			Err(ProtobufModelError::DuplicateFieldNumber {
+				field: "dsnp.Broadcast.from_id".into(),
+				number: 1
+			})
+		);
+	}
+
+	#[test]
+	fn validate_protobuf_model_with_invalid_enums_fails() {
+		let model = file("broadcast.proto", &[enumeration("AnnouncementType", &[])]);
+		assert_eq!(
+			validate_protobuf_model(&model),
+			Err(ProtobufModelError::EmptyEnum { name: "dsnp.AnnouncementType".into() })
+		);
+
+		let model =
+			file("broadcast.proto", &[enumeration("AnnouncementType", &["REPLY", "REPLY"])]);
+		assert_eq!(
+			validate_protobuf_model(&model),
+			Err(ProtobufModelError::DuplicateName { name: "dsnp.AnnouncementType.REPLY".into() })
+		);
+	}
+}
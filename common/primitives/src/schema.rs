@@ -35,6 +35,11 @@ pub enum ModelType {
 	AvroBinary,
 	/// Message payload modeled with Apache Parquet: <https://parquet.apache.org/>
 	Parquet,
+	/// Message payload modeled with JSON Schema draft 2020-12: <https://json-schema.org/draft/2020-12>
+	JsonSchema,
+	/// Message payload modeled with Protocol Buffers, given as a serialized `FileDescriptorSet`:
+	/// <https://protobuf.dev/>
+	Protobuf,
 }
 
 /// Types of payload locations
@@ -62,6 +67,54 @@ pub enum PayloadLocation {
 	Paginated,
 }
 
+/// Lifecycle status of a schema, set by governance
+#[derive(
+	Copy,
+	Clone,
+	Encode,
+	Decode,
+	PartialEq,
+	Debug,
+	TypeInfo,
+	Eq,
+	MaxEncodedLen,
+	Serialize,
+	Deserialize,
+	Default,
+)]
+pub enum SchemaStatus {
+	/// Schema may be used for new messages and stateful storage
+	#[default]
+	Active,
+	/// Schema may still be used, but clients should move to a newer schema
+	Deprecated,
+	/// New messages and stateful storage writes are rejected for the schema
+	Retired,
+}
+
+/// Compatibility that a new version of a named Avro schema must have with the previous version
+#[derive(
+	Copy,
+	Clone,
+	Encode,
+	Decode,
+	PartialEq,
+	Debug,
+	TypeInfo,
+	Eq,
+	MaxEncodedLen,
+	Serialize,
+	Deserialize,
+)]
+pub enum SchemaCompatibilityMode {
+	/// Data written with the previous version can be read with the new version
+	Backward,
+	/// Data written with the new version can be read with the previous version
+	Forward,
+	/// Both backward and forward compatible
+	Full,
+}
+
 /// Support for up to 16 user-enabled features on a collection.
 #[bitflags]
 #[repr(u16)]
@@ -106,6 +159,40 @@ pub struct SchemaResponse {
 	pub payload_location: PayloadLocation,
 	/// grants for the schema
 	pub settings: Vec<SchemaSetting>,
+	/// The status of the schema
+	pub status: SchemaStatus,
+}
+
+/// A [`SchemaResponse`] as returned by the runtime APIs before Schemas had a status: version 2
+/// of the Schemas runtime API and version 1 of the Messages runtime API
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
+pub struct SchemaResponseV2 {
+	/// The unique identifier for this Schema
+	pub schema_id: SchemaId,
+	/// The data that represents how this schema is structured
+	#[cfg_attr(feature = "std", serde(with = "as_string"))]
+	pub model: Vec<u8>,
+	/// The model format type for how the schema model is represented
+	pub model_type: ModelType,
+	/// The payload location
+	pub payload_location: PayloadLocation,
+	/// grants for the schema
+	pub settings: Vec<SchemaSetting>,
+}
+
+impl From<SchemaResponseV2> for SchemaResponse {
+	/// Schemas were all active before they had a status
+	fn from(schema: SchemaResponseV2) -> Self {
+		Self {
+			schema_id: schema.schema_id,
+			model: schema.model,
+			model_type: schema.model_type,
+			payload_location: schema.payload_location,
+			settings: schema.settings,
+			status: SchemaStatus::Active,
+		}
+	}
 }
 
 /// RPC Response form for a Schema Info
@@ -120,6 +207,8 @@ pub struct SchemaInfoResponse {
 	pub payload_location: PayloadLocation,
 	/// grants for the schema
 	pub settings: Vec<SchemaSetting>,
+	/// The status of the schema
+	pub status: SchemaStatus,
 }
 
 /// This allows other pallets to resolve Schema information. With generic SchemaId
@@ -176,6 +265,57 @@ pub struct SchemaVersionResponse {
 	pub schema_version: SchemaVersion,
 	/// The unique identifier for this Schema
 	pub schema_id: SchemaId,
+	/// The status of the schema
+	pub status: SchemaStatus,
+}
+
+/// A [`SchemaVersionResponse`] as returned by version 2 of the Schemas runtime API, before
+/// Schemas had a status
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
+pub struct SchemaVersionResponseV2 {
+	/// Schema name in following format: namespace.descriptor
+	#[cfg_attr(feature = "std", serde(with = "as_string"))]
+	pub schema_name: Vec<u8>,
+	/// The version for this schema
+	pub schema_version: SchemaVersion,
+	/// The unique identifier for this Schema
+	pub schema_id: SchemaId,
+}
+
+impl From<SchemaVersionResponseV2> for SchemaVersionResponse {
+	/// Schemas were all active before they had a status
+	fn from(version: SchemaVersionResponseV2) -> Self {
+		Self {
+			schema_name: version.schema_name,
+			schema_version: version.schema_version,
+			schema_id: version.schema_id,
+			status: SchemaStatus::Active,
+		}
+	}
+}
+
+/// An RPC response about a Schema, with a warning for clients when the Schema is deprecated
+#[cfg(feature = "std")]
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct WithSchemaWarning<Response> {
+	/// The response itself
+	#[serde(flatten)]
+	pub response: Response,
+	/// Set when the Schema is deprecated
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub warning: Option<String>,
+}
+
+#[cfg(feature = "std")]
+impl<Response> WithSchemaWarning<Response> {
+	/// Adds a warning to `response` when the Schema `schema_id` has the `Deprecated` status
+	pub fn new(response: Response, schema_id: SchemaId, status: SchemaStatus) -> Self {
+		let warning = (status == SchemaStatus::Deprecated).then(|| {
+			format!("Schema {} is deprecated, clients should move to a newer Schema", schema_id)
+		});
+		Self { response, warning }
+	}
 }
 
 #[cfg(test)]
@@ -194,4 +334,24 @@ mod tests {
 		assert!(settings.is_enabled(SchemaSetting::AppendOnly));
 		assert!(settings.is_enabled(SchemaSetting::SignatureRequired));
 	}
+
+	#[test]
+	fn with_schema_warning_flattens_the_response_and_warns_only_when_deprecated() {
+		let version = SchemaVersionResponse {
+			schema_name: b"namespace.descriptor".to_vec(),
+			schema_version: 1,
+			schema_id: 4,
+			status: SchemaStatus::Active,
+		};
+
+		let active = WithSchemaWarning::new(version.clone(), 4, SchemaStatus::Active);
+		let json = serde_json::to_value(&active).unwrap();
+		assert_eq!(json["schema_id"], 4);
+		assert!(json.get("warning").is_none());
+
+		let deprecated = WithSchemaWarning::new(version, 4, SchemaStatus::Deprecated);
+		let json = serde_json::to_value(&deprecated).unwrap();
+		assert_eq!(json["schema_id"], 4);
+		assert!(json["warning"].is_string());
+	}
 }
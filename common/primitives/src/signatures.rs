@@ -0,0 +1,378 @@
+use crate::utils::wrap_binary_data;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_core::crypto::AccountId32;
+use sp_io::hashing::keccak_256;
+use sp_runtime::{traits::Verify, MultiSignature, RuntimeDebug};
+use sp_std::vec::Vec;
+
+/// The last 12 bytes of an `AccountId32` that holds a 20 byte Ethereum address
+pub const ETHEREUM_ACCOUNT_SUFFIX: [u8; 12] = [0xEE; 12];
+
+/// The EIP-712 domain name used by all Recurrency typed data
+pub const EIP_712_DOMAIN_NAME: &[u8] = b"Recurrency";
+
+/// The EIP-712 domain version used by all Recurrency typed data
+pub const EIP_712_DOMAIN_VERSION: &[u8] = b"1";
+
+/// The chain specific members of the EIP-712 domain of Recurrency typed data.
+/// Each runtime sets its own, so that a signature for one chain cannot be replayed on another.
+#[derive(Clone, Copy, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug, PartialEq, Eq)]
+pub struct EIP712Domain {
+	/// The EIP-155 chain id
+	pub chain_id: u64,
+	/// The address that verifies the typed data
+	pub verifying_contract: [u8; 20],
+}
+
+/// The order `n` of the secp256k1 curve, big-endian
+const SECP256K1_ORDER: [u8; 32] = [
+	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE,
+	0xBA, 0xAE, 0xDC, 0xE6, 0xAF, 0x48, 0xA0, 0x3B, 0xBF, 0xD2, 0x5E, 0x8C, 0xD0, 0x36, 0x41, 0x41,
+];
+
+/// Half the order of the secp256k1 curve, big-endian. A signature with a greater `s` verifies
+/// just like its twin with `n - s` and the other recovery id.
+const SECP256K1_HALF_ORDER: [u8; 32] = [
+	0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+	0x5D, 0x57, 0x6E, 0x73, 0x57, 0xA4, 0x50, 0x1D, 0xDF, 0xE9, 0x2F, 0x46, 0x68, 0x1B, 0x20, 0xA0,
+];
+
+const EIP_712_DOMAIN_TYPE: &[u8] =
+	b"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)";
+
+/// A signed payload that can be presented to an Ethereum wallet as EIP-712 typed data
+pub trait EIP712Encode {
+	/// The EIP-712 `hashStruct` of the payload
+	fn eip_712_hash_struct(&self) -> [u8; 32];
+}
+
+/// Converts a 20 byte Ethereum address into the `AccountId32` it is stored as
+pub fn ethereum_address_to_account_id(address: &[u8; 20]) -> AccountId32 {
+	let mut bytes = [0u8; 32];
+	bytes[..20].copy_from_slice(address);
+	bytes[20..].copy_from_slice(&ETHEREUM_ACCOUNT_SUFFIX);
+	AccountId32::new(bytes)
+}
+
+/// Returns the Ethereum address held by `account`, if it holds one
+pub fn account_id_to_ethereum_address(account: &AccountId32) -> Option<[u8; 20]> {
+	let bytes: &[u8; 32] = account.as_ref();
+	if bytes[20..] != ETHEREUM_ACCOUNT_SUFFIX {
+		return None
+	}
+	let mut address = [0u8; 20];
+	address.copy_from_slice(&bytes[..20]);
+	Some(address)
+}
+
+/// Encodes an unsigned integer as an EIP-712 `uintN` value
+pub fn eip_712_encode_uint(value: u64) -> [u8; 32] {
+	let mut encoded = [0u8; 32];
+	encoded[24..].copy_from_slice(&value.to_be_bytes());
+	encoded
+}
+
+/// Encodes a 20 byte address as an EIP-712 `address` value
+pub fn eip_712_encode_address(address: &[u8; 20]) -> [u8; 32] {
+	let mut encoded = [0u8; 32];
+	encoded[12..].copy_from_slice(address);
+	encoded
+}
+
+/// Encodes a dynamic `bytes` or `string` value as EIP-712 does, by hashing it
+pub fn eip_712_encode_bytes(value: &[u8]) -> [u8; 32] {
+	keccak_256(value)
+}
+
+/// Encodes an array of already encoded values as EIP-712 does, by hashing their concatenation
+pub fn eip_712_encode_array(values: &[[u8; 32]]) -> [u8; 32] {
+	keccak_256(&values.concat())
+}
+
+/// Calculates the EIP-712 `hashStruct` of a struct with the type string `type_string` and the
+/// encoded member values `values`
+pub fn eip_712_hash_struct(type_string: &[u8], values: &[[u8; 32]]) -> [u8; 32] {
+	let mut encoded: Vec<u8> = keccak_256(type_string).to_vec();
+	encoded.extend_from_slice(&values.concat());
+	keccak_256(&encoded)
+}
+
+/// The EIP-712 domain separator of Recurrency typed data in `domain`
+pub fn eip_712_domain_separator(domain: &EIP712Domain) -> [u8; 32] {
+	eip_712_hash_struct(
+		EIP_712_DOMAIN_TYPE,
+		&[
+			eip_712_encode_bytes(EIP_712_DOMAIN_NAME),
+			eip_712_encode_bytes(EIP_712_DOMAIN_VERSION),
+			eip_712_encode_uint(domain.chain_id),
+			eip_712_encode_address(&domain.verifying_contract),
+		],
+	)
+}
+
+/// The hash an Ethereum wallet signs for the typed data `payload` in `domain`
+pub fn eip_712_signing_hash<P: EIP712Encode>(domain: &EIP712Domain, payload: &P) -> [u8; 32] {
+	let mut message: Vec<u8> = b"\x19\x01".to_vec();
+	message.extend_from_slice(&eip_712_domain_separator(domain));
+	message.extend_from_slice(&payload.eip_712_hash_struct());
+	keccak_256(&message)
+}
+
+/// Returns the canonical form of the secp256k1 `signature` (`r`, `s`, `v`): `v` is 0 or 1 rather
+/// than 27 or 28, and `s` is at most half the curve order, which flips `v` when `s` is replaced by
+/// `n - s`. All forms of a signature have the same canonical form.
+/// Returns None if `v` is none of 0, 1, 27 and 28.
+pub fn normalize_ecdsa_signature(signature: &[u8; 65]) -> Option<[u8; 65]> {
+	let mut normalized = *signature;
+	normalized[64] = match signature[64] {
+		0 | 1 => signature[64],
+		27 | 28 => signature[64] - 27,
+		_ => return None,
+	};
+	if normalized[32..64] > SECP256K1_HALF_ORDER[..] {
+		return Some(ecdsa_signature_twin(&normalized))
+	}
+	Some(normalized)
+}
+
+/// Returns the twin of the secp256k1 `signature`, with `n - s` for `s` and the other recovery id.
+/// Both verify for the same key and message.
+pub fn ecdsa_signature_twin(signature: &[u8; 65]) -> [u8; 65] {
+	let mut twin = *signature;
+	let mut borrow = 0u8;
+	for i in (32..64).rev() {
+		let (digit, underflow) = SECP256K1_ORDER[i - 32].overflowing_sub(signature[i]);
+		let (digit, underflow_borrow) = digit.overflowing_sub(borrow);
+		twin[i] = digit;
+		borrow = (underflow || underflow_borrow) as u8;
+	}
+	twin[64] ^= 1;
+	twin
+}
+
+/// Returns the canonical form of `signature`, under which it is recorded as submitted.
+/// Only secp256k1 signatures have more than one form, see [`normalize_ecdsa_signature`].
+pub fn normalize_signature(signature: &MultiSignature) -> MultiSignature {
+	match signature {
+		MultiSignature::Ecdsa(ecdsa) => match normalize_ecdsa_signature(&ecdsa.0) {
+			Some(normalized) =>
+				MultiSignature::Ecdsa(sp_core::ecdsa::Signature::from_raw(normalized)),
+			None => signature.clone(),
+		},
+		_ => signature.clone(),
+	}
+}
+
+/// Verify the `signature` was signed by `signer` on `payload`.
+///
+/// Ethereum signers must sign the EIP-712 typed data of `payload` in `domain` with a secp256k1 key.
+/// Their signatures may use a `v` of 0, 1, 27 or 28, but not an `s` over half the curve order.
+/// All other signers must sign `encoded_payload` wrapped with `<Bytes>` tags by `wrap_binary_data`,
+/// following the Polkadot wallet pattern.
+pub fn verify_signature<P: EIP712Encode>(
+	signature: &MultiSignature,
+	signer: &AccountId32,
+	payload: &P,
+	encoded_payload: Vec<u8>,
+	domain: &EIP712Domain,
+) -> bool {
+	match account_id_to_ethereum_address(signer) {
+		Some(address) => match signature {
+			MultiSignature::Ecdsa(signature) => {
+				if signature.0[32..64] > SECP256K1_HALF_ORDER[..] {
+					return false
+				}
+				let Some(normalized) = normalize_ecdsa_signature(&signature.0) else {
+					return false
+				};
+				let hash = eip_712_signing_hash(domain, payload);
+				match sp_io::crypto::secp256k1_ecdsa_recover(&normalized, &hash) {
+					Ok(public_key) => keccak_256(&public_key)[12..] == address,
+					Err(_) => false,
+				}
+			},
+			_ => false,
+		},
+		None => signature.verify(&wrap_binary_data(encoded_payload)[..], signer),
+	}
+}
+
+/// Returns the Ethereum address of the secp256k1 `pair`
+#[cfg(feature = "std")]
+pub fn ethereum_address_of(pair: &sp_core::ecdsa::Pair) -> [u8; 20] {
+	use sp_core::Pair;
+
+	let hash = [0u8; 32];
+	let signature = pair.sign_prehashed(&hash);
+	let public_key = sp_io::crypto::secp256k1_ecdsa_recover(&signature.0, &hash)
+		.expect("a freshly made signature should recover");
+	let mut address = [0u8; 20];
+	address.copy_from_slice(&keccak_256(&public_key)[12..]);
+	address
+}
+
+/// Signs the EIP-712 typed data of `payload` in `domain` with the secp256k1 `pair`, as an Ethereum
+/// wallet would
+#[cfg(feature = "std")]
+pub fn sign_eip_712<P: EIP712Encode>(
+	pair: &sp_core::ecdsa::Pair,
+	domain: &EIP712Domain,
+	payload: &P,
+) -> MultiSignature {
+	MultiSignature::Ecdsa(pair.sign_prehashed(&eip_712_signing_hash(domain, payload)))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use parity_scale_codec::Encode;
+	use sp_core::{ecdsa, sr25519, Pair};
+
+	const TEST_DOMAIN: EIP712Domain =
+		EIP712Domain { chain_id: 0x190f_1b44, verifying_contract: [0xCC; 20] };
+
+	struct TestPayload {
+		value: u64,
+	}
+
+	impl EIP712Encode for TestPayload {
+		fn eip_712_hash_struct(&self) -> [u8; 32] {
+			eip_712_hash_struct(b"TestPayload(uint64 value)", &[eip_712_encode_uint(self.value)])
+		}
+	}
+
+	#[test]
+	fn ethereum_address_round_trips_through_account_id() {
+		let address = [7u8; 20];
+		let account = ethereum_address_to_account_id(&address);
+		assert_eq!(account_id_to_ethereum_address(&account), Some(address));
+		assert_eq!(account_id_to_ethereum_address(&AccountId32::new([7u8; 32])), None);
+	}
+
+	#[test]
+	fn eip_712_encode_uint_is_big_endian_and_left_padded() {
+		let encoded = eip_712_encode_uint(0x0102);
+		assert_eq!(encoded[..30], [0u8; 30]);
+		assert_eq!(encoded[30..], [1u8, 2u8]);
+	}
+
+	#[test]
+	fn verify_signature_accepts_eip_712_signature_from_ethereum_signer() {
+		let (pair, _) = ecdsa::Pair::generate();
+		let signer = ethereum_address_to_account_id(&ethereum_address_of(&pair));
+		let payload = TestPayload { value: 42 };
+		let signature = sign_eip_712(&pair, &TEST_DOMAIN, &payload);
+
+		assert!(verify_signature(
+			&signature,
+			&signer,
+			&payload,
+			payload.value.encode(),
+			&TEST_DOMAIN
+		));
+		assert!(!verify_signature(
+			&signature,
+			&signer,
+			&TestPayload { value: 43 },
+			payload.value.encode(),
+			&TEST_DOMAIN
+		));
+	}
+
+	#[test]
+	fn verify_signature_rejects_eip_712_signature_for_other_domain() {
+		let (pair, _) = ecdsa::Pair::generate();
+		let signer = ethereum_address_to_account_id(&ethereum_address_of(&pair));
+		let payload = TestPayload { value: 42 };
+		let signature = sign_eip_712(&pair, &TEST_DOMAIN, &payload);
+		let other_domain = EIP712Domain { chain_id: TEST_DOMAIN.chain_id + 1, ..TEST_DOMAIN };
+
+		assert!(!verify_signature(
+			&signature,
+			&signer,
+			&payload,
+			payload.value.encode(),
+			&other_domain
+		));
+	}
+
+	#[test]
+	fn verify_signature_rejects_eip_712_signature_from_other_ethereum_signer() {
+		let (pair, _) = ecdsa::Pair::generate();
+		let (other_pair, _) = ecdsa::Pair::generate();
+		let signer = ethereum_address_to_account_id(&ethereum_address_of(&other_pair));
+		let payload = TestPayload { value: 42 };
+		let signature = sign_eip_712(&pair, &TEST_DOMAIN, &payload);
+
+		assert!(!verify_signature(
+			&signature,
+			&signer,
+			&payload,
+			payload.value.encode(),
+			&TEST_DOMAIN
+		));
+	}
+
+	/// The other forms of the EIP-712 `signature`: `v` + 27, and the twin with `n - s`
+	fn other_forms_of(signature: &MultiSignature) -> (MultiSignature, MultiSignature) {
+		let MultiSignature::Ecdsa(signature) = signature else { panic!("not an ecdsa signature") };
+		let mut with_27 = signature.0;
+		with_27[64] += 27;
+		let high_s = ecdsa_signature_twin(&signature.0);
+		(
+			MultiSignature::Ecdsa(ecdsa::Signature::from_raw(with_27)),
+			MultiSignature::Ecdsa(ecdsa::Signature::from_raw(high_s)),
+		)
+	}
+
+	#[test]
+	fn verify_signature_accepts_v_plus_27_and_rejects_high_s_eip_712_signatures() {
+		let (pair, _) = ecdsa::Pair::generate();
+		let signer = ethereum_address_to_account_id(&ethereum_address_of(&pair));
+		let payload = TestPayload { value: 42 };
+		let signature = sign_eip_712(&pair, &TEST_DOMAIN, &payload);
+		let (with_27, high_s) = other_forms_of(&signature);
+
+		assert!(verify_signature(
+			&with_27,
+			&signer,
+			&payload,
+			payload.value.encode(),
+			&TEST_DOMAIN
+		));
+		assert!(!verify_signature(
+			&high_s,
+			&signer,
+			&payload,
+			payload.value.encode(),
+			&TEST_DOMAIN
+		));
+	}
+
+	#[test]
+	fn normalize_signature_gives_every_form_of_a_signature_the_same_form() {
+		let (pair, _) = ecdsa::Pair::generate();
+		let signature = sign_eip_712(&pair, &TEST_DOMAIN, &TestPayload { value: 42 });
+		let (with_27, high_s) = other_forms_of(&signature);
+
+		assert_eq!(normalize_signature(&signature), signature);
+		assert_eq!(normalize_signature(&with_27), signature);
+		assert_eq!(normalize_signature(&high_s), signature);
+	}
+
+	#[test]
+	fn verify_signature_accepts_wrapped_scale_signature_from_substrate_signer() {
+		let (pair, _) = sr25519::Pair::generate();
+		let payload = TestPayload { value: 42 };
+		let signature: MultiSignature = pair.sign(&wrap_binary_data(payload.value.encode())).into();
+
+		assert!(verify_signature(
+			&signature,
+			&pair.public().into(),
+			&payload,
+			payload.value.encode(),
+			&TEST_DOMAIN
+		));
+	}
+}
@@ -0,0 +1,105 @@
+export default {
+  rpc: {
+    getCapacityForProvider: {
+      description: 'Get the Capacity details of a Provider, if it has any stake',
+      params: [
+        {
+          name: 'provider_msa_id',
+          type: 'MessageSourceId',
+        },
+        {
+          name: 'at',
+          type: 'BlockHash',
+          isOptional: true,
+        },
+      ],
+      type: 'Option<CapacityDetailsResponse>',
+    },
+    getStakesForAccount: {
+      description: "Get an account's stakes for each Provider it targets",
+      params: [
+        {
+          name: 'staker',
+          type: 'AccountId',
+        },
+        {
+          name: 'at',
+          type: 'BlockHash',
+          isOptional: true,
+        },
+      ],
+      type: 'Vec<StakingTargetResponse>',
+    },
+    getUnlockChunks: {
+      description: "Get an account's pending unlock chunks",
+      params: [
+        {
+          name: 'staker',
+          type: 'AccountId',
+        },
+        {
+          name: 'at',
+          type: 'BlockHash',
+          isOptional: true,
+        },
+      ],
+      type: 'Vec<UnlockChunkResponse>',
+    },
+  },
+  types: {
+    CapacityDetailsResponse: {
+      remaining_capacity: 'Balance',
+      total_tokens_staked: 'Balance',
+      total_capacity_issued: 'Balance',
+      last_replenished_epoch: 'u32',
+    },
+    StakingTargetResponse: {
+      target: 'MessageSourceId',
+      amount: 'Balance',
+      capacity: 'Balance',
+    },
+    UnlockChunkResponse: {
+      value: 'Balance',
+      thaw_at: 'u32',
+    },
+  },
+  runtime: {
+    CapacityRuntimeApi: [
+      {
+        methods: {
+          get_capacity_for_provider: {
+            description: 'Get the Capacity details of a Provider, if it has any stake',
+            params: [
+              {
+                name: 'provider',
+                type: 'MessageSourceId',
+              },
+            ],
+            type: 'Option<CapacityDetailsResponse>',
+          },
+          get_stakes_for_account: {
+            description: "Get an account's stakes for each Provider it targets",
+            params: [
+              {
+                name: 'staker',
+                type: 'AccountId',
+              },
+            ],
+            type: 'Vec<StakingTargetResponse>',
+          },
+          get_unlock_chunks: {
+            description: "Get an account's pending unlock chunks",
+            params: [
+              {
+                name: 'staker',
+                type: 'AccountId',
+              },
+            ],
+            type: 'Vec<UnlockChunkResponse>',
+          },
+        },
+        version: 1,
+      },
+    ],
+  },
+};
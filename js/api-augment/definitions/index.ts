@@ -6,5 +6,6 @@ export { default as messages } from './messages.js';
 export { default as schemas } from './schemas.js';
 export { default as statefulStorage } from './statefulStorage.js';
 export { default as handles } from './handles.js';
+export { default as capacity } from './capacity.js';
 export { default as recurrency } from './recurrency.js';
 export { default as recurrencyTxPayment } from './recurrencyTxPayment.js';
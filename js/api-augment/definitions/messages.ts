@@ -12,7 +12,7 @@ export default {
           type: 'BlockPaginationRequest',
         },
       ],
-      type: 'BlockPaginationResponseMessage',
+      type: 'BlockPaginationResponseMessageWithWarning',
     },
   },
   types: {
@@ -37,6 +37,13 @@ export default {
       next_block: 'Option<BlockNumber>',
       next_index: 'Option<u32>',
     },
+    BlockPaginationResponseMessageWithWarning: {
+      content: 'Vec<MessageResponse>',
+      has_next: 'bool',
+      next_block: 'Option<BlockNumber>',
+      next_index: 'Option<u32>',
+      warning: 'Option<Text>',
+    },
   },
   runtime: {
     MessagesRuntimeApi: [
@@ -71,6 +78,39 @@ export default {
             type: 'Option<SchemaResponse>',
           },
         },
+        version: 2,
+      },
+      {
+        methods: {
+          get_messages_by_schema_and_block: {
+            description: 'Retrieve the messages for a particular schema and block number',
+            params: [
+              {
+                name: 'schema_id',
+                type: 'SchemaId',
+              },
+              {
+                name: 'schema_payload_location',
+                type: 'PayloadLocation',
+              },
+              {
+                name: 'block_number',
+                type: 'BlockNumber',
+              },
+            ],
+            type: 'Vec<MessageResponse>',
+          },
+          get_schema_by_id: {
+            description: 'Retrieve a schema by id',
+            params: [
+              {
+                name: 'schema_id',
+                type: 'SchemaId',
+              },
+            ],
+            type: 'Option<SchemaResponseV2>',
+          },
+        },
         version: 1,
       },
     ],
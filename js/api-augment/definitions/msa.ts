@@ -56,6 +56,104 @@ export default {
       ],
       type: 'Vec<DelegationResponse>',
     },
+    getDelegatorsByProvider: {
+      description: 'Get a page of the delegators of a provider, optionally filtered by schema and status',
+      params: [
+        {
+          name: 'provider_msa_id',
+          type: 'ProviderId',
+        },
+        {
+          name: 'request',
+          type: 'DelegatorsPaginationRequest',
+        },
+      ],
+      type: 'DelegatorsPaginationResponse',
+    },
+    getDelegationRequests: {
+      description: 'Get the delegation requests pending for a delegator that can still be accepted',
+      params: [
+        {
+          name: 'delegator_msa_id',
+          type: 'DelegatorId',
+        },
+      ],
+      type: 'Vec<DelegationRequestResponse>',
+    },
+    getMsaIdsForKeys: {
+      description: 'Get the MSA id each key belongs to',
+      params: [
+        {
+          name: 'keys',
+          type: 'Vec<AccountId>',
+        },
+        {
+          name: 'at',
+          type: 'BlockHash',
+          isOptional: true,
+        },
+      ],
+      type: 'Vec<Option<MessageSourceId>>',
+    },
+    getKeyOwners: {
+      description: 'Get the MSA id and number of public keys of the MSA each key belongs to',
+      params: [
+        {
+          name: 'keys',
+          type: 'Vec<AccountId>',
+        },
+        {
+          name: 'at',
+          type: 'BlockHash',
+          isOptional: true,
+        },
+      ],
+      type: 'Vec<Option<KeyOwnerResponse>>',
+    },
+    getMsaRedirect: {
+      description: 'Get the MSA id a merged MSA was merged into',
+      params: [
+        {
+          name: 'msa_id',
+          type: 'MessageSourceId',
+        },
+        {
+          name: 'at',
+          type: 'BlockHash',
+          isOptional: true,
+        },
+      ],
+      type: 'Option<MessageSourceId>',
+    },
+    getHistory: {
+      description: 'Get a page of the key and delegation history of an MSA within a block range',
+      params: [
+        {
+          name: 'msa_id',
+          type: 'MessageSourceId',
+        },
+        {
+          name: 'pagination',
+          type: 'BlockPaginationRequest',
+        },
+      ],
+      type: 'BlockPaginationResponseMsaHistory',
+    },
+    checkOffchainIndex: {
+      description: 'Compare the offchain index of MSA keys with the chain state at a block',
+      params: [
+        {
+          name: 'block_number',
+          type: 'Option<BlockNumber>',
+        },
+      ],
+      type: 'OffchainIndexReport',
+    },
+    rebuildOffchainIndex: {
+      description: 'Rewrite the offchain index keys of the MSAs that differ from the chain state',
+      params: [],
+      type: 'OffchainIndexReport',
+    },
   },
   types: {
     MessageSourceId: 'u64',
@@ -73,6 +171,74 @@ export default {
       provider_id: 'ProviderId',
       permissions: 'Vec<SchemaGrantResponse>',
     },
+    ProviderInfoResponse: {
+      provider_id: 'ProviderId',
+      provider_name: 'Bytes',
+      homepage_url: 'Bytes',
+      logo_cid: 'Bytes',
+      contact: 'Bytes',
+      terms_of_service_hash: 'Option<H256>',
+      supported_schema_ids: 'Vec<SchemaId>',
+    },
+    DelegationStatus: {
+      _enum: ['Active', 'Revoked'],
+    },
+    DelegatorsPaginationRequest: {
+      schema_id: 'Option<SchemaId>',
+      status: 'Option<DelegationStatus>',
+      after: 'Option<DelegatorId>',
+      page_size: 'u32',
+    },
+    DelegatorResponse: {
+      delegator_id: 'DelegatorId',
+      revoked_at: 'BlockNumber',
+      permissions: 'Vec<SchemaGrantResponse>',
+    },
+    DelegatorsPaginationResponse: {
+      content: 'Vec<DelegatorResponse>',
+      has_next: 'bool',
+      next_after: 'Option<DelegatorId>',
+    },
+    DelegationRequestResponse: {
+      provider_id: 'ProviderId',
+      schema_ids: 'Vec<SchemaId>',
+      requested_at: 'BlockNumber',
+      expiration: 'BlockNumber',
+      delegation_expires_at: 'Option<BlockNumber>',
+    },
+    KeyOwnerResponse: {
+      msa_id: 'MessageSourceId',
+      public_key_count: 'u8',
+    },
+    MsaHistoryEvent: {
+      _enum: {
+        DelegationGranted: { provider_id: 'ProviderId' },
+        DelegationUpdated: { provider_id: 'ProviderId' },
+        DelegationRevoked: { provider_id: 'ProviderId' },
+        PublicKeyAdded: { key: 'AccountId' },
+        PublicKeyDeleted: { key: 'AccountId' },
+      },
+    },
+    MsaHistoryRecord: {
+      block_number: 'BlockNumber',
+      event: 'MsaHistoryEvent',
+    },
+    BlockPaginationResponseMsaHistory: {
+      content: 'Vec<MsaHistoryRecord>',
+      has_next: 'bool',
+      next_block: 'Option<BlockNumber>',
+      next_index: 'Option<u32>',
+    },
+    OffchainIndexMismatch: {
+      msa_id: 'MessageSourceId',
+      missing_keys: 'Vec<AccountId>',
+      unexpected_keys: 'Vec<AccountId>',
+    },
+    OffchainIndexReport: {
+      block_number: 'BlockNumber',
+      checked_msa_count: 'u64',
+      mismatches: 'Vec<OffchainIndexMismatch>',
+    },
   },
   runtime: {
     MsaRuntimeApi: [
@@ -126,8 +292,72 @@ export default {
             ],
             type: 'Vec<DelegationResponse>',
           },
+          get_provider_info: {
+            description: 'Get the name and published metadata of a registered provider',
+            params: [
+              {
+                name: 'provider_id',
+                type: 'ProviderId',
+              },
+            ],
+            type: 'Option<ProviderInfoResponse>',
+          },
+          get_delegators_by_provider: {
+            description: 'Get a page of the delegators of a provider, optionally filtered by schema and status',
+            params: [
+              {
+                name: 'provider_id',
+                type: 'ProviderId',
+              },
+              {
+                name: 'request',
+                type: 'DelegatorsPaginationRequest',
+              },
+            ],
+            type: 'DelegatorsPaginationResponse',
+          },
+          get_delegation_requests: {
+            description: 'Get the delegation requests pending for a delegator that can still be accepted',
+            params: [
+              {
+                name: 'delegator_id',
+                type: 'DelegatorId',
+              },
+            ],
+            type: 'Vec<DelegationRequestResponse>',
+          },
+          get_msa_ids_for_keys: {
+            description: 'Get the MSA id each key belongs to',
+            params: [
+              {
+                name: 'keys',
+                type: 'Vec<AccountId>',
+              },
+            ],
+            type: 'Vec<Option<MessageSourceId>>',
+          },
+          get_key_owners: {
+            description: 'Get the MSA id and number of public keys of the MSA each key belongs to',
+            params: [
+              {
+                name: 'keys',
+                type: 'Vec<AccountId>',
+              },
+            ],
+            type: 'Vec<Option<KeyOwnerResponse>>',
+          },
+          get_msa_redirect: {
+            description: 'Get the MSA id a merged MSA was merged into',
+            params: [
+              {
+                name: 'msa_id',
+                type: 'MessageSourceId',
+              },
+            ],
+            type: 'Option<MessageSourceId>',
+          },
         },
-        version: 2,
+        version: 7,
       },
     ],
   },
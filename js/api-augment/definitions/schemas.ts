@@ -8,7 +8,7 @@ export default {
           type: 'SchemaId',
         },
       ],
-      type: 'Option<SchemaResponse>',
+      type: 'Option<SchemaResponseWithWarning>',
     },
     checkSchemaValidity: {
       description: '',
@@ -22,6 +22,11 @@ export default {
           type: 'BlockHash',
           isOptional: true,
         },
+        {
+          name: 'model_type',
+          type: 'ModelType',
+          isOptional: true,
+        },
       ],
       type: 'bool',
     },
@@ -33,7 +38,7 @@ export default {
           type: 'String',
         },
       ],
-      type: 'Option<Vec<SchemaVersionResponse>>',
+      type: 'Option<Vec<SchemaVersionResponseWithWarning>>',
     },
   },
   types: {
@@ -46,9 +51,26 @@ export default {
       model_type: 'ModelType',
       payload_location: 'PayloadLocation',
       settings: 'Vec<SchemaSetting>',
+      status: 'SchemaStatus',
+    },
+    SchemaResponseWithWarning: {
+      schema_id: 'SchemaId',
+      model: 'SchemaModel',
+      model_type: 'ModelType',
+      payload_location: 'PayloadLocation',
+      settings: 'Vec<SchemaSetting>',
+      status: 'SchemaStatus',
+      warning: 'Option<Text>',
+    },
+    SchemaResponseV2: {
+      schema_id: 'SchemaId',
+      model: 'SchemaModel',
+      model_type: 'ModelType',
+      payload_location: 'PayloadLocation',
+      settings: 'Vec<SchemaSetting>',
     },
     ModelType: {
-      _enum: ['AvroBinary', 'Parquet'],
+      _enum: ['AvroBinary', 'Parquet', 'JsonSchema', 'Protobuf'],
     },
     PayloadLocation: {
       _enum: ['OnChain', 'IPFS', 'Itemized', 'Paginated'],
@@ -56,10 +78,26 @@ export default {
     SchemaSetting: {
       _enum: ['AppendOnly', 'SignatureRequired'],
     },
+    SchemaStatus: {
+      _enum: ['Active', 'Deprecated', 'Retired'],
+    },
     SchemaVersionResponse: {
       schema_name: 'String',
       schema_version: 'SchemaVersion',
       schema_id: 'SchemaId',
+      status: 'SchemaStatus',
+    },
+    SchemaVersionResponseWithWarning: {
+      schema_name: 'String',
+      schema_version: 'SchemaVersion',
+      schema_id: 'SchemaId',
+      status: 'SchemaStatus',
+      warning: 'Option<Text>',
+    },
+    SchemaVersionResponseV2: {
+      schema_name: 'String',
+      schema_version: 'SchemaVersion',
+      schema_id: 'SchemaId',
     },
   },
   runtime: {
@@ -87,6 +125,31 @@ export default {
             type: 'Option<Vec<SchemaVersionResponse>>',
           },
         },
+        version: 3,
+      },
+      {
+        methods: {
+          get_schema_by_id: {
+            description: 'Fetch the schema by id',
+            params: [
+              {
+                name: 'schema_id',
+                type: 'SchemaId',
+              },
+            ],
+            type: 'Option<SchemaResponseV2>',
+          },
+          get_schema_versions_by_name: {
+            description: 'Fetch the schema versions by name',
+            params: [
+              {
+                name: 'schema_name',
+                type: 'Vec<u8>',
+              },
+            ],
+            type: 'Option<Vec<SchemaVersionResponseV2>>',
+          },
+        },
         version: 2,
       },
     ],
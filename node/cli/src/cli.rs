@@ -2,7 +2,7 @@
 
 // File originally from https://github.com/paritytech/cumulus/blob/master/parachain-template/node/src/cli.rs
 
-use crate::{ExportMetadataCmd, ExportRuntimeVersionCmd};
+use crate::{ExportMetadataCmd, ExportRuntimeVersionCmd, MsaOffchainIndexCmd};
 use std::path::PathBuf;
 
 #[cfg(feature = "recurrency-no-relay")]
@@ -35,6 +35,9 @@ pub enum Subcommand {
 	/// Export metadata.
 	ExportMetadata(ExportMetadataCmd),
 
+	/// Verify or rebuild the offchain index of MSA keys.
+	MsaOffchainIndex(MsaOffchainIndexCmd),
+
 	/// Revert the chain to a previous state.
 	Revert(sc_cli::RevertCmd),
 
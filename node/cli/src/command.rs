@@ -295,6 +295,11 @@ pub fn run() -> Result<()> {
 		Some(Subcommand::ExportMetadata(cmd)) => {
 			construct_async_run!(|components, cli, cmd, config| Ok(cmd.run(components.client)))
 		},
+		Some(Subcommand::MsaOffchainIndex(cmd)) => {
+			construct_async_run!(|components, cli, cmd, config| {
+				Ok(cmd.run(components.client, components.backend))
+			})
+		},
 		Some(Subcommand::PurgeChain(cmd)) => {
 			let runner = cli.create_runner(cmd)?;
 
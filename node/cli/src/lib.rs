@@ -13,6 +13,9 @@ mod command;
 #[cfg(feature = "cli")]
 mod export_metadata_cmd;
 
+#[cfg(feature = "cli")]
+mod msa_offchain_index_cmd;
+
 #[cfg(feature = "cli")]
 mod runtime_version_cmd;
 
@@ -28,6 +31,9 @@ pub use command::*;
 #[cfg(feature = "cli")]
 pub use export_metadata_cmd::*;
 
+#[cfg(feature = "cli")]
+pub use msa_offchain_index_cmd::*;
+
 #[cfg(feature = "cli")]
 pub use runtime_version_cmd::*;
 
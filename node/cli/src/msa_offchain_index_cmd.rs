@@ -0,0 +1,58 @@
+use clap::Parser;
+use common_primitives::node::{AccountId, BlockNumber};
+use pallet_msa_rpc::offchain_index::{check_offchain_index, rebuild_offchain_index};
+use sc_cli::{CliConfiguration, Error, SharedParams};
+use sc_client_api::{Backend, HeaderBackend, StorageProvider};
+use serde_json::to_writer_pretty;
+use sp_runtime::traits::{Block as BlockT, NumberFor};
+use std::{io, sync::Arc};
+
+/// The `msa-offchain-index` command used to verify the offchain index of MSA keys against the
+/// chain state, and to rebuild it without resyncing the chain.
+/// Remember that this opens the node database, so the node must not be running.
+#[derive(Debug, Clone, Parser)]
+pub struct MsaOffchainIndexCmd {
+	/// Block whose state the index is compared with.
+	///
+	/// Default is the last block indexed by the offchain worker.
+	#[clap(long, value_name = "BLOCK", conflicts_with = "rebuild")]
+	pub block: Option<BlockNumber>,
+
+	/// Rewrite the keys of every MSA whose index differs from the chain state
+	/// at the last block indexed by the offchain worker.
+	#[clap(long)]
+	pub rebuild: bool,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+}
+
+impl MsaOffchainIndexCmd {
+	/// Run the msa-offchain-index command and print the report of mismatched MSAs
+	pub async fn run<B, BE, C>(&self, client: Arc<C>, backend: Arc<BE>) -> Result<(), Error>
+	where
+		B: BlockT,
+		BE: Backend<B>,
+		C: StorageProvider<B, BE> + HeaderBackend<B>,
+		NumberFor<B>: From<BlockNumber>,
+	{
+		let mut offchain = backend
+			.offchain_storage()
+			.ok_or_else(|| Error::from("Offchain storage is not available"))?;
+
+		let report = match self.rebuild {
+			true => rebuild_offchain_index::<B, BE, C, _, AccountId>(&client, &mut offchain),
+			false => check_offchain_index::<B, BE, C, _, AccountId>(&client, &offchain, self.block),
+		}
+		.map_err(|e| Error::from(format!("{:?}", e)))?;
+
+		to_writer_pretty(io::stdout(), &report).map_err(|_| Error::from("Failed Encoding"))
+	}
+}
+
+impl CliConfiguration for MsaOffchainIndexCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+}
@@ -59,6 +59,7 @@ where
 	C::Api: pallet_msa_runtime_api::MsaRuntimeApi<Block, AccountId>,
 	C::Api: pallet_stateful_storage_runtime_api::StatefulStorageRuntimeApi<Block>,
 	C::Api: pallet_handles_runtime_api::HandlesRuntimeApi<Block>,
+	C::Api: pallet_capacity_runtime_api::CapacityRuntimeApi<Block, AccountId, Balance>,
 	OffchainDB: sp_core::offchain::OffchainStorage + 'static,
 	P: TransactionPool + Sync + Send + 'static,
 {
@@ -68,9 +69,12 @@ where
 	// Recurrency RPCs
 	use recurrency_rpc::{RecurrencyRpcApiServer, RecurrencyRpcHandler};
 	use pallet_recurrency_tx_payment_rpc::{CapacityPaymentApiServer, CapacityPaymentHandler};
+	use pallet_capacity_rpc::{CapacityApiServer, CapacityHandler};
 	use pallet_handles_rpc::{HandlesApiServer, HandlesHandler};
 	use pallet_messages_rpc::{MessagesApiServer, MessagesHandler};
-	use pallet_msa_rpc::{MsaApiServer, MsaHandler};
+	use pallet_msa_rpc::{
+		MsaApiServer, MsaHandler, MsaOffchainIndexApiServer, MsaOffchainIndexHandler,
+	};
 	use pallet_schemas_rpc::{SchemasApiServer, SchemasHandler};
 	use pallet_stateful_storage_rpc::{StatefulStorageApiServer, StatefulStorageHandler};
 
@@ -81,10 +85,18 @@ where
 	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
 	module.merge(MessagesHandler::new(client.clone()).into_rpc())?;
 	module.merge(SchemasHandler::new(client.clone()).into_rpc())?;
+	if let DenyUnsafe::No = deny_unsafe {
+		let handler = MsaOffchainIndexHandler::<_, DbBackend<Block>, Block, _>::new(
+			client.clone(),
+			offchain.clone(),
+		);
+		module.merge(MsaOffchainIndexApiServer::<AccountId>::into_rpc(handler))?;
+	}
 	module.merge(MsaHandler::new(client.clone(), offchain).into_rpc())?;
 	module.merge(StatefulStorageHandler::new(client.clone()).into_rpc())?;
 	module.merge(HandlesHandler::new(client.clone()).into_rpc())?;
 	module.merge(CapacityPaymentHandler::new(client.clone()).into_rpc())?;
+	module.merge(CapacityHandler::new(client.clone()).into_rpc())?;
 	module.merge(RecurrencyRpcHandler::new(client, pool).into_rpc())?;
 	if let Some(command_sink) = command_sink {
 		module.merge(
@@ -37,6 +37,30 @@ pub fn set_up_epoch<T: Config>(current_block: BlockNumberFor<T>, current_epoch:
 	CurrentEpochInfo::<T>::set(EpochInfo { epoch_start });
 }
 
+/// Sets up a Provider Boost reward pool for each of the past `ProviderBoostHistoryLimit` eras
+/// and makes `current_era` the current Reward Era.
+pub fn set_up_reward_pool_history<T: Config>(
+	current_era: T::RewardEra,
+	total_staked: BalanceOf<T>,
+) {
+	let history_limit: u32 = T::ProviderBoostHistoryLimit::get();
+	let pool_size: BalanceOf<T> = T::RewardPoolPerEra::get();
+	let oldest_era = current_era.saturating_sub(history_limit.into());
+	let mut era = oldest_era;
+	while era < current_era {
+		ProviderBoostRewardPools::<T>::insert(
+			era,
+			RewardPoolInfo {
+				total_staked_token: total_staked,
+				total_reward_pool: pool_size,
+				unclaimed_balance: pool_size,
+			},
+		);
+		era = era.saturating_add(One::one());
+	}
+	CurrentEraInfo::<T>::set(RewardEraInfo { era_index: current_era, started_at: 0u32.into() });
+}
+
 benchmarks! {
 	stake {
 		let caller: T::AccountId = create_funded_account::<T>("account", SEED, 105u32);
@@ -81,6 +105,7 @@ benchmarks! {
 		assert_eq!(current_epoch.saturating_add(1u32.into()), CurrentEpoch::<T>::get());
 		assert_eq!(current_block, CurrentEpochInfo::<T>::get().epoch_start);
 	}
+	// Provider Boost accounts are the worst case for unstaking, since their history must be checked and updated.
 	unstake {
 		let caller: T::AccountId = create_funded_account::<T>("account", SEED, 5u32);
 		let staking_amount: BalanceOf<T> = T::MinimumStakingAmount::get().saturating_add(20u32.into());
@@ -93,6 +118,7 @@ benchmarks! {
 		let mut target_details = StakingTargetDetails::<BalanceOf<T>>::default();
 		let mut capacity_details = CapacityDetails::<BalanceOf<T>, <T as Config>::EpochNumber>::default();
 
+		staking_account.staking_type = StakingType::ProviderBoost;
 		staking_account.deposit(staking_amount);
 		target_details.deposit(staking_amount, capacity_amount);
 		capacity_details.deposit(&staking_amount, &capacity_amount);
@@ -101,6 +127,18 @@ benchmarks! {
 		Capacity::<T>::set_target_details_for(&caller.clone(), target, target_details);
 		Capacity::<T>::set_capacity_for(target, capacity_details);
 
+		let history_limit: u32 = T::ProviderBoostHistoryLimit::get();
+		let current_era: T::RewardEra = (history_limit + 1u32).into();
+		set_up_reward_pool_history::<T>(current_era, staking_amount);
+
+		// Nothing was boosted in the earlier eras, so every reward pool is checked for unclaimed rewards
+		let mut history = ProviderBoostHistory::<T>::new();
+		history.add_era_balance(&Zero::zero(), &Zero::zero());
+		history.add_era_balance(&current_era, &staking_amount);
+		ProviderBoostHistories::<T>::insert(&caller, history);
+		CurrentEraProviderBoostTotal::<T>::set(staking_amount);
+		CurrentEraEligibleProviderBoostTotal::<T>::set(staking_amount);
+
 		// fill up unlock chunks to max bound - 1
 		let count = T::MaxUnlockingChunks::get()-1;
 		let mut unlocking: UnlockChunkList<T> = BoundedVec::default();
@@ -116,6 +154,53 @@ benchmarks! {
 		assert_last_event::<T>(Event::<T>::UnStaked {account: caller, target: target, amount: unstaking_amount.into(), capacity: Capacity::<T>::calculate_capacity_reduction(unstaking_amount.into(), staking_amount, capacity_amount) }.into());
 	}
 
+	// The target is not a registered Provider, so it is not a valid staking target.
+	unstake_from_retired_provider {
+		let caller: T::AccountId = create_funded_account::<T>("caller", SEED, 5u32);
+		let staker: T::AccountId = create_funded_account::<T>("account", SEED, 5u32);
+		let staking_amount: BalanceOf<T> = T::MinimumStakingAmount::get().saturating_add(20u32.into());
+		let capacity_amount: BalanceOf<T> = Capacity::<T>::capacity_generated(staking_amount);
+		let target = 1;
+
+		let mut staking_account = StakingDetails::<T>::default();
+		let mut target_details = StakingTargetDetails::<BalanceOf<T>>::default();
+		let mut capacity_details = CapacityDetails::<BalanceOf<T>, <T as Config>::EpochNumber>::default();
+
+		staking_account.staking_type = StakingType::ProviderBoost;
+		staking_account.deposit(staking_amount);
+		target_details.deposit(staking_amount, capacity_amount);
+		capacity_details.deposit(&staking_amount, &capacity_amount);
+
+		Capacity::<T>::set_staking_account_and_lock(&staker.clone(), &staking_account).expect("Failed to set staking account");
+		Capacity::<T>::set_target_details_for(&staker.clone(), target, target_details);
+		Capacity::<T>::set_capacity_for(target, capacity_details);
+
+		let history_limit: u32 = T::ProviderBoostHistoryLimit::get();
+		let current_era: T::RewardEra = (history_limit + 1u32).into();
+		set_up_reward_pool_history::<T>(current_era, staking_amount);
+
+		// Nothing was boosted in the earlier eras, so every reward pool is checked for unclaimed rewards
+		let mut history = ProviderBoostHistory::<T>::new();
+		history.add_era_balance(&Zero::zero(), &Zero::zero());
+		history.add_era_balance(&current_era, &staking_amount);
+		ProviderBoostHistories::<T>::insert(&staker, history);
+		CurrentEraProviderBoostTotal::<T>::set(staking_amount);
+		CurrentEraEligibleProviderBoostTotal::<T>::set(staking_amount);
+
+		// fill up unlock chunks to max bound - 1
+		let count = T::MaxUnlockingChunks::get()-1;
+		let mut unlocking: UnlockChunkList<T> = BoundedVec::default();
+		for _i in 0..count {
+			let unlock_chunk: UnlockChunk<BalanceOf<T>, T::EpochNumber> = UnlockChunk { value: 1u32.into(), thaw_at: 3u32.into() };
+			assert_ok!(unlocking.try_push(unlock_chunk));
+		}
+		UnstakeUnlocks::<T>::set(&staker, Some(unlocking));
+
+	}: _ (RawOrigin::Signed(caller), staker.clone(), target)
+	verify {
+		assert_last_event::<T>(Event::<T>::UnStaked {account: staker, target: target, amount: staking_amount, capacity: capacity_amount }.into());
+	}
+
 	set_epoch_length {
 		let epoch_length: BlockNumberFor<T> = 9u32.into();
 	}: _ (RawOrigin::Root, epoch_length)
@@ -124,6 +209,82 @@ benchmarks! {
 		assert_last_event::<T>(Event::<T>::EpochLengthUpdated {blocks: epoch_length}.into());
 	}
 
+	// Worst case is a history with room for only the entry of the current era.
+	provider_boost {
+		let caller: T::AccountId = create_funded_account::<T>("boostaccount", SEED, 260u32);
+		let amount: BalanceOf<T> = T::MinimumStakingAmount::get();
+		let capacity: BalanceOf<T> = Capacity::<T>::capacity_generated(T::RewardsProvider::capacity_boost(amount));
+		let target = 1;
+		let history_limit: u32 = T::ProviderBoostHistoryLimit::get();
+		let current_era: T::RewardEra = history_limit.into();
+
+		register_provider::<T>(target, "Foo");
+		CurrentEraInfo::<T>::set(RewardEraInfo { era_index: current_era, started_at: 0u32.into() });
+		let mut history = ProviderBoostHistory::<T>::new();
+		let mut era: T::RewardEra = One::one();
+		while era < current_era {
+			history.add_era_balance(&era, &One::one());
+			era = era.saturating_add(One::one());
+		}
+		ProviderBoostHistories::<T>::insert(&caller, history);
+
+	}: _ (RawOrigin::Signed(caller.clone()), target, amount)
+	verify {
+		assert!(StakingAccountLedger::<T>::contains_key(&caller));
+		assert!(ProviderBoostHistories::<T>::contains_key(&caller));
+		assert_last_event::<T>(Event::<T>::ProviderBoosted {account: caller, amount, target, capacity}.into());
+	}
+
+	// Worst case is claiming rewards for every era in the reward pool history.
+	claim_staking_rewards {
+		let caller: T::AccountId = create_funded_account::<T>("account", SEED, 5u32);
+		let boost_amount: BalanceOf<T> = T::MinimumStakingAmount::get();
+		let history_limit: u32 = T::ProviderBoostHistoryLimit::get();
+		let current_era: T::RewardEra = (history_limit + 1u32).into();
+
+		set_up_reward_pool_history::<T>(current_era, boost_amount);
+		let mut history = ProviderBoostHistory::<T>::new();
+		history.add_era_balance(&Zero::zero(), &boost_amount);
+		ProviderBoostHistories::<T>::insert(&caller, history);
+
+		let expected_reward: BalanceOf<T> = Capacity::<T>::list_unclaimed_rewards(&caller)
+			.iter()
+			.fold(Zero::zero(), |acc: BalanceOf<T>, reward| acc.saturating_add(reward.earned_amount));
+	}: _ (RawOrigin::Signed(caller.clone()))
+	verify {
+		assert_last_event::<T>(Event::<T>::ProviderBoostRewardClaimed {account: caller, reward_amount: expected_reward}.into());
+	}
+
+	start_new_reward_era_if_needed {
+		let current_block: BlockNumberFor<T> = 1_209_600u32.into();
+		let history_limit: u32 = T::ProviderBoostHistoryLimit::get();
+		let current_era: T::RewardEra = (history_limit + 1u32).into();
+		set_up_reward_pool_history::<T>(current_era, T::MinimumStakingAmount::get());
+		CurrentEraProviderBoostTotal::<T>::set(T::MinimumStakingAmount::get());
+		CurrentEraEligibleProviderBoostTotal::<T>::set(T::MinimumStakingAmount::get());
+	}: {
+		Capacity::<T>::start_new_reward_era_if_needed(current_block);
+	} verify {
+		assert_eq!(current_era.saturating_add(One::one()), CurrentEraInfo::<T>::get().era_index);
+		assert_eq!(history_limit, ProviderBoostRewardPools::<T>::count());
+	}
+
+	// Worst case is when both Providers already have stake from the caller.
+	change_staking_target {
+		let caller: T::AccountId = create_funded_account::<T>("account", SEED, 105u32);
+		let amount: BalanceOf<T> = T::MinimumStakingAmount::get();
+		let from: MessageSourceId = 1;
+		let to: MessageSourceId = 2;
+		register_provider::<T>(from, "Foo");
+		register_provider::<T>(to, "Bar");
+		assert_ok!(Capacity::<T>::stake(RawOrigin::Signed(caller.clone()).into(), from, amount.saturating_add(amount)));
+		assert_ok!(Capacity::<T>::stake(RawOrigin::Signed(caller.clone()).into(), to, amount));
+	}: _ (RawOrigin::Signed(caller.clone()), from, to, amount)
+	verify {
+		assert_eq!(amount.saturating_add(amount), StakingTargetLedger::<T>::get(&caller, to).unwrap_or_default().amount);
+		assert_last_event::<T>(Event::<T>::StakingTargetChanged {account: caller, from_msa: from, to_msa: to, amount}.into());
+	}
+
 	impl_benchmark_test_suite!(Capacity,
 		crate::tests::mock::new_test_ext(),
 		crate::tests::mock::Test);
@@ -15,6 +15,14 @@
 //! Thus, the first use of Capacity in an Epoch will update the last Epoch number to match the current Epoch.
 //! If a provider does not use any Capacity in an Epoch, the provider's capacity balance information is never updated for that Epoch.
 //!
+//! ## Provider Boosting
+//!
+//! A Provider Boost stake generates a reduced amount of Capacity for the target Provider,
+//! and in exchange earns the staker a token reward for every complete Reward Era the amount was boosted.
+//! Reward Eras are much longer than Epochs and are tracked separately in [`CurrentEraInfo`].
+//! At the end of each Reward Era the reward pool for that era is recorded in [`ProviderBoostRewardPools`],
+//! and rewards are minted only when the staker calls `claim_staking_rewards`.
+//!
 // Substrate macros are tripping the clippy::expect_used lint.
 #![allow(clippy::expect_used)]
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -36,13 +44,16 @@ use frame_support::{
 };
 
 use sp_runtime::{
-	traits::{CheckedAdd, Saturating, Zero},
-	ArithmeticError, DispatchError, Perbill,
+	traits::{CheckedAdd, One, Saturating, UniqueSaturatedInto, Zero},
+	ArithmeticError, DispatchError, Perbill, Permill,
 };
-use sp_std::ops::Mul;
+use sp_std::{ops::Mul, vec::Vec};
 
 pub use common_primitives::{
-	capacity::{Nontransferable, Replenishable, TargetValidator},
+	capacity::{
+		CapacityDetailsResponse, Nontransferable, Replenishable, StakingTargetResponse,
+		TargetValidator, UnlockChunkResponse,
+	},
 	msa::MessageSourceId,
 	utils::wrap_binary_data,
 };
@@ -64,6 +75,9 @@ mod tests;
 /// storage migrations
 pub mod migration;
 pub mod weights;
+/// The percentage of a Provider Boost amount that is used to generate Capacity for the target.
+pub const STAKED_PERCENTAGE_TO_BOOST: u32 = 50;
+
 type BalanceOf<T> =
 	<<T as Config>::Currency as InspectFungible<<T as frame_system::Config>::AccountId>>::Balance;
 
@@ -88,7 +102,7 @@ pub mod pallet {
 	}
 
 	/// the storage version for this pallet
-	pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+	pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
@@ -151,6 +165,44 @@ pub mod pallet {
 		/// How much FRQCY one unit of Capacity costs
 		#[pallet::constant]
 		type CapacityPerToken: Get<Perbill>;
+
+		/// A period of `EraLength` blocks in which a Staking Pool applies and
+		/// when Provider Boost Rewards may be earned.
+		type RewardEra: Parameter
+			+ Member
+			+ MaybeSerializeDeserialize
+			+ MaybeDisplay
+			+ AtLeast32BitUnsigned
+			+ Default
+			+ Copy
+			+ sp_std::hash::Hash
+			+ MaxEncodedLen
+			+ TypeInfo;
+
+		/// The number of blocks in a Reward Era.
+		#[pallet::constant]
+		type EraLength: Get<u32>;
+
+		/// The number of past Reward Eras for which reward pool history is kept,
+		/// which is also the number of eras a reward may be claimed for before it expires.
+		#[pallet::constant]
+		type ProviderBoostHistoryLimit: Get<u32>;
+
+		/// The trait providing the Provider Boost economic model calculations and values.
+		type RewardsProvider: ProviderBoostRewardsProvider<Self, Balance = BalanceOf<Self>>;
+
+		/// The amount of token available for Provider Boost rewards each Reward Era.
+		#[pallet::constant]
+		type RewardPoolPerEra: Get<BalanceOf<Self>>;
+
+		/// The maximum percentage of a boosted amount that can be rewarded for a single Reward Era.
+		#[pallet::constant]
+		type RewardPercentCap: Get<Permill>;
+
+		/// The maximum number of times a staking account may change its staking target in a
+		/// single Reward Era.
+		#[pallet::constant]
+		type MaxRetargetsPerRewardEra: Get<u32>;
 	}
 
 	/// Storage for keeping a ledger of staked token amounts for accounts.
@@ -205,6 +257,42 @@ pub mod pallet {
 	pub type UnstakeUnlocks<T: Config> =
 		StorageMap<_, Twox64Concat, T::AccountId, UnlockChunkList<T>>;
 
+	/// Storage for the current Reward Era info
+	#[pallet::storage]
+	#[pallet::whitelist_storage]
+	pub type CurrentEraInfo<T: Config> =
+		StorageValue<_, RewardEraInfo<T::RewardEra, BlockNumberFor<T>>, ValueQuery>;
+
+	/// The total amount of token currently boosted to all Providers.
+	#[pallet::storage]
+	pub type CurrentEraProviderBoostTotal<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// The total amount of token boosted to all Providers for the whole of the current Reward Era,
+	/// which is the amount that earns rewards for it.
+	#[pallet::storage]
+	pub type CurrentEraEligibleProviderBoostTotal<T: Config> =
+		StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// Reward Pool history, limited to `ProviderBoostHistoryLimit` completed Reward Eras.
+	/// - Keys: RewardEra
+	/// - Value: [`RewardPoolInfo`](types::RewardPoolInfo)
+	#[pallet::storage]
+	pub type ProviderBoostRewardPools<T: Config> =
+		CountedStorageMap<_, Twox64Concat, T::RewardEra, RewardPoolInfo<BalanceOf<T>>>;
+
+	/// The boosted amount history of Provider Boost accounts, used to calculate rewards.
+	/// - Keys: AccountId
+	/// - Value: [`ProviderBoostHistory`](types::ProviderBoostHistory)
+	#[pallet::storage]
+	pub type ProviderBoostHistories<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, ProviderBoostHistory<T>>;
+
+	/// How many times an account has changed its staking target in the current Reward Era.
+	/// - Keys: AccountId
+	/// - Value: [`RetargetInfo`](types::RetargetInfo)
+	#[pallet::storage]
+	pub type Retargets<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, RetargetInfo<T>>;
+
 	// Simple declaration of the `Pallet` type. It is placeholder we use to implement traits and
 	// method.
 	#[pallet::pallet]
@@ -255,6 +343,35 @@ pub mod pallet {
 			/// The amount of Capacity withdrawn from MSA.
 			amount: BalanceOf<T>,
 		},
+		/// Tokens have been staked on the Recurrency network for Provider Boosting.
+		ProviderBoosted {
+			/// The token account that staked tokens to the network.
+			account: T::AccountId,
+			/// The MSA that a token account targeted to receive Capacity based on this staking amount.
+			target: MessageSourceId,
+			/// An amount that was staked.
+			amount: BalanceOf<T>,
+			/// The Capacity amount issued to the target as a result of the stake.
+			capacity: BalanceOf<T>,
+		},
+		/// Provider Boost token rewards have been minted and transferred to the staking account.
+		ProviderBoostRewardClaimed {
+			/// The token account claiming and receiving the reward from ProviderBoost staking
+			account: T::AccountId,
+			/// The reward amount
+			reward_amount: BalanceOf<T>,
+		},
+		/// A staking account has moved some of its stake from one Provider to another.
+		StakingTargetChanged {
+			/// The token account that changed its staking target.
+			account: T::AccountId,
+			/// The Provider MSA that the stake was moved from.
+			from_msa: MessageSourceId,
+			/// The Provider MSA that the stake was moved to.
+			to_msa: MessageSourceId,
+			/// The amount of stake that was moved.
+			amount: BalanceOf<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -290,12 +407,32 @@ pub mod pallet {
 		BalanceTooLowtoStake,
 		/// None of the token amounts in UnlockChunks has thawed yet.
 		NoThawedTokenAvailable,
+		/// Staker tried to change StakingType on an existing account
+		CannotChangeStakingType,
+		/// The account's Provider Boost history is full; rewards must be claimed before boosting again.
+		MaxRewardHistoryExceeded,
+		/// Rewards must be claimed before a Provider Boost account can unstake.
+		MustFirstClaimRewards,
+		/// This AccountId does not have a Provider Boost history.
+		NotAProviderBoostAccount,
+		/// There are no unclaimed rewards for this account.
+		NoRewardsEligibleToClaim,
+		/// The account has already changed its staking target the maximum number of times
+		/// in this Reward Era.
+		MaxRetargetsExceeded,
+		/// Attempted to change the staking target to the current target.
+		CannotRetargetToSameProvider,
+		/// Attempted to move more stake than is staked to the Provider.
+		InsufficientStakingBalance,
+		/// Attempted to force an unstake from a Provider that is still a valid staking target.
+		TargetNotRetired,
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn on_initialize(current: BlockNumberFor<T>) -> Weight {
 			Self::start_new_epoch_if_needed(current)
+				.saturating_add(Self::start_new_reward_era_if_needed(current))
 		}
 	}
 
@@ -318,7 +455,7 @@ pub mod pallet {
 			let staker = ensure_signed(origin)?;
 
 			let (mut staking_account, actual_amount) =
-				Self::ensure_can_stake(&staker, target, amount)?;
+				Self::ensure_can_stake(&staker, target, amount, StakingType::MaximumCapacity)?;
 
 			let capacity = Self::increase_stake_and_issue_capacity(
 				&staker,
@@ -364,8 +501,16 @@ pub mod pallet {
 		/// - Returns `Error::InvalidTarget` if `target` is not a valid staking target (not a Provider)
 		/// - Returns `Error:: NotAStakingAccount` if `origin` has nothing staked at all
 		/// - Returns `Error::StakerTargetRelationshipNotFound` if `origin` has nothing staked to `target`
+		/// - Returns `Error::MustFirstClaimRewards` if `origin` is a Provider Boost account with unclaimed rewards
 		#[pallet::call_index(2)]
-		#[pallet::weight(T::WeightInfo::unstake())]
+		// Checking every Reward Pool for unclaimed rewards and updating the staker's Provider Boost
+		// history and the Provider Boost totals are not covered by the generated weight
+		#[pallet::weight(
+			T::WeightInfo::unstake()
+				.saturating_add(T::DbWeight::get().reads_writes(3, 3))
+				.saturating_add(Pallet::<T>::reward_pool_reads_weight())
+				.saturating_add(Weight::from_parts(0, 3_627))
+		)]
 		pub fn unstake(
 			origin: OriginFor<T>,
 			target: MessageSourceId,
@@ -375,10 +520,16 @@ pub mod pallet {
 
 			ensure!(requested_amount > Zero::zero(), Error::<T>::UnstakedAmountIsZero);
 
-			let actual_amount = Self::decrease_active_staking_balance(&unstaker, requested_amount)?;
+			let (actual_amount, staking_type) =
+				Self::decrease_active_staking_balance(&unstaker, requested_amount)?;
 			Self::add_unlock_chunk(&unstaker, actual_amount)?;
 
-			let capacity_reduction = Self::reduce_capacity(&unstaker, target, actual_amount)?;
+			let capacity_reduction =
+				Self::reduce_capacity(&unstaker, target, actual_amount, staking_type)?;
+
+			if staking_type == StakingType::ProviderBoost {
+				Self::reduce_provider_boost(&unstaker, actual_amount)?;
+			}
 
 			Self::deposit_event(Event::UnStaked {
 				account: unstaker,
@@ -407,27 +558,206 @@ pub mod pallet {
 			Self::deposit_event(Event::EpochLengthUpdated { blocks: length });
 			Ok(())
 		}
+
+		/// Stakes some amount of tokens to the network and generates a comparatively small amount of Capacity
+		/// for the target, and gives periodic rewards to origin.
+		/// ### Errors
+		///
+		/// - Error::ZeroAmountNotAllowed if the staker is attempting to stake a zero amount.
+		/// - Error::InvalidTarget if attempting to stake to an invalid target.
+		/// - Error::InsufficientStakingAmount if attempting to stake an amount below the minimum amount.
+		/// - Error::CannotChangeStakingType if the staking account exists and staking_type is MaximumCapacity
+		/// - Error::MaxRewardHistoryExceeded if the staking account's Provider Boost history is full
+		#[pallet::call_index(4)]
+		// Updating the eligible Provider Boost total and decoding a full Provider Boost history are
+		// not covered by the generated weight
+		#[pallet::weight(
+			T::WeightInfo::provider_boost()
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				.saturating_add(Weight::from_parts(5_000_000, 0))
+		)]
+		pub fn provider_boost(
+			origin: OriginFor<T>,
+			target: MessageSourceId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let staker = ensure_signed(origin)?;
+			let (mut boosting_details, actual_amount) =
+				Self::ensure_can_stake(&staker, target, amount, StakingType::ProviderBoost)?;
+
+			let capacity = Self::increase_stake_and_issue_capacity(
+				&staker,
+				&mut boosting_details,
+				target,
+				actual_amount,
+			)?;
+
+			Self::deposit_event(Event::ProviderBoosted {
+				account: staker,
+				amount: actual_amount,
+				target,
+				capacity,
+			});
+
+			Ok(())
+		}
+
+		/// Claim all outstanding Provider Boost rewards, up to `ProviderBoostHistoryLimit` Reward Eras
+		/// in the past.  Accounts should check for unclaimed rewards before calling this extrinsic
+		/// to avoid needless transaction fees.
+		/// ### Errors
+		///
+		/// - Error::NotAProviderBoostAccount if the origin has no Provider Boost history
+		/// - Error::NoRewardsEligibleToClaim if there are no rewards to claim
+		#[pallet::call_index(5)]
+		// Updating the last Reward Pool and the total issuance are not covered by the generated weight
+		#[pallet::weight(
+			T::WeightInfo::claim_staking_rewards()
+				.saturating_add(T::DbWeight::get().reads_writes(1, 2))
+				.saturating_add(Weight::from_parts(0, 511))
+		)]
+		pub fn claim_staking_rewards(origin: OriginFor<T>) -> DispatchResult {
+			let staker = ensure_signed(origin)?;
+			ensure!(
+				ProviderBoostHistories::<T>::contains_key(&staker),
+				Error::<T>::NotAProviderBoostAccount
+			);
+			let reward_amount = Self::do_claim_rewards(&staker)?;
+
+			Self::deposit_event(Event::ProviderBoostRewardClaimed {
+				account: staker,
+				reward_amount,
+			});
+			Ok(())
+		}
+
+		/// Moves `amount` of stake from the Provider `from` to the Provider `to`, along with the
+		/// Capacity it generates, without going through the thaw period.
+		/// An account may do this at most `MaxRetargetsPerRewardEra` times per Reward Era.
+		/// ### Errors
+		///
+		/// - Error::MaxRetargetsExceeded if the origin has already retargeted the maximum number of times this Reward Era
+		/// - Error::CannotRetargetToSameProvider if `from` and `to` are the same
+		/// - Error::InsufficientStakingAmount if `amount` is below the minimum staking amount
+		/// - Error::InvalidTarget if `to` is not a valid staking target (not a Provider)
+		/// - Error::NotAStakingAccount if the origin has nothing staked at all
+		/// - Error::StakerTargetRelationshipNotFound if the origin has nothing staked to `from`
+		/// - Error::InsufficientStakingBalance if `amount` exceeds the amount staked to `from`, or would leave
+		///   a non-zero stake to `from` below the minimum staking amount
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::change_staking_target())]
+		pub fn change_staking_target(
+			origin: OriginFor<T>,
+			from: MessageSourceId,
+			to: MessageSourceId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let staker = ensure_signed(origin)?;
+			let retargets = Self::next_retarget_record(&staker)?;
+			ensure!(from != to, Error::<T>::CannotRetargetToSameProvider);
+			ensure!(
+				amount >= T::MinimumStakingAmount::get(),
+				Error::<T>::InsufficientStakingAmount
+			);
+			ensure!(T::TargetValidator::validate(to), Error::<T>::InvalidTarget);
+
+			Self::do_retarget(&staker, from, to, amount)?;
+			Retargets::<T>::insert(&staker, retargets);
+
+			Self::deposit_event(Event::StakingTargetChanged {
+				account: staker,
+				from_msa: from,
+				to_msa: to,
+				amount,
+			});
+			Ok(())
+		}
+
+		/// Unstakes everything `staker` has staked to `target` once `target` is no longer a valid
+		/// staking target, e.g. because the Provider is retiring or has retired.
+		/// The stake goes through the normal thaw period, and Provider Boost stakes stop accruing
+		/// rewards for the retired Provider. Anyone may submit this on behalf of the staker.
+		/// ### Errors
+		///
+		/// - Error::TargetNotRetired if `target` is still a valid staking target
+		/// - Error::StakerTargetRelationshipNotFound if `staker` has nothing staked to `target`
+		/// - Error::NotAStakingAccount if `staker` has nothing staked at all
+		/// - Error::MaxUnlockingChunksExceeded if `staker` already has the maximum number of unlocking chunks
+		/// - Error::MustFirstClaimRewards if `staker` is a Provider Boost account with unclaimed rewards
+		#[pallet::call_index(7)]
+		// Updating the eligible Provider Boost total is not covered by the generated weight
+		#[pallet::weight(
+			T::WeightInfo::unstake_from_retired_provider().saturating_add(T::DbWeight::get().reads_writes(1, 1))
+		)]
+		pub fn unstake_from_retired_provider(
+			origin: OriginFor<T>,
+			staker: T::AccountId,
+			target: MessageSourceId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			ensure!(!T::TargetValidator::validate(target), Error::<T>::TargetNotRetired);
+
+			let staked_amount = StakingTargetLedger::<T>::get(&staker, target)
+				.ok_or(Error::<T>::StakerTargetRelationshipNotFound)?
+				.amount;
+			ensure!(staked_amount > Zero::zero(), Error::<T>::StakerTargetRelationshipNotFound);
+
+			let (actual_amount, staking_type) =
+				Self::decrease_active_staking_balance(&staker, staked_amount)?;
+			Self::add_unlock_chunk(&staker, actual_amount)?;
+
+			let capacity_reduction =
+				Self::reduce_capacity(&staker, target, actual_amount, staking_type)?;
+
+			if staking_type == StakingType::ProviderBoost {
+				Self::reduce_provider_boost(&staker, actual_amount)?;
+			}
+
+			Self::deposit_event(Event::UnStaked {
+				account: staker,
+				target,
+				amount: actual_amount,
+				capacity: capacity_reduction,
+			});
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// The weight of reading every Reward Pool in the Provider Boost history
+	fn reward_pool_reads_weight() -> Weight {
+		let history_limit: u64 = T::ProviderBoostHistoryLimit::get().into();
+		T::DbWeight::get()
+			.reads(history_limit)
+			.saturating_add(Weight::from_parts(0, 2_527).saturating_mul(history_limit))
+	}
+
 	/// Checks to see if staker has sufficient free-balance to stake the minimum required staking amount,
 	/// and leave the minimum required free balance after staking.
 	///
 	/// # Errors
 	/// * [`Error::ZeroAmountNotAllowed`]
 	/// * [`Error::InvalidTarget`]
+	/// * [`Error::CannotChangeStakingType`]
 	/// * [`Error::BalanceTooLowtoStake`]
 	///
 	fn ensure_can_stake(
 		staker: &T::AccountId,
 		target: MessageSourceId,
 		amount: BalanceOf<T>,
+		staking_type: StakingType,
 	) -> Result<(StakingDetails<T>, BalanceOf<T>), DispatchError> {
 		ensure!(amount > Zero::zero(), Error::<T>::ZeroAmountNotAllowed);
 		ensure!(T::TargetValidator::validate(target), Error::<T>::InvalidTarget);
 
-		let staking_account = StakingAccountLedger::<T>::get(&staker).unwrap_or_default();
+		let mut staking_account = StakingAccountLedger::<T>::get(&staker).unwrap_or_default();
+		ensure!(
+			staking_account.active.is_zero() || staking_account.staking_type == staking_type,
+			Error::<T>::CannotChangeStakingType
+		);
+		staking_account.staking_type = staking_type;
+
 		let stakable_amount = Self::get_stakable_amount_for(&staker, amount);
 
 		ensure!(stakable_amount > Zero::zero(), Error::<T>::BalanceTooLowtoStake);
@@ -446,7 +776,8 @@ impl<T: Config> Pallet<T> {
 	}
 
 	/// Increase a staking account and target account balances by amount.
-	/// Additionally, it issues Capacity to the MSA target.
+	/// Additionally, it issues Capacity to the MSA target, and for Provider Boost accounts,
+	/// records the boosted amount for rewards.
 	fn increase_stake_and_issue_capacity(
 		staker: &T::AccountId,
 		staking_account: &mut StakingDetails<T>,
@@ -455,7 +786,13 @@ impl<T: Config> Pallet<T> {
 	) -> Result<BalanceOf<T>, DispatchError> {
 		staking_account.deposit(amount).ok_or(ArithmeticError::Overflow)?;
 
-		let capacity = Self::capacity_generated(amount);
+		let capacity = match staking_account.staking_type {
+			StakingType::MaximumCapacity => Self::capacity_generated(amount),
+			StakingType::ProviderBoost => {
+				Self::increase_provider_boost(staker, amount)?;
+				Self::capacity_generated(T::RewardsProvider::capacity_boost(amount))
+			},
+		};
 		let mut target_details =
 			StakingTargetLedger::<T>::get(&staker, &target).unwrap_or_default();
 		target_details.deposit(amount, capacity).ok_or(ArithmeticError::Overflow)?;
@@ -516,17 +853,23 @@ impl<T: Config> Pallet<T> {
 	}
 
 	/// Decrease a staking account's active token.
+	/// Returns the actual amount unstaked and the staking type of the account.
 	fn decrease_active_staking_balance(
 		unstaker: &T::AccountId,
 		amount: BalanceOf<T>,
-	) -> Result<BalanceOf<T>, DispatchError> {
+	) -> Result<(BalanceOf<T>, StakingType), DispatchError> {
 		let mut staking_account =
 			StakingAccountLedger::<T>::get(unstaker).ok_or(Error::<T>::NotAStakingAccount)?;
 		ensure!(amount <= staking_account.active, Error::<T>::AmountToUnstakeExceedsAmountStaked);
 
+		let staking_type = staking_account.staking_type;
+		if staking_type == StakingType::ProviderBoost {
+			ensure!(!Self::has_unclaimed_rewards(unstaker), Error::<T>::MustFirstClaimRewards);
+		}
+
 		let actual_unstaked_amount = staking_account.withdraw(amount)?;
 		Self::set_staking_account(unstaker, &staking_account);
-		Ok(actual_unstaked_amount)
+		Ok((actual_unstaked_amount, staking_type))
 	}
 
 	fn add_unlock_chunk(
@@ -588,10 +931,13 @@ impl<T: Config> Pallet<T> {
 	}
 
 	/// Reduce available capacity of target and return the amount of capacity reduction.
+	/// Provider Boost stakes generate less Capacity per token, so their reduction is calculated
+	/// from the staker's own target details rather than the target's totals.
 	fn reduce_capacity(
 		unstaker: &T::AccountId,
 		target: MessageSourceId,
 		amount: BalanceOf<T>,
+		staking_type: StakingType,
 	) -> Result<BalanceOf<T>, DispatchError> {
 		let mut staking_target_details = StakingTargetLedger::<T>::get(&unstaker, &target)
 			.ok_or(Error::<T>::StakerTargetRelationshipNotFound)?;
@@ -600,6 +946,12 @@ impl<T: Config> Pallet<T> {
 
 		let capacity_to_withdraw = if staking_target_details.amount.eq(&amount) {
 			staking_target_details.capacity
+		} else if staking_type == StakingType::ProviderBoost {
+			Self::calculate_capacity_reduction(
+				amount,
+				staking_target_details.amount,
+				staking_target_details.capacity,
+			)
 		} else {
 			Self::calculate_capacity_reduction(
 				amount,
@@ -648,6 +1000,308 @@ impl<T: Config> Pallet<T> {
 			T::DbWeight::get().reads(2u64).saturating_add(T::DbWeight::get().writes(1))
 		}
 	}
+
+	/// Starts a new Reward Era when the current one has lasted `EraLength` blocks.
+	/// The reward pool for the era that just ended is recorded with the amount boosted for the
+	/// whole era, and the oldest reward pool is removed once there are more than
+	/// `ProviderBoostHistoryLimit` of them.
+	fn start_new_reward_era_if_needed(current_block: BlockNumberFor<T>) -> Weight {
+		let current_era_info = CurrentEraInfo::<T>::get(); // 1r
+
+		if current_block.saturating_sub(current_era_info.started_at) >= T::EraLength::get().into() {
+			let total_staked_token = CurrentEraEligibleProviderBoostTotal::<T>::get();
+			// Everything boosted when the new era starts is boosted for the whole of it so far
+			CurrentEraEligibleProviderBoostTotal::<T>::set(CurrentEraProviderBoostTotal::<T>::get());
+			let total_reward_pool = T::RewardsProvider::reward_pool_size(total_staked_token);
+			ProviderBoostRewardPools::<T>::insert(
+				current_era_info.era_index,
+				RewardPoolInfo {
+					total_staked_token,
+					total_reward_pool,
+					unclaimed_balance: total_reward_pool,
+				},
+			);
+
+			let new_era_index = current_era_info.era_index.saturating_add(One::one());
+			if ProviderBoostRewardPools::<T>::count() > T::ProviderBoostHistoryLimit::get() {
+				let expired_era = new_era_index
+					.saturating_sub(T::ProviderBoostHistoryLimit::get().into())
+					.saturating_sub(One::one());
+				ProviderBoostRewardPools::<T>::remove(expired_era);
+			}
+
+			CurrentEraInfo::<T>::set(RewardEraInfo {
+				era_index: new_era_index,
+				started_at: current_block,
+			});
+			// The eligible total is not covered by the generated weight
+			T::WeightInfo::start_new_reward_era_if_needed()
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+		} else {
+			T::DbWeight::get().reads(1)
+		}
+	}
+
+	/// Adds `amount` to the Provider Boost history of `staker` for the current Reward Era,
+	/// and to the total boosted amount. The eligible total only grows by what `staker` had
+	/// unboosted earlier in the era, since an amount boosted mid-era earns no reward for it.
+	fn increase_provider_boost(
+		staker: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> Result<(), DispatchError> {
+		let current_era = CurrentEraInfo::<T>::get().era_index;
+		let mut history = ProviderBoostHistories::<T>::get(staker).unwrap_or_default();
+		let eligible_before = history.get_eligible_amount_for_era(&current_era);
+		history
+			.add_era_balance(&current_era, &amount)
+			.ok_or(Error::<T>::MaxRewardHistoryExceeded)?;
+		let eligible_increase = history
+			.get_eligible_amount_for_era(&current_era)
+			.saturating_sub(eligible_before);
+		ProviderBoostHistories::<T>::insert(staker, history);
+
+		let total = CurrentEraProviderBoostTotal::<T>::get()
+			.checked_add(&amount)
+			.ok_or(ArithmeticError::Overflow)?;
+		CurrentEraProviderBoostTotal::<T>::set(total);
+		CurrentEraEligibleProviderBoostTotal::<T>::mutate(|total| {
+			*total = total.saturating_add(eligible_increase)
+		});
+		Ok(())
+	}
+
+	/// Subtracts `amount` from the Provider Boost history of `unstaker` for the current Reward Era,
+	/// and from the total boosted amount and, as far as it was boosted since the start of the era,
+	/// from the eligible total.
+	/// Since rewards must be claimed before unstaking, the history is removed once nothing is boosted.
+	fn reduce_provider_boost(
+		unstaker: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> Result<(), DispatchError> {
+		let current_era = CurrentEraInfo::<T>::get().era_index;
+		let mut history = ProviderBoostHistories::<T>::get(unstaker)
+			.ok_or(Error::<T>::NotAProviderBoostAccount)?;
+		let eligible_before = history.get_eligible_amount_for_era(&current_era);
+		history
+			.subtract_era_balance(&current_era, &amount)
+			.ok_or(Error::<T>::MaxRewardHistoryExceeded)?;
+		let eligible_decrease =
+			eligible_before.saturating_sub(history.get_eligible_amount_for_era(&current_era));
+		if history.get_amount_staked_for_era(&current_era).is_zero() {
+			ProviderBoostHistories::<T>::remove(unstaker);
+		} else {
+			ProviderBoostHistories::<T>::insert(unstaker, history);
+		}
+
+		CurrentEraProviderBoostTotal::<T>::mutate(|total| *total = total.saturating_sub(amount));
+		CurrentEraEligibleProviderBoostTotal::<T>::mutate(|total| {
+			*total = total.saturating_sub(eligible_decrease)
+		});
+		Ok(())
+	}
+
+	/// Returns the retarget record of `staker` with one more change of staking target
+	/// counted in the current Reward Era.
+	///
+	/// # Errors
+	/// * [`Error::MaxRetargetsExceeded`]
+	///
+	fn next_retarget_record(staker: &T::AccountId) -> Result<RetargetInfo<T>, DispatchError> {
+		let current_era = CurrentEraInfo::<T>::get().era_index;
+		let mut retargets = Retargets::<T>::get(staker).unwrap_or_default();
+		retargets.update(current_era).ok_or(Error::<T>::MaxRetargetsExceeded)?;
+		Ok(retargets)
+	}
+
+	/// Moves `amount` of stake, and the Capacity it generates, from `from` to `to`.
+	/// The staking account's active balance and Provider Boost history are unchanged.
+	pub(crate) fn do_retarget(
+		staker: &T::AccountId,
+		from: MessageSourceId,
+		to: MessageSourceId,
+		amount: BalanceOf<T>,
+	) -> Result<(), DispatchError> {
+		let staking_type = StakingAccountLedger::<T>::get(staker)
+			.ok_or(Error::<T>::NotAStakingAccount)?
+			.staking_type;
+		let from_details = StakingTargetLedger::<T>::get(staker, from)
+			.ok_or(Error::<T>::StakerTargetRelationshipNotFound)?;
+		ensure!(amount <= from_details.amount, Error::<T>::InsufficientStakingBalance);
+		// The stake left behind must either be zero or still meet the minimum.
+		let left_behind = from_details.amount.saturating_sub(amount);
+		ensure!(
+			left_behind.is_zero() || left_behind >= T::MinimumStakingAmount::get(),
+			Error::<T>::InsufficientStakingBalance
+		);
+
+		let current_epoch = CurrentEpoch::<T>::get();
+		let from_capacity =
+			CapacityLedger::<T>::get(from).ok_or(Error::<T>::TargetCapacityNotFound)?;
+		let from_remaining = if from_capacity.can_replenish(current_epoch) {
+			from_capacity.total_capacity_issued
+		} else {
+			from_capacity.remaining_capacity
+		};
+
+		let capacity = Self::reduce_capacity(staker, from, amount, staking_type)?;
+		// Only the unused share of the moved Capacity is spendable at `to` this Epoch;
+		// the share already used at `from` moves along with it.
+		let unused_capacity = Self::calculate_capacity_reduction(
+			from_remaining,
+			from_capacity.total_capacity_issued,
+			capacity,
+		)
+		.min(capacity);
+		let mut from_details_after =
+			CapacityLedger::<T>::get(from).ok_or(Error::<T>::TargetCapacityNotFound)?;
+		from_details_after.remaining_capacity = from_remaining
+			.saturating_sub(unused_capacity)
+			.min(from_details_after.total_capacity_issued);
+		Self::set_capacity_for(from, from_details_after);
+
+		let mut to_details = StakingTargetLedger::<T>::get(staker, to).unwrap_or_default();
+		to_details.deposit(amount, capacity).ok_or(ArithmeticError::Overflow)?;
+		let mut capacity_details = CapacityLedger::<T>::get(to).unwrap_or_default();
+		if capacity_details.can_replenish(current_epoch) {
+			capacity_details.replenish_all(&current_epoch);
+		}
+		capacity_details.deposit(&amount, &capacity).ok_or(ArithmeticError::Overflow)?;
+		capacity_details.deduct_capacity_by_amount(capacity.saturating_sub(unused_capacity))?;
+
+		Self::set_target_details_for(staker, to, to_details);
+		Self::set_capacity_for(to, capacity_details);
+		Ok(())
+	}
+
+	/// Returns whether `account` has any unclaimed Provider Boost rewards.
+	pub fn has_unclaimed_rewards(account: &T::AccountId) -> bool {
+		!Self::list_unclaimed_rewards(account).is_empty()
+	}
+
+	/// Returns the unclaimed Provider Boost rewards for `account`, oldest first.
+	/// Only completed Reward Eras whose reward pool is still in history are included.
+	pub fn list_unclaimed_rewards(
+		account: &T::AccountId,
+	) -> Vec<UnclaimedRewardInfo<BalanceOf<T>, T::RewardEra, BlockNumberFor<T>>> {
+		let mut unclaimed_rewards = Vec::new();
+		let history = match ProviderBoostHistories::<T>::get(account) {
+			Some(history) => history,
+			None => return unclaimed_rewards,
+		};
+		let earliest_era = match history.earliest_era() {
+			Some(era) => *era,
+			None => return unclaimed_rewards,
+		};
+
+		let current_era_info = CurrentEraInfo::<T>::get();
+		let history_limit: T::RewardEra = T::ProviderBoostHistoryLimit::get().into();
+		let era_length: BlockNumberFor<T> = T::EraLength::get().into();
+		let oldest_available_era = current_era_info.era_index.saturating_sub(history_limit);
+		// An amount must be boosted for an entire era to earn a reward for it.
+		let mut reward_era = earliest_era.saturating_add(One::one()).max(oldest_available_era);
+
+		while reward_era < current_era_info.era_index {
+			let eligible_amount = history.get_eligible_amount_for_era(&reward_era);
+			if let Some(pool) = ProviderBoostRewardPools::<T>::get(reward_era) {
+				if !eligible_amount.is_zero() {
+					let earned_amount = T::RewardsProvider::era_staking_reward(
+						eligible_amount,
+						pool.total_staked_token,
+						pool.total_reward_pool,
+					);
+					// The pool for `reward_era` is removed when era `reward_era + history_limit + 1` starts.
+					let eras_remaining: u32 = reward_era
+						.saturating_add(history_limit)
+						.saturating_add(One::one())
+						.saturating_sub(current_era_info.era_index)
+						.unique_saturated_into();
+					let expires_at_block = current_era_info
+						.started_at
+						.saturating_add(era_length.saturating_mul(eras_remaining.into()))
+						.saturating_sub(One::one());
+					unclaimed_rewards.push(UnclaimedRewardInfo {
+						reward_era,
+						expires_at_block,
+						eligible_amount,
+						earned_amount,
+					});
+				}
+			}
+			reward_era = reward_era.saturating_add(One::one());
+		}
+		unclaimed_rewards
+	}
+
+	/// Mints all unclaimed Provider Boost rewards to `staker` and marks them as claimed.
+	/// Returns the total amount minted.
+	pub(crate) fn do_claim_rewards(staker: &T::AccountId) -> Result<BalanceOf<T>, DispatchError> {
+		let rewards = Self::list_unclaimed_rewards(staker);
+		ensure!(!rewards.is_empty(), Error::<T>::NoRewardsEligibleToClaim);
+
+		let mut total_to_mint: BalanceOf<T> = Zero::zero();
+		for reward in rewards.iter() {
+			total_to_mint = total_to_mint
+				.checked_add(&reward.earned_amount)
+				.ok_or(ArithmeticError::Overflow)?;
+			ProviderBoostRewardPools::<T>::mutate(reward.reward_era, |maybe_pool| {
+				if let Some(pool) = maybe_pool {
+					pool.unclaimed_balance =
+						pool.unclaimed_balance.saturating_sub(reward.earned_amount);
+				}
+			});
+		}
+
+		let current_era = CurrentEraInfo::<T>::get().era_index;
+		let mut history =
+			ProviderBoostHistories::<T>::get(staker).ok_or(Error::<T>::NotAProviderBoostAccount)?;
+		history.mark_claimed_through(&current_era);
+		if history.count() == 0 {
+			ProviderBoostHistories::<T>::remove(staker);
+		} else {
+			ProviderBoostHistories::<T>::insert(staker, history);
+		}
+
+		if !total_to_mint.is_zero() {
+			T::Currency::mint_into(staker, total_to_mint)?;
+		}
+		Ok(total_to_mint)
+	}
+
+	/// Returns the Capacity details of `provider`, or None if nothing is staked to it.
+	pub fn get_capacity_for_provider(
+		provider: MessageSourceId,
+	) -> Option<CapacityDetailsResponse<BalanceOf<T>, T::EpochNumber>> {
+		CapacityLedger::<T>::get(provider).map(|details| CapacityDetailsResponse {
+			remaining_capacity: details.remaining_capacity,
+			total_tokens_staked: details.total_tokens_staked,
+			total_capacity_issued: details.total_capacity_issued,
+			last_replenished_epoch: details.last_replenished_epoch,
+		})
+	}
+
+	/// Returns the stakes of `staker` for each Provider it targets.
+	pub fn get_stakes_for_account(
+		staker: &T::AccountId,
+	) -> Vec<StakingTargetResponse<BalanceOf<T>>> {
+		StakingTargetLedger::<T>::iter_prefix(staker)
+			.map(|(target, details)| StakingTargetResponse {
+				target,
+				amount: details.amount,
+				capacity: details.capacity,
+			})
+			.collect()
+	}
+
+	/// Returns the pending unlock chunks of `staker`, in the order they were unstaked.
+	pub fn get_unlock_chunks(
+		staker: &T::AccountId,
+	) -> Vec<UnlockChunkResponse<BalanceOf<T>, T::EpochNumber>> {
+		UnstakeUnlocks::<T>::get(staker)
+			.unwrap_or_default()
+			.into_iter()
+			.map(|chunk| UnlockChunkResponse { value: chunk.value, thaw_at: chunk.thaw_at })
+			.collect()
+	}
 }
 
 impl<T: Config> Nontransferable for Pallet<T> {
@@ -686,6 +1340,39 @@ impl<T: Config> Nontransferable for Pallet<T> {
 	}
 }
 
+impl<T: Config> ProviderBoostRewardsProvider<T> for Pallet<T> {
+	type Balance = BalanceOf<T>;
+
+	/// The reward pool is a fixed amount each Reward Era, when anything is boosted at all.
+	fn reward_pool_size(total_staked: Self::Balance) -> Self::Balance {
+		if total_staked.is_zero() {
+			return Zero::zero()
+		}
+		T::RewardPoolPerEra::get()
+	}
+
+	/// The reward is the staker's share of the reward pool, capped at `RewardPercentCap` of the
+	/// amount staked for the era.
+	fn era_staking_reward(
+		era_amount_staked: Self::Balance,
+		era_total_staked: Self::Balance,
+		era_reward_pool_size: Self::Balance,
+	) -> Self::Balance {
+		if era_total_staked.is_zero() {
+			return Zero::zero()
+		}
+		let capped_reward = T::RewardPercentCap::get().mul_floor(era_amount_staked);
+		let proportional_reward = Perbill::from_rational(era_amount_staked, era_total_staked)
+			.mul_floor(era_reward_pool_size);
+		proportional_reward.min(capped_reward)
+	}
+
+	/// Only `STAKED_PERCENTAGE_TO_BOOST` of a boosted amount generates Capacity.
+	fn capacity_boost(amount: Self::Balance) -> Self::Balance {
+		Perbill::from_percent(STAKED_PERCENTAGE_TO_BOOST).mul(amount)
+	}
+}
+
 impl<T: Config> Replenishable for Pallet<T> {
 	type Balance = BalanceOf<T>;
 
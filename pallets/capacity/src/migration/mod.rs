@@ -1 +1,2 @@
-
+/// initialize the Provider Boost Reward Era
+pub mod v4;
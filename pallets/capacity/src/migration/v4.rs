@@ -0,0 +1,96 @@
+use crate::{BlockNumberFor, Config, CurrentEraInfo, Pallet, RewardEraInfo};
+
+use frame_support::{
+	pallet_prelude::{GetStorageVersion, Weight},
+	traits::{Get, OnRuntimeUpgrade, StorageVersion},
+};
+
+const LOG_TARGET: &str = "runtime::capacity";
+
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// The OnRuntimeUpgrade implementation for this storage migration.
+/// Starts the first Provider Boost Reward Era at the block of the upgrade, so that it lasts
+/// a full `EraLength` instead of ending immediately.
+pub struct MigrationToV4<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for MigrationToV4<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let on_chain_version = Pallet::<T>::on_chain_storage_version(); // 1r
+
+		if on_chain_version >= 4 {
+			log::info!(target: LOG_TARGET, "Old Capacity Provider Boost migration attempted to run. Please remove");
+			return T::DbWeight::get().reads(1)
+		}
+
+		log::info!(target: LOG_TARGET, "🔄 Capacity Provider Boost migration started");
+		let current_block = frame_system::Pallet::<T>::block_number(); // 1r
+		let era_info: RewardEraInfo<T::RewardEra, BlockNumberFor<T>> =
+			RewardEraInfo { era_index: 0u32.into(), started_at: current_block };
+		CurrentEraInfo::<T>::set(era_info); // 1w
+
+		StorageVersion::new(4).put::<Pallet<T>>(); // 1w
+		log::info!(target: LOG_TARGET, "🔄 Capacity Provider Boost migration finished");
+
+		T::DbWeight::get().reads_writes(2, 2)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+		log::info!(target: LOG_TARGET, "Running pre_upgrade...");
+		Ok(Vec::new())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		let on_chain_version = Pallet::<T>::on_chain_storage_version();
+		assert_eq!(on_chain_version, crate::pallet::STORAGE_VERSION);
+		assert_eq!(
+			CurrentEraInfo::<T>::get().started_at,
+			frame_system::Pallet::<T>::block_number()
+		);
+
+		log::info!(target: LOG_TARGET, "✅ migration post_upgrade checks passed");
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::tests::mock::{Test as T, *};
+
+	#[test]
+	fn migration_works() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1_000);
+			StorageVersion::new(3).put::<Pallet<T>>();
+
+			MigrationToV4::<T>::on_runtime_upgrade();
+
+			let on_chain_version = Pallet::<T>::on_chain_storage_version();
+			assert_eq!(on_chain_version, crate::pallet::STORAGE_VERSION);
+			assert_eq!(
+				CurrentEraInfo::<T>::get(),
+				RewardEraInfo { era_index: 0u32, started_at: 1_000u32 }
+			);
+		})
+	}
+
+	#[test]
+	fn migration_does_not_run_twice() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(4).put::<Pallet<T>>();
+			CurrentEraInfo::<T>::set(RewardEraInfo { era_index: 3u32, started_at: 500u32 });
+			System::set_block_number(1_000);
+
+			MigrationToV4::<T>::on_runtime_upgrade();
+
+			assert_eq!(
+				CurrentEraInfo::<T>::get(),
+				RewardEraInfo { era_index: 3u32, started_at: 500u32 }
+			);
+		})
+	}
+}
@@ -0,0 +1,158 @@
+// Strong Documentation Lints
+#![deny(
+	rustdoc::broken_intra_doc_links,
+	rustdoc::missing_crate_level_docs,
+	rustdoc::invalid_codeblock_attributes,
+	missing_docs
+)]
+
+//! Custom APIs for [Capacity](../pallet_capacity/index.html)
+
+use common_helpers::rpc::map_rpc_result;
+use common_primitives::{
+	capacity::{CapacityDetailsResponse, EpochNumber, StakingTargetResponse, UnlockChunkResponse},
+	msa::MessageSourceId,
+};
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+	types::{error::ErrorCode, ErrorObject},
+};
+use parity_scale_codec::Codec;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::traits::{Block as BlockT, MaybeDisplay};
+use std::{convert::TryInto, sync::Arc};
+
+pub use pallet_capacity_runtime_api::CapacityRuntimeApi;
+
+#[cfg(test)]
+mod tests;
+
+/// Recurrency Capacity Custom RPC API
+#[rpc(client, server)]
+pub trait CapacityApi<BlockHash, AccountId, Balance> {
+	/// Retrieve the Capacity details of a Provider, if it has any stake
+	#[method(name = "capacity_getCapacityForProvider")]
+	fn get_capacity_for_provider(
+		&self,
+		provider_msa_id: MessageSourceId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<CapacityDetailsResponse<NumberOrHex, EpochNumber>>>;
+
+	/// Retrieve an account's stakes for each Provider it targets
+	#[method(name = "capacity_getStakesForAccount")]
+	fn get_stakes_for_account(
+		&self,
+		staker: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<StakingTargetResponse<NumberOrHex>>>;
+
+	/// Retrieve an account's pending unlock chunks
+	#[method(name = "capacity_getUnlockChunks")]
+	fn get_unlock_chunks(
+		&self,
+		staker: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<UnlockChunkResponse<NumberOrHex, EpochNumber>>>;
+}
+
+/// The client handler for the API used by Recurrency Service RPC with `jsonrpsee`
+pub struct CapacityHandler<C, M> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<M>,
+}
+
+impl<C, M> CapacityHandler<C, M> {
+	/// Create new instance with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Converts a runtime balance into its RPC representation
+fn try_into_rpc_balance<Balance>(value: Balance) -> RpcResult<NumberOrHex>
+where
+	Balance: MaybeDisplay + Copy + TryInto<NumberOrHex>,
+{
+	value.try_into().map_err(|_| {
+		ErrorObject::owned(
+			ErrorCode::InvalidParams.code(),
+			format!("{} doesn't fit in NumberOrHex representation", value),
+			None::<()>,
+		)
+	})
+}
+
+#[async_trait]
+impl<C, Block, AccountId, Balance> CapacityApiServer<<Block as BlockT>::Hash, AccountId, Balance>
+	for CapacityHandler<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static,
+	C: ProvideRuntimeApi<Block>,
+	C: HeaderBackend<Block>,
+	C::Api: CapacityRuntimeApi<Block, AccountId, Balance>,
+	AccountId: Codec,
+	Balance: Codec + MaybeDisplay + Copy + TryInto<NumberOrHex> + Send + Sync + 'static,
+{
+	fn get_capacity_for_provider(
+		&self,
+		provider_msa_id: MessageSourceId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<CapacityDetailsResponse<NumberOrHex, EpochNumber>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let runtime_api_result = api.get_capacity_for_provider(at, provider_msa_id);
+		map_rpc_result(runtime_api_result)?
+			.map(|details| {
+				Ok(CapacityDetailsResponse {
+					remaining_capacity: try_into_rpc_balance(details.remaining_capacity)?,
+					total_tokens_staked: try_into_rpc_balance(details.total_tokens_staked)?,
+					total_capacity_issued: try_into_rpc_balance(details.total_capacity_issued)?,
+					last_replenished_epoch: details.last_replenished_epoch,
+				})
+			})
+			.transpose()
+	}
+
+	fn get_stakes_for_account(
+		&self,
+		staker: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<StakingTargetResponse<NumberOrHex>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let runtime_api_result = api.get_stakes_for_account(at, staker);
+		map_rpc_result(runtime_api_result)?
+			.into_iter()
+			.map(|stake| {
+				Ok(StakingTargetResponse {
+					target: stake.target,
+					amount: try_into_rpc_balance(stake.amount)?,
+					capacity: try_into_rpc_balance(stake.capacity)?,
+				})
+			})
+			.collect()
+	}
+
+	fn get_unlock_chunks(
+		&self,
+		staker: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<UnlockChunkResponse<NumberOrHex, EpochNumber>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let runtime_api_result = api.get_unlock_chunks(at, staker);
+		map_rpc_result(runtime_api_result)?
+			.into_iter()
+			.map(|chunk| {
+				Ok(UnlockChunkResponse {
+					value: try_into_rpc_balance(chunk.value)?,
+					thaw_at: chunk.thaw_at,
+				})
+			})
+			.collect()
+	}
+}
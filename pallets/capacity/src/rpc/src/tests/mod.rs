@@ -0,0 +1,111 @@
+mod rpc_mock;
+
+use super::*;
+use rpc_mock::*;
+
+use common_primitives::node::{AccountId, Balance, Block};
+use pallet_capacity_runtime_api::CapacityRuntimeApi;
+use std::sync::Arc;
+
+const VALID_PROVIDER_ID: u64 = 1;
+const STAKER: [u8; 32] = [1u8; 32];
+
+sp_api::mock_impl_runtime_apis! {
+	impl CapacityRuntimeApi<Block, AccountId, Balance> for TestRuntimeApi {
+		fn get_capacity_for_provider(provider: MessageSourceId) -> Option<CapacityDetailsResponse<Balance, EpochNumber>> {
+			match provider {
+				VALID_PROVIDER_ID => Some(CapacityDetailsResponse {
+					remaining_capacity: 5,
+					total_tokens_staked: 100,
+					total_capacity_issued: 10,
+					last_replenished_epoch: 3,
+				}),
+				_ => None,
+			}
+		}
+
+		fn get_stakes_for_account(staker: AccountId) -> Vec<StakingTargetResponse<Balance>> {
+			if staker == AccountId::from(STAKER) {
+				vec![
+					StakingTargetResponse { target: 1, amount: 100, capacity: 10 },
+					StakingTargetResponse { target: 2, amount: u128::MAX, capacity: 1 },
+				]
+			} else {
+				vec![]
+			}
+		}
+
+		fn get_unlock_chunks(staker: AccountId) -> Vec<UnlockChunkResponse<Balance, EpochNumber>> {
+			if staker == AccountId::from(STAKER) {
+				vec![UnlockChunkResponse { value: 40, thaw_at: 7 }]
+			} else {
+				vec![]
+			}
+		}
+	}
+}
+
+#[tokio::test]
+async fn get_capacity_for_provider_with_success() {
+	let client = Arc::new(TestApi {});
+	let api = CapacityHandler::new(client);
+
+	let result = api.get_capacity_for_provider(VALID_PROVIDER_ID, None);
+
+	assert_eq!(
+		Some(CapacityDetailsResponse {
+			remaining_capacity: NumberOrHex::from(5u128),
+			total_tokens_staked: NumberOrHex::from(100u128),
+			total_capacity_issued: NumberOrHex::from(10u128),
+			last_replenished_epoch: 3,
+		}),
+		result.unwrap()
+	);
+}
+
+#[tokio::test]
+async fn get_capacity_for_provider_without_stake_should_return_none() {
+	let client = Arc::new(TestApi {});
+	let api = CapacityHandler::new(client);
+
+	let result = api.get_capacity_for_provider(1233, None);
+
+	assert_eq!(true, result.is_ok());
+	assert_eq!(true, result.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn get_stakes_for_account_with_success() {
+	let client = Arc::new(TestApi {});
+	let api = CapacityHandler::new(client);
+
+	let result = api.get_stakes_for_account(AccountId::from(STAKER), None);
+
+	let stakes = result.unwrap();
+	assert_eq!(2, stakes.len());
+	assert_eq!(NumberOrHex::from(100u128), stakes[0].amount);
+	assert_eq!(NumberOrHex::from(u128::MAX), stakes[1].amount);
+}
+
+#[tokio::test]
+async fn get_unlock_chunks_with_success() {
+	let client = Arc::new(TestApi {});
+	let api = CapacityHandler::new(client);
+
+	let result = api.get_unlock_chunks(AccountId::from(STAKER), None);
+
+	assert_eq!(
+		vec![UnlockChunkResponse { value: NumberOrHex::from(40u128), thaw_at: 7 }],
+		result.unwrap()
+	);
+}
+
+#[tokio::test]
+async fn get_unlock_chunks_for_unknown_account_should_return_empty() {
+	let client = Arc::new(TestApi {});
+	let api = CapacityHandler::new(client);
+
+	let result = api.get_unlock_chunks(AccountId::from([2u8; 32]), None);
+
+	assert_eq!(true, result.unwrap().is_empty());
+}
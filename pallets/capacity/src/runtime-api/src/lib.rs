@@ -0,0 +1,51 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::unnecessary_mut_passed)]
+#![allow(rustdoc::bare_urls)]
+// Strong Documentation Lints
+#![deny(
+	rustdoc::broken_intra_doc_links,
+	rustdoc::missing_crate_level_docs,
+	rustdoc::invalid_codeblock_attributes,
+	missing_docs
+)]
+
+//! Runtime API definition for [Capacity](../pallet_capacity/index.html)
+//!
+//! This api must be implemented by the node runtime.
+//! Runtime APIs Provide:
+//! - An interface between the runtime and Custom RPCs.
+//! - Runtime interfaces for end users beyond just State Queries
+
+use common_primitives::{
+	capacity::{CapacityDetailsResponse, EpochNumber, StakingTargetResponse, UnlockChunkResponse},
+	msa::MessageSourceId,
+};
+use parity_scale_codec::Codec;
+use sp_std::vec::Vec;
+
+// Here we declare the runtime API. It is implemented it the `impl` block in
+// runtime files (the `runtime` folder)
+sp_api::decl_runtime_apis! {
+
+	/// Runtime Version for Capacity
+	/// - MUST be incremented if anything changes
+	/// - Also update in js/api-augment
+	/// - See: https://paritytech.github.io/polkadot/doc/polkadot_primitives/runtime_api/index.html
+	#[api_version(1)]
+
+	/// Runtime API definition for [Capacity](../pallet_capacity/index.html)
+	pub trait CapacityRuntimeApi<AccountId, Balance> where
+		AccountId: Codec,
+		Balance: Codec,
+	{
+		/// Retrieve the Capacity details of a Provider, if it has any stake
+		fn get_capacity_for_provider(provider: MessageSourceId) -> Option<CapacityDetailsResponse<Balance, EpochNumber>>;
+
+		/// Retrieve an account's stakes for each Provider it targets
+		fn get_stakes_for_account(staker: AccountId) -> Vec<StakingTargetResponse<Balance>>;
+
+		/// Retrieve an account's pending unlock chunks
+		fn get_unlock_chunks(staker: AccountId) -> Vec<UnlockChunkResponse<Balance, EpochNumber>>;
+	}
+}
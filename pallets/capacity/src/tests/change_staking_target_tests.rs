@@ -0,0 +1,280 @@
+use super::{mock::*, testing_utils::*};
+use crate::{
+	CapacityDetails, CapacityLedger, Config, CurrentEraProviderBoostTotal, Error, Event,
+	ProviderBoostHistories, RetargetInfo, Retargets, StakingAccountLedger, StakingTargetDetails,
+	StakingTargetLedger, UnstakeUnlocks,
+};
+use common_primitives::{capacity::Nontransferable, msa::MessageSourceId};
+use frame_support::{assert_noop, assert_ok, traits::Get};
+
+fn setup_providers(providers: &[MessageSourceId]) {
+	for provider in providers {
+		register_provider(*provider, format!("Provider{}", provider));
+	}
+}
+
+fn capacity_details(
+	remaining_capacity: u64,
+	total_tokens_staked: u64,
+	total_capacity_issued: u64,
+) -> CapacityDetails<u64, u32> {
+	CapacityDetails {
+		remaining_capacity,
+		total_tokens_staked,
+		total_capacity_issued,
+		last_replenished_epoch: 0,
+	}
+}
+
+#[test]
+fn change_staking_target_moves_stake_and_capacity() {
+	new_test_ext().execute_with(|| {
+		let account = 200;
+		let from: MessageSourceId = 1;
+		let to: MessageSourceId = 2;
+		setup_providers(&[from, to]);
+		assert_ok!(Capacity::stake(RuntimeOrigin::signed(account), from, 100));
+
+		assert_ok!(Capacity::change_staking_target(RuntimeOrigin::signed(account), from, to, 40));
+
+		assert_eq!(
+			StakingTargetLedger::<Test>::get(account, from).unwrap(),
+			StakingTargetDetails { amount: 60, capacity: 6 }
+		);
+		assert_eq!(
+			StakingTargetLedger::<Test>::get(account, to).unwrap(),
+			StakingTargetDetails { amount: 40, capacity: 4 }
+		);
+		assert_eq!(CapacityLedger::<Test>::get(from).unwrap(), capacity_details(6, 60, 6));
+		assert_eq!(CapacityLedger::<Test>::get(to).unwrap(), capacity_details(4, 40, 4));
+
+		// The stake is not unlocked.
+		assert_eq!(StakingAccountLedger::<Test>::get(account).unwrap().active, 100);
+		assert!(UnstakeUnlocks::<Test>::get(account).is_none());
+
+		let events = staking_events();
+		assert_eq!(
+			events.last().unwrap(),
+			&Event::StakingTargetChanged { account, from_msa: from, to_msa: to, amount: 40 }
+		);
+	});
+}
+
+#[test]
+fn change_staking_target_adds_to_existing_stake() {
+	new_test_ext().execute_with(|| {
+		let account = 200;
+		let from: MessageSourceId = 1;
+		let to: MessageSourceId = 2;
+		setup_providers(&[from, to]);
+		assert_ok!(Capacity::stake(RuntimeOrigin::signed(account), from, 100));
+		assert_ok!(Capacity::stake(RuntimeOrigin::signed(account), to, 50));
+
+		assert_ok!(Capacity::change_staking_target(RuntimeOrigin::signed(account), from, to, 100));
+
+		assert!(StakingTargetLedger::<Test>::get(account, from).is_none());
+		assert_eq!(
+			StakingTargetLedger::<Test>::get(account, to).unwrap(),
+			StakingTargetDetails { amount: 150, capacity: 15 }
+		);
+		assert_eq!(CapacityLedger::<Test>::get(from).unwrap(), capacity_details(0, 0, 0));
+		assert_eq!(CapacityLedger::<Test>::get(to).unwrap(), capacity_details(15, 150, 15));
+	});
+}
+
+#[test]
+fn change_staking_target_carries_over_capacity_used_this_epoch() {
+	new_test_ext().execute_with(|| {
+		let account = 200;
+		let from: MessageSourceId = 1;
+		let to: MessageSourceId = 2;
+		setup_providers(&[from, to]);
+		assert_ok!(Capacity::stake(RuntimeOrigin::signed(account), from, 100));
+		assert_ok!(Capacity::deduct(from, 5));
+
+		assert_ok!(Capacity::change_staking_target(RuntimeOrigin::signed(account), from, to, 40));
+
+		// Half of the moved Capacity was already used, so only half is spendable at `to`.
+		assert_eq!(CapacityLedger::<Test>::get(from).unwrap(), capacity_details(3, 60, 6));
+		assert_eq!(CapacityLedger::<Test>::get(to).unwrap(), capacity_details(2, 40, 4));
+	});
+}
+
+#[test]
+fn change_staking_target_errors_when_remaining_stake_below_minimum() {
+	new_test_ext().execute_with(|| {
+		let account = 200;
+		let from: MessageSourceId = 1;
+		let to: MessageSourceId = 2;
+		setup_providers(&[from, to]);
+		assert_ok!(Capacity::stake(RuntimeOrigin::signed(account), from, 100));
+
+		let minimum: u64 = <Test as Config>::MinimumStakingAmount::get();
+		assert_noop!(
+			Capacity::change_staking_target(
+				RuntimeOrigin::signed(account),
+				from,
+				to,
+				100 - minimum + 1
+			),
+			Error::<Test>::InsufficientStakingBalance
+		);
+		assert_ok!(Capacity::change_staking_target(
+			RuntimeOrigin::signed(account),
+			from,
+			to,
+			100 - minimum
+		));
+	});
+}
+
+#[test]
+fn change_staking_target_works_for_provider_boost() {
+	new_test_ext().execute_with(|| {
+		let account = 600;
+		let from: MessageSourceId = 1;
+		let to: MessageSourceId = 2;
+		setup_providers(&[from, to]);
+		assert_ok!(Capacity::provider_boost(RuntimeOrigin::signed(account), from, 200));
+
+		assert_ok!(Capacity::change_staking_target(RuntimeOrigin::signed(account), from, to, 100));
+
+		assert_eq!(
+			StakingTargetLedger::<Test>::get(account, from).unwrap(),
+			StakingTargetDetails { amount: 100, capacity: 5 }
+		);
+		assert_eq!(
+			StakingTargetLedger::<Test>::get(account, to).unwrap(),
+			StakingTargetDetails { amount: 100, capacity: 5 }
+		);
+
+		// The boosted amount is unchanged, so rewards are unaffected.
+		let history = ProviderBoostHistories::<Test>::get(account).unwrap();
+		assert_eq!(history.get_amount_staked_for_era(&0), 200);
+		assert_eq!(CurrentEraProviderBoostTotal::<Test>::get(), 200);
+	});
+}
+
+#[test]
+fn change_staking_target_errors_with_same_provider() {
+	new_test_ext().execute_with(|| {
+		let account = 200;
+		let from: MessageSourceId = 1;
+		setup_providers(&[from]);
+		assert_ok!(Capacity::stake(RuntimeOrigin::signed(account), from, 100));
+
+		assert_noop!(
+			Capacity::change_staking_target(RuntimeOrigin::signed(account), from, from, 40),
+			Error::<Test>::CannotRetargetToSameProvider
+		);
+	});
+}
+
+#[test]
+fn change_staking_target_errors_with_amount_below_minimum() {
+	new_test_ext().execute_with(|| {
+		let account = 200;
+		let from: MessageSourceId = 1;
+		let to: MessageSourceId = 2;
+		setup_providers(&[from, to]);
+		assert_ok!(Capacity::stake(RuntimeOrigin::signed(account), from, 100));
+
+		let amount: u64 = <Test as Config>::MinimumStakingAmount::get() - 1;
+		assert_noop!(
+			Capacity::change_staking_target(RuntimeOrigin::signed(account), from, to, amount),
+			Error::<Test>::InsufficientStakingAmount
+		);
+	});
+}
+
+#[test]
+fn change_staking_target_errors_with_invalid_target() {
+	new_test_ext().execute_with(|| {
+		let account = 200;
+		let from: MessageSourceId = 1;
+		setup_providers(&[from]);
+		assert_ok!(Capacity::stake(RuntimeOrigin::signed(account), from, 100));
+
+		assert_noop!(
+			Capacity::change_staking_target(RuntimeOrigin::signed(account), from, 2, 40),
+			Error::<Test>::InvalidTarget
+		);
+	});
+}
+
+#[test]
+fn change_staking_target_errors_without_stake() {
+	new_test_ext().execute_with(|| {
+		let account = 200;
+		let from: MessageSourceId = 1;
+		let to: MessageSourceId = 2;
+		setup_providers(&[from, to, 3]);
+
+		assert_noop!(
+			Capacity::change_staking_target(RuntimeOrigin::signed(account), from, to, 40),
+			Error::<Test>::NotAStakingAccount
+		);
+
+		assert_ok!(Capacity::stake(RuntimeOrigin::signed(account), 3, 100));
+		assert_noop!(
+			Capacity::change_staking_target(RuntimeOrigin::signed(account), from, to, 40),
+			Error::<Test>::StakerTargetRelationshipNotFound
+		);
+	});
+}
+
+#[test]
+fn change_staking_target_errors_when_amount_exceeds_stake() {
+	new_test_ext().execute_with(|| {
+		let account = 200;
+		let from: MessageSourceId = 1;
+		let to: MessageSourceId = 2;
+		setup_providers(&[from, to]);
+		assert_ok!(Capacity::stake(RuntimeOrigin::signed(account), from, 50));
+		assert_ok!(Capacity::stake(RuntimeOrigin::signed(account), to, 50));
+
+		assert_noop!(
+			Capacity::change_staking_target(RuntimeOrigin::signed(account), from, to, 51),
+			Error::<Test>::InsufficientStakingBalance
+		);
+	});
+}
+
+#[test]
+fn change_staking_target_is_limited_per_reward_era() {
+	new_test_ext().execute_with(|| {
+		let account = 200;
+		let from: MessageSourceId = 1;
+		let to: MessageSourceId = 2;
+		setup_providers(&[from, to]);
+		assert_ok!(Capacity::stake(RuntimeOrigin::signed(account), from, 100));
+
+		let max_retargets: u32 = <Test as Config>::MaxRetargetsPerRewardEra::get();
+		for _ in 0..max_retargets {
+			assert_ok!(Capacity::change_staking_target(
+				RuntimeOrigin::signed(account),
+				from,
+				to,
+				10
+			));
+		}
+		assert_noop!(
+			Capacity::change_staking_target(RuntimeOrigin::signed(account), from, to, 10),
+			Error::<Test>::MaxRetargetsExceeded
+		);
+
+		// The count resets in the next Reward Era.
+		run_to_block(10);
+		assert_ok!(Capacity::change_staking_target(RuntimeOrigin::signed(account), from, to, 10));
+		assert_eq!(Retargets::<Test>::get(account).unwrap(), RetargetInfo::new(1, 1));
+	});
+}
+
+#[test]
+fn retarget_info_update_resets_count_in_new_era() {
+	let max_retargets: u32 = <Test as Config>::MaxRetargetsPerRewardEra::get();
+	let mut retarget_info = RetargetInfo::<Test>::new(max_retargets, 3);
+	assert_eq!(retarget_info.update(3), None);
+	assert_eq!(retarget_info.update(4), Some(()));
+	assert_eq!(retarget_info, RetargetInfo::new(1, 4));
+}
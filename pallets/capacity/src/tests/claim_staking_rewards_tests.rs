@@ -0,0 +1,210 @@
+use super::{mock::*, testing_utils::*};
+use crate::{
+	Config, Error, Event, ProviderBoostHistories, ProviderBoostRewardPools,
+	ProviderBoostRewardsProvider, UnclaimedRewardInfo,
+};
+use common_primitives::msa::MessageSourceId;
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::fungible::{Inspect, Mutate},
+};
+
+fn fund_and_boost(account: u64, target: MessageSourceId, amount: u64) {
+	<Test as Config>::Currency::set_balance(&account, amount + 1_000);
+	assert_ok!(Capacity::provider_boost(RuntimeOrigin::signed(account), target, amount));
+}
+
+#[test]
+fn rewards_are_not_earned_for_a_partial_era() {
+	new_test_ext().execute_with(|| {
+		let account = 600;
+		let target: MessageSourceId = 1;
+		register_provider(target, String::from("Foo"));
+		fund_and_boost(account, target, 10_000_000);
+
+		run_to_block(10);
+		assert!(Capacity::list_unclaimed_rewards(&account).is_empty());
+		assert_noop!(
+			Capacity::claim_staking_rewards(RuntimeOrigin::signed(account)),
+			Error::<Test>::NoRewardsEligibleToClaim
+		);
+	});
+}
+
+#[test]
+fn list_unclaimed_rewards_returns_completed_eras() {
+	new_test_ext().execute_with(|| {
+		let account = 600;
+		let target: MessageSourceId = 1;
+		register_provider(target, String::from("Foo"));
+		fund_and_boost(account, target, 10_000_000);
+
+		// Era 3 starts at block 30; eras 1 and 2 were boosted for their entirety.
+		run_to_block(30);
+		assert_eq!(
+			Capacity::list_unclaimed_rewards(&account),
+			vec![
+				UnclaimedRewardInfo {
+					reward_era: 1,
+					expires_at_block: 79,
+					eligible_amount: 10_000_000,
+					earned_amount: 10_000,
+				},
+				UnclaimedRewardInfo {
+					reward_era: 2,
+					expires_at_block: 89,
+					eligible_amount: 10_000_000,
+					earned_amount: 10_000,
+				},
+			]
+		);
+	});
+}
+
+#[test]
+fn claim_staking_rewards_works() {
+	new_test_ext().execute_with(|| {
+		let account = 600;
+		let target: MessageSourceId = 1;
+		register_provider(target, String::from("Foo"));
+		fund_and_boost(account, target, 10_000_000);
+		let balance_before = <Test as Config>::Currency::balance(&account);
+
+		run_to_block(30);
+		assert_ok!(Capacity::claim_staking_rewards(RuntimeOrigin::signed(account)));
+
+		assert_eq!(<Test as Config>::Currency::balance(&account), balance_before + 20_000);
+		assert_eq!(ProviderBoostRewardPools::<Test>::get(1).unwrap().unclaimed_balance, 0);
+		assert_eq!(ProviderBoostRewardPools::<Test>::get(2).unwrap().unclaimed_balance, 0);
+
+		let history = ProviderBoostHistories::<Test>::get(account).unwrap();
+		assert_eq!(history.count(), 1);
+		assert_eq!(history.get_amount_staked_for_era(&2), 10_000_000);
+
+		let events = staking_events();
+		assert_eq!(
+			events.last().unwrap(),
+			&Event::ProviderBoostRewardClaimed { account, reward_amount: 20_000 }
+		);
+
+		assert!(Capacity::list_unclaimed_rewards(&account).is_empty());
+		assert_noop!(
+			Capacity::claim_staking_rewards(RuntimeOrigin::signed(account)),
+			Error::<Test>::NoRewardsEligibleToClaim
+		);
+	});
+}
+
+#[test]
+fn amounts_boosted_mid_era_do_not_dilute_the_rewards_for_that_era() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let target: MessageSourceId = 1;
+		register_provider(target, String::from("Foo"));
+		fund_and_boost(600, target, 10_000_000);
+		run_to_block(15);
+		fund_and_boost(500, target, 10_000_000);
+		run_to_block(30);
+
+		// act
+		assert_ok!(Capacity::claim_staking_rewards(RuntimeOrigin::signed(600)));
+		assert_ok!(Capacity::claim_staking_rewards(RuntimeOrigin::signed(500)));
+
+		// assert
+		assert_eq!(
+			staking_events().last().unwrap(),
+			&Event::ProviderBoostRewardClaimed { account: 500, reward_amount: 5_000 }
+		);
+		for reward_era in [1, 2] {
+			assert_eq!(
+				ProviderBoostRewardPools::<Test>::get(reward_era).unwrap().unclaimed_balance,
+				0
+			);
+		}
+	});
+}
+
+#[test]
+fn claim_staking_rewards_errors_when_not_a_provider_boost_account() {
+	new_test_ext().execute_with(|| {
+		let account = 600;
+		let target: MessageSourceId = 1;
+		register_provider(target, String::from("Foo"));
+		assert_ok!(Capacity::stake(RuntimeOrigin::signed(account), target, 100));
+
+		run_to_block(30);
+		assert_noop!(
+			Capacity::claim_staking_rewards(RuntimeOrigin::signed(account)),
+			Error::<Test>::NotAProviderBoostAccount
+		);
+	});
+}
+
+#[test]
+fn reward_pool_is_shared_in_proportion_to_amount_boosted() {
+	new_test_ext().execute_with(|| {
+		let target: MessageSourceId = 1;
+		register_provider(target, String::from("Foo"));
+		fund_and_boost(600, target, 10_000_000);
+		fund_and_boost(500, target, 30_000_000);
+
+		run_to_block(20);
+		assert_eq!(Capacity::list_unclaimed_rewards(&600)[0].earned_amount, 2_500);
+		assert_eq!(Capacity::list_unclaimed_rewards(&500)[0].earned_amount, 7_500);
+	});
+}
+
+#[test]
+fn reward_is_capped_at_reward_percent_cap() {
+	new_test_ext().execute_with(|| {
+		let account = 600;
+		let target: MessageSourceId = 1;
+		register_provider(target, String::from("Foo"));
+		fund_and_boost(account, target, 1_000_000);
+
+		run_to_block(20);
+		// 0.38% of 1_000_000 is less than the whole 10_000 pool.
+		assert_eq!(Capacity::list_unclaimed_rewards(&account)[0].earned_amount, 3_800);
+	});
+}
+
+#[test]
+fn unclaimed_rewards_expire_with_reward_pool_history() {
+	new_test_ext().execute_with(|| {
+		let account = 600;
+		let target: MessageSourceId = 1;
+		register_provider(target, String::from("Foo"));
+		fund_and_boost(account, target, 10_000_000);
+
+		// Era 10 starts at block 100; only eras 4 through 9 remain in history.
+		run_to_block(100);
+		let rewards = Capacity::list_unclaimed_rewards(&account);
+		assert_eq!(rewards.len(), 6);
+		assert_eq!(rewards.first().unwrap().reward_era, 4);
+		assert_eq!(rewards.last().unwrap().reward_era, 9);
+	});
+}
+
+#[test]
+fn unstake_succeeds_after_claiming_rewards() {
+	new_test_ext().execute_with(|| {
+		let account = 600;
+		let target: MessageSourceId = 1;
+		register_provider(target, String::from("Foo"));
+		fund_and_boost(account, target, 10_000_000);
+
+		run_to_block(20);
+		assert_ok!(Capacity::claim_staking_rewards(RuntimeOrigin::signed(account)));
+		assert_ok!(Capacity::unstake(RuntimeOrigin::signed(account), target, 5_000_000));
+	});
+}
+
+#[test]
+fn era_staking_reward_is_zero_when_nothing_is_staked() {
+	assert_eq!(Capacity::era_staking_reward(100, 0, 10_000), 0);
+}
+
+#[test]
+fn capacity_boost_is_half_of_amount() {
+	assert_eq!(Capacity::capacity_boost(200), 100);
+}
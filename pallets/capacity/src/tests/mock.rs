@@ -3,6 +3,7 @@ use crate as pallet_capacity;
 use common_primitives::{
 	node::{AccountId, ProposalProvider},
 	schema::{SchemaId, SchemaValidator},
+	signatures::EIP712Domain,
 };
 use frame_support::{
 	construct_runtime, parameter_types,
@@ -12,7 +13,7 @@ use frame_system::EnsureSigned;
 use sp_core::{ConstU8, H256};
 use sp_runtime::{
 	traits::{BlakeTwo256, Convert, IdentityLookup},
-	AccountId32, BuildStorage, DispatchError, Perbill,
+	AccountId32, BuildStorage, DispatchError, Perbill, Permill,
 };
 
 type Block = frame_system::mocking::MockBlockU32<Test>;
@@ -23,7 +24,7 @@ construct_runtime!(
 	{
 		System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
-		Msa: pallet_msa::{Pallet, Call, Storage, Event<T>},
+		Msa: pallet_msa::{Pallet, Call, Storage, Event<T>, HoldReason},
 		Capacity: pallet_capacity::{Pallet, Call, Storage, Event<T>, FreezeReason},
 	}
 );
@@ -72,7 +73,7 @@ impl pallet_balances::Config for Test {
 	type WeightInfo = ();
 	type FreezeIdentifier = RuntimeFreezeReason;
 	type MaxFreezes = ConstU32<1>;
-	type RuntimeHoldReason = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type RuntimeFreezeReason = ();
 }
 
@@ -117,26 +118,49 @@ impl ProposalProvider<u64, RuntimeCall> for CouncilProposalProvider {
 	}
 }
 
+// Needs parameter_types! for the EIP712Domain
+parameter_types! {
+	pub const TestEip712Domain: EIP712Domain =
+		EIP712Domain { chain_id: 0x190f_1b44, verifying_contract: [0xCC; 20] };
+}
+
 impl pallet_msa::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type WeightInfo = ();
+	type Currency = pallet_balances::Pallet<Self>;
 	type ConvertIntoAccountId32 = TestAccountId;
 	type MaxPublicKeysPerMsa = ConstU8<255>;
 	type MaxSchemaGrantsPerDelegation = MaxSchemaGrantsPerDelegation;
 	type MaxProviderNameSize = ConstU32<16>;
+	type MaxProviderMetadataFieldSize = ConstU32<64>;
 	type SchemaValidator = Schemas;
 	type HandleProvider = ();
+	type MsaDataPurger = ();
+	type MsaDataMerger = ();
 	type MortalityWindowSize = ConstU32<100>;
+	type Eip712Domain = TestEip712Domain;
 	type Proposal = RuntimeCall;
 	type ProposalProvider = CouncilProposalProvider;
 	type CreateProviderViaGovernanceOrigin = EnsureSigned<u64>;
 	/// This MUST ALWAYS be MaxSignaturesPerBucket * NumberOfBuckets.
 	type MaxSignaturesStored = ConstU32<8000>;
+	type MaxRecoveryGuardians = ConstU32<5>;
+	type RecoveryDelay = ConstU32<10>;
+	type ProviderRetirementGracePeriod = ConstU32<10>;
+	type MaxSubDelegationDepth = ConstU32<3>;
+	type MaxSubDelegationsPerProvider = ConstU32<4>;
+	type MaxSubDelegationLookups = ConstU32<8>;
+	type DelegationRequestDeposit = ConstU64<10>;
+	type MaxPendingDelegationRequests = ConstU32<10>;
+	type MaxDelegationRequestLifetime = ConstU32<100>;
+	type MaxThresholdProposalsPerMsa = ConstU32<10>;
 }
 
 // Needs parameter_types! for the Perbill
 parameter_types! {
 	pub const TestCapacityPerToken: Perbill = Perbill::from_percent(10);
+	pub const TestRewardCap: Permill = Permill::from_parts(3_800); // 0.38% or 0.0038 per RewardEra
 }
 impl pallet_capacity::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
@@ -156,6 +180,13 @@ impl pallet_capacity::Config for Test {
 	type MaxEpochLength = ConstU32<100>;
 	type EpochNumber = u32;
 	type CapacityPerToken = TestCapacityPerToken;
+	type RewardEra = u32;
+	type EraLength = ConstU32<10>;
+	type ProviderBoostHistoryLimit = ConstU32<6>;
+	type RewardsProvider = Capacity;
+	type RewardPoolPerEra = ConstU64<10_000>;
+	type RewardPercentCap = TestRewardCap;
+	type MaxRetargetsPerRewardEra = ConstU32<5>;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
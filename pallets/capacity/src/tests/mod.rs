@@ -1,8 +1,14 @@
 pub mod capacity_details_tests;
+pub mod change_staking_target_tests;
+pub mod claim_staking_rewards_tests;
 pub mod epochs_tests;
 pub mod mock;
 pub mod other_tests;
+pub mod provider_boost_history_tests;
+pub mod provider_boost_tests;
 pub mod replenishment_tests;
+pub mod reward_era_tests;
+pub mod runtime_api_tests;
 pub mod stake_and_deposit_tests;
 pub mod staking_account_details_tests;
 pub mod staking_target_details_tests;
@@ -0,0 +1,61 @@
+use super::mock::*;
+use crate::{Config, ProviderBoostHistory};
+use frame_support::traits::Get;
+
+#[test]
+fn provider_boost_history_add_and_subtract_era_balance() {
+	let mut history = ProviderBoostHistory::<Test>::new();
+	assert_eq!(history.add_era_balance(&1, &100), Some(1));
+	assert_eq!(history.add_era_balance(&1, &50), Some(1));
+	assert_eq!(history.add_era_balance(&3, &10), Some(2));
+	assert_eq!(history.subtract_era_balance(&4, &60), Some(3));
+	assert_eq!(history.subtract_era_balance(&4, &200), None);
+
+	assert_eq!(history.get_amount_staked_for_era(&0), 0);
+	assert_eq!(history.get_amount_staked_for_era(&1), 150);
+	assert_eq!(history.get_amount_staked_for_era(&2), 150);
+	assert_eq!(history.get_amount_staked_for_era(&3), 160);
+	assert_eq!(history.get_amount_staked_for_era(&4), 100);
+	assert_eq!(history.get_amount_staked_for_era(&9), 100);
+}
+
+#[test]
+fn provider_boost_history_eligible_amount_requires_a_full_era() {
+	let mut history = ProviderBoostHistory::<Test>::new();
+	history.add_era_balance(&1, &100);
+	history.subtract_era_balance(&3, &40);
+
+	assert_eq!(history.get_eligible_amount_for_era(&1), 0);
+	assert_eq!(history.get_eligible_amount_for_era(&2), 100);
+	assert_eq!(history.get_eligible_amount_for_era(&3), 60);
+	assert_eq!(history.get_eligible_amount_for_era(&4), 60);
+}
+
+#[test]
+fn provider_boost_history_mark_claimed_through_collapses_past_eras() {
+	let mut history = ProviderBoostHistory::<Test>::new();
+	history.add_era_balance(&1, &100);
+	history.add_era_balance(&2, &100);
+	history.add_era_balance(&5, &100);
+
+	history.mark_claimed_through(&5);
+	assert_eq!(history.count(), 2);
+	assert_eq!(history.earliest_era(), Some(&4));
+	assert_eq!(history.get_amount_staked_for_era(&4), 200);
+	assert_eq!(history.get_amount_staked_for_era(&5), 300);
+}
+
+#[test]
+fn provider_boost_history_add_era_balance_fails_when_full() {
+	let limit: u32 = <Test as Config>::ProviderBoostHistoryLimit::get();
+	let mut history = ProviderBoostHistory::<Test>::new();
+	for era in 0..limit {
+		history.add_era_balance(&era, &10);
+	}
+	assert_eq!(history.count(), limit as usize);
+
+	assert_eq!(history.add_era_balance(&limit, &10), None);
+	// An existing entry can still be updated.
+	assert_eq!(history.add_era_balance(&(limit - 1), &10), Some(limit as usize));
+	assert_eq!(history.earliest_era(), Some(&0));
+}
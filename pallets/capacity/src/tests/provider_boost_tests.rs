@@ -0,0 +1,206 @@
+use super::{mock::*, testing_utils::*};
+use crate::{
+	CapacityDetails, CapacityLedger, Config, CurrentEraProviderBoostTotal, Error, Event,
+	FreezeReason, ProviderBoostHistories, StakingAccountLedger, StakingDetails,
+	StakingTargetDetails, StakingTargetLedger, StakingType,
+};
+use common_primitives::msa::MessageSourceId;
+use frame_support::{assert_noop, assert_ok, traits::fungible::InspectFreeze};
+
+#[test]
+fn provider_boost_works() {
+	new_test_ext().execute_with(|| {
+		let account = 600;
+		let target: MessageSourceId = 1;
+		let amount = 200;
+		let capacity = 10; // Boosting generates half the Capacity of a MaximumCapacity stake
+		register_provider(target, String::from("Foo"));
+		assert_ok!(Capacity::provider_boost(RuntimeOrigin::signed(account), target, amount));
+
+		assert_eq!(
+			StakingAccountLedger::<Test>::get(account).unwrap(),
+			StakingDetails::<Test> { active: 200, staking_type: StakingType::ProviderBoost }
+		);
+		assert_eq!(
+			StakingTargetLedger::<Test>::get(account, target).unwrap(),
+			StakingTargetDetails { amount: 200, capacity: 10 }
+		);
+		assert_eq!(
+			CapacityLedger::<Test>::get(target).unwrap(),
+			CapacityDetails {
+				remaining_capacity: 10,
+				total_tokens_staked: 200,
+				total_capacity_issued: 10,
+				last_replenished_epoch: 0,
+			}
+		);
+
+		let history = ProviderBoostHistories::<Test>::get(account).unwrap();
+		assert_eq!(history.get_amount_staked_for_era(&0), 200);
+		assert_eq!(CurrentEraProviderBoostTotal::<Test>::get(), 200);
+
+		let events = staking_events();
+		assert_eq!(
+			events.last().unwrap(),
+			&Event::ProviderBoosted { account, target, amount, capacity }
+		);
+
+		assert_eq!(
+			<Test as Config>::Currency::balance_frozen(
+				&FreezeReason::CapacityStaking.into(),
+				&account
+			),
+			amount
+		);
+	});
+}
+
+#[test]
+fn provider_boost_errors_when_account_is_maximum_capacity_staker() {
+	new_test_ext().execute_with(|| {
+		let account = 600;
+		let target: MessageSourceId = 1;
+		register_provider(target, String::from("Foo"));
+		assert_ok!(Capacity::stake(RuntimeOrigin::signed(account), target, 100));
+
+		assert_noop!(
+			Capacity::provider_boost(RuntimeOrigin::signed(account), target, 100),
+			Error::<Test>::CannotChangeStakingType
+		);
+	});
+}
+
+#[test]
+fn stake_errors_when_account_is_provider_boost_staker() {
+	new_test_ext().execute_with(|| {
+		let account = 600;
+		let target: MessageSourceId = 1;
+		register_provider(target, String::from("Foo"));
+		assert_ok!(Capacity::provider_boost(RuntimeOrigin::signed(account), target, 100));
+
+		assert_noop!(
+			Capacity::stake(RuntimeOrigin::signed(account), target, 100),
+			Error::<Test>::CannotChangeStakingType
+		);
+	});
+}
+
+#[test]
+fn provider_boost_errors_with_invalid_target() {
+	new_test_ext().execute_with(|| {
+		let account = 600;
+		let target: MessageSourceId = 1;
+
+		assert_noop!(
+			Capacity::provider_boost(RuntimeOrigin::signed(account), target, 100),
+			Error::<Test>::InvalidTarget
+		);
+	});
+}
+
+#[test]
+fn provider_boost_in_a_later_era_adds_to_history() {
+	new_test_ext().execute_with(|| {
+		let account = 600;
+		let target: MessageSourceId = 1;
+		register_provider(target, String::from("Foo"));
+		assert_ok!(Capacity::provider_boost(RuntimeOrigin::signed(account), target, 100));
+
+		run_to_block(10);
+		assert_ok!(Capacity::provider_boost(RuntimeOrigin::signed(account), target, 50));
+
+		let history = ProviderBoostHistories::<Test>::get(account).unwrap();
+		assert_eq!(history.count(), 2);
+		assert_eq!(history.get_amount_staked_for_era(&0), 100);
+		assert_eq!(history.get_amount_staked_for_era(&1), 150);
+		assert_eq!(CurrentEraProviderBoostTotal::<Test>::get(), 150);
+	});
+}
+
+#[test]
+fn unstake_provider_boost_reduces_boosted_amount_and_capacity() {
+	new_test_ext().execute_with(|| {
+		let account = 600;
+		let target: MessageSourceId = 1;
+		register_provider(target, String::from("Foo"));
+		assert_ok!(Capacity::provider_boost(RuntimeOrigin::signed(account), target, 200));
+		assert_ok!(Capacity::unstake(RuntimeOrigin::signed(account), target, 100));
+
+		assert_eq!(
+			StakingTargetLedger::<Test>::get(account, target).unwrap(),
+			StakingTargetDetails { amount: 100, capacity: 5 }
+		);
+		assert_eq!(CapacityLedger::<Test>::get(target).unwrap().total_capacity_issued, 5);
+
+		let history = ProviderBoostHistories::<Test>::get(account).unwrap();
+		assert_eq!(history.get_amount_staked_for_era(&0), 100);
+		assert_eq!(CurrentEraProviderBoostTotal::<Test>::get(), 100);
+
+		let events = staking_events();
+		assert_eq!(
+			events.last().unwrap(),
+			&Event::UnStaked { account, target, amount: 100, capacity: 5 }
+		);
+	});
+}
+
+#[test]
+fn unstake_all_provider_boost_removes_history() {
+	new_test_ext().execute_with(|| {
+		let account = 600;
+		let target: MessageSourceId = 1;
+		register_provider(target, String::from("Foo"));
+		assert_ok!(Capacity::provider_boost(RuntimeOrigin::signed(account), target, 200));
+		assert_ok!(Capacity::unstake(RuntimeOrigin::signed(account), target, 200));
+
+		assert!(StakingAccountLedger::<Test>::get(account).is_none());
+		assert!(ProviderBoostHistories::<Test>::get(account).is_none());
+		assert_eq!(CurrentEraProviderBoostTotal::<Test>::get(), 0);
+
+		// With nothing staked, the account may switch to MaximumCapacity staking.
+		assert_ok!(Capacity::stake(RuntimeOrigin::signed(account), target, 100));
+	});
+}
+
+#[test]
+fn unstake_provider_boost_errors_with_unclaimed_rewards() {
+	new_test_ext().execute_with(|| {
+		let account = 600;
+		let target: MessageSourceId = 1;
+		register_provider(target, String::from("Foo"));
+		assert_ok!(Capacity::provider_boost(RuntimeOrigin::signed(account), target, 200));
+
+		// Era 1 is completed, so there is a reward for it.
+		run_to_block(20);
+		assert!(Capacity::has_unclaimed_rewards(&account));
+
+		assert_noop!(
+			Capacity::unstake(RuntimeOrigin::signed(account), target, 100),
+			Error::<Test>::MustFirstClaimRewards
+		);
+	});
+}
+
+#[test]
+fn provider_boost_errors_when_history_is_full() {
+	new_test_ext().execute_with(|| {
+		let account = 600;
+		let target: MessageSourceId = 1;
+		register_provider(target, String::from("Foo"));
+
+		// The mock allows 6 history entries, one per era boosted.
+		for era in 0..6 {
+			run_to_block(era * 10);
+			assert_ok!(Capacity::provider_boost(RuntimeOrigin::signed(account), target, 10));
+		}
+
+		run_to_block(60);
+		assert_noop!(
+			Capacity::provider_boost(RuntimeOrigin::signed(account), target, 10),
+			Error::<Test>::MaxRewardHistoryExceeded
+		);
+
+		assert_ok!(Capacity::claim_staking_rewards(RuntimeOrigin::signed(account)));
+		assert_ok!(Capacity::provider_boost(RuntimeOrigin::signed(account), target, 10));
+	});
+}
@@ -0,0 +1,87 @@
+use super::{mock::*, testing_utils::*};
+use crate::{
+	CurrentEraEligibleProviderBoostTotal, CurrentEraInfo, CurrentEraProviderBoostTotal,
+	ProviderBoostRewardPools, RewardEraInfo, RewardPoolInfo,
+};
+use common_primitives::msa::MessageSourceId;
+use frame_support::assert_ok;
+
+#[test]
+fn start_new_reward_era_if_needed_records_reward_pool() {
+	new_test_ext().execute_with(|| {
+		let account = 600;
+		let target: MessageSourceId = 1;
+		register_provider(target, String::from("Foo"));
+		assert_ok!(Capacity::provider_boost(RuntimeOrigin::signed(account), target, 200));
+
+		run_to_block(9);
+		assert_eq!(CurrentEraInfo::<Test>::get(), RewardEraInfo { era_index: 0, started_at: 0 });
+		assert_eq!(ProviderBoostRewardPools::<Test>::count(), 0);
+
+		run_to_block(10);
+		assert_eq!(CurrentEraInfo::<Test>::get(), RewardEraInfo { era_index: 1, started_at: 10 });
+		// Nothing was boosted for the whole of era 0
+		assert_eq!(
+			ProviderBoostRewardPools::<Test>::get(0).unwrap(),
+			RewardPoolInfo { total_staked_token: 0, total_reward_pool: 0, unclaimed_balance: 0 }
+		);
+		assert_eq!(CurrentEraProviderBoostTotal::<Test>::get(), 200);
+
+		run_to_block(20);
+		assert_eq!(
+			ProviderBoostRewardPools::<Test>::get(1).unwrap(),
+			RewardPoolInfo {
+				total_staked_token: 200,
+				total_reward_pool: 10_000,
+				unclaimed_balance: 10_000,
+			}
+		);
+	});
+}
+
+#[test]
+fn reward_pool_only_records_the_amount_boosted_for_the_whole_era() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let target: MessageSourceId = 1;
+		register_provider(target, String::from("Foo"));
+		assert_ok!(Capacity::provider_boost(RuntimeOrigin::signed(600), target, 200));
+		run_to_block(15);
+
+		// act
+		assert_ok!(Capacity::provider_boost(RuntimeOrigin::signed(500), target, 300));
+		assert_ok!(Capacity::unstake(RuntimeOrigin::signed(600), target, 50));
+		assert_ok!(Capacity::provider_boost(RuntimeOrigin::signed(600), target, 20));
+
+		// assert
+		assert_eq!(CurrentEraProviderBoostTotal::<Test>::get(), 470);
+		assert_eq!(CurrentEraEligibleProviderBoostTotal::<Test>::get(), 170);
+		run_to_block(20);
+		assert_eq!(ProviderBoostRewardPools::<Test>::get(1).unwrap().total_staked_token, 170);
+		assert_eq!(CurrentEraEligibleProviderBoostTotal::<Test>::get(), 470);
+	});
+}
+
+#[test]
+fn reward_pool_is_empty_when_nothing_is_boosted() {
+	new_test_ext().execute_with(|| {
+		run_to_block(10);
+		assert_eq!(
+			ProviderBoostRewardPools::<Test>::get(0).unwrap(),
+			RewardPoolInfo { total_staked_token: 0, total_reward_pool: 0, unclaimed_balance: 0 }
+		);
+	});
+}
+
+#[test]
+fn reward_pool_history_is_limited() {
+	new_test_ext().execute_with(|| {
+		// The mock keeps 6 eras of history; eras 0 through 7 end by block 80.
+		run_to_block(80);
+		assert_eq!(CurrentEraInfo::<Test>::get().era_index, 8);
+		assert_eq!(ProviderBoostRewardPools::<Test>::count(), 6);
+		assert!(ProviderBoostRewardPools::<Test>::get(1).is_none());
+		assert!(ProviderBoostRewardPools::<Test>::get(2).is_some());
+		assert!(ProviderBoostRewardPools::<Test>::get(7).is_some());
+	});
+}
@@ -0,0 +1,74 @@
+use super::{mock::*, testing_utils::*};
+use common_primitives::{
+	capacity::{CapacityDetailsResponse, StakingTargetResponse, UnlockChunkResponse},
+	msa::MessageSourceId,
+};
+use frame_support::assert_ok;
+
+#[test]
+fn get_capacity_for_provider_returns_none_without_stake() {
+	new_test_ext().execute_with(|| {
+		let target: MessageSourceId = 1;
+		register_provider(target, String::from("Foo"));
+
+		assert_eq!(Capacity::get_capacity_for_provider(target), None);
+	});
+}
+
+#[test]
+fn get_capacity_for_provider_returns_capacity_details() {
+	new_test_ext().execute_with(|| {
+		let target: MessageSourceId = 1;
+		register_provider(target, String::from("Foo"));
+		assert_ok!(Capacity::stake(RuntimeOrigin::signed(200), target, 100));
+
+		assert_eq!(
+			Capacity::get_capacity_for_provider(target),
+			Some(CapacityDetailsResponse {
+				remaining_capacity: 10,
+				total_tokens_staked: 100,
+				total_capacity_issued: 10,
+				last_replenished_epoch: 0,
+			})
+		);
+	});
+}
+
+#[test]
+fn get_stakes_for_account_returns_each_target() {
+	new_test_ext().execute_with(|| {
+		let account = 200;
+		register_provider(1, String::from("Foo"));
+		register_provider(2, String::from("Bar"));
+		assert_ok!(Capacity::stake(RuntimeOrigin::signed(account), 1, 100));
+		assert_ok!(Capacity::stake(RuntimeOrigin::signed(account), 2, 50));
+
+		let mut stakes = Capacity::get_stakes_for_account(&account);
+		stakes.sort_by_key(|stake| stake.target);
+		assert_eq!(
+			stakes,
+			vec![
+				StakingTargetResponse { target: 1, amount: 100, capacity: 10 },
+				StakingTargetResponse { target: 2, amount: 50, capacity: 5 },
+			]
+		);
+		assert!(Capacity::get_stakes_for_account(&300).is_empty());
+	});
+}
+
+#[test]
+fn get_unlock_chunks_returns_pending_chunks() {
+	new_test_ext().execute_with(|| {
+		let account = 200;
+		let target: MessageSourceId = 1;
+		register_provider(target, String::from("Foo"));
+		assert_ok!(Capacity::stake(RuntimeOrigin::signed(account), target, 100));
+		assert!(Capacity::get_unlock_chunks(&account).is_empty());
+
+		assert_ok!(Capacity::unstake(RuntimeOrigin::signed(account), target, 40));
+		assert_eq!(
+			Capacity::get_unlock_chunks(&account),
+			vec![UnlockChunkResponse { value: 40, thaw_at: 2 }]
+		);
+	});
+}
@@ -1,9 +1,12 @@
 use super::{mock::*, testing_utils::*};
 use crate::{
 	BalanceOf, CapacityDetails, CapacityLedger, Config, Error, Event, FreezeReason,
-	StakingAccountLedger, StakingDetails, StakingTargetLedger,
+	StakingAccountLedger, StakingDetails, StakingTargetLedger, StakingType::MaximumCapacity,
+};
+use common_primitives::{
+	capacity::Nontransferable,
+	msa::{MessageSourceId, ProviderId},
 };
-use common_primitives::{capacity::Nontransferable, msa::MessageSourceId};
 use frame_support::{assert_noop, assert_ok, traits::fungible::InspectFreeze};
 use sp_runtime::ArithmeticError;
 
@@ -63,6 +66,21 @@ fn stake_errors_invalid_target_when_target_is_not_registered_provider() {
 	});
 }
 
+#[test]
+fn stake_errors_invalid_target_when_target_is_retiring_provider() {
+	new_test_ext().execute_with(|| {
+		let account = 200;
+		let target: MessageSourceId = 1;
+		register_provider(target, String::from("Foo"));
+		pallet_msa::ProviderRetirements::<Test>::insert(ProviderId(target), 10);
+
+		assert_noop!(
+			Capacity::stake(RuntimeOrigin::signed(account), target, 50),
+			Error::<Test>::InvalidTarget
+		);
+	});
+}
+
 #[test]
 fn stake_errors_insufficient_staking_amount_when_staking_below_minimum_staking_amount() {
 	new_test_ext().execute_with(|| {
@@ -302,7 +320,7 @@ fn ensure_can_stake_errors_with_zero_amount_not_allowed() {
 		let target: MessageSourceId = 1;
 		let amount = 0;
 		assert_noop!(
-			Capacity::ensure_can_stake(&account, target, amount),
+			Capacity::ensure_can_stake(&account, target, amount, MaximumCapacity),
 			Error::<Test>::ZeroAmountNotAllowed
 		);
 	});
@@ -340,7 +358,7 @@ fn ensure_can_stake_errors_invalid_target() {
 		let amount = 1;
 
 		assert_noop!(
-			Capacity::ensure_can_stake(&account, target, amount),
+			Capacity::ensure_can_stake(&account, target, amount, MaximumCapacity),
 			Error::<Test>::InvalidTarget
 		);
 	});
@@ -355,7 +373,7 @@ fn ensure_can_stake_errors_insufficient_staking_amount() {
 		register_provider(target, String::from("Foo"));
 
 		assert_noop!(
-			Capacity::ensure_can_stake(&account, target, amount),
+			Capacity::ensure_can_stake(&account, target, amount, MaximumCapacity),
 			Error::<Test>::InsufficientStakingAmount
 		);
 	});
@@ -371,7 +389,7 @@ fn ensure_can_stake_is_successful() {
 
 		let staking_details = StakingDetails::<Test>::default();
 		assert_ok!(
-			Capacity::ensure_can_stake(&account, target, amount),
+			Capacity::ensure_can_stake(&account, target, amount, MaximumCapacity),
 			(staking_details, BalanceOf::<Test>::from(10u64))
 		);
 	});
@@ -4,7 +4,7 @@ use crate::{
 	CapacityDetails, CapacityLedger, FreezeReason, StakingAccountLedger, StakingDetails,
 	StakingTargetDetails, StakingTargetLedger, StakingType, UnlockChunk, UnstakeUnlocks,
 };
-use common_primitives::msa::MessageSourceId;
+use common_primitives::msa::{MessageSourceId, ProviderId};
 use frame_support::{
 	assert_noop, assert_ok,
 	traits::{fungible::InspectFreeze, Get},
@@ -90,6 +90,88 @@ fn unstake_happy_path() {
 // This test checks that when two accounts stake to a target, and one
 // account unstakes everything, that all the capacity generated is removed AND that
 // the remaining capacity is correct
+#[test]
+fn unstake_from_retiring_provider_works() {
+	new_test_ext().execute_with(|| {
+		let token_account = 200;
+		let target: MessageSourceId = 1;
+		register_provider(target, String::from("Test Target"));
+		assert_ok!(Capacity::stake(RuntimeOrigin::signed(token_account), target, 100));
+
+		pallet_msa::ProviderRetirements::<Test>::insert(ProviderId(target), 10);
+
+		assert_ok!(Capacity::unstake(RuntimeOrigin::signed(token_account), target, 100));
+		assert!(StakingTargetLedger::<Test>::get(token_account, target).is_none());
+	});
+}
+
+#[test]
+fn unstake_from_retired_provider_unstakes_everything_to_target() {
+	new_test_ext().execute_with(|| {
+		let token_account = 200;
+		let caller = 300;
+		let target: MessageSourceId = 1;
+		register_provider(target, String::from("Test Target"));
+		assert_ok!(Capacity::stake(RuntimeOrigin::signed(token_account), target, 100));
+
+		pallet_msa::ProviderRetirements::<Test>::insert(ProviderId(target), 10);
+
+		assert_ok!(Capacity::unstake_from_retired_provider(
+			RuntimeOrigin::signed(caller),
+			token_account,
+			target
+		));
+
+		assert!(StakingTargetLedger::<Test>::get(token_account, target).is_none());
+		assert_eq!(StakingAccountLedger::<Test>::get(token_account).unwrap().active, 0);
+		assert_eq!(
+			UnstakeUnlocks::<Test>::get(token_account).unwrap().into_inner(),
+			vec![UnlockChunk { value: 100u64, thaw_at: 2u32 }]
+		);
+		System::assert_last_event(
+			Event::UnStaked { account: token_account, target, amount: 100, capacity: 10 }.into(),
+		);
+	});
+}
+
+#[test]
+fn unstake_from_retired_provider_fails_when_target_is_valid() {
+	new_test_ext().execute_with(|| {
+		let token_account = 200;
+		let target: MessageSourceId = 1;
+		register_provider(target, String::from("Test Target"));
+		assert_ok!(Capacity::stake(RuntimeOrigin::signed(token_account), target, 100));
+
+		assert_noop!(
+			Capacity::unstake_from_retired_provider(
+				RuntimeOrigin::signed(300),
+				token_account,
+				target
+			),
+			Error::<Test>::TargetNotRetired
+		);
+	});
+}
+
+#[test]
+fn unstake_from_retired_provider_fails_without_stake_to_target() {
+	new_test_ext().execute_with(|| {
+		let token_account = 200;
+		let target: MessageSourceId = 1;
+		register_provider(target, String::from("Test Target"));
+		pallet_msa::ProviderRetirements::<Test>::insert(ProviderId(target), 10);
+
+		assert_noop!(
+			Capacity::unstake_from_retired_provider(
+				RuntimeOrigin::signed(300),
+				token_account,
+				target
+			),
+			Error::<Test>::StakerTargetRelationshipNotFound
+		);
+	});
+}
+
 #[test]
 fn unstaking_all_by_one_staker_reaps_target() {
 	new_test_ext().execute_with(|| {
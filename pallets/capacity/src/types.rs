@@ -1,10 +1,12 @@
 //! Types for the Capacity Pallet
 use super::*;
-use frame_support::{BoundedVec, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound};
+use frame_support::{
+	BoundedBTreeMap, BoundedVec, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_runtime::{
-	traits::{CheckedAdd, CheckedSub, Saturating, Zero},
+	traits::{CheckedAdd, CheckedSub, One, Saturating, Zero},
 	RuntimeDebug,
 };
 #[cfg(any(feature = "runtime-benchmarks", test))]
@@ -223,3 +225,200 @@ pub fn unlock_chunks_from_vec<T: Config>(chunks: &Vec<(u32, u32)>) -> UnlockChun
 	// CAUTION
 	BoundedVec::try_from(result).unwrap()
 }
+
+/// The type for storing information about a Provider Boost Reward Era.
+#[derive(
+	PartialEq, Eq, Clone, Copy, Default, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub struct RewardEraInfo<RewardEra, BlockNumber> {
+	/// The index of this era.
+	pub era_index: RewardEra,
+	/// The block number when this era started.
+	pub started_at: BlockNumber,
+}
+
+/// Information about the reward pool for a completed Reward Era.
+#[derive(PartialEq, Eq, Clone, Default, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RewardPoolInfo<Balance> {
+	/// The total amount of token boosted to Providers at the end of the associated Reward Era.
+	pub total_staked_token: Balance,
+	/// The size of the reward pool for the associated Reward Era.
+	pub total_reward_pool: Balance,
+	/// The amount of the reward pool that has not been claimed yet.
+	pub unclaimed_balance: Balance,
+}
+
+/// The history of a Provider Boost account's boosted amount, keyed by the Reward Era in which it changed.
+/// Each entry records the total boosted amount at the end of that era.
+#[derive(
+	TypeInfo, RuntimeDebugNoBound, PartialEqNoBound, EqNoBound, Clone, Decode, Encode, MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct ProviderBoostHistory<T: Config>(
+	BoundedBTreeMap<T::RewardEra, BalanceOf<T>, T::ProviderBoostHistoryLimit>,
+);
+
+impl<T: Config> Default for ProviderBoostHistory<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: Config> ProviderBoostHistory<T> {
+	/// Constructs a new empty ProviderBoostHistory
+	pub fn new() -> Self {
+		ProviderBoostHistory(BoundedBTreeMap::new())
+	}
+
+	/// Adds `add_amount` to the boosted amount recorded for `reward_era`, carrying forward
+	/// the most recent earlier entry if there is none for that era yet.
+	/// Returns the number of entries, or None if the amount overflows or the history is full.
+	pub fn add_era_balance(
+		&mut self,
+		reward_era: &T::RewardEra,
+		add_amount: &BalanceOf<T>,
+	) -> Option<usize> {
+		let new_amount = self.get_amount_staked_for_era(reward_era).checked_add(add_amount)?;
+		self.set_era_balance(reward_era, new_amount)?;
+		Some(self.count())
+	}
+
+	/// Subtracts `subtract_amount` from the boosted amount recorded for `reward_era`,
+	/// carrying forward the most recent earlier entry if there is none for that era yet.
+	/// Returns the number of entries, or None if the amount underflows or the history is full.
+	pub fn subtract_era_balance(
+		&mut self,
+		reward_era: &T::RewardEra,
+		subtract_amount: &BalanceOf<T>,
+	) -> Option<usize> {
+		let new_amount = self.get_amount_staked_for_era(reward_era).checked_sub(subtract_amount)?;
+		self.set_era_balance(reward_era, new_amount)?;
+		Some(self.count())
+	}
+
+	/// Returns the boosted amount at the end of `reward_era`, i.e. the value of the latest entry
+	/// at or before `reward_era`, or zero if there is none.
+	pub fn get_amount_staked_for_era(&self, reward_era: &T::RewardEra) -> BalanceOf<T> {
+		self.0
+			.iter()
+			.rev()
+			.find(|(era, _)| (*era).le(reward_era))
+			.map(|(_, amount)| *amount)
+			.unwrap_or_else(Zero::zero)
+	}
+
+	/// Returns the amount that was boosted for the entirety of `reward_era`: the lesser of the
+	/// amount at the end of the previous era and the amount at the end of `reward_era`.
+	pub fn get_eligible_amount_for_era(&self, reward_era: &T::RewardEra) -> BalanceOf<T> {
+		if reward_era.is_zero() {
+			return Zero::zero()
+		}
+		let previous_era = reward_era.saturating_sub(One::one());
+		self.get_amount_staked_for_era(&previous_era)
+			.min(self.get_amount_staked_for_era(reward_era))
+	}
+
+	/// Returns the earliest Reward Era recorded in this history, if any.
+	pub fn earliest_era(&self) -> Option<&T::RewardEra> {
+		self.0.keys().next()
+	}
+
+	/// Returns how many entries are in this history.
+	pub fn count(&self) -> usize {
+		self.0.len()
+	}
+
+	/// Collapses all entries before `current_era` into a single entry for the era just
+	/// before it, so that eras up to and including that era are treated as claimed.
+	pub fn mark_claimed_through(&mut self, current_era: &T::RewardEra) {
+		let last_claimed_era = current_era.saturating_sub(One::one());
+		let last_claimed_amount = self.get_amount_staked_for_era(&last_claimed_era);
+		self.0.retain(|era, _| era.ge(current_era));
+		if !last_claimed_amount.is_zero() {
+			// At most one entry remains, so there is always room for this one.
+			let _ = self.set_era_balance(&last_claimed_era, last_claimed_amount);
+		}
+	}
+
+	fn set_era_balance(&mut self, reward_era: &T::RewardEra, amount: BalanceOf<T>) -> Option<()> {
+		if let Some(entry) = self.0.get_mut(reward_era) {
+			*entry = amount;
+			return Some(())
+		}
+		self.0.try_insert(*reward_era, amount).ok().map(|_| ())
+	}
+}
+
+/// The type for tracking how many times an account has changed its staking target
+/// in a Reward Era.
+#[derive(
+	TypeInfo, RuntimeDebugNoBound, PartialEqNoBound, EqNoBound, Clone, Decode, Encode, MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct RetargetInfo<T: Config> {
+	/// The number of times the staking target was changed in `last_retarget_at`.
+	pub retarget_count: u32,
+	/// The last Reward Era in which the staking target was changed.
+	pub last_retarget_at: T::RewardEra,
+}
+
+impl<T: Config> Default for RetargetInfo<T> {
+	fn default() -> Self {
+		Self { retarget_count: 0u32, last_retarget_at: Zero::zero() }
+	}
+}
+
+impl<T: Config> RetargetInfo<T> {
+	/// Constructs a new RetargetInfo.
+	pub fn new(retarget_count: u32, last_retarget_at: T::RewardEra) -> Self {
+		Self { retarget_count, last_retarget_at }
+	}
+
+	/// Records a retarget in `current_era`, resetting the count if the last one was in an
+	/// earlier era. Returns None if the maximum number of retargets for the era is reached.
+	pub fn update(&mut self, current_era: T::RewardEra) -> Option<()> {
+		if self.last_retarget_at < current_era {
+			self.last_retarget_at = current_era;
+			self.retarget_count = 0;
+		}
+		if self.retarget_count >= T::MaxRetargetsPerRewardEra::get() {
+			return None
+		}
+		self.retarget_count = self.retarget_count.saturating_add(1);
+		Some(())
+	}
+}
+
+/// Details about an unclaimed Provider Boost reward for a single Reward Era.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct UnclaimedRewardInfo<Balance, RewardEra, BlockNumber> {
+	/// The Reward Era for which this reward was earned.
+	pub reward_era: RewardEra,
+	/// The last block at which this reward can be claimed.
+	pub expires_at_block: BlockNumber,
+	/// The amount that was boosted for the entirety of the Reward Era.
+	pub eligible_amount: Balance,
+	/// The amount in token of the reward.
+	pub earned_amount: Balance,
+}
+
+/// A trait that provides the Provider Boost economic model calculations and values.
+pub trait ProviderBoostRewardsProvider<T: Config> {
+	/// The type for currency.
+	type Balance;
+
+	/// Return the size of the reward pool for a Reward Era, in token.
+	fn reward_pool_size(total_staked: Self::Balance) -> Self::Balance;
+
+	/// Calculate the reward earned for a single Reward Era, given the amount staked by the
+	/// account, the total staked by all Provider Boost accounts and the size of the reward pool.
+	fn era_staking_reward(
+		era_amount_staked: Self::Balance,
+		era_total_staked: Self::Balance,
+		era_reward_pool_size: Self::Balance,
+	) -> Self::Balance;
+
+	/// Return the effective amount when `amount` is boosted, used to compute the reduced
+	/// Capacity issued to the Provider.
+	fn capacity_boost(amount: Self::Balance) -> Self::Balance;
+}
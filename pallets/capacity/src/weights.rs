@@ -38,6 +38,11 @@ pub trait WeightInfo {
 	fn on_initialize() -> Weight;
 	fn unstake() -> Weight;
 	fn set_epoch_length() -> Weight;
+	fn provider_boost() -> Weight;
+	fn claim_staking_rewards() -> Weight;
+	fn start_new_reward_era_if_needed() -> Weight;
+	fn change_staking_target() -> Weight;
+	fn unstake_from_retired_provider() -> Weight;
 }
 
 /// Weights for `pallet_capacity` using the Substrate node and recommended hardware.
@@ -123,6 +128,111 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(4_067_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:1 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::StakingAccountLedger` (r:1 w:1)
+	/// Proof: `Capacity::StakingAccountLedger` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::ProviderBoostHistories` (r:1 w:1)
+	/// Proof: `Capacity::ProviderBoostHistories` (`max_values`: None, `max_size`: Some(641), added: 3116, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::CurrentEraProviderBoostTotal` (r:1 w:1)
+	/// Proof: `Capacity::CurrentEraProviderBoostTotal` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::StakingTargetLedger` (r:1 w:1)
+	/// Proof: `Capacity::StakingTargetLedger` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::CapacityLedger` (r:1 w:1)
+	/// Proof: `Capacity::CapacityLedger` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::UnstakeUnlocks` (r:1 w:0)
+	/// Proof: `Capacity::UnstakeUnlocks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	fn provider_boost() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `247`
+		//  Estimated: `5259`
+		// Minimum execution time: 44_310_000 picoseconds.
+		Weight::from_parts(45_472_000, 5259)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Capacity::ProviderBoostHistories` (r:1 w:1)
+	/// Proof: `Capacity::ProviderBoostHistories` (`max_values`: None, `max_size`: Some(641), added: 3116, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::ProviderBoostRewardPools` (r:30 w:29)
+	/// Proof: `Capacity::ProviderBoostRewardPools` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn claim_staking_rewards() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2217`
+		//  Estimated: `76800`
+		// Minimum execution time: 162_930_000 picoseconds.
+		Weight::from_parts(167_451_000, 76800)
+			.saturating_add(T::DbWeight::get().reads(32_u64))
+			.saturating_add(T::DbWeight::get().writes(31_u64))
+	}
+	/// Storage: `Capacity::CurrentEraInfo` (r:1 w:1)
+	/// Proof: `Capacity::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::CurrentEraProviderBoostTotal` (r:1 w:0)
+	/// Proof: `Capacity::CurrentEraProviderBoostTotal` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::CounterForProviderBoostRewardPools` (r:1 w:1)
+	/// Proof: `Capacity::CounterForProviderBoostRewardPools` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::ProviderBoostRewardPools` (r:1 w:2)
+	/// Proof: `Capacity::ProviderBoostRewardPools` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn start_new_reward_era_if_needed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `156`
+		//  Estimated: `3517`
+		// Minimum execution time: 14_062_000 picoseconds.
+		Weight::from_parts(14_543_000, 3517)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Capacity::Retargets` (r:1 w:1)
+	/// Proof: `Capacity::Retargets` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:1 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::StakingAccountLedger` (r:1 w:0)
+	/// Proof: `Capacity::StakingAccountLedger` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::StakingTargetLedger` (r:2 w:2)
+	/// Proof: `Capacity::StakingTargetLedger` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::CapacityLedger` (r:2 w:2)
+	/// Proof: `Capacity::CapacityLedger` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn change_staking_target() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `353`
+		//  Estimated: `6078`
+		// Minimum execution time: 31_419_000 picoseconds.
+		Weight::from_parts(32_391_000, 6078)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:1 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderRetirements` (r:1 w:0)
+	/// Proof: `Msa::ProviderRetirements` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::StakingTargetLedger` (r:1 w:1)
+	/// Proof: `Capacity::StakingTargetLedger` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::StakingAccountLedger` (r:1 w:1)
+	/// Proof: `Capacity::StakingAccountLedger` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::UnstakeUnlocks` (r:1 w:1)
+	/// Proof: `Capacity::UnstakeUnlocks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::CapacityLedger` (r:1 w:1)
+	/// Proof: `Capacity::CapacityLedger` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::ProviderBoostHistories` (r:1 w:1)
+	/// Proof: `Capacity::ProviderBoostHistories` (`max_values`: None, `max_size`: Some(641), added: 3116, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::CurrentEraProviderBoostTotal` (r:1 w:1)
+	/// Proof: `Capacity::CurrentEraProviderBoostTotal` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::ProviderBoostRewardPools` (r:30 w:0)
+	/// Proof: `Capacity::ProviderBoostRewardPools` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn unstake_from_retired_provider() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2316`
+		//  Estimated: `79916`
+		// Minimum execution time: 88_157_000 picoseconds.
+		Weight::from_parts(91_830_000, 79916)
+			.saturating_add(T::DbWeight::get().reads(38_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -207,6 +317,111 @@ impl WeightInfo for () {
 		Weight::from_parts(4_067_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:1 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::StakingAccountLedger` (r:1 w:1)
+	/// Proof: `Capacity::StakingAccountLedger` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::ProviderBoostHistories` (r:1 w:1)
+	/// Proof: `Capacity::ProviderBoostHistories` (`max_values`: None, `max_size`: Some(641), added: 3116, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::CurrentEraProviderBoostTotal` (r:1 w:1)
+	/// Proof: `Capacity::CurrentEraProviderBoostTotal` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::StakingTargetLedger` (r:1 w:1)
+	/// Proof: `Capacity::StakingTargetLedger` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::CapacityLedger` (r:1 w:1)
+	/// Proof: `Capacity::CapacityLedger` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::UnstakeUnlocks` (r:1 w:0)
+	/// Proof: `Capacity::UnstakeUnlocks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	fn provider_boost() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `247`
+		//  Estimated: `5259`
+		// Minimum execution time: 44_310_000 picoseconds.
+		Weight::from_parts(45_472_000, 5259)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Capacity::ProviderBoostHistories` (r:1 w:1)
+	/// Proof: `Capacity::ProviderBoostHistories` (`max_values`: None, `max_size`: Some(641), added: 3116, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::ProviderBoostRewardPools` (r:30 w:29)
+	/// Proof: `Capacity::ProviderBoostRewardPools` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn claim_staking_rewards() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2217`
+		//  Estimated: `76800`
+		// Minimum execution time: 162_930_000 picoseconds.
+		Weight::from_parts(167_451_000, 76800)
+			.saturating_add(RocksDbWeight::get().reads(32_u64))
+			.saturating_add(RocksDbWeight::get().writes(31_u64))
+	}
+	/// Storage: `Capacity::CurrentEraInfo` (r:1 w:1)
+	/// Proof: `Capacity::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::CurrentEraProviderBoostTotal` (r:1 w:0)
+	/// Proof: `Capacity::CurrentEraProviderBoostTotal` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::CounterForProviderBoostRewardPools` (r:1 w:1)
+	/// Proof: `Capacity::CounterForProviderBoostRewardPools` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::ProviderBoostRewardPools` (r:1 w:2)
+	/// Proof: `Capacity::ProviderBoostRewardPools` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn start_new_reward_era_if_needed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `156`
+		//  Estimated: `3517`
+		// Minimum execution time: 14_062_000 picoseconds.
+		Weight::from_parts(14_543_000, 3517)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Capacity::Retargets` (r:1 w:1)
+	/// Proof: `Capacity::Retargets` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:1 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::StakingAccountLedger` (r:1 w:0)
+	/// Proof: `Capacity::StakingAccountLedger` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::StakingTargetLedger` (r:2 w:2)
+	/// Proof: `Capacity::StakingTargetLedger` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::CapacityLedger` (r:2 w:2)
+	/// Proof: `Capacity::CapacityLedger` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn change_staking_target() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `353`
+		//  Estimated: `6078`
+		// Minimum execution time: 31_419_000 picoseconds.
+		Weight::from_parts(32_391_000, 6078)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:1 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderRetirements` (r:1 w:0)
+	/// Proof: `Msa::ProviderRetirements` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::StakingTargetLedger` (r:1 w:1)
+	/// Proof: `Capacity::StakingTargetLedger` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::StakingAccountLedger` (r:1 w:1)
+	/// Proof: `Capacity::StakingAccountLedger` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::UnstakeUnlocks` (r:1 w:1)
+	/// Proof: `Capacity::UnstakeUnlocks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::CapacityLedger` (r:1 w:1)
+	/// Proof: `Capacity::CapacityLedger` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::ProviderBoostHistories` (r:1 w:1)
+	/// Proof: `Capacity::ProviderBoostHistories` (`max_values`: None, `max_size`: Some(641), added: 3116, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::CurrentEraProviderBoostTotal` (r:1 w:1)
+	/// Proof: `Capacity::CurrentEraProviderBoostTotal` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Capacity::ProviderBoostRewardPools` (r:30 w:0)
+	/// Proof: `Capacity::ProviderBoostRewardPools` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn unstake_from_retired_provider() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2316`
+		//  Estimated: `79916`
+		// Minimum execution time: 88_157_000 picoseconds.
+		Weight::from_parts(91_830_000, 79916)
+			.saturating_add(RocksDbWeight::get().reads(38_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
 }
 
 
@@ -280,4 +495,64 @@ mod tests {
 				> 4081
 		);
 	}
+	#[test]
+	fn test_provider_boost() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 5259
+		);
+	}
+	#[test]
+	fn test_claim_staking_rewards() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 76800
+		);
+	}
+	#[test]
+	fn test_start_new_reward_era_if_needed() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3517
+		);
+	}
+	#[test]
+	fn test_change_staking_target() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 6078
+		);
+	}
+	#[test]
+	fn test_unstake_from_retired_provider() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 79916
+		);
+	}
 }
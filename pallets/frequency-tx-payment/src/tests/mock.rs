@@ -5,13 +5,14 @@ use common_primitives::{
 	msa::MessageSourceId,
 	node::{AccountId, ProposalProvider},
 	schema::{SchemaId, SchemaValidator},
+	signatures::EIP712Domain,
 };
 use frame_system::EnsureSigned;
 use pallet_transaction_payment::FungibleAdapter;
 use sp_core::{ConstU8, H256};
 use sp_runtime::{
 	traits::{BlakeTwo256, Convert, IdentityLookup, SaturatedConversion},
-	AccountId32, BuildStorage, Perbill,
+	AccountId32, BuildStorage, Perbill, Permill,
 };
 
 use frame_support::{
@@ -37,7 +38,7 @@ frame_support::construct_runtime!(
 		{
 			System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
 			Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
-			Msa: pallet_msa::{Pallet, Call, Storage, Event<T>},
+			Msa: pallet_msa::{Pallet, Call, Storage, Event<T>, HoldReason},
 			Capacity: pallet_capacity::{Pallet, Call, Storage, Event<T>, FreezeReason},
 			TransactionPayment: pallet_transaction_payment::{Pallet, Storage, Event<T>},
 			RecurrencyTxPayment: pallet_recurrency_tx_payment::{Pallet, Call, Event<T>},
@@ -105,7 +106,7 @@ impl pallet_balances::Config for Test {
 	type FreezeIdentifier = RuntimeFreezeReason;
 	type RuntimeFreezeReason = ();
 	type MaxFreezes = ConstU32<1>;
-	type RuntimeHoldReason = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
 }
 
 pub type MaxSchemaGrantsPerDelegation = ConstU32<30>;
@@ -151,21 +152,43 @@ impl ProposalProvider<u64, RuntimeCall> for CouncilProposalProvider {
 	}
 }
 
+// Needs parameter_types! for the EIP712Domain
+parameter_types! {
+	pub const TestEip712Domain: EIP712Domain =
+		EIP712Domain { chain_id: 0x190f_1b44, verifying_contract: [0xCC; 20] };
+}
+
 impl pallet_msa::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type WeightInfo = ();
+	type Currency = pallet_balances::Pallet<Self>;
 	type ConvertIntoAccountId32 = TestAccountId;
 	type MaxPublicKeysPerMsa = ConstU8<255>;
 	type MaxSchemaGrantsPerDelegation = MaxSchemaGrantsPerDelegation;
 	type MaxProviderNameSize = ConstU32<16>;
+	type MaxProviderMetadataFieldSize = ConstU32<64>;
 	type SchemaValidator = Schemas;
 	type HandleProvider = ();
+	type MsaDataPurger = ();
+	type MsaDataMerger = ();
 	type MortalityWindowSize = ConstU32<100>;
+	type Eip712Domain = TestEip712Domain;
 	type Proposal = RuntimeCall;
 	type ProposalProvider = CouncilProposalProvider;
 	type CreateProviderViaGovernanceOrigin = EnsureSigned<u64>;
 	/// This MUST ALWAYS be MaxSignaturesPerBucket * NumberOfBuckets.
 	type MaxSignaturesStored = ConstU32<8000>;
+	type MaxRecoveryGuardians = ConstU32<5>;
+	type RecoveryDelay = ConstU32<10>;
+	type ProviderRetirementGracePeriod = ConstU32<10>;
+	type MaxSubDelegationDepth = ConstU32<3>;
+	type MaxSubDelegationsPerProvider = ConstU32<4>;
+	type MaxSubDelegationLookups = ConstU32<8>;
+	type DelegationRequestDeposit = ConstU64<10>;
+	type MaxPendingDelegationRequests = ConstU32<10>;
+	type MaxDelegationRequestLifetime = ConstU32<100>;
+	type MaxThresholdProposalsPerMsa = ConstU32<10>;
 }
 
 // Needs parameter_types! for the impls below
@@ -208,6 +231,7 @@ pub const TEST_TOKEN_PER_CAPACITY: u32 = 10;
 // Needs parameter_types! for the Perbill
 parameter_types! {
 	pub const TestCapacityPerToken: Perbill = Perbill::from_percent(TEST_TOKEN_PER_CAPACITY);
+	pub const TestRewardCap: Permill = Permill::from_parts(3_800);
 }
 
 impl pallet_capacity::Config for Test {
@@ -228,6 +252,13 @@ impl pallet_capacity::Config for Test {
 	type EpochNumber = u32;
 	type CapacityPerToken = TestCapacityPerToken;
 	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type RewardEra = u32;
+	type EraLength = ConstU32<10>;
+	type ProviderBoostHistoryLimit = ConstU32<6>;
+	type RewardsProvider = Capacity;
+	type RewardPoolPerEra = ConstU64<10_000>;
+	type RewardPercentCap = TestRewardCap;
+	type MaxRetargetsPerRewardEra = ConstU32<5>;
 }
 
 use pallet_balances::Call as BalancesCall;
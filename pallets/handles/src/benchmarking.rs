@@ -3,6 +3,7 @@
 
 use super::*;
 use crate::Pallet as Handles;
+use common_primitives::utils::wrap_binary_data;
 use frame_benchmarking::{benchmarks, whitelisted_caller};
 use frame_support::assert_ok;
 use frame_system::RawOrigin;
@@ -105,5 +106,20 @@ benchmarks! {
 		let stored_handle = Handles::<T>::get_handle_for_msa(delegator_msa_id.into());
 		assert!(stored_handle.is_none());
 	}
+	purge_msa_handle {
+		let caller: T::AccountId = whitelisted_caller();
+		let delegator_account_public = SignerId::generate_pair(None);
+		let (payload, proof, key, delegator_msa_id) = create_signed_claims_payload::<T>(delegator_account_public.clone(), 32);
+		assert_ok!(T::MsaBenchmarkHelper::add_key(delegator_msa_id.into(), caller.clone()));
+		assert_ok!(T::MsaBenchmarkHelper::add_key(delegator_msa_id.into(), key.clone()));
+		assert_ok!(Handles::<T>::claim_handle(RawOrigin::Signed(caller.clone()).into(), key.clone(), proof, payload));
+		assert!(Handles::<T>::get_handle_for_msa(delegator_msa_id.into()).is_some());
+	}: {
+		Handles::<T>::purge_msa_data(delegator_msa_id.into(), Weight::MAX);
+	}
+	verify {
+		assert!(Handles::<T>::get_handle_for_msa(delegator_msa_id.into()).is_none());
+	}
+
 	impl_benchmark_test_suite!(Handles, crate::tests::mock::new_test_ext_keystore(), crate::tests::mock::Test,);
 }
@@ -35,6 +35,7 @@ impl<T: Config + Send + Sync> HandlesSignedExtension<T> {
 	///
 	/// # Errors (as u8 wrapped by `InvalidTransaction::Custom`)
 	/// * [`Error::InvalidMessageSourceAccount`]
+	/// * [`Error::KeyScopeRestricted`]
 	/// * [`Error::MSAHandleDoesNotExist`]
 	fn validate_retire_handle(delegator_key: &T::AccountId) -> TransactionValidity {
 		const TAG_PREFIX: &str = "HandlesRetireHandle";
@@ -42,6 +43,9 @@ impl<T: Config + Send + Sync> HandlesSignedExtension<T> {
 		// Validation: The delegator must already have a MSA id
 		let delegator_msa_id = T::MsaInfoProvider::ensure_valid_msa_key(&delegator_key)
 			.map_err(|e| map_dispatch_error(e))?;
+		// Validation: The key must be allowed to manage the MSA
+		T::MsaInfoProvider::ensure_key_can_manage_msa(&delegator_key)
+			.map_err(|e| map_dispatch_error(e))?;
 		// Validation: The MSA must already have a handle associated with it
 		let handle_from_state = MSAIdToDisplayName::<T>::try_get(delegator_msa_id)
 			.map_err(|_| UnknownTransaction::CannotLookup)?;
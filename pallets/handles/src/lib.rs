@@ -49,18 +49,15 @@ mod tests;
 use common_primitives::benchmarks::MsaBenchmarkHelper;
 use common_primitives::{
 	handles::*,
-	msa::{MessageSourceId, MsaLookup, MsaValidator},
-	utils::wrap_binary_data,
+	msa::{MessageSourceId, MsaDataMerger, MsaDataPurger, MsaLookup, MsaValidator},
+	signatures::{self, EIP712Domain, EIP712Encode},
 };
 use frame_support::{dispatch::DispatchResult, ensure, pallet_prelude::*, traits::Get};
 use frame_system::pallet_prelude::*;
 use numtoa::*;
 pub use pallet::*;
 use sp_core::crypto::AccountId32;
-use sp_runtime::{
-	traits::{Convert, Verify},
-	DispatchError, MultiSignature,
-};
+use sp_runtime::{traits::Convert, DispatchError, MultiSignature};
 use sp_std::{prelude::*, vec::Vec};
 
 pub mod handles_signed_extension;
@@ -74,6 +71,65 @@ impl<T: Config> HandleProvider for Pallet<T> {
 	}
 }
 
+impl<T: Config> MsaDataPurger for Pallet<T> {
+	/// Retires the handle of `msa_id`, if it has one
+	fn purge_msa_data(msa_id: MessageSourceId, remaining_weight: Weight) -> (Weight, bool) {
+		let read_weight = T::DbWeight::get().reads(1);
+		if remaining_weight.any_lt(read_weight) {
+			return (Weight::zero(), false)
+		}
+		if !MSAIdToDisplayName::<T>::contains_key(msa_id) {
+			return (read_weight, true)
+		}
+		let purge_weight = T::WeightInfo::purge_msa_handle();
+		if remaining_weight.any_lt(purge_weight) {
+			return (read_weight, false)
+		}
+		match Self::do_retire_handle(msa_id) {
+			Ok(handle) => {
+				Self::deposit_event(Event::HandleRetired { msa_id, handle });
+			},
+			Err(e) => {
+				log::error!("Unable to retire the handle of purged MSA {:?}: {:?}", msa_id, e);
+			},
+		}
+		(purge_weight, true)
+	}
+}
+
+impl<T: Config> MsaDataMerger for Pallet<T> {
+	/// Moves the handle of `source_msa_id` to `target_msa_id`, unless the target already has a
+	/// handle. A handle that is not moved is retired when the source MSA is purged.
+	fn merge_msa_data(
+		source_msa_id: MessageSourceId,
+		target_msa_id: MessageSourceId,
+		limit: u32,
+	) -> (u32, bool) {
+		if !MSAIdToDisplayName::<T>::contains_key(source_msa_id) ||
+			MSAIdToDisplayName::<T>::contains_key(target_msa_id)
+		{
+			return (0, true)
+		}
+		if limit == 0 {
+			return (0, false)
+		}
+		match Self::do_move_handle(source_msa_id, target_msa_id) {
+			Ok(handle) => {
+				Self::deposit_event(Event::HandleRetired {
+					msa_id: source_msa_id,
+					handle: handle.clone(),
+				});
+				Self::deposit_event(Event::HandleClaimed { msa_id: target_msa_id, handle });
+				(1, true)
+			},
+			Err(e) => {
+				log::error!("Unable to move the handle of merged MSA {:?}: {:?}", source_msa_id, e);
+				(0, true)
+			},
+		}
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 
@@ -104,6 +160,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type MortalityWindowSize: Get<u32>;
 
+		/// The chain id and verifying contract of the EIP-712 domain that Ethereum keys sign typed data in
+		#[pallet::constant]
+		type Eip712Domain: Get<EIP712Domain>;
+
 		#[cfg(feature = "runtime-benchmarks")]
 		/// A set of helper functions for benchmarking.
 		type MsaBenchmarkHelper: MsaBenchmarkHelper<Self::AccountId>;
@@ -183,6 +243,8 @@ pub mod pallet {
 		HandleWithinMortalityPeriod,
 		/// The handle is invalid
 		InvalidHandle,
+		/// The scope of the MSA key does not allow it to manage the handle
+		KeyScopeRestricted,
 	}
 
 	#[pallet::event]
@@ -274,19 +336,28 @@ pub mod pallet {
 		///
 		/// * `signature` - The `MultiSignature` to verify against the payload.
 		/// * `signer` - The `T::AccountId` of the signer that signed the payload.
-		/// * `payload` - The payload to verify the signature against.
+		/// * `payload` - The payload to verify the signature against. Ethereum signers sign its
+		///   EIP-712 typed data, all other signers sign its wrapped SCALE encoding.
 		///
 		/// # Errors
 		/// * [`Error::InvalidSignature`]
-		pub fn verify_signed_payload(
+		pub fn verify_signed_payload<P: Encode + EIP712Encode>(
 			signature: &MultiSignature,
 			signer: &T::AccountId,
-			payload: Vec<u8>,
+			payload: &P,
 		) -> DispatchResult {
 			let key = T::ConvertIntoAccountId32::convert((*signer).clone());
-			let wrapped_payload = wrap_binary_data(payload);
 
-			ensure!(signature.verify(&wrapped_payload[..], &key), Error::<T>::InvalidSignature);
+			ensure!(
+				signatures::verify_signature(
+					signature,
+					&key,
+					payload,
+					payload.encode(),
+					&T::Eip712Domain::get()
+				),
+				Error::<T>::InvalidSignature
+			);
 
 			Ok(())
 		}
@@ -361,13 +432,17 @@ pub mod pallet {
 		///
 		/// * [`Error::InvalidHandleByteLength`] - The base_handle size exceeds the maximum allowed size.
 		/// * [`Error::InvalidMessageSourceAccount`] - The caller does not have a valid  `MessageSourceId`.
+		/// * [`Error::KeyScopeRestricted`] - The scope of `msa_owner_key` does not allow it to manage the MSA.
 		/// * [`Error::InvalidSignature`] - The payload signature verification fails.
 		///
 		/// # Events
 		/// * [`Event::HandleClaimed`]
 		///
 		#[pallet::call_index(0)]
-		#[pallet::weight(T::WeightInfo::claim_handle(payload.base_handle.len() as u32))]
+		#[pallet::weight(
+			T::WeightInfo::claim_handle(payload.base_handle.len() as u32)
+				.saturating_add(Pallet::<T>::key_scopes_read_weight())
+		)]
 		pub fn claim_handle(
 			origin: OriginFor<T>,
 			msa_owner_key: T::AccountId,
@@ -383,11 +458,15 @@ pub mod pallet {
 			let msa_id = T::MsaInfoProvider::ensure_valid_msa_key(&msa_owner_key)
 				.map_err(|_| Error::<T>::InvalidMessageSourceAccount)?;
 
+			// Validation: the MSA key must be allowed to manage the MSA
+			T::MsaInfoProvider::ensure_key_can_manage_msa(&msa_owner_key)
+				.map_err(|_| Error::<T>::KeyScopeRestricted)?;
+
 			// Validation: The signature is within the mortality window
 			Self::verify_signature_mortality(payload.expiration)?;
 
 			// Validation: Verify the payload was signed
-			Self::verify_signed_payload(&proof, &msa_owner_key, payload.encode())?;
+			Self::verify_signed_payload(&proof, &msa_owner_key, &payload)?;
 
 			let display_handle = Self::do_claim_handle(msa_id, payload)?;
 
@@ -407,12 +486,17 @@ pub mod pallet {
 		///
 		/// * `InvalidHandleByteLength` - If the length of the `payload.display_handle` exceeds the maximum allowed size.
 		/// * `InvalidMessageSourceAccount` - If caller of this extrinsic does not have a valid MSA (Message Source Account) ID.
+		/// * `KeyScopeRestricted` - If the scope of the caller's key does not allow it to manage the MSA.
 		///
 		/// # Events
 		/// * [`Event::HandleRetired`]
 		///
 		#[pallet::call_index(1)]
-		#[pallet::weight((T::WeightInfo::retire_handle(), DispatchClass::Normal, Pays::No))]
+		#[pallet::weight((
+			T::WeightInfo::retire_handle().saturating_add(Pallet::<T>::key_scopes_read_weight()),
+			DispatchClass::Normal,
+			Pays::No
+		))]
 		pub fn retire_handle(origin: OriginFor<T>) -> DispatchResult {
 			let msa_owner_key = ensure_signed(origin)?;
 
@@ -420,6 +504,10 @@ pub mod pallet {
 			let msa_id = T::MsaInfoProvider::ensure_valid_msa_key(&msa_owner_key)
 				.map_err(|_| Error::<T>::InvalidMessageSourceAccount)?;
 
+			// Validation: the MSA key must be allowed to manage the MSA
+			T::MsaInfoProvider::ensure_key_can_manage_msa(&msa_owner_key)
+				.map_err(|_| Error::<T>::KeyScopeRestricted)?;
+
 			let display_handle: Vec<u8> = Self::do_retire_handle(msa_id)?;
 
 			Self::deposit_event(Event::HandleRetired { msa_id, handle: display_handle });
@@ -446,6 +534,7 @@ pub mod pallet {
 		///
 		/// * [`Error::InvalidHandleByteLength`] - The base_handle size exceeds the maximum allowed size.
 		/// * [`Error::InvalidMessageSourceAccount`] - The caller does not have a valid  `MessageSourceId`.
+		/// * [`Error::KeyScopeRestricted`] - The scope of `msa_owner_key` does not allow it to manage the MSA.
 		/// * [`Error::InvalidSignature`] - The payload signature verification fails.
 		///
 		/// # Events
@@ -453,7 +542,10 @@ pub mod pallet {
 		/// * [`Event::HandleClaimed`]
 		///
 		#[pallet::call_index(2)]
-		#[pallet::weight(T::WeightInfo::change_handle(payload.base_handle.len() as u32))]
+		#[pallet::weight(
+			T::WeightInfo::change_handle(payload.base_handle.len() as u32)
+				.saturating_add(Pallet::<T>::key_scopes_read_weight())
+		)]
 		pub fn change_handle(
 			origin: OriginFor<T>,
 			msa_owner_key: T::AccountId,
@@ -469,11 +561,15 @@ pub mod pallet {
 			let msa_id = T::MsaInfoProvider::ensure_valid_msa_key(&msa_owner_key)
 				.map_err(|_| Error::<T>::InvalidMessageSourceAccount)?;
 
+			// Validation: the MSA key must be allowed to manage the MSA
+			T::MsaInfoProvider::ensure_key_can_manage_msa(&msa_owner_key)
+				.map_err(|_| Error::<T>::KeyScopeRestricted)?;
+
 			// Validation: The signature is within the mortality window
 			Self::verify_signature_mortality(payload.expiration)?;
 
 			// Validation: Verify the payload was signed
-			Self::verify_signed_payload(&proof, &msa_owner_key, payload.encode())?;
+			Self::verify_signed_payload(&proof, &msa_owner_key, &payload)?;
 
 			// Get existing handle to retire
 			MSAIdToDisplayName::<T>::get(msa_id).ok_or(Error::<T>::MSAHandleDoesNotExist)?;
@@ -491,6 +587,12 @@ pub mod pallet {
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// The weight of checking the scopes of the MSA owner key, which the generated weights do
+		/// not cover
+		fn key_scopes_read_weight() -> Weight {
+			T::DbWeight::get().reads(1).saturating_add(Weight::from_parts(0, 2_578))
+		}
+
 		/// Retrieves a handle for a given MSA (MessageSourceId).
 		///
 		/// # Arguments
@@ -751,6 +853,37 @@ pub mod pallet {
 			Ok(display_name_str.as_bytes().to_vec())
 		}
 
+		/// Moves the handle of `source_msa_id` to `target_msa_id`, keeping its suffix and expiration
+		pub fn do_move_handle(
+			source_msa_id: MessageSourceId,
+			target_msa_id: MessageSourceId,
+		) -> Result<Vec<u8>, DispatchError> {
+			let handle_from_state = MSAIdToDisplayName::<T>::get(source_msa_id)
+				.ok_or(Error::<T>::MSAHandleDoesNotExist)?;
+			ensure!(
+				!MSAIdToDisplayName::<T>::contains_key(target_msa_id),
+				Error::<T>::MSAHandleAlreadyExists
+			);
+			let display_name_str = core::str::from_utf8(&handle_from_state.0)
+				.map_err(|_| Error::<T>::InvalidHandleEncoding)?;
+
+			let (base_handle_str, suffix_num) =
+				split_display_name(display_name_str).ok_or(Error::<T>::InvalidHandle)?;
+			let (_, canonical_base) =
+				Self::get_canonical_string_vec_from_base_handle(&base_handle_str);
+
+			let display_name = display_name_str.as_bytes().to_vec();
+			MSAIdToDisplayName::<T>::remove(source_msa_id);
+			MSAIdToDisplayName::<T>::insert(target_msa_id, handle_from_state);
+			CanonicalBaseHandleAndSuffixToMSAId::<T>::insert(
+				canonical_base,
+				suffix_num,
+				target_msa_id,
+			);
+
+			Ok(display_name)
+		}
+
 		/// Checks whether the supplied handle passes all the checks performed by a
 		/// claim_handle call.
 		/// # Returns
@@ -1,8 +1,13 @@
 use crate::{tests::mock::*, Error, Event};
-use common_primitives::{handles::HANDLE_BYTES_MAX, msa::MessageSourceId};
+use common_primitives::{
+	handles::{ClaimHandlePayload, HANDLE_BYTES_MAX},
+	msa::MessageSourceId,
+	signatures::{ethereum_address_of, ethereum_address_to_account_id, sign_eip_712},
+	utils::wrap_binary_data,
+};
 use frame_support::{assert_err, assert_noop, assert_ok, dispatch::DispatchResult};
 use parity_scale_codec::Decode;
-use sp_core::{sr25519, Encode, Pair};
+use sp_core::{ecdsa, sr25519, Encode, Pair};
 use sp_std::collections::btree_set::BTreeSet;
 
 struct TestCase<T> {
@@ -42,6 +47,66 @@ fn claim_handle_happy_path() {
 	});
 }
 
+#[test]
+fn claim_handle_with_key_scoped_from_managing_msa_fails() {
+	new_test_ext().execute_with(|| {
+		let scoped_key_pair = sr25519::Pair::from_seed(&SCOPED_KEY_SEED);
+		let (payload, proof) =
+			get_signed_claims_payload(&scoped_key_pair, "test1".as_bytes().to_vec(), 100);
+
+		assert_noop!(
+			Handles::claim_handle(
+				RuntimeOrigin::signed(scoped_key_pair.public().into()),
+				scoped_key_pair.public().into(),
+				proof,
+				payload
+			),
+			Error::<Test>::KeyScopeRestricted
+		);
+	});
+}
+
+#[test]
+fn claim_handle_with_ethereum_key_signing_eip_712_payload() {
+	new_test_ext().execute_with(|| {
+		let (pair, _) = ecdsa::Pair::generate();
+		let ethereum_key = ethereum_address_to_account_id(&ethereum_address_of(&pair));
+		let payload = ClaimHandlePayload::new("test1".as_bytes().to_vec(), 100u32);
+		let proof = sign_eip_712(&pair, &TestEip712Domain::get(), &payload);
+
+		assert_ok!(Handles::claim_handle(
+			RuntimeOrigin::signed(ethereum_key.clone()),
+			ethereum_key.clone(),
+			proof,
+			payload
+		));
+
+		let msa_id = MessageSourceId::decode(&mut &ethereum_key.encode()[..]).unwrap();
+		let handle = create_full_handle_for_index("test1", 0);
+		System::assert_last_event(Event::HandleClaimed { msa_id, handle }.into());
+	});
+}
+
+#[test]
+fn claim_handle_with_ethereum_key_signing_scale_payload_fails() {
+	new_test_ext().execute_with(|| {
+		let (pair, _) = ecdsa::Pair::generate();
+		let ethereum_key = ethereum_address_to_account_id(&ethereum_address_of(&pair));
+		let payload = ClaimHandlePayload::new("test1".as_bytes().to_vec(), 100u32);
+		let proof = pair.sign(&wrap_binary_data(payload.encode())).into();
+
+		assert_noop!(
+			Handles::claim_handle(
+				RuntimeOrigin::signed(ethereum_key.clone()),
+				ethereum_key,
+				proof,
+				payload
+			),
+			Error::<Test>::InvalidSignature
+		);
+	});
+}
+
 #[test]
 fn claim_handle_already_claimed() {
 	new_test_ext().execute_with(|| {
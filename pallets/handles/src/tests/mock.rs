@@ -4,19 +4,25 @@ use parity_scale_codec::Decode;
 
 use common_primitives::{
 	handles::*,
-	msa::{MessageSourceId, MsaLookup, MsaValidator},
+	msa::{MessageSourceId, MsaLookup, MsaValidator, SchemaId},
 	node::AccountId,
+	signatures::EIP712Domain,
 	utils::wrap_binary_data,
 };
-use frame_support::traits::{ConstU16, ConstU32, OnFinalize, OnInitialize};
+use frame_support::{
+	parameter_types,
+	traits::{ConstU16, ConstU32, OnFinalize, OnInitialize},
+};
 use sp_core::{crypto::AccountId32, sr25519, ByteArray, Encode, Pair, H256};
 use sp_runtime::{
 	traits::{BlakeTwo256, ConvertInto, IdentityLookup},
-	BuildStorage, DispatchError, MultiSignature,
+	BuildStorage, DispatchError, DispatchResult, MultiSignature,
 };
 
 use handles_utils::converter::convert_to_canonical;
 pub const INVALID_MSA_ID: MessageSourceId = 100;
+/// Seed of a key whose scope does not allow it to manage its MSA
+pub const SCOPED_KEY_SEED: [u8; 32] = [7; 32];
 
 type Block = frame_system::mocking::MockBlockU32<Test>;
 
@@ -44,6 +50,18 @@ impl MsaValidator for MsaInfoHandler {
 
 		Ok(MessageSourceId::decode(&mut key.as_slice()).unwrap())
 	}
+
+	fn ensure_key_can_manage_msa(key: &Self::AccountId) -> DispatchResult {
+		if *key == sr25519::Pair::from_seed(&SCOPED_KEY_SEED).public().into() {
+			return Err(DispatchError::Other("key scope restricted"));
+		}
+
+		Ok(())
+	}
+
+	fn ensure_key_allows_schema(_key: &Self::AccountId, _schema_id: SchemaId) -> DispatchResult {
+		Ok(())
+	}
 }
 
 // Configure a mock runtime to test the pallet.
@@ -87,6 +105,12 @@ impl frame_system::Config for Test {
 	type PostTransactions = ();
 }
 
+// Needs parameter_types! for the EIP712Domain
+parameter_types! {
+	pub const TestEip712Domain: EIP712Domain =
+		EIP712Domain { chain_id: 0x190f_1b44, verifying_contract: [0xCC; 20] };
+}
+
 impl pallet_handles::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 
@@ -107,6 +131,7 @@ impl pallet_handles::Config for Test {
 
 	/// The mortality window for a handle claim
 	type MortalityWindowSize = ConstU32<150>;
+	type Eip712Domain = TestEip712Domain;
 
 	/// A set of helper functions for benchmarking.
 	#[cfg(feature = "runtime-benchmarks")]
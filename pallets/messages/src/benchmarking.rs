@@ -74,7 +74,7 @@ benchmarks! {
 			assert_ok!(create_schema::<T>(PayloadLocation::OnChain));
 		}
 		assert_ok!(T::MsaBenchmarkHelper::add_key(ProviderId(1).into(), caller.clone()));
-		assert_ok!(T::MsaBenchmarkHelper::set_delegation_relationship(ProviderId(1), message_source_id.into(), [schema_id].to_vec()));
+		assert_ok!(T::MsaBenchmarkHelper::set_sub_delegated_relationship(ProviderId(1), message_source_id.into(), [schema_id].to_vec()));
 
 		let payload = vec![1; n as usize];
 		for j in 1 .. MAX_MESSAGES_IN_BLOCK {
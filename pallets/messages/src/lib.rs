@@ -38,7 +38,8 @@ use sp_std::{convert::TryInto, fmt::Debug, prelude::*};
 use common_primitives::{
 	messages::*,
 	msa::{
-		DelegatorId, MessageSourceId, MsaLookup, MsaValidator, ProviderId, SchemaGrantValidator,
+		DelegatorId, MessageSourceId, MsaLookup, MsaValidator, ProviderId, SchemaGrantPermission,
+		SchemaGrantValidator,
 	},
 	schema::*,
 };
@@ -145,6 +146,9 @@ pub mod pallet {
 
 		/// Invalid CID
 		InvalidCid,
+
+		/// The schema is retired and no longer accepts new messages
+		SchemaRetired,
 	}
 
 	#[pallet::event]
@@ -187,6 +191,7 @@ pub mod pallet {
 		/// * [`Error::ExceedsMaxMessagePayloadSizeBytes`] - Payload is too large
 		/// * [`Error::InvalidSchemaId`] - Schema not found
 		/// * [`Error::InvalidPayloadLocation`] - The schema is not an IPFS payload location
+		/// * [`Error::SchemaRetired`] - The schema no longer accepts new messages
 		/// * [`Error::InvalidMessageSourceAccount`] - Origin must be from an MSA
 		/// * [`Error::TypeConversionOverflow`] - Failed to add the message to storage as it is very full
 		/// * [`Error::UnsupportedCidVersion`] - CID version is not supported (V0)
@@ -213,6 +218,7 @@ pub mod pallet {
 					schema.payload_location == PayloadLocation::IPFS,
 					Error::<T>::InvalidPayloadLocation
 				);
+				ensure!(schema.status != SchemaStatus::Retired, Error::<T>::SchemaRetired);
 
 				let provider_msa_id = Self::find_msa_id(&provider_key)?;
 				let current_block = frame_system::Pallet::<T>::block_number();
@@ -240,12 +246,16 @@ pub mod pallet {
 		/// * [`Error::ExceedsMaxMessagePayloadSizeBytes`] - Payload is too large
 		/// * [`Error::InvalidSchemaId`] - Schema not found
 		/// * [`Error::InvalidPayloadLocation`] - The schema is not an IPFS payload location
+		/// * [`Error::SchemaRetired`] - The schema no longer accepts new messages
 		/// * [`Error::InvalidMessageSourceAccount`] - Origin must be from an MSA
 		/// * [`Error::UnAuthorizedDelegate`] - Trying to add a message without a proper delegation between the origin and the on_behalf_of MSA
 		/// * [`Error::TypeConversionOverflow`] - Failed to add the message to storage as it is very full
 		///
 		#[pallet::call_index(1)]
-		#[pallet::weight(T::WeightInfo::add_onchain_message(payload.len() as u32))]
+		#[pallet::weight(
+			T::WeightInfo::add_onchain_message(payload.len() as u32)
+				.saturating_add(Pallet::<T>::sub_delegation_weight())
+		)]
 		pub fn add_onchain_message(
 			origin: OriginFor<T>,
 			on_behalf_of: Option<MessageSourceId>,
@@ -262,6 +272,7 @@ pub mod pallet {
 					schema.payload_location == PayloadLocation::OnChain,
 					Error::<T>::InvalidPayloadLocation
 				);
+				ensure!(schema.status != SchemaStatus::Retired, Error::<T>::SchemaRetired);
 
 				let provider_msa_id = Self::find_msa_id(&provider_key)?;
 				let provider_id = ProviderId(provider_msa_id);
@@ -276,6 +287,7 @@ pub mod pallet {
 							delegator_id,
 							schema_id,
 							current_block,
+							SchemaGrantPermission::Create,
 						)
 						.map_err(|_| Error::<T>::UnAuthorizedDelegate)?;
 						delegator_id
@@ -302,6 +314,15 @@ pub mod pallet {
 }
 
 impl<T: Config> Pallet<T> {
+	/// The weight of resolving a delegation through sub-delegations, which the generated weights
+	/// do not cover: up to 8 more delegations and 9 sub-delegations and Provider retirements are
+	/// read
+	fn sub_delegation_weight() -> Weight {
+		T::DbWeight::get()
+			.reads(8 + 9 + 9)
+			.saturating_add(Weight::from_parts(9_000_000, 8 * 2_692 + 9 * (3_660 + 2_495)))
+	}
+
 	/// Stores a message for a given schema id.
 	/// returns true if it needs to emit an event
 	/// # Errors
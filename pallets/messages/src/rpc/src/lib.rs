@@ -18,7 +18,7 @@ use jsonrpsee::{
 	types::{ErrorObject, ErrorObjectOwned},
 };
 use pallet_messages_runtime_api::MessagesRuntimeApi;
-use sp_api::ProvideRuntimeApi;
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_runtime::traits::Block as BlockT;
 use std::sync::Arc;
@@ -29,13 +29,13 @@ mod tests;
 /// Recurrency Messages Custom RPC API
 #[rpc(client, server)]
 pub trait MessagesApi {
-	/// Retrieve paginated messages by schema id
+	/// Retrieve paginated messages by schema id, with a warning when the schema is deprecated
 	#[method(name = "messages_getBySchemaId")]
 	fn get_messages_by_schema_id(
 		&self,
 		schema_id: SchemaId,
 		pagination: BlockPaginationRequest,
-	) -> RpcResult<BlockPaginationResponse<MessageResponse>>;
+	) -> RpcResult<WithSchemaWarning<BlockPaginationResponse<MessageResponse>>>;
 }
 
 /// The client handler for the API used by Recurrency Service RPC with `jsonrpsee`
@@ -84,7 +84,7 @@ where
 		&self,
 		schema_id: SchemaId,
 		pagination: BlockPaginationRequest,
-	) -> RpcResult<BlockPaginationResponse<MessageResponse>> {
+	) -> RpcResult<WithSchemaWarning<BlockPaginationResponse<MessageResponse>>> {
 		// Request Validation
 		ensure!(pagination.validate(), MessageRpcError::InvalidPaginationRequest);
 
@@ -93,7 +93,16 @@ where
 		let at = self.client.info().best_hash;
 
 		// Schema Fetch and Check
-		let schema: SchemaResponse = match api.get_schema_by_id(at, schema_id) {
+		let schema_result = match api.api_version::<dyn MessagesRuntimeApi<Block>>(at) {
+			// Runtimes before version 2 have no schema status
+			Ok(Some(version)) if version < 2 => {
+				#[allow(deprecated)]
+				let schema = api.get_schema_by_id_before_version_2(at, schema_id);
+				schema.map(|schema| schema.map(SchemaResponse::from))
+			},
+			_ => api.get_schema_by_id(at, schema_id),
+		};
+		let schema: SchemaResponse = match schema_result {
 			Ok(Some(s)) => s,
 			_ => fail!(MessageRpcError::InvalidSchemaId),
 		};
@@ -132,6 +141,6 @@ where
 			from_index = 0;
 		}
 
-		map_rpc_result(Ok(response))
+		map_rpc_result(Ok(WithSchemaWarning::new(response, schema_id, schema.status)))
 	}
 }
@@ -9,6 +9,7 @@ use std::sync::Arc;
 
 const SCHEMA_ID_EMPTY: u16 = 1;
 const SCHEMA_ID_HAS_MESSAGES: u16 = 2;
+const SCHEMA_ID_DEPRECATED: u16 = 3;
 const DUMMY_CID: &str = "bafkreidgvpkjawlxz6sffxzwgooowe5yt7i6wsyg236mfoks77nywkptdq";
 
 fn test_messages() -> Vec<MessageResponse> {
@@ -37,16 +38,19 @@ fn test_messages() -> Vec<MessageResponse> {
 sp_api::mock_impl_runtime_apis! {
 	impl MessagesRuntimeApi<Block> for TestRuntimeApi {
 		fn get_schema_by_id(schema_id: SchemaId) -> Option<SchemaResponse> {
-			match schema_id {
-				SCHEMA_ID_EMPTY | SCHEMA_ID_HAS_MESSAGES => Some(SchemaResponse {
-					schema_id,
-					model: b"schema".to_vec(),
-					model_type: ModelType::AvroBinary,
-					payload_location: PayloadLocation::OnChain,
-					settings: Vec::new(),
-				}),
-				_ => None,
-			}
+			let status = match schema_id {
+				SCHEMA_ID_EMPTY | SCHEMA_ID_HAS_MESSAGES => SchemaStatus::Active,
+				SCHEMA_ID_DEPRECATED => SchemaStatus::Deprecated,
+				_ => return None,
+			};
+			Some(SchemaResponse {
+				schema_id,
+				model: b"schema".to_vec(),
+				model_type: ModelType::AvroBinary,
+				payload_location: PayloadLocation::OnChain,
+				settings: Vec::new(),
+				status,
+			})
 		}
 
 		fn get_messages_by_schema_and_block(schema_id: SchemaId, _schema_payload_location: PayloadLocation, _block_number: BlockNumber) ->
@@ -60,8 +64,10 @@ sp_api::mock_impl_runtime_apis! {
 }
 
 type GetMessagesBySchemaResult = Result<
-	common_primitives::messages::BlockPaginationResponse<
-		common_primitives::messages::MessageResponse,
+	WithSchemaWarning<
+		common_primitives::messages::BlockPaginationResponse<
+			common_primitives::messages::MessageResponse,
+		>,
 	>,
 	jsonrpsee::types::ErrorObjectOwned,
 >;
@@ -106,6 +112,8 @@ async fn get_messages_by_schema_with_success() {
 
 	assert_eq!(true, result.is_ok());
 	let response = result.unwrap();
+	assert_eq!(None, response.warning);
+	let response = response.response;
 	// Because the page size is set to 2, we only get one set of messages
 	assert_eq!(test_messages(), response.content);
 	// There is more because we haven't done all the blocks yet
@@ -114,3 +122,18 @@ async fn get_messages_by_schema_with_success() {
 	assert_eq!(Some(2), response.next_block);
 	assert_eq!(Some(0), response.next_index);
 }
+
+#[tokio::test]
+async fn get_messages_by_deprecated_schema_should_warn() {
+	let client = Arc::new(TestApi {});
+	let api = MessagesHandler::new(client);
+
+	let result: GetMessagesBySchemaResult = api.get_messages_by_schema_id(
+		SCHEMA_ID_DEPRECATED, // Schema Id
+		BlockPaginationRequest { from_block: 1, to_block: 5, from_index: 0, page_size: 2 },
+	);
+
+	let response = result.unwrap();
+	assert!(response.warning.is_some());
+	assert_eq!(true, response.response.content.is_empty());
+}
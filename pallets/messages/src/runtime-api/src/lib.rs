@@ -28,7 +28,7 @@ sp_api::decl_runtime_apis! {
 	/// - MUST be incremented if anything changes
 	/// - Also update in js/api-augment
 	/// - See: https://paritytech.github.io/polkadot/doc/polkadot_primitives/runtime_api/index.html
-	#[api_version(1)]
+	#[api_version(2)]
 
 	/// Runtime APIs for [Messages](../pallet_messages/index.html)
 	pub trait MessagesRuntimeApi
@@ -37,6 +37,10 @@ sp_api::decl_runtime_apis! {
 		fn get_messages_by_schema_and_block(schema_id: SchemaId, schema_payload_location: PayloadLocation, block_number: BlockNumber) ->
 			Vec<MessageResponse>;
 
+		/// Retrieve a schema by id
+		#[changed_in(2)]
+		fn get_schema_by_id(schema_id: SchemaId) -> Option<SchemaResponseV2>;
+
 		/// Retrieve a schema by id
 		fn get_schema_by_id(schema_id: SchemaId) -> Option<SchemaResponse>;
 	}
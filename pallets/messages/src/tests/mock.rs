@@ -2,7 +2,7 @@ use crate as pallet_messages;
 use common_primitives::{
 	msa::{
 		Delegation, DelegationValidator, DelegatorId, MessageSourceId, MsaLookup, MsaValidator,
-		ProviderId, ProviderLookup, SchemaGrantValidator,
+		ProviderId, ProviderLookup, SchemaGrantPermission, SchemaGrantValidator,
 	},
 	schema::*,
 };
@@ -25,6 +25,7 @@ type Block = frame_system::mocking::MockBlockU32<Test>;
 
 pub const INVALID_SCHEMA_ID: SchemaId = 65534;
 pub const IPFS_SCHEMA_ID: SchemaId = 50;
+pub const RETIRED_SCHEMA_ID: SchemaId = 51;
 
 pub const IPFS_PAYLOAD_LENGTH: u32 = 1200;
 
@@ -145,6 +146,14 @@ impl MsaValidator for MsaInfoHandler {
 
 		Ok(get_msa_from_account(*key))
 	}
+
+	fn ensure_key_can_manage_msa(_key: &Self::AccountId) -> DispatchResult {
+		Ok(())
+	}
+
+	fn ensure_key_allows_schema(_key: &Self::AccountId, _schema_id: SchemaId) -> DispatchResult {
+		Ok(())
+	}
 }
 impl ProviderLookup for DelegationInfoHandler {
 	type BlockNumber = u32;
@@ -187,6 +196,7 @@ impl<BlockNumber> SchemaGrantValidator<BlockNumber> for SchemaGrantValidationHan
 		delegator: DelegatorId,
 		_schema_id: SchemaId,
 		_block_number: BlockNumber,
+		_permission: SchemaGrantPermission,
 	) -> DispatchResult {
 		match DelegationInfoHandler::get_delegation_of(delegator, provider) {
 			Some(_) => Ok(()),
@@ -208,6 +218,7 @@ impl SchemaProvider<u16> for SchemaHandler {
 				model_type: ModelType::Parquet,
 				payload_location: PayloadLocation::IPFS,
 				settings: Vec::new(),
+				status: SchemaStatus::Active,
 			})
 		}
 
@@ -217,6 +228,10 @@ impl SchemaProvider<u16> for SchemaHandler {
 			model_type: ModelType::AvroBinary,
 			payload_location: PayloadLocation::OnChain,
 			settings: Vec::new(),
+			status: match schema_id {
+				RETIRED_SCHEMA_ID => SchemaStatus::Retired,
+				_ => SchemaStatus::Active,
+			},
 		})
 	}
 
@@ -227,6 +242,7 @@ impl SchemaProvider<u16> for SchemaHandler {
 				settings: schema.settings,
 				model_type: schema.model_type,
 				payload_location: schema.payload_location,
+				status: schema.status,
 			})
 		})
 	}
@@ -358,6 +358,26 @@ fn add_message_with_invalid_schema_id_should_error() {
 	});
 }
 
+#[test]
+fn add_message_with_retired_schema_should_error() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let caller_1 = 5;
+		let message_payload_1 = generate_payload(2, None);
+
+		// act
+		assert_err!(
+			MessagesPallet::add_onchain_message(
+				RuntimeOrigin::signed(caller_1),
+				None,
+				RETIRED_SCHEMA_ID,
+				message_payload_1
+			),
+			Error::<Test>::SchemaRetired
+		);
+	});
+}
+
 #[test]
 fn add_ipfs_message_with_invalid_schema_id_should_error() {
 	new_test_ext().execute_with(|| {
@@ -8,7 +8,7 @@ use frame_benchmarking::{account, benchmarks, whitelisted_caller};
 use frame_support::assert_ok;
 use frame_system::RawOrigin;
 use sp_core::{crypto::KeyTypeId, Encode};
-use sp_runtime::RuntimeAppPublic;
+use sp_runtime::{traits::Saturating, RuntimeAppPublic};
 
 pub const TEST_KEY_TYPE_ID: KeyTypeId = KeyTypeId(*b"test");
 
@@ -34,7 +34,7 @@ fn create_payload_and_signature<T: Config>(
 	let delegator_account = SignerId::generate_pair(None);
 	let expiration = 10u32;
 	let add_provider_payload = AddProvider::new(authorized_msa_id, Some(schemas), expiration);
-	let encode_add_provider_data = wrap_binary_data(add_provider_payload.encode());
+	let encode_add_provider_data = wrap_binary_data(add_provider_payload.signed_payload());
 
 	let signature = delegator_account.sign(&encode_add_provider_data).unwrap();
 	let acc = T::AccountId::decode(&mut &delegator_account.encode()[..]).unwrap();
@@ -50,9 +50,10 @@ fn add_key_payload_and_signature<T: Config>(
 		msa_id: msa_id.into(),
 		expiration: 10u32.into(),
 		new_public_key: public_key.into(),
+		scope: None,
 	};
 
-	let encoded_add_key_payload = wrap_binary_data(add_key_payload.encode());
+	let encoded_add_key_payload = wrap_binary_data(add_key_payload.signed_payload());
 
 	let signature = new_keys.sign(&encoded_add_key_payload).unwrap();
 	let acc = T::AccountId::decode(&mut &new_keys.encode()[..]).unwrap();
@@ -68,6 +69,57 @@ fn create_msa_account_and_keys<T: Config>() -> (T::AccountId, SignerId, MessageS
 	(account_id, key_pair, msa_id)
 }
 
+fn create_recovery_guardians<T: Config>(
+	msa_id: MessageSourceId,
+	guardian_count: u32,
+	threshold: u32,
+) -> Vec<(MessageSourceId, T::AccountId)> {
+	let guardians: Vec<(MessageSourceId, T::AccountId)> = (0..guardian_count)
+		.map(|i| {
+			let guardian_account = create_account::<T>("guardian", i);
+			Msa::<T>::create_account(guardian_account, EMPTY_FUNCTION).unwrap()
+		})
+		.collect();
+	let config = RecoveryConfig::<T> {
+		guardians: guardians.iter().map(|(id, _)| *id).collect::<Vec<_>>().try_into().unwrap(),
+		threshold,
+		commitment: Some(H256(sp_io::hashing::blake2_256(&[1u8; 32]))),
+	};
+	RecoveryConfigs::<T>::insert(msa_id, config);
+	guardians
+}
+
+/// Creates a provider whose key holds enough to pay the deposit of a delegation request,
+/// and a delegator, and returns their keys and MSA ids
+fn create_provider_and_delegator_for_request<T: Config>(
+) -> (T::AccountId, MessageSourceId, SignerId, MessageSourceId) {
+	let provider_account = create_account::<T>("provider", 0);
+	let (provider_msa_id, provider_public_key) =
+		Msa::<T>::create_account(provider_account, EMPTY_FUNCTION).unwrap();
+	assert_ok!(Msa::<T>::create_provider_for(provider_msa_id, b"provider".to_vec()));
+	T::Currency::set_balance(
+		&provider_public_key,
+		T::Currency::minimum_balance().saturating_add(T::DelegationRequestDeposit::get()),
+	);
+	let (_, delegator_key_pair, delegator_msa_id) = create_msa_account_and_keys::<T>();
+	(provider_public_key, provider_msa_id, delegator_key_pair, delegator_msa_id)
+}
+
+fn request_max_delegation<T: Config>(
+	provider_public_key: T::AccountId,
+	delegator_msa_id: MessageSourceId,
+) {
+	let schema_ids: Vec<SchemaId> = (1..=T::MaxSchemaGrantsPerDelegation::get() as u16).collect();
+	T::SchemaValidator::set_schema_count(schema_ids.len().try_into().unwrap());
+	assert_ok!(Msa::<T>::request_delegation(
+		RawOrigin::Signed(provider_public_key).into(),
+		delegator_msa_id,
+		schema_ids,
+		10u32.into(),
+		Some(100u32.into()),
+	));
+}
+
 fn generate_fake_signature(i: u8) -> MultiSignature {
 	let sig = [i; 64];
 	MultiSignature::Sr25519(sp_core::sr25519::Signature::from_raw(sig))
@@ -129,7 +181,7 @@ benchmarks! {
 		let delegator_account = create_account::<T>("account", 1);
 		let (delegator_msa_id, _) = Msa::<T>::create_account(delegator_account.into(), EMPTY_FUNCTION).unwrap();
 
-		assert_ok!(Msa::<T>::add_provider(ProviderId(provider_msa_id), DelegatorId(delegator_msa_id), vec![]));
+		assert_ok!(Msa::<T>::add_provider(ProviderId(provider_msa_id), DelegatorId(delegator_msa_id), vec![], None));
 	}: _ (RawOrigin::Signed(provider_public_key), delegator_msa_id)
 	verify {
 		assert_eq!(frame_system::Pallet::<T>::events().len(), 1);
@@ -144,7 +196,7 @@ benchmarks! {
 
 		let (add_key_payload, new_public_key_signature, new_public_key) = add_key_payload_and_signature::<T>(delegator_msa_id);
 
-		let encoded_add_key_payload = wrap_binary_data(add_key_payload.encode());
+		let encoded_add_key_payload = wrap_binary_data(add_key_payload.signed_payload());
 		let owner_signature = MultiSignature::Sr25519(delegator_key_pair.sign(&encoded_add_key_payload).unwrap().into());
 	}: _ (RawOrigin::Signed(provider_public_key.clone()), delegator_public_key.clone(), owner_signature, new_public_key_signature, add_key_payload)
 	verify {
@@ -160,7 +212,7 @@ benchmarks! {
 
 		let (add_key_payload, new_public_key_signature, new_public_key) = add_key_payload_and_signature::<T>(delegator_msa_id);
 
-		let encoded_add_key_payload = wrap_binary_data(add_key_payload.encode());
+		let encoded_add_key_payload = wrap_binary_data(add_key_payload.signed_payload());
 		let owner_signature = MultiSignature::Sr25519(delegator_key_pair.sign(&encoded_add_key_payload).unwrap().into());
 
 		assert_ok!(Msa::<T>::add_public_key_to_msa(RawOrigin::Signed(provider_public_key).into(), caller_and_delegator_public_key.clone(), owner_signature,  new_public_key_signature, add_key_payload));
@@ -208,7 +260,7 @@ benchmarks! {
 		let delegator_account = create_account::<T>("account", 1);
 		let (delegator_msa_id, delegator_public_key) = Msa::<T>::create_account(delegator_account.into(), EMPTY_FUNCTION).unwrap();
 
-		assert_ok!(Msa::<T>::add_provider(ProviderId(provider_msa_id), DelegatorId(delegator_msa_id), vec![]));
+		assert_ok!(Msa::<T>::add_provider(ProviderId(provider_msa_id), DelegatorId(delegator_msa_id), vec![], None));
 	}: _ (RawOrigin::Signed(delegator_public_key), provider_msa_id)
 	verify {
 		assert_eq!(frame_system::Pallet::<T>::events().len(), 1);
@@ -262,12 +314,351 @@ benchmarks! {
 		let schema_ids: Vec<SchemaId> = (1..s as u16).collect::<Vec<_>>();
 		T::SchemaValidator::set_schema_count(schema_ids.len().try_into().unwrap());
 
-		assert_ok!(Msa::<T>::add_provider(ProviderId(provider_msa_id), DelegatorId(delegator_msa_id), schema_ids.clone()));
+		assert_ok!(Msa::<T>::add_provider(ProviderId(provider_msa_id), DelegatorId(delegator_msa_id), schema_ids.clone(), None));
 	}: _ (RawOrigin::Signed(delegator_public_key), provider_msa_id, schema_ids.clone())
 	verify {
 		assert_eq!(frame_system::Pallet::<T>::events().len(), 1);
 	}
 
+	set_schema_grant_permissions {
+		let provider_account = create_account::<T>("account", 0);
+		let (provider_msa_id, _) = Msa::<T>::create_account(provider_account.into(), EMPTY_FUNCTION).unwrap();
+
+		let delegator_account = create_account::<T>("account", 1);
+		let (delegator_msa_id, delegator_public_key) = Msa::<T>::create_account(delegator_account.into(), EMPTY_FUNCTION).unwrap();
+
+		let schema_ids: Vec<SchemaId> = (1..=T::MaxSchemaGrantsPerDelegation::get() as u16).collect::<Vec<_>>();
+		T::SchemaValidator::set_schema_count(schema_ids.len().try_into().unwrap());
+
+		assert_ok!(Msa::<T>::add_provider(ProviderId(provider_msa_id), DelegatorId(delegator_msa_id), schema_ids.clone(), None));
+		let permissions = SchemaPermissions { create: true, update: false, delete: false };
+	}: _ (RawOrigin::Signed(delegator_public_key), provider_msa_id, 1, permissions)
+	verify {
+		assert_eq!(frame_system::Pallet::<T>::events().len(), 1);
+	}
+
+	set_recovery_config {
+		let g in 1 .. T::MaxRecoveryGuardians::get();
+
+		let owner_account = create_account::<T>("account", 0);
+		let (msa_id, owner_key) = Msa::<T>::create_account(owner_account, EMPTY_FUNCTION).unwrap();
+		let guardians = create_recovery_guardians::<T>(msa_id, g, 1);
+		RecoveryConfigs::<T>::remove(msa_id);
+		let guardian_ids: BoundedVec<MessageSourceId, T::MaxRecoveryGuardians> =
+			guardians.iter().map(|(id, _)| *id).collect::<Vec<_>>().try_into().unwrap();
+	}: _ (RawOrigin::Signed(owner_key), guardian_ids, g, None)
+	verify {
+		assert!(RecoveryConfigs::<T>::contains_key(msa_id));
+	}
+
+	remove_recovery_config {
+		let owner_account = create_account::<T>("account", 0);
+		let (msa_id, owner_key) = Msa::<T>::create_account(owner_account, EMPTY_FUNCTION).unwrap();
+		create_recovery_guardians::<T>(msa_id, T::MaxRecoveryGuardians::get(), 1);
+	}: _ (RawOrigin::Signed(owner_key))
+	verify {
+		assert!(!RecoveryConfigs::<T>::contains_key(msa_id));
+	}
+
+	initiate_recovery {
+		let owner_account = create_account::<T>("account", 0);
+		let (msa_id, _) = Msa::<T>::create_account(owner_account, EMPTY_FUNCTION).unwrap();
+		let guardians = create_recovery_guardians::<T>(msa_id, T::MaxRecoveryGuardians::get(), 1);
+		let new_key = create_account::<T>("account", 1);
+	}: _ (RawOrigin::Signed(guardians[0].1.clone()), msa_id, new_key, None, Some([1u8; 32]))
+	verify {
+		assert!(ActiveRecoveries::<T>::contains_key(msa_id));
+	}
+
+	approve_recovery {
+		let owner_account = create_account::<T>("account", 0);
+		let (msa_id, _) = Msa::<T>::create_account(owner_account, EMPTY_FUNCTION).unwrap();
+		let guardians = create_recovery_guardians::<T>(msa_id, T::MaxRecoveryGuardians::get(), 2);
+		let new_key = create_account::<T>("account", 1);
+		assert_ok!(Msa::<T>::initiate_recovery(RawOrigin::Signed(guardians[0].1.clone()).into(), msa_id, new_key.clone(), None, Some([1u8; 32])));
+	}: _ (RawOrigin::Signed(guardians[1].1.clone()), msa_id, new_key)
+	verify {
+		assert_eq!(ActiveRecoveries::<T>::get(msa_id).unwrap().approvals.len(), 2);
+	}
+
+	cancel_recovery {
+		let owner_account = create_account::<T>("account", 0);
+		let (msa_id, owner_key) = Msa::<T>::create_account(owner_account, EMPTY_FUNCTION).unwrap();
+		let guardians = create_recovery_guardians::<T>(msa_id, T::MaxRecoveryGuardians::get(), 1);
+		let new_key = create_account::<T>("account", 1);
+		assert_ok!(Msa::<T>::initiate_recovery(RawOrigin::Signed(guardians[0].1.clone()).into(), msa_id, new_key, None, Some([1u8; 32])));
+	}: _ (RawOrigin::Signed(owner_key))
+	verify {
+		assert!(!ActiveRecoveries::<T>::contains_key(msa_id));
+	}
+
+	complete_recovery {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner_account = create_account::<T>("account", 0);
+		let (msa_id, _) = Msa::<T>::create_account(owner_account.clone(), EMPTY_FUNCTION).unwrap();
+		let guardians = create_recovery_guardians::<T>(msa_id, T::MaxRecoveryGuardians::get(), 1);
+		let new_key = create_account::<T>("account", 1);
+		assert_ok!(Msa::<T>::initiate_recovery(RawOrigin::Signed(guardians[0].1.clone()).into(), msa_id, new_key.clone(), Some(owner_account.clone()), Some([1u8; 32])));
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() + T::RecoveryDelay::get(),
+		);
+	}: _ (RawOrigin::Signed(caller), msa_id)
+	verify {
+		assert_eq!(PublicKeyToMsaId::<T>::get(new_key), Some(msa_id));
+		assert_eq!(PublicKeyToMsaId::<T>::get(owner_account), None);
+	}
+
+	rotate_msa_public_key {
+		prep_signature_registry::<T>();
+
+		let (provider_public_key, _, _) = create_msa_account_and_keys::<T>();
+		let (old_public_key, old_key_pair, msa_id) = create_msa_account_and_keys::<T>();
+
+		let new_keys = SignerId::generate_pair(None);
+		let new_public_key = T::AccountId::decode(&mut &new_keys.encode()[..]).unwrap();
+		let rotate_key_payload = RotateKeyData::<T> {
+			msa_id,
+			expiration: 10u32.into(),
+			new_public_key: new_public_key.clone(),
+			old_public_key: old_public_key.clone(),
+		};
+
+		let encoded_rotate_key_payload = wrap_binary_data(rotate_key_payload.encode());
+		let old_key_signature = MultiSignature::Sr25519(old_key_pair.sign(&encoded_rotate_key_payload).unwrap().into());
+		let new_key_signature = MultiSignature::Sr25519(new_keys.sign(&encoded_rotate_key_payload).unwrap().into());
+	}: _ (RawOrigin::Signed(provider_public_key), old_key_signature, new_key_signature, rotate_key_payload)
+	verify {
+		assert_eq!(PublicKeyToMsaId::<T>::get(new_public_key), Some(msa_id));
+		assert!(PublicKeyToMsaId::<T>::get(old_public_key).is_none());
+	}
+
+	set_provider_metadata {
+		let s in 0 .. T::MaxSchemaGrantsPerDelegation::get();
+
+		let field = vec![1u8; T::MaxProviderMetadataFieldSize::get() as usize];
+		let account = create_account::<T>("account", 0);
+		let (provider_msa_id, provider_public_key) = Msa::<T>::create_account(account.into(), EMPTY_FUNCTION).unwrap();
+		assert_ok!(Msa::<T>::create_provider_for(provider_msa_id, b"provider".to_vec()));
+
+		let schema_ids: Vec<SchemaId> = (1..=s as u16).collect::<Vec<_>>();
+		T::SchemaValidator::set_schema_count(schema_ids.len().try_into().unwrap());
+	}: _ (RawOrigin::Signed(provider_public_key), field.clone(), field.clone(), field, Some(H256::zero()), schema_ids)
+	verify {
+		assert!(ProviderToMetadata::<T>::get(ProviderId(provider_msa_id)).is_some());
+	}
+
+	propose_to_change_provider_name {
+		let s = T::MaxProviderNameSize::get();
+
+		let provider_name = (1 .. s as u8).collect::<Vec<_>>();
+		let account = create_account::<T>("account", 0);
+		let (provider_msa_id, provider_public_key) = Msa::<T>::create_account(account.into(), EMPTY_FUNCTION).unwrap();
+		assert_ok!(Msa::<T>::create_provider_for(provider_msa_id, b"provider".to_vec()));
+
+	}:	_ (RawOrigin::Signed(provider_public_key), provider_name)
+	verify {
+		assert_eq!(frame_system::Pallet::<T>::events().len(), 1);
+	}
+
+	change_provider_name_via_governance {
+		let s = T::MaxProviderNameSize::get();
+
+		let provider_name = (1 .. s as u8).collect::<Vec<_>>();
+		let account = create_account::<T>("account", 0);
+		let (provider_msa_id, provider_public_key) = Msa::<T>::create_account(account.into(), EMPTY_FUNCTION).unwrap();
+		assert_ok!(Msa::<T>::create_provider_for(provider_msa_id, b"provider".to_vec()));
+
+	}:	_ (RawOrigin::Root, provider_public_key, provider_name.clone())
+	verify {
+		assert_eq!(
+			ProviderToRegistryEntry::<T>::get(ProviderId(provider_msa_id)).unwrap().provider_name.into_inner(),
+			provider_name
+		);
+	}
+
+	retire_provider {
+		let account = create_account::<T>("account", 0);
+		let (provider_msa_id, provider_public_key) = Msa::<T>::create_account(account.into(), EMPTY_FUNCTION).unwrap();
+		assert_ok!(Msa::<T>::create_provider_for(provider_msa_id, b"provider".to_vec()));
+
+	}:	_ (RawOrigin::Signed(provider_public_key))
+	verify {
+		assert!(Msa::<T>::is_retiring_provider(provider_msa_id));
+	}
+
+	complete_provider_retirement {
+		let caller: T::AccountId = whitelisted_caller();
+		let account = create_account::<T>("account", 0);
+		let (provider_msa_id, _) = Msa::<T>::create_account(account.into(), EMPTY_FUNCTION).unwrap();
+		assert_ok!(Msa::<T>::create_provider_for(provider_msa_id, b"provider".to_vec()));
+		ProviderRetirements::<T>::insert(ProviderId(provider_msa_id), BlockNumberFor::<T>::zero());
+		frame_system::Pallet::<T>::set_block_number(1u32.into());
+
+	}:	_ (RawOrigin::Signed(caller), provider_msa_id)
+	verify {
+		assert!(!Msa::<T>::is_registered_provider(provider_msa_id));
+	}
+
+	revoke_retired_provider_delegations {
+		let n in 0 .. 1000;
+		let account = create_account::<T>("account", 0);
+		let (provider_msa_id, _) = Msa::<T>::create_account(account.into(), EMPTY_FUNCTION).unwrap();
+		let provider_id = ProviderId(provider_msa_id);
+		for i in 0 .. n {
+			let account = create_account::<T>("delegator", i);
+			let (delegator_msa_id, _) = Msa::<T>::create_account(account, EMPTY_FUNCTION).unwrap();
+			assert_ok!(Msa::<T>::add_provider(provider_id, DelegatorId(delegator_msa_id), vec![], None));
+		}
+		ProviderRetirements::<T>::insert(provider_id, BlockNumberFor::<T>::from(1u32));
+		ProviderRevocationQueue::<T>::insert(provider_id, ());
+
+	}: {
+		Msa::<T>::revoke_retired_provider(provider_id, n);
+	}
+	verify {
+		assert!(!ProviderRevocationQueue::<T>::contains_key(provider_id));
+		assert!(!ProviderRetirements::<T>::contains_key(provider_id));
+	}
+
+	purge_retired_msa {
+		let n in 0 .. 1000;
+		let (_, _, delegator_msa_id) = create_msa_account_and_keys::<T>();
+		for i in 0 .. n {
+			let account = create_account::<T>("provider", i);
+			let (provider_msa_id, _) = Msa::<T>::create_account(account, EMPTY_FUNCTION).unwrap();
+			assert_ok!(Msa::<T>::add_provider(ProviderId(provider_msa_id), DelegatorId(delegator_msa_id), vec![], None));
+		}
+		RetiredMsaPurgeQueue::<T>::insert(delegator_msa_id, ());
+
+	}: {
+		Msa::<T>::purge_retired_msa(delegator_msa_id, Weight::MAX);
+	}
+	verify {
+		assert_eq!(DelegatorAndProviderToDelegation::<T>::iter_prefix(DelegatorId(delegator_msa_id)).count(), 0);
+	}
+
+	index_provider_delegators {
+		let n in 0 .. 1000;
+		let account = create_account::<T>("account", 0);
+		let (provider_msa_id, _) = Msa::<T>::create_account(account.into(), EMPTY_FUNCTION).unwrap();
+		for i in 0 .. n {
+			let account = create_account::<T>("delegator", i);
+			let (delegator_msa_id, _) = Msa::<T>::create_account(account, EMPTY_FUNCTION).unwrap();
+			assert_ok!(Msa::<T>::add_provider(ProviderId(provider_msa_id), DelegatorId(delegator_msa_id), vec![], None));
+		}
+		let _ = ProviderToDelegators::<T>::clear(u32::MAX, None);
+		ProviderToDelegatorsMigrationCursor::<T>::put(BoundedVec::default());
+
+	}: {
+		crate::migration::v2::index_delegations_from::<T>(BoundedVec::default(), n);
+	}
+	verify {
+		assert_eq!(ProviderToDelegators::<T>::iter_prefix(ProviderId(provider_msa_id)).count(), n as usize);
+	}
+
+	request_delegation {
+		let s in 0 .. T::MaxSchemaGrantsPerDelegation::get();
+		frame_system::Pallet::<T>::set_block_number(1u32.into());
+		let (provider_public_key, provider_msa_id, _, delegator_msa_id) =
+			create_provider_and_delegator_for_request::<T>();
+		let schema_ids: Vec<SchemaId> = (1..=s as u16).collect();
+		T::SchemaValidator::set_schema_count(schema_ids.len().try_into().unwrap());
+	}: _ (RawOrigin::Signed(provider_public_key), delegator_msa_id, schema_ids, 10u32.into(), Some(100u32.into()))
+	verify {
+		assert!(PendingDelegationRequests::<T>::contains_key(DelegatorId(delegator_msa_id), ProviderId(provider_msa_id)));
+	}
+
+	cancel_delegation_request {
+		frame_system::Pallet::<T>::set_block_number(1u32.into());
+		let (provider_public_key, provider_msa_id, _, delegator_msa_id) =
+			create_provider_and_delegator_for_request::<T>();
+		request_max_delegation::<T>(provider_public_key.clone(), delegator_msa_id);
+	}: _ (RawOrigin::Signed(provider_public_key), delegator_msa_id)
+	verify {
+		assert!(!PendingDelegationRequests::<T>::contains_key(DelegatorId(delegator_msa_id), ProviderId(provider_msa_id)));
+	}
+
+	answer_delegation_request {
+		prep_signature_registry::<T>();
+		frame_system::Pallet::<T>::set_block_number(1u32.into());
+		let caller: T::AccountId = whitelisted_caller();
+		let (provider_public_key, provider_msa_id, delegator_key_pair, delegator_msa_id) =
+			create_provider_and_delegator_for_request::<T>();
+		request_max_delegation::<T>(provider_public_key, delegator_msa_id);
+
+		let answer_payload = DelegationRequestAnswer::<T> {
+			provider_msa_id,
+			requested_at: 1u32.into(),
+			accept: true,
+			expiration: 10u32.into(),
+		};
+		let signature = delegator_key_pair.sign(&wrap_binary_data(answer_payload.encode())).unwrap();
+		let delegator_public_key = T::AccountId::decode(&mut &delegator_key_pair.encode()[..]).unwrap();
+	}: _ (RawOrigin::Signed(caller), delegator_public_key, MultiSignature::Sr25519(signature.into()), answer_payload)
+	verify {
+		assert!(DelegatorAndProviderToDelegation::<T>::get(DelegatorId(delegator_msa_id), ProviderId(provider_msa_id)).is_some());
+	}
+
+	remove_expired_delegation_request {
+		frame_system::Pallet::<T>::set_block_number(1u32.into());
+		let caller: T::AccountId = whitelisted_caller();
+		let (provider_public_key, provider_msa_id, _, delegator_msa_id) =
+			create_provider_and_delegator_for_request::<T>();
+		request_max_delegation::<T>(provider_public_key, delegator_msa_id);
+		frame_system::Pallet::<T>::set_block_number(11u32.into());
+	}: _ (RawOrigin::Signed(caller), delegator_msa_id, provider_msa_id)
+	verify {
+		assert!(!PendingDelegationRequests::<T>::contains_key(DelegatorId(delegator_msa_id), ProviderId(provider_msa_id)));
+	}
+
+	merge_msa {
+		let k in 1 .. 25;
+		prep_signature_registry::<T>();
+
+		let (caller, _, _) = create_msa_account_and_keys::<T>();
+		let (source_public_key, source_key_pair, source_msa_id) = create_msa_account_and_keys::<T>();
+		let (target_public_key, target_key_pair, target_msa_id) = create_msa_account_and_keys::<T>();
+		let mut source_keys = vec![source_public_key.clone()];
+		for i in 1 .. k {
+			let account = create_account::<T>("key", i);
+			assert_ok!(Msa::<T>::add_key(source_msa_id, &account, EMPTY_FUNCTION));
+			source_keys.push(account);
+		}
+
+		let merge_payload = MergeMsaData::<T> {
+			source_msa_id,
+			source_public_key: source_public_key.clone(),
+			target_msa_id,
+			target_public_key,
+			expiration: 10u32.into(),
+		};
+		let encoded_merge_payload = wrap_binary_data(merge_payload.encode());
+		let source_key_signature = MultiSignature::Sr25519(source_key_pair.sign(&encoded_merge_payload).unwrap().into());
+		let target_key_signature = MultiSignature::Sr25519(target_key_pair.sign(&encoded_merge_payload).unwrap().into());
+	}: _ (RawOrigin::Signed(caller), source_key_signature, target_key_signature, merge_payload, source_keys)
+	verify {
+		assert_eq!(PublicKeyToMsaId::<T>::get(source_public_key), Some(target_msa_id));
+		assert_eq!(MsaMergeQueue::<T>::get(source_msa_id), Some(target_msa_id));
+	}
+
+	merge_queued_msa {
+		let n in 0 .. 1000;
+		let (_, _, source_msa_id) = create_msa_account_and_keys::<T>();
+		let (_, _, target_msa_id) = create_msa_account_and_keys::<T>();
+		for i in 0 .. n {
+			let account = create_account::<T>("provider", i);
+			let (provider_msa_id, _) = Msa::<T>::create_account(account, EMPTY_FUNCTION).unwrap();
+			assert_ok!(Msa::<T>::add_provider(ProviderId(provider_msa_id), DelegatorId(source_msa_id), vec![], None));
+		}
+		MsaMergeQueue::<T>::insert(source_msa_id, target_msa_id);
+
+	}: {
+		Msa::<T>::merge_queued_msa(source_msa_id, target_msa_id, n);
+	}
+	verify {
+		assert_eq!(DelegatorAndProviderToDelegation::<T>::iter_prefix(DelegatorId(target_msa_id)).count(), n as usize);
+	}
+
 	impl_benchmark_test_suite!(Msa,
 		crate::tests::mock::new_test_ext_keystore(),
 		crate::tests::mock::Test);
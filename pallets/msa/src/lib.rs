@@ -35,7 +35,11 @@ use frame_support::{
 	dispatch::{DispatchInfo, DispatchResult, PostDispatchInfo},
 	ensure,
 	pallet_prelude::*,
-	traits::IsSubType,
+	traits::{
+		fungible::{Inspect as InspectFungible, Mutate, MutateHold},
+		tokens::Precision,
+		IsSubType,
+	},
 };
 use parity_scale_codec::{Decode, Encode};
 
@@ -45,20 +49,25 @@ use common_primitives::benchmarks::{MsaBenchmarkHelper, RegisterProviderBenchmar
 use common_primitives::{
 	capacity::TargetValidator,
 	msa::{
-		Delegation, DelegationValidator, DelegatorId, MsaLookup, MsaValidator, ProviderId,
-		ProviderLookup, ProviderRegistryEntry, SchemaGrant, SchemaGrantValidator,
+		Delegation, DelegationRequestResponse, DelegationStatus, DelegationValidator, DelegatorId,
+		DelegatorResponse, DelegatorsPaginationRequest, DelegatorsPaginationResponse,
+		KeyOwnerResponse, KeyScope, MsaDataMerger, MsaDataPurger, MsaLookup, MsaValidator,
+		ProviderId, ProviderInfoResponse, ProviderLookup, ProviderMetadata, ProviderRegistryEntry,
+		SchemaGrant, SchemaGrantPermission, SchemaGrantValidator, SchemaPermissions,
 		SignatureRegistryPointer,
 	},
 	node::ProposalProvider,
 	schema::{SchemaId, SchemaValidator},
+	signatures::{self, EIP712Domain, EIP712Encode},
 };
 use frame_system::pallet_prelude::*;
 use log;
 use scale_info::TypeInfo;
-use sp_core::crypto::AccountId32;
+use sp_core::{crypto::AccountId32, H256};
 use sp_runtime::{
 	traits::{
-		BlockNumberProvider, Convert, DispatchInfoOf, Dispatchable, SignedExtension, Verify, Zero,
+		BlockNumberProvider, Convert, DispatchInfoOf, Dispatchable, SaturatedConversion,
+		SignedExtension, Zero,
 	},
 	ArithmeticError, DispatchError, MultiSignature,
 };
@@ -69,7 +78,11 @@ pub use common_primitives::{
 	handles::HandleProvider, msa::MessageSourceId, utils::wrap_binary_data,
 };
 pub use pallet::*;
-pub use types::{AddKeyData, AddProvider, PermittedDelegationSchemas, EMPTY_FUNCTION};
+pub use types::{
+	ActiveRecovery, AddKeyData, AddProvider, DelegationRequest, DelegationRequestAnswer,
+	MergeMsaData, PermittedDelegationSchemas, RecoveryConfig, RotateKeyData, SubDelegation,
+	ThresholdAction, ThresholdProposal, EMPTY_FUNCTION,
+};
 pub use weights::*;
 
 /// Offchain storage for MSA pallet
@@ -82,21 +95,42 @@ mod benchmarking;
 #[cfg(test)]
 mod tests;
 
+/// storage migrations
+pub mod migration;
 pub mod types;
 
 pub mod weights;
+
+pub(crate) type BalanceOf<T> =
+	<<T as Config>::Currency as InspectFungible<<T as frame_system::Config>::AccountId>>::Balance;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 
+	/// A reason for holding funds.
+	/// Creates a hold reason for this pallet that is aggregated by `construct_runtime`.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// The Provider has a pending delegation request.
+		DelegationRequestDeposit,
+	}
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		/// The overarching event type.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
+		/// The overarching hold reason.
+		type RuntimeHoldReason: From<HoldReason>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
+		/// Functions that allow a fungible balance to be changed or held.
+		type Currency: MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>
+			+ Mutate<Self::AccountId>;
+
 		/// AccountId truncated to 32 bytes
 		type ConvertIntoAccountId32: Convert<Self::AccountId, AccountId32>;
 
@@ -112,16 +146,30 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxProviderNameSize: Get<u32>;
 
+		/// Maximum size of each text field of the provider metadata
+		#[pallet::constant]
+		type MaxProviderMetadataFieldSize: Get<u32>;
+
 		/// A type that will supply schema related information.
 		type SchemaValidator: SchemaValidator<SchemaId>;
 
 		/// A type that will supply `Handle` related information.
 		type HandleProvider: HandleProvider;
 
+		/// A type that will delete the data other pallets store for a retired MSA
+		type MsaDataPurger: MsaDataPurger;
+
+		/// A type that will move the data other pallets store for an MSA merged into another MSA
+		type MsaDataMerger: MsaDataMerger;
+
 		/// The number of blocks before a signature can be ejected from the PayloadSignatureRegistryList
 		#[pallet::constant]
 		type MortalityWindowSize: Get<u32>;
 
+		/// The chain id and verifying contract of the EIP-712 domain that Ethereum keys sign typed data in
+		#[pallet::constant]
+		type Eip712Domain: Get<EIP712Domain>;
+
 		/// The maximum number of signatures that can be stored in PayloadSignatureRegistryList.
 		#[pallet::constant]
 		type MaxSignaturesStored: Get<Option<u32>>;
@@ -136,9 +184,55 @@ pub mod pallet {
 
 		/// The Council proposal provider interface
 		type ProposalProvider: ProposalProvider<Self::AccountId, Self::Proposal>;
+
+		/// Maximum number of guardians an MSA may register for recovery
+		#[pallet::constant]
+		type MaxRecoveryGuardians: Get<u32>;
+
+		/// The number of blocks a recovery must wait after being initiated before it can complete
+		#[pallet::constant]
+		type RecoveryDelay: Get<BlockNumberFor<Self>>;
+
+		/// The number of blocks a retiring provider keeps its delegations before its retirement takes effect
+		#[pallet::constant]
+		type ProviderRetirementGracePeriod: Get<BlockNumberFor<Self>>;
+
+		/// Maximum number of sub-delegation links between a provider and the delegation it derives from
+		#[pallet::constant]
+		type MaxSubDelegationDepth: Get<u32>;
+
+		/// Maximum number of providers that may sub-delegate to a single provider
+		#[pallet::constant]
+		type MaxSubDelegationsPerProvider: Get<u32>;
+
+		/// Maximum number of sub-delegation links examined when resolving a single delegation,
+		/// across every level of the chain
+		#[pallet::constant]
+		type MaxSubDelegationLookups: Get<u32>;
+
+		/// The amount a Provider holds for each of its pending delegation requests
+		#[pallet::constant]
+		type DelegationRequestDeposit: Get<BalanceOf<Self>>;
+
+		/// Maximum number of pending delegation requests to a single delegator
+		#[pallet::constant]
+		type MaxPendingDelegationRequests: Get<u32>;
+
+		/// Maximum number of blocks a delegation request may stay pending
+		#[pallet::constant]
+		type MaxDelegationRequestLifetime: Get<BlockNumberFor<Self>>;
+
+		/// Maximum number of actions that may be proposed and waiting for approvals on a single MSA
+		#[pallet::constant]
+		type MaxThresholdProposalsPerMsa: Get<u32>;
 	}
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	/// The current storage version
+	pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
+	/// The proof size the generated weights of the recovery calls miss for the key an
+	/// [`ActiveRecovery`] replaces
+	const REPLACED_KEY_PROOF_SIZE: u64 = 33;
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -165,6 +259,115 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Index of the Delegators of each Provider, kept alongside [`DelegatorAndProviderToDelegation`]
+	/// - Keys: Provider MSA, Delegator MSA
+	#[pallet::storage]
+	pub type ProviderToDelegators<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, ProviderId, Twox64Concat, DelegatorId, (), OptionQuery>;
+
+	/// The raw key of the last delegation added to [`ProviderToDelegators`] by the storage
+	/// migration to v2, while the migration is in progress. Empty until the first delegation is indexed.
+	#[pallet::storage]
+	pub type ProviderToDelegatorsMigrationCursor<T: Config> =
+		StorageValue<_, BoundedVec<u8, ConstU32<128>>, OptionQuery>;
+
+	/// Storage type for the permissions a Delegator has granted to its Provider on each schema.
+	/// Granted schemas without an entry allow every permission.
+	/// - Keys: Delegator MSA, Provider MSA
+	/// - Value: Map of [`SchemaId`] to [`SchemaPermissions`](common_primitives::msa::SchemaPermissions)
+	#[pallet::storage]
+	pub type DelegationSchemaPermissions<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		DelegatorId,
+		Twox64Concat,
+		ProviderId,
+		BoundedBTreeMap<SchemaId, SchemaPermissions, T::MaxSchemaGrantsPerDelegation>,
+		OptionQuery,
+	>;
+
+	/// Storage type for the delegations requested by Providers and not yet answered by the Delegator
+	/// - Keys: Delegator MSA, Provider MSA
+	/// - Value: [`DelegationRequest`](types::DelegationRequest)
+	#[pallet::storage]
+	pub type PendingDelegationRequests<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		DelegatorId,
+		Twox64Concat,
+		ProviderId,
+		DelegationRequest<T>,
+		OptionQuery,
+	>;
+
+	/// Storage type for the number of pending delegation requests to a Delegator
+	/// - Key: Delegator MSA
+	/// - Value: The number of entries of the Delegator in [`PendingDelegationRequests`]
+	#[pallet::storage]
+	pub type PendingDelegationRequestCount<T: Config> =
+		StorageMap<_, Twox64Concat, DelegatorId, u32, ValueQuery>;
+
+	/// Storage type for the schema grants Providers have sub-delegated to another Provider
+	/// - Key: Sub-provider MSA
+	/// - Value: List of [`SubDelegation`](types::SubDelegation), one per parent Provider
+	#[pallet::storage]
+	pub type SubDelegations<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		ProviderId,
+		BoundedVec<SubDelegation<T>, T::MaxSubDelegationsPerProvider>,
+		ValueQuery,
+	>;
+
+	/// Storage type for the number of keys of an MSA that must approve its sensitive actions
+	/// - Key: MSA Id
+	/// - Value: Threshold
+	#[pallet::storage]
+	pub type ThresholdPolicies<T: Config> =
+		StorageMap<_, Twox64Concat, MessageSourceId, u8, OptionQuery>;
+
+	/// Storage type for the actions proposed on MSAs with a threshold policy, waiting for approvals
+	/// - Keys: MSA Id, Proposal Id
+	/// - Value: [`ThresholdProposal`](types::ThresholdProposal)
+	#[pallet::storage]
+	pub type ThresholdProposals<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		MessageSourceId,
+		Twox64Concat,
+		u32,
+		ThresholdProposal<T>,
+		OptionQuery,
+	>;
+
+	/// Storage type for the id of the next action proposed on an MSA
+	/// - Key: MSA Id
+	/// - Value: Proposal Id
+	#[pallet::storage]
+	pub type NextThresholdProposalId<T: Config> =
+		StorageMap<_, Twox64Concat, MessageSourceId, u32, ValueQuery>;
+
+	/// Storage type for the number of actions proposed on an MSA and waiting for approvals
+	/// - Key: MSA Id
+	/// - Value: Number of open proposals
+	#[pallet::storage]
+	pub type ThresholdProposalCount<T: Config> =
+		StorageMap<_, Twox64Concat, MessageSourceId, u32, ValueQuery>;
+
+	/// Storage type for the recovery guardians registered by an MSA
+	/// - Key: MSA Id
+	/// - Value: [`RecoveryConfig`](types::RecoveryConfig)
+	#[pallet::storage]
+	pub type RecoveryConfigs<T: Config> =
+		StorageMap<_, Twox64Concat, MessageSourceId, RecoveryConfig<T>, OptionQuery>;
+
+	/// Storage type for the recovery currently in progress for an MSA
+	/// - Key: MSA Id
+	/// - Value: [`ActiveRecovery`](types::ActiveRecovery)
+	#[pallet::storage]
+	pub type ActiveRecoveries<T: Config> =
+		StorageMap<_, Twox64Concat, MessageSourceId, ActiveRecovery<T>, OptionQuery>;
+
 	/// Provider registration information
 	/// - Key: Provider MSA Id
 	/// - Value: [`ProviderRegistryEntry`](common_primitives::msa::ProviderRegistryEntry)
@@ -177,6 +380,52 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Metadata published by providers
+	/// - Key: Provider MSA Id
+	/// - Value: [`ProviderMetadata`](common_primitives::msa::ProviderMetadata)
+	#[pallet::storage]
+	pub type ProviderToMetadata<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		ProviderId,
+		ProviderMetadata<T::MaxProviderMetadataFieldSize, T::MaxSchemaGrantsPerDelegation>,
+		OptionQuery,
+	>;
+
+	/// Retirements of providers. Entries are kept after the retirement completes until every
+	/// delegation to the provider has been revoked in storage.
+	/// - Key: Provider MSA Id
+	/// - Value: The block number at which the retirement takes effect
+	#[pallet::storage]
+	pub type ProviderRetirements<T: Config> =
+		StorageMap<_, Twox64Concat, ProviderId, BlockNumberFor<T>, OptionQuery>;
+
+	/// Retired providers whose delegations have not yet all been revoked in storage
+	/// - Key: Provider MSA Id
+	#[pallet::storage]
+	pub type ProviderRevocationQueue<T: Config> =
+		StorageMap<_, Twox64Concat, ProviderId, (), OptionQuery>;
+
+	/// Retired MSAs whose data has not yet been purged
+	/// - Key: MSA Id
+	#[pallet::storage]
+	pub type RetiredMsaPurgeQueue<T: Config> =
+		StorageMap<_, Twox64Concat, MessageSourceId, (), OptionQuery>;
+
+	/// MSAs that were merged into another MSA, kept after the merge completes
+	/// - Key: Merged MSA Id
+	/// - Value: The MSA Id it was merged into
+	#[pallet::storage]
+	pub type MsaRedirects<T: Config> =
+		StorageMap<_, Twox64Concat, MessageSourceId, MessageSourceId, OptionQuery>;
+
+	/// Merged MSAs whose delegations and data have not yet been moved to the MSA they were merged into
+	/// - Key: Merged MSA Id
+	/// - Value: The MSA Id it was merged into
+	#[pallet::storage]
+	pub type MsaMergeQueue<T: Config> =
+		StorageMap<_, Twox64Concat, MessageSourceId, MessageSourceId, OptionQuery>;
+
 	/// Storage type for key to MSA information
 	/// - Key: AccountId
 	/// - Value: [`MessageSourceId`]
@@ -191,6 +440,19 @@ pub mod pallet {
 	pub(super) type PublicKeyCountForMsaId<T: Config> =
 		StorageMap<_, Twox64Concat, MessageSourceId, u8, ValueQuery>;
 
+	/// Storage type for the scopes of keys with restricted powers over their MSA.
+	/// Keys without a scope have full power over their MSA.
+	/// - Key: AccountId
+	/// - Value: [`KeyScope`]
+	#[pallet::storage]
+	pub type PublicKeyScopes<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		KeyScope<T::MaxSchemaGrantsPerDelegation>,
+		OptionQuery,
+	>;
+
 	/// PayloadSignatureRegistryList is used to prevent replay attacks for extrinsics
 	/// that take an externally-signed payload.
 	/// For this to work, the payload must include a mortality block number, which
@@ -289,6 +551,206 @@ pub mod pallet {
 			/// The Delegator MSA Id
 			delegator_id: DelegatorId,
 		},
+		/// An MSA registered or replaced its recovery guardians
+		RecoveryConfigSet {
+			/// The MSA id for the Event
+			msa_id: MessageSourceId,
+		},
+		/// An MSA removed its recovery guardians
+		RecoveryConfigRemoved {
+			/// The MSA id for the Event
+			msa_id: MessageSourceId,
+		},
+		/// A guardian started recovering an MSA
+		RecoveryInitiated {
+			/// The MSA being recovered
+			msa_id: MessageSourceId,
+			/// The guardian MSA that initiated the recovery
+			guardian_msa_id: MessageSourceId,
+			/// The key that will be added to the MSA
+			new_key: T::AccountId,
+		},
+		/// A guardian approved the recovery of an MSA
+		RecoveryApproved {
+			/// The MSA being recovered
+			msa_id: MessageSourceId,
+			/// The guardian MSA that approved the recovery
+			guardian_msa_id: MessageSourceId,
+		},
+		/// The owner of an MSA cancelled its recovery
+		RecoveryCancelled {
+			/// The MSA id for the Event
+			msa_id: MessageSourceId,
+		},
+		/// A recovery completed and the new key was added to the MSA
+		RecoveryCompleted {
+			/// The recovered MSA
+			msa_id: MessageSourceId,
+			/// The key added to the MSA
+			new_key: T::AccountId,
+		},
+		/// An MSA control key was replaced by a new key
+		PublicKeyRotated {
+			/// The MSA for the Event
+			msa_id: MessageSourceId,
+			/// The key removed from the MSA
+			old_key: T::AccountId,
+			/// The key added to the MSA
+			new_key: T::AccountId,
+		},
+		/// A Provider published new metadata
+		ProviderMetadataUpdated {
+			/// The MSA id associated with the provider
+			provider_id: ProviderId,
+		},
+		/// A Provider's name was changed by governance
+		ProviderNameChanged {
+			/// The MSA id associated with the provider
+			provider_id: ProviderId,
+		},
+		/// A Provider started retiring. All delegations to the Provider are revoked at `effective_at`.
+		ProviderRetirementScheduled {
+			/// The MSA id associated with the provider
+			provider_id: ProviderId,
+			/// The block number at which the retirement takes effect
+			effective_at: BlockNumberFor<T>,
+		},
+		/// A Provider's retirement completed and its registry entry was removed
+		ProviderRetired {
+			/// The MSA id associated with the provider
+			provider_id: ProviderId,
+		},
+		/// Some of the data of a retired MSA was purged
+		MsaPurgeProgressed {
+			/// The MSA id for the Event
+			msa_id: MessageSourceId,
+			/// The number of MSA pallet items purged in this block
+			items_purged: u32,
+		},
+		/// All of the data of a retired MSA has been purged
+		MsaPurgeCompleted {
+			/// The MSA id for the Event
+			msa_id: MessageSourceId,
+		},
+		/// A Provider requested a delegation from a Delegator
+		DelegationRequested {
+			/// The Provider MSA Id
+			provider_id: ProviderId,
+			/// The Delegator MSA Id
+			delegator_id: DelegatorId,
+			/// The block after which the request can no longer be accepted
+			expiration: BlockNumberFor<T>,
+		},
+		/// A Provider cancelled its delegation request
+		DelegationRequestCancelled {
+			/// The Provider MSA Id
+			provider_id: ProviderId,
+			/// The Delegator MSA Id
+			delegator_id: DelegatorId,
+		},
+		/// A Delegator rejected a delegation request
+		DelegationRequestRejected {
+			/// The Provider MSA Id
+			provider_id: ProviderId,
+			/// The Delegator MSA Id
+			delegator_id: DelegatorId,
+		},
+		/// An expired delegation request was removed
+		DelegationRequestRemoved {
+			/// The Provider MSA Id
+			provider_id: ProviderId,
+			/// The Delegator MSA Id
+			delegator_id: DelegatorId,
+		},
+		/// The threshold policy of an MSA was set or changed
+		ThresholdPolicySet {
+			/// The MSA id for the Event
+			msa_id: MessageSourceId,
+			/// The number of keys that must approve an action
+			threshold: u8,
+		},
+		/// The threshold policy of an MSA was removed
+		ThresholdPolicyRemoved {
+			/// The MSA id for the Event
+			msa_id: MessageSourceId,
+		},
+		/// A key of an MSA proposed an action that a threshold of its keys must approve
+		ThresholdActionProposed {
+			/// The MSA id for the Event
+			msa_id: MessageSourceId,
+			/// The id of the proposal
+			proposal_id: u32,
+			/// The key that proposed the action
+			key: T::AccountId,
+		},
+		/// A key of an MSA approved a proposed action
+		ThresholdActionApproved {
+			/// The MSA id for the Event
+			msa_id: MessageSourceId,
+			/// The id of the proposal
+			proposal_id: u32,
+			/// The key that approved the action
+			key: T::AccountId,
+		},
+		/// A proposed action was approved by enough keys and executed
+		ThresholdActionExecuted {
+			/// The MSA id for the Event
+			msa_id: MessageSourceId,
+			/// The id of the proposal
+			proposal_id: u32,
+		},
+		/// A proposed action was cancelled
+		ThresholdActionCancelled {
+			/// The MSA id for the Event
+			msa_id: MessageSourceId,
+			/// The id of the proposal
+			proposal_id: u32,
+		},
+		/// A Provider sub-delegated some of its schema grants to another Provider
+		SubDelegationGranted {
+			/// The Provider that sub-delegated its grants
+			parent_provider_id: ProviderId,
+			/// The Provider the grants were sub-delegated to
+			sub_provider_id: ProviderId,
+		},
+		/// A Provider revoked the schema grants it sub-delegated to another Provider
+		SubDelegationRevoked {
+			/// The Provider that sub-delegated its grants
+			parent_provider_id: ProviderId,
+			/// The Provider the grants were sub-delegated to
+			sub_provider_id: ProviderId,
+		},
+		/// The keys of an MSA were moved to another MSA, which its delegations and data will follow
+		MsaMerged {
+			/// The merged MSA, which is retired
+			source_msa_id: MessageSourceId,
+			/// The MSA it was merged into
+			target_msa_id: MessageSourceId,
+		},
+		/// All of the delegations and data of a merged MSA have been moved
+		MsaMergeCompleted {
+			/// The merged MSA
+			source_msa_id: MessageSourceId,
+			/// The MSA it was merged into
+			target_msa_id: MessageSourceId,
+		},
+		/// The MSA a merged MSA was merged into was retired before the merge completed, so what was
+		/// not yet moved is purged with the merged MSA instead
+		MsaMergeAbandoned {
+			/// The merged MSA
+			source_msa_id: MessageSourceId,
+			/// The retired MSA it was merged into
+			target_msa_id: MessageSourceId,
+		},
+		/// A key of an MSA asked to cancel an action proposed by another key
+		ThresholdActionRejected {
+			/// The MSA id for the Event
+			msa_id: MessageSourceId,
+			/// The id of the proposal
+			proposal_id: u32,
+			/// The key that asked to cancel the action
+			key: T::AccountId,
+		},
 	}
 
 	#[pallet::error]
@@ -379,6 +841,115 @@ pub mod pallet {
 
 		/// Attempted to add a new signature to a corrupt signature registry
 		SignatureRegistryCorrupted,
+
+		/// The requested delegation expiry block is not in the future
+		DelegationExpiryInPast,
+
+		/// The schema grant does not include the permission required for the action
+		SchemaPermissionNotGranted,
+
+		/// The recovery guardians or threshold are not valid
+		InvalidRecoveryConfig,
+
+		/// The MSA has not registered any recovery guardians
+		RecoveryNotConfigured,
+
+		/// Origin's MSA is not a recovery guardian of the MSA
+		NotRecoveryGuardian,
+
+		/// A recovery is already in progress for the MSA
+		RecoveryAlreadyInProgress,
+
+		/// There is no recovery in progress for the MSA
+		RecoveryNotInProgress,
+
+		/// The guardian has already approved the recovery
+		RecoveryAlreadyApproved,
+
+		/// The key does not match the key of the recovery in progress
+		RecoveryKeyMismatch,
+
+		/// The recovery secret is missing or does not match the registered commitment
+		InvalidRecoverySecret,
+
+		/// Not enough guardians have approved the recovery
+		RecoveryThresholdNotMet,
+
+		/// The recovery delay has not yet passed
+		RecoveryDelayNotElapsed,
+
+		/// The maximum size of a provider metadata field has been exceeded
+		ExceedsMaxProviderMetadataFieldSize,
+
+		/// The provider is retiring or has retired
+		ProviderRetiring,
+
+		/// The provider has not started retiring
+		ProviderNotRetiring,
+
+		/// The provider retirement grace period has not yet passed
+		ProviderRetirementGracePeriodNotElapsed,
+
+		/// The scope of the key does not allow this action
+		KeyScopeRestricted,
+
+		/// The Provider already has a pending delegation request for the Delegator
+		DelegationRequestAlreadyPending,
+
+		/// There is no pending delegation request matching the one given
+		DelegationRequestNotFound,
+
+		/// The delegation request can no longer be accepted
+		DelegationRequestExpired,
+
+		/// The threshold is less than two or greater than the number of keys of the MSA
+		InvalidThresholdPolicy,
+
+		/// The MSA has a threshold policy, so the action must be proposed and approved by its keys
+		ThresholdApprovalRequired,
+
+		/// The MSA does not have a threshold policy
+		ThresholdPolicyNotSet,
+
+		/// There is no proposed action with the given id for the MSA
+		ThresholdProposalNotFound,
+
+		/// The key has already approved the proposed action
+		ThresholdProposalAlreadyApproved,
+
+		/// The approved action would leave the MSA with fewer keys than its threshold,
+		/// or would retire a provider MSA or an MSA with keys that did not approve it
+		ThresholdActionNotAllowed,
+
+		/// The Provider has not sub-delegated its grants to the given Provider
+		SubDelegationNotFound,
+
+		/// The Provider already has the maximum number of sub-delegations from other Providers
+		ExceedsMaxSubDelegationsPerProvider,
+
+		/// An MSA cannot be merged into itself
+		MsaMergeIntoSelf,
+
+		/// A provider MSA cannot be merged into another MSA
+		MsaMergeSourceIsProvider,
+
+		/// The keys given for the merged MSA are not exactly the keys of that MSA
+		MsaMergeKeysMismatch,
+
+		/// The Delegator already has the maximum number of pending delegation requests
+		ExceedsMaxPendingDelegationRequests,
+
+		/// The delegation request would stay pending for longer than allowed
+		DelegationRequestExpirationTooFar,
+
+		/// The delegation request can still be accepted
+		DelegationRequestNotExpired,
+
+		/// The MSA already has the maximum number of actions waiting for approvals
+		ExceedsMaxThresholdProposals,
+
+		/// The key has already asked to cancel the proposed action
+		ThresholdProposalAlreadyRejected,
 	}
 
 	impl<T: Config> BlockNumberProvider for Pallet<T> {
@@ -397,6 +968,19 @@ pub mod pallet {
 			T::DbWeight::get().reads_writes(1u64, 1u64)
 		}
 
+		fn on_idle(_block_number: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let migration_weight = crate::migration::v2::index_delegations::<T>(remaining_weight);
+			let merge_weight =
+				Self::merge_queued_msas(remaining_weight.saturating_sub(migration_weight));
+			let used_weight = migration_weight.saturating_add(merge_weight);
+			let purge_weight =
+				Self::purge_retired_msas(remaining_weight.saturating_sub(used_weight));
+			let used_weight = used_weight.saturating_add(purge_weight);
+			used_weight.saturating_add(Self::revoke_retired_provider_delegations(
+				remaining_weight.saturating_sub(used_weight),
+			))
+		}
+
 		fn offchain_worker(block_number: BlockNumberFor<T>) {
 			log::info!("Running offchain workers! {:?}", block_number);
 			do_offchain_worker::<T>(block_number)
@@ -445,14 +1029,20 @@ pub mod pallet {
 		/// * [`Error::NoKeyExists`] - there is no MSA for `origin`.
 		/// * [`Error::KeyAlreadyRegistered`] - there is already an MSA for `delegator_key`.
 		/// * [`Error::ProviderNotRegistered`] - the a non-provider MSA is used as the provider
+		/// * [`Error::ProviderRetiring`] - the provider is retiring
 		/// * [`Error::ProofNotYetValid`] - `add_provider_payload` expiration is too far in the future
 		/// * [`Error::ProofHasExpired`] - `add_provider_payload` expiration is in the past
 		/// * [`Error::SignatureAlreadySubmitted`] - signature has already been used
+		/// * [`Error::DelegationExpiryInPast`] - `add_provider_payload.expires_at` is not in the future
 		///
 		#[pallet::call_index(1)]
-		#[pallet::weight(T::WeightInfo::create_sponsored_account_with_delegation(
-		add_provider_payload.schema_ids.len() as u32
-		))]
+		// Indexing the delegator under its provider is not covered by the generated weight
+		#[pallet::weight(
+			T::WeightInfo::create_sponsored_account_with_delegation(
+				add_provider_payload.schema_ids.len() as u32
+			)
+			.saturating_add(T::DbWeight::get().writes(1))
+		)]
 		pub fn create_sponsored_account_with_delegation(
 			origin: OriginFor<T>,
 			delegator_key: T::AccountId,
@@ -461,7 +1051,14 @@ pub mod pallet {
 		) -> DispatchResult {
 			let provider_key = ensure_signed(origin)?;
 
-			Self::verify_signature(&proof, &delegator_key, add_provider_payload.encode())?;
+			Self::ensure_valid_delegation_expiry(add_provider_payload.expires_at.map(Into::into))?;
+
+			Self::verify_signature(
+				&proof,
+				&delegator_key,
+				&add_provider_payload,
+				add_provider_payload.signed_payload(),
+			)?;
 
 			Self::register_signature(&proof, add_provider_payload.expiration.into())?;
 
@@ -476,6 +1073,7 @@ pub mod pallet {
 				Self::is_registered_provider(provider_msa_id),
 				Error::<T>::ProviderNotRegistered
 			);
+			ensure!(!Self::is_retiring_provider(provider_msa_id), Error::<T>::ProviderRetiring);
 
 			let (new_delegator_msa_id, new_delegator_public_key) =
 				Self::create_account(delegator_key, |new_msa_id| -> DispatchResult {
@@ -483,6 +1081,7 @@ pub mod pallet {
 						ProviderId(provider_msa_id),
 						DelegatorId(new_msa_id),
 						add_provider_payload.schema_ids,
+						add_provider_payload.expires_at.map(Into::into),
 					)?;
 					Ok(())
 				})?;
@@ -491,10 +1090,12 @@ pub mod pallet {
 				Event::MsaCreated { msa_id: new_delegator_msa_id, key: new_delegator_public_key };
 			offchain_index_event::<T>(&event, new_delegator_msa_id);
 			Self::deposit_event(event);
-			Self::deposit_event(Event::DelegationGranted {
+			let event = Event::DelegationGranted {
 				delegator_id: DelegatorId(new_delegator_msa_id),
 				provider_id: ProviderId(provider_msa_id),
-			});
+			};
+			offchain_index_event::<T>(&event, new_delegator_msa_id);
+			Self::deposit_event(event);
 			Ok(())
 		}
 
@@ -506,6 +1107,7 @@ pub mod pallet {
 		///
 		/// # Errors
 		/// * [`Error::NoKeyExists`] - origin does not have an MSA
+		/// * [`Error::KeyScopeRestricted`] - origin is scoped without the power to manage its MSA.
 		/// * [`Error::ExceedsMaxProviderNameSize`] - Too long of a provider name
 		/// * [`Error::DuplicateProviderRegistryEntry`] - a ProviderRegistryEntry associated with the given MSA id already exists.
 		///
@@ -514,6 +1116,7 @@ pub mod pallet {
 		pub fn create_provider(origin: OriginFor<T>, provider_name: Vec<u8>) -> DispatchResult {
 			let provider_key = ensure_signed(origin)?;
 			let provider_msa_id = Self::ensure_valid_msa_key(&provider_key)?;
+			Self::ensure_key_can_manage_msa(&provider_key)?;
 			Self::create_provider_for(provider_msa_id, provider_name)?;
 			Self::deposit_event(Event::ProviderCreated {
 				provider_id: ProviderId(provider_msa_id),
@@ -539,10 +1142,18 @@ pub mod pallet {
 		/// * [`Error::InvalidSignature`] - `proof` verification fails; `delegator_key` must have signed `add_provider_payload`
 		/// * [`Error::NoKeyExists`] - there is no MSA for `origin` or `delegator_key`.
 		/// * [`Error::ProviderNotRegistered`] - the a non-provider MSA is used as the provider
+		/// * [`Error::ProviderRetiring`] - the provider is retiring
+		/// * [`Error::KeyScopeRestricted`] - `delegator_key` is scoped without the power to manage its MSA
 		/// * [`Error::UnauthorizedDelegator`] - Origin attempted to add a delegate for someone else's MSA
+		/// * [`Error::DelegationExpiryInPast`] - `add_provider_payload.expires_at` is not in the future
 		///
 		#[pallet::call_index(3)]
-		#[pallet::weight(T::WeightInfo::grant_delegation(add_provider_payload.schema_ids.len() as u32))]
+		// Indexing the delegator under its provider is not covered by the generated weight
+		#[pallet::weight(
+			T::WeightInfo::grant_delegation(add_provider_payload.schema_ids.len() as u32)
+				.saturating_add(T::DbWeight::get().writes(1))
+				.saturating_add(Pallet::<T>::key_scopes_weight(1, 0))
+		)]
 		pub fn grant_delegation(
 			origin: OriginFor<T>,
 			delegator_key: T::AccountId,
@@ -551,13 +1162,21 @@ pub mod pallet {
 		) -> DispatchResult {
 			let provider_key = ensure_signed(origin)?;
 
+			Self::ensure_valid_delegation_expiry(add_provider_payload.expires_at.map(Into::into))?;
+
 			// delegator must have signed the payload.
-			Self::verify_signature(&proof, &delegator_key, add_provider_payload.encode())
-				.map_err(|_| Error::<T>::AddProviderSignatureVerificationFailed)?;
+			Self::verify_signature(
+				&proof,
+				&delegator_key,
+				&add_provider_payload,
+				add_provider_payload.signed_payload(),
+			)
+			.map_err(|_| Error::<T>::AddProviderSignatureVerificationFailed)?;
 
 			Self::register_signature(&proof, add_provider_payload.expiration.into())?;
 			let (provider_id, delegator_id) =
 				Self::ensure_valid_registered_provider(&delegator_key, &provider_key)?;
+			Self::ensure_key_can_manage_msa(&delegator_key)?;
 
 			ensure!(
 				add_provider_payload.authorized_msa_id == provider_id.0,
@@ -568,8 +1187,11 @@ pub mod pallet {
 				provider_id,
 				delegator_id,
 				add_provider_payload.schema_ids,
+				add_provider_payload.expires_at.map(Into::into),
 			)?;
-			Self::deposit_event(Event::DelegationGranted { delegator_id, provider_id });
+			let event = Event::DelegationGranted { delegator_id, provider_id };
+			offchain_index_event::<T>(&event, delegator_id.into());
+			Self::deposit_event(event);
 
 			Ok(())
 		}
@@ -583,10 +1205,17 @@ pub mod pallet {
 		///
 		/// * [`Error::NoKeyExists`] - origin does not have an MSA
 		/// * [`Error::DelegationRevoked`] - the delegation has already been revoked.
+		/// * [`Error::KeyScopeRestricted`] - origin is scoped without the power to manage its MSA.
+		/// * [`Error::ThresholdApprovalRequired`] - the MSA has a threshold policy.
 		/// * [`Error::DelegationNotFound`] - there is not delegation relationship between Origin and Delegator or Origin and Delegator are the same.
 		///
 		#[pallet::call_index(4)]
-		#[pallet::weight((T::WeightInfo::revoke_delegation_by_delegator(), DispatchClass::Normal, Pays::No))]
+		#[pallet::weight((
+			T::WeightInfo::revoke_delegation_by_delegator()
+				.saturating_add(Pallet::<T>::threshold_policy_read_weight()),
+			DispatchClass::Normal,
+			Pays::No
+		))]
 		pub fn revoke_delegation_by_delegator(
 			origin: OriginFor<T>,
 			#[pallet::compact] provider_msa_id: MessageSourceId,
@@ -595,10 +1224,14 @@ pub mod pallet {
 
 			match PublicKeyToMsaId::<T>::get(&who) {
 				Some(delegator_msa_id) => {
+					Self::ensure_key_can_manage_msa(&who)?;
+					Self::ensure_no_threshold_policy(delegator_msa_id)?;
 					let delegator_id = DelegatorId(delegator_msa_id);
 					let provider_id = ProviderId(provider_msa_id);
 					Self::revoke_provider(provider_id, delegator_id)?;
-					Self::deposit_event(Event::DelegationRevoked { delegator_id, provider_id });
+					let event = Event::DelegationRevoked { delegator_id, provider_id };
+					offchain_index_event::<T>(&event, delegator_msa_id);
+					Self::deposit_event(event);
 				},
 				None => {
 					log::error!(
@@ -622,6 +1255,8 @@ pub mod pallet {
 		///
 		/// The necessary information for the key addition, the new public key and the MSA ID, is contained in the `add_key_payload` parameter of type [AddKeyData].
 		/// It also contains an expiration block number for both proofs, ensuring they are valid and must be greater than the current block.
+		/// A `scope` in the payload restricts what the new key may do; without one the new key has full power over the MSA.
+		/// A scoped `msa_owner_public_key` may only add keys whose `scope` is within its own.
 		///
 		/// # Events
 		/// * [`Event::PublicKeyAdded`]
@@ -632,12 +1267,23 @@ pub mod pallet {
 		/// * [`Error::NewKeyOwnershipInvalidSignature`] - `key` is not a valid signer of the provided `add_key_payload`.
 		/// * [`Error::NoKeyExists`] - the MSA id for the account in `add_key_payload` does not exist.
 		/// * [`Error::NotMsaOwner`] - Origin's MSA is not the same as 'add_key_payload` MSA. Essentially you can only add a key to your own MSA.
+		/// * [`Error::KeyScopeRestricted`] - `msa_owner_public_key` is scoped without the power to manage the MSA,
+		/// or the `scope` grants more than the scope of `msa_owner_public_key`.
+		/// * [`Error::InvalidSchemaId`] - a schema in the `scope` does not exist.
 		/// * [`Error::ProofHasExpired`] - the current block is less than the `expired` block number set in `AddKeyData`.
 		/// * [`Error::ProofNotYetValid`] - the `expired` block number set in `AddKeyData` is greater than the current block number plus mortality_block_limit().
 		/// * [`Error::SignatureAlreadySubmitted`] - signature has already been used.
+		/// * [`Error::ThresholdApprovalRequired`] - the MSA has a threshold policy.
 		///
 		#[pallet::call_index(5)]
-		#[pallet::weight(T::WeightInfo::add_public_key_to_msa())]
+		// Validating the schemas in the scope of the new key is not covered by the generated weight
+		#[pallet::weight(
+			T::WeightInfo::add_public_key_to_msa()
+				.saturating_add(Pallet::<T>::key_scopes_weight(1, 1))
+				.saturating_add(T::DbWeight::get().reads(1))
+				.saturating_add(Weight::from_parts(0, 497))
+				.saturating_add(Pallet::<T>::threshold_policy_read_weight())
+		)]
 		pub fn add_public_key_to_msa(
 			origin: OriginFor<T>,
 			msa_owner_public_key: T::AccountId,
@@ -650,14 +1296,16 @@ pub mod pallet {
 			Self::verify_signature(
 				&msa_owner_proof,
 				&msa_owner_public_key,
-				add_key_payload.encode(),
+				&add_key_payload,
+				add_key_payload.signed_payload(),
 			)
 			.map_err(|_| Error::<T>::MsaOwnershipInvalidSignature)?;
 
 			Self::verify_signature(
 				&new_key_owner_proof,
 				&add_key_payload.new_public_key.clone(),
-				add_key_payload.encode(),
+				&add_key_payload,
+				add_key_payload.signed_payload(),
 			)
 			.map_err(|_| Error::<T>::NewKeyOwnershipInvalidSignature)?;
 
@@ -667,22 +1315,10 @@ pub mod pallet {
 			let msa_id = add_key_payload.msa_id;
 
 			Self::ensure_msa_owner(&msa_owner_public_key, msa_id)?;
+			Self::ensure_no_threshold_policy(msa_id)?;
+			Self::ensure_scope_within_key_scope(&msa_owner_public_key, &add_key_payload.scope)?;
 
-			Self::add_key(
-				msa_id,
-				&add_key_payload.new_public_key.clone(),
-				|msa_id| -> DispatchResult {
-					let event = Event::PublicKeyAdded {
-						msa_id,
-						key: add_key_payload.new_public_key.clone(),
-					};
-					offchain_index_event::<T>(&event, msa_id);
-					Self::deposit_event(event);
-					Ok(())
-				},
-			)?;
-
-			Ok(())
+			Self::add_scoped_key(add_key_payload)
 		}
 
 		/// Remove a key associated with an MSA by expiring it at the current block.
@@ -696,11 +1332,19 @@ pub mod pallet {
 		///
 		/// # Errors
 		/// * [`Error::InvalidSelfRemoval`] - `origin` and `key` are the same.
+		/// * [`Error::KeyScopeRestricted`] - `origin` is scoped without the power to manage its MSA.
+		/// * [`Error::ThresholdApprovalRequired`] - the MSA has a threshold policy.
 		/// * [`Error::NotKeyOwner`] - `origin` does not own the MSA ID associated with `key`.
 		/// * [`Error::NoKeyExists`] - `origin` or `key` are not associated with `origin`'s MSA ID.
 		///
 		#[pallet::call_index(6)]
-		#[pallet::weight((T::WeightInfo::delete_msa_public_key(), DispatchClass::Normal, Pays::No))]
+		#[pallet::weight((
+			T::WeightInfo::delete_msa_public_key()
+				.saturating_add(Pallet::<T>::key_scopes_weight(1, 1))
+				.saturating_add(Pallet::<T>::threshold_policy_read_weight()),
+			DispatchClass::Normal,
+			Pays::No
+		))]
 		pub fn delete_msa_public_key(
 			origin: OriginFor<T>,
 			public_key_to_delete: T::AccountId,
@@ -709,6 +1353,8 @@ pub mod pallet {
 
 			match PublicKeyToMsaId::<T>::get(&who) {
 				Some(who_msa_id) => {
+					Self::ensure_key_can_manage_msa(&who)?;
+					Self::ensure_no_threshold_policy(who_msa_id)?;
 					Self::delete_key_for_msa(who_msa_id, &public_key_to_delete)?;
 
 					// Deposit the event
@@ -735,10 +1381,17 @@ pub mod pallet {
 		///
 		/// * [`Error::NoKeyExists`] - `provider_key` does not have an MSA key.
 		/// * [`Error::DelegationRevoked`] - delegation is already revoked
+		/// * [`Error::KeyScopeRestricted`] - `provider_key` is scoped without the power to manage its MSA.
+		/// * [`Error::ThresholdApprovalRequired`] - the MSA has a threshold policy.
 		/// * [`Error::DelegationNotFound`] - no Delegation found between origin MSA and delegator MSA.
 		///
 		#[pallet::call_index(7)]
-		#[pallet::weight((T::WeightInfo::revoke_delegation_by_provider(), DispatchClass::Normal, Pays::No))]
+		#[pallet::weight((
+			T::WeightInfo::revoke_delegation_by_provider()
+				.saturating_add(Pallet::<T>::threshold_policy_read_weight()),
+			DispatchClass::Normal,
+			Pays::No
+		))]
 		pub fn revoke_delegation_by_provider(
 			origin: OriginFor<T>,
 			#[pallet::compact] delegator: MessageSourceId,
@@ -750,10 +1403,14 @@ pub mod pallet {
 			// validity checks are in SignedExtension so in theory this should never error.
 			match PublicKeyToMsaId::<T>::get(&who) {
 				Some(msa_id) => {
+					Self::ensure_key_can_manage_msa(&who)?;
+					Self::ensure_no_threshold_policy(msa_id)?;
 					let provider_id = ProviderId(msa_id);
 					let delegator_id = DelegatorId(delegator);
 					Self::revoke_provider(provider_id, delegator_id)?;
-					Self::deposit_event(Event::DelegationRevoked { provider_id, delegator_id })
+					let event = Event::DelegationRevoked { provider_id, delegator_id };
+					offchain_index_event::<T>(&event, delegator);
+					Self::deposit_event(event);
 				},
 				None => {
 					log::error!(
@@ -776,13 +1433,16 @@ pub mod pallet {
 		///
 		/// # Errors
 		/// - [`NoKeyExists`](Error::NoKeyExists) - If there is not MSA for `origin`.
+		/// - [`KeyScopeRestricted`](Error::KeyScopeRestricted) - If `origin` is scoped without the power to manage its MSA.
+		/// - [`ThresholdApprovalRequired`](Error::ThresholdApprovalRequired) - If the MSA of `origin` has a threshold policy.
 		/// - [`DelegationNotFound`](Error::DelegationNotFound) - If there is not delegation relationship between Origin and Delegator or Origin and Delegator are the same.
 		/// - [`SchemaNotGranted`](Error::SchemaNotGranted) - If attempting to revoke a schema that has not previously been granted.
 		///
 		#[pallet::call_index(9)]
-		#[pallet::weight(T::WeightInfo::revoke_schema_permissions(
-		schema_ids.len() as u32
-		))]
+		#[pallet::weight(
+			T::WeightInfo::revoke_schema_permissions(schema_ids.len() as u32)
+				.saturating_add(Pallet::<T>::threshold_policy_read_weight())
+		)]
 		#[allow(deprecated)]
 		#[deprecated(since = "1.3.0", note = "revoke_schema_permissions() has been deprecated.")]
 		pub fn revoke_schema_permissions(
@@ -792,13 +1452,14 @@ pub mod pallet {
 		) -> DispatchResult {
 			let delegator_key = ensure_signed(origin)?;
 			let delegator_msa_id = Self::ensure_valid_msa_key(&delegator_key)?;
-			let provider_id = ProviderId(provider_msa_id);
-			let delegator_id = DelegatorId(delegator_msa_id);
+			Self::ensure_key_can_manage_msa(&delegator_key)?;
+			Self::ensure_no_threshold_policy(delegator_msa_id)?;
 
-			Self::revoke_permissions_for_schemas(delegator_id, provider_id, schema_ids)?;
-			Self::deposit_event(Event::DelegationUpdated { provider_id, delegator_id });
-
-			Ok(())
+			Self::revoke_delegation_schemas(
+				DelegatorId(delegator_msa_id),
+				ProviderId(provider_msa_id),
+				schema_ids,
+			)
 		}
 
 		/// Retires a MSA
@@ -806,10 +1467,10 @@ pub mod pallet {
 		/// When a user wants to disassociate themselves from Recurrency, they can retire their MSA for free provided that:
 		///  (1) They own the MSA
 		///  (2) The MSA is not a registered provider.
-		///  (3) They retire their user handle (if they have one)
-		///  (4) There is only one account key
-		///  (5) The user has already deleted all delegations to providers
+		///  (3) There is only one account key
 		///
+		/// The remaining delegations, stateful storage and handle of the MSA are then purged over
+		/// the following blocks, as block space allows. See [`Event::MsaPurgeProgressed`].
 		/// This does not currently remove any messages related to the MSA.
 		///
 		/// # Events
@@ -818,9 +1479,20 @@ pub mod pallet {
 		///
 		/// # Errors
 		/// * [`Error::NoKeyExists`] - `delegator` does not have an MSA key.
+		/// * [`Error::KeyScopeRestricted`] - `delegator` is scoped without the power to manage its MSA.
+		/// * [`Error::ThresholdApprovalRequired`] - the MSA has a threshold policy.
 		///
 		#[pallet::call_index(10)]
-		#[pallet::weight((T::WeightInfo::retire_msa(), DispatchClass::Normal, Pays::No))]
+		// Removing the recovery of the MSA and queueing the purge of its data are not covered by
+		// the generated weight
+		#[pallet::weight((
+			T::WeightInfo::retire_msa()
+				.saturating_add(T::DbWeight::get().writes(3))
+				.saturating_add(Pallet::<T>::key_scopes_weight(1, 1))
+				.saturating_add(Pallet::<T>::threshold_policy_read_weight()),
+			DispatchClass::Normal,
+			Pays::No
+		))]
 		pub fn retire_msa(origin: OriginFor<T>) -> DispatchResult {
 			// Check and get the account id from the origin
 			let who = ensure_signed(origin)?;
@@ -829,10 +1501,14 @@ pub mod pallet {
 			// check for valid MSA is in SignedExtension.
 			match PublicKeyToMsaId::<T>::get(&who) {
 				Some(msa_id) => {
+					Self::ensure_key_can_manage_msa(&who)?;
+					Self::ensure_no_threshold_policy(msa_id)?;
 					Self::delete_key_for_msa(msa_id, &who)?;
 					let event = Event::PublicKeyDeleted { key: who };
 					offchain_index_event::<T>(&event, msa_id);
 					Self::deposit_event(event);
+					Self::remove_recovery(msa_id);
+					RetiredMsaPurgeQueue::<T>::insert(msa_id, ());
 					Self::deposit_event(Event::MsaRetired { msa_id });
 				},
 				None => {
@@ -897,162 +1573,1465 @@ pub mod pallet {
 			});
 			Ok(())
 		}
-	}
-}
 
-impl<T: Config> Pallet<T> {
-	/// Create the account for the `key`
-	///
-	/// # Errors
-	/// * [`Error::MsaIdOverflow`]
-	/// * [`Error::KeyLimitExceeded`]
-	/// * [`Error::KeyAlreadyRegistered`]
-	///
-	pub fn create_account<F>(
-		key: T::AccountId,
-		on_success: F,
-	) -> Result<(MessageSourceId, T::AccountId), DispatchError>
-	where
-		F: FnOnce(MessageSourceId) -> DispatchResult,
-	{
-		let next_msa_id = Self::get_next_msa_id()?;
-		Self::add_key(next_msa_id, &key, on_success)?;
-		let _ = Self::set_msa_identifier(next_msa_id);
+		/// Sets which actions a Provider may take on behalf of the Delegator with a granted schema.
+		/// The origin must be a key of the Delegator MSA.
+		///
+		/// # Events
+		/// * [`Event::DelegationUpdated`]
+		///
+		/// # Errors
+		/// * [`Error::NoKeyExists`] - origin does not have an MSA key.
+		/// * [`Error::KeyScopeRestricted`] - origin is scoped without the power to manage its MSA.
+		/// * [`Error::ThresholdApprovalRequired`] - the MSA has a threshold policy.
+		/// * [`Error::DelegationNotFound`] - there is no delegation between the origin MSA and the Provider.
+		/// * [`Error::DelegationRevoked`] - the delegation has been revoked.
+		/// * [`Error::SchemaNotGranted`] - the schema is not currently granted to the Provider.
+		///
+		#[pallet::call_index(13)]
+		#[pallet::weight(
+			T::WeightInfo::set_schema_grant_permissions()
+				.saturating_add(Pallet::<T>::threshold_policy_read_weight())
+		)]
+		pub fn set_schema_grant_permissions(
+			origin: OriginFor<T>,
+			#[pallet::compact] provider_msa_id: MessageSourceId,
+			#[pallet::compact] schema_id: SchemaId,
+			permissions: SchemaPermissions,
+		) -> DispatchResult {
+			let delegator_key = ensure_signed(origin)?;
+			let delegator_msa_id = Self::ensure_valid_msa_key(&delegator_key)?;
+			Self::ensure_key_can_manage_msa(&delegator_key)?;
+			Self::ensure_no_threshold_policy(delegator_msa_id)?;
+
+			Self::set_delegation_schema_permissions(
+				DelegatorId(delegator_msa_id),
+				ProviderId(provider_msa_id),
+				schema_id,
+				permissions,
+			)
+		}
 
-		Ok((next_msa_id, key))
-	}
+		/// Registers or replaces the guardians that may recover the origin's MSA.
+		/// Guardians may be recovery providers or any other MSAs, and `threshold` of them must
+		/// approve a recovery. If `commitment` is set, a recovery may only be initiated by revealing
+		/// a secret whose blake2-256 hash matches it.
+		///
+		/// # Events
+		/// * [`Event::RecoveryConfigSet`]
+		///
+		/// # Errors
+		/// * [`Error::NoKeyExists`] - origin does not have an MSA key.
+		/// * [`Error::KeyScopeRestricted`] - origin is scoped without the power to manage its MSA.
+		/// * [`Error::ThresholdApprovalRequired`] - the MSA has a threshold policy.
+		/// * [`Error::InvalidRecoveryConfig`] - a guardian is the MSA itself, is repeated, or has no keys,
+		/// or `threshold` is zero or greater than the number of guardians.
+		/// * [`Error::RecoveryAlreadyInProgress`] - a recovery of the MSA is in progress.
+		///
+		#[pallet::call_index(14)]
+		#[pallet::weight(
+			T::WeightInfo::set_recovery_config(guardians.len() as u32)
+				.saturating_add(Weight::from_parts(0, REPLACED_KEY_PROOF_SIZE))
+				.saturating_add(Pallet::<T>::threshold_policy_read_weight())
+		)]
+		pub fn set_recovery_config(
+			origin: OriginFor<T>,
+			guardians: BoundedVec<MessageSourceId, T::MaxRecoveryGuardians>,
+			threshold: u32,
+			commitment: Option<H256>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let msa_id = Self::ensure_valid_msa_key(&who)?;
+			Self::ensure_key_can_manage_msa(&who)?;
+			Self::ensure_no_threshold_policy(msa_id)?;
 
-	/// Generate the next MSA Id
-	///
-	/// # Errors
-	/// * [`Error::MsaIdOverflow`]
-	///
-	pub fn get_next_msa_id() -> Result<MessageSourceId, DispatchError> {
-		let next = CurrentMsaIdentifierMaximum::<T>::get()
-			.checked_add(1)
-			.ok_or(Error::<T>::MsaIdOverflow)?;
+			Self::apply_recovery_config(msa_id, guardians, threshold, commitment)
+		}
 
-		Ok(next)
-	}
+		/// Removes the recovery guardians of the origin's MSA.
+		///
+		/// # Events
+		/// * [`Event::RecoveryConfigRemoved`]
+		///
+		/// # Errors
+		/// * [`Error::NoKeyExists`] - origin does not have an MSA key.
+		/// * [`Error::KeyScopeRestricted`] - origin is scoped without the power to manage its MSA.
+		/// * [`Error::ThresholdApprovalRequired`] - the MSA has a threshold policy.
+		/// * [`Error::RecoveryNotConfigured`] - the MSA has no recovery guardians.
+		/// * [`Error::RecoveryAlreadyInProgress`] - a recovery of the MSA is in progress.
+		///
+		#[pallet::call_index(15)]
+		#[pallet::weight(
+			T::WeightInfo::remove_recovery_config()
+				.saturating_add(Weight::from_parts(0, REPLACED_KEY_PROOF_SIZE))
+				.saturating_add(Pallet::<T>::threshold_policy_read_weight())
+		)]
+		pub fn remove_recovery_config(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let msa_id = Self::ensure_valid_msa_key(&who)?;
+			Self::ensure_key_can_manage_msa(&who)?;
+			Self::ensure_no_threshold_policy(msa_id)?;
 
-	/// Set the current identifier in storage
-	pub fn set_msa_identifier(identifier: MessageSourceId) -> DispatchResult {
-		CurrentMsaIdentifierMaximum::<T>::set(identifier);
+			Self::clear_recovery_config(msa_id)
+		}
 
-		Ok(())
-	}
+		/// Starts recovering `msa_id` by adding `new_key` to it. The origin must be a key of one of
+		/// the MSA's guardians, and counts as the first approval. The recovery can be completed
+		/// once enough guardians have approved it and [`Config::RecoveryDelay`] blocks have passed.
+		/// If `replaced_key` is set, that key of the MSA is removed in favour of `new_key`, which lets
+		/// an MSA holding [`Config::MaxPublicKeysPerMsa`] keys be recovered.
+		///
+		/// # Events
+		/// * [`Event::RecoveryInitiated`]
+		///
+		/// # Errors
+		/// * [`Error::NoKeyExists`] - origin does not have an MSA key, or `msa_id` has no keys.
+		/// * [`Error::KeyScopeRestricted`] - origin is scoped without the power to manage its MSA.
+		/// * [`Error::RecoveryNotConfigured`] - `msa_id` has no recovery guardians.
+		/// * [`Error::NotRecoveryGuardian`] - origin's MSA is not a guardian of `msa_id`.
+		/// * [`Error::InvalidRecoverySecret`] - `recovery_secret` does not match the registered commitment.
+		/// * [`Error::KeyAlreadyRegistered`] - `new_key` already belongs to an MSA.
+		/// * [`Error::NotMsaOwner`] - `replaced_key` is not a key of `msa_id`.
+		/// * [`Error::RecoveryAlreadyInProgress`] - a recovery of `msa_id` is already in progress.
+		///
+		#[pallet::call_index(16)]
+		// Checking the replaced key is not covered by the generated weight
+		#[pallet::weight(
+			T::WeightInfo::initiate_recovery()
+				.saturating_add(T::DbWeight::get().reads(1))
+				.saturating_add(Weight::from_parts(0, REPLACED_KEY_PROOF_SIZE))
+		)]
+		pub fn initiate_recovery(
+			origin: OriginFor<T>,
+			#[pallet::compact] msa_id: MessageSourceId,
+			new_key: T::AccountId,
+			replaced_key: Option<T::AccountId>,
+			recovery_secret: Option<[u8; 32]>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let guardian_msa_id = Self::ensure_valid_msa_key(&who)?;
+			Self::ensure_key_can_manage_msa(&who)?;
+			ensure!(PublicKeyCountForMsaId::<T>::get(msa_id) > 0, Error::<T>::NoKeyExists);
+
+			let config =
+				RecoveryConfigs::<T>::get(msa_id).ok_or(Error::<T>::RecoveryNotConfigured)?;
+			ensure!(config.guardians.contains(&guardian_msa_id), Error::<T>::NotRecoveryGuardian);
+			if let Some(commitment) = config.commitment {
+				let secret = recovery_secret.ok_or(Error::<T>::InvalidRecoverySecret)?;
+				ensure!(
+					H256(sp_io::hashing::blake2_256(&secret)) == commitment,
+					Error::<T>::InvalidRecoverySecret
+				);
+			}
+			ensure!(
+				!PublicKeyToMsaId::<T>::contains_key(&new_key),
+				Error::<T>::KeyAlreadyRegistered
+			);
+			if let Some(key) = &replaced_key {
+				ensure!(PublicKeyToMsaId::<T>::get(key) == Some(msa_id), Error::<T>::NotMsaOwner);
+			}
+
+			ActiveRecoveries::<T>::try_mutate(msa_id, |maybe_recovery| -> DispatchResult {
+				ensure!(maybe_recovery.is_none(), Error::<T>::RecoveryAlreadyInProgress);
+				let mut approvals = BoundedVec::default();
+				approvals
+					.try_push(guardian_msa_id)
+					.map_err(|_| Error::<T>::InvalidRecoveryConfig)?;
+				*maybe_recovery = Some(ActiveRecovery {
+					new_key: new_key.clone(),
+					replaced_key,
+					initiated_at: frame_system::Pallet::<T>::block_number(),
+					approvals,
+				});
+				Ok(())
+			})?;
+			Self::deposit_event(Event::RecoveryInitiated { msa_id, guardian_msa_id, new_key });
 
-	/// Create Register Provider
-	pub fn create_registered_provider(
-		provider_id: ProviderId,
-		name: BoundedVec<u8, T::MaxProviderNameSize>,
-	) -> DispatchResult {
-		ProviderToRegistryEntry::<T>::try_mutate(provider_id, |maybe_metadata| -> DispatchResult {
-			ensure!(maybe_metadata.take().is_none(), Error::<T>::DuplicateProviderRegistryEntry);
-			*maybe_metadata = Some(ProviderRegistryEntry { provider_name: name });
 			Ok(())
-		})
-	}
+		}
 
-	/// Adds a list of schema permissions to a delegation relationship.
-	pub fn grant_permissions_for_schemas(
-		delegator_id: DelegatorId,
-		provider_id: ProviderId,
-		schema_ids: Vec<SchemaId>,
-	) -> DispatchResult {
-		Self::try_mutate_delegation(delegator_id, provider_id, |delegation, is_new_delegation| {
-			ensure!(!is_new_delegation, Error::<T>::DelegationNotFound);
-			Self::ensure_all_schema_ids_are_valid(&schema_ids)?;
+		/// Approves the recovery in progress for `msa_id`. The origin must be a key of one of the
+		/// MSA's guardians, and `new_key` must match the key being recovered to.
+		///
+		/// # Events
+		/// * [`Event::RecoveryApproved`]
+		///
+		/// # Errors
+		/// * [`Error::NoKeyExists`] - origin does not have an MSA key.
+		/// * [`Error::KeyScopeRestricted`] - origin is scoped without the power to manage its MSA.
+		/// * [`Error::RecoveryNotConfigured`] - `msa_id` has no recovery guardians.
+		/// * [`Error::NotRecoveryGuardian`] - origin's MSA is not a guardian of `msa_id`.
+		/// * [`Error::RecoveryNotInProgress`] - there is no recovery of `msa_id` in progress.
+		/// * [`Error::RecoveryKeyMismatch`] - `new_key` is not the key being recovered to.
+		/// * [`Error::RecoveryAlreadyApproved`] - origin's MSA has already approved the recovery.
+		///
+		#[pallet::call_index(17)]
+		#[pallet::weight(
+			T::WeightInfo::approve_recovery()
+				.saturating_add(Weight::from_parts(0, REPLACED_KEY_PROOF_SIZE))
+		)]
+		pub fn approve_recovery(
+			origin: OriginFor<T>,
+			#[pallet::compact] msa_id: MessageSourceId,
+			new_key: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let guardian_msa_id = Self::ensure_valid_msa_key(&who)?;
+			Self::ensure_key_can_manage_msa(&who)?;
 
-			PermittedDelegationSchemas::<T>::try_insert_schemas(delegation, schema_ids)?;
+			let config =
+				RecoveryConfigs::<T>::get(msa_id).ok_or(Error::<T>::RecoveryNotConfigured)?;
+			ensure!(config.guardians.contains(&guardian_msa_id), Error::<T>::NotRecoveryGuardian);
+
+			ActiveRecoveries::<T>::try_mutate(msa_id, |maybe_recovery| -> DispatchResult {
+				let recovery = maybe_recovery.as_mut().ok_or(Error::<T>::RecoveryNotInProgress)?;
+				ensure!(recovery.new_key == new_key, Error::<T>::RecoveryKeyMismatch);
+				ensure!(
+					!recovery.approvals.contains(&guardian_msa_id),
+					Error::<T>::RecoveryAlreadyApproved
+				);
+				recovery
+					.approvals
+					.try_push(guardian_msa_id)
+					.map_err(|_| Error::<T>::RecoveryAlreadyApproved)?;
+				Ok(())
+			})?;
+			Self::deposit_event(Event::RecoveryApproved { msa_id, guardian_msa_id });
 
 			Ok(())
-		})
-	}
+		}
 
-	/// Revokes a list of schema permissions from a delegation relationship.
-	pub fn revoke_permissions_for_schemas(
-		delegator_id: DelegatorId,
-		provider_id: ProviderId,
-		schema_ids: Vec<SchemaId>,
-	) -> DispatchResult {
-		Self::try_mutate_delegation(delegator_id, provider_id, |delegation, is_new_delegation| {
-			ensure!(!is_new_delegation, Error::<T>::DelegationNotFound);
-			Self::ensure_all_schema_ids_are_valid(&schema_ids)?;
+		/// Cancels the recovery in progress for the origin's MSA. Any key of the MSA may cancel it.
+		///
+		/// # Events
+		/// * [`Event::RecoveryCancelled`]
+		///
+		/// # Errors
+		/// * [`Error::NoKeyExists`] - origin does not have an MSA key.
+		/// * [`Error::KeyScopeRestricted`] - origin is scoped without the power to manage its MSA.
+		/// * [`Error::RecoveryNotInProgress`] - there is no recovery of the MSA in progress.
+		///
+		#[pallet::call_index(18)]
+		#[pallet::weight(
+			T::WeightInfo::cancel_recovery()
+				.saturating_add(Weight::from_parts(0, REPLACED_KEY_PROOF_SIZE))
+		)]
+		pub fn cancel_recovery(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let msa_id = Self::ensure_valid_msa_key(&who)?;
+			Self::ensure_key_can_manage_msa(&who)?;
+			ActiveRecoveries::<T>::take(msa_id).ok_or(Error::<T>::RecoveryNotInProgress)?;
+			Self::deposit_event(Event::RecoveryCancelled { msa_id });
+
+			Ok(())
+		}
+
+		/// Completes the recovery in progress for `msa_id`, adding its new key to the MSA and removing
+		/// the replaced key, if one was named and still belongs to the MSA.
+		/// May be called by anyone once enough guardians have approved the recovery and
+		/// [`Config::RecoveryDelay`] blocks have passed since it was initiated.
+		///
+		/// # Events
+		/// * [`Event::PublicKeyDeleted`]
+		/// * [`Event::PublicKeyAdded`]
+		/// * [`Event::RecoveryCompleted`]
+		///
+		/// # Errors
+		/// * [`Error::NoKeyExists`] - `msa_id` has no keys or is retired.
+		/// * [`Error::RecoveryNotInProgress`] - there is no recovery of `msa_id` in progress.
+		/// * [`Error::RecoveryNotConfigured`] - `msa_id` has no recovery guardians.
+		/// * [`Error::RecoveryThresholdNotMet`] - not enough guardians have approved the recovery.
+		/// * [`Error::RecoveryDelayNotElapsed`] - the recovery delay has not yet passed.
+		/// * [`Error::KeyLimitExceeded`] - the MSA already has the maximum number of keys and no key is replaced.
+		/// * [`Error::KeyAlreadyRegistered`] - the new key was registered to an MSA since the recovery was initiated.
+		///
+		#[pallet::call_index(19)]
+		// Removing the replaced key is not covered by the generated weight
+		#[pallet::weight(
+			T::WeightInfo::complete_recovery()
+				.saturating_add(T::DbWeight::get().reads_writes(1, 2))
+				.saturating_add(Weight::from_parts(0, REPLACED_KEY_PROOF_SIZE))
+		)]
+		pub fn complete_recovery(
+			origin: OriginFor<T>,
+			#[pallet::compact] msa_id: MessageSourceId,
+		) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+			ensure!(
+				PublicKeyCountForMsaId::<T>::get(msa_id) > 0 &&
+					!RetiredMsaPurgeQueue::<T>::contains_key(msa_id),
+				Error::<T>::NoKeyExists
+			);
 
+			let recovery =
+				ActiveRecoveries::<T>::get(msa_id).ok_or(Error::<T>::RecoveryNotInProgress)?;
+			let config =
+				RecoveryConfigs::<T>::get(msa_id).ok_or(Error::<T>::RecoveryNotConfigured)?;
+			ensure!(
+				recovery.approvals.len() >= config.threshold as usize,
+				Error::<T>::RecoveryThresholdNotMet
+			);
 			let current_block = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				current_block >= recovery.initiated_at.saturating_add(T::RecoveryDelay::get()),
+				Error::<T>::RecoveryDelayNotElapsed
+			);
 
-			PermittedDelegationSchemas::<T>::try_get_mut_schemas(
-				delegation,
-				schema_ids,
-				current_block,
-			)?;
+			if let Some(replaced_key) = recovery
+				.replaced_key
+				.filter(|key| PublicKeyToMsaId::<T>::get(key) == Some(msa_id))
+			{
+				Self::delete_key_for_msa(msa_id, &replaced_key)?;
+				let event = Event::PublicKeyDeleted { key: replaced_key };
+				offchain_index_event::<T>(&event, msa_id);
+				Self::deposit_event(event);
+			}
+			Self::add_key(msa_id, &recovery.new_key, |msa_id| -> DispatchResult {
+				let event = Event::PublicKeyAdded { msa_id, key: recovery.new_key.clone() };
+				offchain_index_event::<T>(&event, msa_id);
+				Self::deposit_event(event);
+				Ok(())
+			})?;
+			ActiveRecoveries::<T>::remove(msa_id);
+			Self::deposit_event(Event::RecoveryCompleted { msa_id, new_key: recovery.new_key });
 
 			Ok(())
-		})
-	}
+		}
 
-	/// Add a new key to the MSA
-	///
-	/// # Errors
-	/// * [`Error::KeyLimitExceeded`]
-	/// * [`Error::KeyAlreadyRegistered`]
-	///
-	pub fn add_key<F>(msa_id: MessageSourceId, key: &T::AccountId, on_success: F) -> DispatchResult
-	where
-		F: FnOnce(MessageSourceId) -> DispatchResult,
-	{
-		PublicKeyToMsaId::<T>::try_mutate(key, |maybe_msa_id| {
-			ensure!(maybe_msa_id.is_none(), Error::<T>::KeyAlreadyRegistered);
-			*maybe_msa_id = Some(msa_id);
+		/// Replaces an existing control key of an MSA with a new key in a single step.
+		///
+		/// Both the key being replaced and the new key must sign the `rotate_key_payload`, proving
+		/// that the owner of the old key approves the rotation and that the new key is controlled
+		/// by the MSA owner. The MSA keeps the same number of keys, so a rotation succeeds even when
+		/// the MSA already holds [`Config::MaxPublicKeysPerMsa`] keys.
+		///
+		/// # Events
+		/// * [`Event::PublicKeyRotated`]
+		///
+		/// # Errors
+		///
+		/// * [`Error::MsaOwnershipInvalidSignature`] - `old_public_key` is not a valid signer of the provided `rotate_key_payload`.
+		/// * [`Error::NewKeyOwnershipInvalidSignature`] - `new_public_key` is not a valid signer of the provided `rotate_key_payload`.
+		/// * [`Error::NoKeyExists`] - `old_public_key` is not registered to an MSA.
+		/// * [`Error::NotMsaOwner`] - `old_public_key` does not belong to the MSA in `rotate_key_payload`.
+		/// * [`Error::KeyAlreadyRegistered`] - `new_public_key` is already registered to an MSA.
+		/// * [`Error::ProofHasExpired`] - the current block is less than the `expired` block number set in `RotateKeyData`.
+		/// * [`Error::ProofNotYetValid`] - the `expired` block number set in `RotateKeyData` is greater than the current block number plus mortality_block_limit().
+		/// * [`Error::SignatureAlreadySubmitted`] - signature has already been used.
+		///
+		#[pallet::call_index(20)]
+		#[pallet::weight(
+			T::WeightInfo::rotate_msa_public_key().saturating_add(Pallet::<T>::key_scopes_weight(1, 2))
+		)]
+		pub fn rotate_msa_public_key(
+			origin: OriginFor<T>,
+			old_key_owner_proof: MultiSignature,
+			new_key_owner_proof: MultiSignature,
+			rotate_key_payload: RotateKeyData<T>,
+		) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
 
-			// Increment the key counter
-			<PublicKeyCountForMsaId<T>>::try_mutate(msa_id, |key_count| {
-				// key_count:u8 should default to 0 if it does not exist
-				let incremented_key_count =
-					key_count.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+			Self::verify_signature(
+				&old_key_owner_proof,
+				&rotate_key_payload.old_public_key,
+				&rotate_key_payload,
+				rotate_key_payload.encode(),
+			)
+			.map_err(|_| Error::<T>::MsaOwnershipInvalidSignature)?;
 
-				ensure!(
-					incremented_key_count <= T::MaxPublicKeysPerMsa::get(),
-					Error::<T>::KeyLimitExceeded
-				);
+			Self::verify_signature(
+				&new_key_owner_proof,
+				&rotate_key_payload.new_public_key,
+				&rotate_key_payload,
+				rotate_key_payload.encode(),
+			)
+			.map_err(|_| Error::<T>::NewKeyOwnershipInvalidSignature)?;
 
-				*key_count = incremented_key_count;
-				on_success(msa_id)
-			})
-		})
-	}
+			Self::register_signature(&old_key_owner_proof, rotate_key_payload.expiration)?;
+			Self::register_signature(&new_key_owner_proof, rotate_key_payload.expiration)?;
 
-	/// Check that schema ids are all valid
-	///
-	/// # Errors
-	/// * [`Error::InvalidSchemaId`]
-	/// * [`Error::ExceedsMaxSchemaGrantsPerDelegation`]
-	///
-	pub fn ensure_all_schema_ids_are_valid(schema_ids: &Vec<SchemaId>) -> DispatchResult {
-		ensure!(
-			schema_ids.len() <= T::MaxSchemaGrantsPerDelegation::get() as usize,
-			Error::<T>::ExceedsMaxSchemaGrantsPerDelegation
-		);
+			let msa_id = rotate_key_payload.msa_id;
 
-		let are_schemas_valid = T::SchemaValidator::are_all_schema_ids_valid(schema_ids);
+			Self::ensure_msa_owner(&rotate_key_payload.old_public_key, msa_id)?;
 
-		ensure!(are_schemas_valid, Error::<T>::InvalidSchemaId);
+			Self::rotate_key(
+				msa_id,
+				&rotate_key_payload.old_public_key,
+				&rotate_key_payload.new_public_key,
+			)?;
 
-		Ok(())
-	}
+			let event = Event::PublicKeyRotated {
+				msa_id,
+				old_key: rotate_key_payload.old_public_key,
+				new_key: rotate_key_payload.new_public_key,
+			};
+			offchain_index_event::<T>(&event, msa_id);
+			Self::deposit_event(event);
 
-	/// Returns if provider is registered by checking if the [`ProviderToRegistryEntry`] contains the MSA id
-	pub fn is_registered_provider(msa_id: MessageSourceId) -> bool {
-		ProviderToRegistryEntry::<T>::contains_key(ProviderId(msa_id))
-	}
+			Ok(())
+		}
 
-	/// Checks that a provider and delegator keys are valid
+		/// Publishes metadata about the Provider of the origin, replacing any metadata it published before.
+		/// The origin must be a key of a registered Provider MSA.
+		///
+		/// # Events
+		/// * [`Event::ProviderMetadataUpdated`]
+		///
+		/// # Errors
+		/// * [`Error::NoKeyExists`] - origin does not have an MSA key.
+		/// * [`Error::KeyScopeRestricted`] - origin is scoped without the power to manage its MSA.
+		/// * [`Error::ProviderNotRegistered`] - the origin MSA is not a registered provider.
+		/// * [`Error::ExceedsMaxProviderMetadataFieldSize`] - a text field is too long.
+		/// * [`Error::ThresholdApprovalRequired`] - the MSA has a threshold policy.
+		/// * [`Error::ExceedsMaxSchemaGrantsPerDelegation`] - too many supported schemas.
+		/// * [`Error::InvalidSchemaId`] - a supported schema does not exist.
+		///
+		#[pallet::call_index(21)]
+		#[pallet::weight(
+			T::WeightInfo::set_provider_metadata(supported_schema_ids.len() as u32)
+				.saturating_add(Pallet::<T>::threshold_policy_read_weight())
+		)]
+		pub fn set_provider_metadata(
+			origin: OriginFor<T>,
+			homepage_url: Vec<u8>,
+			logo_cid: Vec<u8>,
+			contact: Vec<u8>,
+			terms_of_service_hash: Option<H256>,
+			supported_schema_ids: Vec<SchemaId>,
+		) -> DispatchResult {
+			let provider_key = ensure_signed(origin)?;
+			let provider_msa_id = Self::ensure_valid_msa_key(&provider_key)?;
+			Self::ensure_key_can_manage_msa(&provider_key)?;
+			Self::ensure_no_threshold_policy(provider_msa_id)?;
+			ensure!(
+				Self::is_registered_provider(provider_msa_id),
+				Error::<T>::ProviderNotRegistered
+			);
+
+			Self::ensure_all_schema_ids_are_valid(&supported_schema_ids)?;
+			let metadata = ProviderMetadata {
+				homepage_url: Self::bound_provider_metadata_field(homepage_url)?,
+				logo_cid: Self::bound_provider_metadata_field(logo_cid)?,
+				contact: Self::bound_provider_metadata_field(contact)?,
+				terms_of_service_hash,
+				supported_schema_ids: supported_schema_ids
+					.try_into()
+					.map_err(|_| Error::<T>::ExceedsMaxSchemaGrantsPerDelegation)?,
+			};
+
+			let provider_id = ProviderId(provider_msa_id);
+			ProviderToMetadata::<T>::insert(provider_id, metadata);
+			Self::deposit_event(Event::ProviderMetadataUpdated { provider_id });
+
+			Ok(())
+		}
+
+		/// Propose to change the name of a provider. Creates a proposal for council approval to
+		/// change the name of the Provider of the origin.
+		///
+		/// # Errors
+		/// - [`NoKeyExists`](Error::NoKeyExists) - If there is not MSA for `origin`.
+		/// - [`ProviderNotRegistered`](Error::ProviderNotRegistered) - If the MSA for `origin` is not a registered provider.
+		/// - [`ExceedsMaxProviderNameSize`](Error::ExceedsMaxProviderNameSize) - Too long of a provider name
+		/// - [`KeyScopeRestricted`](Error::KeyScopeRestricted) - If `origin` is scoped without the power to manage its MSA.
+		/// - [`ThresholdApprovalRequired`](Error::ThresholdApprovalRequired) - If the MSA for `origin` has a threshold policy.
+		#[pallet::call_index(22)]
+		#[pallet::weight(
+			T::WeightInfo::propose_to_change_provider_name()
+				.saturating_add(Pallet::<T>::threshold_policy_read_weight())
+		)]
+		pub fn propose_to_change_provider_name(
+			origin: OriginFor<T>,
+			provider_name: Vec<u8>,
+		) -> DispatchResult {
+			let bounded_name: BoundedVec<u8, T::MaxProviderNameSize> =
+				provider_name.try_into().map_err(|_| Error::<T>::ExceedsMaxProviderNameSize)?;
+
+			let proposer = ensure_signed(origin)?;
+			let provider_msa_id = Self::ensure_valid_msa_key(&proposer)?;
+			Self::ensure_key_can_manage_msa(&proposer)?;
+			Self::ensure_no_threshold_policy(provider_msa_id)?;
+
+			Self::propose_provider_name_change(provider_msa_id, proposer, bounded_name)
+		}
+
+		/// Change the name of a provider by means of governance approval
+		///
+		/// # Events
+		/// * [`Event::ProviderNameChanged`]
+		///
+		/// # Errors
+		/// * [`Error::NoKeyExists`] - account does not have an MSA
+		/// * [`Error::ExceedsMaxProviderNameSize`] - Too long of a provider name
+		/// * [`Error::ProviderNotRegistered`] - the MSA of the account is not a registered provider.
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::change_provider_name_via_governance())]
+		pub fn change_provider_name_via_governance(
+			origin: OriginFor<T>,
+			provider_key: T::AccountId,
+			provider_name: Vec<u8>,
+		) -> DispatchResult {
+			T::CreateProviderViaGovernanceOrigin::ensure_origin(origin)?;
+			let provider_msa_id = Self::ensure_valid_msa_key(&provider_key)?;
+			let bounded_name: BoundedVec<u8, T::MaxProviderNameSize> =
+				provider_name.try_into().map_err(|_| Error::<T>::ExceedsMaxProviderNameSize)?;
+
+			let provider_id = ProviderId(provider_msa_id);
+			ProviderToRegistryEntry::<T>::try_mutate(
+				provider_id,
+				|maybe_entry| -> DispatchResult {
+					let entry = maybe_entry.as_mut().ok_or(Error::<T>::ProviderNotRegistered)?;
+					entry.provider_name = bounded_name;
+					Ok(())
+				},
+			)?;
+			Self::deposit_event(Event::ProviderNameChanged { provider_id });
+
+			Ok(())
+		}
+
+		/// Starts the retirement of the Provider of the origin.
+		/// The Provider can no longer take new delegations or capacity stake. Once
+		/// [`Config::ProviderRetirementGracePeriod`] blocks have passed, all delegations to the
+		/// Provider are revoked and anyone may [`complete_provider_retirement`](Pallet::complete_provider_retirement).
+		///
+		/// # Events
+		/// * [`Event::ProviderRetirementScheduled`]
+		///
+		/// # Errors
+		/// * [`Error::NoKeyExists`] - origin does not have an MSA key.
+		/// * [`Error::KeyScopeRestricted`] - origin is scoped without the power to manage its MSA.
+		/// * [`Error::ThresholdApprovalRequired`] - the MSA has a threshold policy.
+		/// * [`Error::ProviderNotRegistered`] - the origin MSA is not a registered provider.
+		/// * [`Error::ProviderRetiring`] - the provider is already retiring.
+		///
+		#[pallet::call_index(24)]
+		#[pallet::weight(
+			T::WeightInfo::retire_provider().saturating_add(Pallet::<T>::threshold_policy_read_weight())
+		)]
+		pub fn retire_provider(origin: OriginFor<T>) -> DispatchResult {
+			let provider_key = ensure_signed(origin)?;
+			let provider_msa_id = Self::ensure_valid_msa_key(&provider_key)?;
+			Self::ensure_key_can_manage_msa(&provider_key)?;
+			Self::ensure_no_threshold_policy(provider_msa_id)?;
+
+			Self::schedule_provider_retirement(provider_msa_id)
+		}
+
+		/// Completes the retirement of a Provider once its grace period has passed, removing its
+		/// registry entry and metadata. Any signed origin may complete a retirement.
+		/// The delegations to the Provider are then revoked in storage as blocks have spare weight.
+		///
+		/// # Events
+		/// * [`Event::ProviderRetired`]
+		///
+		/// # Errors
+		/// * [`Error::ProviderNotRetiring`] - the provider has not started retiring.
+		/// * [`Error::ProviderRetirementGracePeriodNotElapsed`] - the grace period has not yet passed.
+		/// * [`Error::ProviderNotRegistered`] - the retirement has already completed.
+		///
+		#[pallet::call_index(25)]
+		// Queueing the revocation of the Provider's delegations is not covered by the generated weight
+		#[pallet::weight(
+			T::WeightInfo::complete_provider_retirement()
+				.saturating_add(T::DbWeight::get().writes(1))
+				.saturating_add(Weight::from_parts(1_300_000, 0))
+		)]
+		pub fn complete_provider_retirement(
+			origin: OriginFor<T>,
+			provider_msa_id: MessageSourceId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let provider_id = ProviderId(provider_msa_id);
+			let effective_at = ProviderRetirements::<T>::get(provider_id)
+				.ok_or(Error::<T>::ProviderNotRetiring)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() > effective_at,
+				Error::<T>::ProviderRetirementGracePeriodNotElapsed
+			);
+			ensure!(
+				ProviderToRegistryEntry::<T>::take(provider_id).is_some(),
+				Error::<T>::ProviderNotRegistered
+			);
+			ProviderToMetadata::<T>::remove(provider_id);
+			ProviderRevocationQueue::<T>::insert(provider_id, ());
+			Self::deposit_event(Event::ProviderRetired { provider_id });
+
+			Ok(())
+		}
+
+		/// Requests a delegation from `delegator_msa_id` to the Provider of the origin. The
+		/// Delegator may accept or reject the request with
+		/// [`answer_delegation_request`](Pallet::answer_delegation_request) until `expiration`.
+		/// The origin holds [`Config::DelegationRequestDeposit`] until the request is answered,
+		/// cancelled or removed after it expires. An accepted request grants a delegation that
+		/// expires at `delegation_expires_at`, if given.
+		///
+		/// # Events
+		/// * [`Event::DelegationRequested`]
+		///
+		/// # Errors
+		/// * [`Error::NoKeyExists`] - origin or `delegator_msa_id` does not have an MSA key.
+		/// * [`Error::KeyScopeRestricted`] - origin is scoped without the power to manage its MSA.
+		/// * [`Error::InvalidSelfProvider`] - Cannot request a delegation from the same MSA.
+		/// * [`Error::ProviderNotRegistered`] - the origin MSA is not a registered provider.
+		/// * [`Error::ProviderRetiring`] - the provider is retiring.
+		/// * [`Error::DelegationExpiryInPast`] - `expiration` or `delegation_expires_at` is not in the future.
+		/// * [`Error::DelegationRequestExpirationTooFar`] - `expiration` is more than [`Config::MaxDelegationRequestLifetime`] blocks away.
+		/// * [`Error::ExceedsMaxSchemaGrantsPerDelegation`] - too many schemas are requested.
+		/// * [`Error::InvalidSchemaId`] - a requested schema does not exist.
+		/// * [`Error::DelegationRequestAlreadyPending`] - the provider already has a pending request for the delegator.
+		/// * [`Error::ExceedsMaxPendingDelegationRequests`] - the delegator has too many pending requests.
+		///
+		#[pallet::call_index(26)]
+		#[pallet::weight(
+			T::WeightInfo::request_delegation(schema_ids.len() as u32)
+				.saturating_add(Pallet::<T>::key_scopes_weight(1, 0))
+				.saturating_add(Pallet::<T>::delegation_request_deposit_weight())
+		)]
+		pub fn request_delegation(
+			origin: OriginFor<T>,
+			delegator_msa_id: MessageSourceId,
+			schema_ids: Vec<SchemaId>,
+			expiration: BlockNumberFor<T>,
+			delegation_expires_at: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let provider_key = ensure_signed(origin)?;
+			let provider_msa_id = Self::ensure_valid_msa_key(&provider_key)?;
+			Self::ensure_key_can_manage_msa(&provider_key)?;
+			ensure!(delegator_msa_id != provider_msa_id, Error::<T>::InvalidSelfProvider);
+			ensure!(
+				PublicKeyCountForMsaId::<T>::get(delegator_msa_id) > 0,
+				Error::<T>::NoKeyExists
+			);
+			ensure!(
+				Self::is_registered_provider(provider_msa_id),
+				Error::<T>::ProviderNotRegistered
+			);
+			ensure!(!Self::is_retiring_provider(provider_msa_id), Error::<T>::ProviderRetiring);
+			Self::ensure_valid_delegation_expiry(Some(expiration))?;
+			Self::ensure_valid_delegation_expiry(delegation_expires_at)?;
+			let current_block = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				expiration <= current_block.saturating_add(T::MaxDelegationRequestLifetime::get()),
+				Error::<T>::DelegationRequestExpirationTooFar
+			);
+			Self::ensure_all_schema_ids_are_valid(&schema_ids)?;
+
+			let provider_id = ProviderId(provider_msa_id);
+			let delegator_id = DelegatorId(delegator_msa_id);
+			ensure!(
+				!PendingDelegationRequests::<T>::contains_key(delegator_id, provider_id),
+				Error::<T>::DelegationRequestAlreadyPending
+			);
+			PendingDelegationRequestCount::<T>::try_mutate(delegator_id, |count| {
+				ensure!(
+					*count < T::MaxPendingDelegationRequests::get(),
+					Error::<T>::ExceedsMaxPendingDelegationRequests
+				);
+				*count = count.saturating_add(1);
+				Ok::<(), DispatchError>(())
+			})?;
+			let deposit = T::DelegationRequestDeposit::get();
+			T::Currency::hold(
+				&HoldReason::DelegationRequestDeposit.into(),
+				&provider_key,
+				deposit,
+			)?;
+			PendingDelegationRequests::<T>::insert(
+				delegator_id,
+				provider_id,
+				DelegationRequest {
+					schema_ids: schema_ids
+						.try_into()
+						.map_err(|_| Error::<T>::ExceedsMaxSchemaGrantsPerDelegation)?,
+					requested_at: current_block,
+					expiration,
+					delegation_expires_at,
+					depositor: provider_key,
+					deposit,
+				},
+			);
+			Self::deposit_event(Event::DelegationRequested {
+				provider_id,
+				delegator_id,
+				expiration,
+			});
+
+			Ok(())
+		}
+
+		/// Cancels the pending delegation request of the Provider of the origin to `delegator_msa_id`.
+		///
+		/// # Events
+		/// * [`Event::DelegationRequestCancelled`]
+		///
+		/// # Errors
+		/// * [`Error::NoKeyExists`] - origin does not have an MSA key.
+		/// * [`Error::DelegationRequestNotFound`] - there is no pending request to the delegator.
+		///
+		#[pallet::call_index(27)]
+		#[pallet::weight(
+			T::WeightInfo::cancel_delegation_request()
+				.saturating_add(Pallet::<T>::delegation_request_deposit_weight())
+		)]
+		pub fn cancel_delegation_request(
+			origin: OriginFor<T>,
+			delegator_msa_id: MessageSourceId,
+		) -> DispatchResult {
+			let provider_key = ensure_signed(origin)?;
+			let provider_id = ProviderId(Self::ensure_valid_msa_key(&provider_key)?);
+			let delegator_id = DelegatorId(delegator_msa_id);
+			Self::remove_delegation_request(delegator_id, provider_id)?
+				.ok_or(Error::<T>::DelegationRequestNotFound)?;
+			Self::deposit_event(Event::DelegationRequestCancelled { provider_id, delegator_id });
+
+			Ok(())
+		}
+
+		/// Accepts or rejects a pending delegation request on behalf of the Delegator of
+		/// `delegator_key`. Any signed origin may submit the answer.
+		/// Accepting grants the requested schemas to the Provider, just as
+		/// [`grant_delegation`](Pallet::grant_delegation) does. Either answer releases the
+		/// deposit of the request.
+		///
+		/// # Remarks
+		/// * Signatures should be over the [`DelegationRequestAnswer`] struct
+		///
+		/// # Events
+		/// * [`Event::DelegationGranted`] - when the request is accepted
+		/// * [`Event::DelegationRequestRejected`] - when the request is rejected
+		///
+		/// # Errors
+		/// * [`Error::InvalidSignature`] - `proof` verification fails; `delegator_key` must have signed `answer_payload`
+		/// * [`Error::ProofHasExpired`] - the current block is less than the `expiration` block number set in the payload.
+		/// * [`Error::ProofNotYetValid`] - the `expiration` block number set in the payload is greater than the current block number plus mortality_block_limit().
+		/// * [`Error::SignatureAlreadySubmitted`] - signature has already been used.
+		/// * [`Error::NoKeyExists`] - `delegator_key` does not have an MSA.
+		/// * [`Error::KeyScopeRestricted`] - `delegator_key` is scoped without the power to manage its MSA
+		/// * [`Error::DelegationRequestNotFound`] - there is no pending request made by the provider at `requested_at`.
+		/// * [`Error::DelegationRequestExpired`] - the request is accepted after its expiration.
+		/// * [`Error::DelegationExpiryInPast`] - the requested delegation expiry has passed.
+		/// * [`Error::ProviderNotRegistered`] - the provider is no longer registered.
+		/// * [`Error::ProviderRetiring`] - the provider is retiring.
+		///
+		#[pallet::call_index(28)]
+		#[pallet::weight(
+			T::WeightInfo::answer_delegation_request()
+				.saturating_add(Pallet::<T>::delegation_request_deposit_weight())
+		)]
+		pub fn answer_delegation_request(
+			origin: OriginFor<T>,
+			delegator_key: T::AccountId,
+			proof: MultiSignature,
+			answer_payload: DelegationRequestAnswer<T>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			Self::verify_signature(
+				&proof,
+				&delegator_key,
+				&answer_payload,
+				answer_payload.encode(),
+			)?;
+			Self::register_signature(&proof, answer_payload.expiration)?;
+
+			let delegator_id = DelegatorId(Self::ensure_valid_msa_key(&delegator_key)?);
+			Self::ensure_key_can_manage_msa(&delegator_key)?;
+
+			let provider_id = ProviderId(answer_payload.provider_msa_id);
+			ensure!(
+				PendingDelegationRequests::<T>::get(delegator_id, provider_id)
+					.map_or(false, |request| request.requested_at == answer_payload.requested_at),
+				Error::<T>::DelegationRequestNotFound
+			);
+			let request = Self::remove_delegation_request(delegator_id, provider_id)?
+				.ok_or(Error::<T>::DelegationRequestNotFound)?;
+
+			if !answer_payload.accept {
+				Self::deposit_event(Event::DelegationRequestRejected { provider_id, delegator_id });
+				return Ok(())
+			}
+
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= request.expiration,
+				Error::<T>::DelegationRequestExpired
+			);
+			ensure!(
+				Self::is_registered_provider(provider_id.into()),
+				Error::<T>::ProviderNotRegistered
+			);
+			ensure!(!Self::is_retiring_provider(provider_id.into()), Error::<T>::ProviderRetiring);
+
+			Self::ensure_valid_delegation_expiry(request.delegation_expires_at)?;
+			Self::upsert_schema_permissions(
+				provider_id,
+				delegator_id,
+				request.schema_ids.into_inner(),
+				request.delegation_expires_at,
+			)?;
+			let event = Event::DelegationGranted { delegator_id, provider_id };
+			offchain_index_event::<T>(&event, delegator_id.into());
+			Self::deposit_event(event);
+
+			Ok(())
+		}
+
+		/// Sets a threshold policy on the origin's MSA: from then on, `threshold` of its keys must
+		/// approve adding or deleting a key, retiring the MSA, changing its recovery guardians,
+		/// publishing Provider metadata, renaming or retiring its Provider, revoking a delegation or
+		/// changing its schema grants, and changing or removing the policy. See [`ThresholdAction`].
+		///
+		/// # Events
+		/// * [`Event::ThresholdPolicySet`]
+		///
+		/// # Errors
+		/// * [`Error::NoKeyExists`] - origin does not have an MSA key.
+		/// * [`Error::KeyScopeRestricted`] - origin is scoped without the power to manage its MSA.
+		/// * [`Error::ThresholdApprovalRequired`] - the MSA already has a threshold policy.
+		/// * [`Error::InvalidThresholdPolicy`] - `threshold` is less than two or greater than the number of keys of the MSA.
+		///
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::set_threshold_policy())]
+		pub fn set_threshold_policy(origin: OriginFor<T>, threshold: u8) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let msa_id = Self::ensure_valid_msa_key(&who)?;
+			Self::ensure_key_can_manage_msa(&who)?;
+			Self::ensure_no_threshold_policy(msa_id)?;
+			Self::ensure_valid_threshold(msa_id, threshold)?;
+
+			ThresholdPolicies::<T>::insert(msa_id, threshold);
+			Self::deposit_event(Event::ThresholdPolicySet { msa_id, threshold });
+
+			Ok(())
+		}
+
+		/// Proposes an action on the origin's MSA, which has a threshold policy. The origin counts as
+		/// the first approval, and the action is executed once enough keys of the MSA approve it with
+		/// [`approve_threshold_action`](Pallet::approve_threshold_action).
+		///
+		/// # Events
+		/// * [`Event::ThresholdActionProposed`]
+		///
+		/// # Errors
+		/// * [`Error::NoKeyExists`] - origin does not have an MSA key.
+		/// * [`Error::KeyScopeRestricted`] - origin is scoped without the power to manage its MSA.
+		/// * [`Error::ThresholdPolicyNotSet`] - the MSA does not have a threshold policy.
+		/// * [`Error::ExceedsMaxThresholdProposals`] - the MSA already has [`Config::MaxThresholdProposalsPerMsa`]
+		/// actions waiting for approvals.
+		///
+		#[pallet::call_index(30)]
+		// Counting the pending proposals of the MSA is not covered by the generated weight
+		#[pallet::weight(
+			T::WeightInfo::propose_threshold_action()
+				.saturating_add(Pallet::<T>::threshold_proposal_count_weight())
+		)]
+		pub fn propose_threshold_action(
+			origin: OriginFor<T>,
+			action: ThresholdAction<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let msa_id = Self::ensure_valid_msa_key(&who)?;
+			Self::ensure_key_can_manage_msa(&who)?;
+			ensure!(
+				ThresholdPolicies::<T>::contains_key(msa_id),
+				Error::<T>::ThresholdPolicyNotSet
+			);
+			ThresholdProposalCount::<T>::try_mutate(msa_id, |count| -> DispatchResult {
+				ensure!(
+					*count < T::MaxThresholdProposalsPerMsa::get(),
+					Error::<T>::ExceedsMaxThresholdProposals
+				);
+				*count = count.saturating_add(1);
+				Ok(())
+			})?;
+
+			let proposal_id = NextThresholdProposalId::<T>::mutate(msa_id, |next_id| {
+				let proposal_id = *next_id;
+				*next_id = next_id.wrapping_add(1);
+				proposal_id
+			});
+			let mut approvals = BoundedVec::default();
+			approvals.try_push(who.clone()).map_err(|_| Error::<T>::KeyLimitExceeded)?;
+			ThresholdProposals::<T>::insert(
+				msa_id,
+				proposal_id,
+				ThresholdProposal {
+					action,
+					proposed_at: frame_system::Pallet::<T>::block_number(),
+					approvals,
+					rejections: BoundedVec::default(),
+				},
+			);
+			Self::deposit_event(Event::ThresholdActionProposed { msa_id, proposal_id, key: who });
+
+			Ok(())
+		}
+
+		/// Approves an action proposed on the origin's MSA. Only the approvals of keys that still
+		/// belong to the MSA count, and once they reach its threshold the action is executed.
+		/// If the action fails, the approval is not recorded.
+		///
+		/// # Events
+		/// * [`Event::ThresholdActionApproved`]
+		/// * [`Event::ThresholdActionExecuted`] - along with the events of the action, once executed
+		///
+		/// # Errors
+		/// * [`Error::NoKeyExists`] - origin does not have an MSA key.
+		/// * [`Error::KeyScopeRestricted`] - origin is scoped without the power to manage its MSA.
+		/// * [`Error::ThresholdPolicyNotSet`] - the MSA does not have a threshold policy.
+		/// * [`Error::ThresholdProposalNotFound`] - the MSA has no proposed action `proposal_id`.
+		/// * [`Error::ThresholdProposalAlreadyApproved`] - origin has already approved the action.
+		/// * [`Error::ThresholdActionNotAllowed`] - the action would leave the MSA with fewer keys than
+		/// its threshold, or cannot retire the MSA.
+		/// * Any error of the action being executed.
+		///
+		#[pallet::call_index(31)]
+		// Removing the recovery of a retired MSA and counting the pending proposals of the MSA are
+		// not covered by the generated weight
+		#[pallet::weight(
+			T::WeightInfo::approve_threshold_action()
+				.saturating_add(T::DbWeight::get().writes(2))
+				.saturating_add(Pallet::<T>::threshold_proposal_count_weight())
+		)]
+		pub fn approve_threshold_action(
+			origin: OriginFor<T>,
+			#[pallet::compact] proposal_id: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let msa_id = Self::ensure_valid_msa_key(&who)?;
+			Self::ensure_key_can_manage_msa(&who)?;
+			let threshold =
+				ThresholdPolicies::<T>::get(msa_id).ok_or(Error::<T>::ThresholdPolicyNotSet)?;
+
+			let mut proposal = ThresholdProposals::<T>::get(msa_id, proposal_id)
+				.ok_or(Error::<T>::ThresholdProposalNotFound)?;
+			ensure!(
+				!proposal.approvals.contains(&who),
+				Error::<T>::ThresholdProposalAlreadyApproved
+			);
+			proposal.approvals.retain(|key| PublicKeyToMsaId::<T>::get(key) == Some(msa_id));
+			proposal
+				.approvals
+				.try_push(who.clone())
+				.map_err(|_| Error::<T>::KeyLimitExceeded)?;
+			Self::deposit_event(Event::ThresholdActionApproved { msa_id, proposal_id, key: who });
+
+			if proposal.approvals.len() < threshold as usize {
+				ThresholdProposals::<T>::insert(msa_id, proposal_id, proposal);
+				return Ok(())
+			}
+
+			Self::remove_threshold_proposal(msa_id, proposal_id);
+			Self::execute_threshold_action(msa_id, &proposal.approvals, proposal.action)?;
+			Self::deposit_event(Event::ThresholdActionExecuted { msa_id, proposal_id });
+
+			Ok(())
+		}
+
+		/// Cancels an action proposed on the origin's MSA. The key that proposed the action may
+		/// cancel it alone; any other key only asks to cancel it, and the action is cancelled once
+		/// as many keys as the threshold of the MSA have asked. Only the requests of keys that
+		/// still belong to the MSA count.
+		///
+		/// # Events
+		/// * [`Event::ThresholdActionRejected`] - when a key other than the proposer asks to cancel
+		/// * [`Event::ThresholdActionCancelled`]
+		///
+		/// # Errors
+		/// * [`Error::NoKeyExists`] - origin does not have an MSA key.
+		/// * [`Error::KeyScopeRestricted`] - origin is scoped without the power to manage its MSA.
+		/// * [`Error::ThresholdProposalNotFound`] - the MSA has no proposed action `proposal_id`.
+		/// * [`Error::ThresholdPolicyNotSet`] - the MSA does not have a threshold policy.
+		/// * [`Error::ThresholdProposalAlreadyRejected`] - origin has already asked to cancel the action.
+		///
+		#[pallet::call_index(32)]
+		// A request to cancel also reads the threshold policy and the MSA of every key that has asked
+		// to cancel the action, and counts the pending proposals of the MSA, which the generated
+		// weight does not cover.
+		#[pallet::weight(
+			T::WeightInfo::cancel_threshold_action()
+				.saturating_add(T::DbWeight::get().reads(
+					1u64.saturating_add(T::MaxPublicKeysPerMsa::get().into())
+				))
+				.saturating_add(Pallet::<T>::threshold_proposal_count_weight())
+		)]
+		pub fn cancel_threshold_action(
+			origin: OriginFor<T>,
+			#[pallet::compact] proposal_id: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let msa_id = Self::ensure_valid_msa_key(&who)?;
+			Self::ensure_key_can_manage_msa(&who)?;
+			let mut proposal = ThresholdProposals::<T>::get(msa_id, proposal_id)
+				.ok_or(Error::<T>::ThresholdProposalNotFound)?;
+
+			if proposal.approvals.first() != Some(&who) {
+				let threshold =
+					ThresholdPolicies::<T>::get(msa_id).ok_or(Error::<T>::ThresholdPolicyNotSet)?;
+				ensure!(
+					!proposal.rejections.contains(&who),
+					Error::<T>::ThresholdProposalAlreadyRejected
+				);
+				proposal
+					.rejections
+					.retain(|key| PublicKeyToMsaId::<T>::get(key) == Some(msa_id));
+				proposal
+					.rejections
+					.try_push(who.clone())
+					.map_err(|_| Error::<T>::KeyLimitExceeded)?;
+				Self::deposit_event(Event::ThresholdActionRejected {
+					msa_id,
+					proposal_id,
+					key: who,
+				});
+
+				if proposal.rejections.len() < threshold as usize {
+					ThresholdProposals::<T>::insert(msa_id, proposal_id, proposal);
+					return Ok(())
+				}
+			}
+
+			Self::remove_threshold_proposal(msa_id, proposal_id);
+			Self::deposit_event(Event::ThresholdActionCancelled { msa_id, proposal_id });
+
+			Ok(())
+		}
+
+		/// Sub-delegates the grants of `schema_ids` the Provider of the origin holds from its
+		/// Delegators to the Provider `sub_provider_msa_id`. The sub-provider only holds a grant
+		/// from a Delegator while every link between it and the Delegator's delegation is active.
+		/// Granting again replaces the sub-delegated schemas and reactivates a revoked sub-delegation.
+		///
+		/// # Events
+		/// * [`Event::SubDelegationGranted`]
+		///
+		/// # Errors
+		/// * [`Error::NoKeyExists`] - origin does not have an MSA key.
+		/// * [`Error::KeyScopeRestricted`] - origin is scoped without the power to manage its MSA.
+		/// * [`Error::InvalidSelfProvider`] - Cannot sub-delegate to the same MSA.
+		/// * [`Error::ProviderNotRegistered`] - the origin MSA or `sub_provider_msa_id` is not a registered provider.
+		/// * [`Error::ProviderRetiring`] - the origin MSA or `sub_provider_msa_id` is retiring.
+		/// * [`Error::ExceedsMaxSchemaGrantsPerDelegation`] - too many schemas are sub-delegated.
+		/// * [`Error::InvalidSchemaId`] - a sub-delegated schema does not exist.
+		/// * [`Error::ExceedsMaxSubDelegationsPerProvider`] - `sub_provider_msa_id` has too many sub-delegations.
+		///
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::grant_sub_delegation(schema_ids.len() as u32))]
+		pub fn grant_sub_delegation(
+			origin: OriginFor<T>,
+			sub_provider_msa_id: MessageSourceId,
+			schema_ids: Vec<SchemaId>,
+		) -> DispatchResult {
+			let provider_key = ensure_signed(origin)?;
+			let provider_msa_id = Self::ensure_valid_msa_key(&provider_key)?;
+			Self::ensure_key_can_manage_msa(&provider_key)?;
+			ensure!(sub_provider_msa_id != provider_msa_id, Error::<T>::InvalidSelfProvider);
+			for msa_id in [provider_msa_id, sub_provider_msa_id] {
+				ensure!(Self::is_registered_provider(msa_id), Error::<T>::ProviderNotRegistered);
+				ensure!(!Self::is_retiring_provider(msa_id), Error::<T>::ProviderRetiring);
+			}
+			Self::ensure_all_schema_ids_are_valid(&schema_ids)?;
+			let schema_ids: BoundedVec<SchemaId, T::MaxSchemaGrantsPerDelegation> = schema_ids
+				.try_into()
+				.map_err(|_| Error::<T>::ExceedsMaxSchemaGrantsPerDelegation)?;
+
+			let parent_provider_id = ProviderId(provider_msa_id);
+			let sub_provider_id = ProviderId(sub_provider_msa_id);
+			SubDelegations::<T>::try_mutate(sub_provider_id, |links| -> DispatchResult {
+				match links.iter_mut().find(|link| link.parent_provider_id == parent_provider_id) {
+					Some(link) => {
+						link.schema_ids = schema_ids;
+						link.revoked_at = BlockNumberFor::<T>::zero();
+					},
+					None => links
+						.try_push(SubDelegation {
+							parent_provider_id,
+							schema_ids,
+							revoked_at: BlockNumberFor::<T>::zero(),
+						})
+						.map_err(|_| Error::<T>::ExceedsMaxSubDelegationsPerProvider)?,
+				}
+				Ok(())
+			})?;
+			Self::deposit_event(Event::SubDelegationGranted {
+				parent_provider_id,
+				sub_provider_id,
+			});
+
+			Ok(())
+		}
+
+		/// Revokes the grants the Provider of the origin sub-delegated to `sub_provider_msa_id`.
+		/// The revocation cascades to every Provider the sub-provider passed the grants on to.
+		///
+		/// # Events
+		/// * [`Event::SubDelegationRevoked`]
+		///
+		/// # Errors
+		/// * [`Error::NoKeyExists`] - origin does not have an MSA key.
+		/// * [`Error::KeyScopeRestricted`] - origin is scoped without the power to manage its MSA.
+		/// * [`Error::SubDelegationNotFound`] - the origin MSA has not sub-delegated to `sub_provider_msa_id`.
+		/// * [`Error::DelegationRevoked`] - the sub-delegation is already revoked.
+		///
+		#[pallet::call_index(34)]
+		#[pallet::weight(T::WeightInfo::revoke_sub_delegation())]
+		pub fn revoke_sub_delegation(
+			origin: OriginFor<T>,
+			sub_provider_msa_id: MessageSourceId,
+		) -> DispatchResult {
+			let provider_key = ensure_signed(origin)?;
+			let parent_provider_id = ProviderId(Self::ensure_valid_msa_key(&provider_key)?);
+			Self::ensure_key_can_manage_msa(&provider_key)?;
+			let sub_provider_id = ProviderId(sub_provider_msa_id);
+			SubDelegations::<T>::try_mutate(sub_provider_id, |links| -> DispatchResult {
+				let link = links
+					.iter_mut()
+					.find(|link| link.parent_provider_id == parent_provider_id)
+					.ok_or(Error::<T>::SubDelegationNotFound)?;
+				ensure!(
+					link.revoked_at == BlockNumberFor::<T>::zero(),
+					Error::<T>::DelegationRevoked
+				);
+				link.revoked_at = frame_system::Pallet::<T>::block_number();
+				Ok(())
+			})?;
+			Self::deposit_event(Event::SubDelegationRevoked {
+				parent_provider_id,
+				sub_provider_id,
+			});
+
+			Ok(())
+		}
+
+		/// Merges the source MSA of `merge_payload` into its target MSA.
+		///
+		/// A key of each MSA must sign the `merge_payload`, proving that the owner of both MSAs
+		/// approves the merge. `source_keys` must list every key of the source MSA: they are moved
+		/// to the target MSA right away, the source MSA is retired, and a redirect from the source
+		/// to the target MSA is recorded in [`MsaRedirects`]. The delegations of the source MSA and
+		/// the data other pallets store for it are moved over the following blocks. Delegations to
+		/// providers the target MSA has already delegated to, and stateful storage of schemas the
+		/// target MSA already has pages for, are not moved and are purged with the source MSA.
+		///
+		/// # Events
+		/// * [`Event::PublicKeyDeleted`] and [`Event::PublicKeyAdded`] - for each moved key
+		/// * [`Event::MsaMerged`]
+		/// * [`Event::MsaRetired`]
+		///
+		/// # Errors
+		///
+		/// * [`Error::MsaOwnershipInvalidSignature`] - a key in `merge_payload` is not a valid signer of it.
+		/// * [`Error::MsaMergeIntoSelf`] - the source and target MSAs are the same.
+		/// * [`Error::NoKeyExists`] - a key in `merge_payload` is not registered to an MSA.
+		/// * [`Error::NotMsaOwner`] - a key in `merge_payload` does not belong to its MSA.
+		/// * [`Error::KeyScopeRestricted`] - a key in `merge_payload` is scoped without the power to manage its MSA.
+		/// * [`Error::ThresholdApprovalRequired`] - either MSA has a threshold policy.
+		/// * [`Error::MsaMergeSourceIsProvider`] - the source MSA is a registered provider.
+		/// * [`Error::MsaMergeKeysMismatch`] - `source_keys` are not exactly the keys of the source MSA.
+		/// * [`Error::KeyLimitExceeded`] - the target MSA would have more than [`Config::MaxPublicKeysPerMsa`] keys.
+		/// * [`Error::ProofHasExpired`] - the current block is less than the `expired` block number set in `MergeMsaData`.
+		/// * [`Error::ProofNotYetValid`] - the `expired` block number set in `MergeMsaData` is greater than the current block number plus mortality_block_limit().
+		/// * [`Error::SignatureAlreadySubmitted`] - signature has already been used.
+		///
+		#[pallet::call_index(35)]
+		#[pallet::weight(T::WeightInfo::merge_msa(source_keys.len() as u32))]
+		pub fn merge_msa(
+			origin: OriginFor<T>,
+			source_key_proof: MultiSignature,
+			target_key_proof: MultiSignature,
+			merge_payload: MergeMsaData<T>,
+			source_keys: Vec<T::AccountId>,
+		) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+
+			Self::verify_signature(
+				&source_key_proof,
+				&merge_payload.source_public_key,
+				&merge_payload,
+				merge_payload.encode(),
+			)
+			.map_err(|_| Error::<T>::MsaOwnershipInvalidSignature)?;
+
+			Self::verify_signature(
+				&target_key_proof,
+				&merge_payload.target_public_key,
+				&merge_payload,
+				merge_payload.encode(),
+			)
+			.map_err(|_| Error::<T>::MsaOwnershipInvalidSignature)?;
+
+			Self::register_signature(&source_key_proof, merge_payload.expiration)?;
+			Self::register_signature(&target_key_proof, merge_payload.expiration)?;
+
+			let source_msa_id = merge_payload.source_msa_id;
+			let target_msa_id = merge_payload.target_msa_id;
+			ensure!(source_msa_id != target_msa_id, Error::<T>::MsaMergeIntoSelf);
+
+			Self::ensure_msa_owner(&merge_payload.source_public_key, source_msa_id)?;
+			Self::ensure_msa_owner(&merge_payload.target_public_key, target_msa_id)?;
+			Self::ensure_no_threshold_policy(source_msa_id)?;
+			Self::ensure_no_threshold_policy(target_msa_id)?;
+			ensure!(
+				!Self::is_registered_provider(source_msa_id),
+				Error::<T>::MsaMergeSourceIsProvider
+			);
+
+			Self::move_msa_keys(source_msa_id, target_msa_id, &source_keys)?;
+
+			// The source MSA has no keys left, so a recovery must not give it a new one
+			Self::remove_recovery(source_msa_id);
+			MsaRedirects::<T>::insert(source_msa_id, target_msa_id);
+			MsaMergeQueue::<T>::insert(source_msa_id, target_msa_id);
+
+			Self::deposit_event(Event::MsaMerged { source_msa_id, target_msa_id });
+			Self::deposit_event(Event::MsaRetired { msa_id: source_msa_id });
+
+			Ok(())
+		}
+
+		/// Removes a delegation request that has expired and releases its deposit to the
+		/// Provider. Any signed origin may remove it.
+		///
+		/// # Events
+		/// * [`Event::DelegationRequestRemoved`]
+		///
+		/// # Errors
+		/// * [`Error::DelegationRequestNotFound`] - there is no pending request from the provider to the delegator.
+		/// * [`Error::DelegationRequestNotExpired`] - the request can still be accepted.
+		///
+		#[pallet::call_index(36)]
+		#[pallet::weight(T::WeightInfo::remove_expired_delegation_request())]
+		pub fn remove_expired_delegation_request(
+			origin: OriginFor<T>,
+			delegator_msa_id: MessageSourceId,
+			provider_msa_id: MessageSourceId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let delegator_id = DelegatorId(delegator_msa_id);
+			let provider_id = ProviderId(provider_msa_id);
+			let request = PendingDelegationRequests::<T>::get(delegator_id, provider_id)
+				.ok_or(Error::<T>::DelegationRequestNotFound)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() > request.expiration,
+				Error::<T>::DelegationRequestNotExpired
+			);
+			Self::remove_delegation_request(delegator_id, provider_id)?;
+			Self::deposit_event(Event::DelegationRequestRemoved { provider_id, delegator_id });
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Create the account for the `key`
+	///
+	/// # Errors
+	/// * [`Error::MsaIdOverflow`]
+	/// * [`Error::KeyLimitExceeded`]
+	/// * [`Error::KeyAlreadyRegistered`]
+	///
+	pub fn create_account<F>(
+		key: T::AccountId,
+		on_success: F,
+	) -> Result<(MessageSourceId, T::AccountId), DispatchError>
+	where
+		F: FnOnce(MessageSourceId) -> DispatchResult,
+	{
+		let next_msa_id = Self::get_next_msa_id()?;
+		Self::add_key(next_msa_id, &key, on_success)?;
+		let _ = Self::set_msa_identifier(next_msa_id);
+
+		Ok((next_msa_id, key))
+	}
+
+	/// Generate the next MSA Id
+	///
+	/// # Errors
+	/// * [`Error::MsaIdOverflow`]
+	///
+	pub fn get_next_msa_id() -> Result<MessageSourceId, DispatchError> {
+		let next = CurrentMsaIdentifierMaximum::<T>::get()
+			.checked_add(1)
+			.ok_or(Error::<T>::MsaIdOverflow)?;
+
+		Ok(next)
+	}
+
+	/// Set the current identifier in storage
+	pub fn set_msa_identifier(identifier: MessageSourceId) -> DispatchResult {
+		CurrentMsaIdentifierMaximum::<T>::set(identifier);
+
+		Ok(())
+	}
+
+	/// Create Register Provider
+	pub fn create_registered_provider(
+		provider_id: ProviderId,
+		name: BoundedVec<u8, T::MaxProviderNameSize>,
+	) -> DispatchResult {
+		ProviderToRegistryEntry::<T>::try_mutate(provider_id, |maybe_metadata| -> DispatchResult {
+			ensure!(maybe_metadata.take().is_none(), Error::<T>::DuplicateProviderRegistryEntry);
+			*maybe_metadata = Some(ProviderRegistryEntry { provider_name: name });
+			Ok(())
+		})
+	}
+
+	/// Adds a list of schema permissions to a delegation relationship.
+	pub fn grant_permissions_for_schemas(
+		delegator_id: DelegatorId,
+		provider_id: ProviderId,
+		schema_ids: Vec<SchemaId>,
+	) -> DispatchResult {
+		Self::try_mutate_delegation(delegator_id, provider_id, |delegation, is_new_delegation| {
+			ensure!(!is_new_delegation, Error::<T>::DelegationNotFound);
+			Self::ensure_all_schema_ids_are_valid(&schema_ids)?;
+
+			Self::clear_schema_grant_permissions(delegator_id, provider_id, &schema_ids);
+			PermittedDelegationSchemas::<T>::try_insert_schemas(delegation, schema_ids)?;
+
+			Ok(())
+		})
+	}
+
+	/// Revokes a list of schema permissions from a delegation relationship.
+	pub fn revoke_permissions_for_schemas(
+		delegator_id: DelegatorId,
+		provider_id: ProviderId,
+		schema_ids: Vec<SchemaId>,
+	) -> DispatchResult {
+		Self::try_mutate_delegation(delegator_id, provider_id, |delegation, is_new_delegation| {
+			ensure!(!is_new_delegation, Error::<T>::DelegationNotFound);
+			Self::ensure_all_schema_ids_are_valid(&schema_ids)?;
+
+			let current_block = frame_system::Pallet::<T>::block_number();
+
+			Self::clear_schema_grant_permissions(delegator_id, provider_id, &schema_ids);
+			PermittedDelegationSchemas::<T>::try_get_mut_schemas(
+				delegation,
+				schema_ids,
+				current_block,
+			)?;
+
+			Ok(())
+		})
+	}
+
+	/// Add a new key to the MSA
+	///
+	/// # Errors
+	/// * [`Error::KeyLimitExceeded`]
+	/// * [`Error::KeyAlreadyRegistered`]
+	///
+	pub fn add_key<F>(msa_id: MessageSourceId, key: &T::AccountId, on_success: F) -> DispatchResult
+	where
+		F: FnOnce(MessageSourceId) -> DispatchResult,
+	{
+		PublicKeyToMsaId::<T>::try_mutate(key, |maybe_msa_id| {
+			ensure!(maybe_msa_id.is_none(), Error::<T>::KeyAlreadyRegistered);
+			*maybe_msa_id = Some(msa_id);
+
+			// Increment the key counter
+			<PublicKeyCountForMsaId<T>>::try_mutate(msa_id, |key_count| {
+				// key_count:u8 should default to 0 if it does not exist
+				let incremented_key_count =
+					key_count.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+
+				ensure!(
+					incremented_key_count <= T::MaxPublicKeysPerMsa::get(),
+					Error::<T>::KeyLimitExceeded
+				);
+
+				*key_count = incremented_key_count;
+				on_success(msa_id)
+			})
+		})
+	}
+
+	/// Check that schema ids are all valid
+	///
+	/// # Errors
+	/// * [`Error::InvalidSchemaId`]
+	/// * [`Error::ExceedsMaxSchemaGrantsPerDelegation`]
+	///
+	pub fn ensure_all_schema_ids_are_valid(schema_ids: &Vec<SchemaId>) -> DispatchResult {
+		ensure!(
+			schema_ids.len() <= T::MaxSchemaGrantsPerDelegation::get() as usize,
+			Error::<T>::ExceedsMaxSchemaGrantsPerDelegation
+		);
+
+		let are_schemas_valid = T::SchemaValidator::are_all_schema_ids_valid(schema_ids);
+
+		ensure!(are_schemas_valid, Error::<T>::InvalidSchemaId);
+
+		Ok(())
+	}
+
+	/// Bounds a provider metadata text field to [`Config::MaxProviderMetadataFieldSize`]
+	///
+	/// # Errors
+	/// * [`Error::ExceedsMaxProviderMetadataFieldSize`]
+	///
+	pub fn bound_provider_metadata_field(
+		field: Vec<u8>,
+	) -> Result<BoundedVec<u8, T::MaxProviderMetadataFieldSize>, DispatchError> {
+		field
+			.try_into()
+			.map_err(|_| Error::<T>::ExceedsMaxProviderMetadataFieldSize.into())
+	}
+
+	/// Get the registry entry and metadata of a provider, if it is registered
+	pub fn get_provider_info(provider_id: ProviderId) -> Option<ProviderInfoResponse> {
+		let entry = ProviderToRegistryEntry::<T>::get(provider_id)?;
+		let mut response = ProviderInfoResponse {
+			provider_id,
+			provider_name: entry.provider_name.into_inner(),
+			..Default::default()
+		};
+		if let Some(metadata) = ProviderToMetadata::<T>::get(provider_id) {
+			response.homepage_url = metadata.homepage_url.into_inner();
+			response.logo_cid = metadata.logo_cid.into_inner();
+			response.contact = metadata.contact.into_inner();
+			response.terms_of_service_hash = metadata.terms_of_service_hash;
+			response.supported_schema_ids = metadata.supported_schema_ids.into_inner();
+		}
+		Some(response)
+	}
+
+	/// Returns if provider is registered by checking if the [`ProviderToRegistryEntry`] contains the MSA id
+	pub fn is_registered_provider(msa_id: MessageSourceId) -> bool {
+		ProviderToRegistryEntry::<T>::contains_key(ProviderId(msa_id))
+	}
+
+	/// Returns if provider has started retiring by checking if [`ProviderRetirements`] contains the MSA id
+	pub fn is_retiring_provider(msa_id: MessageSourceId) -> bool {
+		ProviderRetirements::<T>::contains_key(ProviderId(msa_id))
+	}
+
+	/// Treats `delegation` as revoked from the block the retirement of `provider_id` takes effect,
+	/// unless it was revoked before then
+	pub fn apply_provider_retirement(
+		provider_id: ProviderId,
+		mut delegation: Delegation<SchemaId, BlockNumberFor<T>, T::MaxSchemaGrantsPerDelegation>,
+	) -> Delegation<SchemaId, BlockNumberFor<T>, T::MaxSchemaGrantsPerDelegation> {
+		if let Some(effective_at) = ProviderRetirements::<T>::get(provider_id) {
+			if delegation.revoked_at == BlockNumberFor::<T>::zero() ||
+				delegation.revoked_at > effective_at
+			{
+				delegation.revoked_at = effective_at;
+			}
+		}
+		delegation
+	}
+
+	/// Checks that a provider and delegator keys are valid
 	/// and that a provider and delegator are not the same
 	/// and that a provider has authorized a delegator to create a delegation relationship.
 	///
 	/// # Errors
 	/// * [`Error::ProviderNotRegistered`]
+	/// * [`Error::ProviderRetiring`]
 	/// * [`Error::InvalidSelfProvider`]
 	/// * [`Error::NoKeyExists`]
 	///
@@ -1068,43 +3047,455 @@ impl<T: Config> Pallet<T> {
 
 		// Verify that the provider is a registered provider
 		ensure!(Self::is_registered_provider(provider_msa_id), Error::<T>::ProviderNotRegistered);
+		ensure!(!Self::is_retiring_provider(provider_msa_id), Error::<T>::ProviderRetiring);
+
+		Ok((provider_msa_id.into(), delegator_msa_id.into()))
+	}
+
+	/// Checks that the MSA for `who` is the same as `msa_id`, and that the scope of `who` allows it
+	/// to manage that MSA
+	///
+	/// # Errors
+	/// * [`Error::NotMsaOwner`]
+	/// * [`Error::NoKeyExists`]
+	/// * [`Error::KeyScopeRestricted`]
+	///
+	pub fn ensure_msa_owner(who: &T::AccountId, msa_id: MessageSourceId) -> DispatchResult {
+		let provider_msa_id = Self::ensure_valid_msa_key(who)?;
+		ensure!(provider_msa_id == msa_id, Error::<T>::NotMsaOwner);
+		Self::ensure_key_can_manage_msa(who)?;
+
+		Ok(())
+	}
+
+	/// Checks that the scope of `key`, if any, allows it to manage its MSA
+	///
+	/// # Errors
+	/// * [`Error::KeyScopeRestricted`]
+	///
+	pub fn ensure_key_can_manage_msa(key: &T::AccountId) -> DispatchResult {
+		let can_manage_msa =
+			PublicKeyScopes::<T>::get(key).map_or(true, |scope| scope.can_manage_msa);
+		ensure!(can_manage_msa, Error::<T>::KeyScopeRestricted);
+
+		Ok(())
+	}
+
+	/// Checks that the MSA does not have a threshold policy, so a single key may act for it
+	///
+	/// # Errors
+	/// * [`Error::ThresholdApprovalRequired`]
+	///
+	pub fn ensure_no_threshold_policy(msa_id: MessageSourceId) -> DispatchResult {
+		ensure!(
+			!ThresholdPolicies::<T>::contains_key(msa_id),
+			Error::<T>::ThresholdApprovalRequired
+		);
+
+		Ok(())
+	}
+
+	/// Checks that `threshold` is at least two and no greater than the number of keys of the MSA
+	///
+	/// # Errors
+	/// * [`Error::InvalidThresholdPolicy`]
+	///
+	pub fn ensure_valid_threshold(msa_id: MessageSourceId, threshold: u8) -> DispatchResult {
+		ensure!(
+			threshold >= 2 && threshold <= PublicKeyCountForMsaId::<T>::get(msa_id),
+			Error::<T>::InvalidThresholdPolicy
+		);
+
+		Ok(())
+	}
+
+	/// Executes an action on an MSA with a threshold policy, approved by `approvals`
+	///
+	/// # Errors
+	/// * [`Error::NotKeyOwner`]
+	/// * [`Error::ThresholdActionNotAllowed`]
+	/// * [`Error::InvalidThresholdPolicy`]
+	/// * [`Error::ProviderNotRegistered`]
+	/// * [`Error::NotMsaOwner`] - a key is added to another MSA
+	/// * [`Error::NewKeyOwnershipInvalidSignature`]
+	/// * [`Error::KeyScopeRestricted`] - a key is added with a scope wider than a scoped approving key
+	/// * Any error of the action, as for the extrinsic it stands for
+	///
+	pub fn execute_threshold_action(
+		msa_id: MessageSourceId,
+		approvals: &[T::AccountId],
+		action: ThresholdAction<T>,
+	) -> DispatchResult {
+		match action {
+			ThresholdAction::DeletePublicKey { key } => {
+				ensure!(PublicKeyToMsaId::<T>::get(&key) == Some(msa_id), Error::<T>::NotKeyOwner);
+				let threshold = ThresholdPolicies::<T>::get(msa_id).unwrap_or_default();
+				ensure!(
+					PublicKeyCountForMsaId::<T>::get(msa_id) > threshold,
+					Error::<T>::ThresholdActionNotAllowed
+				);
+				Self::delete_key_for_msa(msa_id, &key)?;
+				let event = Event::PublicKeyDeleted { key };
+				offchain_index_event::<T>(&event, msa_id);
+				Self::deposit_event(event);
+			},
+			ThresholdAction::RetireMsa => {
+				ensure!(
+					!Self::is_registered_provider(msa_id) &&
+						PublicKeyCountForMsaId::<T>::get(msa_id) as usize == approvals.len(),
+					Error::<T>::ThresholdActionNotAllowed
+				);
+				for key in approvals {
+					Self::delete_key_for_msa(msa_id, key)?;
+					let event = Event::PublicKeyDeleted { key: key.clone() };
+					offchain_index_event::<T>(&event, msa_id);
+					Self::deposit_event(event);
+				}
+				ThresholdPolicies::<T>::remove(msa_id);
+				Self::remove_recovery(msa_id);
+				RetiredMsaPurgeQueue::<T>::insert(msa_id, ());
+				Self::deposit_event(Event::MsaRetired { msa_id });
+			},
+			ThresholdAction::SetProviderMetadata { metadata } => {
+				ensure!(Self::is_registered_provider(msa_id), Error::<T>::ProviderNotRegistered);
+				Self::ensure_all_schema_ids_are_valid(&metadata.supported_schema_ids.to_vec())?;
+				let provider_id = ProviderId(msa_id);
+				ProviderToMetadata::<T>::insert(provider_id, metadata);
+				Self::deposit_event(Event::ProviderMetadataUpdated { provider_id });
+			},
+			ThresholdAction::RevokeDelegationByDelegator { provider_msa_id } => {
+				let delegator_id = DelegatorId(msa_id);
+				let provider_id = ProviderId(provider_msa_id);
+				Self::revoke_provider(provider_id, delegator_id)?;
+				let event = Event::DelegationRevoked { delegator_id, provider_id };
+				offchain_index_event::<T>(&event, msa_id);
+				Self::deposit_event(event);
+			},
+			ThresholdAction::RevokeDelegationByProvider { delegator_msa_id } => {
+				let provider_id = ProviderId(msa_id);
+				let delegator_id = DelegatorId(delegator_msa_id);
+				Self::revoke_provider(provider_id, delegator_id)?;
+				let event = Event::DelegationRevoked { provider_id, delegator_id };
+				offchain_index_event::<T>(&event, delegator_msa_id);
+				Self::deposit_event(event);
+			},
+			ThresholdAction::SetThreshold { threshold: Some(threshold) } => {
+				Self::ensure_valid_threshold(msa_id, threshold)?;
+				ThresholdPolicies::<T>::insert(msa_id, threshold);
+				Self::deposit_event(Event::ThresholdPolicySet { msa_id, threshold });
+			},
+			ThresholdAction::SetThreshold { threshold: None } => {
+				ThresholdPolicies::<T>::remove(msa_id);
+				Self::deposit_event(Event::ThresholdPolicyRemoved { msa_id });
+			},
+			ThresholdAction::AddPublicKey { add_key_payload, new_key_owner_proof } => {
+				ensure!(add_key_payload.msa_id == msa_id, Error::<T>::NotMsaOwner);
+				Self::verify_signature(
+					&new_key_owner_proof,
+					&add_key_payload.new_public_key,
+					&add_key_payload,
+					add_key_payload.signed_payload(),
+				)
+				.map_err(|_| Error::<T>::NewKeyOwnershipInvalidSignature)?;
+				Self::register_signature(&new_key_owner_proof, add_key_payload.expiration)?;
+				for key in approvals {
+					Self::ensure_scope_within_key_scope(key, &add_key_payload.scope)?;
+				}
+				Self::add_scoped_key(add_key_payload)?;
+			},
+			ThresholdAction::SetRecoveryConfig { guardians, threshold, commitment } => {
+				Self::apply_recovery_config(msa_id, guardians, threshold, commitment)?;
+			},
+			ThresholdAction::RemoveRecoveryConfig => {
+				Self::clear_recovery_config(msa_id)?;
+			},
+			ThresholdAction::SetSchemaGrantPermissions {
+				provider_msa_id,
+				schema_id,
+				permissions,
+			} => {
+				Self::set_delegation_schema_permissions(
+					DelegatorId(msa_id),
+					ProviderId(provider_msa_id),
+					schema_id,
+					permissions,
+				)?;
+			},
+			ThresholdAction::RevokeSchemaPermissions { provider_msa_id, schema_ids } => {
+				Self::revoke_delegation_schemas(
+					DelegatorId(msa_id),
+					ProviderId(provider_msa_id),
+					schema_ids.into_inner(),
+				)?;
+			},
+			ThresholdAction::RetireProvider => {
+				Self::schedule_provider_retirement(msa_id)?;
+			},
+			ThresholdAction::ChangeProviderName { provider_name } => {
+				let proposer = approvals.first().ok_or(Error::<T>::ThresholdActionNotAllowed)?;
+				Self::propose_provider_name_change(msa_id, proposer.clone(), provider_name)?;
+			},
+		}
+
+		Ok(())
+	}
+
+	/// Removes a proposed action of an MSA, keeping count of its open proposals
+	pub fn remove_threshold_proposal(msa_id: MessageSourceId, proposal_id: u32) {
+		ThresholdProposals::<T>::remove(msa_id, proposal_id);
+		ThresholdProposalCount::<T>::mutate(msa_id, |count| *count = count.saturating_sub(1));
+	}
+
+	/// Checks that `scope` grants nothing beyond the scope of `key`, if `key` is scoped
+	///
+	/// # Errors
+	/// * [`Error::KeyScopeRestricted`]
+	///
+	pub fn ensure_scope_within_key_scope(
+		key: &T::AccountId,
+		scope: &Option<KeyScope<T::MaxSchemaGrantsPerDelegation>>,
+	) -> DispatchResult {
+		if let Some(key_scope) = PublicKeyScopes::<T>::get(key) {
+			ensure!(
+				scope.as_ref().map_or(false, |scope| scope.is_within(&key_scope)),
+				Error::<T>::KeyScopeRestricted
+			);
+		}
+
+		Ok(())
+	}
+
+	/// Adds the new key of `add_key_payload` to its MSA, with the scope of the payload
+	///
+	/// # Errors
+	/// * [`Error::InvalidSchemaId`]
+	/// * [`Error::KeyAlreadyRegistered`]
+	/// * [`Error::KeyLimitExceeded`]
+	///
+	pub fn add_scoped_key(add_key_payload: AddKeyData<T>) -> DispatchResult {
+		if let Some(schema_ids) =
+			add_key_payload.scope.as_ref().and_then(|scope| scope.schema_ids.as_ref())
+		{
+			ensure!(
+				T::SchemaValidator::are_all_schema_ids_valid(schema_ids),
+				Error::<T>::InvalidSchemaId
+			);
+		}
+
+		Self::add_key(
+			add_key_payload.msa_id,
+			&add_key_payload.new_public_key.clone(),
+			|msa_id| -> DispatchResult {
+				if let Some(scope) = add_key_payload.scope.clone() {
+					PublicKeyScopes::<T>::insert(&add_key_payload.new_public_key, scope);
+				}
+				let event =
+					Event::PublicKeyAdded { msa_id, key: add_key_payload.new_public_key.clone() };
+				offchain_index_event::<T>(&event, msa_id);
+				Self::deposit_event(event);
+				Ok(())
+			},
+		)
+	}
+
+	/// Registers or replaces the recovery guardians of an MSA
+	///
+	/// # Errors
+	/// * [`Error::RecoveryAlreadyInProgress`]
+	/// * [`Error::InvalidRecoveryConfig`]
+	///
+	pub fn apply_recovery_config(
+		msa_id: MessageSourceId,
+		guardians: BoundedVec<MessageSourceId, T::MaxRecoveryGuardians>,
+		threshold: u32,
+		commitment: Option<H256>,
+	) -> DispatchResult {
+		ensure!(
+			!ActiveRecoveries::<T>::contains_key(msa_id),
+			Error::<T>::RecoveryAlreadyInProgress
+		);
+
+		ensure!(
+			threshold > 0 && threshold as usize <= guardians.len(),
+			Error::<T>::InvalidRecoveryConfig
+		);
+		for (index, guardian) in guardians.iter().enumerate() {
+			ensure!(
+				*guardian != msa_id &&
+					!guardians[..index].contains(guardian) &&
+					PublicKeyCountForMsaId::<T>::get(guardian) > 0,
+				Error::<T>::InvalidRecoveryConfig
+			);
+		}
+
+		RecoveryConfigs::<T>::insert(msa_id, RecoveryConfig { guardians, threshold, commitment });
+		Self::deposit_event(Event::RecoveryConfigSet { msa_id });
+
+		Ok(())
+	}
+
+	/// Removes the recovery guardians of an MSA
+	///
+	/// # Errors
+	/// * [`Error::RecoveryNotConfigured`]
+	/// * [`Error::RecoveryAlreadyInProgress`]
+	///
+	pub fn clear_recovery_config(msa_id: MessageSourceId) -> DispatchResult {
+		ensure!(RecoveryConfigs::<T>::contains_key(msa_id), Error::<T>::RecoveryNotConfigured);
+		ensure!(
+			!ActiveRecoveries::<T>::contains_key(msa_id),
+			Error::<T>::RecoveryAlreadyInProgress
+		);
+
+		RecoveryConfigs::<T>::remove(msa_id);
+		Self::deposit_event(Event::RecoveryConfigRemoved { msa_id });
+
+		Ok(())
+	}
+
+	/// Sets which actions a Provider may take with a schema granted by a Delegator
+	///
+	/// # Errors
+	/// * [`Error::DelegationNotFound`]
+	/// * [`Error::DelegationRevoked`]
+	/// * [`Error::SchemaNotGranted`]
+	/// * [`Error::ExceedsMaxSchemaGrantsPerDelegation`]
+	///
+	pub fn set_delegation_schema_permissions(
+		delegator_id: DelegatorId,
+		provider_id: ProviderId,
+		schema_id: SchemaId,
+		permissions: SchemaPermissions,
+	) -> DispatchResult {
+		let current_block = frame_system::Pallet::<T>::block_number();
+		Self::ensure_schema_granted(provider_id, delegator_id, schema_id, current_block)?;
+
+		DelegationSchemaPermissions::<T>::try_mutate(
+			delegator_id,
+			provider_id,
+			|maybe_permissions| -> DispatchResult {
+				let schema_permissions = maybe_permissions.get_or_insert_with(Default::default);
+				schema_permissions
+					.try_insert(schema_id, permissions)
+					.map_err(|_| Error::<T>::ExceedsMaxSchemaGrantsPerDelegation)?;
+				Ok(())
+			},
+		)?;
+		let event = Event::DelegationUpdated { provider_id, delegator_id };
+		offchain_index_event::<T>(&event, delegator_id.into());
+		Self::deposit_event(event);
+
+		Ok(())
+	}
+
+	/// Revokes schema grants of a delegation
+	///
+	/// # Errors
+	/// * [`Error::DelegationNotFound`]
+	/// * [`Error::SchemaNotGranted`]
+	///
+	pub fn revoke_delegation_schemas(
+		delegator_id: DelegatorId,
+		provider_id: ProviderId,
+		schema_ids: Vec<SchemaId>,
+	) -> DispatchResult {
+		Self::revoke_permissions_for_schemas(delegator_id, provider_id, schema_ids)?;
+		let event = Event::DelegationUpdated { provider_id, delegator_id };
+		offchain_index_event::<T>(&event, delegator_id.into());
+		Self::deposit_event(event);
+
+		Ok(())
+	}
+
+	/// Starts the retirement of a Provider
+	///
+	/// # Errors
+	/// * [`Error::ProviderNotRegistered`]
+	/// * [`Error::ProviderRetiring`]
+	///
+	pub fn schedule_provider_retirement(provider_msa_id: MessageSourceId) -> DispatchResult {
+		ensure!(Self::is_registered_provider(provider_msa_id), Error::<T>::ProviderNotRegistered);
+		ensure!(!Self::is_retiring_provider(provider_msa_id), Error::<T>::ProviderRetiring);
+
+		let provider_id = ProviderId(provider_msa_id);
+		let effective_at = frame_system::Pallet::<T>::block_number()
+			.saturating_add(T::ProviderRetirementGracePeriod::get());
+		ProviderRetirements::<T>::insert(provider_id, effective_at);
+		Self::deposit_event(Event::ProviderRetirementScheduled { provider_id, effective_at });
+
+		Ok(())
+	}
+
+	/// Proposes to governance a new name for a Provider, on behalf of `proposer`
+	///
+	/// # Errors
+	/// * [`Error::ProviderNotRegistered`]
+	/// * Any error of the proposal
+	///
+	pub fn propose_provider_name_change(
+		provider_msa_id: MessageSourceId,
+		proposer: T::AccountId,
+		provider_name: BoundedVec<u8, T::MaxProviderNameSize>,
+	) -> DispatchResult {
+		ensure!(Self::is_registered_provider(provider_msa_id), Error::<T>::ProviderNotRegistered);
 
-		Ok((provider_msa_id.into(), delegator_msa_id.into()))
+		let proposal: Box<T::Proposal> = Box::new(
+			(Call::<T>::change_provider_name_via_governance {
+				provider_key: proposer.clone(),
+				provider_name: provider_name.into(),
+			})
+			.into(),
+		);
+		let threshold = 1;
+		T::ProposalProvider::propose(proposer, threshold, proposal)?;
+
+		Ok(())
 	}
 
-	/// Checks that the MSA for `who` is the same as `msa_id`
+	/// Checks that the scope of `key`, if any, allows it to write data of `schema_id`
 	///
 	/// # Errors
-	/// * [`Error::NotMsaOwner`]
-	/// * [`Error::NoKeyExists`]
+	/// * [`Error::KeyScopeRestricted`]
 	///
-	pub fn ensure_msa_owner(who: &T::AccountId, msa_id: MessageSourceId) -> DispatchResult {
-		let provider_msa_id = Self::ensure_valid_msa_key(who)?;
-		ensure!(provider_msa_id == msa_id, Error::<T>::NotMsaOwner);
+	pub fn ensure_key_allows_schema(key: &T::AccountId, schema_id: SchemaId) -> DispatchResult {
+		let allows_schema =
+			PublicKeyScopes::<T>::get(key).map_or(true, |scope| scope.allows_schema(schema_id));
+		ensure!(allows_schema, Error::<T>::KeyScopeRestricted);
 
 		Ok(())
 	}
 
 	/// Verify the `signature` was signed by `signer` on `payload` by a wallet
-	/// Note the `wrap_binary_data` follows the Polkadot wallet pattern of wrapping with `<Byte>` tags.
+	/// Note the `wrap_binary_data` follows the Polkadot wallet pattern of wrapping `encoded_payload` with `<Byte>` tags.
+	/// Ethereum keys instead sign the EIP-712 typed data of `payload`.
 	///
 	/// # Errors
 	/// * [`Error::InvalidSignature`]
 	///
-	pub fn verify_signature(
+	pub fn verify_signature<P: EIP712Encode>(
 		signature: &MultiSignature,
 		signer: &T::AccountId,
-		payload: Vec<u8>,
+		payload: &P,
+		encoded_payload: Vec<u8>,
 	) -> DispatchResult {
 		let key = T::ConvertIntoAccountId32::convert((*signer).clone());
-		let wrapped_payload = wrap_binary_data(payload);
 
-		ensure!(signature.verify(&wrapped_payload[..], &key), Error::<T>::InvalidSignature);
+		ensure!(
+			signatures::verify_signature(
+				signature,
+				&key,
+				payload,
+				encoded_payload,
+				&T::Eip712Domain::get()
+			),
+			Error::<T>::InvalidSignature
+		);
 
 		Ok(())
 	}
 
-	/// Add a provider to a delegator with the default permissions
+	/// Add a provider to a delegator with the default permissions.
+	/// A delegation with `expires_at` is treated as revoked after that block.
 	///
 	/// # Errors
 	/// * [`Error::ExceedsMaxSchemaGrantsPerDelegation`]
@@ -1113,18 +3504,21 @@ impl<T: Config> Pallet<T> {
 		provider_id: ProviderId,
 		delegator_id: DelegatorId,
 		schema_ids: Vec<SchemaId>,
+		expires_at: Option<BlockNumberFor<T>>,
 	) -> DispatchResult {
 		Self::try_mutate_delegation(delegator_id, provider_id, |delegation, is_new_delegation| {
 			ensure!(is_new_delegation, Error::<T>::DuplicateProvider);
 			Self::ensure_all_schema_ids_are_valid(&schema_ids)?;
 
 			PermittedDelegationSchemas::<T>::try_insert_schemas(delegation, schema_ids)?;
+			delegation.revoked_at = expires_at.unwrap_or_default();
 
 			Ok(())
 		})
 	}
 
-	/// Modify delegation's schema permissions
+	/// Modify delegation's schema permissions, and set when the delegation expires.
+	/// A delegation with `expires_at` is treated as revoked after that block.
 	///
 	/// # Errors
 	/// * [`Error::ExceedsMaxSchemaGrantsPerDelegation`]
@@ -1132,6 +3526,7 @@ impl<T: Config> Pallet<T> {
 		provider_id: ProviderId,
 		delegator_id: DelegatorId,
 		schema_ids: Vec<SchemaId>,
+		expires_at: Option<BlockNumberFor<T>>,
 	) -> DispatchResult {
 		Self::try_mutate_delegation(delegator_id, provider_id, |delegation, _is_new_delegation| {
 			Self::ensure_all_schema_ids_are_valid(&schema_ids)?;
@@ -1154,13 +3549,23 @@ impl<T: Config> Pallet<T> {
 					}
 				}
 			}
+			// Schemas being revoked, newly granted or granted again start over with every permission.
+			let mut cleared_ids: Vec<SchemaId> = revoke_ids.clone();
 			for schema_id in &schema_ids {
-				if !delegation.schema_permissions.contains_key(&schema_id) {
-					insert_ids.push(*schema_id);
-				} else {
-					update_ids.push(*schema_id);
+				match delegation.schema_permissions.get(&schema_id) {
+					None => {
+						insert_ids.push(*schema_id);
+						cleared_ids.push(*schema_id);
+					},
+					Some(block) => {
+						if *block != BlockNumberFor::<T>::zero() {
+							cleared_ids.push(*schema_id);
+						}
+						update_ids.push(*schema_id);
+					},
 				}
 			}
+			Self::clear_schema_grant_permissions(delegator_id, provider_id, &cleared_ids);
 
 			let current_block = frame_system::Pallet::<T>::block_number();
 
@@ -1180,7 +3585,7 @@ impl<T: Config> Pallet<T> {
 
 			// Insert any new ones that are not in the existing list
 			PermittedDelegationSchemas::<T>::try_insert_schemas(delegation, insert_ids)?;
-			delegation.revoked_at = BlockNumberFor::<T>::zero();
+			delegation.revoked_at = expires_at.unwrap_or_default();
 			Ok(())
 		})
 	}
@@ -1202,6 +3607,7 @@ impl<T: Config> Pallet<T> {
 	) -> DispatchResult {
 		let bounded_name: BoundedVec<u8, T::MaxProviderNameSize> =
 			provider_name.try_into().map_err(|_| Error::<T>::ExceedsMaxProviderNameSize)?;
+		ensure!(!Self::is_retiring_provider(provider_msa_id), Error::<T>::ProviderRetiring);
 
 		ProviderToRegistryEntry::<T>::try_mutate(
 			ProviderId(provider_msa_id),
@@ -1217,82 +3623,875 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
-	/// Mutates the delegation relationship storage item only when the supplied function returns an 'Ok()' result.
-	/// The callback function 'f' takes the value (a delegation) and a reference to a boolean variable. This callback
-	/// sets the boolean variable to 'true' if the value is to be inserted and to 'false' if it is to be updated.
-	pub fn try_mutate_delegation<R, E: From<DispatchError>>(
-		delegator_id: DelegatorId,
-		provider_id: ProviderId,
-		f: impl FnOnce(
-			&mut Delegation<SchemaId, BlockNumberFor<T>, T::MaxSchemaGrantsPerDelegation>,
-			bool,
-		) -> Result<R, E>,
-	) -> Result<R, E> {
-		DelegatorAndProviderToDelegation::<T>::try_mutate_exists(
-			delegator_id,
-			provider_id,
-			|maybe_delegation_info| {
-				let is_new = maybe_delegation_info.is_none();
-				let mut delegation = maybe_delegation_info.take().unwrap_or_default();
+	/// Mutates the delegation relationship storage item only when the supplied function returns an 'Ok()' result.
+	/// The callback function 'f' takes the value (a delegation) and a reference to a boolean variable. This callback
+	/// sets the boolean variable to 'true' if the value is to be inserted and to 'false' if it is to be updated.
+	pub fn try_mutate_delegation<R, E: From<DispatchError>>(
+		delegator_id: DelegatorId,
+		provider_id: ProviderId,
+		f: impl FnOnce(
+			&mut Delegation<SchemaId, BlockNumberFor<T>, T::MaxSchemaGrantsPerDelegation>,
+			bool,
+		) -> Result<R, E>,
+	) -> Result<R, E> {
+		DelegatorAndProviderToDelegation::<T>::try_mutate_exists(
+			delegator_id,
+			provider_id,
+			|maybe_delegation_info| {
+				let is_new = maybe_delegation_info.is_none();
+				let mut delegation = maybe_delegation_info.take().unwrap_or_default();
+
+				f(&mut delegation, is_new).map(move |result| {
+					if is_new {
+						ProviderToDelegators::<T>::insert(provider_id, delegator_id, ());
+					}
+					*maybe_delegation_info = Some(delegation);
+					result
+				})
+			},
+		)
+	}
+
+	/// Deletes a key associated with a given MSA
+	///
+	/// # Errors
+	/// * [`Error::NoKeyExists`]
+	///
+	pub fn delete_key_for_msa(msa_id: MessageSourceId, key: &T::AccountId) -> DispatchResult {
+		PublicKeyToMsaId::<T>::try_mutate_exists(key, |maybe_msa_id| {
+			ensure!(maybe_msa_id.is_some(), Error::<T>::NoKeyExists);
+
+			// Delete the key if it exists
+			*maybe_msa_id = None;
+			PublicKeyScopes::<T>::remove(key);
+
+			<PublicKeyCountForMsaId<T>>::try_mutate_exists(msa_id, |key_count| {
+				match key_count {
+					Some(1) => *key_count = None,
+					Some(count) => *count = *count - 1u8,
+					None => (),
+				}
+
+				Ok(())
+			})
+		})
+	}
+
+	/// Replaces `old_key` of the given MSA with `new_key`, leaving the key count unchanged.
+	/// The scope of `old_key`, if any, moves to `new_key`.
+	///
+	/// # Errors
+	/// * [`Error::KeyAlreadyRegistered`]
+	/// * [`Error::NoKeyExists`]
+	///
+	pub fn rotate_key(
+		msa_id: MessageSourceId,
+		old_key: &T::AccountId,
+		new_key: &T::AccountId,
+	) -> DispatchResult {
+		ensure!(PublicKeyToMsaId::<T>::get(old_key) == Some(msa_id), Error::<T>::NoKeyExists);
+		PublicKeyToMsaId::<T>::try_mutate(new_key, |maybe_msa_id| {
+			ensure!(maybe_msa_id.is_none(), Error::<T>::KeyAlreadyRegistered);
+			*maybe_msa_id = Some(msa_id);
+			Ok::<(), DispatchError>(())
+		})?;
+		PublicKeyToMsaId::<T>::remove(old_key);
+		if let Some(scope) = PublicKeyScopes::<T>::take(old_key) {
+			PublicKeyScopes::<T>::insert(new_key, scope);
+		}
+
+		Ok(())
+	}
+
+	/// Revoke the grant for permissions from the delegator to the provider
+	///
+	/// # Errors
+	/// * [`Error::DelegationRevoked`]
+	/// * [`Error::DelegationNotFound`]
+	///
+	pub fn revoke_provider(provider_id: ProviderId, delegator_id: DelegatorId) -> DispatchResult {
+		DelegatorAndProviderToDelegation::<T>::try_mutate_exists(
+			delegator_id,
+			provider_id,
+			|maybe_info| -> DispatchResult {
+				let mut info = maybe_info.take().ok_or(Error::<T>::DelegationNotFound)?;
+				let current_block = frame_system::Pallet::<T>::block_number();
+
+				// A delegation that has not yet expired may still be revoked.
+				ensure!(
+					info.revoked_at == BlockNumberFor::<T>::default() ||
+						info.revoked_at > current_block,
+					Error::<T>::DelegationRevoked
+				);
+
+				info.revoked_at = current_block;
+				*maybe_info = Some(info);
+				Ok(())
+			},
+		)?;
+		DelegationSchemaPermissions::<T>::remove(delegator_id, provider_id);
+
+		Ok(())
+	}
+
+	/// Removes the permissions set on `schema_ids` of a delegation, so that a schema granted again
+	/// allows every permission until the Delegator restricts it.
+	pub fn clear_schema_grant_permissions(
+		delegator_id: DelegatorId,
+		provider_id: ProviderId,
+		schema_ids: &[SchemaId],
+	) {
+		DelegationSchemaPermissions::<T>::mutate_exists(
+			delegator_id,
+			provider_id,
+			|maybe_permissions| {
+				if let Some(permissions) = maybe_permissions {
+					for schema_id in schema_ids {
+						permissions.remove(schema_id);
+					}
+					if permissions.is_empty() {
+						*maybe_permissions = None;
+					}
+				}
+			},
+		);
+	}
+
+	/// Removes the recovery guardians and any recovery in progress of an MSA that is retired or
+	/// merged, so that no recovery can add a key to it
+	fn remove_recovery(msa_id: MessageSourceId) {
+		RecoveryConfigs::<T>::remove(msa_id);
+		ActiveRecoveries::<T>::remove(msa_id);
+	}
+
+	/// Purges the data of the next MSA in the [`RetiredMsaPurgeQueue`], deleting as much as fits
+	/// in `remaining_weight`. Returns the weight used.
+	pub fn purge_retired_msas(remaining_weight: Weight) -> Weight {
+		let queue_read_weight = T::DbWeight::get().reads(1);
+		if remaining_weight.any_lt(queue_read_weight) {
+			return Weight::zero()
+		}
+
+		let Some(msa_id) = RetiredMsaPurgeQueue::<T>::iter_keys().next() else {
+			return queue_read_weight
+		};
+
+		queue_read_weight.saturating_add(Self::purge_retired_msa(
+			msa_id,
+			remaining_weight.saturating_sub(queue_read_weight),
+		))
+	}
+
+	/// Revokes the delegations to the first provider in the [`ProviderRevocationQueue`], using at
+	/// most `remaining_weight`. Returns the weight used.
+	pub fn revoke_retired_provider_delegations(remaining_weight: Weight) -> Weight {
+		let base_weight = T::WeightInfo::revoke_retired_provider_delegations(0);
+		let item_weight =
+			T::WeightInfo::revoke_retired_provider_delegations(1).saturating_sub(base_weight);
+		if remaining_weight.any_lt(base_weight.saturating_add(item_weight)) {
+			return Weight::zero()
+		}
+
+		let Some(provider_id) = ProviderRevocationQueue::<T>::iter_keys().next() else {
+			return T::DbWeight::get().reads(1)
+		};
+
+		let available_weight = remaining_weight.saturating_sub(base_weight);
+		let limit = [
+			available_weight.ref_time().checked_div(item_weight.ref_time()),
+			available_weight.proof_size().checked_div(item_weight.proof_size()),
+		]
+		.into_iter()
+		.flatten()
+		.min()
+		.unwrap_or(u64::MAX);
+		let revoked = Self::revoke_retired_provider(provider_id, limit.saturated_into());
+
+		T::WeightInfo::revoke_retired_provider_delegations(revoked)
+	}
+
+	/// Moves every key of `source_msa_id` to `target_msa_id`
+	///
+	/// # Errors
+	/// * [`Error::MsaMergeKeysMismatch`]
+	/// * [`Error::KeyLimitExceeded`]
+	///
+	pub fn move_msa_keys(
+		source_msa_id: MessageSourceId,
+		target_msa_id: MessageSourceId,
+		keys: &[T::AccountId],
+	) -> DispatchResult {
+		let source_key_count = PublicKeyCountForMsaId::<T>::get(source_msa_id);
+		ensure!(keys.len() == source_key_count as usize, Error::<T>::MsaMergeKeysMismatch);
+		let target_key_count = PublicKeyCountForMsaId::<T>::get(target_msa_id)
+			.checked_add(source_key_count)
+			.filter(|count| *count <= T::MaxPublicKeysPerMsa::get())
+			.ok_or(Error::<T>::KeyLimitExceeded)?;
+
+		for key in keys {
+			PublicKeyToMsaId::<T>::try_mutate(key, |maybe_msa_id| -> DispatchResult {
+				ensure!(*maybe_msa_id == Some(source_msa_id), Error::<T>::MsaMergeKeysMismatch);
+				*maybe_msa_id = Some(target_msa_id);
+				Ok(())
+			})?;
+
+			let event = Event::PublicKeyDeleted { key: key.clone() };
+			offchain_index_event::<T>(&event, source_msa_id);
+			Self::deposit_event(event);
+			let event = Event::PublicKeyAdded { msa_id: target_msa_id, key: key.clone() };
+			offchain_index_event::<T>(&event, target_msa_id);
+			Self::deposit_event(event);
+		}
+
+		PublicKeyCountForMsaId::<T>::remove(source_msa_id);
+		PublicKeyCountForMsaId::<T>::insert(target_msa_id, target_key_count);
+
+		Ok(())
+	}
+
+	/// Get the MSA that `msa_id` was merged into, following the later merges of that MSA.
+	/// Returns `None` if `msa_id` was not merged.
+	pub fn get_msa_redirect(msa_id: MessageSourceId) -> Option<MessageSourceId> {
+		MsaRedirects::<T>::contains_key(msa_id).then(|| Self::resolve_merged_msa_id(msa_id))
+	}
+
+	/// Follows the redirects of merged MSAs from `msa_id` to the MSA its keys are now in.
+	/// Returns `msa_id` if it was not merged.
+	pub fn resolve_merged_msa_id(msa_id: MessageSourceId) -> MessageSourceId {
+		let mut resolved_msa_id = msa_id;
+		while let Some(target_msa_id) = MsaRedirects::<T>::get(resolved_msa_id) {
+			resolved_msa_id = target_msa_id;
+		}
+		resolved_msa_id
+	}
+
+	/// Moves the delegations and data of the first merged MSA in the [`MsaMergeQueue`], using
+	/// at most `remaining_weight`. Returns the weight used.
+	pub fn merge_queued_msas(remaining_weight: Weight) -> Weight {
+		let base_weight = Self::merge_queued_msa_weight(0);
+		let item_weight = Self::merge_queued_msa_weight(1).saturating_sub(base_weight);
+		if remaining_weight.any_lt(base_weight.saturating_add(item_weight)) {
+			return Weight::zero()
+		}
+
+		let Some((source_msa_id, target_msa_id)) = MsaMergeQueue::<T>::iter().next() else {
+			return T::DbWeight::get().reads(1)
+		};
+
+		let available_weight = remaining_weight.saturating_sub(base_weight);
+		let limit = [
+			available_weight.ref_time().checked_div(item_weight.ref_time()),
+			available_weight.proof_size().checked_div(item_weight.proof_size()),
+		]
+		.into_iter()
+		.flatten()
+		.min()
+		.unwrap_or(u64::MAX);
+		let items_merged =
+			Self::merge_queued_msa(source_msa_id, target_msa_id, limit.saturated_into());
+
+		Self::merge_queued_msa_weight(items_merged)
+	}
+
+	/// The weight of merging `items` delegations and pages of a merged MSA
+	fn merge_queued_msa_weight(items: u32) -> Weight {
+		// Checking the keys of the target MSA and the cursor of a paginated merge are not covered
+		// by the generated weight
+		T::WeightInfo::merge_queued_msa(items)
+			.saturating_add(T::DbWeight::get().reads_writes(2, 1))
+			.saturating_add(Weight::from_parts(3_000_000, 2_492 + 2_494))
+	}
+
+	/// Moves up to `limit` items of the delegations and then of the data in other pallets of the
+	/// merged `source_msa_id` to the MSA it was merged into. A delegation is not moved when the
+	/// target MSA already has a delegation to the same provider. Once everything is moved, removes
+	/// `source_msa_id` from the [`MsaMergeQueue`] and queues it for purging with what was not moved.
+	/// If the target MSA has been retired meanwhile, nothing more is moved to it, since it is being
+	/// purged, and `source_msa_id` is queued for purging right away.
+	/// Returns the number of items moved.
+	///
+	/// # Events
+	/// * [`Event::DelegationRevoked`] - for each delegation of `source_msa_id` that is not revoked,
+	/// whether it is moved or not
+	/// * [`Event::DelegationGranted`] - for each moved delegation that is not revoked
+	/// * [`Event::MsaMergeCompleted`]
+	/// * [`Event::MsaMergeAbandoned`]
+	///
+	pub fn merge_queued_msa(
+		source_msa_id: MessageSourceId,
+		target_msa_id: MessageSourceId,
+		limit: u32,
+	) -> u32 {
+		// The target may itself have been merged into another MSA since
+		let target_msa_id = Self::resolve_merged_msa_id(target_msa_id);
+		// A retired MSA has no keys left; data moved to it would outlive its purge
+		if PublicKeyCountForMsaId::<T>::get(target_msa_id) == 0 {
+			MsaMergeQueue::<T>::remove(source_msa_id);
+			RetiredMsaPurgeQueue::<T>::insert(source_msa_id, ());
+			Self::deposit_event(Event::MsaMergeAbandoned { source_msa_id, target_msa_id });
+			return 0
+		}
+		let source_delegator_id = DelegatorId(source_msa_id);
+		let target_delegator_id = DelegatorId(target_msa_id);
+		let current_block = frame_system::Pallet::<T>::block_number();
+
+		let delegations: Vec<_> =
+			DelegatorAndProviderToDelegation::<T>::drain_prefix(source_delegator_id)
+				.take(limit as usize)
+				.collect();
+		let mut items_merged = 0u32;
+		for (provider_id, delegation) in delegations {
+			ProviderToDelegators::<T>::remove(provider_id, source_delegator_id);
+			let permissions =
+				DelegationSchemaPermissions::<T>::take(source_delegator_id, provider_id);
+			let is_active =
+				delegation.revoked_at.is_zero() || delegation.revoked_at >= current_block;
+			if is_active {
+				let event =
+					Event::DelegationRevoked { provider_id, delegator_id: source_delegator_id };
+				offchain_index_event::<T>(&event, source_msa_id);
+				Self::deposit_event(event);
+			}
+			if !DelegatorAndProviderToDelegation::<T>::contains_key(
+				target_delegator_id,
+				provider_id,
+			) {
+				DelegatorAndProviderToDelegation::<T>::insert(
+					target_delegator_id,
+					provider_id,
+					delegation,
+				);
+				ProviderToDelegators::<T>::insert(provider_id, target_delegator_id, ());
+				if let Some(permissions) = permissions {
+					DelegationSchemaPermissions::<T>::insert(
+						target_delegator_id,
+						provider_id,
+						permissions,
+					);
+				}
+				if is_active {
+					let event =
+						Event::DelegationGranted { delegator_id: target_delegator_id, provider_id };
+					offchain_index_event::<T>(&event, target_msa_id);
+					Self::deposit_event(event);
+				}
+			}
+			items_merged = items_merged.saturating_add(1);
+		}
+		let mut complete =
+			DelegatorAndProviderToDelegation::<T>::iter_key_prefix(source_delegator_id)
+				.next()
+				.is_none();
+
+		if complete {
+			let (data_merged, data_complete) = T::MsaDataMerger::merge_msa_data(
+				source_msa_id,
+				target_msa_id,
+				limit.saturating_sub(items_merged),
+			);
+			items_merged = items_merged.saturating_add(data_merged);
+			complete = data_complete;
+		}
+
+		if complete {
+			MsaMergeQueue::<T>::remove(source_msa_id);
+			RetiredMsaPurgeQueue::<T>::insert(source_msa_id, ());
+			Self::deposit_event(Event::MsaMergeCompleted { source_msa_id, target_msa_id });
+		}
+
+		items_merged
+	}
+
+	/// Revokes up to `limit` delegations to the retired `provider_id` from the block its retirement
+	/// took effect, unless they were revoked before then. Once every delegation is revoked, removes
+	/// the provider from the [`ProviderRevocationQueue`] and [`ProviderRetirements`].
+	/// Returns the number of delegations revoked.
+	///
+	/// # Events
+	/// * [`Event::DelegationRevoked`] - for each delegation revoked by the retirement
+	///
+	pub fn revoke_retired_provider(provider_id: ProviderId, limit: u32) -> u32 {
+		let effective_at = ProviderRetirements::<T>::get(provider_id).unwrap_or_default();
+		let delegators: Vec<DelegatorId> = ProviderToDelegators::<T>::drain_prefix(provider_id)
+			.map(|(delegator_id, _)| delegator_id)
+			.take(limit as usize)
+			.collect();
+		let revoked = delegators.len() as u32;
+		for delegator_id in delegators {
+			let was_active = DelegatorAndProviderToDelegation::<T>::mutate(
+				delegator_id,
+				provider_id,
+				|maybe_delegation| match maybe_delegation {
+					Some(delegation)
+						if delegation.revoked_at.is_zero() ||
+							delegation.revoked_at > effective_at =>
+					{
+						delegation.revoked_at = effective_at;
+						true
+					},
+					_ => false,
+				},
+			);
+			if was_active {
+				DelegationSchemaPermissions::<T>::remove(delegator_id, provider_id);
+				let event = Event::DelegationRevoked { provider_id, delegator_id };
+				offchain_index_event::<T>(&event, delegator_id.into());
+				Self::deposit_event(event);
+			}
+		}
+
+		// Until the migration has indexed every delegation, the index may still be missing some
+		if ProviderToDelegators::<T>::iter_key_prefix(provider_id).next().is_none() &&
+			!ProviderToDelegatorsMigrationCursor::<T>::exists()
+		{
+			ProviderRevocationQueue::<T>::remove(provider_id);
+			ProviderRetirements::<T>::remove(provider_id);
+		}
+
+		revoked
+	}
+
+	/// Deletes as much of the data of the retired `msa_id` as fits in `remaining_weight`: its
+	/// recovery guardians, its delegations, its pending delegation requests, its proposed threshold
+	/// actions, and then, through [`Config::MsaDataPurger`], the data it has in other pallets.
+	/// Removes `msa_id` from the [`RetiredMsaPurgeQueue`] once everything is deleted.
+	/// Returns the weight used.
+	///
+	/// # Events
+	/// * [`Event::DelegationRevoked`] - for each purged delegation that was still active
+	/// * [`Event::MsaPurgeProgressed`]
+	/// * [`Event::MsaPurgeCompleted`]
+	///
+	pub fn purge_retired_msa(msa_id: MessageSourceId, remaining_weight: Weight) -> Weight {
+		let base_weight = Self::purge_retired_msa_weight(0);
+		let item_weight = Self::purge_retired_msa_weight(1).saturating_sub(base_weight);
+		if remaining_weight.any_lt(base_weight.saturating_add(item_weight)) {
+			return Weight::zero()
+		}
+
+		let available_weight = remaining_weight.saturating_sub(base_weight);
+		let limit = [
+			available_weight.ref_time().checked_div(item_weight.ref_time()),
+			available_weight.proof_size().checked_div(item_weight.proof_size()),
+		]
+		.into_iter()
+		.flatten()
+		.min()
+		.unwrap_or(u64::MAX);
+		let (items_purged, mut complete) =
+			Self::purge_retired_msa_items(msa_id, limit.saturated_into());
+		let mut used_weight = Self::purge_retired_msa_weight(items_purged);
+
+		if complete {
+			let (data_weight, data_complete) = T::MsaDataPurger::purge_msa_data(
+				msa_id,
+				remaining_weight.saturating_sub(used_weight),
+			);
+			used_weight = used_weight.saturating_add(data_weight);
+			complete = data_complete;
+		}
+
+		Self::deposit_event(Event::MsaPurgeProgressed { msa_id, items_purged });
+		if complete {
+			NextThresholdProposalId::<T>::remove(msa_id);
+			ThresholdProposalCount::<T>::remove(msa_id);
+			RetiredMsaPurgeQueue::<T>::remove(msa_id);
+			Self::deposit_event(Event::MsaPurgeCompleted { msa_id });
+		}
+
+		used_weight
+	}
+
+	/// The weight of reading and writing the scopes of MSA keys, which the generated weights do
+	/// not cover
+	fn key_scopes_weight(reads: u64, writes: u64) -> Weight {
+		T::DbWeight::get()
+			.reads_writes(reads, writes)
+			.saturating_add(Weight::from_parts(0, 2_578).saturating_mul(reads))
+	}
+
+	/// The weight of purging `items` delegations and guardians of a retired MSA
+	pub fn purge_retired_msa_weight(items: u32) -> Weight {
+		// Checking for pending delegation requests, clearing the threshold proposal counters and
+		// removing each purged delegation from the index of its provider's delegators are not
+		// covered by the generated weight
+		T::WeightInfo::purge_retired_msa(items)
+			.saturating_add(T::DbWeight::get().reads_writes(2, 2))
+			.saturating_add(Weight::from_parts(400_000, 2_576 + 4_177))
+			.saturating_add(
+				T::DbWeight::get()
+					.reads_writes(1, 1)
+					.saturating_add(Weight::from_parts(3_500_000, 0))
+					.saturating_mul(items.into()),
+			)
+	}
+
+	/// The weight of checking whether an MSA has a threshold policy, which the generated weights
+	/// do not cover
+	fn threshold_policy_read_weight() -> Weight {
+		T::DbWeight::get().reads(1).saturating_add(Weight::from_parts(0, 2_492))
+	}
+
+	/// The weight of counting the pending threshold proposals of an MSA
+	fn threshold_proposal_count_weight() -> Weight {
+		T::DbWeight::get()
+			.reads_writes(1, 1)
+			.saturating_add(Weight::from_parts(0, 2_495))
+	}
+
+	/// The weight of holding or releasing the deposit of a delegation request and counting the
+	/// pending requests of its Delegator, which the generated weights do not cover
+	fn delegation_request_deposit_weight() -> Weight {
+		T::DbWeight::get()
+			.reads_writes(3, 3)
+			.saturating_add(Weight::from_parts(18_000_000, 2_495 + 2_560 + 2_603))
+	}
+
+	/// Deletes up to `limit` of the items the MSA pallet stores for the retired `msa_id`.
+	/// Returns the number of items deleted, and whether all of them have been deleted.
+	fn purge_retired_msa_items(msa_id: MessageSourceId, limit: u32) -> (u32, bool) {
+		RecoveryConfigs::<T>::remove(msa_id);
+		ActiveRecoveries::<T>::remove(msa_id);
+
+		let current_block = frame_system::Pallet::<T>::block_number();
+		let delegator_id = DelegatorId(msa_id);
+		let mut items_purged = 0u32;
+		for (provider_id, delegation) in
+			DelegatorAndProviderToDelegation::<T>::drain_prefix(delegator_id).take(limit as usize)
+		{
+			ProviderToDelegators::<T>::remove(provider_id, delegator_id);
+			if delegation.revoked_at.is_zero() || delegation.revoked_at >= current_block {
+				let event = Event::DelegationRevoked { provider_id, delegator_id };
+				offchain_index_event::<T>(&event, msa_id);
+				Self::deposit_event(event);
+			}
+			items_purged = items_purged.saturating_add(1);
+		}
+		let mut complete = DelegatorAndProviderToDelegation::<T>::iter_key_prefix(delegator_id)
+			.next()
+			.is_none();
+
+		if complete {
+			let permissions = DelegationSchemaPermissions::<T>::clear_prefix(
+				delegator_id,
+				limit.saturating_sub(items_purged),
+				None,
+			);
+			items_purged = items_purged.saturating_add(permissions.unique);
+			complete = permissions.maybe_cursor.is_none();
+		}
+
+		if complete {
+			// Each request also releases its deposit, which writes the holds and the account of
+			// the depositor, so it counts as three items
+			let providers: Vec<ProviderId> =
+				PendingDelegationRequests::<T>::iter_key_prefix(delegator_id)
+					.take((limit.saturating_sub(items_purged) / 3) as usize)
+					.collect();
+			for provider_id in providers {
+				if let Err(error) = Self::remove_delegation_request(delegator_id, provider_id) {
+					log::error!("failed to release a delegation request deposit: {:?}", error);
+				}
+				items_purged = items_purged.saturating_add(3);
+			}
+			complete =
+				PendingDelegationRequests::<T>::iter_key_prefix(delegator_id).next().is_none();
+		}
+
+		if complete {
+			let proposals = ThresholdProposals::<T>::clear_prefix(
+				msa_id,
+				limit.saturating_sub(items_purged),
+				None,
+			);
+			items_purged = items_purged.saturating_add(proposals.unique);
+			complete = proposals.maybe_cursor.is_none();
+		}
+
+		(items_purged, complete)
+	}
+
+	/// Checks that a requested delegation expiry, if any, is in the future
+	///
+	/// # Errors
+	/// * [`Error::DelegationExpiryInPast`]
+	///
+	pub fn ensure_valid_delegation_expiry(expires_at: Option<BlockNumberFor<T>>) -> DispatchResult {
+		if let Some(expires_at) = expires_at {
+			let current_block = frame_system::Pallet::<T>::block_number();
+			ensure!(expires_at > current_block, Error::<T>::DelegationExpiryInPast);
+		}
+		Ok(())
+	}
+
+	/// Removes the pending delegation request of `provider_id` to `delegator_id`, if any, and
+	/// releases its deposit
+	pub fn remove_delegation_request(
+		delegator_id: DelegatorId,
+		provider_id: ProviderId,
+	) -> Result<Option<DelegationRequest<T>>, DispatchError> {
+		let Some(request) = PendingDelegationRequests::<T>::take(delegator_id, provider_id) else {
+			return Ok(None)
+		};
+		PendingDelegationRequestCount::<T>::mutate_exists(delegator_id, |count| {
+			*count = count.map(|count| count.saturating_sub(1)).filter(|count| *count > 0);
+		});
+		T::Currency::release(
+			&HoldReason::DelegationRequestDeposit.into(),
+			&request.depositor,
+			request.deposit,
+			Precision::BestEffort,
+		)?;
+		Ok(Some(request))
+	}
+
+	/// Check if provider has been granted a given schema_id for a given delegator, regardless of
+	/// which permissions the grant carries
+	///
+	/// # Errors
+	/// * [`Error::DelegationNotFound`]
+	/// * [`Error::DelegationRevoked`]
+	/// * [`Error::SchemaNotGranted`]
+	/// * [`Error::CannotPredictValidityPastCurrentBlock`]
+	///
+	pub fn ensure_schema_granted(
+		provider: ProviderId,
+		delegator: DelegatorId,
+		schema_id: SchemaId,
+		block_number: BlockNumberFor<T>,
+	) -> DispatchResult {
+		Self::ensure_schema_granted_by_root(provider, delegator, schema_id, block_number)?;
+		Ok(())
+	}
+
+	/// Same as [`ensure_schema_granted`](Pallet::ensure_schema_granted), returning the Provider
+	/// that holds the delegation from `delegator` that the grant of `provider` derives from.
+	fn ensure_schema_granted_by_root(
+		provider: ProviderId,
+		delegator: DelegatorId,
+		schema_id: SchemaId,
+		block_number: BlockNumberFor<T>,
+	) -> Result<ProviderId, DispatchError> {
+		let mut lookups = T::MaxSubDelegationLookups::get();
+		let (root_provider, provider_info) = Self::resolve_delegation(
+			provider,
+			delegator,
+			Some(block_number),
+			Some(schema_id),
+			T::MaxSubDelegationDepth::get(),
+			&mut lookups,
+		)?;
 
-				f(&mut delegation, is_new).map(move |result| {
-					*maybe_delegation_info = Some(delegation);
-					result
-				})
-			},
-		)
+		let schema_permission_revoked_at_block_number = provider_info
+			.schema_permissions
+			.get(&schema_id)
+			.ok_or(Error::<T>::SchemaNotGranted)?;
+
+		if *schema_permission_revoked_at_block_number == BlockNumberFor::<T>::zero() {
+			return Ok(root_provider);
+		}
+
+		ensure!(
+			block_number <= *schema_permission_revoked_at_block_number,
+			Error::<T>::SchemaNotGranted
+		);
+
+		Ok(root_provider)
 	}
 
-	/// Deletes a key associated with a given MSA
+	/// Check that the delegator has an active delegation to the provider itself, ignoring the
+	/// grants sub-delegated to the provider.
+	/// `block_number`: Provide `None` to know if the delegation is active at the current block.
+	///                 Provide Some(N) to know if the delegation was or will be active at block N.
 	///
 	/// # Errors
-	/// * [`Error::NoKeyExists`]
+	/// * [`Error::DelegationNotFound`]
+	/// * [`Error::DelegationRevoked`]
+	/// * [`Error::CannotPredictValidityPastCurrentBlock`]
 	///
-	pub fn delete_key_for_msa(msa_id: MessageSourceId, key: &T::AccountId) -> DispatchResult {
-		PublicKeyToMsaId::<T>::try_mutate_exists(key, |maybe_msa_id| {
-			ensure!(maybe_msa_id.is_some(), Error::<T>::NoKeyExists);
-
-			// Delete the key if it exists
-			*maybe_msa_id = None;
+	pub fn ensure_valid_direct_delegation(
+		provider_id: ProviderId,
+		delegator_id: DelegatorId,
+		block_number: Option<BlockNumberFor<T>>,
+	) -> Result<
+		Delegation<SchemaId, BlockNumberFor<T>, T::MaxSchemaGrantsPerDelegation>,
+		DispatchError,
+	> {
+		let info = DelegatorAndProviderToDelegation::<T>::get(delegator_id, provider_id)
+			.map(|delegation| Pallet::<T>::apply_provider_retirement(provider_id, delegation))
+			.ok_or(Error::<T>::DelegationNotFound)?;
+		let current_block = frame_system::Pallet::<T>::block_number();
+		let requested_block = match block_number {
+			Some(block_number) => {
+				ensure!(
+					current_block >= block_number,
+					Error::<T>::CannotPredictValidityPastCurrentBlock
+				);
+				block_number
+			},
+			None => current_block,
+		};
 
-			<PublicKeyCountForMsaId<T>>::try_mutate_exists(msa_id, |key_count| {
-				match key_count {
-					Some(1) => *key_count = None,
-					Some(count) => *count = *count - 1u8,
-					None => (),
-				}
+		if info.revoked_at == BlockNumberFor::<T>::zero() {
+			return Ok(info);
+		}
+		ensure!(info.revoked_at >= requested_block, Error::<T>::DelegationRevoked);
 
-				Ok(())
-			})
-		})
+		Ok(info)
 	}
 
-	/// Revoke the grant for permissions from the delegator to the provider
+	/// Check that the delegator has an active delegation to the provider, either directly or
+	/// through a chain of at most [`Config::MaxSubDelegationDepth`] sub-delegations.
+	/// Returns the Provider holding the delegation from the delegator along with the delegation
+	/// `provider_id` effectively holds. The delegation of a chain only grants the schemas
+	/// sub-delegated at every link, and is revoked at the earliest block any link was revoked.
+	/// A revoked direct delegation is final: the provider does not fall back to sub-delegations.
 	///
 	/// # Errors
-	/// * [`Error::DelegationRevoked`]
 	/// * [`Error::DelegationNotFound`]
+	/// * [`Error::DelegationRevoked`]
+	/// * [`Error::CannotPredictValidityPastCurrentBlock`]
 	///
-	pub fn revoke_provider(provider_id: ProviderId, delegator_id: DelegatorId) -> DispatchResult {
-		DelegatorAndProviderToDelegation::<T>::try_mutate_exists(
+	pub fn ensure_valid_delegation_chain(
+		provider_id: ProviderId,
+		delegator_id: DelegatorId,
+		block_number: Option<BlockNumberFor<T>>,
+	) -> Result<
+		(ProviderId, Delegation<SchemaId, BlockNumberFor<T>, T::MaxSchemaGrantsPerDelegation>),
+		DispatchError,
+	> {
+		let mut lookups = T::MaxSubDelegationLookups::get();
+		Self::resolve_delegation(
+			provider_id,
 			delegator_id,
+			block_number,
+			None,
+			T::MaxSubDelegationDepth::get(),
+			&mut lookups,
+		)
+	}
+
+	/// Resolves the delegation from `delegator_id` that `provider_id` holds at `block_number`,
+	/// directly or through sub-delegations at most `depth` links deep, examining at most `lookups`
+	/// links in total. When `schema_id` is set, a delegation granting it is preferred over one
+	/// that does not, so that holding a delegation without the schema does not hide a chain
+	/// granting it.
+	fn resolve_delegation(
+		provider_id: ProviderId,
+		delegator_id: DelegatorId,
+		block_number: Option<BlockNumberFor<T>>,
+		schema_id: Option<SchemaId>,
+		depth: u32,
+		lookups: &mut u32,
+	) -> Result<
+		(ProviderId, Delegation<SchemaId, BlockNumberFor<T>, T::MaxSchemaGrantsPerDelegation>),
+		DispatchError,
+	> {
+		let requested_block = block_number.unwrap_or_else(frame_system::Pallet::<T>::block_number);
+		let direct = Self::ensure_valid_direct_delegation(provider_id, delegator_id, block_number);
+		// a delegation granting the schema, a revoked delegation and an unpredictable block are final
+		let is_final = match &direct {
+			Ok(delegation) => Self::grants_schema(delegation, schema_id, requested_block),
+			Err(error) => *error != Error::<T>::DelegationNotFound.into(),
+		};
+		if is_final {
+			return direct.map(|delegation| (provider_id, delegation))
+		}
+
+		let sub_delegation = Self::resolve_sub_delegation(
 			provider_id,
-			|maybe_info| -> DispatchResult {
-				let mut info = maybe_info.take().ok_or(Error::<T>::DelegationNotFound)?;
+			delegator_id,
+			requested_block,
+			schema_id,
+			depth,
+			lookups,
+		);
+		match (direct, sub_delegation) {
+			(_, Some(chain)) if Self::grants_schema(&chain.1, schema_id, requested_block) =>
+				Ok(chain),
+			(Ok(delegation), _) => Ok((provider_id, delegation)),
+			(Err(error), sub_delegation) => sub_delegation.ok_or(error),
+		}
+	}
 
-				ensure!(
-					info.revoked_at == BlockNumberFor::<T>::default(),
-					Error::<T>::DelegationRevoked
-				);
+	/// Follows the sub-delegations of `provider_id`, at most `depth` links deep, to a delegation
+	/// from `delegator_id` that is active at `block_number`. Each link examined uses up one of
+	/// `lookups`, and the search stops once they run out.
+	fn resolve_sub_delegation(
+		provider_id: ProviderId,
+		delegator_id: DelegatorId,
+		block_number: BlockNumberFor<T>,
+		schema_id: Option<SchemaId>,
+		depth: u32,
+		lookups: &mut u32,
+	) -> Option<(
+		ProviderId,
+		Delegation<SchemaId, BlockNumberFor<T>, T::MaxSchemaGrantsPerDelegation>,
+	)> {
+		if depth == 0 {
+			return None
+		}
+		let retired_at = ProviderRetirements::<T>::get(provider_id);
+		let mut resolved = None;
+		for link in SubDelegations::<T>::get(provider_id) {
+			if *lookups == 0 {
+				break
+			}
+			*lookups -= 1;
+			let revoked_at = Self::earliest_revocation(link.revoked_at, retired_at);
+			if revoked_at != BlockNumberFor::<T>::zero() && revoked_at < block_number {
+				continue
+			}
+			let Ok((root_provider, mut delegation)) = Self::resolve_delegation(
+				link.parent_provider_id,
+				delegator_id,
+				Some(block_number),
+				schema_id,
+				depth - 1,
+				lookups,
+			) else {
+				continue
+			};
+			delegation
+				.schema_permissions
+				.retain(|schema_id, _| link.schema_ids.contains(schema_id));
+			delegation.revoked_at =
+				Self::earliest_revocation(delegation.revoked_at, Some(revoked_at));
+			if Self::grants_schema(&delegation, schema_id, block_number) {
+				return Some((root_provider, delegation))
+			}
+			resolved = resolved.or(Some((root_provider, delegation)));
+		}
+		resolved
+	}
 
-				let current_block = frame_system::Pallet::<T>::block_number();
-				info.revoked_at = current_block;
-				*maybe_info = Some(info);
-				Ok(())
-			},
-		)?;
+	/// Whether `delegation` grants `schema_id` at `block_number`, or `true` without a schema
+	fn grants_schema(
+		delegation: &Delegation<SchemaId, BlockNumberFor<T>, T::MaxSchemaGrantsPerDelegation>,
+		schema_id: Option<SchemaId>,
+		block_number: BlockNumberFor<T>,
+	) -> bool {
+		schema_id.map_or(true, |schema_id| {
+			delegation.schema_permissions.get(&schema_id).map_or(false, |revoked_at| {
+				*revoked_at == BlockNumberFor::<T>::zero() || block_number <= *revoked_at
+			})
+		})
+	}
 
-		Ok(())
+	/// Returns the earlier of two revocation blocks, where zero or `None` means not revoked
+	fn earliest_revocation(
+		revoked_at: BlockNumberFor<T>,
+		other: Option<BlockNumberFor<T>>,
+	) -> BlockNumberFor<T> {
+		match other {
+			Some(other) if other != BlockNumberFor::<T>::zero() =>
+				if revoked_at == BlockNumberFor::<T>::zero() {
+					other
+				} else {
+					revoked_at.min(other)
+				},
+			_ => revoked_at,
+		}
 	}
 
 	/// Retrieves the MSA Id for a given `AccountId`
@@ -1322,38 +4521,141 @@ impl<T: Config> Pallet<T> {
 				Self::get_delegation_of(delegator, provider_id)
 					.ok_or(Error::<T>::DelegationNotFound)?,
 			)],
-			None => DelegatorAndProviderToDelegation::<T>::iter_prefix(delegator).collect(),
+			None => DelegatorAndProviderToDelegation::<T>::iter_prefix(delegator)
+				.map(|(provider_id, delegation)| {
+					(provider_id, Self::apply_provider_retirement(provider_id, delegation))
+				})
+				.collect(),
 		};
 
 		let mut result = vec![];
 		for (provider_id, provider_info) in delegations {
-			let schema_permissions = provider_info.schema_permissions;
 			// checking only if this is called for a specific provider
-			if provider.is_some() && schema_permissions.is_empty() {
+			if provider.is_some() && provider_info.schema_permissions.is_empty() {
 				return Err(Error::<T>::SchemaNotGranted.into());
 			}
 
-			let mut schema_list = Vec::new();
-			for (schema_id, revoked_at) in schema_permissions {
-				if provider_info.revoked_at > BlockNumberFor::<T>::zero() &&
-					(revoked_at > provider_info.revoked_at ||
-						revoked_at == BlockNumberFor::<T>::zero())
-				{
-					schema_list
-						.push(SchemaGrant { schema_id, revoked_at: provider_info.revoked_at });
-				} else {
-					schema_list.push(SchemaGrant { schema_id, revoked_at });
-				}
-			}
-
+			let schema_list = Self::get_schema_grants(provider_info);
 			result.push(DelegationResponse { provider_id, permissions: schema_list });
 		}
 
 		Ok(result)
 	}
 
+	/// Get the MSA that each of `keys` belongs to, in the order of `keys`.
+	/// Keys that belong to no MSA are `None`.
+	pub fn get_msa_ids_for_keys(keys: Vec<T::AccountId>) -> Vec<Option<MessageSourceId>> {
+		keys.iter().map(Self::get_owner_of).collect()
+	}
+
+	/// Get the MSA that each of `keys` belongs to, with its number of public keys, in the order
+	/// of `keys`. Keys that belong to no MSA are `None`.
+	pub fn get_key_owners(keys: Vec<T::AccountId>) -> Vec<Option<KeyOwnerResponse>> {
+		keys.iter()
+			.map(|key| {
+				Self::get_owner_of(key).map(|msa_id| KeyOwnerResponse {
+					msa_id,
+					public_key_count: PublicKeyCountForMsaId::<T>::get(msa_id),
+				})
+			})
+			.collect()
+	}
+
+	/// Get the delegation requests pending for a Delegator that can still be accepted.
+	/// A Delegator has at most [`Config::MaxPendingDelegationRequests`] pending requests.
+	pub fn get_delegation_requests(
+		delegator_id: DelegatorId,
+	) -> Vec<DelegationRequestResponse<SchemaId, BlockNumberFor<T>>> {
+		let current_block = frame_system::Pallet::<T>::block_number();
+		PendingDelegationRequests::<T>::iter_prefix(delegator_id)
+			.take(T::MaxPendingDelegationRequests::get() as usize)
+			.filter(|(_, request)| request.expiration >= current_block)
+			.map(|(provider_id, request)| DelegationRequestResponse {
+				provider_id,
+				schema_ids: request.schema_ids.into_inner(),
+				requested_at: request.requested_at,
+				expiration: request.expiration,
+				delegation_expires_at: request.delegation_expires_at,
+			})
+			.collect()
+	}
+
+	/// Get a page of the Delegators of a provider, with the schema grants of each delegation.
+	/// Each page examines up to `page_size` Delegators of the index, so a page may hold fewer
+	/// Delegators when filtering by schema or status. Keep requesting pages while `has_next` is set.
+	pub fn get_delegators_by_provider(
+		provider_id: ProviderId,
+		request: DelegatorsPaginationRequest,
+	) -> DelegatorsPaginationResponse<SchemaId, BlockNumberFor<T>> {
+		let current_block = frame_system::Pallet::<T>::block_number();
+		let has_status = |revoked_at: BlockNumberFor<T>| {
+			let is_active =
+				revoked_at == BlockNumberFor::<T>::zero() || revoked_at >= current_block;
+			match request.status {
+				Some(DelegationStatus::Active) => is_active,
+				Some(DelegationStatus::Revoked) => !is_active,
+				None => true,
+			}
+		};
+
+		let mut delegators = match request.after {
+			Some(after) => ProviderToDelegators::<T>::iter_key_prefix_from(
+				provider_id,
+				ProviderToDelegators::<T>::hashed_key_for(provider_id, after),
+			),
+			None => ProviderToDelegators::<T>::iter_key_prefix(provider_id),
+		};
+
+		let mut response = DelegatorsPaginationResponse::new();
+		let mut last_delegator_id = None;
+		for delegator_id in delegators.by_ref().take(request.page_size as usize) {
+			last_delegator_id = Some(delegator_id);
+			let Some(delegation) = Self::get_delegation_of(delegator_id, provider_id) else {
+				continue
+			};
+			let revoked_at = delegation.revoked_at;
+			let permissions = Self::get_schema_grants(delegation);
+			let matches = match request.schema_id {
+				Some(schema_id) => permissions
+					.iter()
+					.any(|grant| grant.schema_id == schema_id && has_status(grant.revoked_at)),
+				None => has_status(revoked_at),
+			};
+			if matches {
+				response
+					.content
+					.push(DelegatorResponse { delegator_id, revoked_at, permissions });
+			}
+		}
+
+		if delegators.next().is_some() {
+			response.has_next = true;
+			response.next_after = last_delegator_id;
+		}
+		response
+	}
+
+	/// The schema grants of `delegation`, with each grant revoked no later than the delegation
+	fn get_schema_grants(
+		delegation: Delegation<SchemaId, BlockNumberFor<T>, T::MaxSchemaGrantsPerDelegation>,
+	) -> Vec<SchemaGrant<SchemaId, BlockNumberFor<T>>> {
+		let mut schema_list = Vec::new();
+		for (schema_id, revoked_at) in delegation.schema_permissions {
+			if delegation.revoked_at > BlockNumberFor::<T>::zero() &&
+				(revoked_at > delegation.revoked_at || revoked_at == BlockNumberFor::<T>::zero())
+			{
+				schema_list.push(SchemaGrant { schema_id, revoked_at: delegation.revoked_at });
+			} else {
+				schema_list.push(SchemaGrant { schema_id, revoked_at });
+			}
+		}
+		schema_list
+	}
+
 	/// Adds a signature to the `PayloadSignatureRegistryList`
 	/// Check that mortality_block is within bounds. If so, proceed and add the new entry.
+	/// The signature is recorded in its canonical form, so that other encodings of the same
+	/// secp256k1 signature count as already submitted.
 	/// Raises `SignatureAlreadySubmitted` if the signature exists in the registry.
 	/// Raises `SignatureRegistryLimitExceeded` if the oldest signature of the list has not yet expired.
 	///
@@ -1373,6 +4675,7 @@ impl<T: Config> Pallet<T> {
 		signature: &MultiSignature,
 		signature_expires_at: BlockNumberFor<T>,
 	) -> DispatchResult {
+		let signature = &signatures::normalize_signature(signature);
 		let current_block = frame_system::Pallet::<T>::block_number();
 
 		let max_lifetime = Self::mortality_block_limit(current_block);
@@ -1469,7 +4772,7 @@ impl<T: Config> MsaBenchmarkHelper<T::AccountId> for Pallet<T> {
 		delegator: DelegatorId,
 		schemas: Vec<SchemaId>,
 	) -> DispatchResult {
-		Self::add_provider(provider, delegator, schemas)?;
+		Self::add_provider(provider, delegator, schemas, None)?;
 		Ok(())
 	}
 
@@ -1478,6 +4781,45 @@ impl<T: Config> MsaBenchmarkHelper<T::AccountId> for Pallet<T> {
 		Self::add_key(msa_id, &key, EMPTY_FUNCTION)?;
 		Ok(())
 	}
+
+	/// sets a delegation that the provider holds through a chain of [`Config::MaxSubDelegationDepth`]
+	/// sub-delegations, examined after links to providers without a delegation from the delegator
+	fn set_sub_delegated_relationship(
+		provider: ProviderId,
+		delegator: DelegatorId,
+		schemas: Vec<SchemaId>,
+	) -> DispatchResult {
+		let schema_ids: BoundedVec<SchemaId, T::MaxSchemaGrantsPerDelegation> = schemas
+			.clone()
+			.try_into()
+			.map_err(|_| Error::<T>::ExceedsMaxSchemaGrantsPerDelegation)?;
+		let link = |parent_provider_id| SubDelegation {
+			parent_provider_id,
+			schema_ids: schema_ids.clone(),
+			revoked_at: BlockNumberFor::<T>::zero(),
+		};
+		let depth = T::MaxSubDelegationDepth::get();
+		let unresolved_links = T::MaxSubDelegationLookups::get()
+			.saturating_sub(depth)
+			.min(T::MaxSubDelegationsPerProvider::get().saturating_sub(1));
+
+		// the MSA ids of the benchmark providers are counted down from the maximum id
+		let mut links: Vec<SubDelegation<T>> = (0..unresolved_links)
+			.map(|index| link(ProviderId(MessageSourceId::MAX - depth as u64 - index as u64 - 1)))
+			.collect();
+		let mut sub_provider = provider;
+		for level in 0..depth {
+			let parent_provider = ProviderId(MessageSourceId::MAX - level as u64);
+			links.push(link(parent_provider));
+			let bounded_links = BoundedVec::try_from(sp_std::mem::take(&mut links))
+				.map_err(|_| Error::<T>::ExceedsMaxSubDelegationsPerProvider)?;
+			SubDelegations::<T>::insert(sub_provider, bounded_links);
+			sub_provider = parent_provider;
+		}
+
+		Self::add_provider(sub_provider, delegator, schemas, None)?;
+		Ok(())
+	}
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -1505,6 +4847,14 @@ impl<T: Config> MsaValidator for Pallet<T> {
 	fn ensure_valid_msa_key(key: &T::AccountId) -> Result<MessageSourceId, DispatchError> {
 		Self::ensure_valid_msa_key(key)
 	}
+
+	fn ensure_key_can_manage_msa(key: &T::AccountId) -> DispatchResult {
+		Self::ensure_key_can_manage_msa(key)
+	}
+
+	fn ensure_key_allows_schema(key: &T::AccountId, schema_id: SchemaId) -> DispatchResult {
+		Self::ensure_key_allows_schema(key, schema_id)
+	}
 }
 
 impl<T: Config> ProviderLookup for Pallet<T> {
@@ -1517,6 +4867,7 @@ impl<T: Config> ProviderLookup for Pallet<T> {
 		provider: ProviderId,
 	) -> Option<Delegation<SchemaId, Self::BlockNumber, Self::MaxSchemaGrantsPerDelegation>> {
 		DelegatorAndProviderToDelegation::<T>::get(delegator, provider)
+			.map(|delegation| Pallet::<T>::apply_provider_retirement(provider, delegation))
 	}
 }
 
@@ -1525,7 +4876,8 @@ impl<T: Config> DelegationValidator for Pallet<T> {
 	type MaxSchemaGrantsPerDelegation = T::MaxSchemaGrantsPerDelegation;
 	type SchemaId = SchemaId;
 
-	/// Check that the delegator has an active delegation to the provider.
+	/// Check that the delegator has an active delegation to the provider, either directly or
+	/// through a chain of sub-delegations.
 	/// `block_number`: Provide `None` to know if the delegation is active at the current block.
 	///                 Provide Some(N) to know if the delegation was or will be active at block N.
 	///
@@ -1542,32 +4894,14 @@ impl<T: Config> DelegationValidator for Pallet<T> {
 		Delegation<SchemaId, BlockNumberFor<T>, T::MaxSchemaGrantsPerDelegation>,
 		DispatchError,
 	> {
-		let info = DelegatorAndProviderToDelegation::<T>::get(delegator_id, provider_id)
-			.ok_or(Error::<T>::DelegationNotFound)?;
-		let current_block = frame_system::Pallet::<T>::block_number();
-		let requested_block = match block_number {
-			Some(block_number) => {
-				ensure!(
-					current_block >= block_number,
-					Error::<T>::CannotPredictValidityPastCurrentBlock
-				);
-				block_number
-			},
-			None => current_block,
-		};
-
-		if info.revoked_at == BlockNumberFor::<T>::zero() {
-			return Ok(info);
-		}
-		ensure!(info.revoked_at >= requested_block, Error::<T>::DelegationRevoked);
-
-		Ok(info)
+		Pallet::<T>::ensure_valid_delegation_chain(provider_id, delegator_id, block_number)
+			.map(|(_, info)| info)
 	}
 }
 
 impl<T: Config> TargetValidator for Pallet<T> {
 	fn validate(target: MessageSourceId) -> bool {
-		Self::is_registered_provider(target)
+		Self::is_registered_provider(target) && !Self::is_retiring_provider(target)
 	}
 }
 
@@ -1579,28 +4913,22 @@ impl<T: Config> SchemaGrantValidator<BlockNumberFor<T>> for Pallet<T> {
 	/// * [`Error::DelegationRevoked`]
 	/// * [`Error::SchemaNotGranted`]
 	/// * [`Error::CannotPredictValidityPastCurrentBlock`]
+	/// * [`Error::SchemaPermissionNotGranted`]
 	///
 	fn ensure_valid_schema_grant(
 		provider: ProviderId,
 		delegator: DelegatorId,
 		schema_id: SchemaId,
 		block_number: BlockNumberFor<T>,
+		permission: SchemaGrantPermission,
 	) -> DispatchResult {
-		let provider_info = Self::ensure_valid_delegation(provider, delegator, Some(block_number))?;
-
-		let schema_permission_revoked_at_block_number = provider_info
-			.schema_permissions
-			.get(&schema_id)
-			.ok_or(Error::<T>::SchemaNotGranted)?;
-
-		if *schema_permission_revoked_at_block_number == BlockNumberFor::<T>::zero() {
-			return Ok(());
-		}
+		let root_provider =
+			Self::ensure_schema_granted_by_root(provider, delegator, schema_id, block_number)?;
 
-		ensure!(
-			block_number <= *schema_permission_revoked_at_block_number,
-			Error::<T>::SchemaNotGranted
-		);
+		let permissions = DelegationSchemaPermissions::<T>::get(delegator, root_provider)
+			.and_then(|schema_permissions| schema_permissions.get(&schema_id).copied())
+			.unwrap_or_default();
+		ensure!(permissions.contains(permission), Error::<T>::SchemaPermissionNotGranted);
 
 		Ok(())
 	}
@@ -1623,6 +4951,8 @@ impl<T: Config + Send + Sync> CheckFreeExtrinsicUse<T> {
 	///
 	/// # Errors
 	/// * [`ValidityError::InvalidMsaKey`] - if  `account_id` does not have an MSA
+	/// * [`ValidityError::KeyScopeRestricted`] - if `account_id` is scoped without the power to manage its MSA
+	/// * [`ValidityError::ThresholdApprovalRequired`] - if the delegator MSA has a threshold policy
 	/// * [`ValidityError::InvalidDelegation`] - if the delegation with `delegator_msa_id` is invalid
 	///
 	pub fn validate_delegation_by_delegator(
@@ -1633,9 +4963,14 @@ impl<T: Config + Send + Sync> CheckFreeExtrinsicUse<T> {
 		let delegator_msa_id: DelegatorId = Pallet::<T>::ensure_valid_msa_key(account_id)
 			.map_err(|_| InvalidTransaction::Custom(ValidityError::InvalidMsaKey as u8))?
 			.into();
+		Pallet::<T>::ensure_key_can_manage_msa(account_id)
+			.map_err(|_| InvalidTransaction::Custom(ValidityError::KeyScopeRestricted as u8))?;
+		Pallet::<T>::ensure_no_threshold_policy(delegator_msa_id.into()).map_err(|_| {
+			InvalidTransaction::Custom(ValidityError::ThresholdApprovalRequired as u8)
+		})?;
 		let provider_msa_id = ProviderId(*provider_msa_id);
 
-		Pallet::<T>::ensure_valid_delegation(provider_msa_id, delegator_msa_id, None)
+		Pallet::<T>::ensure_valid_direct_delegation(provider_msa_id, delegator_msa_id, None)
 			.map_err(|_| InvalidTransaction::Custom(ValidityError::InvalidDelegation as u8))?;
 		ValidTransaction::with_tag_prefix(TAG_PREFIX).and_provides(account_id).build()
 	}
@@ -1648,6 +4983,8 @@ impl<T: Config + Send + Sync> CheckFreeExtrinsicUse<T> {
 	///
 	/// # Errors
 	/// * [`ValidityError::InvalidMsaKey`] - if  `account_id` does not have an MSA
+	/// * [`ValidityError::KeyScopeRestricted`] - if `account_id` is scoped without the power to manage its MSA
+	/// * [`ValidityError::ThresholdApprovalRequired`] - if the provider MSA has a threshold policy
 	/// * [`ValidityError::InvalidDelegation`] - if the delegation with `delegator_msa_id` is invalid
 	///
 	pub fn validate_delegation_by_provider(
@@ -1659,10 +4996,15 @@ impl<T: Config + Send + Sync> CheckFreeExtrinsicUse<T> {
 		let provider_msa_id: ProviderId = Pallet::<T>::ensure_valid_msa_key(account_id)
 			.map_err(|_| InvalidTransaction::Custom(ValidityError::InvalidMsaKey as u8))?
 			.into();
+		Pallet::<T>::ensure_key_can_manage_msa(account_id)
+			.map_err(|_| InvalidTransaction::Custom(ValidityError::KeyScopeRestricted as u8))?;
+		Pallet::<T>::ensure_no_threshold_policy(provider_msa_id.into()).map_err(|_| {
+			InvalidTransaction::Custom(ValidityError::ThresholdApprovalRequired as u8)
+		})?;
 		let delegator_msa_id = DelegatorId(*delegator_msa_id);
 
 		// Verify the delegation exists and is active
-		Pallet::<T>::ensure_valid_delegation(provider_msa_id, delegator_msa_id, None)
+		Pallet::<T>::ensure_valid_direct_delegation(provider_msa_id, delegator_msa_id, None)
 			.map_err(|_| InvalidTransaction::Custom(ValidityError::InvalidDelegation as u8))?;
 		ValidTransaction::with_tag_prefix(TAG_PREFIX).and_provides(account_id).build()
 	}
@@ -1679,6 +5021,8 @@ impl<T: Config + Send + Sync> CheckFreeExtrinsicUse<T> {
 	/// * [`ValidityError::InvalidMsaKey`] - if  `account_id` does not have an MSA or if
 	/// 'public_key_to_delete' does not have an MSA.
 	/// * [`ValidityError::NotKeyOwner`] - if the `signing_public_key` and `public_key_to_delete` do not belong to the same MSA ID.
+	/// * [`ValidityError::KeyScopeRestricted`] - if `signing_public_key` is scoped without the power to manage its MSA.
+	/// * [`ValidityError::ThresholdApprovalRequired`] - if the MSA has a threshold policy.
 	pub fn validate_key_delete(
 		signing_public_key: &T::AccountId,
 		public_key_to_delete: &T::AccountId,
@@ -1703,29 +5047,40 @@ impl<T: Config + Send + Sync> CheckFreeExtrinsicUse<T> {
 			InvalidTransaction::Custom(ValidityError::NotKeyOwner as u8)
 		);
 
+		Pallet::<T>::ensure_key_can_manage_msa(signing_public_key)
+			.map_err(|_| InvalidTransaction::Custom(ValidityError::KeyScopeRestricted as u8))?;
+		Pallet::<T>::ensure_no_threshold_policy(maybe_owner_msa_id).map_err(|_| {
+			InvalidTransaction::Custom(ValidityError::ThresholdApprovalRequired as u8)
+		})?;
+
 		return ValidTransaction::with_tag_prefix(TAG_PREFIX)
 			.and_provides(signing_public_key)
 			.build();
 	}
 
 	/// Validates that a MSA being retired exists, does not belong to a registered provider,
-	/// that `account_id` is the only access key associated with the MSA,
-	/// and that there are no delegations to providers.
+	/// and that `account_id` is the only access key associated with the MSA.
 	/// Returns a `ValidTransaction` or wrapped [`ValidityError]
 	/// # Arguments:
 	/// * account_id: the account id associated with the MSA to retire
 	///
 	/// # Errors
 	/// * [`ValidityError::InvalidMsaKey`]
+	/// * [`ValidityError::KeyScopeRestricted`]
+	/// * [`ValidityError::ThresholdApprovalRequired`]
 	/// * [`ValidityError::InvalidRegisteredProviderCannotBeRetired`]
 	/// * [`ValidityError::InvalidMoreThanOneKeyExists`]
-	/// * [`ValidityError::InvalidNonZeroProviderDelegations`]
 	///
 	pub fn ensure_msa_can_retire(account_id: &T::AccountId) -> TransactionValidity {
 		const TAG_PREFIX: &str = "MSARetirement";
 		let msa_id = Pallet::<T>::ensure_valid_msa_key(account_id)
 			.map_err(|_| InvalidTransaction::Custom(ValidityError::InvalidMsaKey as u8))?
 			.into();
+		Pallet::<T>::ensure_key_can_manage_msa(account_id)
+			.map_err(|_| InvalidTransaction::Custom(ValidityError::KeyScopeRestricted as u8))?;
+		Pallet::<T>::ensure_no_threshold_policy(msa_id).map_err(|_| {
+			InvalidTransaction::Custom(ValidityError::ThresholdApprovalRequired as u8)
+		})?;
 
 		ensure!(
 			!Pallet::<T>::is_registered_provider(msa_id),
@@ -1734,31 +5089,12 @@ impl<T: Config + Send + Sync> CheckFreeExtrinsicUse<T> {
 			)
 		);
 
-		let msa_handle = T::HandleProvider::get_handle_for_msa(msa_id);
-		ensure!(
-			msa_handle.is_none(),
-			InvalidTransaction::Custom(ValidityError::HandleNotRetired as u8)
-		);
-
 		let key_count = PublicKeyCountForMsaId::<T>::get(msa_id);
 		ensure!(
 			key_count == 1,
 			InvalidTransaction::Custom(ValidityError::InvalidMoreThanOneKeyExists as u8)
 		);
 
-		let delegator_id = DelegatorId(msa_id);
-		let has_active_delegations: bool = DelegatorAndProviderToDelegation::<T>::iter_key_prefix(
-			delegator_id,
-		)
-		.any(|provider_id| {
-			Pallet::<T>::ensure_valid_delegation(provider_id, delegator_id, None).is_ok()
-		});
-
-		ensure!(
-			!has_active_delegations,
-			InvalidTransaction::Custom(ValidityError::InvalidNonZeroProviderDelegations as u8)
-		);
-
 		return ValidTransaction::with_tag_prefix(TAG_PREFIX).and_provides(account_id).build();
 	}
 }
@@ -1777,10 +5113,14 @@ pub enum ValidityError {
 	InvalidSelfRemoval,
 	/// NotKeyOwner
 	NotKeyOwner,
-	/// InvalidNonZeroProviderDelegations
+	/// InvalidNonZeroProviderDelegations. No longer returned, as retirement purges delegations.
 	InvalidNonZeroProviderDelegations,
-	/// HandleNotRetired
+	/// HandleNotRetired. No longer returned, as retirement purges the handle.
 	HandleNotRetired,
+	/// The scope of the key does not allow it to manage its MSA
+	KeyScopeRestricted,
+	/// The MSA has a threshold policy, so the action must be approved by a threshold of its keys
+	ThresholdApprovalRequired,
 }
 
 impl<T: Config + Send + Sync> CheckFreeExtrinsicUse<T> {
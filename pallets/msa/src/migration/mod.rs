@@ -0,0 +1,2 @@
+/// index the Delegators of each Provider
+pub mod v2;
@@ -0,0 +1,195 @@
+use crate::{
+	weights::WeightInfo, Config, DelegatorAndProviderToDelegation, Pallet, ProviderToDelegators,
+	ProviderToDelegatorsMigrationCursor,
+};
+
+use frame_support::{
+	pallet_prelude::{GetStorageVersion, Weight},
+	traits::{Get, OnRuntimeUpgrade, StorageVersion},
+	BoundedVec,
+};
+use sp_core::ConstU32;
+use sp_runtime::SaturatedConversion;
+
+const LOG_TARGET: &str = "runtime::msa";
+
+#[cfg(feature = "try-runtime")]
+use parity_scale_codec::{Decode, Encode};
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// The OnRuntimeUpgrade implementation for this storage migration.
+/// Starts building the [`ProviderToDelegators`] index from the existing delegations.
+/// The delegations are indexed over many blocks in `on_idle`, see [`index_delegations`].
+pub struct MigrateToV2<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let on_chain_version = Pallet::<T>::on_chain_storage_version(); // 1r
+
+		if on_chain_version >= 2 {
+			log::info!(target: LOG_TARGET, "Old MSA Provider to Delegators migration attempted to run. Please remove");
+			return T::DbWeight::get().reads(1)
+		}
+
+		log::info!(target: LOG_TARGET, "🔄 MSA Provider to Delegators migration started");
+		ProviderToDelegatorsMigrationCursor::<T>::put(BoundedVec::default()); // 1w
+		StorageVersion::new(2).put::<Pallet<T>>(); // 1w
+
+		T::DbWeight::get().reads_writes(1, 2)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+		log::info!(target: LOG_TARGET, "Running pre_upgrade...");
+		let on_chain_version = Pallet::<T>::on_chain_storage_version();
+		Ok(on_chain_version.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		let pre_upgrade_version: StorageVersion = Decode::decode(&mut state.as_slice())
+			.expect("the state parameter should be something that was generated by pre_upgrade");
+		let on_chain_version = Pallet::<T>::on_chain_storage_version();
+		assert_eq!(on_chain_version, crate::pallet::STORAGE_VERSION);
+		if pre_upgrade_version < 2 {
+			assert!(ProviderToDelegatorsMigrationCursor::<T>::exists());
+		}
+
+		log::info!(target: LOG_TARGET, "✅ migration post_upgrade checks passed");
+		Ok(())
+	}
+}
+
+/// Indexes as many delegations as fit in `remaining_weight` while the migration is in progress.
+/// Returns the weight used.
+pub fn index_delegations<T: Config>(remaining_weight: Weight) -> Weight {
+	let base_weight = T::WeightInfo::index_provider_delegators(0);
+	let item_weight = T::WeightInfo::index_provider_delegators(1).saturating_sub(base_weight);
+	if remaining_weight.any_lt(base_weight.saturating_add(item_weight)) {
+		return Weight::zero()
+	}
+
+	let Some(cursor) = ProviderToDelegatorsMigrationCursor::<T>::get() else {
+		return T::DbWeight::get().reads(1)
+	};
+
+	let available_weight = remaining_weight.saturating_sub(base_weight);
+	let limit = [
+		available_weight.ref_time().checked_div(item_weight.ref_time()),
+		available_weight.proof_size().checked_div(item_weight.proof_size()),
+	]
+	.into_iter()
+	.flatten()
+	.min()
+	.unwrap_or(u64::MAX);
+	let indexed = index_delegations_from::<T>(cursor, limit.saturated_into());
+
+	T::WeightInfo::index_provider_delegators(indexed)
+}
+
+/// Indexes up to `limit` delegations after the raw key `cursor`, and moves the cursor past them.
+/// Completes the migration once every delegation is indexed.
+/// Returns the number of delegations indexed.
+pub fn index_delegations_from<T: Config>(cursor: BoundedVec<u8, ConstU32<128>>, limit: u32) -> u32 {
+	let mut keys = if cursor.is_empty() {
+		DelegatorAndProviderToDelegation::<T>::iter_keys()
+	} else {
+		DelegatorAndProviderToDelegation::<T>::iter_keys_from(cursor.into_inner())
+	};
+
+	let mut indexed = 0u32;
+	while indexed < limit {
+		let Some((delegator_id, provider_id)) = keys.next() else {
+			ProviderToDelegatorsMigrationCursor::<T>::kill();
+			log::info!(target: LOG_TARGET, "🔄 MSA Provider to Delegators migration finished");
+			return indexed
+		};
+		ProviderToDelegators::<T>::insert(provider_id, delegator_id, ());
+		indexed = indexed.saturating_add(1);
+	}
+
+	ProviderToDelegatorsMigrationCursor::<T>::put(BoundedVec::truncate_from(
+		keys.last_raw_key().to_vec(),
+	));
+	indexed
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::tests::mock::{Test as T, *};
+	use common_primitives::msa::{DelegatorId, ProviderId};
+	use frame_support::assert_ok;
+
+	fn delegators_of(provider_id: ProviderId) -> Vec<DelegatorId> {
+		let mut delegators: Vec<DelegatorId> =
+			ProviderToDelegators::<T>::iter_key_prefix(provider_id).collect();
+		delegators.sort_by_key(|delegator_id| delegator_id.0);
+		delegators
+	}
+
+	#[test]
+	fn migration_indexes_existing_delegations() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(1).put::<Pallet<T>>();
+			assert_ok!(Msa::add_provider(ProviderId(1), DelegatorId(2), vec![], None));
+			assert_ok!(Msa::add_provider(ProviderId(1), DelegatorId(3), vec![], None));
+			// Delegations made before the index existed
+			let _ = ProviderToDelegators::<T>::clear(u32::MAX, None);
+
+			MigrateToV2::<T>::on_runtime_upgrade();
+
+			let on_chain_version = Pallet::<T>::on_chain_storage_version();
+			assert_eq!(on_chain_version, crate::pallet::STORAGE_VERSION);
+			assert!(ProviderToDelegatorsMigrationCursor::<T>::exists());
+
+			index_delegations::<T>(Weight::MAX);
+
+			assert!(!ProviderToDelegatorsMigrationCursor::<T>::exists());
+			assert_eq!(delegators_of(ProviderId(1)), vec![DelegatorId(2), DelegatorId(3)]);
+		})
+	}
+
+	#[test]
+	fn migration_indexes_delegations_over_several_steps() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(1).put::<Pallet<T>>();
+			for delegator in 2..5 {
+				assert_ok!(Msa::add_provider(ProviderId(1), DelegatorId(delegator), vec![], None));
+			}
+			let _ = ProviderToDelegators::<T>::clear(u32::MAX, None);
+			MigrateToV2::<T>::on_runtime_upgrade();
+
+			for _ in 0..3 {
+				let cursor = ProviderToDelegatorsMigrationCursor::<T>::get().unwrap();
+				assert_eq!(index_delegations_from::<T>(cursor, 1), 1);
+				assert!(ProviderToDelegatorsMigrationCursor::<T>::exists());
+			}
+			assert_eq!(delegators_of(ProviderId(1)).len(), 3);
+
+			let cursor = ProviderToDelegatorsMigrationCursor::<T>::get().unwrap();
+			assert_eq!(index_delegations_from::<T>(cursor, 1), 0);
+			assert!(!ProviderToDelegatorsMigrationCursor::<T>::exists());
+			assert_eq!(
+				delegators_of(ProviderId(1)),
+				vec![DelegatorId(2), DelegatorId(3), DelegatorId(4)]
+			);
+		})
+	}
+
+	#[test]
+	fn migration_does_not_run_twice() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(2).put::<Pallet<T>>();
+			assert_ok!(Msa::add_provider(ProviderId(1), DelegatorId(2), vec![], None));
+			let _ = ProviderToDelegators::<T>::clear(u32::MAX, None);
+
+			MigrateToV2::<T>::on_runtime_upgrade();
+			index_delegations::<T>(Weight::MAX);
+
+			assert!(!ProviderToDelegatorsMigrationCursor::<T>::exists());
+			assert_eq!(ProviderToDelegators::<T>::iter_keys().count(), 0);
+		})
+	}
+}
@@ -1,9 +1,14 @@
 use crate::{pallet::OffchainIndexEventCount, Config, Event, Pallet, PublicKeyToMsaId};
-pub use common_primitives::msa::MessageSourceId;
+use common_primitives::msa::{MsaHistoryEvent, MsaHistoryRecord, ProviderId};
 /// Offchain Storage for MSA
 use common_primitives::offchain::{
 	self as offchain_common, get_msa_account_lock_name, get_msa_account_storage_key_name,
-	LockStatus, MSA_ACCOUNT_LOCK_TIMEOUT_EXPIRATION_MS,
+	get_msa_history_chunk_storage_key_name, get_msa_history_storage_key_name, LockStatus,
+	MSA_ACCOUNT_LOCK_TIMEOUT_EXPIRATION_MS, MSA_HISTORY_CHUNK_SIZE,
+};
+pub use common_primitives::{
+	msa::MessageSourceId,
+	offchain::{LAST_PROCESSED_BLOCK_STORAGE_NAME, MSA_INITIAL_INDEXED_STORAGE_NAME},
 };
 use frame_support::RuntimeDebugNoBound;
 use frame_system::pallet_prelude::BlockNumberFor;
@@ -38,15 +43,9 @@ const MSA_INITIAL_LOCK_BLOCK_EXPIRATION_BLOCKS: u32 = 40;
 /// Lock name for initial data index for msa pallet
 const MSA_INITIAL_LOCK_NAME: &[u8; 28] = b"Msa::ofw::initial-index-lock";
 
-/// storage name for initial data import storage
-pub const MSA_INITIAL_INDEXED_STORAGE_NAME: &[u8; 25] = b"Msa::ofw::initial-indexed";
-
 /// Lock name for last processed block number events
 const LAST_PROCESSED_BLOCK_LOCK_NAME: &[u8; 35] = b"Msa::ofw::last-processed-block-lock";
 
-/// lst processed block storage name
-pub const LAST_PROCESSED_BLOCK_STORAGE_NAME: &[u8; 30] = b"Msa::ofw::last-processed-block";
-
 /// Lock expiration timeout in in milli-seconds for last processed block
 const LAST_PROCESSED_BLOCK_LOCK_TIMEOUT_EXPIRATION_MS: u64 = 5000;
 
@@ -128,6 +127,7 @@ fn offchain_index_initial_state<T: Config>(block_number: BlockNumberFor<T>) -> L
 				process_offchain_events::<T>(
 					msa_id,
 					vec![IndexedEvent::IndexedPublicKeyAdded { key: account_id, msa_id }],
+					None,
 				);
 
 				// extend the initial index lock
@@ -233,6 +233,36 @@ enum IndexedEvent<T: Config> {
 		/// The key no longer approved for the associated MSA
 		key: T::AccountId,
 	},
+	/// An MSA control key was replaced by a new key
+	IndexedPublicKeyRotated {
+		/// The MSA for the Event
+		msa_id: MessageSourceId,
+		/// The key removed from the MSA
+		old_key: T::AccountId,
+		/// The key added to the MSA
+		new_key: T::AccountId,
+	},
+	/// A delegation was granted to a provider
+	IndexedDelegationGranted {
+		/// The delegator MSA for the Event
+		msa_id: MessageSourceId,
+		/// The provider of the delegation
+		provider_id: ProviderId,
+	},
+	/// The schema grants of a delegation changed
+	IndexedDelegationUpdated {
+		/// The delegator MSA for the Event
+		msa_id: MessageSourceId,
+		/// The provider of the delegation
+		provider_id: ProviderId,
+	},
+	/// A delegation was revoked
+	IndexedDelegationRevoked {
+		/// The delegator MSA for the Event
+		msa_id: MessageSourceId,
+		/// The provider of the delegation
+		provider_id: ProviderId,
+	},
 }
 
 impl<T: Config> IndexedEvent<T> {
@@ -245,9 +275,50 @@ impl<T: Config> IndexedEvent<T> {
 				Some(Self::IndexedPublicKeyAdded { msa_id: *msa_id, key: key.clone() }),
 			Event::PublicKeyDeleted { key } =>
 				Some(Self::IndexedPublicKeyDeleted { msa_id: event_msa_id, key: key.clone() }),
+			Event::PublicKeyRotated { msa_id, old_key, new_key } =>
+				Some(Self::IndexedPublicKeyRotated {
+					msa_id: *msa_id,
+					old_key: old_key.clone(),
+					new_key: new_key.clone(),
+				}),
+			Event::DelegationGranted { delegator_id, provider_id } =>
+				Some(Self::IndexedDelegationGranted {
+					msa_id: (*delegator_id).into(),
+					provider_id: *provider_id,
+				}),
+			Event::DelegationUpdated { delegator_id, provider_id } =>
+				Some(Self::IndexedDelegationUpdated {
+					msa_id: (*delegator_id).into(),
+					provider_id: *provider_id,
+				}),
+			Event::DelegationRevoked { delegator_id, provider_id } =>
+				Some(Self::IndexedDelegationRevoked {
+					msa_id: (*delegator_id).into(),
+					provider_id: *provider_id,
+				}),
 			_ => None,
 		}
 	}
+
+	/// maps an indexed event to the changes it records in the MSA history
+	fn history_events(&self) -> Vec<MsaHistoryEvent<T::AccountId>> {
+		match self {
+			Self::IndexedMsaCreated { key, .. } | Self::IndexedPublicKeyAdded { key, .. } =>
+				vec![MsaHistoryEvent::PublicKeyAdded { key: key.clone() }],
+			Self::IndexedPublicKeyDeleted { key, .. } =>
+				vec![MsaHistoryEvent::PublicKeyDeleted { key: key.clone() }],
+			Self::IndexedPublicKeyRotated { old_key, new_key, .. } => vec![
+				MsaHistoryEvent::PublicKeyDeleted { key: old_key.clone() },
+				MsaHistoryEvent::PublicKeyAdded { key: new_key.clone() },
+			],
+			Self::IndexedDelegationGranted { provider_id, .. } =>
+				vec![MsaHistoryEvent::DelegationGranted { provider_id: *provider_id }],
+			Self::IndexedDelegationUpdated { provider_id, .. } =>
+				vec![MsaHistoryEvent::DelegationUpdated { provider_id: *provider_id }],
+			Self::IndexedDelegationRevoked { provider_id, .. } =>
+				vec![MsaHistoryEvent::DelegationRevoked { provider_id: *provider_id }],
+		}
+	}
 }
 
 /// Initializes the last_process_block value in offchain DB
@@ -326,7 +397,14 @@ fn reverse_map_msa_keys<T: Config>(block_number: BlockNumberFor<T>) -> bool {
 				let events = events_by_msa_id.entry(msa_id).or_default();
 				events.push(event);
 			},
-			IndexedEvent::IndexedPublicKeyDeleted { msa_id, .. } => {
+			IndexedEvent::IndexedPublicKeyDeleted { msa_id, .. } |
+			IndexedEvent::IndexedPublicKeyRotated { msa_id, .. } => {
+				let events = events_by_msa_id.entry(msa_id).or_default();
+				events.push(event);
+			},
+			IndexedEvent::IndexedDelegationGranted { msa_id, .. } |
+			IndexedEvent::IndexedDelegationUpdated { msa_id, .. } |
+			IndexedEvent::IndexedDelegationRevoked { msa_id, .. } => {
 				let events = events_by_msa_id.entry(msa_id).or_default();
 				events.push(event);
 			},
@@ -334,9 +412,10 @@ fn reverse_map_msa_keys<T: Config>(block_number: BlockNumberFor<T>) -> bool {
 	}
 
 	// process and save to offchain db
+	let history_block_number: u32 = block_number.try_into().unwrap_or_default();
 	for (msa_id, events) in events_by_msa_id {
 		if !events.is_empty() {
-			process_offchain_events(msa_id, events);
+			process_offchain_events(msa_id, events, Some(history_block_number));
 		}
 	}
 
@@ -347,7 +426,13 @@ fn reverse_map_msa_keys<T: Config>(block_number: BlockNumberFor<T>) -> bool {
 	events_exists
 }
 
-fn process_offchain_events<T: Config>(msa_id: MessageSourceId, events: Vec<IndexedEvent<T>>) {
+/// applies the events of an MSA to its offchain keys, and appends them to its offchain history
+/// when `history_block_number` is set
+fn process_offchain_events<T: Config>(
+	msa_id: MessageSourceId,
+	events: Vec<IndexedEvent<T>>,
+	history_block_number: Option<u32>,
+) {
 	// Lock will specifically prevent multiple offchain workers from
 	// processing the same msa events at the same time
 	let msa_lock_name = get_msa_account_lock_name(msa_id);
@@ -362,6 +447,10 @@ fn process_offchain_events<T: Config>(msa_id: MessageSourceId, events: Vec<Index
 	let mut msa_keys =
 		msa_storage.get::<Vec<T::AccountId>>().unwrap_or(None).unwrap_or(Vec::default());
 
+	if let Some(block_number) = history_block_number {
+		append_msa_history::<T>(msa_id, block_number, &events);
+	}
+
 	for event in events {
 		match &event {
 			IndexedEvent::IndexedPublicKeyAdded { key, .. } |
@@ -377,10 +466,87 @@ fn process_offchain_events<T: Config>(msa_id: MessageSourceId, events: Vec<Index
 				} else {
 					log::warn!("{:?} already removed!", key);
 				},
+			IndexedEvent::IndexedPublicKeyRotated { old_key, new_key, .. } => {
+				if msa_keys.contains(old_key) {
+					msa_keys.retain(|k| k != old_key);
+				} else {
+					log::warn!("{:?} already removed!", old_key);
+				}
+				if !msa_keys.contains(new_key) {
+					msa_keys.push(new_key.clone());
+				} else {
+					log::warn!("{:?} already added!", new_key);
+				}
+			},
+			IndexedEvent::IndexedDelegationGranted { .. } |
+			IndexedEvent::IndexedDelegationUpdated { .. } |
+			IndexedEvent::IndexedDelegationRevoked { .. } => {},
 		}
 	}
 	msa_storage.set(&msa_keys);
 }
+
+/// appends the changes recorded by `events` to the offchain history of an MSA
+/// the history is stored in chunks of at most `MSA_HISTORY_CHUNK_SIZE` records, so only the
+/// last chunk is read and rewritten
+/// the caller must hold the lock of the MSA
+fn append_msa_history<T: Config>(
+	msa_id: MessageSourceId,
+	block_number: u32,
+	events: &[IndexedEvent<T>],
+) {
+	let records: Vec<MsaHistoryRecord<T::AccountId>> = events
+		.iter()
+		.flat_map(|event| event.history_events())
+		.map(|event| MsaHistoryRecord { block_number, event })
+		.collect();
+	if records.is_empty() {
+		return
+	}
+
+	let history_storage_name = get_msa_history_storage_key_name(msa_id);
+	let history_storage = StorageValueRef::persistent(&history_storage_name);
+	let mut chunk_count = history_storage.get::<u32>().unwrap_or(None).unwrap_or_default();
+
+	let mut chunk = match chunk_count.checked_sub(1) {
+		Some(last_chunk_index) => read_msa_history_chunk::<T>(msa_id, last_chunk_index),
+		None => {
+			chunk_count = 1;
+			Vec::new()
+		},
+	};
+	for record in records {
+		if chunk.len() as u32 >= MSA_HISTORY_CHUNK_SIZE {
+			write_msa_history_chunk::<T>(msa_id, chunk_count.saturating_sub(1), &chunk);
+			chunk = Vec::new();
+			chunk_count = chunk_count.saturating_add(1);
+		}
+		chunk.push(record);
+	}
+	write_msa_history_chunk::<T>(msa_id, chunk_count.saturating_sub(1), &chunk);
+	history_storage.set(&chunk_count);
+}
+
+fn read_msa_history_chunk<T: Config>(
+	msa_id: MessageSourceId,
+	chunk_index: u32,
+) -> Vec<MsaHistoryRecord<T::AccountId>> {
+	let chunk_storage_name = get_msa_history_chunk_storage_key_name(msa_id, chunk_index);
+	StorageValueRef::persistent(&chunk_storage_name)
+		.get::<Vec<MsaHistoryRecord<T::AccountId>>>()
+		.unwrap_or(None)
+		.unwrap_or_default()
+}
+
+fn write_msa_history_chunk<T: Config>(
+	msa_id: MessageSourceId,
+	chunk_index: u32,
+	chunk: &[MsaHistoryRecord<T::AccountId>],
+) {
+	let chunk_storage_name = get_msa_history_chunk_storage_key_name(msa_id, chunk_index);
+	StorageValueRef::persistent(&chunk_storage_name).set(&chunk);
+}
+
 /// Response type of rpc to get finalized block
 #[derive(Serialize, Deserialize, Encode, Decode, Default, Debug)]
 pub struct FinalizedBlockResponse {
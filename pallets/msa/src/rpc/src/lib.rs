@@ -10,11 +10,17 @@
 
 use common_helpers::rpc::map_rpc_result;
 use common_primitives::{
+	messages::{BlockPaginationRequest, BlockPaginationResponse},
 	msa::{
-		DelegationResponse, DelegatorId, KeyInfoResponse, MessageSourceId, ProviderId, SchemaGrant,
+		DelegationRequestResponse, DelegationResponse, DelegatorId, DelegatorsPaginationRequest,
+		DelegatorsPaginationResponse, KeyInfoResponse, KeyOwnerResponse, MessageSourceId,
+		MsaHistoryRecord, OffchainIndexReport, ProviderId, SchemaGrant, MAX_KEYS_PER_MSA_LOOKUP,
 	},
 	node::BlockNumber,
-	offchain::get_msa_account_storage_key_name,
+	offchain::{
+		get_msa_account_storage_key_name, get_msa_history_chunk_storage_key_name,
+		get_msa_history_storage_key_name,
+	},
 	schema::SchemaId,
 };
 use jsonrpsee::{
@@ -27,11 +33,14 @@ use pallet_msa_runtime_api::MsaRuntimeApi;
 use parity_scale_codec::{Codec, Decode};
 use parking_lot::RwLock;
 use rayon::prelude::*;
+use sc_client_api::{Backend, StorageProvider};
 use sp_api::ProvideRuntimeApi;
 use sp_blockchain::HeaderBackend;
 use sp_core::Bytes;
-use sp_runtime::traits::Block as BlockT;
-use std::sync::Arc;
+use sp_runtime::traits::{Block as BlockT, NumberFor};
+use std::{collections::BTreeMap, sync::Arc};
+
+pub mod offchain_index;
 
 #[cfg(test)]
 mod tests;
@@ -72,6 +81,87 @@ pub trait MsaApi<BlockHash, AccountId> {
 		&self,
 		msa_id: MessageSourceId,
 	) -> RpcResult<Option<KeyInfoResponse<AccountId>>>;
+
+	/// Retrieve a page of the Delegators of a provider, optionally filtered by schema and status
+	#[method(name = "msa_getDelegatorsByProvider")]
+	fn get_delegators_by_provider(
+		&self,
+		provider_msa_id: ProviderId,
+		request: DelegatorsPaginationRequest,
+	) -> RpcResult<DelegatorsPaginationResponse<SchemaId, BlockNumber>>;
+
+	/// Retrieve the delegation requests pending for a Delegator that can still be accepted
+	#[method(name = "msa_getDelegationRequests")]
+	fn get_delegation_requests(
+		&self,
+		delegator_msa_id: DelegatorId,
+	) -> RpcResult<Vec<DelegationRequestResponse<SchemaId, BlockNumber>>>;
+
+	/// Retrieve the MSA id each key belongs to, in the order of the keys.
+	/// Keys that belong to no MSA are `null`.
+	/// At most [`MAX_KEYS_PER_MSA_LOOKUP`] keys can be looked up at once.
+	#[method(name = "msa_getMsaIdsForKeys")]
+	fn get_msa_ids_for_keys(
+		&self,
+		keys: Vec<AccountId>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<Option<MessageSourceId>>>;
+
+	/// Retrieve the MSA id and number of public keys of the MSA each key belongs to,
+	/// in the order of the keys. Keys that belong to no MSA are `null`.
+	/// At most [`MAX_KEYS_PER_MSA_LOOKUP`] keys can be looked up at once.
+	#[method(name = "msa_getKeyOwners")]
+	fn get_key_owners(
+		&self,
+		keys: Vec<AccountId>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<Option<KeyOwnerResponse>>>;
+
+	/// Retrieve the MSA id a merged MSA was merged into, following later merges of that MSA.
+	/// Returns `null` if the MSA was not merged.
+	#[method(name = "msa_getMsaRedirect")]
+	fn get_msa_redirect(
+		&self,
+		msa_id: MessageSourceId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<MessageSourceId>>;
+
+	/// Retrieve a page of the key and delegation history of an MSA within a block range
+	#[method(name = "msa_getHistory")]
+	fn get_msa_redirect(
+		&self,
+		msa_id: MessageSourceId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<MessageSourceId>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let runtime_api_result = api.get_msa_redirect(at, msa_id);
+		map_rpc_result(runtime_api_result)
+	}
+
+	fn get_history(
+		&self,
+		msa_id: MessageSourceId,
+		pagination: BlockPaginationRequest,
+	) -> RpcResult<BlockPaginationResponse<MsaHistoryRecord<AccountId>>>;
+}
+
+/// Recurrency MSA RPC API to verify and rebuild the offchain index of MSA keys.
+/// Only served when unsafe RPC methods are allowed.
+#[rpc(client, server)]
+pub trait MsaOffchainIndexApi<AccountId> {
+	/// Compare the offchain index of MSA keys with the chain state at a block,
+	/// by default the last block indexed by the offchain worker
+	#[method(name = "msa_checkOffchainIndex")]
+	async fn check_offchain_index(
+		&self,
+		block_number: Option<BlockNumber>,
+	) -> RpcResult<OffchainIndexReport<AccountId>>;
+
+	/// Rewrite the offchain index of the MSAs whose keys differ from the chain state at the last
+	/// block indexed by the offchain worker
+	#[method(name = "msa_rebuildOffchainIndex")]
+	async fn rebuild_offchain_index(&self) -> RpcResult<OffchainIndexReport<AccountId>>;
 }
 
 /// The client handler for the API used by Recurrency Service RPC with `jsonrpsee`
@@ -100,6 +190,14 @@ pub enum MsaOffchainRpcError {
 	ErrorDecodingData,
 	/// Offchain indexing is not enabled
 	OffchainIndexingNotEnabled,
+	/// The offchain worker has not indexed any block yet
+	OffchainIndexNotInitialized,
+	/// The requested block is not known to the node
+	BlockNotFound,
+	/// Error reading the chain state
+	ErrorReadingState,
+	/// The blocking task that scans the chain state did not complete
+	ErrorRunningTask,
 }
 
 impl From<MsaOffchainRpcError> for ErrorObjectOwned {
@@ -111,6 +209,31 @@ impl From<MsaOffchainRpcError> for ErrorObjectOwned {
 			MsaOffchainRpcError::ErrorDecodingData => ErrorObject::owned(2, msg, None::<()>),
 			MsaOffchainRpcError::OffchainIndexingNotEnabled =>
 				ErrorObject::owned(3, msg, None::<()>),
+			MsaOffchainRpcError::OffchainIndexNotInitialized =>
+				ErrorObject::owned(4, msg, None::<()>),
+			MsaOffchainRpcError::BlockNotFound => ErrorObject::owned(5, msg, None::<()>),
+			MsaOffchainRpcError::ErrorReadingState => ErrorObject::owned(6, msg, None::<()>),
+			MsaOffchainRpcError::ErrorRunningTask => ErrorObject::owned(7, msg, None::<()>),
+		}
+	}
+}
+
+/// Errors that occur on the client RPC when querying on-chain data
+#[derive(Debug)]
+pub enum MsaRpcError {
+	/// Pagination request is bad
+	InvalidPaginationRequest,
+	/// More keys were requested than can be looked up at once
+	TooManyKeys,
+}
+
+impl From<MsaRpcError> for ErrorObjectOwned {
+	fn from(e: MsaRpcError) -> Self {
+		let msg = format!("{:?}", e);
+
+		match e {
+			MsaRpcError::InvalidPaginationRequest => ErrorObject::owned(1, msg, None::<()>),
+			MsaRpcError::TooManyKeys => ErrorObject::owned(2, msg, None::<()>),
 		}
 	}
 }
@@ -201,4 +324,192 @@ where
 		}
 		Ok(None)
 	}
+
+	fn get_delegators_by_provider(
+		&self,
+		provider_msa_id: ProviderId,
+		request: DelegatorsPaginationRequest,
+	) -> RpcResult<DelegatorsPaginationResponse<SchemaId, BlockNumber>> {
+		if !request.validate() {
+			return Err(MsaRpcError::InvalidPaginationRequest.into())
+		}
+
+		let api = self.client.runtime_api();
+		let at = self.client.info().best_hash;
+		let runtime_api_result = api.get_delegators_by_provider(at, provider_msa_id, request);
+		map_rpc_result(runtime_api_result)
+	}
+
+	fn get_delegation_requests(
+		&self,
+		delegator_msa_id: DelegatorId,
+	) -> RpcResult<Vec<DelegationRequestResponse<SchemaId, BlockNumber>>> {
+		let api = self.client.runtime_api();
+		let at = self.client.info().best_hash;
+		let runtime_api_result = api.get_delegation_requests(at, delegator_msa_id);
+		map_rpc_result(runtime_api_result)
+	}
+
+	fn get_msa_ids_for_keys(
+		&self,
+		keys: Vec<AccountId>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<Option<MessageSourceId>>> {
+		if keys.len() > MAX_KEYS_PER_MSA_LOOKUP as usize {
+			return Err(MsaRpcError::TooManyKeys.into())
+		}
+
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let runtime_api_result = api.get_msa_ids_for_keys(at, keys);
+		map_rpc_result(runtime_api_result)
+	}
+
+	fn get_key_owners(
+		&self,
+		keys: Vec<AccountId>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<Option<KeyOwnerResponse>>> {
+		if keys.len() > MAX_KEYS_PER_MSA_LOOKUP as usize {
+			return Err(MsaRpcError::TooManyKeys.into())
+		}
+
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let runtime_api_result = api.get_key_owners(at, keys);
+		map_rpc_result(runtime_api_result)
+	}
+
+	fn get_history(
+		&self,
+		msa_id: MessageSourceId,
+		pagination: BlockPaginationRequest,
+	) -> RpcResult<BlockPaginationResponse<MsaHistoryRecord<AccountId>>> {
+		if !pagination.validate() {
+			return Err(MsaRpcError::InvalidPaginationRequest.into())
+		}
+
+		let reader = self.offchain.try_read().ok_or(MsaOffchainRpcError::ErrorAcquiringLock)?;
+		let db = reader.as_ref().ok_or(MsaOffchainRpcError::OffchainIndexingNotEnabled)?;
+		let read = |key: &[u8]| -> Option<Bytes> {
+			db.get(sp_offchain::STORAGE_PREFIX, key).map(Into::into)
+		};
+		let chunk_count = match read(&get_msa_history_storage_key_name(msa_id)) {
+			Some(rr) =>
+				u32::decode(&mut &rr.0[..]).map_err(|_| MsaOffchainRpcError::ErrorDecodingData)?,
+			None => 0,
+		};
+
+		// chunks are appended in block order, so only the chunks overlapping the range are kept
+		let mut history: Vec<MsaHistoryRecord<AccountId>> = Vec::new();
+		for chunk_index in 0..chunk_count {
+			let chunk = match read(&get_msa_history_chunk_storage_key_name(msa_id, chunk_index)) {
+				Some(rr) => Vec::<MsaHistoryRecord<AccountId>>::decode(&mut &rr.0[..])
+					.map_err(|_| MsaOffchainRpcError::ErrorDecodingData)?,
+				None => Vec::new(),
+			};
+			if chunk.first().map_or(false, |record| record.block_number >= pagination.to_block) {
+				break
+			}
+			if chunk
+				.last()
+				.map_or(false, |record| record.block_number >= pagination.from_block)
+			{
+				history.extend(chunk);
+			}
+		}
+
+		// records are appended in block order, grouping keeps the order within a block
+		let mut records_by_block: BTreeMap<BlockNumber, Vec<MsaHistoryRecord<AccountId>>> =
+			BTreeMap::new();
+		for record in history.into_iter().filter(|record| {
+			record.block_number >= pagination.from_block &&
+				record.block_number < pagination.to_block
+		}) {
+			records_by_block.entry(record.block_number).or_default().push(record);
+		}
+
+		let mut response = BlockPaginationResponse::new();
+		'loops: for (block_number, records) in records_by_block {
+			let list_size = records.len() as u32;
+			let from_index =
+				if block_number == pagination.from_block { pagination.from_index } else { 0 };
+			for (i, record) in records.into_iter().enumerate().skip(from_index as usize) {
+				response.content.push(record);
+
+				if response.check_end_condition_and_set_next_pagination(
+					block_number,
+					i as u32,
+					list_size,
+					&pagination,
+				) {
+					break 'loops
+				}
+			}
+		}
+		Ok(response)
+	}
+}
+
+/// The client handler for the offchain index API used by Recurrency Service RPC with `jsonrpsee`
+pub struct MsaOffchainIndexHandler<C, B, Block, OffchainDB> {
+	client: Arc<C>,
+	offchain: Arc<RwLock<Option<OffchainDB>>>,
+	_marker: std::marker::PhantomData<(B, Block)>,
+}
+
+impl<C, B, Block, OffchainDB> MsaOffchainIndexHandler<C, B, Block, OffchainDB>
+where
+	OffchainDB: Send + Sync,
+{
+	/// Create new instance with the given reference to the client.
+	pub fn new(client: Arc<C>, offchain: Option<OffchainDB>) -> Self {
+		Self { client, offchain: Arc::new(RwLock::new(offchain)), _marker: Default::default() }
+	}
+}
+
+#[async_trait]
+impl<C, B, Block, OffchainDB, AccountId> MsaOffchainIndexApiServer<AccountId>
+	for MsaOffchainIndexHandler<C, B, Block, OffchainDB>
+where
+	Block: BlockT,
+	NumberFor<Block>: From<BlockNumber>,
+	B: Backend<Block> + Send + Sync + 'static,
+	C: StorageProvider<Block, B> + HeaderBackend<Block> + Send + Sync + 'static,
+	AccountId: Codec + PartialEq + Clone + Send + Sync + 'static,
+	OffchainDB: sp_core::offchain::OffchainStorage + 'static,
+{
+	async fn check_offchain_index(
+		&self,
+		block_number: Option<BlockNumber>,
+	) -> RpcResult<OffchainIndexReport<AccountId>> {
+		let (client, offchain) = (self.client.clone(), self.offchain.clone());
+		// the scan reads the whole of `PublicKeyToMsaId` so it must not block the RPC executor
+		let report = tokio::task::spawn_blocking(move || {
+			let reader = offchain.try_read().ok_or(MsaOffchainRpcError::ErrorAcquiringLock)?;
+			let offchain =
+				reader.as_ref().ok_or(MsaOffchainRpcError::OffchainIndexingNotEnabled)?;
+			offchain_index::check_offchain_index::<Block, B, C, _, AccountId>(
+				&client,
+				offchain,
+				block_number,
+			)
+		})
+		.await
+		.map_err(|_| MsaOffchainRpcError::ErrorRunningTask)??;
+		Ok(report)
+	}
+
+	async fn rebuild_offchain_index(&self) -> RpcResult<OffchainIndexReport<AccountId>> {
+		let (client, offchain) = (self.client.clone(), self.offchain.clone());
+		let report = tokio::task::spawn_blocking(move || {
+			let mut writer = offchain.try_write().ok_or(MsaOffchainRpcError::ErrorAcquiringLock)?;
+			let offchain =
+				writer.as_mut().ok_or(MsaOffchainRpcError::OffchainIndexingNotEnabled)?;
+			offchain_index::rebuild_offchain_index::<Block, B, C, _, AccountId>(&client, offchain)
+		})
+		.await
+		.map_err(|_| MsaOffchainRpcError::ErrorRunningTask)??;
+		Ok(report)
+	}
 }
@@ -0,0 +1,275 @@
+//! Verification and rebuild of the offchain index of MSA keys against the chain state
+//!
+//! The offchain worker of the MSA pallet builds the index incrementally from the events of each
+//! block, so it diverges from `PublicKeyToMsaId` if events are missed. These helpers compare the
+//! index with the chain state read straight from the node's database and rewrite the keys of the
+//! MSAs that differ, without resyncing the chain. The offchain history of MSAs cannot be
+//! recovered from the chain state and is left untouched.
+//!
+//! The keys of an MSA are only rewritten while holding the same per-MSA lock the offchain worker
+//! takes to apply the events of that MSA, so a rebuild cannot interleave with the worker.
+
+use crate::MsaOffchainRpcError;
+use common_primitives::{
+	msa::{MessageSourceId, OffchainIndexMismatch, OffchainIndexReport},
+	node::BlockNumber,
+	offchain::{
+		get_msa_account_lock_name, get_msa_account_storage_key_name,
+		LAST_PROCESSED_BLOCK_STORAGE_NAME, MSA_ACCOUNT_LOCK_TIMEOUT_EXPIRATION_MS,
+	},
+};
+use parity_scale_codec::{Decode, Encode};
+use sc_client_api::{Backend, StorageProvider};
+use sp_blockchain::HeaderBackend;
+use sp_core::{
+	hashing::twox_128,
+	offchain::{OffchainStorage, Timestamp},
+	storage::StorageKey,
+};
+use sp_runtime::traits::{Block as BlockT, NumberFor};
+use std::{
+	collections::BTreeMap,
+	thread::sleep,
+	time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Length of the `Twox64Concat` hash that prefixes the keys of `PublicKeyToMsaId`
+const TWOX_64_HASH_LENGTH: usize = 8;
+
+/// The keys of each MSA in the chain state
+type MsaKeys<AccountId> = BTreeMap<MessageSourceId, Vec<AccountId>>;
+
+/// Storage key prefix of a storage item of the MSA pallet
+fn msa_storage_prefix(storage_name: &[u8]) -> StorageKey {
+	StorageKey([twox_128(b"Msa"), twox_128(storage_name)].concat())
+}
+
+/// Returns the block up to which the offchain worker has applied MSA events to the index
+pub fn last_indexed_block<OffchainDB: OffchainStorage>(
+	offchain: &OffchainDB,
+) -> Result<BlockNumber, MsaOffchainRpcError> {
+	let raw = offchain
+		.get(sp_offchain::STORAGE_PREFIX, LAST_PROCESSED_BLOCK_STORAGE_NAME)
+		.ok_or(MsaOffchainRpcError::OffchainIndexNotInitialized)?;
+	BlockNumber::decode(&mut &raw[..]).map_err(|_| MsaOffchainRpcError::ErrorDecodingData)
+}
+
+/// Reads the greatest MSA id and the keys of every MSA from the state of block `block_number`
+fn msa_keys_at<Block, B, C, AccountId>(
+	client: &C,
+	block_number: BlockNumber,
+) -> Result<(MessageSourceId, MsaKeys<AccountId>), MsaOffchainRpcError>
+where
+	Block: BlockT,
+	B: Backend<Block>,
+	C: StorageProvider<Block, B> + HeaderBackend<Block>,
+	NumberFor<Block>: From<BlockNumber>,
+	AccountId: Decode,
+{
+	let at = client
+		.hash(block_number.into())
+		.map_err(|_| MsaOffchainRpcError::ErrorReadingState)?
+		.ok_or(MsaOffchainRpcError::BlockNotFound)?;
+
+	let max_msa_id = client
+		.storage(at, &msa_storage_prefix(b"CurrentMsaIdentifierMaximum"))
+		.map_err(|_| MsaOffchainRpcError::ErrorReadingState)?
+		.map(|data| MessageSourceId::decode(&mut &data.0[..]))
+		.transpose()
+		.map_err(|_| MsaOffchainRpcError::ErrorDecodingData)?
+		.unwrap_or_default();
+
+	let prefix = msa_storage_prefix(b"PublicKeyToMsaId");
+	let mut msa_keys: MsaKeys<AccountId> = BTreeMap::new();
+	for (key, value) in client
+		.storage_pairs(at, Some(&prefix), None)
+		.map_err(|_| MsaOffchainRpcError::ErrorReadingState)?
+	{
+		let encoded_account_id = key
+			.0
+			.get(prefix.0.len() + TWOX_64_HASH_LENGTH..)
+			.ok_or(MsaOffchainRpcError::ErrorDecodingData)?;
+		let account_id = AccountId::decode(&mut &encoded_account_id[..])
+			.map_err(|_| MsaOffchainRpcError::ErrorDecodingData)?;
+		let msa_id = MessageSourceId::decode(&mut &value.0[..])
+			.map_err(|_| MsaOffchainRpcError::ErrorDecodingData)?;
+		msa_keys.entry(msa_id).or_default().push(account_id);
+	}
+	Ok((max_msa_id, msa_keys))
+}
+
+/// Compares the indexed keys of `msa_id` with `keys`.
+/// An index entry that cannot be decoded is compared as if it held no keys.
+fn compare_keys_of_msa<OffchainDB, AccountId>(
+	offchain: &OffchainDB,
+	msa_id: MessageSourceId,
+	keys: &[AccountId],
+) -> Option<OffchainIndexMismatch<AccountId>>
+where
+	OffchainDB: OffchainStorage,
+	AccountId: Decode + PartialEq + Clone,
+{
+	let indexed_keys = offchain
+		.get(sp_offchain::STORAGE_PREFIX, &get_msa_account_storage_key_name(msa_id))
+		.and_then(|raw| Vec::<AccountId>::decode(&mut &raw[..]).ok())
+		.unwrap_or_default();
+
+	let missing_keys: Vec<AccountId> =
+		keys.iter().filter(|key| !indexed_keys.contains(key)).cloned().collect();
+	let unexpected_keys: Vec<AccountId> =
+		indexed_keys.into_iter().filter(|key| !keys.contains(key)).collect();
+	match missing_keys.is_empty() && unexpected_keys.is_empty() {
+		true => None,
+		false => Some(OffchainIndexMismatch { msa_id, missing_keys, unexpected_keys }),
+	}
+}
+
+/// Compares the indexed keys of MSAs `1..=max_msa_id` with `msa_keys`
+pub(crate) fn compare_msa_keys<OffchainDB, AccountId>(
+	offchain: &OffchainDB,
+	block_number: BlockNumber,
+	max_msa_id: MessageSourceId,
+	msa_keys: &MsaKeys<AccountId>,
+) -> OffchainIndexReport<AccountId>
+where
+	OffchainDB: OffchainStorage,
+	AccountId: Decode + PartialEq + Clone,
+{
+	let mismatches = (1..=max_msa_id)
+		.filter_map(|msa_id| {
+			let keys = msa_keys.get(&msa_id).map(Vec::as_slice).unwrap_or_default();
+			compare_keys_of_msa(offchain, msa_id, keys)
+		})
+		.collect();
+	OffchainIndexReport { block_number, checked_msa_count: max_msa_id, mismatches }
+}
+
+/// The current time as the offchain worker reads it, in milliseconds since the UNIX epoch
+fn now() -> Timestamp {
+	let since_epoch = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+	Timestamp::from_unix_millis(since_epoch.as_millis() as u64)
+}
+
+/// Takes the lock the offchain worker holds while it applies the events of `msa_id`, waiting
+/// for the worker to release it or for it to expire. The lock is stored the same way as a
+/// `StorageLock<Time>` of the runtime: the encoded deadline under the lock name.
+pub(crate) fn lock_msa<OffchainDB: OffchainStorage>(
+	offchain: &mut OffchainDB,
+	msa_id: MessageSourceId,
+) {
+	let lock_name = get_msa_account_lock_name(msa_id);
+	let timeout = Duration::from_millis(MSA_ACCOUNT_LOCK_TIMEOUT_EXPIRATION_MS);
+	loop {
+		let current = offchain.get(sp_offchain::STORAGE_PREFIX, &lock_name);
+		let held_until = current
+			.as_ref()
+			.and_then(|raw| Timestamp::decode(&mut &raw[..]).ok())
+			.filter(|deadline| *deadline >= now());
+		match held_until {
+			Some(deadline) =>
+				sleep(Duration::from_millis(deadline.diff(&now()).millis()).min(timeout)),
+			None => {
+				let deadline = now().add(sp_core::offchain::Duration::from_millis(
+					MSA_ACCOUNT_LOCK_TIMEOUT_EXPIRATION_MS,
+				));
+				if offchain.compare_and_set(
+					sp_offchain::STORAGE_PREFIX,
+					&lock_name,
+					current.as_deref(),
+					&deadline.encode(),
+				) {
+					return;
+				}
+			},
+		}
+	}
+}
+
+/// Releases the lock of `msa_id` taken by [`lock_msa`]
+pub(crate) fn unlock_msa<OffchainDB: OffchainStorage>(
+	offchain: &mut OffchainDB,
+	msa_id: MessageSourceId,
+) {
+	offchain.remove(sp_offchain::STORAGE_PREFIX, &get_msa_account_lock_name(msa_id));
+}
+
+/// Overwrites the indexed keys of every MSA of `report` with its keys in `msa_keys`, holding the
+/// lock of the MSA. An MSA whose indexed keys changed since `report` was made has been updated
+/// by the offchain worker meanwhile, so it is left as is and dropped from `report`.
+pub(crate) fn write_msa_keys<OffchainDB, AccountId>(
+	offchain: &mut OffchainDB,
+	report: &mut OffchainIndexReport<AccountId>,
+	msa_keys: &MsaKeys<AccountId>,
+) where
+	OffchainDB: OffchainStorage,
+	AccountId: Decode + Encode + PartialEq + Clone,
+{
+	report.mismatches.retain(|mismatch| {
+		let keys = msa_keys.get(&mismatch.msa_id);
+		lock_msa(offchain, mismatch.msa_id);
+		let unchanged = compare_keys_of_msa(
+			offchain,
+			mismatch.msa_id,
+			keys.map(Vec::as_slice).unwrap_or_default(),
+		)
+		.as_ref() == Some(mismatch);
+		if unchanged {
+			let key = get_msa_account_storage_key_name(mismatch.msa_id);
+			match keys {
+				Some(keys) => offchain.set(sp_offchain::STORAGE_PREFIX, &key, &keys.encode()),
+				None => offchain.remove(sp_offchain::STORAGE_PREFIX, &key),
+			}
+		}
+		unlock_msa(offchain, mismatch.msa_id);
+		unchanged
+	});
+}
+
+/// Compares the offchain index of MSA keys with `PublicKeyToMsaId` in the state of
+/// `block_number`, or of the last block indexed by the offchain worker when `None`.
+/// Comparing with another block reports the keys changed between the two blocks as mismatches.
+/// Reads the whole of `PublicKeyToMsaId`, so it must not run on an async executor.
+pub fn check_offchain_index<Block, B, C, OffchainDB, AccountId>(
+	client: &C,
+	offchain: &OffchainDB,
+	block_number: Option<BlockNumber>,
+) -> Result<OffchainIndexReport<AccountId>, MsaOffchainRpcError>
+where
+	Block: BlockT,
+	B: Backend<Block>,
+	C: StorageProvider<Block, B> + HeaderBackend<Block>,
+	NumberFor<Block>: From<BlockNumber>,
+	OffchainDB: OffchainStorage,
+	AccountId: Decode + PartialEq + Clone,
+{
+	let block_number = match block_number {
+		Some(block_number) => block_number,
+		None => last_indexed_block(offchain)?,
+	};
+	let (max_msa_id, msa_keys) = msa_keys_at::<Block, B, C, AccountId>(client, block_number)?;
+	Ok(compare_msa_keys(offchain, block_number, max_msa_id, &msa_keys))
+}
+
+/// Rewrites the indexed keys of every MSA that differ from `PublicKeyToMsaId` in the state of the
+/// last block indexed by the offchain worker, so that the worker can carry on from that block.
+/// Returns the MSAs that were rewritten. The offchain worker may index new blocks while the
+/// index is rebuilt, so a running node should be checked again afterwards.
+/// Reads the whole of `PublicKeyToMsaId`, so it must not run on an async executor.
+pub fn rebuild_offchain_index<Block, B, C, OffchainDB, AccountId>(
+	client: &C,
+	offchain: &mut OffchainDB,
+) -> Result<OffchainIndexReport<AccountId>, MsaOffchainRpcError>
+where
+	Block: BlockT,
+	B: Backend<Block>,
+	C: StorageProvider<Block, B> + HeaderBackend<Block>,
+	NumberFor<Block>: From<BlockNumber>,
+	OffchainDB: OffchainStorage,
+	AccountId: Decode + Encode + PartialEq + Clone,
+{
+	let block_number = last_indexed_block(offchain)?;
+	let (max_msa_id, msa_keys) = msa_keys_at::<Block, B, C, AccountId>(client, block_number)?;
+	let mut report = compare_msa_keys(offchain, block_number, max_msa_id, &msa_keys);
+	write_msa_keys(offchain, &mut report, &msa_keys);
+	Ok(report)
+}
@@ -3,10 +3,14 @@ mod rpc_mock;
 use super::*;
 use rpc_mock::*;
 
-use common_primitives::node::{AccountId, Block, BlockNumber};
+use common_primitives::{
+	msa::{DelegatorResponse, MsaHistoryEvent, OffchainIndexMismatch},
+	node::{AccountId, Block, BlockNumber},
+	offchain::{get_msa_account_lock_name, MSA_ACCOUNT_LOCK_TIMEOUT_EXPIRATION_MS},
+};
 use pallet_msa_runtime_api::MsaRuntimeApi;
 use parity_scale_codec::Encode;
-use sp_core::offchain::{testing::TestPersistentOffchainDB, OffchainStorage};
+use sp_core::offchain::{testing::TestPersistentOffchainDB, OffchainStorage, Timestamp};
 
 use sp_runtime::traits::Zero;
 use std::{sync::Arc, vec};
@@ -56,6 +60,63 @@ sp_api::mock_impl_runtime_apis! {
 				_ => vec![],
 			}
 		}
+
+		/// Get a page of the Delegators of a provider
+		fn get_delegators_by_provider(provider: ProviderId, request: DelegatorsPaginationRequest) -> DelegatorsPaginationResponse<SchemaId, BlockNumber> {
+			let mut response = DelegatorsPaginationResponse::new();
+			if provider == PROVIDER_WITH_DELEGATE_A_AND_B && request.after.is_none() {
+				response.content.push(DelegatorResponse {
+					delegator_id: DELEGATE_A,
+					revoked_at: BlockNumber::zero(),
+					permissions: vec![SchemaGrant::new(SCHEMA_FOR_A_AND_B, BlockNumber::zero())],
+				});
+				response.has_next = true;
+				response.next_after = Some(DELEGATE_A);
+			}
+			response
+		}
+
+		/// Get the delegation requests pending for a Delegator
+		fn get_delegation_requests(delegator: DelegatorId) -> Vec<DelegationRequestResponse<SchemaId, BlockNumber>> {
+			match delegator {
+				DELEGATE_B => vec![DelegationRequestResponse {
+					provider_id: PROVIDER_WITH_DELEGATE_A,
+					schema_ids: vec![SCHEMA_FOR_B],
+					requested_at: 10,
+					expiration: 20,
+					delegation_expires_at: None,
+				}],
+				_ => vec![],
+			}
+		}
+
+		/// Get the MSA of each key
+		fn get_msa_ids_for_keys(keys: Vec<AccountId>) -> Vec<Option<MessageSourceId>> {
+			keys.into_iter()
+				.map(|key| match key == AccountId::from([1u8; 32]) {
+					true => Some(1),
+					false => None,
+				})
+				.collect()
+		}
+
+		/// Get the MSA and key count of each key
+		fn get_key_owners(keys: Vec<AccountId>) -> Vec<Option<KeyOwnerResponse>> {
+			keys.into_iter()
+				.map(|key| match key == AccountId::from([1u8; 32]) {
+					true => Some(KeyOwnerResponse { msa_id: 1, public_key_count: 2 }),
+					false => None,
+				})
+				.collect()
+		}
+
+		/// Get the MSA a merged MSA was merged into
+		fn get_msa_redirect(msa_id: MessageSourceId) -> Option<MessageSourceId> {
+			match msa_id {
+				2 => Some(1),
+				_ => None,
+			}
+		}
 	}
 }
 
@@ -272,3 +333,374 @@ async fn get_keys_by_msa_id_with_value_should_work() {
 	assert_eq!(true, response.is_some());
 	assert_eq!(KeyInfoResponse { msa_id, msa_keys: accounts }, response.unwrap());
 }
+
+#[tokio::test]
+async fn get_delegators_by_provider_with_success() {
+	let client = Arc::new(TestApi {});
+	let api = MsaHandler::<TestApi, Block, TestPersistentOffchainDB>::new(client, None);
+
+	let result = api.get_delegators_by_provider(
+		PROVIDER_WITH_DELEGATE_A_AND_B,
+		DelegatorsPaginationRequest { page_size: 1, ..Default::default() },
+	);
+
+	assert_eq!(true, result.is_ok());
+	let response = result.unwrap();
+	assert_eq!(
+		vec![DELEGATE_A],
+		response.content.iter().map(|d| d.delegator_id).collect::<Vec<_>>()
+	);
+	assert_eq!(true, response.has_next);
+	assert_eq!(Some(DELEGATE_A), response.next_after);
+}
+
+#[tokio::test]
+async fn get_delegators_by_provider_with_invalid_page_size_should_fail() {
+	let client = Arc::new(TestApi {});
+	let api = MsaHandler::<TestApi, Block, TestPersistentOffchainDB>::new(client, None);
+
+	let result = api.get_delegators_by_provider(
+		PROVIDER_WITH_DELEGATE_A_AND_B,
+		DelegatorsPaginationRequest {
+			page_size: DelegatorsPaginationRequest::MAX_PAGE_SIZE + 1,
+			..Default::default()
+		},
+	);
+
+	assert_eq!(true, result.is_err());
+	assert_eq!("InvalidPaginationRequest", result.unwrap_err().message());
+}
+
+#[tokio::test]
+async fn get_delegation_requests_with_success() {
+	let client = Arc::new(TestApi {});
+	let api = MsaHandler::<TestApi, Block, TestPersistentOffchainDB>::new(client, None);
+
+	let result = api.get_delegation_requests(DELEGATE_B);
+
+	assert_eq!(true, result.is_ok());
+	let requests = result.unwrap();
+	assert_eq!(1, requests.len());
+	assert_eq!(PROVIDER_WITH_DELEGATE_A, requests[0].provider_id);
+	assert_eq!(vec![SCHEMA_FOR_B], requests[0].schema_ids);
+}
+
+#[tokio::test]
+async fn get_delegation_requests_without_requests_is_empty() {
+	let client = Arc::new(TestApi {});
+	let api = MsaHandler::<TestApi, Block, TestPersistentOffchainDB>::new(client, None);
+
+	let result = api.get_delegation_requests(DELEGATE_A);
+
+	assert_eq!(true, result.is_ok());
+	assert_eq!(0, result.unwrap().len());
+}
+
+#[tokio::test]
+async fn get_msa_ids_for_keys_keeps_the_order_of_the_keys() {
+	let client = Arc::new(TestApi {});
+	let api = MsaHandler::<TestApi, Block, TestPersistentOffchainDB>::new(client, None);
+
+	let result = api
+		.get_msa_ids_for_keys(vec![AccountId::from([2u8; 32]), AccountId::from([1u8; 32])], None);
+
+	assert_eq!(true, result.is_ok());
+	assert_eq!(vec![None, Some(1)], result.unwrap());
+}
+
+#[tokio::test]
+async fn get_key_owners_keeps_the_order_of_the_keys() {
+	let client = Arc::new(TestApi {});
+	let api = MsaHandler::<TestApi, Block, TestPersistentOffchainDB>::new(client, None);
+
+	let result =
+		api.get_key_owners(vec![AccountId::from([2u8; 32]), AccountId::from([1u8; 32])], None);
+
+	assert_eq!(true, result.is_ok());
+	assert_eq!(
+		vec![None, Some(KeyOwnerResponse { msa_id: 1, public_key_count: 2 })],
+		result.unwrap()
+	);
+}
+
+#[tokio::test]
+async fn get_msa_redirect_returns_the_msa_merged_into() {
+	let client = Arc::new(TestApi {});
+	let api = MsaHandler::<TestApi, Block, TestPersistentOffchainDB>::new(client, None);
+
+	assert_eq!(Some(1), api.get_msa_redirect(2, None).unwrap());
+	assert_eq!(None, api.get_msa_redirect(1, None).unwrap());
+}
+
+#[tokio::test]
+async fn get_msa_ids_for_keys_rejects_too_many_keys() {
+	let client = Arc::new(TestApi {});
+	let api = MsaHandler::<TestApi, Block, TestPersistentOffchainDB>::new(client, None);
+	let keys = vec![AccountId::from([1u8; 32]); MAX_KEYS_PER_MSA_LOOKUP as usize + 1];
+
+	assert!(api.get_msa_ids_for_keys(keys.clone(), None).is_err());
+	assert!(api.get_key_owners(keys, None).is_err());
+}
+
+fn history_db(msa_id: MessageSourceId) -> TestPersistentOffchainDB {
+	let key = AccountId::from([1u8; 32]);
+	let history: Vec<MsaHistoryRecord<AccountId>> = vec![
+		MsaHistoryRecord {
+			block_number: 1,
+			event: MsaHistoryEvent::PublicKeyAdded { key: key.clone() },
+		},
+		MsaHistoryRecord {
+			block_number: 3,
+			event: MsaHistoryEvent::DelegationGranted { provider_id: PROVIDER_WITH_DELEGATE_A },
+		},
+		MsaHistoryRecord {
+			block_number: 3,
+			event: MsaHistoryEvent::DelegationUpdated { provider_id: PROVIDER_WITH_DELEGATE_A },
+		},
+		MsaHistoryRecord {
+			block_number: 5,
+			event: MsaHistoryEvent::DelegationRevoked { provider_id: PROVIDER_WITH_DELEGATE_A },
+		},
+		MsaHistoryRecord { block_number: 8, event: MsaHistoryEvent::PublicKeyDeleted { key } },
+	];
+	// the history is split in chunks of at most `MSA_HISTORY_CHUNK_SIZE` records
+	let mut db = TestPersistentOffchainDB::new();
+	db.set(sp_offchain::STORAGE_PREFIX, &get_msa_history_storage_key_name(msa_id), &2u32.encode());
+	db.set(
+		sp_offchain::STORAGE_PREFIX,
+		&get_msa_history_chunk_storage_key_name(msa_id, 0),
+		&history[..3].encode(),
+	);
+	db.set(
+		sp_offchain::STORAGE_PREFIX,
+		&get_msa_history_chunk_storage_key_name(msa_id, 1),
+		&history[3..].encode(),
+	);
+	db
+}
+
+#[tokio::test]
+async fn get_history_with_disabled_offchain_should_fail() {
+	let client = Arc::new(TestApi {});
+	let api = MsaHandler::<TestApi, Block, TestPersistentOffchainDB>::new(client, None);
+
+	let result = api.get_history(
+		NOT_EXIST_MSA,
+		BlockPaginationRequest { from_block: 1, from_index: 0, to_block: 10, page_size: 10 },
+	);
+
+	assert_eq!(true, result.is_err());
+	assert_eq!("OffchainIndexingNotEnabled", result.unwrap_err().message());
+}
+
+#[tokio::test]
+async fn get_history_with_invalid_pagination_should_fail() {
+	let client = Arc::new(TestApi {});
+	let api = MsaHandler::<TestApi, Block, TestPersistentOffchainDB>::new(
+		client,
+		Some(TestPersistentOffchainDB::new()),
+	);
+
+	let result = api.get_history(
+		NOT_EXIST_MSA,
+		BlockPaginationRequest { from_block: 10, from_index: 0, to_block: 1, page_size: 10 },
+	);
+
+	assert_eq!(true, result.is_err());
+	assert_eq!("InvalidPaginationRequest", result.unwrap_err().message());
+}
+
+#[tokio::test]
+async fn get_history_with_empty_value_should_work() {
+	let client = Arc::new(TestApi {});
+	let api = MsaHandler::<TestApi, Block, TestPersistentOffchainDB>::new(
+		client,
+		Some(TestPersistentOffchainDB::new()),
+	);
+
+	let result = api.get_history(
+		NOT_EXIST_MSA,
+		BlockPaginationRequest { from_block: 1, from_index: 0, to_block: 10, page_size: 10 },
+	);
+
+	assert_eq!(true, result.is_ok());
+	assert_eq!(BlockPaginationResponse::new(), result.unwrap());
+}
+
+#[tokio::test]
+async fn get_history_returns_records_within_block_range() {
+	let msa_id: MessageSourceId = 10;
+	let client = Arc::new(TestApi {});
+	let api = MsaHandler::<TestApi, Block, TestPersistentOffchainDB>::new(
+		client,
+		Some(history_db(msa_id)),
+	);
+
+	let result = api.get_history(
+		msa_id,
+		BlockPaginationRequest { from_block: 2, from_index: 0, to_block: 8, page_size: 10 },
+	);
+
+	assert_eq!(true, result.is_ok());
+	let response = result.unwrap();
+	assert_eq!(vec![3, 3, 5], response.content.iter().map(|r| r.block_number).collect::<Vec<_>>());
+	assert_eq!(false, response.has_next);
+}
+
+#[tokio::test]
+async fn get_history_pages_within_a_block() {
+	let msa_id: MessageSourceId = 10;
+	let client = Arc::new(TestApi {});
+	let api = MsaHandler::<TestApi, Block, TestPersistentOffchainDB>::new(
+		client,
+		Some(history_db(msa_id)),
+	);
+
+	let first_page = api
+		.get_history(
+			msa_id,
+			BlockPaginationRequest { from_block: 1, from_index: 0, to_block: 10, page_size: 2 },
+		)
+		.unwrap();
+	assert_eq!(
+		vec![
+			MsaHistoryEvent::PublicKeyAdded { key: AccountId::from([1u8; 32]) },
+			MsaHistoryEvent::DelegationGranted { provider_id: PROVIDER_WITH_DELEGATE_A },
+		],
+		first_page.content.into_iter().map(|r| r.event).collect::<Vec<_>>()
+	);
+	assert_eq!(true, first_page.has_next);
+	assert_eq!(Some(3), first_page.next_block);
+	assert_eq!(Some(1), first_page.next_index);
+
+	let second_page = api
+		.get_history(
+			msa_id,
+			BlockPaginationRequest {
+				from_block: first_page.next_block.unwrap(),
+				from_index: first_page.next_index.unwrap(),
+				to_block: 10,
+				page_size: 2,
+			},
+		)
+		.unwrap();
+	assert_eq!(
+		vec![
+			MsaHistoryEvent::DelegationUpdated { provider_id: PROVIDER_WITH_DELEGATE_A },
+			MsaHistoryEvent::DelegationRevoked { provider_id: PROVIDER_WITH_DELEGATE_A },
+		],
+		second_page.content.into_iter().map(|r| r.event).collect::<Vec<_>>()
+	);
+	assert_eq!(true, second_page.has_next);
+	assert_eq!(Some(6), second_page.next_block);
+	assert_eq!(Some(0), second_page.next_index);
+}
+
+#[tokio::test]
+async fn last_indexed_block_without_offchain_worker_progress_should_fail() {
+	let db = TestPersistentOffchainDB::new();
+
+	let result = offchain_index::last_indexed_block(&db);
+
+	assert_eq!("OffchainIndexNotInitialized", format!("{:?}", result.unwrap_err()));
+}
+
+#[tokio::test]
+async fn compare_msa_keys_reports_missing_and_unexpected_keys() {
+	let (key_a, key_b, key_c) =
+		(AccountId::from([1u8; 32]), AccountId::from([2u8; 32]), AccountId::from([3u8; 32]));
+	let mut db = TestPersistentOffchainDB::new();
+	db.set(
+		sp_offchain::STORAGE_PREFIX,
+		&get_msa_account_storage_key_name(1),
+		&vec![key_a.clone()].encode(),
+	);
+	db.set(
+		sp_offchain::STORAGE_PREFIX,
+		&get_msa_account_storage_key_name(2),
+		&vec![key_c.clone()].encode(),
+	);
+	let msa_keys = BTreeMap::from([(1, vec![key_a]), (2, vec![key_b.clone()])]);
+
+	let report = offchain_index::compare_msa_keys(&db, 7, 3, &msa_keys);
+
+	assert_eq!(7, report.block_number);
+	assert_eq!(3, report.checked_msa_count);
+	assert_eq!(
+		vec![OffchainIndexMismatch {
+			msa_id: 2,
+			missing_keys: vec![key_b],
+			unexpected_keys: vec![key_c]
+		}],
+		report.mismatches
+	);
+}
+
+#[tokio::test]
+async fn write_msa_keys_rewrites_only_mismatched_msas() {
+	let (key_a, key_b) = (AccountId::from([1u8; 32]), AccountId::from([2u8; 32]));
+	let mut db = TestPersistentOffchainDB::new();
+	db.set(
+		sp_offchain::STORAGE_PREFIX,
+		&get_msa_account_storage_key_name(2),
+		&vec![key_a.clone()].encode(),
+	);
+	let msa_keys = BTreeMap::from([(1, vec![key_b.clone()])]);
+	let mut report = offchain_index::compare_msa_keys(&db, 7, 2, &msa_keys);
+
+	offchain_index::write_msa_keys(&mut db, &mut report, &msa_keys);
+
+	assert_eq!(
+		Some(vec![key_b].encode()),
+		db.get(sp_offchain::STORAGE_PREFIX, &get_msa_account_storage_key_name(1))
+	);
+	assert_eq!(None, db.get(sp_offchain::STORAGE_PREFIX, &get_msa_account_storage_key_name(2)));
+	assert!(offchain_index::compare_msa_keys(&db, 7, 2, &msa_keys).mismatches.is_empty());
+	assert_eq!(2, report.mismatches.len());
+	assert_eq!(None, db.get(sp_offchain::STORAGE_PREFIX, &get_msa_account_lock_name(1)));
+}
+
+#[tokio::test]
+async fn write_msa_keys_leaves_msas_updated_since_the_comparison() {
+	// arrange
+	let (key_a, key_b) = (AccountId::from([1u8; 32]), AccountId::from([2u8; 32]));
+	let mut db = TestPersistentOffchainDB::new();
+	let msa_keys = BTreeMap::from([(1, vec![key_a.clone()])]);
+	let mut report = offchain_index::compare_msa_keys(&db, 7, 1, &msa_keys);
+	let worker_keys = vec![key_a, key_b].encode();
+	db.set(sp_offchain::STORAGE_PREFIX, &get_msa_account_storage_key_name(1), &worker_keys);
+
+	// act
+	offchain_index::write_msa_keys(&mut db, &mut report, &msa_keys);
+
+	// assert
+	assert!(report.mismatches.is_empty());
+	assert_eq!(
+		Some(worker_keys),
+		db.get(sp_offchain::STORAGE_PREFIX, &get_msa_account_storage_key_name(1))
+	);
+}
+
+#[tokio::test]
+async fn lock_msa_waits_for_the_lock_of_the_offchain_worker() {
+	// arrange
+	let mut db = TestPersistentOffchainDB::new();
+	let now = std::time::SystemTime::now()
+		.duration_since(std::time::UNIX_EPOCH)
+		.unwrap()
+		.as_millis() as u64;
+	let worker_deadline = Timestamp::from_unix_millis(now + MSA_ACCOUNT_LOCK_TIMEOUT_EXPIRATION_MS);
+	db.set(sp_offchain::STORAGE_PREFIX, &get_msa_account_lock_name(1), &worker_deadline.encode());
+
+	// act
+	offchain_index::lock_msa(&mut db, 1);
+
+	// assert
+	let deadline = db
+		.get(sp_offchain::STORAGE_PREFIX, &get_msa_account_lock_name(1))
+		.map(|raw| Timestamp::decode(&mut &raw[..]).unwrap())
+		.unwrap();
+	assert!(deadline > worker_deadline);
+	offchain_index::unlock_msa(&mut db, 1);
+	assert_eq!(None, db.get(sp_offchain::STORAGE_PREFIX, &get_msa_account_lock_name(1)));
+}
@@ -29,7 +29,7 @@ sp_api::decl_runtime_apis! {
 	/// - MUST be incremented if anything changes
 	/// - Also update in js/api-augment
 	/// - See: https://paritytech.github.io/polkadot/doc/polkadot_primitives/runtime_api/index.html
-	#[api_version(2)]
+	#[api_version(7)]
 
 	/// Runtime API definition for [MSA](../pallet_msa/index.html)
 	pub trait MsaRuntimeApi<AccountId> where
@@ -45,5 +45,29 @@ sp_api::decl_runtime_apis! {
 		/// Get the list of all delegated providers with schema permission grants (if any) that exist in any delegation between the delegator and provider
 		/// The returned list contains both schema id and the block number at which permission was revoked (0 if currently not revoked)
 		fn get_all_granted_delegations_by_msa_id(delegator: DelegatorId) -> Vec<DelegationResponse<SchemaId, BlockNumber>>;
+
+		/// Get the name and published metadata of a registered provider
+		#[api_version(3)]
+		fn get_provider_info(provider: ProviderId) -> Option<ProviderInfoResponse>;
+
+		/// Get a page of the Delegators of a provider, optionally filtered by schema and delegation status
+		#[api_version(4)]
+		fn get_delegators_by_provider(provider: ProviderId, request: DelegatorsPaginationRequest) -> DelegatorsPaginationResponse<SchemaId, BlockNumber>;
+
+		/// Get the delegation requests pending for a Delegator that can still be accepted
+		#[api_version(5)]
+		fn get_delegation_requests(delegator: DelegatorId) -> Vec<DelegationRequestResponse<SchemaId, BlockNumber>>;
+
+		/// Get the MSA id each key belongs to, in the order of the keys
+		#[api_version(6)]
+		fn get_msa_ids_for_keys(keys: Vec<AccountId>) -> Vec<Option<MessageSourceId>>;
+
+		/// Get the MSA id and number of public keys of the MSA each key belongs to, in the order of the keys
+		#[api_version(6)]
+		fn get_key_owners(keys: Vec<AccountId>) -> Vec<Option<KeyOwnerResponse>>;
+
+		/// Get the MSA id a merged MSA was merged into, following later merges of that MSA
+		#[api_version(7)]
+		fn get_msa_redirect(msa_id: MessageSourceId) -> Option<MessageSourceId>;
 	}
 }
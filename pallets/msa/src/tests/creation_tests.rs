@@ -34,7 +34,7 @@ pub fn create_sponsored_account_with_delegation_with_valid_input_should_succeed(
 		let expiration: BlockNumber = 10;
 
 		let add_provider_payload = AddProvider::new(provider_msa, None, expiration);
-		let encode_add_provider_data = wrap_binary_data(add_provider_payload.encode());
+		let encode_add_provider_data = wrap_binary_data(add_provider_payload.signed_payload());
 
 		let signature: MultiSignature = key_pair_delegator.sign(&encode_add_provider_data).into();
 
@@ -85,7 +85,7 @@ fn create_sponsored_account_with_delegation_with_invalid_signature_should_fail()
 
 		let expiration: BlockNumber = 10;
 		let add_provider_payload = AddProvider::new(1u64, None, expiration);
-		let encode_add_provider_data = wrap_binary_data(add_provider_payload.encode());
+		let encode_add_provider_data = wrap_binary_data(add_provider_payload.signed_payload());
 
 		let signature: MultiSignature = signer_pair.sign(&encode_add_provider_data).into();
 
@@ -116,7 +116,7 @@ pub fn create_sponsored_account_with_delegation_with_invalid_add_provider_should
 
 		let expiration: BlockNumber = 10;
 		let add_provider_payload = AddProvider::new(1u64, None, expiration);
-		let encode_add_provider_data = wrap_binary_data(add_provider_payload.encode());
+		let encode_add_provider_data = wrap_binary_data(add_provider_payload.signed_payload());
 
 		let signature: MultiSignature = key_pair_delegator.sign(&encode_add_provider_data).into();
 
@@ -154,7 +154,7 @@ pub fn create_sponsored_account_with_delegation_with_different_authorized_msa_id
 
 		let expiration: BlockNumber = 10;
 		let add_provider_payload = AddProvider::new(3u64, None, expiration);
-		let encode_add_provider_data = wrap_binary_data(add_provider_payload.encode());
+		let encode_add_provider_data = wrap_binary_data(add_provider_payload.signed_payload());
 
 		let signature: MultiSignature = key_pair_delegator.sign(&encode_add_provider_data).into();
 
@@ -186,7 +186,7 @@ pub fn create_sponsored_account_with_delegation_expired() {
 		let expiration: BlockNumber = 0;
 
 		let add_provider_payload = AddProvider::new(1u64, None, expiration);
-		let encode_add_provider_data = wrap_binary_data(add_provider_payload.encode());
+		let encode_add_provider_data = wrap_binary_data(add_provider_payload.signed_payload());
 
 		let signature: MultiSignature = key_pair_delegator.sign(&encode_add_provider_data).into();
 
@@ -0,0 +1,166 @@
+use frame_support::{assert_noop, assert_ok};
+
+use sp_runtime::MultiSignature;
+
+use crate::{tests::mock::*, types::AddProvider, DelegatorAndProviderToDelegation, Error};
+use common_primitives::{
+	msa::{DelegationValidator, DelegatorId, MessageSourceId, ProviderId},
+	node::BlockNumber,
+	utils::wrap_binary_data,
+};
+use parity_scale_codec::Encode;
+use sp_core::{sr25519, Pair};
+
+fn create_and_sign_expiring_add_provider_payload(
+	delegator_pair: &sr25519::Pair,
+	provider_msa: MessageSourceId,
+	expires_at: BlockNumber,
+) -> (MultiSignature, AddProvider) {
+	let add_provider_payload = AddProvider::new(provider_msa, None, 10).with_expires_at(expires_at);
+	let encode_add_provider_data = wrap_binary_data(add_provider_payload.signed_payload());
+	let signature: MultiSignature = delegator_pair.sign(&encode_add_provider_data).into();
+	(signature, add_provider_payload)
+}
+
+fn create_provider() -> (MessageSourceId, sr25519::Pair) {
+	let (provider_msa_id, provider_pair) = create_account();
+	assert_ok!(Msa::create_provider(
+		RuntimeOrigin::signed(provider_pair.public().into()),
+		Vec::from("Foo")
+	));
+	(provider_msa_id, provider_pair)
+}
+
+fn grant_expiring_delegation(expires_at: BlockNumber) -> (ProviderId, DelegatorId) {
+	let (provider_msa_id, provider_pair) = create_provider();
+	let (delegator_msa_id, delegator_pair) = create_account();
+	let (signature, payload) =
+		create_and_sign_expiring_add_provider_payload(&delegator_pair, provider_msa_id, expires_at);
+
+	assert_ok!(Msa::grant_delegation(
+		RuntimeOrigin::signed(provider_pair.public().into()),
+		delegator_pair.public().into(),
+		signature,
+		payload
+	));
+	(ProviderId(provider_msa_id), DelegatorId(delegator_msa_id))
+}
+
+#[test]
+fn grant_delegation_with_expires_at_sets_revoked_at() {
+	new_test_ext().execute_with(|| {
+		let (provider, delegator) = grant_expiring_delegation(50);
+
+		let delegation = DelegatorAndProviderToDelegation::<Test>::get(delegator, provider);
+		assert_eq!(delegation.map(|d| d.revoked_at), Some(50));
+	})
+}
+
+#[test]
+fn create_sponsored_account_with_delegation_with_expires_at_sets_revoked_at() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, provider_pair) = create_provider();
+		let (delegator_pair, _) = sr25519::Pair::generate();
+		let (signature, payload) =
+			create_and_sign_expiring_add_provider_payload(&delegator_pair, provider_msa_id, 50);
+
+		assert_ok!(Msa::create_sponsored_account_with_delegation(
+			RuntimeOrigin::signed(provider_pair.public().into()),
+			delegator_pair.public().into(),
+			signature,
+			payload
+		));
+
+		let delegator_msa_id = Msa::get_owner_of(&delegator_pair.public().into()).unwrap();
+		let delegation = DelegatorAndProviderToDelegation::<Test>::get(
+			DelegatorId(delegator_msa_id),
+			ProviderId(provider_msa_id),
+		);
+		assert_eq!(delegation.map(|d| d.revoked_at), Some(50));
+	})
+}
+
+#[test]
+fn expiring_delegation_is_valid_until_expiry_and_revoked_after() {
+	new_test_ext().execute_with(|| {
+		let (provider, delegator) = grant_expiring_delegation(5);
+
+		run_to_block(5);
+		assert_ok!(Msa::ensure_valid_delegation(provider, delegator, None));
+
+		run_to_block(6);
+		assert_noop!(
+			Msa::ensure_valid_delegation(provider, delegator, None),
+			Error::<Test>::DelegationRevoked
+		);
+		assert_ok!(Msa::ensure_valid_delegation(provider, delegator, Some(4)));
+	})
+}
+
+#[test]
+fn grant_delegation_with_expires_at_not_in_future_fails() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, provider_pair) = create_provider();
+		let (_, delegator_pair) = create_account();
+		run_to_block(3);
+		let (signature, payload) =
+			create_and_sign_expiring_add_provider_payload(&delegator_pair, provider_msa_id, 3);
+
+		assert_noop!(
+			Msa::grant_delegation(
+				RuntimeOrigin::signed(provider_pair.public().into()),
+				delegator_pair.public().into(),
+				signature,
+				payload
+			),
+			Error::<Test>::DelegationExpiryInPast
+		);
+	})
+}
+
+#[test]
+fn expiring_delegation_can_be_revoked_before_expiry() {
+	new_test_ext().execute_with(|| {
+		let (provider, delegator) = grant_expiring_delegation(50);
+
+		run_to_block(5);
+		assert_ok!(Msa::revoke_provider(provider, delegator));
+
+		let delegation = DelegatorAndProviderToDelegation::<Test>::get(delegator, provider);
+		assert_eq!(delegation.map(|d| d.revoked_at), Some(5));
+	})
+}
+
+#[test]
+fn expired_delegation_cannot_be_revoked() {
+	new_test_ext().execute_with(|| {
+		let (provider, delegator) = grant_expiring_delegation(5);
+
+		run_to_block(6);
+		assert_noop!(Msa::revoke_provider(provider, delegator), Error::<Test>::DelegationRevoked);
+	})
+}
+
+#[test]
+fn regranting_without_expires_at_removes_expiry() {
+	new_test_ext().execute_with(|| {
+		let (provider, delegator) = grant_expiring_delegation(5);
+
+		assert_ok!(Msa::upsert_schema_permissions(provider, delegator, vec![], None));
+
+		run_to_block(10);
+		assert_ok!(Msa::ensure_valid_delegation(provider, delegator, None));
+	})
+}
+
+#[test]
+fn signed_payload_without_expires_at_matches_legacy_encoding() {
+	let payload = AddProvider::new(1, Some(vec![2, 3]), 10);
+	let legacy: (MessageSourceId, Vec<u16>, BlockNumber) = (1, vec![2, 3], 10);
+
+	assert_eq!(payload.signed_payload(), legacy.encode());
+	assert_eq!(
+		payload.clone().with_expires_at(20).signed_payload(),
+		payload.with_expires_at(20).encode()
+	);
+}
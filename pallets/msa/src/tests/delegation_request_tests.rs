@@ -0,0 +1,568 @@
+use frame_support::{assert_noop, assert_ok, traits::fungible::Mutate, weights::Weight};
+
+use pretty_assertions::assert_eq;
+use sp_core::{crypto::AccountId32, sr25519, Encode, Pair};
+use sp_runtime::MultiSignature;
+
+use crate::{
+	tests::{mock::*, other_tests::set_schema_count},
+	types::DelegationRequestAnswer,
+	DelegatorAndProviderToDelegation, Error, Event, PendingDelegationRequestCount,
+	PendingDelegationRequests,
+};
+
+use common_primitives::{
+	msa::{DelegationRequestResponse, DelegatorId, MessageSourceId, ProviderId},
+	utils::wrap_binary_data,
+};
+
+fn sign_answer(
+	delegator_key_pair: &sr25519::Pair,
+	provider_msa_id: MessageSourceId,
+	requested_at: u32,
+	accept: bool,
+) -> (MultiSignature, DelegationRequestAnswer<Test>) {
+	let payload =
+		DelegationRequestAnswer::<Test> { provider_msa_id, requested_at, accept, expiration: 10 };
+	let signature = delegator_key_pair.sign(&wrap_binary_data(payload.encode())).into();
+	(signature, payload)
+}
+
+fn answer(
+	delegator_key_pair: &sr25519::Pair,
+	provider_msa_id: MessageSourceId,
+	requested_at: u32,
+	accept: bool,
+) -> frame_support::dispatch::DispatchResult {
+	let (signature, payload) =
+		sign_answer(delegator_key_pair, provider_msa_id, requested_at, accept);
+	Msa::answer_delegation_request(
+		test_origin_signed(1),
+		delegator_key_pair.public().into(),
+		signature,
+		payload,
+	)
+}
+
+/// Creates a provider whose key can pay the deposits of delegation requests
+fn create_funded_provider(name: &str) -> (MessageSourceId, sr25519::Public) {
+	let (provider_msa_id, provider_key) = create_provider_with_name(name);
+	Balances::set_balance(&provider_key.into(), 100);
+	(provider_msa_id, provider_key)
+}
+
+/// Creates a provider, a delegator and a pending request for schemas 1 and 2 expiring at block 20.
+fn create_pending_request() -> (MessageSourceId, sr25519::Public, MessageSourceId, sr25519::Pair) {
+	set_schema_count::<Test>(2);
+	let (provider_msa_id, provider_key) = create_funded_provider("Foo");
+	let (delegator_msa_id, delegator_key_pair) = create_account();
+	assert_ok!(Msa::request_delegation(
+		RuntimeOrigin::signed(provider_key.into()),
+		delegator_msa_id,
+		vec![1, 2],
+		20,
+		None
+	));
+	(provider_msa_id, provider_key, delegator_msa_id, delegator_key_pair)
+}
+
+#[test]
+fn request_delegation_stores_request_and_emits_event() {
+	new_test_ext().execute_with(|| {
+		// arrange & act
+		let (provider_msa_id, _, delegator_msa_id, _) = create_pending_request();
+
+		// assert
+		let provider_id = ProviderId(provider_msa_id);
+		let delegator_id = DelegatorId(delegator_msa_id);
+		let request = PendingDelegationRequests::<Test>::get(delegator_id, provider_id).unwrap();
+		assert_eq!(request.schema_ids.into_inner(), vec![1, 2]);
+		assert_eq!(request.requested_at, 1);
+		assert_eq!(request.expiration, 20);
+		System::assert_last_event(
+			Event::DelegationRequested { provider_id, delegator_id, expiration: 20 }.into(),
+		);
+	})
+}
+
+#[test]
+fn request_delegation_by_non_provider_fails() {
+	new_test_ext().execute_with(|| {
+		let (_, key_pair) = create_account();
+		let (delegator_msa_id, _) = create_account();
+
+		assert_noop!(
+			Msa::request_delegation(
+				RuntimeOrigin::signed(key_pair.public().into()),
+				delegator_msa_id,
+				vec![],
+				20,
+				None
+			),
+			Error::<Test>::ProviderNotRegistered
+		);
+	})
+}
+
+#[test]
+fn request_delegation_to_unknown_delegator_fails() {
+	new_test_ext().execute_with(|| {
+		let (_, provider_key) = create_provider_with_name("Foo");
+
+		assert_noop!(
+			Msa::request_delegation(
+				RuntimeOrigin::signed(provider_key.into()),
+				100,
+				vec![],
+				20,
+				None
+			),
+			Error::<Test>::NoKeyExists
+		);
+	})
+}
+
+#[test]
+fn request_delegation_with_invalid_schema_fails() {
+	new_test_ext().execute_with(|| {
+		set_schema_count::<Test>(1);
+		let (_, provider_key) = create_provider_with_name("Foo");
+		let (delegator_msa_id, _) = create_account();
+
+		assert_noop!(
+			Msa::request_delegation(
+				RuntimeOrigin::signed(provider_key.into()),
+				delegator_msa_id,
+				vec![1, 2],
+				20,
+				None
+			),
+			Error::<Test>::InvalidSchemaId
+		);
+	})
+}
+
+#[test]
+fn request_delegation_with_expiration_in_past_fails() {
+	new_test_ext().execute_with(|| {
+		let (_, provider_key) = create_provider_with_name("Foo");
+		let (delegator_msa_id, _) = create_account();
+
+		assert_noop!(
+			Msa::request_delegation(
+				RuntimeOrigin::signed(provider_key.into()),
+				delegator_msa_id,
+				vec![],
+				1,
+				None
+			),
+			Error::<Test>::DelegationExpiryInPast
+		);
+	})
+}
+
+#[test]
+fn request_delegation_while_pending_fails() {
+	new_test_ext().execute_with(|| {
+		let (_, provider_key, delegator_msa_id, _) = create_pending_request();
+
+		assert_noop!(
+			Msa::request_delegation(
+				RuntimeOrigin::signed(provider_key.into()),
+				delegator_msa_id,
+				vec![1],
+				20,
+				None
+			),
+			Error::<Test>::DelegationRequestAlreadyPending
+		);
+	})
+}
+
+#[test]
+fn cancel_delegation_request_removes_request() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, provider_key, delegator_msa_id, _) = create_pending_request();
+
+		assert_ok!(Msa::cancel_delegation_request(
+			RuntimeOrigin::signed(provider_key.into()),
+			delegator_msa_id
+		));
+
+		let provider_id = ProviderId(provider_msa_id);
+		let delegator_id = DelegatorId(delegator_msa_id);
+		assert_eq!(PendingDelegationRequests::<Test>::get(delegator_id, provider_id), None);
+		System::assert_last_event(
+			Event::DelegationRequestCancelled { provider_id, delegator_id }.into(),
+		);
+	})
+}
+
+#[test]
+fn cancel_delegation_request_without_request_fails() {
+	new_test_ext().execute_with(|| {
+		let (_, provider_key) = create_provider_with_name("Foo");
+		let (delegator_msa_id, _) = create_account();
+
+		assert_noop!(
+			Msa::cancel_delegation_request(
+				RuntimeOrigin::signed(provider_key.into()),
+				delegator_msa_id
+			),
+			Error::<Test>::DelegationRequestNotFound
+		);
+	})
+}
+
+#[test]
+fn accepting_delegation_request_grants_delegation() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (provider_msa_id, _, delegator_msa_id, delegator_key_pair) = create_pending_request();
+		run_to_block(5);
+
+		// act
+		assert_ok!(answer(&delegator_key_pair, provider_msa_id, 1, true));
+
+		// assert
+		let provider_id = ProviderId(provider_msa_id);
+		let delegator_id = DelegatorId(delegator_msa_id);
+		assert_eq!(PendingDelegationRequests::<Test>::get(delegator_id, provider_id), None);
+		let delegation =
+			DelegatorAndProviderToDelegation::<Test>::get(delegator_id, provider_id).unwrap();
+		assert!(delegation.schema_permissions.contains_key(&1));
+		assert!(delegation.schema_permissions.contains_key(&2));
+		System::assert_last_event(Event::DelegationGranted { delegator_id, provider_id }.into());
+	})
+}
+
+#[test]
+fn rejecting_delegation_request_removes_request_without_delegation() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, _, delegator_msa_id, delegator_key_pair) = create_pending_request();
+
+		assert_ok!(answer(&delegator_key_pair, provider_msa_id, 1, false));
+
+		let provider_id = ProviderId(provider_msa_id);
+		let delegator_id = DelegatorId(delegator_msa_id);
+		assert_eq!(PendingDelegationRequests::<Test>::get(delegator_id, provider_id), None);
+		assert!(DelegatorAndProviderToDelegation::<Test>::get(delegator_id, provider_id).is_none());
+		System::assert_last_event(
+			Event::DelegationRequestRejected { provider_id, delegator_id }.into(),
+		);
+	})
+}
+
+#[test]
+fn answering_a_replaced_delegation_request_fails() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, provider_key, delegator_msa_id, delegator_key_pair) =
+			create_pending_request();
+		run_to_block(3);
+		assert_ok!(Msa::cancel_delegation_request(
+			RuntimeOrigin::signed(provider_key.into()),
+			delegator_msa_id
+		));
+		assert_ok!(Msa::request_delegation(
+			RuntimeOrigin::signed(provider_key.into()),
+			delegator_msa_id,
+			vec![1],
+			20,
+			None
+		));
+
+		assert_noop!(
+			answer(&delegator_key_pair, provider_msa_id, 1, true),
+			Error::<Test>::DelegationRequestNotFound
+		);
+	})
+}
+
+#[test]
+fn accepting_an_expired_delegation_request_fails() {
+	new_test_ext().execute_with(|| {
+		set_schema_count::<Test>(1);
+		let (provider_msa_id, provider_key) = create_funded_provider("Foo");
+		let (delegator_msa_id, delegator_key_pair) = create_account();
+		assert_ok!(Msa::request_delegation(
+			RuntimeOrigin::signed(provider_key.into()),
+			delegator_msa_id,
+			vec![1],
+			3,
+			None
+		));
+		run_to_block(4);
+
+		assert_noop!(
+			answer(&delegator_key_pair, provider_msa_id, 1, true),
+			Error::<Test>::DelegationRequestExpired
+		);
+	})
+}
+
+#[test]
+fn accepting_a_request_of_a_retiring_provider_fails() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, provider_key, _, delegator_key_pair) = create_pending_request();
+		assert_ok!(Msa::retire_provider(RuntimeOrigin::signed(provider_key.into())));
+
+		assert_noop!(
+			answer(&delegator_key_pair, provider_msa_id, 1, true),
+			Error::<Test>::ProviderRetiring
+		);
+	})
+}
+
+#[test]
+fn answering_with_a_key_of_another_msa_fails() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, _, _, _) = create_pending_request();
+		let (_, other_key_pair) = create_account();
+
+		assert_noop!(
+			answer(&other_key_pair, provider_msa_id, 1, true),
+			Error::<Test>::DelegationRequestNotFound
+		);
+	})
+}
+
+#[test]
+fn answering_with_an_invalid_signature_fails() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, _, _, delegator_key_pair) = create_pending_request();
+		let (signature, mut payload) = sign_answer(&delegator_key_pair, provider_msa_id, 1, false);
+		payload.accept = true;
+
+		assert_noop!(
+			Msa::answer_delegation_request(
+				test_origin_signed(1),
+				delegator_key_pair.public().into(),
+				signature,
+				payload
+			),
+			Error::<Test>::InvalidSignature
+		);
+	})
+}
+
+#[test]
+fn get_delegation_requests_skips_expired_requests() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (provider_msa_id, _, delegator_msa_id, _) = create_pending_request();
+		let (_, other_provider_key) = create_funded_provider("Bar");
+		assert_ok!(Msa::request_delegation(
+			RuntimeOrigin::signed(other_provider_key.into()),
+			delegator_msa_id,
+			vec![1],
+			3,
+			None
+		));
+		run_to_block(4);
+
+		// act
+		let requests = Msa::get_delegation_requests(DelegatorId(delegator_msa_id));
+
+		// assert
+		assert_eq!(
+			requests,
+			vec![DelegationRequestResponse {
+				provider_id: ProviderId(provider_msa_id),
+				schema_ids: vec![1, 2],
+				requested_at: 1,
+				expiration: 20,
+				delegation_expires_at: None,
+			}]
+		);
+	})
+}
+
+#[test]
+fn request_delegation_holds_deposit_until_cancelled() {
+	new_test_ext().execute_with(|| {
+		// arrange & act
+		let (_, provider_key, delegator_msa_id, _) = create_pending_request();
+		let provider_account = AccountId32::from(provider_key);
+
+		// assert
+		assert_eq!(Balances::reserved_balance(&provider_account), 10);
+		assert_eq!(PendingDelegationRequestCount::<Test>::get(DelegatorId(delegator_msa_id)), 1);
+
+		assert_ok!(Msa::cancel_delegation_request(
+			RuntimeOrigin::signed(provider_account.clone()),
+			delegator_msa_id
+		));
+		assert_eq!(Balances::reserved_balance(&provider_account), 0);
+		assert_eq!(PendingDelegationRequestCount::<Test>::get(DelegatorId(delegator_msa_id)), 0);
+	})
+}
+
+#[test]
+fn answering_delegation_request_releases_deposit() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, provider_key, _, delegator_key_pair) = create_pending_request();
+
+		assert_ok!(answer(&delegator_key_pair, provider_msa_id, 1, false));
+
+		assert_eq!(Balances::reserved_balance(&provider_key.into()), 0);
+	})
+}
+
+#[test]
+fn request_delegation_without_funds_for_deposit_fails() {
+	new_test_ext().execute_with(|| {
+		let (_, provider_key) = create_provider_with_name("Foo");
+		let (delegator_msa_id, _) = create_account();
+
+		assert!(Msa::request_delegation(
+			RuntimeOrigin::signed(provider_key.into()),
+			delegator_msa_id,
+			vec![],
+			20,
+			None
+		)
+		.is_err());
+	})
+}
+
+#[test]
+fn request_delegation_beyond_max_lifetime_fails() {
+	new_test_ext().execute_with(|| {
+		let (_, provider_key) = create_funded_provider("Foo");
+		let (delegator_msa_id, _) = create_account();
+
+		assert_noop!(
+			Msa::request_delegation(
+				RuntimeOrigin::signed(provider_key.into()),
+				delegator_msa_id,
+				vec![],
+				102,
+				None
+			),
+			Error::<Test>::DelegationRequestExpirationTooFar
+		);
+	})
+}
+
+#[test]
+fn request_delegation_beyond_max_pending_requests_fails() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (delegator_msa_id, _) = create_account();
+		for name in ["Foo", "Bar"] {
+			let (_, provider_key) = create_funded_provider(name);
+			assert_ok!(Msa::request_delegation(
+				RuntimeOrigin::signed(provider_key.into()),
+				delegator_msa_id,
+				vec![],
+				20,
+				None
+			));
+		}
+		let (_, provider_key) = create_funded_provider("Baz");
+
+		// act & assert
+		assert_noop!(
+			Msa::request_delegation(
+				RuntimeOrigin::signed(provider_key.into()),
+				delegator_msa_id,
+				vec![],
+				20,
+				None
+			),
+			Error::<Test>::ExceedsMaxPendingDelegationRequests
+		);
+	})
+}
+
+#[test]
+fn accepting_delegation_request_grants_delegation_with_requested_expiry() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		set_schema_count::<Test>(1);
+		let (provider_msa_id, provider_key) = create_funded_provider("Foo");
+		let (delegator_msa_id, delegator_key_pair) = create_account();
+		assert_ok!(Msa::request_delegation(
+			RuntimeOrigin::signed(provider_key.into()),
+			delegator_msa_id,
+			vec![1],
+			20,
+			Some(50)
+		));
+
+		// act
+		assert_ok!(answer(&delegator_key_pair, provider_msa_id, 1, true));
+
+		// assert
+		let delegation = DelegatorAndProviderToDelegation::<Test>::get(
+			DelegatorId(delegator_msa_id),
+			ProviderId(provider_msa_id),
+		)
+		.unwrap();
+		assert_eq!(delegation.revoked_at, 50);
+	})
+}
+
+#[test]
+fn remove_expired_delegation_request_before_expiration_fails() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, _, delegator_msa_id, _) = create_pending_request();
+
+		assert_noop!(
+			Msa::remove_expired_delegation_request(
+				test_origin_signed(1),
+				delegator_msa_id,
+				provider_msa_id
+			),
+			Error::<Test>::DelegationRequestNotExpired
+		);
+	})
+}
+
+#[test]
+fn remove_expired_delegation_request_removes_request_and_releases_deposit() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (provider_msa_id, provider_key, delegator_msa_id, _) = create_pending_request();
+		run_to_block(21);
+
+		// act
+		assert_ok!(Msa::remove_expired_delegation_request(
+			test_origin_signed(1),
+			delegator_msa_id,
+			provider_msa_id
+		));
+
+		// assert
+		let provider_id = ProviderId(provider_msa_id);
+		let delegator_id = DelegatorId(delegator_msa_id);
+		assert_eq!(PendingDelegationRequests::<Test>::get(delegator_id, provider_id), None);
+		assert_eq!(PendingDelegationRequestCount::<Test>::get(delegator_id), 0);
+		assert_eq!(Balances::reserved_balance(&provider_key.into()), 0);
+		System::assert_last_event(
+			Event::DelegationRequestRemoved { provider_id, delegator_id }.into(),
+		);
+	})
+}
+
+#[test]
+fn purging_a_retired_delegator_releases_deposits_of_its_requests() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (provider_msa_id, provider_key, delegator_msa_id, delegator_key_pair) =
+			create_pending_request();
+		assert_ok!(Msa::retire_msa(RuntimeOrigin::signed(delegator_key_pair.public().into())));
+
+		// act
+		Msa::purge_retired_msa(delegator_msa_id, Weight::MAX);
+
+		// assert
+		let delegator_id = DelegatorId(delegator_msa_id);
+		assert_eq!(
+			PendingDelegationRequests::<Test>::get(delegator_id, ProviderId(provider_msa_id)),
+			None
+		);
+		assert_eq!(PendingDelegationRequestCount::<Test>::get(delegator_id), 0);
+		assert_eq!(Balances::reserved_balance(&provider_key.into()), 0);
+	})
+}
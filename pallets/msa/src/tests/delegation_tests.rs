@@ -185,7 +185,7 @@ pub fn grant_delegation_to_msa_throws_add_provider_verification_failed() {
 		let account = key_pair.public();
 		let expiration: BlockNumber = 10;
 		let add_provider_payload = AddProvider::new(2, None, expiration);
-		let encode_add_provider_data = wrap_binary_data(add_provider_payload.encode());
+		let encode_add_provider_data = wrap_binary_data(add_provider_payload.signed_payload());
 
 		let signature: MultiSignature = key_pair.sign(&encode_add_provider_data).into();
 		let fake_provider_payload = AddProvider::new(3, None, expiration);
@@ -209,7 +209,7 @@ pub fn grant_delegation_throws_no_key_exist_error() {
 
 		let expiration: BlockNumber = 10;
 		let add_provider_payload = AddProvider::new(2, None, expiration);
-		let encode_add_provider_data = wrap_binary_data(add_provider_payload.encode());
+		let encode_add_provider_data = wrap_binary_data(add_provider_payload.signed_payload());
 
 		let signature: MultiSignature = key_pair.sign(&encode_add_provider_data).into();
 
@@ -233,7 +233,7 @@ pub fn grant_delegation_throws_key_revoked_error() {
 
 		let expiration: BlockNumber = 10;
 		let add_provider_payload = AddProvider::new(2, None, expiration);
-		let encode_add_provider_data = wrap_binary_data(add_provider_payload.encode());
+		let encode_add_provider_data = wrap_binary_data(add_provider_payload.signed_payload());
 
 		let signature: MultiSignature = key_pair.sign(&encode_add_provider_data).into();
 
@@ -261,7 +261,7 @@ pub fn grant_delegation_throws_invalid_self_provider_error() {
 
 		let expiration: BlockNumber = 10;
 		let add_provider_payload = AddProvider::new(1, None, expiration);
-		let encode_add_provider_data = wrap_binary_data(add_provider_payload.encode());
+		let encode_add_provider_data = wrap_binary_data(add_provider_payload.signed_payload());
 		let signature: MultiSignature = key_pair.sign(&encode_add_provider_data).into();
 
 		assert_ok!(Msa::create(RuntimeOrigin::signed(provider_account.into())));
@@ -294,7 +294,7 @@ pub fn grant_delegation_throws_unauthorized_delegator_error() {
 
 		let expiration: BlockNumber = 10;
 		let add_provider_payload = AddProvider::new(delegator_msa_id, None, expiration);
-		let encode_add_provider_data = wrap_binary_data(add_provider_payload.encode());
+		let encode_add_provider_data = wrap_binary_data(add_provider_payload.signed_payload());
 		let signature: MultiSignature = delegator_key_pair.sign(&encode_add_provider_data).into();
 
 		assert_ok!(Msa::create(RuntimeOrigin::signed(provider_account.into())));
@@ -484,7 +484,7 @@ pub fn revoke_delegation_by_provider_does_nothing_when_no_msa() {
 			Error::<Test>::DelegationNotFound
 		);
 
-		assert_ok!(Msa::add_provider(ProviderId(1), DelegatorId(2), Vec::default()));
+		assert_ok!(Msa::add_provider(ProviderId(1), DelegatorId(2), Vec::default(), None));
 		assert_ok!(Msa::revoke_provider(ProviderId(1), DelegatorId(2)));
 
 		// 3. when_delegation_expired
@@ -504,7 +504,7 @@ pub fn valid_delegation() {
 		let provider = ProviderId(1);
 		let delegator = DelegatorId(2);
 
-		assert_ok!(Msa::add_provider(provider, delegator, Vec::default()));
+		assert_ok!(Msa::add_provider(provider, delegator, Vec::default(), None));
 
 		System::set_block_number(System::block_number() + 1);
 
@@ -531,7 +531,7 @@ pub fn delegation_expired() {
 		let provider = ProviderId(1);
 		let delegator = DelegatorId(2);
 
-		assert_ok!(Msa::add_provider(provider, delegator, Vec::default()));
+		assert_ok!(Msa::add_provider(provider, delegator, Vec::default(), None));
 
 		System::set_block_number(System::block_number() + 1);
 		assert_ok!(Msa::ensure_valid_delegation(provider, delegator, None));
@@ -553,7 +553,7 @@ pub fn delegation_expired_long_back() {
 		let provider = ProviderId(1);
 		let delegator = DelegatorId(2);
 
-		assert_ok!(Msa::add_provider(provider, delegator, Vec::default()));
+		assert_ok!(Msa::add_provider(provider, delegator, Vec::default(), None));
 
 		System::set_block_number(System::block_number() + 100);
 		assert_ok!(Msa::ensure_valid_delegation(provider, delegator, None));
@@ -0,0 +1,203 @@
+use frame_support::{assert_noop, assert_ok};
+
+use sp_core::{crypto::AccountId32, ecdsa, Pair};
+use sp_runtime::MultiSignature;
+
+use crate::{
+	tests::mock::*,
+	types::{AddKeyData, AddProvider},
+	DelegatorAndProviderToDelegation, Error, Event, PublicKeyToMsaId,
+};
+
+use common_primitives::{
+	msa::{DelegatorId, ProviderId},
+	signatures::{ethereum_address_of, ethereum_address_to_account_id, sign_eip_712},
+	utils::wrap_binary_data,
+};
+
+fn ethereum_key_pair() -> (ecdsa::Pair, AccountId32) {
+	let (pair, _) = ecdsa::Pair::generate();
+	let account = ethereum_address_to_account_id(&ethereum_address_of(&pair));
+	(pair, account)
+}
+
+#[test]
+fn add_ethereum_key_signing_eip_712_payload_should_store_key() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (msa_id, owner_key_pair) = create_account();
+		let (ethereum_pair, ethereum_key) = ethereum_key_pair();
+
+		let add_key_payload = AddKeyData::<Test> {
+			msa_id,
+			expiration: 10,
+			new_public_key: ethereum_key.clone(),
+			scope: None,
+		};
+		let owner_signature: MultiSignature =
+			owner_key_pair.sign(&wrap_binary_data(add_key_payload.signed_payload())).into();
+		let new_key_signature =
+			sign_eip_712(&ethereum_pair, &TestEip712Domain::get(), &add_key_payload);
+
+		// act
+		assert_ok!(Msa::add_public_key_to_msa(
+			test_origin_signed(1),
+			owner_key_pair.public().into(),
+			owner_signature,
+			new_key_signature,
+			add_key_payload
+		));
+
+		// assert
+		assert_eq!(PublicKeyToMsaId::<Test>::get(&ethereum_key), Some(msa_id));
+		System::assert_last_event(Event::PublicKeyAdded { msa_id, key: ethereum_key }.into());
+	});
+}
+
+#[test]
+fn add_ethereum_key_signing_scale_payload_fails() {
+	new_test_ext().execute_with(|| {
+		let (msa_id, owner_key_pair) = create_account();
+		let (ethereum_pair, ethereum_key) = ethereum_key_pair();
+
+		let add_key_payload = AddKeyData::<Test> {
+			msa_id,
+			expiration: 10,
+			new_public_key: ethereum_key,
+			scope: None,
+		};
+		let encoded_payload = wrap_binary_data(add_key_payload.signed_payload());
+		let owner_signature: MultiSignature = owner_key_pair.sign(&encoded_payload).into();
+		let new_key_signature: MultiSignature = ethereum_pair.sign(&encoded_payload).into();
+
+		assert_noop!(
+			Msa::add_public_key_to_msa(
+				test_origin_signed(1),
+				owner_key_pair.public().into(),
+				owner_signature,
+				new_key_signature,
+				add_key_payload
+			),
+			Error::<Test>::NewKeyOwnershipInvalidSignature
+		);
+	});
+}
+
+#[test]
+fn add_ethereum_key_signed_by_other_ethereum_key_fails() {
+	new_test_ext().execute_with(|| {
+		let (msa_id, owner_key_pair) = create_account();
+		let (_, ethereum_key) = ethereum_key_pair();
+		let (other_ethereum_pair, _) = ethereum_key_pair();
+
+		let add_key_payload = AddKeyData::<Test> {
+			msa_id,
+			expiration: 10,
+			new_public_key: ethereum_key,
+			scope: None,
+		};
+		let owner_signature: MultiSignature =
+			owner_key_pair.sign(&wrap_binary_data(add_key_payload.signed_payload())).into();
+		let new_key_signature =
+			sign_eip_712(&other_ethereum_pair, &TestEip712Domain::get(), &add_key_payload);
+
+		assert_noop!(
+			Msa::add_public_key_to_msa(
+				test_origin_signed(1),
+				owner_key_pair.public().into(),
+				owner_signature,
+				new_key_signature,
+				add_key_payload
+			),
+			Error::<Test>::NewKeyOwnershipInvalidSignature
+		);
+	});
+}
+
+#[test]
+fn create_sponsored_account_for_ethereum_delegator_signing_eip_712_payload() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (provider_msa_id, provider_account) = create_provider_with_name("Foo");
+		let (ethereum_pair, ethereum_key) = ethereum_key_pair();
+
+		let add_provider_payload = AddProvider::new(provider_msa_id, None, 10);
+		let signature =
+			sign_eip_712(&ethereum_pair, &TestEip712Domain::get(), &add_provider_payload);
+
+		// act
+		assert_ok!(Msa::create_sponsored_account_with_delegation(
+			RuntimeOrigin::signed(provider_account.into()),
+			ethereum_key.clone(),
+			signature,
+			add_provider_payload
+		));
+
+		// assert
+		let delegator_msa_id = PublicKeyToMsaId::<Test>::get(&ethereum_key).unwrap();
+		assert!(DelegatorAndProviderToDelegation::<Test>::get(
+			DelegatorId(delegator_msa_id),
+			ProviderId(provider_msa_id)
+		)
+		.is_some());
+	});
+}
+
+#[test]
+fn create_sponsored_account_with_eip_712_signature_over_other_expiry_fails() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, provider_account) = create_provider_with_name("Foo");
+		let (ethereum_pair, ethereum_key) = ethereum_key_pair();
+
+		let signed_payload = AddProvider::new(provider_msa_id, None, 10);
+		let signature = sign_eip_712(&ethereum_pair, &TestEip712Domain::get(), &signed_payload);
+		let submitted_payload = signed_payload.with_expires_at(20);
+
+		assert_noop!(
+			Msa::create_sponsored_account_with_delegation(
+				RuntimeOrigin::signed(provider_account.into()),
+				ethereum_key,
+				signature,
+				submitted_payload
+			),
+			Error::<Test>::InvalidSignature
+		);
+	});
+}
+
+#[test]
+fn regranting_delegation_with_v_plus_27_form_of_eip_712_signature_fails() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (provider_msa_id, provider_account) = create_provider_with_name("Foo");
+		let (ethereum_pair, ethereum_key) = ethereum_key_pair();
+
+		let add_provider_payload = AddProvider::new(provider_msa_id, None, 10);
+		let signature =
+			sign_eip_712(&ethereum_pair, &TestEip712Domain::get(), &add_provider_payload);
+		assert_ok!(Msa::create_sponsored_account_with_delegation(
+			RuntimeOrigin::signed(provider_account.into()),
+			ethereum_key.clone(),
+			signature.clone(),
+			add_provider_payload.clone()
+		));
+		assert_ok!(Msa::revoke_delegation_by_delegator(
+			RuntimeOrigin::signed(ethereum_key.clone()),
+			provider_msa_id
+		));
+		let MultiSignature::Ecdsa(signature) = signature else { panic!("not an ecdsa signature") };
+		let mut with_27 = signature.0;
+		with_27[64] += 27;
+
+		// act and assert
+		assert_noop!(
+			Msa::grant_delegation(
+				RuntimeOrigin::signed(provider_account.into()),
+				ethereum_key,
+				MultiSignature::Ecdsa(ecdsa::Signature::from_raw(with_27)),
+				add_provider_payload
+			),
+			Error::<Test>::SignatureAlreadySubmitted
+		);
+	});
+}
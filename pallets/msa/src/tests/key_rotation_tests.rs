@@ -0,0 +1,230 @@
+use frame_support::{assert_noop, assert_ok};
+
+use sp_core::{crypto::AccountId32, sr25519, Encode, Pair};
+use sp_runtime::MultiSignature;
+
+use crate::{
+	tests::mock::*,
+	types::{RotateKeyData, EMPTY_FUNCTION},
+	Config, Error, Event, PublicKeyCountForMsaId, PublicKeyToMsaId,
+};
+
+use common_primitives::{msa::MessageSourceId, utils::wrap_binary_data};
+
+fn sign_rotate_key_payload(
+	msa_id: MessageSourceId,
+	old_key_pair: &sr25519::Pair,
+	new_key_pair: &sr25519::Pair,
+) -> (RotateKeyData<Test>, MultiSignature, MultiSignature) {
+	let rotate_key_payload = RotateKeyData::<Test> {
+		msa_id,
+		expiration: 10,
+		new_public_key: new_key_pair.public().into(),
+		old_public_key: old_key_pair.public().into(),
+	};
+	let encoded_payload = wrap_binary_data(rotate_key_payload.encode());
+	let old_key_signature: MultiSignature = old_key_pair.sign(&encoded_payload).into();
+	let new_key_signature: MultiSignature = new_key_pair.sign(&encoded_payload).into();
+	(rotate_key_payload, old_key_signature, new_key_signature)
+}
+
+#[test]
+fn rotate_key_with_valid_request_should_swap_keys_and_emit_event() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (msa_id, old_key_pair) = create_account();
+		let (new_key_pair, _) = sr25519::Pair::generate();
+		let (payload, old_key_signature, new_key_signature) =
+			sign_rotate_key_payload(msa_id, &old_key_pair, &new_key_pair);
+
+		// act
+		assert_ok!(Msa::rotate_msa_public_key(
+			test_origin_signed(1),
+			old_key_signature,
+			new_key_signature,
+			payload
+		));
+
+		// assert
+		assert_eq!(
+			PublicKeyToMsaId::<Test>::get(AccountId32::from(new_key_pair.public())),
+			Some(msa_id)
+		);
+		assert_eq!(PublicKeyToMsaId::<Test>::get(AccountId32::from(old_key_pair.public())), None);
+		assert_eq!(PublicKeyCountForMsaId::<Test>::get(msa_id), 1);
+		System::assert_last_event(
+			Event::PublicKeyRotated {
+				msa_id,
+				old_key: old_key_pair.public().into(),
+				new_key: new_key_pair.public().into(),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn rotate_key_with_invalid_old_key_signature_fails() {
+	new_test_ext().execute_with(|| {
+		let (msa_id, old_key_pair) = create_account();
+		let (new_key_pair, _) = sr25519::Pair::generate();
+		let (fake_key_pair, _) = sr25519::Pair::generate();
+		let (payload, _, new_key_signature) =
+			sign_rotate_key_payload(msa_id, &old_key_pair, &new_key_pair);
+		let fake_signature: MultiSignature =
+			fake_key_pair.sign(&wrap_binary_data(payload.encode())).into();
+
+		assert_noop!(
+			Msa::rotate_msa_public_key(
+				test_origin_signed(1),
+				fake_signature,
+				new_key_signature,
+				payload
+			),
+			Error::<Test>::MsaOwnershipInvalidSignature
+		);
+	});
+}
+
+#[test]
+fn rotate_key_with_invalid_new_key_signature_fails() {
+	new_test_ext().execute_with(|| {
+		let (msa_id, old_key_pair) = create_account();
+		let (new_key_pair, _) = sr25519::Pair::generate();
+		let (fake_key_pair, _) = sr25519::Pair::generate();
+		let (payload, old_key_signature, _) =
+			sign_rotate_key_payload(msa_id, &old_key_pair, &new_key_pair);
+		let fake_signature: MultiSignature =
+			fake_key_pair.sign(&wrap_binary_data(payload.encode())).into();
+
+		assert_noop!(
+			Msa::rotate_msa_public_key(
+				test_origin_signed(1),
+				old_key_signature,
+				fake_signature,
+				payload
+			),
+			Error::<Test>::NewKeyOwnershipInvalidSignature
+		);
+	});
+}
+
+#[test]
+fn rotate_key_not_owned_by_msa_fails() {
+	new_test_ext().execute_with(|| {
+		let (msa_id, _) = create_account();
+		let (_other_msa_id, other_key_pair) = create_account();
+		let (new_key_pair, _) = sr25519::Pair::generate();
+		let (payload, old_key_signature, new_key_signature) =
+			sign_rotate_key_payload(msa_id, &other_key_pair, &new_key_pair);
+
+		assert_noop!(
+			Msa::rotate_msa_public_key(
+				test_origin_signed(1),
+				old_key_signature,
+				new_key_signature,
+				payload
+			),
+			Error::<Test>::NotMsaOwner
+		);
+	});
+}
+
+#[test]
+fn rotate_key_to_registered_key_fails() {
+	new_test_ext().execute_with(|| {
+		let (msa_id, old_key_pair) = create_account();
+		let (_other_msa_id, other_key_pair) = create_account();
+		let (payload, old_key_signature, new_key_signature) =
+			sign_rotate_key_payload(msa_id, &old_key_pair, &other_key_pair);
+
+		assert_noop!(
+			Msa::rotate_msa_public_key(
+				test_origin_signed(1),
+				old_key_signature,
+				new_key_signature,
+				payload
+			),
+			Error::<Test>::KeyAlreadyRegistered
+		);
+	});
+}
+
+#[test]
+fn rotate_key_with_expired_proof_fails() {
+	new_test_ext().execute_with(|| {
+		let (msa_id, old_key_pair) = create_account();
+		let (new_key_pair, _) = sr25519::Pair::generate();
+		let (payload, old_key_signature, new_key_signature) =
+			sign_rotate_key_payload(msa_id, &old_key_pair, &new_key_pair);
+
+		run_to_block(11);
+
+		assert_noop!(
+			Msa::rotate_msa_public_key(
+				test_origin_signed(1),
+				old_key_signature,
+				new_key_signature,
+				payload
+			),
+			Error::<Test>::ProofHasExpired
+		);
+	});
+}
+
+#[test]
+fn rotate_key_succeeds_when_msa_has_max_keys() {
+	new_test_ext().execute_with(|| {
+		let (msa_id, old_key_pair) = create_account();
+		for _ in 1..<Test as Config>::MaxPublicKeysPerMsa::get() {
+			let (key_pair, _) = sr25519::Pair::generate();
+			assert_ok!(Msa::add_key(msa_id, &key_pair.public().into(), EMPTY_FUNCTION));
+		}
+		let (new_key_pair, _) = sr25519::Pair::generate();
+		let (payload, old_key_signature, new_key_signature) =
+			sign_rotate_key_payload(msa_id, &old_key_pair, &new_key_pair);
+
+		assert_ok!(Msa::rotate_msa_public_key(
+			test_origin_signed(1),
+			old_key_signature,
+			new_key_signature,
+			payload
+		));
+
+		assert_eq!(
+			PublicKeyCountForMsaId::<Test>::get(msa_id),
+			<Test as Config>::MaxPublicKeysPerMsa::get()
+		);
+		assert_eq!(
+			PublicKeyToMsaId::<Test>::get(AccountId32::from(new_key_pair.public())),
+			Some(msa_id)
+		);
+	});
+}
+
+#[test]
+fn rotate_key_signatures_cannot_be_replayed() {
+	new_test_ext().execute_with(|| {
+		let (msa_id, old_key_pair) = create_account();
+		let (new_key_pair, _) = sr25519::Pair::generate();
+		let (payload, old_key_signature, new_key_signature) =
+			sign_rotate_key_payload(msa_id, &old_key_pair, &new_key_pair);
+
+		assert_ok!(Msa::rotate_msa_public_key(
+			test_origin_signed(1),
+			old_key_signature.clone(),
+			new_key_signature.clone(),
+			payload.clone()
+		));
+
+		assert_noop!(
+			Msa::rotate_msa_public_key(
+				test_origin_signed(1),
+				old_key_signature,
+				new_key_signature,
+				payload
+			),
+			Error::<Test>::SignatureAlreadySubmitted
+		);
+	});
+}
@@ -0,0 +1,429 @@
+use frame_support::{assert_noop, assert_ok, pallet_prelude::InvalidTransaction, BoundedVec};
+
+use sp_core::{crypto::AccountId32, sr25519, Encode, Pair};
+use sp_runtime::MultiSignature;
+
+use crate::{
+	tests::{mock::*, other_tests::set_schema_count},
+	types::{AddKeyData, KeyScope, RotateKeyData},
+	CheckFreeExtrinsicUse, Error, PublicKeyScopes, PublicKeyToMsaId, ValidityError,
+};
+
+use common_primitives::{
+	msa::{MessageSourceId, SchemaId, SchemaPermissions},
+	utils::wrap_binary_data,
+};
+
+fn key_scope(
+	can_manage_msa: bool,
+	schema_ids: Option<Vec<SchemaId>>,
+) -> KeyScope<MaxSchemaGrantsPerDelegation> {
+	KeyScope {
+		can_manage_msa,
+		schema_ids: schema_ids.map(|ids| BoundedVec::try_from(ids).unwrap()),
+	}
+}
+
+fn sign_add_key_payload(
+	msa_id: MessageSourceId,
+	owner_key_pair: &sr25519::Pair,
+	new_key_pair: &sr25519::Pair,
+	scope: Option<KeyScope<MaxSchemaGrantsPerDelegation>>,
+) -> (AddKeyData<Test>, MultiSignature, MultiSignature) {
+	let add_key_payload = AddKeyData::<Test> {
+		msa_id,
+		expiration: 10,
+		new_public_key: new_key_pair.public().into(),
+		scope,
+	};
+	let encoded_payload = wrap_binary_data(add_key_payload.signed_payload());
+	let owner_signature: MultiSignature = owner_key_pair.sign(&encoded_payload).into();
+	let new_key_signature: MultiSignature = new_key_pair.sign(&encoded_payload).into();
+	(add_key_payload, owner_signature, new_key_signature)
+}
+
+/// Adds a new key with `scope` to the MSA of `owner_key_pair` and returns its key pair.
+fn add_scoped_key(
+	msa_id: MessageSourceId,
+	owner_key_pair: &sr25519::Pair,
+	scope: KeyScope<MaxSchemaGrantsPerDelegation>,
+) -> sr25519::Pair {
+	let (scoped_key_pair, _) = sr25519::Pair::generate();
+	let (payload, owner_signature, new_key_signature) =
+		sign_add_key_payload(msa_id, owner_key_pair, &scoped_key_pair, Some(scope));
+
+	assert_ok!(Msa::add_public_key_to_msa(
+		test_origin_signed(1),
+		owner_key_pair.public().into(),
+		owner_signature,
+		new_key_signature,
+		payload
+	));
+
+	scoped_key_pair
+}
+
+#[test]
+fn add_public_key_to_msa_with_scope_should_store_the_scope() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		set_schema_count::<Test>(2);
+		let (msa_id, owner_key_pair) = create_account();
+		let scope = key_scope(false, Some(vec![1, 2]));
+
+		// act
+		let scoped_key_pair = add_scoped_key(msa_id, &owner_key_pair, scope.clone());
+
+		// assert
+		let scoped_key = AccountId32::from(scoped_key_pair.public());
+		assert_eq!(PublicKeyToMsaId::<Test>::get(&scoped_key), Some(msa_id));
+		assert_eq!(PublicKeyScopes::<Test>::get(&scoped_key), Some(scope));
+		assert_eq!(PublicKeyScopes::<Test>::get(AccountId32::from(owner_key_pair.public())), None);
+	});
+}
+
+#[test]
+fn add_public_key_to_msa_with_invalid_schema_in_scope_fails() {
+	new_test_ext().execute_with(|| {
+		set_schema_count::<Test>(2);
+		let (msa_id, owner_key_pair) = create_account();
+		let (new_key_pair, _) = sr25519::Pair::generate();
+		let (payload, owner_signature, new_key_signature) = sign_add_key_payload(
+			msa_id,
+			&owner_key_pair,
+			&new_key_pair,
+			Some(key_scope(true, Some(vec![1, 3]))),
+		);
+
+		assert_noop!(
+			Msa::add_public_key_to_msa(
+				test_origin_signed(1),
+				owner_key_pair.public().into(),
+				owner_signature,
+				new_key_signature,
+				payload
+			),
+			Error::<Test>::InvalidSchemaId
+		);
+	});
+}
+
+#[test]
+fn add_public_key_to_msa_with_stripped_scope_fails() {
+	new_test_ext().execute_with(|| {
+		set_schema_count::<Test>(2);
+		let (msa_id, owner_key_pair) = create_account();
+		let (new_key_pair, _) = sr25519::Pair::generate();
+		let (mut payload, owner_signature, new_key_signature) = sign_add_key_payload(
+			msa_id,
+			&owner_key_pair,
+			&new_key_pair,
+			Some(key_scope(false, None)),
+		);
+		payload.scope = None;
+
+		assert_noop!(
+			Msa::add_public_key_to_msa(
+				test_origin_signed(1),
+				owner_key_pair.public().into(),
+				owner_signature,
+				new_key_signature,
+				payload
+			),
+			Error::<Test>::MsaOwnershipInvalidSignature
+		);
+	});
+}
+
+#[test]
+fn scoped_key_without_msa_management_cannot_add_keys() {
+	new_test_ext().execute_with(|| {
+		let (msa_id, owner_key_pair) = create_account();
+		let scoped_key_pair = add_scoped_key(msa_id, &owner_key_pair, key_scope(false, None));
+		let (new_key_pair, _) = sr25519::Pair::generate();
+		let (payload, scoped_key_signature, new_key_signature) =
+			sign_add_key_payload(msa_id, &scoped_key_pair, &new_key_pair, None);
+
+		assert_noop!(
+			Msa::add_public_key_to_msa(
+				test_origin_signed(1),
+				scoped_key_pair.public().into(),
+				scoped_key_signature,
+				new_key_signature,
+				payload
+			),
+			Error::<Test>::KeyScopeRestricted
+		);
+	});
+}
+
+#[test]
+fn scoped_key_with_msa_management_can_add_keys() {
+	new_test_ext().execute_with(|| {
+		set_schema_count::<Test>(1);
+		let (msa_id, owner_key_pair) = create_account();
+		let scoped_key_pair =
+			add_scoped_key(msa_id, &owner_key_pair, key_scope(true, Some(vec![1])));
+
+		add_scoped_key(msa_id, &scoped_key_pair, key_scope(false, Some(vec![1])));
+	});
+}
+
+#[test]
+fn scoped_key_cannot_add_keys_with_a_wider_scope() {
+	new_test_ext().execute_with(|| {
+		set_schema_count::<Test>(2);
+		let (msa_id, owner_key_pair) = create_account();
+		let scoped_key_pair =
+			add_scoped_key(msa_id, &owner_key_pair, key_scope(true, Some(vec![1])));
+
+		for scope in [None, Some(key_scope(true, None)), Some(key_scope(false, Some(vec![1, 2])))] {
+			let (new_key_pair, _) = sr25519::Pair::generate();
+			let (payload, scoped_key_signature, new_key_signature) =
+				sign_add_key_payload(msa_id, &scoped_key_pair, &new_key_pair, scope);
+
+			assert_noop!(
+				Msa::add_public_key_to_msa(
+					test_origin_signed(1),
+					scoped_key_pair.public().into(),
+					scoped_key_signature,
+					new_key_signature,
+					payload
+				),
+				Error::<Test>::KeyScopeRestricted
+			);
+		}
+	});
+}
+
+#[test]
+fn scoped_key_without_msa_management_cannot_delete_keys() {
+	new_test_ext().execute_with(|| {
+		let (msa_id, owner_key_pair) = create_account();
+		let scoped_key_pair = add_scoped_key(msa_id, &owner_key_pair, key_scope(false, None));
+		let scoped_key = AccountId32::from(scoped_key_pair.public());
+		let owner_key = AccountId32::from(owner_key_pair.public());
+
+		assert_noop!(
+			CheckFreeExtrinsicUse::<Test>::validate_key_delete(&scoped_key, &owner_key),
+			InvalidTransaction::Custom(ValidityError::KeyScopeRestricted as u8)
+		);
+		assert_ok!(CheckFreeExtrinsicUse::<Test>::validate_key_delete(&owner_key, &scoped_key));
+	});
+}
+
+#[test]
+fn deleting_a_scoped_key_removes_its_scope() {
+	new_test_ext().execute_with(|| {
+		let (msa_id, owner_key_pair) = create_account();
+		let scoped_key_pair = add_scoped_key(msa_id, &owner_key_pair, key_scope(false, None));
+		let scoped_key = AccountId32::from(scoped_key_pair.public());
+
+		assert_ok!(Msa::delete_msa_public_key(
+			RuntimeOrigin::signed(owner_key_pair.public().into()),
+			scoped_key.clone()
+		));
+
+		assert_eq!(PublicKeyToMsaId::<Test>::get(&scoped_key), None);
+		assert_eq!(PublicKeyScopes::<Test>::get(&scoped_key), None);
+	});
+}
+
+#[test]
+fn scoped_key_without_msa_management_cannot_grant_delegation() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, provider_key) = create_provider_with_name("Foo");
+		let (msa_id, owner_key_pair) = create_account();
+		let scoped_key_pair = add_scoped_key(msa_id, &owner_key_pair, key_scope(false, None));
+		let (signature, add_provider_payload) =
+			create_and_sign_add_provider_payload(scoped_key_pair.clone(), provider_msa_id);
+
+		assert_noop!(
+			Msa::grant_delegation(
+				RuntimeOrigin::signed(provider_key.into()),
+				scoped_key_pair.public().into(),
+				signature,
+				add_provider_payload
+			),
+			Error::<Test>::KeyScopeRestricted
+		);
+	});
+}
+
+#[test]
+fn scoped_key_without_msa_management_cannot_call_msa_management_extrinsics() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (provider_msa_id, owner_key_pair) = create_account();
+		assert_ok!(Msa::create_provider(
+			RuntimeOrigin::signed(owner_key_pair.public().into()),
+			Vec::from("Foo")
+		));
+		let scoped_key_pair =
+			add_scoped_key(provider_msa_id, &owner_key_pair, key_scope(false, None));
+		let origin = || RuntimeOrigin::signed(scoped_key_pair.public().into());
+		let (other_msa_id, _) = create_account();
+
+		// act / assert
+		assert_noop!(
+			Msa::create_provider(origin(), Vec::from("Bar")),
+			Error::<Test>::KeyScopeRestricted
+		);
+		assert_noop!(
+			Msa::set_provider_metadata(origin(), vec![], vec![], vec![], None, vec![]),
+			Error::<Test>::KeyScopeRestricted
+		);
+		assert_noop!(Msa::retire_provider(origin()), Error::<Test>::KeyScopeRestricted);
+		assert_noop!(
+			Msa::request_delegation(origin(), other_msa_id, vec![], 10, None),
+			Error::<Test>::KeyScopeRestricted
+		);
+		assert_noop!(
+			Msa::grant_sub_delegation(origin(), other_msa_id, vec![]),
+			Error::<Test>::KeyScopeRestricted
+		);
+		assert_noop!(
+			Msa::revoke_sub_delegation(origin(), other_msa_id),
+			Error::<Test>::KeyScopeRestricted
+		);
+		assert_noop!(
+			Msa::revoke_schema_permissions(origin(), other_msa_id, vec![1]),
+			Error::<Test>::KeyScopeRestricted
+		);
+		assert_noop!(
+			Msa::set_schema_grant_permissions(
+				origin(),
+				other_msa_id,
+				1,
+				SchemaPermissions { create: true, update: false, delete: false }
+			),
+			Error::<Test>::KeyScopeRestricted
+		);
+		assert_noop!(
+			Msa::set_recovery_config(
+				origin(),
+				BoundedVec::try_from(vec![other_msa_id]).unwrap(),
+				1,
+				None
+			),
+			Error::<Test>::KeyScopeRestricted
+		);
+		assert_noop!(Msa::remove_recovery_config(origin()), Error::<Test>::KeyScopeRestricted);
+		assert_noop!(Msa::cancel_recovery(origin()), Error::<Test>::KeyScopeRestricted);
+		assert_noop!(
+			Msa::propose_to_change_provider_name(origin(), Vec::from("Bar")),
+			Error::<Test>::KeyScopeRestricted
+		);
+		assert_noop!(
+			Msa::revoke_delegation_by_provider(origin(), other_msa_id),
+			Error::<Test>::KeyScopeRestricted
+		);
+		assert_noop!(
+			CheckFreeExtrinsicUse::<Test>::validate_delegation_by_provider(
+				&scoped_key_pair.public().into(),
+				&other_msa_id
+			),
+			InvalidTransaction::Custom(ValidityError::KeyScopeRestricted as u8)
+		);
+	});
+}
+
+/// Calls wrapped in `Utility::batch` skip `CheckFreeExtrinsicUse`, so the dispatchables
+/// themselves must reject a scoped key.
+#[test]
+fn scoped_key_without_msa_management_cannot_delete_keys_revoke_delegations_or_retire_in_dispatch() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (provider_msa_id, provider_key) = create_provider_with_name("Foo");
+		let (msa_id, owner_key_pair) = create_account();
+		let (signature, add_provider_payload) =
+			create_and_sign_add_provider_payload(owner_key_pair.clone(), provider_msa_id);
+		assert_ok!(Msa::grant_delegation(
+			RuntimeOrigin::signed(provider_key.into()),
+			owner_key_pair.public().into(),
+			signature,
+			add_provider_payload
+		));
+		let scoped_key_pair = add_scoped_key(msa_id, &owner_key_pair, key_scope(false, None));
+		let origin = || RuntimeOrigin::signed(scoped_key_pair.public().into());
+
+		// act / assert
+		assert_noop!(
+			Msa::delete_msa_public_key(origin(), owner_key_pair.public().into()),
+			Error::<Test>::KeyScopeRestricted
+		);
+		assert_noop!(
+			Msa::revoke_delegation_by_delegator(origin(), provider_msa_id),
+			Error::<Test>::KeyScopeRestricted
+		);
+		assert_ok!(Msa::delete_key_for_msa(msa_id, &owner_key_pair.public().into()));
+		assert_noop!(Msa::retire_msa(origin()), Error::<Test>::KeyScopeRestricted);
+	});
+}
+
+#[test]
+fn scoped_key_without_msa_management_cannot_retire_msa() {
+	new_test_ext().execute_with(|| {
+		let (msa_id, owner_key_pair) = create_account();
+		let scoped_key_pair = add_scoped_key(msa_id, &owner_key_pair, key_scope(false, None));
+		assert_ok!(Msa::delete_key_for_msa(msa_id, &owner_key_pair.public().into()));
+
+		assert_noop!(
+			CheckFreeExtrinsicUse::<Test>::ensure_msa_can_retire(&scoped_key_pair.public().into()),
+			InvalidTransaction::Custom(ValidityError::KeyScopeRestricted as u8)
+		);
+	});
+}
+
+#[test]
+fn rotating_a_scoped_key_moves_its_scope_to_the_new_key() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		set_schema_count::<Test>(1);
+		let (msa_id, owner_key_pair) = create_account();
+		let scope = key_scope(true, Some(vec![1]));
+		let scoped_key_pair = add_scoped_key(msa_id, &owner_key_pair, scope.clone());
+		let (new_key_pair, _) = sr25519::Pair::generate();
+		let rotate_key_payload = RotateKeyData::<Test> {
+			msa_id,
+			expiration: 10,
+			new_public_key: new_key_pair.public().into(),
+			old_public_key: scoped_key_pair.public().into(),
+		};
+		let encoded_payload = wrap_binary_data(rotate_key_payload.encode());
+
+		// act
+		assert_ok!(Msa::rotate_msa_public_key(
+			test_origin_signed(1),
+			scoped_key_pair.sign(&encoded_payload).into(),
+			new_key_pair.sign(&encoded_payload).into(),
+			rotate_key_payload
+		));
+
+		// assert
+		assert_eq!(PublicKeyScopes::<Test>::get(AccountId32::from(scoped_key_pair.public())), None);
+		assert_eq!(
+			PublicKeyScopes::<Test>::get(AccountId32::from(new_key_pair.public())),
+			Some(scope)
+		);
+	});
+}
+
+#[test]
+fn ensure_key_allows_schema_only_allows_schemas_in_scope() {
+	new_test_ext().execute_with(|| {
+		set_schema_count::<Test>(2);
+		let (msa_id, owner_key_pair) = create_account();
+		let scoped_key_pair =
+			add_scoped_key(msa_id, &owner_key_pair, key_scope(true, Some(vec![1])));
+		let scoped_key = AccountId32::from(scoped_key_pair.public());
+		let owner_key = AccountId32::from(owner_key_pair.public());
+
+		assert_ok!(Msa::ensure_key_allows_schema(&scoped_key, 1));
+		assert_noop!(
+			Msa::ensure_key_allows_schema(&scoped_key, 2),
+			Error::<Test>::KeyScopeRestricted
+		);
+		assert_ok!(Msa::ensure_key_allows_schema(&owner_key, 2));
+	});
+}
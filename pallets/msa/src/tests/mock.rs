@@ -1,11 +1,12 @@
 use crate::{self as pallet_msa, types::EMPTY_FUNCTION, AddProvider};
 use common_primitives::{
-	msa::MessageSourceId, node::BlockNumber, schema::SchemaId, utils::wrap_binary_data,
+	msa::MessageSourceId, node::BlockNumber, schema::SchemaId, signatures::EIP712Domain,
+	utils::wrap_binary_data,
 };
 use common_runtime::constants::DAYS;
 use frame_support::{
 	assert_ok, parameter_types,
-	traits::{ConstU16, ConstU32, EitherOfDiverse, OnFinalize, OnInitialize},
+	traits::{ConstU16, ConstU32, ConstU64, EitherOfDiverse, OnFinalize, OnInitialize},
 	weights::Weight,
 };
 use frame_system::EnsureRoot;
@@ -37,7 +38,8 @@ frame_support::construct_runtime!(
 	pub enum Test
 	{
 		System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
-		Msa: pallet_msa::{Pallet, Call, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Msa: pallet_msa::{Pallet, Call, Storage, Event<T>, HoldReason},
 		Schemas: pallet_schemas::{Pallet, Call, Storage, Event<T>},
 		Council: pallet_collective::<Instance1>::{Pallet, Call, Config<T,I>, Storage, Event<T>, Origin<T>},
 		Handles: pallet_handles::{Pallet, Call, Storage, Event<T>},
@@ -93,7 +95,7 @@ impl frame_system::Config for Test {
 	type BlockHashCount = ConstU32<250>;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u64>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -107,6 +109,22 @@ impl frame_system::Config for Test {
 	type PostTransactions = ();
 }
 
+impl pallet_balances::Config for Test {
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type MaxLocks = ConstU32<10>;
+	type Balance = u64;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ConstU32<0>;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type RuntimeFreezeReason = ();
+}
+
 impl pallet_schemas::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
@@ -124,6 +142,18 @@ impl pallet_schemas::Config for Test {
 		EnsureRoot<AccountId>,
 		pallet_collective::EnsureProportionMoreThan<AccountId, CouncilCollective, 1, 2>,
 	>;
+	type MsaInfoProvider = Msa;
+	type ProviderValidator = Msa;
+	#[cfg(feature = "runtime-benchmarks")]
+	type MsaBenchmarkHelper = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type ProviderBenchmarkHelper = ();
+}
+
+// Needs parameter_types! for the EIP712Domain
+parameter_types! {
+	pub const TestEip712Domain: EIP712Domain =
+		EIP712Domain { chain_id: 0x190f_1b44, verifying_contract: [0xCC; 20] };
 }
 
 impl pallet_handles::Config for Test {
@@ -146,6 +176,7 @@ impl pallet_handles::Config for Test {
 
 	/// The mortality window for a handle claim
 	type MortalityWindowSize = ConstU32<150>;
+	type Eip712Domain = TestEip712Domain;
 
 	/// A set of helper functions for benchmarking.
 	#[cfg(feature = "runtime-benchmarks")]
@@ -158,6 +189,7 @@ parameter_types! {
 	pub static MaxSignaturesStored: Option<u32> = Some(8000);
 }
 pub type MaxProviderNameSize = ConstU32<16>;
+pub type MaxProviderMetadataFieldSize = ConstU32<64>;
 pub type MaxSchemaGrantsPerDelegation = ConstU32<30>;
 
 /// Interface to collective pallet to propose a proposal.
@@ -191,14 +223,20 @@ impl pallet_msa::ProposalProvider<AccountId, RuntimeCall> for CouncilProposalPro
 
 impl pallet_msa::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type WeightInfo = ();
+	type Currency = Balances;
 	type ConvertIntoAccountId32 = ConvertInto;
 	type MaxPublicKeysPerMsa = MaxPublicKeysPerMsa;
 	type MaxSchemaGrantsPerDelegation = MaxSchemaGrantsPerDelegation;
 	type MaxProviderNameSize = MaxProviderNameSize;
+	type MaxProviderMetadataFieldSize = MaxProviderMetadataFieldSize;
 	type SchemaValidator = Schemas;
 	type HandleProvider = Handles;
+	type MsaDataPurger = Handles;
+	type MsaDataMerger = Handles;
 	type MortalityWindowSize = ConstU32<100>;
+	type Eip712Domain = TestEip712Domain;
 	type MaxSignaturesStored = MaxSignaturesStored;
 	// The proposal type
 	type Proposal = RuntimeCall;
@@ -210,6 +248,16 @@ impl pallet_msa::Config for Test {
 		EnsureRoot<AccountId>,
 		pallet_collective::EnsureMembers<AccountId, CouncilCollective, 1>,
 	>;
+	type MaxRecoveryGuardians = ConstU32<5>;
+	type RecoveryDelay = ConstU32<10>;
+	type ProviderRetirementGracePeriod = ConstU32<10>;
+	type MaxSubDelegationDepth = ConstU32<3>;
+	type MaxSubDelegationsPerProvider = ConstU32<4>;
+	type MaxSubDelegationLookups = ConstU32<8>;
+	type DelegationRequestDeposit = ConstU64<10>;
+	type MaxPendingDelegationRequests = ConstU32<2>;
+	type MaxDelegationRequestLifetime = ConstU32<100>;
+	type MaxThresholdProposalsPerMsa = ConstU32<2>;
 }
 
 pub fn set_max_signature_stored(max: u32) {
@@ -295,7 +343,7 @@ pub fn create_and_sign_add_provider_payload_with_schemas(
 	expiration: BlockNumber,
 ) -> (MultiSignature, AddProvider) {
 	let add_provider_payload = AddProvider::new(provider_msa, schema_ids, expiration);
-	let encode_add_provider_data = wrap_binary_data(add_provider_payload.encode());
+	let encode_add_provider_data = wrap_binary_data(add_provider_payload.signed_payload());
 	let signature: MultiSignature = delegator_pair.sign(&encode_add_provider_data).into();
 	(signature, add_provider_payload)
 }
@@ -1,13 +1,26 @@
 pub mod mock;
 
 mod creation_tests;
+mod delegation_expiry_tests;
+mod delegation_request_tests;
 mod delegation_tests;
+mod ethereum_key_tests;
 mod governance_tests;
+mod key_rotation_tests;
+mod key_scope_tests;
+mod msa_merge_tests;
+mod msa_purge_tests;
 mod offchain_tests;
 mod other_tests;
+mod provider_delegators_tests;
+mod provider_metadata_tests;
+mod provider_retirement_tests;
 mod public_key_tests;
+mod recovery_tests;
 mod replay_tests;
 mod retirement_tests;
 mod schema_permission_tests;
 mod signature_registry_tests;
 mod signed_extension_tests;
+mod sub_delegation_tests;
+mod threshold_policy_tests;
@@ -0,0 +1,444 @@
+use frame_support::{assert_noop, assert_ok};
+use frame_system::pallet_prelude::BlockNumberFor;
+
+use pretty_assertions::assert_eq;
+use sp_core::{crypto::AccountId32, sr25519, Encode, Pair};
+use sp_runtime::MultiSignature;
+
+use crate::{
+	tests::{mock::*, other_tests::set_schema_count},
+	types::MergeMsaData,
+	DelegatorAndProviderToDelegation, Error, Event, MsaMergeQueue, MsaRedirects,
+	PublicKeyCountForMsaId, PublicKeyToMsaId, RetiredMsaPurgeQueue,
+};
+
+use common_primitives::{
+	handles::ClaimHandlePayload,
+	msa::{DelegatorId, MessageSourceId, ProviderId},
+	utils::wrap_binary_data,
+};
+
+fn sign_merge_payload(
+	source_msa_id: MessageSourceId,
+	source_key_pair: &sr25519::Pair,
+	target_msa_id: MessageSourceId,
+	target_key_pair: &sr25519::Pair,
+) -> (MergeMsaData<Test>, MultiSignature, MultiSignature) {
+	let merge_payload = MergeMsaData::<Test> {
+		source_msa_id,
+		source_public_key: source_key_pair.public().into(),
+		target_msa_id,
+		target_public_key: target_key_pair.public().into(),
+		expiration: 10,
+	};
+	let encoded_payload = wrap_binary_data(merge_payload.encode());
+	let source_key_signature: MultiSignature = source_key_pair.sign(&encoded_payload).into();
+	let target_key_signature: MultiSignature = target_key_pair.sign(&encoded_payload).into();
+	(merge_payload, source_key_signature, target_key_signature)
+}
+
+/// Merges a new MSA into another new MSA and returns the source and target MSA ids
+fn merge_new_msas() -> (MessageSourceId, MessageSourceId) {
+	let (source_msa_id, source_key_pair) = create_account();
+	let (target_msa_id, target_key_pair) = create_account();
+	let (payload, source_signature, target_signature) =
+		sign_merge_payload(source_msa_id, &source_key_pair, target_msa_id, &target_key_pair);
+	assert_ok!(Msa::merge_msa(
+		test_origin_signed(1),
+		source_signature,
+		target_signature,
+		payload,
+		vec![source_key_pair.public().into()]
+	));
+	(source_msa_id, target_msa_id)
+}
+
+#[test]
+fn merge_msa_moves_keys_and_retires_source() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (source_msa_id, source_key_pair) = create_account();
+		let (target_msa_id, target_key_pair) = create_account();
+		let (payload, source_signature, target_signature) =
+			sign_merge_payload(source_msa_id, &source_key_pair, target_msa_id, &target_key_pair);
+		let source_key = AccountId32::from(source_key_pair.public());
+
+		// act
+		assert_ok!(Msa::merge_msa(
+			test_origin_signed(1),
+			source_signature,
+			target_signature,
+			payload,
+			vec![source_key.clone()]
+		));
+
+		// assert
+		assert_eq!(PublicKeyToMsaId::<Test>::get(&source_key), Some(target_msa_id));
+		assert_eq!(PublicKeyCountForMsaId::<Test>::get(source_msa_id), 0);
+		assert_eq!(PublicKeyCountForMsaId::<Test>::get(target_msa_id), 2);
+		assert_eq!(MsaRedirects::<Test>::get(source_msa_id), Some(target_msa_id));
+		assert_eq!(MsaMergeQueue::<Test>::get(source_msa_id), Some(target_msa_id));
+		System::assert_has_event(Event::PublicKeyDeleted { key: source_key.clone() }.into());
+		System::assert_has_event(
+			Event::PublicKeyAdded { msa_id: target_msa_id, key: source_key }.into(),
+		);
+		System::assert_has_event(Event::MsaMerged { source_msa_id, target_msa_id }.into());
+		System::assert_last_event(Event::MsaRetired { msa_id: source_msa_id }.into());
+	})
+}
+
+#[test]
+fn merge_msa_into_self_fails() {
+	new_test_ext().execute_with(|| {
+		let (msa_id, key_pair) = create_account();
+		let (payload, source_signature, target_signature) =
+			sign_merge_payload(msa_id, &key_pair, msa_id, &key_pair);
+
+		assert_noop!(
+			Msa::merge_msa(
+				test_origin_signed(1),
+				source_signature,
+				target_signature,
+				payload,
+				vec![key_pair.public().into()]
+			),
+			Error::<Test>::MsaMergeIntoSelf
+		);
+	})
+}
+
+#[test]
+fn merge_msa_with_signature_of_another_key_fails() {
+	new_test_ext().execute_with(|| {
+		let (source_msa_id, source_key_pair) = create_account();
+		let (target_msa_id, target_key_pair) = create_account();
+		let (payload, source_signature, _) =
+			sign_merge_payload(source_msa_id, &source_key_pair, target_msa_id, &target_key_pair);
+
+		assert_noop!(
+			Msa::merge_msa(
+				test_origin_signed(1),
+				source_signature.clone(),
+				source_signature,
+				payload,
+				vec![source_key_pair.public().into()]
+			),
+			Error::<Test>::MsaOwnershipInvalidSignature
+		);
+	})
+}
+
+#[test]
+fn merge_msa_with_provider_source_fails() {
+	new_test_ext().execute_with(|| {
+		let (target_msa_id, target_key_pair) = create_account();
+		let (source_msa_id, _) = create_provider_with_name("Foo");
+		let (source_key_pair, _) = sr25519::Pair::generate();
+		assert_ok!(Msa::add_key(source_msa_id, &source_key_pair.public().into(), |_| Ok(())));
+		let (payload, source_signature, target_signature) =
+			sign_merge_payload(source_msa_id, &source_key_pair, target_msa_id, &target_key_pair);
+
+		assert_noop!(
+			Msa::merge_msa(
+				test_origin_signed(1),
+				source_signature,
+				target_signature,
+				payload,
+				vec![]
+			),
+			Error::<Test>::MsaMergeSourceIsProvider
+		);
+	})
+}
+
+#[test]
+fn merge_msa_without_every_source_key_fails() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (source_msa_id, source_key_pair) = create_account();
+		let (target_msa_id, target_key_pair) = create_account();
+		let (other_key_pair, _) = sr25519::Pair::generate();
+		assert_ok!(Msa::add_key(source_msa_id, &other_key_pair.public().into(), |_| Ok(())));
+		let (payload, source_signature, target_signature) =
+			sign_merge_payload(source_msa_id, &source_key_pair, target_msa_id, &target_key_pair);
+
+		// act & assert
+		assert_noop!(
+			Msa::merge_msa(
+				test_origin_signed(1),
+				source_signature.clone(),
+				target_signature.clone(),
+				payload.clone(),
+				vec![source_key_pair.public().into()]
+			),
+			Error::<Test>::MsaMergeKeysMismatch
+		);
+		assert_noop!(
+			Msa::merge_msa(
+				test_origin_signed(1),
+				source_signature,
+				target_signature,
+				payload,
+				vec![source_key_pair.public().into(), target_key_pair.public().into()]
+			),
+			Error::<Test>::MsaMergeKeysMismatch
+		);
+	})
+}
+
+#[test]
+fn merge_msa_exceeding_key_limit_fails() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		MaxPublicKeysPerMsa::set(2);
+		let (source_msa_id, source_key_pair) = create_account();
+		let (target_msa_id, target_key_pair) = create_account();
+		let (other_key_pair, _) = sr25519::Pair::generate();
+		assert_ok!(Msa::add_key(target_msa_id, &other_key_pair.public().into(), |_| Ok(())));
+		let (payload, source_signature, target_signature) =
+			sign_merge_payload(source_msa_id, &source_key_pair, target_msa_id, &target_key_pair);
+
+		// act & assert
+		assert_noop!(
+			Msa::merge_msa(
+				test_origin_signed(1),
+				source_signature,
+				target_signature,
+				payload,
+				vec![source_key_pair.public().into()]
+			),
+			Error::<Test>::KeyLimitExceeded
+		);
+	})
+}
+
+#[test]
+fn merge_queued_msa_moves_delegations_except_to_shared_providers() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		set_schema_count::<Test>(2);
+		let (shared_provider_msa_id, _) = create_provider_with_name("Foo");
+		let (other_provider_msa_id, _) = create_provider_with_name("Bar");
+		let (source_msa_id, target_msa_id) = merge_new_msas();
+		for (provider_msa_id, delegator_msa_id, schema_ids) in [
+			(shared_provider_msa_id, source_msa_id, vec![1]),
+			(other_provider_msa_id, source_msa_id, vec![2]),
+			(shared_provider_msa_id, target_msa_id, vec![2]),
+		] {
+			assert_ok!(Msa::add_provider(
+				ProviderId(provider_msa_id),
+				DelegatorId(delegator_msa_id),
+				schema_ids,
+				None
+			));
+		}
+
+		// act
+		let items_merged = Msa::merge_queued_msa(source_msa_id, target_msa_id, 10);
+
+		// assert
+		let target_id = DelegatorId(target_msa_id);
+		assert_eq!(items_merged, 2);
+		assert_eq!(
+			DelegatorAndProviderToDelegation::<Test>::iter_key_prefix(DelegatorId(source_msa_id))
+				.count(),
+			0
+		);
+		assert_ok!(Msa::ensure_schema_granted(ProviderId(other_provider_msa_id), target_id, 2, 1));
+		assert_noop!(
+			Msa::ensure_schema_granted(ProviderId(shared_provider_msa_id), target_id, 1, 1),
+			Error::<Test>::SchemaNotGranted
+		);
+		System::assert_has_event(
+			Event::DelegationGranted {
+				delegator_id: target_id,
+				provider_id: ProviderId(other_provider_msa_id),
+			}
+			.into(),
+		);
+		assert!(!MsaMergeQueue::<Test>::contains_key(source_msa_id));
+		assert!(RetiredMsaPurgeQueue::<Test>::contains_key(source_msa_id));
+		System::assert_last_event(Event::MsaMergeCompleted { source_msa_id, target_msa_id }.into());
+	})
+}
+
+#[test]
+fn merge_queued_msa_revokes_every_active_delegation_of_the_source() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		set_schema_count::<Test>(1);
+		let (shared_provider_msa_id, _) = create_provider_with_name("Foo");
+		let (other_provider_msa_id, _) = create_provider_with_name("Bar");
+		let (source_msa_id, target_msa_id) = merge_new_msas();
+		for (provider_msa_id, delegator_msa_id) in [
+			(shared_provider_msa_id, source_msa_id),
+			(other_provider_msa_id, source_msa_id),
+			(shared_provider_msa_id, target_msa_id),
+		] {
+			assert_ok!(Msa::add_provider(
+				ProviderId(provider_msa_id),
+				DelegatorId(delegator_msa_id),
+				vec![1],
+				None
+			));
+		}
+
+		// act
+		Msa::merge_queued_msa(source_msa_id, target_msa_id, 10);
+
+		// assert
+		for provider_msa_id in [shared_provider_msa_id, other_provider_msa_id] {
+			System::assert_has_event(
+				Event::DelegationRevoked {
+					provider_id: ProviderId(provider_msa_id),
+					delegator_id: DelegatorId(source_msa_id),
+				}
+				.into(),
+			);
+		}
+		assert!(!System::events().iter().any(|record| record.event ==
+			Event::DelegationRevoked {
+				provider_id: ProviderId(shared_provider_msa_id),
+				delegator_id: DelegatorId(target_msa_id),
+			}
+			.into()));
+	})
+}
+
+#[test]
+fn merge_queued_msa_within_limit_keeps_msa_queued() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		set_schema_count::<Test>(1);
+		let (source_msa_id, target_msa_id) = merge_new_msas();
+		for name in ["Foo", "Bar"] {
+			let (provider_msa_id, _) = create_provider_with_name(name);
+			assert_ok!(Msa::add_provider(
+				ProviderId(provider_msa_id),
+				DelegatorId(source_msa_id),
+				vec![1],
+				None
+			));
+		}
+
+		// act
+		let items_merged = Msa::merge_queued_msa(source_msa_id, target_msa_id, 1);
+
+		// assert
+		assert_eq!(items_merged, 1);
+		assert!(MsaMergeQueue::<Test>::contains_key(source_msa_id));
+		assert!(!RetiredMsaPurgeQueue::<Test>::contains_key(source_msa_id));
+	})
+}
+
+#[test]
+fn merge_queued_msa_moves_handle_to_target_without_handle() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (source_msa_id, target_msa_id) = merge_new_msas();
+		let claim_payload = ClaimHandlePayload::<BlockNumberFor<Test>> {
+			base_handle: "hello".into(),
+			expiration: 2,
+		};
+		assert_ok!(pallet_handles::Pallet::<Test>::do_claim_handle(source_msa_id, claim_payload));
+		let handle = Handles::get_handle_for_msa(source_msa_id);
+
+		// act
+		let items_merged = Msa::merge_queued_msa(source_msa_id, target_msa_id, 10);
+
+		// assert
+		assert_eq!(items_merged, 1);
+		assert_eq!(Handles::get_handle_for_msa(source_msa_id), None);
+		assert_eq!(Handles::get_handle_for_msa(target_msa_id), handle);
+	})
+}
+
+#[test]
+fn resolve_merged_msa_id_follows_redirects() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (first_msa_id, second_msa_id) = merge_new_msas();
+		MsaRedirects::<Test>::insert(second_msa_id, 42);
+
+		// act & assert
+		assert_eq!(Msa::resolve_merged_msa_id(first_msa_id), 42);
+		assert_eq!(Msa::resolve_merged_msa_id(42), 42);
+	})
+}
+
+#[test]
+fn merge_queued_msa_into_retired_target_is_abandoned() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		set_schema_count::<Test>(1);
+		let (provider_msa_id, _) = create_provider_with_name("Foo");
+		let (source_msa_id, target_msa_id) = merge_new_msas();
+		assert_ok!(Msa::add_provider(
+			ProviderId(provider_msa_id),
+			DelegatorId(source_msa_id),
+			vec![1],
+			None
+		));
+		// the target retires its last key
+		PublicKeyCountForMsaId::<Test>::remove(target_msa_id);
+
+		// act
+		let items_merged = Msa::merge_queued_msa(source_msa_id, target_msa_id, 10);
+
+		// assert
+		assert_eq!(items_merged, 0);
+		assert!(DelegatorAndProviderToDelegation::<Test>::contains_key(
+			DelegatorId(source_msa_id),
+			ProviderId(provider_msa_id)
+		));
+		assert!(!MsaMergeQueue::<Test>::contains_key(source_msa_id));
+		assert!(RetiredMsaPurgeQueue::<Test>::contains_key(source_msa_id));
+		System::assert_last_event(Event::MsaMergeAbandoned { source_msa_id, target_msa_id }.into());
+	})
+}
+
+#[test]
+fn merge_queued_msa_regrants_delegation_revoked_at_current_block() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		set_schema_count::<Test>(1);
+		let (provider_msa_id, _) = create_provider_with_name("Foo");
+		let (source_msa_id, target_msa_id) = merge_new_msas();
+		assert_ok!(Msa::add_provider(
+			ProviderId(provider_msa_id),
+			DelegatorId(source_msa_id),
+			vec![1],
+			None
+		));
+		DelegatorAndProviderToDelegation::<Test>::mutate(
+			DelegatorId(source_msa_id),
+			ProviderId(provider_msa_id),
+			|delegation| delegation.as_mut().unwrap().revoked_at = System::block_number(),
+		);
+
+		// act
+		Msa::merge_queued_msa(source_msa_id, target_msa_id, 10);
+
+		// assert
+		System::assert_has_event(
+			Event::DelegationGranted {
+				delegator_id: DelegatorId(target_msa_id),
+				provider_id: ProviderId(provider_msa_id),
+			}
+			.into(),
+		);
+	})
+}
+
+#[test]
+fn get_msa_redirect_returns_the_msa_merged_into() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (first_msa_id, second_msa_id) = merge_new_msas();
+		MsaRedirects::<Test>::insert(second_msa_id, 42);
+
+		// act & assert
+		assert_eq!(Msa::get_msa_redirect(first_msa_id), Some(42));
+		assert_eq!(Msa::get_msa_redirect(42), None);
+	})
+}
@@ -0,0 +1,177 @@
+use frame_support::{
+	assert_ok,
+	traits::{Get, Hooks},
+	weights::Weight,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+
+use pretty_assertions::assert_eq;
+
+use crate::{
+	tests::{mock::*, other_tests::set_schema_count},
+	DelegatorAndProviderToDelegation, Event, RetiredMsaPurgeQueue,
+};
+
+use common_primitives::{
+	handles::ClaimHandlePayload,
+	msa::{DelegatorId, ProviderId},
+};
+use pallet_handles::weights::WeightInfo as HandlesWeightInfo;
+
+fn retire_msa_with_delegations(provider_count: u64) -> u64 {
+	let (msa_id, key_pair) = create_account();
+	set_schema_count::<Test>(1);
+	for i in 0..provider_count {
+		let (provider_msa_id, _) = create_provider_with_name(&format!("provider{}", i));
+		assert_ok!(Msa::add_provider(
+			ProviderId(provider_msa_id),
+			DelegatorId(msa_id),
+			vec![1],
+			None
+		));
+	}
+	assert_ok!(Msa::retire_msa(RuntimeOrigin::signed(key_pair.public().into())));
+	msa_id
+}
+
+fn delegation_count(msa_id: u64) -> usize {
+	DelegatorAndProviderToDelegation::<Test>::iter_prefix(DelegatorId(msa_id)).count()
+}
+
+#[test]
+fn retire_msa_queues_msa_for_purge() {
+	new_test_ext().execute_with(|| {
+		let msa_id = retire_msa_with_delegations(1);
+
+		assert!(RetiredMsaPurgeQueue::<Test>::contains_key(msa_id));
+		assert_eq!(delegation_count(msa_id), 1);
+	})
+}
+
+#[test]
+fn purge_retired_msa_removes_delegations_and_handle() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let msa_id = retire_msa_with_delegations(2);
+		let claim_payload = ClaimHandlePayload::<BlockNumberFor<Test>> {
+			base_handle: "hello".into(),
+			expiration: 2,
+		};
+		assert_ok!(pallet_handles::Pallet::<Test>::do_claim_handle(msa_id, claim_payload));
+
+		// act
+		let used_weight = Msa::purge_retired_msa(msa_id, Weight::MAX);
+
+		// assert
+		assert_eq!(
+			used_weight,
+			Msa::purge_retired_msa_weight(2)
+				.saturating_add(<Test as pallet_handles::Config>::WeightInfo::purge_msa_handle())
+		);
+		assert_eq!(delegation_count(msa_id), 0);
+		assert_eq!(Handles::get_handle_for_msa(msa_id), None);
+		assert!(!RetiredMsaPurgeQueue::<Test>::contains_key(msa_id));
+		System::assert_has_event(Event::MsaPurgeProgressed { msa_id, items_purged: 2 }.into());
+		System::assert_last_event(Event::MsaPurgeCompleted { msa_id }.into());
+	})
+}
+
+#[test]
+fn purge_retired_msa_stops_at_limit() {
+	let mut ext = new_test_ext();
+	let msa_id = ext.execute_with(|| retire_msa_with_delegations(2));
+	// Writes still in the overlay are all removed at once, so purge from committed storage
+	assert_ok!(ext.commit_all());
+
+	let one_item_weight = Msa::purge_retired_msa_weight(1);
+
+	ext.execute_with(|| {
+		assert_eq!(Msa::purge_retired_msa(msa_id, one_item_weight), one_item_weight);
+
+		assert_eq!(delegation_count(msa_id), 1);
+		assert!(RetiredMsaPurgeQueue::<Test>::contains_key(msa_id));
+		System::assert_last_event(Event::MsaPurgeProgressed { msa_id, items_purged: 1 }.into());
+	});
+	assert_ok!(ext.commit_all());
+
+	ext.execute_with(|| {
+		assert_eq!(Msa::purge_retired_msa(msa_id, one_item_weight), one_item_weight);
+
+		assert_eq!(delegation_count(msa_id), 0);
+		assert!(!RetiredMsaPurgeQueue::<Test>::contains_key(msa_id));
+		System::assert_last_event(Event::MsaPurgeCompleted { msa_id }.into());
+	});
+}
+
+#[test]
+fn on_idle_without_enough_weight_does_nothing() {
+	new_test_ext().execute_with(|| {
+		let msa_id = retire_msa_with_delegations(1);
+
+		let used_weight = Msa::on_idle(1, Weight::zero());
+
+		assert_eq!(used_weight, Weight::zero());
+		assert_eq!(delegation_count(msa_id), 1);
+		assert!(RetiredMsaPurgeQueue::<Test>::contains_key(msa_id));
+	})
+}
+
+#[test]
+fn on_idle_purges_retired_msa() {
+	new_test_ext().execute_with(|| {
+		let msa_id = retire_msa_with_delegations(3);
+
+		let used_weight = Msa::on_idle(1, Weight::MAX);
+
+		// The migration cursor and the three on_idle queues are each read once
+		let queue_reads = <Test as frame_system::Config>::DbWeight::get().reads(4);
+		assert_eq!(used_weight, Msa::purge_retired_msa_weight(3).saturating_add(queue_reads));
+		assert_eq!(delegation_count(msa_id), 0);
+		assert!(!RetiredMsaPurgeQueue::<Test>::contains_key(msa_id));
+	})
+}
+
+#[test]
+fn on_idle_with_empty_queue_only_reads_queue() {
+	new_test_ext().execute_with(|| {
+		let used_weight = Msa::on_idle(1, Weight::MAX);
+
+		assert_eq!(used_weight, <Test as frame_system::Config>::DbWeight::get().reads(4));
+	})
+}
+
+#[test]
+fn purge_retired_msa_emits_delegation_revoked_for_active_delegations() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (msa_id, key_pair) = create_account();
+		set_schema_count::<Test>(1);
+		let (active_provider, _) = create_provider_with_name("active");
+		let (revoked_provider, _) = create_provider_with_name("revoked");
+		for provider_msa_id in [active_provider, revoked_provider] {
+			assert_ok!(Msa::add_provider(
+				ProviderId(provider_msa_id),
+				DelegatorId(msa_id),
+				vec![1],
+				None
+			));
+		}
+		assert_ok!(Msa::revoke_provider(ProviderId(revoked_provider), DelegatorId(msa_id)));
+		assert_ok!(Msa::retire_msa(RuntimeOrigin::signed(key_pair.public().into())));
+		System::reset_events();
+
+		// act
+		Msa::purge_retired_msa(msa_id, Weight::MAX);
+
+		// assert
+		let revoked_events: Vec<_> = System::events()
+			.into_iter()
+			.filter_map(|record| match record.event {
+				RuntimeEvent::Msa(Event::DelegationRevoked { provider_id, delegator_id }) =>
+					Some((provider_id, delegator_id)),
+				_ => None,
+			})
+			.collect();
+		assert_eq!(revoked_events, vec![(ProviderId(active_provider), DelegatorId(msa_id))]);
+	})
+}
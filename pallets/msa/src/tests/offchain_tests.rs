@@ -4,18 +4,22 @@ use frame_system::pallet_prelude::BlockNumberFor;
 use parity_scale_codec::{Decode, Encode};
 
 use crate::{
-	tests::mock::*, Config, DispatchResult, FinalizedBlockResponse, Pallet,
+	tests::mock::*, Config, DispatchResult, FinalizedBlockResponse, Pallet, RotateKeyData,
 	LAST_PROCESSED_BLOCK_STORAGE_NAME, MSA_INITIAL_INDEXED_STORAGE_NAME,
 	RPC_FINALIZED_BLOCK_REQUEST_BODY, RPC_FINALIZED_BLOCK_REQUEST_URL,
 };
 
 use common_primitives::{
-	msa::MessageSourceId,
-	offchain::{get_index_value, get_msa_account_storage_key_name},
+	msa::{MessageSourceId, MsaHistoryEvent, MsaHistoryRecord, ProviderId},
+	offchain::{
+		get_index_value, get_msa_account_storage_key_name, get_msa_history_chunk_storage_key_name,
+		get_msa_history_storage_key_name,
+	},
+	utils::wrap_binary_data,
 };
 use pretty_assertions::assert_eq;
 use sp_core::{crypto::AccountId32, offchain::testing::PendingRequest, sr25519, Pair};
-use sp_runtime::offchain::storage::StorageValueRef;
+use sp_runtime::{offchain::storage::StorageValueRef, MultiSignature};
 
 fn fill_accounts<T: Config>(accounts: usize, with_indexing: bool) -> Vec<T::AccountId>
 where
@@ -137,3 +141,145 @@ pub fn offchain_worker_should_populate_accounts_with_offchain_indexed_events() {
 		assert_eq!(last_processed_block, Ok(Some(block_number)));
 	});
 }
+
+#[test]
+pub fn offchain_worker_should_replace_rotated_keys_with_offchain_indexed_events() {
+	// arrange
+	let (mut ext, state) = new_test_with_offchain_ext();
+	let (old_key_pair, _) = sr25519::Pair::generate();
+	let (new_key_pair, _) = sr25519::Pair::generate();
+	let mut block_number = 0;
+	ext.execute_with(|| {
+		assert_ok!(Msa::create(RuntimeOrigin::signed(old_key_pair.public().into())));
+		let rotate_key_payload = RotateKeyData::<Test> {
+			msa_id: 1,
+			expiration: 10,
+			new_public_key: new_key_pair.public().into(),
+			old_public_key: old_key_pair.public().into(),
+		};
+		let encoded_payload = wrap_binary_data(rotate_key_payload.encode());
+		let old_key_signature: MultiSignature = old_key_pair.sign(&encoded_payload).into();
+		let new_key_signature: MultiSignature = new_key_pair.sign(&encoded_payload).into();
+		assert_ok!(Msa::rotate_msa_public_key(
+			test_origin_signed(1),
+			old_key_signature,
+			new_key_signature,
+			rotate_key_payload
+		));
+
+		block_number = BlockNumberFor::<Test>::from(1u32);
+		let response = FinalizedBlockResponse {
+			result: "0x5685c63b9df72b59f6fa8e1223532c041d15a1abbe39a6d2a48d6565a091839b"
+				.to_string(),
+		};
+		let decoded_from_hex = hex::decode(&response.result[2..]).expect("should decode hex");
+		let val = <<Test as frame_system::Config>::Hash>::decode(&mut &decoded_from_hex[..])
+			.expect("should decode hash");
+		frame_system::BlockHash::<Test>::set(block_number, val);
+		let serialized_block = serde_json::to_string(&response).expect("should serialize");
+		let response_bytes = serialized_block.as_bytes().to_vec();
+		state.write().expect_request(PendingRequest {
+			method: "POST".into(),
+			uri: RPC_FINALIZED_BLOCK_REQUEST_URL.into(),
+			headers: vec![("Content-Type".into(), "application/json".into())],
+			sent: true,
+			body: RPC_FINALIZED_BLOCK_REQUEST_BODY.to_vec(),
+			response: Some(response_bytes),
+			..Default::default()
+		});
+		let storage = StorageValueRef::persistent(MSA_INITIAL_INDEXED_STORAGE_NAME);
+		storage.set(&true);
+	});
+
+	ext.persist_offchain_overlay();
+
+	ext.execute_with(|| {
+		// act
+		Msa::offchain_worker(block_number);
+
+		// assert
+		let msa_key = get_msa_account_storage_key_name(1);
+		let result = get_index_value::<Vec<AccountId32>>(&msa_key);
+		assert_eq!(result, Ok(Some(vec![new_key_pair.public().into()])));
+	});
+}
+
+#[test]
+pub fn offchain_worker_should_record_msa_history_with_offchain_indexed_events() {
+	// arrange
+	let (mut ext, state) = new_test_with_offchain_ext();
+	let (key_pair, _) = sr25519::Pair::generate();
+	let mut provider_msa_id = 0;
+	let mut block_number = 0;
+	ext.execute_with(|| {
+		assert_ok!(Msa::create(RuntimeOrigin::signed(key_pair.public().into())));
+		let (provider_id, provider_account) = create_provider_with_name("Foo");
+		provider_msa_id = provider_id;
+		let (signature, add_provider_payload) =
+			create_and_sign_add_provider_payload(key_pair.clone(), provider_msa_id);
+		assert_ok!(Msa::grant_delegation(
+			RuntimeOrigin::signed(provider_account.into()),
+			key_pair.public().into(),
+			signature,
+			add_provider_payload
+		));
+		assert_ok!(Msa::revoke_delegation_by_delegator(
+			RuntimeOrigin::signed(key_pair.public().into()),
+			provider_msa_id
+		));
+
+		block_number = BlockNumberFor::<Test>::from(1u32);
+		let response = FinalizedBlockResponse {
+			result: "0x5685c63b9df72b59f6fa8e1223532c041d15a1abbe39a6d2a48d6565a091839b"
+				.to_string(),
+		};
+		let decoded_from_hex = hex::decode(&response.result[2..]).expect("should decode hex");
+		let val = <<Test as frame_system::Config>::Hash>::decode(&mut &decoded_from_hex[..])
+			.expect("should decode hash");
+		frame_system::BlockHash::<Test>::set(block_number, val);
+		let serialized_block = serde_json::to_string(&response).expect("should serialize");
+		let response_bytes = serialized_block.as_bytes().to_vec();
+		state.write().expect_request(PendingRequest {
+			method: "POST".into(),
+			uri: RPC_FINALIZED_BLOCK_REQUEST_URL.into(),
+			headers: vec![("Content-Type".into(), "application/json".into())],
+			sent: true,
+			body: RPC_FINALIZED_BLOCK_REQUEST_BODY.to_vec(),
+			response: Some(response_bytes),
+			..Default::default()
+		});
+		let storage = StorageValueRef::persistent(MSA_INITIAL_INDEXED_STORAGE_NAME);
+		storage.set(&true);
+	});
+
+	ext.persist_offchain_overlay();
+
+	ext.execute_with(|| {
+		// act
+		Msa::offchain_worker(block_number);
+
+		// assert
+		let history_key = get_msa_history_storage_key_name(1);
+		assert_eq!(get_index_value::<u32>(&history_key), Ok(Some(1)));
+		let chunk_key = get_msa_history_chunk_storage_key_name(1, 0);
+		let result = get_index_value::<Vec<MsaHistoryRecord<AccountId32>>>(&chunk_key);
+		let provider_id = ProviderId(provider_msa_id);
+		assert_eq!(
+			result,
+			Ok(Some(vec![
+				MsaHistoryRecord {
+					block_number: 1,
+					event: MsaHistoryEvent::PublicKeyAdded { key: key_pair.public().into() },
+				},
+				MsaHistoryRecord {
+					block_number: 1,
+					event: MsaHistoryEvent::DelegationGranted { provider_id },
+				},
+				MsaHistoryRecord {
+					block_number: 1,
+					event: MsaHistoryEvent::DelegationRevoked { provider_id },
+				},
+			]))
+		);
+	});
+}
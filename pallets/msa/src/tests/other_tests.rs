@@ -19,8 +19,8 @@ use crate::{
 
 use common_primitives::{
 	msa::{
-		Delegation, DelegationResponse, DelegatorId, ProviderId, ProviderRegistryEntry,
-		SchemaGrant, SchemaGrantValidator,
+		Delegation, DelegationResponse, DelegatorId, KeyOwnerResponse, ProviderId,
+		ProviderRegistryEntry, SchemaGrant, SchemaGrantPermission, SchemaGrantValidator,
 	},
 	node::BlockNumber,
 	schema::{SchemaId, SchemaValidator},
@@ -51,6 +51,38 @@ pub fn test_get_owner_of() {
 	});
 }
 
+#[test]
+pub fn get_msa_ids_for_keys_returns_msa_in_order() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		assert_ok!(Msa::create(test_origin_signed(1)));
+		assert_ok!(Msa::add_key(1, &test_public(2), EMPTY_FUNCTION));
+
+		// act
+		let msa_ids =
+			Msa::get_msa_ids_for_keys(vec![test_public(3), test_public(2), test_public(1)]);
+
+		// assert
+		assert_eq!(msa_ids, vec![None, Some(1), Some(1)]);
+	});
+}
+
+#[test]
+pub fn get_key_owners_returns_msa_and_key_count_in_order() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		assert_ok!(Msa::create(test_origin_signed(1)));
+		assert_ok!(Msa::add_key(1, &test_public(2), EMPTY_FUNCTION));
+
+		// act
+		let owners = Msa::get_key_owners(vec![test_public(3), test_public(2), test_public(1)]);
+
+		// assert
+		let owner = Some(KeyOwnerResponse { msa_id: 1, public_key_count: 2 });
+		assert_eq!(owners, vec![None, owner.clone(), owner]);
+	});
+}
+
 #[test]
 pub fn test_ensure_msa_owner() {
 	new_test_ext().execute_with(|| {
@@ -319,7 +351,7 @@ pub fn revoke_provider_call_has_no_cost() {
 
 		let expiration: BlockNumber = 10;
 		let add_provider_payload = AddProvider::new(1, None, expiration);
-		let encode_add_provider_data = wrap_binary_data(add_provider_payload.encode());
+		let encode_add_provider_data = wrap_binary_data(add_provider_payload.signed_payload());
 
 		let signature: MultiSignature = key_pair.sign(&encode_add_provider_data).into();
 
@@ -400,11 +432,17 @@ pub fn valid_schema_grant() {
 		let provider = ProviderId(1);
 		let delegator = DelegatorId(2);
 		let schema_grants = vec![1, 2];
-		assert_ok!(Msa::add_provider(provider, delegator, schema_grants));
+		assert_ok!(Msa::add_provider(provider, delegator, schema_grants, None));
 
 		System::set_block_number(System::block_number() + 1);
 
-		assert_ok!(Msa::ensure_valid_schema_grant(provider, delegator, 2u16, 1u32));
+		assert_ok!(Msa::ensure_valid_schema_grant(
+			provider,
+			delegator,
+			2u16,
+			1u32,
+			SchemaGrantPermission::Create
+		));
 	})
 }
 
@@ -425,7 +463,7 @@ pub fn error_invalid_schema_id() {
 			TestCase { schema: vec![18], expected: Error::<Test>::InvalidSchemaId },
 		];
 		for tc in test_cases {
-			assert_noop!(Msa::add_provider(provider, delegator, tc.schema), tc.expected);
+			assert_noop!(Msa::add_provider(provider, delegator, tc.schema, None), tc.expected);
 		}
 	})
 }
@@ -438,7 +476,7 @@ pub fn error_exceeding_max_schema_under_minimum_schema_grants() {
 		let provider = ProviderId(1);
 		let delegator = DelegatorId(2);
 		assert_noop!(
-			Msa::add_provider(provider, delegator, (1..32 as u16).collect::<Vec<_>>()),
+			Msa::add_provider(provider, delegator, (1..32 as u16).collect::<Vec<_>>(), None),
 			Error::<Test>::ExceedsMaxSchemaGrantsPerDelegation
 		);
 	})
@@ -461,7 +499,7 @@ pub fn error_schema_not_granted_rpc() {
 	new_test_ext().execute_with(|| {
 		let provider = ProviderId(1);
 		let delegator = DelegatorId(2);
-		assert_ok!(Msa::add_provider(provider, delegator, Vec::default()));
+		assert_ok!(Msa::add_provider(provider, delegator, Vec::default(), None));
 		assert_err!(
 			Msa::get_granted_schemas_by_msa_id(delegator, Some(provider)),
 			Error::<Test>::SchemaNotGranted
@@ -477,7 +515,7 @@ pub fn schema_granted_success_rpc() {
 		let provider = ProviderId(1);
 		let delegator = DelegatorId(2);
 		let schema_grants = vec![1, 2];
-		assert_ok!(Msa::add_provider(provider, delegator, schema_grants));
+		assert_ok!(Msa::add_provider(provider, delegator, schema_grants, None));
 		let schemas_granted = Msa::get_granted_schemas_by_msa_id(delegator, Some(provider));
 		let expected_schemas_granted = vec![
 			SchemaGrant::new(1, BlockNumber::zero()),
@@ -502,8 +540,8 @@ pub fn schema_granted_success_multiple_providers_rpc() {
 		let delegator = DelegatorId(2);
 		let schema_grants_1 = vec![1, 2];
 		let schema_grants_2 = vec![3, 4];
-		assert_ok!(Msa::add_provider(provider_1, delegator, schema_grants_1));
-		assert_ok!(Msa::add_provider(provider_2, delegator, schema_grants_2));
+		assert_ok!(Msa::add_provider(provider_1, delegator, schema_grants_1, None));
+		assert_ok!(Msa::add_provider(provider_2, delegator, schema_grants_2, None));
 		let schemas_granted = Msa::get_granted_schemas_by_msa_id(delegator, None);
 		let expected_schemas_granted_1 = vec![
 			SchemaGrant::new(1, BlockNumber::zero()),
@@ -530,7 +568,7 @@ pub fn schema_revoked_rpc() {
 		let provider = ProviderId(1);
 		let delegator = DelegatorId(2);
 		let mut schema_grants = vec![1, 2];
-		assert_ok!(Msa::add_provider(provider, delegator, schema_grants));
+		assert_ok!(Msa::add_provider(provider, delegator, schema_grants, None));
 		let mut schemas_granted = Msa::get_granted_schemas_by_msa_id(delegator, Some(provider));
 		let mut expected_schemas_granted = vec![
 			SchemaGrant::new(1, BlockNumber::zero()),
@@ -546,7 +584,7 @@ pub fn schema_revoked_rpc() {
 		// Now revoke a schema and check that it is reported correctly by the RPC
 		run_to_block(5);
 		schema_grants = vec![1];
-		assert_ok!(Msa::upsert_schema_permissions(provider, delegator, schema_grants));
+		assert_ok!(Msa::upsert_schema_permissions(provider, delegator, schema_grants, None));
 		schemas_granted = Msa::get_granted_schemas_by_msa_id(delegator, Some(provider));
 		expected_schemas_granted =
 			vec![SchemaGrant::new(1, BlockNumber::zero()), SchemaGrant::new(2, 5)];
@@ -582,7 +620,7 @@ pub fn add_provider_expired() {
 		let expiration: BlockNumber = 0;
 
 		let add_provider_payload = AddProvider::new(1u64, None, expiration);
-		let encode_add_provider_data = wrap_binary_data(add_provider_payload.encode());
+		let encode_add_provider_data = wrap_binary_data(add_provider_payload.signed_payload());
 		let signature: MultiSignature = user_pair.sign(&encode_add_provider_data).into();
 		// 3.5 create the user's MSA + add provider as provider
 		assert_err!(
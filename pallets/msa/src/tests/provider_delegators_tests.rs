@@ -0,0 +1,217 @@
+use frame_support::{assert_ok, weights::Weight};
+
+use pretty_assertions::assert_eq;
+use sp_core::Pair;
+
+use crate::{
+	tests::{mock::*, other_tests::set_schema_count},
+	ProviderToDelegators,
+};
+
+use common_primitives::msa::{
+	DelegationStatus, DelegatorId, DelegatorsPaginationRequest, ProviderId, SchemaGrant,
+};
+
+fn delegate_to(provider_msa_id: u64, schema_ids: Vec<u16>) -> DelegatorId {
+	let (delegator_msa_id, _) = create_account();
+	assert_ok!(Msa::add_provider(
+		ProviderId(provider_msa_id),
+		DelegatorId(delegator_msa_id),
+		schema_ids,
+		None
+	));
+	DelegatorId(delegator_msa_id)
+}
+
+fn delegator_ids(provider_msa_id: u64, request: DelegatorsPaginationRequest) -> Vec<DelegatorId> {
+	let mut ids: Vec<DelegatorId> =
+		Msa::get_delegators_by_provider(ProviderId(provider_msa_id), request)
+			.content
+			.into_iter()
+			.map(|delegator| delegator.delegator_id)
+			.collect();
+	ids.sort_by_key(|id| id.0);
+	ids
+}
+
+#[test]
+fn add_provider_indexes_delegator() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, _) = create_provider_with_name("Foo");
+
+		let delegator_id = delegate_to(provider_msa_id, vec![]);
+
+		assert!(ProviderToDelegators::<Test>::contains_key(
+			ProviderId(provider_msa_id),
+			delegator_id
+		));
+	})
+}
+
+#[test]
+fn get_delegators_by_provider_returns_delegation_and_grants() {
+	new_test_ext().execute_with(|| {
+		set_schema_count::<Test>(2);
+		let (provider_msa_id, _) = create_provider_with_name("Foo");
+		let delegator_id = delegate_to(provider_msa_id, vec![2]);
+
+		let response = Msa::get_delegators_by_provider(
+			ProviderId(provider_msa_id),
+			DelegatorsPaginationRequest { page_size: 10, ..Default::default() },
+		);
+
+		assert_eq!(response.content.len(), 1);
+		assert_eq!(response.content[0].delegator_id, delegator_id);
+		assert_eq!(response.content[0].revoked_at, 0);
+		assert_eq!(response.content[0].permissions, vec![SchemaGrant::new(2, 0)]);
+		assert!(!response.has_next);
+		assert_eq!(response.next_after, None);
+	})
+}
+
+#[test]
+fn get_delegators_by_provider_pages_through_all_delegators() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, _) = create_provider_with_name("Foo");
+		let (other_provider_msa_id, _) = create_provider_with_name("Bar");
+		let mut expected: Vec<DelegatorId> =
+			(0..5).map(|_| delegate_to(provider_msa_id, vec![])).collect();
+		delegate_to(other_provider_msa_id, vec![]);
+
+		let mut found: Vec<DelegatorId> = Vec::new();
+		let mut request = DelegatorsPaginationRequest { page_size: 2, ..Default::default() };
+		loop {
+			let response =
+				Msa::get_delegators_by_provider(ProviderId(provider_msa_id), request.clone());
+			assert!(response.content.len() <= 2);
+			found.extend(response.content.into_iter().map(|delegator| delegator.delegator_id));
+			if !response.has_next {
+				break
+			}
+			request.after = response.next_after;
+		}
+
+		found.sort_by_key(|id| id.0);
+		expected.sort_by_key(|id| id.0);
+		assert_eq!(found, expected);
+	})
+}
+
+#[test]
+fn get_delegators_by_provider_filters_by_status() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, _) = create_provider_with_name("Foo");
+		let active_delegator_id = delegate_to(provider_msa_id, vec![]);
+		let revoked_delegator_id = delegate_to(provider_msa_id, vec![]);
+		assert_ok!(Msa::revoke_provider(ProviderId(provider_msa_id), revoked_delegator_id));
+		run_to_block(2);
+
+		assert_eq!(
+			delegator_ids(
+				provider_msa_id,
+				DelegatorsPaginationRequest {
+					status: Some(DelegationStatus::Active),
+					page_size: 10,
+					..Default::default()
+				}
+			),
+			vec![active_delegator_id]
+		);
+		assert_eq!(
+			delegator_ids(
+				provider_msa_id,
+				DelegatorsPaginationRequest {
+					status: Some(DelegationStatus::Revoked),
+					page_size: 10,
+					..Default::default()
+				}
+			),
+			vec![revoked_delegator_id]
+		);
+	})
+}
+
+#[test]
+fn get_delegators_by_provider_filters_by_schema() {
+	new_test_ext().execute_with(|| {
+		set_schema_count::<Test>(3);
+		let (provider_msa_id, _) = create_provider_with_name("Foo");
+		let delegator_of_1 = delegate_to(provider_msa_id, vec![1]);
+		let delegator_of_1_and_2 = delegate_to(provider_msa_id, vec![1, 2]);
+		delegate_to(provider_msa_id, vec![3]);
+		assert_ok!(Msa::revoke_permissions_for_schemas(
+			delegator_of_1_and_2,
+			ProviderId(provider_msa_id),
+			vec![1]
+		));
+		run_to_block(2);
+
+		assert_eq!(
+			delegator_ids(
+				provider_msa_id,
+				DelegatorsPaginationRequest {
+					schema_id: Some(1),
+					page_size: 10,
+					..Default::default()
+				}
+			),
+			vec![delegator_of_1, delegator_of_1_and_2]
+		);
+		assert_eq!(
+			delegator_ids(
+				provider_msa_id,
+				DelegatorsPaginationRequest {
+					schema_id: Some(1),
+					status: Some(DelegationStatus::Active),
+					page_size: 10,
+					..Default::default()
+				}
+			),
+			vec![delegator_of_1]
+		);
+	})
+}
+
+#[test]
+fn get_delegators_by_provider_treats_retired_provider_delegations_as_revoked() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, provider_account) = create_provider_with_name("Foo");
+		let delegator_id = delegate_to(provider_msa_id, vec![]);
+		assert_ok!(Msa::retire_provider(RuntimeOrigin::signed(provider_account.into())));
+		run_to_block(12);
+
+		assert_eq!(
+			delegator_ids(
+				provider_msa_id,
+				DelegatorsPaginationRequest {
+					status: Some(DelegationStatus::Revoked),
+					page_size: 10,
+					..Default::default()
+				}
+			),
+			vec![delegator_id]
+		);
+	})
+}
+
+#[test]
+fn purge_retired_msa_removes_delegator_from_index() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, _) = create_provider_with_name("Foo");
+		let (delegator_msa_id, delegator_pair) = create_account();
+		assert_ok!(Msa::add_provider(
+			ProviderId(provider_msa_id),
+			DelegatorId(delegator_msa_id),
+			vec![],
+			None
+		));
+		assert_ok!(Msa::retire_msa(RuntimeOrigin::signed(delegator_pair.public().into())));
+
+		Msa::purge_retired_msa(delegator_msa_id, Weight::MAX);
+
+		assert!(!ProviderToDelegators::<Test>::contains_key(
+			ProviderId(provider_msa_id),
+			DelegatorId(delegator_msa_id)
+		));
+	})
+}
@@ -0,0 +1,227 @@
+use frame_support::{assert_noop, assert_ok};
+
+use pretty_assertions::assert_eq;
+use sp_core::{Pair, H256};
+
+use crate::{
+	tests::{mock::*, other_tests::set_schema_count},
+	Config, Error, Event, ProviderToMetadata,
+};
+
+use common_primitives::msa::{ProviderId, ProviderInfoResponse};
+
+fn set_metadata(
+	provider_account: Public,
+	supported_schema_ids: Vec<u16>,
+) -> frame_support::dispatch::DispatchResult {
+	Msa::set_provider_metadata(
+		RuntimeOrigin::signed(provider_account.into()),
+		b"https://example.com".to_vec(),
+		b"bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi".to_vec(),
+		b"support@example.com".to_vec(),
+		Some(H256::repeat_byte(7)),
+		supported_schema_ids,
+	)
+}
+
+#[test]
+fn set_provider_metadata_happy_path() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		set_schema_count::<Test>(10);
+		let (provider_msa_id, provider_account) = create_provider_with_name("Foo");
+
+		// act
+		assert_ok!(set_metadata(provider_account, vec![1, 2]));
+
+		// assert
+		let metadata = ProviderToMetadata::<Test>::get(ProviderId(provider_msa_id)).unwrap();
+		assert_eq!(metadata.homepage_url.into_inner(), b"https://example.com".to_vec());
+		assert_eq!(metadata.terms_of_service_hash, Some(H256::repeat_byte(7)));
+		assert_eq!(metadata.supported_schema_ids.into_inner(), vec![1, 2]);
+		System::assert_last_event(
+			Event::ProviderMetadataUpdated { provider_id: ProviderId(provider_msa_id) }.into(),
+		);
+	})
+}
+
+#[test]
+fn set_provider_metadata_replaces_existing_metadata() {
+	new_test_ext().execute_with(|| {
+		set_schema_count::<Test>(10);
+		let (provider_msa_id, provider_account) = create_provider_with_name("Foo");
+		assert_ok!(set_metadata(provider_account, vec![1, 2]));
+
+		assert_ok!(Msa::set_provider_metadata(
+			RuntimeOrigin::signed(provider_account.into()),
+			b"https://example.org".to_vec(),
+			vec![],
+			vec![],
+			None,
+			vec![],
+		));
+
+		let metadata = ProviderToMetadata::<Test>::get(ProviderId(provider_msa_id)).unwrap();
+		assert_eq!(metadata.homepage_url.into_inner(), b"https://example.org".to_vec());
+		assert_eq!(metadata.terms_of_service_hash, None);
+		assert!(metadata.supported_schema_ids.is_empty());
+	})
+}
+
+#[test]
+fn set_provider_metadata_by_non_provider_fails() {
+	new_test_ext().execute_with(|| {
+		set_schema_count::<Test>(10);
+		let (_msa_id, key_pair) = create_account();
+
+		assert_noop!(
+			set_metadata(key_pair.public(), vec![1]),
+			Error::<Test>::ProviderNotRegistered
+		);
+	})
+}
+
+#[test]
+fn set_provider_metadata_with_too_long_field_fails() {
+	new_test_ext().execute_with(|| {
+		let (_provider_msa_id, provider_account) = create_provider_with_name("Foo");
+		let too_long =
+			vec![1u8; <Test as Config>::MaxProviderMetadataFieldSize::get() as usize + 1];
+
+		assert_noop!(
+			Msa::set_provider_metadata(
+				RuntimeOrigin::signed(provider_account.into()),
+				vec![],
+				vec![],
+				too_long,
+				None,
+				vec![],
+			),
+			Error::<Test>::ExceedsMaxProviderMetadataFieldSize
+		);
+	})
+}
+
+#[test]
+fn set_provider_metadata_with_invalid_schema_fails() {
+	new_test_ext().execute_with(|| {
+		set_schema_count::<Test>(2);
+		let (_provider_msa_id, provider_account) = create_provider_with_name("Foo");
+
+		assert_noop!(set_metadata(provider_account, vec![1, 5]), Error::<Test>::InvalidSchemaId);
+	})
+}
+
+#[test]
+fn get_provider_info_returns_name_and_metadata() {
+	new_test_ext().execute_with(|| {
+		set_schema_count::<Test>(10);
+		let (provider_msa_id, provider_account) = create_provider_with_name("Foo");
+		assert_ok!(set_metadata(provider_account, vec![3]));
+
+		let info = Msa::get_provider_info(ProviderId(provider_msa_id)).unwrap();
+
+		assert_eq!(info.provider_id, ProviderId(provider_msa_id));
+		assert_eq!(info.provider_name, b"Foo".to_vec());
+		assert_eq!(info.contact, b"support@example.com".to_vec());
+		assert_eq!(info.supported_schema_ids, vec![3]);
+	})
+}
+
+#[test]
+fn get_provider_info_without_metadata_returns_name_only() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, _) = create_provider_with_name("Foo");
+
+		assert_eq!(
+			Msa::get_provider_info(ProviderId(provider_msa_id)),
+			Some(ProviderInfoResponse {
+				provider_id: ProviderId(provider_msa_id),
+				provider_name: b"Foo".to_vec(),
+				..Default::default()
+			})
+		);
+	})
+}
+
+#[test]
+fn get_provider_info_for_non_provider_returns_none() {
+	new_test_ext().execute_with(|| {
+		let (msa_id, _) = create_account();
+
+		assert_eq!(Msa::get_provider_info(ProviderId(msa_id)), None);
+	})
+}
+
+#[test]
+fn change_provider_name_via_governance_happy_path() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, provider_account) = create_provider_with_name("Foo");
+
+		assert_ok!(Msa::change_provider_name_via_governance(
+			RuntimeOrigin::from(pallet_collective::RawOrigin::Members(1, 1)),
+			provider_account.into(),
+			Vec::from("Bar")
+		));
+
+		let info = Msa::get_provider_info(ProviderId(provider_msa_id)).unwrap();
+		assert_eq!(info.provider_name, b"Bar".to_vec());
+		System::assert_last_event(
+			Event::ProviderNameChanged { provider_id: ProviderId(provider_msa_id) }.into(),
+		);
+	})
+}
+
+#[test]
+fn change_provider_name_via_governance_for_non_provider_fails() {
+	new_test_ext().execute_with(|| {
+		let (_msa_id, key_pair) = create_account();
+
+		assert_noop!(
+			Msa::change_provider_name_via_governance(
+				RuntimeOrigin::from(pallet_collective::RawOrigin::Members(1, 1)),
+				key_pair.public().into(),
+				Vec::from("Bar")
+			),
+			Error::<Test>::ProviderNotRegistered
+		);
+	})
+}
+
+#[test]
+fn propose_to_change_provider_name_creates_council_proposal() {
+	new_test_ext().execute_with(|| {
+		let (_provider_msa_id, provider_account) = create_provider_with_name("Foo");
+
+		assert_ok!(Msa::propose_to_change_provider_name(
+			RuntimeOrigin::signed(provider_account.into()),
+			Vec::from("Bar")
+		));
+
+		let proposed_count = System::events()
+			.iter()
+			.filter(|record| {
+				matches!(
+					record.event,
+					RuntimeEvent::Council(pallet_collective::Event::Proposed { .. })
+				)
+			})
+			.count();
+		assert_eq!(proposed_count, 1);
+	})
+}
+
+#[test]
+fn propose_to_change_provider_name_by_non_provider_fails() {
+	new_test_ext().execute_with(|| {
+		let (_msa_id, key_pair) = create_account();
+
+		assert_noop!(
+			Msa::propose_to_change_provider_name(
+				RuntimeOrigin::signed(key_pair.public().into()),
+				Vec::from("Bar")
+			),
+			Error::<Test>::ProviderNotRegistered
+		);
+	})
+}
@@ -0,0 +1,285 @@
+use frame_support::{assert_noop, assert_ok, traits::Hooks, weights::Weight};
+
+use pretty_assertions::assert_eq;
+use sp_core::Pair;
+
+use crate::{
+	tests::mock::*, CheckFreeExtrinsicUse, DelegatorAndProviderToDelegation, Error, Event,
+	ProviderRetirements, ProviderRevocationQueue, ProviderToRegistryEntry,
+};
+
+use common_primitives::{
+	capacity::TargetValidator,
+	msa::{DelegationValidator, DelegatorId, ProviderId, ProviderLookup},
+};
+
+#[test]
+fn retire_provider_schedules_retirement_and_emits_event() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (provider_msa_id, provider_account) = create_provider_with_name("Foo");
+
+		// act
+		assert_ok!(Msa::retire_provider(RuntimeOrigin::signed(provider_account.into())));
+
+		// assert
+		let provider_id = ProviderId(provider_msa_id);
+		assert_eq!(ProviderRetirements::<Test>::get(provider_id), Some(11));
+		assert!(Msa::is_registered_provider(provider_msa_id));
+		System::assert_last_event(
+			Event::ProviderRetirementScheduled { provider_id, effective_at: 11 }.into(),
+		);
+	})
+}
+
+#[test]
+fn retire_provider_by_non_provider_fails() {
+	new_test_ext().execute_with(|| {
+		let (_msa_id, key_pair) = create_account();
+
+		assert_noop!(
+			Msa::retire_provider(RuntimeOrigin::signed(key_pair.public().into())),
+			Error::<Test>::ProviderNotRegistered
+		);
+	})
+}
+
+#[test]
+fn retire_provider_twice_fails() {
+	new_test_ext().execute_with(|| {
+		let (_provider_msa_id, provider_account) = create_provider_with_name("Foo");
+		assert_ok!(Msa::retire_provider(RuntimeOrigin::signed(provider_account.into())));
+
+		assert_noop!(
+			Msa::retire_provider(RuntimeOrigin::signed(provider_account.into())),
+			Error::<Test>::ProviderRetiring
+		);
+	})
+}
+
+#[test]
+fn retiring_provider_cannot_be_granted_new_delegations() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, provider_account) = create_provider_with_name("Foo");
+		let (_delegator_msa_id, delegator_pair) = create_account();
+		let delegator_account = delegator_pair.public();
+		let (signature, add_provider_payload) =
+			create_and_sign_add_provider_payload(delegator_pair, provider_msa_id);
+		assert_ok!(Msa::retire_provider(RuntimeOrigin::signed(provider_account.into())));
+
+		assert_noop!(
+			Msa::grant_delegation(
+				RuntimeOrigin::signed(provider_account.into()),
+				delegator_account.into(),
+				signature,
+				add_provider_payload
+			),
+			Error::<Test>::ProviderRetiring
+		);
+	})
+}
+
+#[test]
+fn retiring_provider_cannot_sponsor_new_accounts() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, provider_account) = create_provider_with_name("Foo");
+		let (delegator_pair, _) = sp_core::sr25519::Pair::generate();
+		let delegator_account = delegator_pair.public();
+		let (signature, add_provider_payload) =
+			create_and_sign_add_provider_payload(delegator_pair, provider_msa_id);
+		assert_ok!(Msa::retire_provider(RuntimeOrigin::signed(provider_account.into())));
+
+		assert_noop!(
+			Msa::create_sponsored_account_with_delegation(
+				RuntimeOrigin::signed(provider_account.into()),
+				delegator_account.into(),
+				signature,
+				add_provider_payload
+			),
+			Error::<Test>::ProviderRetiring
+		);
+	})
+}
+
+#[test]
+fn retiring_provider_is_not_a_valid_staking_target() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, provider_account) = create_provider_with_name("Foo");
+		assert!(<Msa as TargetValidator>::validate(provider_msa_id));
+
+		assert_ok!(Msa::retire_provider(RuntimeOrigin::signed(provider_account.into())));
+
+		assert!(!<Msa as TargetValidator>::validate(provider_msa_id));
+	})
+}
+
+#[test]
+fn delegations_to_retiring_provider_are_revoked_after_grace_period() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, provider_account, delegator_msa_id, delegator_account) =
+			create_provider_delegator_msas();
+		let provider_id = ProviderId(provider_msa_id);
+		let delegator_id = DelegatorId(delegator_msa_id);
+		assert_ok!(Msa::retire_provider(RuntimeOrigin::signed(provider_account.into())));
+
+		run_to_block(11);
+		assert_ok!(Msa::ensure_valid_delegation(provider_id, delegator_id, None));
+
+		run_to_block(12);
+		assert_noop!(
+			Msa::ensure_valid_delegation(provider_id, delegator_id, None),
+			Error::<Test>::DelegationRevoked
+		);
+		assert_ok!(Msa::ensure_valid_delegation(provider_id, delegator_id, Some(11)));
+		assert_ok!(CheckFreeExtrinsicUse::<Test>::ensure_msa_can_retire(&delegator_account.into()));
+	})
+}
+
+#[test]
+fn delegation_revoked_before_retirement_keeps_its_revocation_block() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, provider_account, delegator_msa_id, _) =
+			create_provider_delegator_msas();
+		let provider_id = ProviderId(provider_msa_id);
+		let delegator_id = DelegatorId(delegator_msa_id);
+		run_to_block(3);
+		assert_ok!(Msa::revoke_provider(provider_id, delegator_id));
+		assert_ok!(Msa::retire_provider(RuntimeOrigin::signed(provider_account.into())));
+
+		let delegation = Msa::get_delegation_of(delegator_id, provider_id).unwrap();
+
+		assert_eq!(delegation.revoked_at, 3);
+	})
+}
+
+#[test]
+fn complete_provider_retirement_before_grace_period_fails() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, provider_account) = create_provider_with_name("Foo");
+		assert_ok!(Msa::retire_provider(RuntimeOrigin::signed(provider_account.into())));
+
+		run_to_block(11);
+
+		assert_noop!(
+			Msa::complete_provider_retirement(test_origin_signed(1), provider_msa_id),
+			Error::<Test>::ProviderRetirementGracePeriodNotElapsed
+		);
+	})
+}
+
+#[test]
+fn complete_provider_retirement_removes_registry_entry_and_emits_event() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (provider_msa_id, provider_account) = create_provider_with_name("Foo");
+		let provider_id = ProviderId(provider_msa_id);
+		assert_ok!(Msa::retire_provider(RuntimeOrigin::signed(provider_account.into())));
+		run_to_block(12);
+
+		// act
+		assert_ok!(Msa::complete_provider_retirement(test_origin_signed(1), provider_msa_id));
+
+		// assert
+		assert!(ProviderToRegistryEntry::<Test>::get(provider_id).is_none());
+		assert_eq!(Msa::get_provider_info(provider_id), None);
+		System::assert_last_event(Event::ProviderRetired { provider_id }.into());
+	})
+}
+
+#[test]
+fn complete_provider_retirement_twice_fails() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, provider_account) = create_provider_with_name("Foo");
+		assert_ok!(Msa::retire_provider(RuntimeOrigin::signed(provider_account.into())));
+		run_to_block(12);
+		assert_ok!(Msa::complete_provider_retirement(test_origin_signed(1), provider_msa_id));
+
+		assert_noop!(
+			Msa::complete_provider_retirement(test_origin_signed(1), provider_msa_id),
+			Error::<Test>::ProviderNotRegistered
+		);
+	})
+}
+
+#[test]
+fn complete_provider_retirement_for_provider_not_retiring_fails() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, _) = create_provider_with_name("Foo");
+
+		assert_noop!(
+			Msa::complete_provider_retirement(test_origin_signed(1), provider_msa_id),
+			Error::<Test>::ProviderNotRetiring
+		);
+	})
+}
+
+#[test]
+fn retired_provider_cannot_register_again() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, provider_account) = create_provider_with_name("Foo");
+		assert_ok!(Msa::retire_provider(RuntimeOrigin::signed(provider_account.into())));
+		run_to_block(12);
+		assert_ok!(Msa::complete_provider_retirement(test_origin_signed(1), provider_msa_id));
+
+		assert_noop!(
+			Msa::create_provider(RuntimeOrigin::signed(provider_account.into()), Vec::from("Foo")),
+			Error::<Test>::ProviderRetiring
+		);
+	})
+}
+
+#[test]
+fn on_idle_revokes_delegations_to_retired_provider_and_clears_retirement() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (provider_msa_id, provider_account, delegator_msa_id, _) =
+			create_provider_delegator_msas();
+		let provider_id = ProviderId(provider_msa_id);
+		let delegator_id = DelegatorId(delegator_msa_id);
+		assert_ok!(Msa::retire_provider(RuntimeOrigin::signed(provider_account.into())));
+		run_to_block(12);
+		assert_ok!(Msa::complete_provider_retirement(test_origin_signed(1), provider_msa_id));
+
+		// act
+		Msa::on_idle(12, Weight::MAX);
+
+		// assert
+		System::assert_has_event(Event::DelegationRevoked { provider_id, delegator_id }.into());
+		let delegation = DelegatorAndProviderToDelegation::<Test>::get(delegator_id, provider_id)
+			.expect("the revoked delegation should remain");
+		assert_eq!(delegation.revoked_at, 11);
+		assert!(!ProviderRevocationQueue::<Test>::contains_key(provider_id));
+		assert!(ProviderRetirements::<Test>::get(provider_id).is_none());
+		assert_noop!(
+			Msa::ensure_valid_delegation(provider_id, delegator_id, None),
+			Error::<Test>::DelegationRevoked
+		);
+	})
+}
+
+#[test]
+fn revoke_retired_provider_keeps_earlier_revocations_without_events() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (provider_msa_id, provider_account, delegator_msa_id, _) =
+			create_provider_delegator_msas();
+		let provider_id = ProviderId(provider_msa_id);
+		let delegator_id = DelegatorId(delegator_msa_id);
+		run_to_block(3);
+		assert_ok!(Msa::revoke_provider(provider_id, delegator_id));
+		assert_ok!(Msa::retire_provider(RuntimeOrigin::signed(provider_account.into())));
+		run_to_block(14);
+		assert_ok!(Msa::complete_provider_retirement(test_origin_signed(1), provider_msa_id));
+		System::reset_events();
+
+		// act
+		let revoked = Msa::revoke_retired_provider(provider_id, 10);
+
+		// assert
+		assert_eq!(revoked, 1);
+		assert_eq!(System::events().len(), 0);
+		let delegation =
+			DelegatorAndProviderToDelegation::<Test>::get(delegator_id, provider_id).unwrap();
+		assert_eq!(delegation.revoked_at, 3);
+	})
+}
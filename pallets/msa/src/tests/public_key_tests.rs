@@ -3,7 +3,7 @@ use frame_support::{
 	dispatch::{GetDispatchInfo, Pays},
 };
 
-use sp_core::{crypto::AccountId32, sr25519, Encode, Pair};
+use sp_core::{crypto::AccountId32, sr25519, Pair};
 use sp_runtime::{ArithmeticError, MultiSignature};
 
 use crate::{
@@ -29,9 +29,10 @@ fn it_throws_error_when_new_key_verification_fails() {
 			msa_id: new_msa_id,
 			expiration: 10,
 			new_public_key: new_key_pair.public().into(),
+			scope: None,
 		};
 
-		let encode_data_new_key_data = wrap_binary_data(add_new_key_data.encode());
+		let encode_data_new_key_data = wrap_binary_data(add_new_key_data.signed_payload());
 
 		let owner_signature: MultiSignature = owner_key_pair.sign(&encode_data_new_key_data).into();
 		let fake_new_key_signature: MultiSignature =
@@ -61,9 +62,10 @@ fn it_throws_error_when_msa_ownership_verification_fails() {
 			msa_id: new_msa_id,
 			expiration: 10,
 			new_public_key: new_key_pair.public().into(),
+			scope: None,
 		};
 
-		let encode_data_new_key_data = wrap_binary_data(add_new_key_data.encode());
+		let encode_data_new_key_data = wrap_binary_data(add_new_key_data.signed_payload());
 
 		let fake_owner_signature: MultiSignature =
 			fake_owner_pair.sign(&encode_data_new_key_data).into();
@@ -95,9 +97,10 @@ fn it_throws_error_when_not_msa_owner() {
 			msa_id: new_msa_id,
 			expiration: 10,
 			new_public_key: new_key_pair.public().into(),
+			scope: None,
 		};
 
-		let encode_data_new_key_data = wrap_binary_data(add_new_key_data.encode());
+		let encode_data_new_key_data = wrap_binary_data(add_new_key_data.signed_payload());
 
 		let fake_owner_signature: MultiSignature =
 			fake_key_pair.sign(&encode_data_new_key_data).into();
@@ -128,9 +131,10 @@ fn it_throws_error_when_for_duplicate_key() {
 			msa_id: new_msa_id,
 			expiration: 10,
 			new_public_key: new_key_pair.public().into(),
+			scope: None,
 		};
 
-		let encode_data_new_key_data = wrap_binary_data(add_new_key_data.encode());
+		let encode_data_new_key_data = wrap_binary_data(add_new_key_data.signed_payload());
 
 		let owner_signature: MultiSignature = owner_key_pair.sign(&encode_data_new_key_data).into();
 		let new_key_signature: MultiSignature = new_key_pair.sign(&encode_data_new_key_data).into();
@@ -161,8 +165,9 @@ fn add_key_with_more_than_allowed_should_panic() {
 				msa_id: new_msa_id,
 				expiration: 10,
 				new_public_key: new_key_pair.public().into(),
+				scope: None,
 			};
-			let encode_data_new_key_data = wrap_binary_data(add_new_key_data.encode());
+			let encode_data_new_key_data = wrap_binary_data(add_new_key_data.signed_payload());
 
 			let owner_signature: MultiSignature =
 				owner_key_pair.sign(&encode_data_new_key_data).into();
@@ -186,8 +191,9 @@ fn add_key_with_more_than_allowed_should_panic() {
 			msa_id: new_msa_id,
 			expiration: 10,
 			new_public_key: final_key_pair.public().into(),
+			scope: None,
 		};
-		let encode_data_new_key_data = wrap_binary_data(add_new_key_data.encode());
+		let encode_data_new_key_data = wrap_binary_data(add_new_key_data.signed_payload());
 
 		let owner_signature: MultiSignature = owner_key_pair.sign(&encode_data_new_key_data).into();
 		let new_key_signature: MultiSignature =
@@ -217,8 +223,9 @@ fn add_key_with_valid_request_should_store_value_and_event() {
 			msa_id: new_msa_id,
 			expiration: 10,
 			new_public_key: new_key_pair.public().into(),
+			scope: None,
 		};
-		let encode_data_new_key_data = wrap_binary_data(add_new_key_data.encode());
+		let encode_data_new_key_data = wrap_binary_data(add_new_key_data.signed_payload());
 
 		let owner_signature: MultiSignature = owner_key_pair.sign(&encode_data_new_key_data).into();
 		let new_key_signature: MultiSignature = new_key_pair.sign(&encode_data_new_key_data).into();
@@ -256,9 +263,10 @@ fn add_key_with_expired_proof_fails() {
 			msa_id: new_msa_id,
 			expiration: 1,
 			new_public_key: new_key_pair.public().into(),
+			scope: None,
 		};
 
-		let encode_data_new_key_data = wrap_binary_data(add_new_key_data.encode());
+		let encode_data_new_key_data = wrap_binary_data(add_new_key_data.signed_payload());
 
 		let owner_signature: MultiSignature = owner_key_pair.sign(&encode_data_new_key_data).into();
 		let new_key_signature: MultiSignature = new_key_pair.sign(&encode_data_new_key_data).into();
@@ -292,9 +300,10 @@ fn add_key_with_proof_too_far_into_future_fails() {
 			msa_id: new_msa_id,
 			expiration: 202,
 			new_public_key: new_key_pair.public().into(),
+			scope: None,
 		};
 
-		let encode_data_new_key_data = wrap_binary_data(add_new_key_data.encode());
+		let encode_data_new_key_data = wrap_binary_data(add_new_key_data.signed_payload());
 
 		let owner_signature: MultiSignature = owner_key_pair.sign(&encode_data_new_key_data).into();
 		let new_key_signature: MultiSignature = new_key_pair.sign(&encode_data_new_key_data).into();
@@ -324,8 +333,9 @@ fn double_add_key_two_msa_fails() {
 			msa_id: msa_id1,
 			expiration: 10,
 			new_public_key: msa_2_owner_key_pair.public().into(),
+			scope: None,
 		};
-		let encode_data_new_key_data = wrap_binary_data(add_new_key_data.encode());
+		let encode_data_new_key_data = wrap_binary_data(add_new_key_data.signed_payload());
 
 		let owner_signature: MultiSignature = owner_key_pair.sign(&encode_data_new_key_data).into();
 		let new_key_signature: MultiSignature =
@@ -356,8 +366,9 @@ fn add_public_key_to_msa_registers_two_signatures() {
 			msa_id: msa_id1,
 			expiration: 10,
 			new_public_key: new_key_pair.public().into(),
+			scope: None,
 		};
-		let encode_data_new_key_data = wrap_binary_data(add_new_key_data.encode());
+		let encode_data_new_key_data = wrap_binary_data(add_new_key_data.signed_payload());
 
 		let owner_signature: MultiSignature = owner_key_pair.sign(&encode_data_new_key_data).into();
 		let new_key_signature: MultiSignature = new_key_pair.sign(&encode_data_new_key_data).into();
@@ -399,8 +410,9 @@ fn add_removed_key_to_msa_pass() {
 			msa_id: msa_getting_a_second_key,
 			expiration: 10,
 			new_public_key: prior_msa_key.public().into(),
+			scope: None,
 		};
-		let encode_data_new_key_data = wrap_binary_data(add_new_key_data.encode());
+		let encode_data_new_key_data = wrap_binary_data(add_new_key_data.signed_payload());
 		let owner_signature: MultiSignature = owner_key_pair.sign(&encode_data_new_key_data).into();
 		let new_key_signature: MultiSignature =
 			prior_msa_key.sign(&encode_data_new_key_data).into();
@@ -0,0 +1,475 @@
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+
+use crate::{
+	tests::mock::*, types::KeyScope, ActiveRecoveries, Error, Event, PublicKeyCountForMsaId,
+	PublicKeyScopes, PublicKeyToMsaId, RecoveryConfigs, RetiredMsaPurgeQueue,
+};
+use common_primitives::msa::MessageSourceId;
+use sp_core::{sr25519, Pair, H256};
+use sp_runtime::AccountId32;
+
+fn guardian_ids(
+	guardians: &[(MessageSourceId, sr25519::Pair)],
+) -> BoundedVec<MessageSourceId, <Test as crate::Config>::MaxRecoveryGuardians> {
+	guardians.iter().map(|(id, _)| *id).collect::<Vec<_>>().try_into().unwrap()
+}
+
+fn signed(pair: &sr25519::Pair) -> RuntimeOrigin {
+	RuntimeOrigin::signed(pair.public().into())
+}
+
+/// Creates an MSA with `guardian_count` guardians, `threshold` of which must approve a recovery
+fn create_msa_with_guardians(
+	guardian_count: usize,
+	threshold: u32,
+	commitment: Option<H256>,
+) -> (MessageSourceId, sr25519::Pair, Vec<(MessageSourceId, sr25519::Pair)>) {
+	let (msa_id, owner_pair) = create_account();
+	let guardians: Vec<_> = (0..guardian_count).map(|_| create_account()).collect();
+	assert_ok!(Msa::set_recovery_config(
+		signed(&owner_pair),
+		guardian_ids(&guardians),
+		threshold,
+		commitment
+	));
+	(msa_id, owner_pair, guardians)
+}
+
+#[test]
+fn set_recovery_config_stores_guardians() {
+	new_test_ext().execute_with(|| {
+		let (msa_id, _, guardians) = create_msa_with_guardians(3, 2, None);
+
+		System::assert_last_event(Event::RecoveryConfigSet { msa_id }.into());
+		let config = RecoveryConfigs::<Test>::get(msa_id).unwrap();
+		assert_eq!(config.guardians, guardian_ids(&guardians));
+		assert_eq!(config.threshold, 2);
+	})
+}
+
+#[test]
+fn set_recovery_config_rejects_invalid_configs() {
+	new_test_ext().execute_with(|| {
+		let (msa_id, owner_pair) = create_account();
+		let (guardian_id, _) = create_account();
+
+		let invalid_configs: Vec<(Vec<MessageSourceId>, u32)> = vec![
+			(vec![guardian_id], 0),
+			(vec![guardian_id], 2),
+			(vec![guardian_id, guardian_id], 1),
+			(vec![msa_id], 1),
+			(vec![guardian_id + 100], 1),
+		];
+		for (guardians, threshold) in invalid_configs {
+			assert_noop!(
+				Msa::set_recovery_config(
+					signed(&owner_pair),
+					guardians.try_into().unwrap(),
+					threshold,
+					None
+				),
+				Error::<Test>::InvalidRecoveryConfig
+			);
+		}
+	})
+}
+
+#[test]
+fn recovery_adds_key_after_threshold_and_delay() {
+	new_test_ext().execute_with(|| {
+		let (msa_id, _, guardians) = create_msa_with_guardians(3, 2, None);
+		let new_key = test_public(200);
+
+		assert_ok!(Msa::initiate_recovery(
+			signed(&guardians[0].1),
+			msa_id,
+			new_key.clone(),
+			None,
+			None
+		));
+		System::assert_last_event(
+			Event::RecoveryInitiated {
+				msa_id,
+				guardian_msa_id: guardians[0].0,
+				new_key: new_key.clone(),
+			}
+			.into(),
+		);
+
+		assert_noop!(
+			Msa::complete_recovery(signed(&guardians[0].1), msa_id),
+			Error::<Test>::RecoveryThresholdNotMet
+		);
+
+		assert_ok!(Msa::approve_recovery(signed(&guardians[1].1), msa_id, new_key.clone()));
+		System::assert_last_event(
+			Event::RecoveryApproved { msa_id, guardian_msa_id: guardians[1].0 }.into(),
+		);
+
+		assert_noop!(
+			Msa::complete_recovery(signed(&guardians[0].1), msa_id),
+			Error::<Test>::RecoveryDelayNotElapsed
+		);
+
+		run_to_block(11);
+		assert_ok!(Msa::complete_recovery(signed(&guardians[2].1), msa_id));
+		System::assert_last_event(
+			Event::RecoveryCompleted { msa_id, new_key: new_key.clone() }.into(),
+		);
+		assert_eq!(PublicKeyToMsaId::<Test>::get(&new_key), Some(msa_id));
+		assert!(ActiveRecoveries::<Test>::get(msa_id).is_none());
+	})
+}
+
+#[test]
+fn owner_can_cancel_recovery_during_delay() {
+	new_test_ext().execute_with(|| {
+		let (msa_id, owner_pair, guardians) = create_msa_with_guardians(1, 1, None);
+		let new_key = test_public(200);
+		assert_ok!(Msa::initiate_recovery(
+			signed(&guardians[0].1),
+			msa_id,
+			new_key.clone(),
+			None,
+			None
+		));
+
+		assert_ok!(Msa::cancel_recovery(signed(&owner_pair)));
+		System::assert_last_event(Event::RecoveryCancelled { msa_id }.into());
+
+		run_to_block(11);
+		assert_noop!(
+			Msa::complete_recovery(signed(&guardians[0].1), msa_id),
+			Error::<Test>::RecoveryNotInProgress
+		);
+		assert_eq!(PublicKeyToMsaId::<Test>::get(&new_key), None);
+	})
+}
+
+#[test]
+fn cancel_recovery_fails_when_no_recovery_in_progress() {
+	new_test_ext().execute_with(|| {
+		let (_, owner_pair, _) = create_msa_with_guardians(1, 1, None);
+
+		assert_noop!(
+			Msa::cancel_recovery(signed(&owner_pair)),
+			Error::<Test>::RecoveryNotInProgress
+		);
+	})
+}
+
+#[test]
+fn initiate_recovery_requires_guardian() {
+	new_test_ext().execute_with(|| {
+		let (msa_id, _, _) = create_msa_with_guardians(1, 1, None);
+		let (_, stranger_pair) = create_account();
+
+		assert_noop!(
+			Msa::initiate_recovery(signed(&stranger_pair), msa_id, test_public(200), None, None),
+			Error::<Test>::NotRecoveryGuardian
+		);
+	})
+}
+
+#[test]
+fn initiate_recovery_fails_when_not_configured() {
+	new_test_ext().execute_with(|| {
+		let (msa_id, _) = create_account();
+		let (_, guardian_pair) = create_account();
+
+		assert_noop!(
+			Msa::initiate_recovery(signed(&guardian_pair), msa_id, test_public(200), None, None),
+			Error::<Test>::RecoveryNotConfigured
+		);
+	})
+}
+
+#[test]
+fn initiate_recovery_checks_commitment() {
+	new_test_ext().execute_with(|| {
+		let secret = [7u8; 32];
+		let commitment = H256(sp_io::hashing::blake2_256(&secret));
+		let (msa_id, _, guardians) = create_msa_with_guardians(1, 1, Some(commitment));
+
+		assert_noop!(
+			Msa::initiate_recovery(signed(&guardians[0].1), msa_id, test_public(200), None, None),
+			Error::<Test>::InvalidRecoverySecret
+		);
+		assert_noop!(
+			Msa::initiate_recovery(
+				signed(&guardians[0].1),
+				msa_id,
+				test_public(200),
+				None,
+				Some([8u8; 32])
+			),
+			Error::<Test>::InvalidRecoverySecret
+		);
+		assert_ok!(Msa::initiate_recovery(
+			signed(&guardians[0].1),
+			msa_id,
+			test_public(200),
+			None,
+			Some(secret)
+		));
+	})
+}
+
+#[test]
+fn initiate_recovery_fails_when_already_in_progress() {
+	new_test_ext().execute_with(|| {
+		let (msa_id, _, guardians) = create_msa_with_guardians(2, 1, None);
+		assert_ok!(Msa::initiate_recovery(
+			signed(&guardians[0].1),
+			msa_id,
+			test_public(200),
+			None,
+			None
+		));
+
+		assert_noop!(
+			Msa::initiate_recovery(signed(&guardians[1].1), msa_id, test_public(201), None, None),
+			Error::<Test>::RecoveryAlreadyInProgress
+		);
+	})
+}
+
+#[test]
+fn initiate_recovery_fails_when_key_already_registered() {
+	new_test_ext().execute_with(|| {
+		let (msa_id, _, guardians) = create_msa_with_guardians(1, 1, None);
+
+		assert_noop!(
+			Msa::initiate_recovery(
+				signed(&guardians[0].1),
+				msa_id,
+				guardians[0].1.public().into(),
+				None,
+				None
+			),
+			Error::<Test>::KeyAlreadyRegistered
+		);
+	})
+}
+
+#[test]
+fn approve_recovery_rejects_mismatched_key_and_duplicate_approval() {
+	new_test_ext().execute_with(|| {
+		let (msa_id, _, guardians) = create_msa_with_guardians(2, 2, None);
+		assert_ok!(Msa::initiate_recovery(
+			signed(&guardians[0].1),
+			msa_id,
+			test_public(200),
+			None,
+			None
+		));
+
+		assert_noop!(
+			Msa::approve_recovery(signed(&guardians[1].1), msa_id, test_public(201)),
+			Error::<Test>::RecoveryKeyMismatch
+		);
+		assert_noop!(
+			Msa::approve_recovery(signed(&guardians[0].1), msa_id, test_public(200)),
+			Error::<Test>::RecoveryAlreadyApproved
+		);
+	})
+}
+
+#[test]
+fn recovery_config_cannot_change_during_recovery() {
+	new_test_ext().execute_with(|| {
+		let (msa_id, owner_pair, guardians) = create_msa_with_guardians(1, 1, None);
+		assert_ok!(Msa::initiate_recovery(
+			signed(&guardians[0].1),
+			msa_id,
+			test_public(200),
+			None,
+			None
+		));
+
+		assert_noop!(
+			Msa::remove_recovery_config(signed(&owner_pair)),
+			Error::<Test>::RecoveryAlreadyInProgress
+		);
+		assert_noop!(
+			Msa::set_recovery_config(signed(&owner_pair), guardian_ids(&guardians), 1, None),
+			Error::<Test>::RecoveryAlreadyInProgress
+		);
+	})
+}
+
+#[test]
+fn remove_recovery_config_removes_guardians() {
+	new_test_ext().execute_with(|| {
+		let (msa_id, owner_pair, _) = create_msa_with_guardians(1, 1, None);
+
+		assert_ok!(Msa::remove_recovery_config(signed(&owner_pair)));
+		System::assert_last_event(Event::RecoveryConfigRemoved { msa_id }.into());
+		assert!(RecoveryConfigs::<Test>::get(msa_id).is_none());
+	})
+}
+
+#[test]
+fn retired_msa_cannot_be_recovered() {
+	new_test_ext().execute_with(|| {
+		let (msa_id, owner_pair, guardians) = create_msa_with_guardians(1, 1, None);
+		assert_ok!(Msa::retire_msa(signed(&owner_pair)));
+
+		assert_noop!(
+			Msa::initiate_recovery(signed(&guardians[0].1), msa_id, test_public(200), None, None),
+			Error::<Test>::NoKeyExists
+		);
+	})
+}
+
+#[test]
+fn recovery_replaces_key_when_msa_has_maximum_keys() {
+	new_test_ext().execute_with(|| {
+		MaxPublicKeysPerMsa::set(1);
+		let (msa_id, owner_pair, guardians) = create_msa_with_guardians(1, 1, None);
+		let owner_key: AccountId32 = owner_pair.public().into();
+		let new_key = test_public(200);
+
+		assert_ok!(Msa::initiate_recovery(
+			signed(&guardians[0].1),
+			msa_id,
+			new_key.clone(),
+			Some(owner_key.clone()),
+			None
+		));
+		run_to_block(11);
+		assert_ok!(Msa::complete_recovery(signed(&guardians[0].1), msa_id));
+
+		assert_eq!(PublicKeyToMsaId::<Test>::get(&owner_key), None);
+		assert_eq!(PublicKeyToMsaId::<Test>::get(&new_key), Some(msa_id));
+		assert_eq!(PublicKeyCountForMsaId::<Test>::get(msa_id), 1);
+	})
+}
+
+#[test]
+fn recovery_without_replaced_key_fails_when_msa_has_maximum_keys() {
+	new_test_ext().execute_with(|| {
+		MaxPublicKeysPerMsa::set(1);
+		let (msa_id, _, guardians) = create_msa_with_guardians(1, 1, None);
+
+		assert_ok!(Msa::initiate_recovery(
+			signed(&guardians[0].1),
+			msa_id,
+			test_public(200),
+			None,
+			None
+		));
+		run_to_block(11);
+		assert_noop!(
+			Msa::complete_recovery(signed(&guardians[0].1), msa_id),
+			Error::<Test>::KeyLimitExceeded
+		);
+	})
+}
+
+#[test]
+fn initiate_recovery_rejects_replaced_key_of_another_msa() {
+	new_test_ext().execute_with(|| {
+		let (msa_id, _, guardians) = create_msa_with_guardians(1, 1, None);
+		let (_, stranger_pair) = create_account();
+
+		assert_noop!(
+			Msa::initiate_recovery(
+				signed(&guardians[0].1),
+				msa_id,
+				test_public(200),
+				Some(stranger_pair.public().into()),
+				None
+			),
+			Error::<Test>::NotMsaOwner
+		);
+	})
+}
+
+#[test]
+fn retiring_msa_removes_its_recovery() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (msa_id, owner_pair, guardians) = create_msa_with_guardians(1, 1, None);
+		let new_key = test_public(200);
+		assert_ok!(Msa::initiate_recovery(
+			signed(&guardians[0].1),
+			msa_id,
+			new_key.clone(),
+			None,
+			None
+		));
+
+		// act
+		assert_ok!(Msa::retire_msa(signed(&owner_pair)));
+
+		// assert
+		assert!(RecoveryConfigs::<Test>::get(msa_id).is_none());
+		assert!(ActiveRecoveries::<Test>::get(msa_id).is_none());
+		run_to_block(11);
+		assert_noop!(
+			Msa::complete_recovery(signed(&guardians[0].1), msa_id),
+			Error::<Test>::NoKeyExists
+		);
+		assert_eq!(PublicKeyToMsaId::<Test>::get(&new_key), None);
+	})
+}
+
+#[test]
+fn complete_recovery_fails_for_a_retired_msa() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (msa_id, _, guardians) = create_msa_with_guardians(1, 1, None);
+		let new_key = test_public(200);
+		assert_ok!(Msa::initiate_recovery(
+			signed(&guardians[0].1),
+			msa_id,
+			new_key.clone(),
+			None,
+			None
+		));
+		RetiredMsaPurgeQueue::<Test>::insert(msa_id, ());
+		run_to_block(11);
+
+		// act & assert
+		assert_noop!(
+			Msa::complete_recovery(signed(&guardians[0].1), msa_id),
+			Error::<Test>::NoKeyExists
+		);
+	})
+}
+
+#[test]
+fn scoped_guardian_key_without_msa_management_cannot_initiate_or_approve_recovery() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (msa_id, _, guardians) = create_msa_with_guardians(2, 2, None);
+		let new_key = test_public(200);
+		let scope = KeyScope { can_manage_msa: false, schema_ids: None };
+		for (_, guardian_pair) in &guardians {
+			PublicKeyScopes::<Test>::insert(
+				AccountId32::from(guardian_pair.public()),
+				scope.clone(),
+			);
+		}
+
+		// act & assert
+		assert_noop!(
+			Msa::initiate_recovery(signed(&guardians[0].1), msa_id, new_key.clone(), None, None),
+			Error::<Test>::KeyScopeRestricted
+		);
+
+		PublicKeyScopes::<Test>::remove(AccountId32::from(guardians[0].1.public()));
+		assert_ok!(Msa::initiate_recovery(
+			signed(&guardians[0].1),
+			msa_id,
+			new_key.clone(),
+			None,
+			None
+		));
+		assert_noop!(
+			Msa::approve_recovery(signed(&guardians[1].1), msa_id, new_key),
+			Error::<Test>::KeyScopeRestricted
+		);
+	})
+}
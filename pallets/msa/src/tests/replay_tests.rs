@@ -1,13 +1,18 @@
 use crate::{tests::mock::*, AddKeyData, AddProvider, Error};
 
-use common_primitives::{node::BlockNumber, utils::wrap_binary_data};
+use common_primitives::{
+	node::BlockNumber, signatures::ecdsa_signature_twin, utils::wrap_binary_data,
+};
 use frame_support::{assert_err, assert_noop, assert_ok};
-use sp_core::{sr25519, Encode, Pair};
-use sp_runtime::MultiSignature;
+use sp_core::{ecdsa, sr25519, Pair};
+use sp_runtime::{
+	traits::{IdentifyAccount, Verify},
+	MultiSignature, MultiSigner,
+};
 
 fn create_add_provider_payload(signature_expiration: BlockNumber) -> (AddProvider, Vec<u8>) {
 	let add_provider_payload = AddProvider::new(1u64, None, signature_expiration);
-	let encode_add_provider_data = wrap_binary_data(add_provider_payload.encode());
+	let encode_add_provider_data = wrap_binary_data(add_provider_payload.signed_payload());
 	(add_provider_payload, encode_add_provider_data)
 }
 
@@ -35,9 +40,13 @@ pub fn user_adds_key_to_msa(
 	delegator_pair: sp_core::sr25519::Pair,
 	new_pair: sp_core::sr25519::Pair,
 ) {
-	let add_key_payload =
-		AddKeyData { msa_id: 2, expiration: 109, new_public_key: new_pair.public().into() };
-	let encode_add_key_data = wrap_binary_data(add_key_payload.encode());
+	let add_key_payload = AddKeyData {
+		msa_id: 2,
+		expiration: 109,
+		new_public_key: new_pair.public().into(),
+		scope: None,
+	};
+	let encode_add_key_data = wrap_binary_data(add_key_payload.signed_payload());
 	let msa_owner_signature = delegator_pair.sign(&encode_add_key_data);
 	let signature_new_key: MultiSignature = new_pair.sign(&encode_add_key_data).into();
 
@@ -289,10 +298,14 @@ fn replaying_create_sponsored_account_with_delegation_fails_03() {
 		let (new_key_pair, _) = sr25519::Pair::generate();
 		let new_public_key = new_key_pair.public();
 
-		let add_new_key_data =
-			AddKeyData { msa_id: 2, expiration: 10, new_public_key: new_key_pair.public().into() };
+		let add_new_key_data = AddKeyData {
+			msa_id: 2,
+			expiration: 10,
+			new_public_key: new_key_pair.public().into(),
+			scope: None,
+		};
 
-		let encode_add_key_data = wrap_binary_data(add_new_key_data.encode());
+		let encode_add_key_data = wrap_binary_data(add_new_key_data.signed_payload());
 
 		let msa_owner_signature = delegator_keypair.sign(&encode_add_key_data);
 		let new_key_owner_signature = new_key_pair.sign(&encode_add_key_data);
@@ -326,3 +339,58 @@ fn replaying_create_sponsored_account_with_delegation_fails_03() {
 		);
 	});
 }
+
+/// The twin of the secp256k1 `signature` with `n - s`, which verifies just like it
+fn high_s_form_of(signature: &MultiSignature) -> MultiSignature {
+	let MultiSignature::Ecdsa(signature) = signature else { panic!("not an ecdsa signature") };
+	MultiSignature::Ecdsa(ecdsa::Signature::from_raw(ecdsa_signature_twin(&signature.0)))
+}
+
+#[test]
+fn replaying_add_public_key_to_msa_with_high_s_form_of_signatures_fails() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (owner_pair, _) = ecdsa::Pair::generate();
+		let owner_key = MultiSigner::from(owner_pair.public()).into_account();
+		assert_ok!(Msa::create(RuntimeOrigin::signed(owner_key.clone())));
+		let (new_pair, _) = ecdsa::Pair::generate();
+		let new_key = MultiSigner::from(new_pair.public()).into_account();
+
+		let add_key_payload = AddKeyData::<Test> {
+			msa_id: 1,
+			expiration: 10,
+			new_public_key: new_key.clone(),
+			scope: None,
+		};
+		let encoded_payload = wrap_binary_data(add_key_payload.signed_payload());
+		let owner_signature: MultiSignature = owner_pair.sign(&encoded_payload).into();
+		let new_key_signature: MultiSignature = new_pair.sign(&encoded_payload).into();
+		assert_ok!(Msa::add_public_key_to_msa(
+			test_origin_signed(1),
+			owner_key.clone(),
+			owner_signature.clone(),
+			new_key_signature.clone(),
+			add_key_payload.clone()
+		));
+		assert_ok!(Msa::delete_msa_public_key(
+			RuntimeOrigin::signed(owner_key.clone()),
+			new_key.clone()
+		));
+		let owner_twin = high_s_form_of(&owner_signature);
+		let new_key_twin = high_s_form_of(&new_key_signature);
+		assert!(owner_twin.verify(&encoded_payload[..], &owner_key));
+		assert!(new_key_twin.verify(&encoded_payload[..], &new_key));
+
+		// act and assert
+		assert_noop!(
+			Msa::add_public_key_to_msa(
+				test_origin_signed(1),
+				owner_key,
+				owner_twin,
+				new_key_twin,
+				add_key_payload
+			),
+			Error::<Test>::SignatureAlreadySubmitted
+		);
+	});
+}
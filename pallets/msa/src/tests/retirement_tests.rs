@@ -1,7 +1,7 @@
-use frame_support::{assert_err, assert_noop, assert_ok, pallet_prelude::InvalidTransaction};
+use frame_support::{assert_noop, assert_ok, pallet_prelude::InvalidTransaction};
 
 use frame_system::pallet_prelude::BlockNumberFor;
-use sp_core::{crypto::AccountId32, sr25519, Encode, Pair};
+use sp_core::{crypto::AccountId32, sr25519, Pair};
 use sp_runtime::MultiSignature;
 
 use crate::{
@@ -52,10 +52,14 @@ fn test_retire_msa_success() {
 		let new_account1 = key_pair1.public();
 		let (msa_id2, _) = create_account();
 
-		let add_new_key_data =
-			AddKeyData { msa_id: msa_id2, expiration: 10, new_public_key: new_account1.into() };
+		let add_new_key_data = AddKeyData {
+			msa_id: msa_id2,
+			expiration: 10,
+			new_public_key: new_account1.into(),
+			scope: None,
+		};
 
-		let encode_data_new_key_data = wrap_binary_data(add_new_key_data.encode());
+		let encode_data_new_key_data = wrap_binary_data(add_new_key_data.signed_payload());
 		let old_msa_owner_signature: MultiSignature =
 			test_account_key_pair.sign(&encode_data_new_key_data).into();
 		let new_key_signature: MultiSignature = key_pair1.sign(&encode_data_new_key_data).into();
@@ -164,7 +168,7 @@ fn test_ensure_msa_can_retire_fails_if_more_than_one_account_exists() {
 }
 
 #[test]
-fn test_ensure_msa_can_retire_fails_if_any_active_delegations_exist() {
+fn test_ensure_msa_can_retire_with_active_delegations() {
 	new_test_ext().execute_with(|| {
 		// Create delegator
 		let msa_id = 2;
@@ -176,18 +180,20 @@ fn test_ensure_msa_can_retire_fails_if_any_active_delegations_exist() {
 		let (provider_id, _provider_key) = create_provider_with_name("test");
 		let schema_ids = vec![1];
 		set_schema_count::<Test>(1);
-		assert_ok!(Msa::add_provider(ProviderId(provider_id), DelegatorId(msa_id), schema_ids));
+		assert_ok!(Msa::add_provider(
+			ProviderId(provider_id),
+			DelegatorId(msa_id),
+			schema_ids,
+			None
+		));
 
-		// Retire the MSA
-		assert_err!(
-			CheckFreeExtrinsicUse::<Test>::ensure_msa_can_retire(&test_account),
-			InvalidTransaction::Custom(ValidityError::InvalidNonZeroProviderDelegations as u8)
-		);
+		// Delegations are purged after the MSA is retired
+		assert_ok!(CheckFreeExtrinsicUse::<Test>::ensure_msa_can_retire(&test_account));
 	})
 }
 
 #[test]
-fn test_ensure_msa_cannot_retire_if_handle_exists() {
+fn test_ensure_msa_can_retire_if_handle_exists() {
 	new_test_ext().execute_with(|| {
 		let msa_id = 1;
 		let (test_account_1_key_pair, _) = sr25519::Pair::generate();
@@ -206,10 +212,7 @@ fn test_ensure_msa_cannot_retire_if_handle_exists() {
 		assert_ok!(pallet_handles::Pallet::<Test>::do_claim_handle(msa_id, claim_payload));
 
 		// Assumption: handle exists
-		// Retire the MSA
-		assert_noop!(
-			CheckFreeExtrinsicUse::<Test>::ensure_msa_can_retire(&test_account_1),
-			InvalidTransaction::Custom(ValidityError::HandleNotRetired as u8)
-		);
+		// The handle is purged after the MSA is retired
+		assert_ok!(CheckFreeExtrinsicUse::<Test>::ensure_msa_can_retire(&test_account_1));
 	})
 }
@@ -4,11 +4,16 @@ use frame_system::pallet_prelude::BlockNumberFor;
 use crate::{
 	tests::{mock::*, other_tests::set_schema_count},
 	types::PermittedDelegationSchemas,
-	Config, DelegatorAndProviderToDelegation, Error,
+	Config, DelegationSchemaPermissions, DelegatorAndProviderToDelegation, Error, Event,
 };
 
+use sp_core::Pair;
+
 use common_primitives::{
-	msa::{Delegation, DelegatorId, ProviderId, SchemaGrantValidator},
+	msa::{
+		Delegation, DelegatorId, ProviderId, SchemaGrantPermission, SchemaGrantValidator,
+		SchemaPermissions,
+	},
 	schema::SchemaId,
 };
 
@@ -32,7 +37,7 @@ fn grant_permissions_for_schemas_errors_when_invalid_schema_id() {
 		let provider = ProviderId(1);
 		let schema_grants = vec![1];
 
-		assert_ok!(Msa::add_provider(provider, delegator, schema_grants));
+		assert_ok!(Msa::add_provider(provider, delegator, schema_grants, None));
 
 		let additional_grants = vec![2];
 		let result = Msa::grant_permissions_for_schemas(delegator, provider, additional_grants);
@@ -50,7 +55,7 @@ fn grant_permissions_for_schemas_errors_when_exceeds_max_schema_grants() {
 		let provider = ProviderId(1);
 		let schema_grants = vec![1];
 
-		assert_ok!(Msa::add_provider(provider, delegator, schema_grants));
+		assert_ok!(Msa::add_provider(provider, delegator, schema_grants, None));
 
 		let additional_grants = (2..32 as u16).collect::<Vec<_>>();
 		let result = Msa::grant_permissions_for_schemas(delegator, provider, additional_grants);
@@ -68,7 +73,7 @@ fn grant_permissions_for_schema_success() {
 		let provider = ProviderId(1);
 		let schema_grants = vec![1];
 
-		assert_ok!(Msa::add_provider(provider, delegator, schema_grants));
+		assert_ok!(Msa::add_provider(provider, delegator, schema_grants, None));
 
 		let delegation_relationship =
 			DelegatorAndProviderToDelegation::<Test>::get(delegator, provider).unwrap();
@@ -147,7 +152,7 @@ fn revoke_permissions_for_schema_success() {
 		let provider = ProviderId(1);
 		let schema_grants = vec![1];
 
-		assert_ok!(Msa::add_provider(provider, delegator, schema_grants));
+		assert_ok!(Msa::add_provider(provider, delegator, schema_grants, None));
 
 		let delegation_relationship =
 			DelegatorAndProviderToDelegation::<Test>::get(delegator, provider).unwrap();
@@ -203,7 +208,7 @@ fn revoke_permissions_for_schemas_errors_when_schema_does_not_exist_in_list_of_s
 		let provider = ProviderId(1);
 		let schema_grants = vec![1, 2];
 
-		assert_ok!(Msa::add_provider(provider, delegator, schema_grants));
+		assert_ok!(Msa::add_provider(provider, delegator, schema_grants, None));
 
 		let additional_grants = (3..32 as u16).collect::<Vec<_>>();
 		let result = Msa::revoke_permissions_for_schemas(delegator, provider, additional_grants);
@@ -274,11 +279,17 @@ pub fn ensure_valid_schema_grant_success() {
 		let provider = ProviderId(1);
 		let delegator = DelegatorId(2);
 		let schema_grants = vec![1, 2];
-		assert_ok!(Msa::add_provider(provider, delegator, schema_grants));
+		assert_ok!(Msa::add_provider(provider, delegator, schema_grants, None));
 
 		System::set_block_number(System::block_number() + 1);
 
-		assert_ok!(Msa::ensure_valid_schema_grant(provider, delegator, 1_u16, 1u32));
+		assert_ok!(Msa::ensure_valid_schema_grant(
+			provider,
+			delegator,
+			1_u16,
+			1u32,
+			SchemaGrantPermission::Create
+		));
 	})
 }
 
@@ -293,13 +304,19 @@ pub fn ensure_valid_schema_grant_errors_when_delegation_relationship_is_valid_an
 		let schema_grants = vec![1, 2];
 
 		// Add delegation relationship with schema grants.
-		assert_ok!(Msa::add_provider(provider, delegator, schema_grants));
+		assert_ok!(Msa::add_provider(provider, delegator, schema_grants, None));
 
 		// Set block number to 2.
 		System::set_block_number(System::block_number() + 1);
 
 		assert_err!(
-			Msa::ensure_valid_schema_grant(provider, delegator, 3_u16, 1u32),
+			Msa::ensure_valid_schema_grant(
+				provider,
+				delegator,
+				3_u16,
+				1u32,
+				SchemaGrantPermission::Create
+			),
 			Error::<Test>::SchemaNotGranted
 		);
 	})
@@ -315,7 +332,7 @@ pub fn ensure_valid_schema_grant_errors_when_delegation_relationship_is_valid_an
 		let provider = ProviderId(1);
 		let delegator = DelegatorId(2);
 		let schema_grants = vec![1, 2];
-		assert_ok!(Msa::add_provider(provider, delegator, schema_grants));
+		assert_ok!(Msa::add_provider(provider, delegator, schema_grants, None));
 
 		// Set block number to 6.
 		System::set_block_number(System::block_number() + 5);
@@ -324,11 +341,23 @@ pub fn ensure_valid_schema_grant_errors_when_delegation_relationship_is_valid_an
 		assert_ok!(Msa::revoke_permissions_for_schemas(delegator, provider, vec![1]));
 
 		// Schemas is valid for the current block that is revoked 6
-		assert_ok!(Msa::ensure_valid_schema_grant(provider, delegator, 1, 6));
+		assert_ok!(Msa::ensure_valid_schema_grant(
+			provider,
+			delegator,
+			1,
+			6,
+			SchemaGrantPermission::Create
+		));
 
 		// Checking that asking for validity past the current block, 6, errors.
 		assert_noop!(
-			Msa::ensure_valid_schema_grant(provider, delegator, 1, 7),
+			Msa::ensure_valid_schema_grant(
+				provider,
+				delegator,
+				1,
+				7,
+				SchemaGrantPermission::Create
+			),
 			Error::<Test>::CannotPredictValidityPastCurrentBlock
 		);
 
@@ -337,7 +366,13 @@ pub fn ensure_valid_schema_grant_errors_when_delegation_relationship_is_valid_an
 		assert_eq!(System::block_number(), 11);
 
 		assert_noop!(
-			Msa::ensure_valid_schema_grant(provider, delegator, 1, 7),
+			Msa::ensure_valid_schema_grant(
+				provider,
+				delegator,
+				1,
+				7,
+				SchemaGrantPermission::Create
+			),
 			Error::<Test>::SchemaNotGranted
 		);
 	});
@@ -356,7 +391,7 @@ pub fn ensure_valid_schema_grant_errors_delegation_revoked_when_delegation_relat
 		let schema_grants = vec![1, 2];
 
 		// Create delegation relationship.
-		assert_ok!(Msa::add_provider(provider, delegator, schema_grants));
+		assert_ok!(Msa::add_provider(provider, delegator, schema_grants, None));
 
 		// Move forward to block 6.
 		System::set_block_number(System::block_number() + 5);
@@ -365,12 +400,30 @@ pub fn ensure_valid_schema_grant_errors_delegation_revoked_when_delegation_relat
 		assert_ok!(Msa::revoke_provider(provider, delegator));
 
 		// Schemas is valid for the current block that is revoked 6.
-		assert_ok!(Msa::ensure_valid_schema_grant(provider, delegator, 1, 6));
-		assert_ok!(Msa::ensure_valid_schema_grant(provider, delegator, 1, 5));
+		assert_ok!(Msa::ensure_valid_schema_grant(
+			provider,
+			delegator,
+			1,
+			6,
+			SchemaGrantPermission::Create
+		));
+		assert_ok!(Msa::ensure_valid_schema_grant(
+			provider,
+			delegator,
+			1,
+			5,
+			SchemaGrantPermission::Create
+		));
 
 		// Checking that asking for validity past the current block, 6, errors.
 		assert_noop!(
-			Msa::ensure_valid_schema_grant(provider, delegator, 1, 8),
+			Msa::ensure_valid_schema_grant(
+				provider,
+				delegator,
+				1,
+				8,
+				SchemaGrantPermission::Create
+			),
 			Error::<Test>::CannotPredictValidityPastCurrentBlock
 		);
 
@@ -379,7 +432,13 @@ pub fn ensure_valid_schema_grant_errors_delegation_revoked_when_delegation_relat
 
 		// Check that schema is not valid after delegation revocation
 		assert_noop!(
-			Msa::ensure_valid_schema_grant(provider, delegator, 1, 7),
+			Msa::ensure_valid_schema_grant(
+				provider,
+				delegator,
+				1,
+				7,
+				SchemaGrantPermission::Create
+			),
 			Error::<Test>::DelegationRevoked
 		);
 	});
@@ -397,7 +456,7 @@ pub fn ensure_delegation_revocation_reflects_in_schema_permissions() {
 		let schema_grants = vec![1, 2];
 
 		// Create delegation relationship.
-		assert_ok!(Msa::add_provider(provider, delegator, schema_grants));
+		assert_ok!(Msa::add_provider(provider, delegator, schema_grants, None));
 
 		// Move forward to block 6.
 		System::set_block_number(System::block_number() + 5);
@@ -414,3 +473,166 @@ pub fn ensure_delegation_revocation_reflects_in_schema_permissions() {
 		assert!(grants.permissions[1].revoked_at == 6);
 	});
 }
+
+#[test]
+fn set_schema_grant_permissions_restricts_schema_grant() {
+	new_test_ext().execute_with(|| {
+		set_schema_count::<Test>(2);
+		let (provider_msa_id, _) = create_account();
+		let (delegator_msa_id, delegator_pair) = create_account();
+		let provider = ProviderId(provider_msa_id);
+		let delegator = DelegatorId(delegator_msa_id);
+		assert_ok!(Msa::add_provider(provider, delegator, vec![1, 2], None));
+
+		let permissions = SchemaPermissions { create: true, update: false, delete: false };
+		assert_ok!(Msa::set_schema_grant_permissions(
+			RuntimeOrigin::signed(delegator_pair.public().into()),
+			provider_msa_id,
+			1,
+			permissions
+		));
+		System::assert_last_event(
+			Event::DelegationUpdated { provider_id: provider, delegator_id: delegator }.into(),
+		);
+		assert_eq!(
+			DelegationSchemaPermissions::<Test>::get(delegator, provider)
+				.and_then(|p| p.get(&1).copied()),
+			Some(permissions)
+		);
+
+		let block_number = System::block_number();
+		assert_ok!(Msa::ensure_valid_schema_grant(
+			provider,
+			delegator,
+			1,
+			block_number,
+			SchemaGrantPermission::Create
+		));
+		assert_noop!(
+			Msa::ensure_valid_schema_grant(
+				provider,
+				delegator,
+				1,
+				block_number,
+				SchemaGrantPermission::Update
+			),
+			Error::<Test>::SchemaPermissionNotGranted
+		);
+		assert_noop!(
+			Msa::ensure_valid_schema_grant(
+				provider,
+				delegator,
+				1,
+				block_number,
+				SchemaGrantPermission::Delete
+			),
+			Error::<Test>::SchemaPermissionNotGranted
+		);
+
+		// Other schemas keep every permission
+		assert_ok!(Msa::ensure_valid_schema_grant(
+			provider,
+			delegator,
+			2,
+			block_number,
+			SchemaGrantPermission::Delete
+		));
+	});
+}
+
+#[test]
+fn set_schema_grant_permissions_fails_when_schema_not_granted() {
+	new_test_ext().execute_with(|| {
+		set_schema_count::<Test>(2);
+		let (provider_msa_id, _) = create_account();
+		let (delegator_msa_id, delegator_pair) = create_account();
+		assert_ok!(Msa::add_provider(
+			ProviderId(provider_msa_id),
+			DelegatorId(delegator_msa_id),
+			vec![1],
+			None
+		));
+
+		assert_noop!(
+			Msa::set_schema_grant_permissions(
+				RuntimeOrigin::signed(delegator_pair.public().into()),
+				provider_msa_id,
+				2,
+				SchemaPermissions::all()
+			),
+			Error::<Test>::SchemaNotGranted
+		);
+	});
+}
+
+#[test]
+fn set_schema_grant_permissions_fails_when_no_delegation() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, _) = create_account();
+		let (_, delegator_pair) = create_account();
+
+		assert_noop!(
+			Msa::set_schema_grant_permissions(
+				RuntimeOrigin::signed(delegator_pair.public().into()),
+				provider_msa_id,
+				1,
+				SchemaPermissions::all()
+			),
+			Error::<Test>::DelegationNotFound
+		);
+	});
+}
+
+#[test]
+fn schema_grant_permissions_are_cleared_when_schema_is_revoked_and_granted_again() {
+	new_test_ext().execute_with(|| {
+		set_schema_count::<Test>(2);
+		let (provider_msa_id, _) = create_account();
+		let (delegator_msa_id, delegator_pair) = create_account();
+		let provider = ProviderId(provider_msa_id);
+		let delegator = DelegatorId(delegator_msa_id);
+		assert_ok!(Msa::add_provider(provider, delegator, vec![1, 2], None));
+		let permissions = SchemaPermissions { create: true, update: false, delete: false };
+		assert_ok!(Msa::set_schema_grant_permissions(
+			RuntimeOrigin::signed(delegator_pair.public().into()),
+			provider_msa_id,
+			1,
+			permissions
+		));
+
+		System::set_block_number(System::block_number() + 1);
+		assert_ok!(Msa::revoke_permissions_for_schemas(delegator, provider, vec![1]));
+		assert_eq!(DelegationSchemaPermissions::<Test>::get(delegator, provider), None);
+
+		assert_ok!(Msa::upsert_schema_permissions(provider, delegator, vec![1, 2], None));
+		assert_ok!(Msa::ensure_valid_schema_grant(
+			provider,
+			delegator,
+			1,
+			System::block_number(),
+			SchemaGrantPermission::Delete
+		));
+	});
+}
+
+#[test]
+fn schema_grant_permissions_are_cleared_when_delegation_is_revoked() {
+	new_test_ext().execute_with(|| {
+		set_schema_count::<Test>(2);
+		let (provider_msa_id, _) = create_account();
+		let (delegator_msa_id, delegator_pair) = create_account();
+		let provider = ProviderId(provider_msa_id);
+		let delegator = DelegatorId(delegator_msa_id);
+		assert_ok!(Msa::add_provider(provider, delegator, vec![1, 2], None));
+		assert_ok!(Msa::set_schema_grant_permissions(
+			RuntimeOrigin::signed(delegator_pair.public().into()),
+			provider_msa_id,
+			2,
+			SchemaPermissions { create: false, update: true, delete: false }
+		));
+
+		assert_ok!(Msa::revoke_provider(provider, delegator));
+
+		assert_eq!(DelegationSchemaPermissions::<Test>::get(delegator, provider), None);
+	});
+}
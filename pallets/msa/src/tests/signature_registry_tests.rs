@@ -7,7 +7,7 @@ use frame_support::{assert_noop, assert_ok};
 
 use common_primitives::{node::BlockNumber, utils::wrap_binary_data};
 
-use sp_core::{sr25519, Encode, Pair};
+use sp_core::{sr25519, Pair};
 use sp_runtime::{BuildStorage, MultiSignature};
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -218,9 +218,10 @@ pub fn add_msa_key_replay_fails() {
 				msa_id: new_msa_id,
 				expiration: tc.mortality,
 				new_public_key: new_key_pair.public().into(),
+				scope: None,
 			};
 
-			let encode_data_new_key_data = wrap_binary_data(add_new_key_data.encode());
+			let encode_data_new_key_data = wrap_binary_data(add_new_key_data.signed_payload());
 
 			let signature_owner: MultiSignature =
 				key_pair_provider.sign(&encode_data_new_key_data).into();
@@ -0,0 +1,403 @@
+use frame_support::{assert_noop, assert_ok, pallet_prelude::InvalidTransaction, BoundedVec};
+
+use pretty_assertions::assert_eq;
+use sp_core::sr25519::Public;
+
+use crate::{
+	tests::{mock::*, other_tests::set_schema_count},
+	CheckFreeExtrinsicUse, Error, Event, SubDelegation, SubDelegations, ValidityError,
+};
+
+use common_primitives::msa::{
+	DelegationValidator, DelegatorId, MessageSourceId, ProviderId, SchemaGrantPermission,
+	SchemaGrantValidator,
+};
+
+/// Creates a delegator that delegates schemas 1 and 2 to a root provider, which sub-delegates
+/// `schema_ids` to a second provider. Returns the delegator, root provider and sub-provider.
+fn create_sub_delegation(
+	schema_ids: Vec<u16>,
+) -> (MessageSourceId, (MessageSourceId, Public), (MessageSourceId, Public)) {
+	set_schema_count::<Test>(3);
+	let (root_msa_id, root_key) = create_provider_with_name("Foo");
+	let (sub_msa_id, sub_key) = create_provider_with_name("Bar");
+	let (delegator_msa_id, _) = create_account();
+	assert_ok!(Msa::add_provider(
+		ProviderId(root_msa_id),
+		DelegatorId(delegator_msa_id),
+		vec![1, 2],
+		None
+	));
+	assert_ok!(Msa::grant_sub_delegation(
+		RuntimeOrigin::signed(root_key.into()),
+		sub_msa_id,
+		schema_ids
+	));
+	(delegator_msa_id, (root_msa_id, root_key), (sub_msa_id, sub_key))
+}
+
+#[test]
+fn grant_sub_delegation_stores_link_and_emits_event() {
+	new_test_ext().execute_with(|| {
+		// arrange & act
+		let (_, (root_msa_id, _), (sub_msa_id, _)) = create_sub_delegation(vec![1]);
+
+		// assert
+		let links = SubDelegations::<Test>::get(ProviderId(sub_msa_id));
+		assert_eq!(links.len(), 1);
+		assert_eq!(links[0].parent_provider_id, ProviderId(root_msa_id));
+		assert_eq!(links[0].schema_ids.clone().into_inner(), vec![1]);
+		assert_eq!(links[0].revoked_at, 0);
+		System::assert_last_event(
+			Event::SubDelegationGranted {
+				parent_provider_id: ProviderId(root_msa_id),
+				sub_provider_id: ProviderId(sub_msa_id),
+			}
+			.into(),
+		);
+	})
+}
+
+#[test]
+fn grant_sub_delegation_to_non_provider_fails() {
+	new_test_ext().execute_with(|| {
+		let (_, provider_key) = create_provider_with_name("Foo");
+		let (msa_id, _) = create_account();
+
+		assert_noop!(
+			Msa::grant_sub_delegation(RuntimeOrigin::signed(provider_key.into()), msa_id, vec![]),
+			Error::<Test>::ProviderNotRegistered
+		);
+	})
+}
+
+#[test]
+fn grant_sub_delegation_to_self_fails() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, provider_key) = create_provider_with_name("Foo");
+
+		assert_noop!(
+			Msa::grant_sub_delegation(
+				RuntimeOrigin::signed(provider_key.into()),
+				provider_msa_id,
+				vec![]
+			),
+			Error::<Test>::InvalidSelfProvider
+		);
+	})
+}
+
+#[test]
+fn grant_sub_delegation_with_invalid_schema_fails() {
+	new_test_ext().execute_with(|| {
+		set_schema_count::<Test>(1);
+		let (_, provider_key) = create_provider_with_name("Foo");
+		let (sub_msa_id, _) = create_provider_with_name("Bar");
+
+		assert_noop!(
+			Msa::grant_sub_delegation(
+				RuntimeOrigin::signed(provider_key.into()),
+				sub_msa_id,
+				vec![1, 2]
+			),
+			Error::<Test>::InvalidSchemaId
+		);
+	})
+}
+
+#[test]
+fn sub_provider_holds_only_the_sub_delegated_schemas() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (delegator_msa_id, _, (sub_msa_id, _)) = create_sub_delegation(vec![1, 3]);
+		let sub_provider_id = ProviderId(sub_msa_id);
+		let delegator_id = DelegatorId(delegator_msa_id);
+
+		// act
+		let delegation = Msa::ensure_valid_delegation(sub_provider_id, delegator_id, None).unwrap();
+
+		// assert
+		assert!(delegation.schema_permissions.contains_key(&1));
+		assert!(!delegation.schema_permissions.contains_key(&2));
+		assert!(!delegation.schema_permissions.contains_key(&3));
+		assert_ok!(Msa::ensure_schema_granted(sub_provider_id, delegator_id, 1, 1));
+		assert_noop!(
+			Msa::ensure_schema_granted(sub_provider_id, delegator_id, 2, 1),
+			Error::<Test>::SchemaNotGranted
+		);
+	})
+}
+
+#[test]
+fn sub_provider_uses_the_schema_permissions_of_the_root_provider() {
+	new_test_ext().execute_with(|| {
+		let (delegator_msa_id, _, (sub_msa_id, _)) = create_sub_delegation(vec![1]);
+
+		assert_ok!(Msa::ensure_valid_schema_grant(
+			ProviderId(sub_msa_id),
+			DelegatorId(delegator_msa_id),
+			1,
+			1,
+			SchemaGrantPermission::Create
+		));
+	})
+}
+
+#[test]
+fn delegation_resolves_through_a_chain_of_sub_delegations() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (delegator_msa_id, _, (sub_msa_id, sub_key)) = create_sub_delegation(vec![1, 2]);
+		let (third_msa_id, _) = create_provider_with_name("Baz");
+		assert_ok!(Msa::grant_sub_delegation(
+			RuntimeOrigin::signed(sub_key.into()),
+			third_msa_id,
+			vec![2]
+		));
+
+		// act & assert
+		assert_ok!(Msa::ensure_schema_granted(
+			ProviderId(third_msa_id),
+			DelegatorId(delegator_msa_id),
+			2,
+			1
+		));
+		assert_noop!(
+			Msa::ensure_schema_granted(
+				ProviderId(third_msa_id),
+				DelegatorId(delegator_msa_id),
+				1,
+				1
+			),
+			Error::<Test>::SchemaNotGranted
+		);
+		assert_eq!(SubDelegations::<Test>::get(ProviderId(sub_msa_id)).len(), 1);
+	})
+}
+
+#[test]
+fn delegation_does_not_resolve_past_the_maximum_depth() {
+	new_test_ext().execute_with(|| {
+		// arrange: the mock allows chains of 3 links
+		let (delegator_msa_id, _, (_, mut parent_key)) = create_sub_delegation(vec![1]);
+		let mut last_msa_id = 0;
+		for name in ["Baz", "Qux", "Quux"] {
+			let (msa_id, key) = create_provider_with_name(name);
+			assert_ok!(Msa::grant_sub_delegation(
+				RuntimeOrigin::signed(parent_key.into()),
+				msa_id,
+				vec![1]
+			));
+			parent_key = key;
+			last_msa_id = msa_id;
+		}
+
+		// act & assert
+		assert_noop!(
+			Msa::ensure_valid_delegation(
+				ProviderId(last_msa_id),
+				DelegatorId(delegator_msa_id),
+				None
+			),
+			Error::<Test>::DelegationNotFound
+		);
+	})
+}
+
+#[test]
+fn revoking_a_sub_delegation_cascades_down_the_chain() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (delegator_msa_id, (_, root_key), (sub_msa_id, sub_key)) =
+			create_sub_delegation(vec![1]);
+		let (third_msa_id, _) = create_provider_with_name("Baz");
+		assert_ok!(Msa::grant_sub_delegation(
+			RuntimeOrigin::signed(sub_key.into()),
+			third_msa_id,
+			vec![1]
+		));
+		run_to_block(5);
+
+		// act
+		assert_ok!(Msa::revoke_sub_delegation(RuntimeOrigin::signed(root_key.into()), sub_msa_id));
+		run_to_block(6);
+
+		// assert
+		let delegator_id = DelegatorId(delegator_msa_id);
+		for msa_id in [sub_msa_id, third_msa_id] {
+			assert_noop!(
+				Msa::ensure_valid_delegation(ProviderId(msa_id), delegator_id, None),
+				Error::<Test>::DelegationNotFound
+			);
+			assert_ok!(Msa::ensure_valid_delegation(ProviderId(msa_id), delegator_id, Some(5)));
+		}
+	})
+}
+
+#[test]
+fn revoking_the_root_delegation_cascades_to_sub_providers() {
+	new_test_ext().execute_with(|| {
+		let (delegator_msa_id, (root_msa_id, _), (sub_msa_id, _)) = create_sub_delegation(vec![1]);
+		assert_ok!(Msa::revoke_provider(ProviderId(root_msa_id), DelegatorId(delegator_msa_id)));
+		run_to_block(2);
+
+		assert_noop!(
+			Msa::ensure_valid_delegation(
+				ProviderId(sub_msa_id),
+				DelegatorId(delegator_msa_id),
+				None
+			),
+			Error::<Test>::DelegationNotFound
+		);
+	})
+}
+
+#[test]
+fn revoke_sub_delegation_emits_event_and_fails_when_repeated() {
+	new_test_ext().execute_with(|| {
+		let (_, (root_msa_id, root_key), (sub_msa_id, _)) = create_sub_delegation(vec![1]);
+
+		assert_ok!(Msa::revoke_sub_delegation(RuntimeOrigin::signed(root_key.into()), sub_msa_id));
+		System::assert_last_event(
+			Event::SubDelegationRevoked {
+				parent_provider_id: ProviderId(root_msa_id),
+				sub_provider_id: ProviderId(sub_msa_id),
+			}
+			.into(),
+		);
+		assert_noop!(
+			Msa::revoke_sub_delegation(RuntimeOrigin::signed(root_key.into()), sub_msa_id),
+			Error::<Test>::DelegationRevoked
+		);
+	})
+}
+
+#[test]
+fn revoke_sub_delegation_without_link_fails() {
+	new_test_ext().execute_with(|| {
+		let (_, provider_key) = create_provider_with_name("Foo");
+		let (sub_msa_id, _) = create_provider_with_name("Bar");
+
+		assert_noop!(
+			Msa::revoke_sub_delegation(RuntimeOrigin::signed(provider_key.into()), sub_msa_id),
+			Error::<Test>::SubDelegationNotFound
+		);
+	})
+}
+
+#[test]
+fn sub_providers_cannot_revoke_sub_delegated_grants_as_delegations() {
+	new_test_ext().execute_with(|| {
+		let (delegator_msa_id, _, (sub_msa_id, sub_key)) = create_sub_delegation(vec![1]);
+
+		assert_noop!(
+			CheckFreeExtrinsicUse::<Test>::validate_delegation_by_provider(
+				&sub_key.into(),
+				&delegator_msa_id
+			),
+			InvalidTransaction::Custom(ValidityError::InvalidDelegation as u8)
+		);
+		assert_eq!(SubDelegations::<Test>::get(ProviderId(sub_msa_id)).len(), 1);
+	})
+}
+
+#[test]
+fn a_revoked_direct_delegation_does_not_fall_back_to_sub_delegations() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (delegator_msa_id, _, (sub_msa_id, _)) = create_sub_delegation(vec![1]);
+		let sub_provider_id = ProviderId(sub_msa_id);
+		let delegator_id = DelegatorId(delegator_msa_id);
+		assert_ok!(Msa::add_provider(sub_provider_id, delegator_id, vec![1], None));
+		assert_ok!(Msa::revoke_provider(sub_provider_id, delegator_id));
+		run_to_block(2);
+
+		// act & assert
+		assert_noop!(
+			Msa::ensure_valid_delegation(sub_provider_id, delegator_id, None),
+			Error::<Test>::DelegationRevoked
+		);
+		assert_noop!(
+			Msa::ensure_schema_granted(sub_provider_id, delegator_id, 1, 2),
+			Error::<Test>::DelegationRevoked
+		);
+	})
+}
+
+#[test]
+fn a_direct_delegation_without_the_schema_does_not_hide_a_sub_delegation() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (delegator_msa_id, _, (sub_msa_id, _)) = create_sub_delegation(vec![1]);
+		let sub_provider_id = ProviderId(sub_msa_id);
+		let delegator_id = DelegatorId(delegator_msa_id);
+		assert_ok!(Msa::add_provider(sub_provider_id, delegator_id, vec![3], None));
+
+		// act & assert
+		assert_ok!(Msa::ensure_schema_granted(sub_provider_id, delegator_id, 3, 1));
+		assert_ok!(Msa::ensure_schema_granted(sub_provider_id, delegator_id, 1, 1));
+		assert_noop!(
+			Msa::ensure_schema_granted(sub_provider_id, delegator_id, 2, 1),
+			Error::<Test>::SchemaNotGranted
+		);
+	})
+}
+
+#[test]
+fn a_sub_delegation_without_the_schema_does_not_hide_another_one() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (delegator_msa_id, _, (sub_msa_id, _)) = create_sub_delegation(vec![2]);
+		let (other_root_msa_id, other_root_key) = create_provider_with_name("Baz");
+		let delegator_id = DelegatorId(delegator_msa_id);
+		assert_ok!(Msa::add_provider(ProviderId(other_root_msa_id), delegator_id, vec![1], None));
+		assert_ok!(Msa::grant_sub_delegation(
+			RuntimeOrigin::signed(other_root_key.into()),
+			sub_msa_id,
+			vec![1]
+		));
+
+		// act & assert
+		assert_ok!(Msa::ensure_schema_granted(ProviderId(sub_msa_id), delegator_id, 1, 1));
+		assert_ok!(Msa::ensure_schema_granted(ProviderId(sub_msa_id), delegator_id, 2, 1));
+	})
+}
+
+#[test]
+fn delegation_does_not_resolve_past_the_maximum_lookups() {
+	new_test_ext().execute_with(|| {
+		// arrange: the mock examines at most 8 links, so the links of the first two parents use
+		// them up before the last parent, which holds the delegation, is reached
+		set_schema_count::<Test>(1);
+		let delegator_id = DelegatorId(1);
+		let provider_id = ProviderId(2);
+		let parents: Vec<ProviderId> = (10..14).map(ProviderId).collect();
+		let link = |parent_provider_id| SubDelegation::<Test> {
+			parent_provider_id,
+			schema_ids: BoundedVec::truncate_from(vec![1]),
+			revoked_at: 0,
+		};
+		SubDelegations::<Test>::insert(
+			provider_id,
+			BoundedVec::truncate_from(parents.iter().copied().map(link).collect()),
+		);
+		for parent in &parents[..3] {
+			let unrelated_providers = (20..24).map(|msa_id| link(ProviderId(msa_id))).collect();
+			SubDelegations::<Test>::insert(parent, BoundedVec::truncate_from(unrelated_providers));
+		}
+		assert_ok!(Msa::add_provider(parents[3], delegator_id, vec![1], None));
+
+		// act & assert
+		assert_ok!(Msa::ensure_valid_delegation(parents[3], delegator_id, None));
+		assert_noop!(
+			Msa::ensure_valid_delegation(provider_id, delegator_id, None),
+			Error::<Test>::DelegationNotFound
+		);
+
+		// assert: with fewer links to examine, the delegation resolves
+		for parent in &parents[..2] {
+			SubDelegations::<Test>::remove(parent);
+		}
+		assert_ok!(Msa::ensure_valid_delegation(provider_id, delegator_id, None));
+	})
+}
@@ -0,0 +1,554 @@
+use frame_support::{assert_noop, assert_ok, pallet_prelude::InvalidTransaction, BoundedVec};
+
+use pretty_assertions::assert_eq;
+use sp_core::{crypto::AccountId32, sr25519, Pair};
+use sp_runtime::MultiSignature;
+
+use crate::{
+	tests::{mock::*, other_tests::set_schema_count},
+	types::{AddKeyData, KeyScope, ThresholdAction, EMPTY_FUNCTION},
+	CheckFreeExtrinsicUse, DelegatorAndProviderToDelegation, Error, Event, ProviderRetirements,
+	PublicKeyCountForMsaId, PublicKeyScopes, PublicKeyToMsaId, RecoveryConfigs,
+	RetiredMsaPurgeQueue, ThresholdPolicies, ThresholdProposalCount, ThresholdProposals,
+	ValidityError,
+};
+
+use common_primitives::{
+	msa::{DelegatorId, MessageSourceId, ProviderId, SchemaPermissions},
+	utils::wrap_binary_data,
+};
+
+/// Creates an MSA with `key_count` keys and returns them.
+fn create_msa_with_keys(key_count: usize) -> (MessageSourceId, Vec<AccountId32>) {
+	let (msa_id, key_pair) = create_account();
+	let mut keys = vec![AccountId32::from(key_pair.public())];
+	for _ in 1..key_count {
+		let (new_key_pair, _) = sr25519::Pair::generate();
+		let new_key = AccountId32::from(new_key_pair.public());
+		assert_ok!(Msa::add_key(msa_id, &new_key, EMPTY_FUNCTION));
+		keys.push(new_key);
+	}
+	(msa_id, keys)
+}
+
+/// Creates an MSA with `key_count` keys and a threshold policy of `threshold`.
+fn create_msa_with_threshold(
+	key_count: usize,
+	threshold: u8,
+) -> (MessageSourceId, Vec<AccountId32>) {
+	let (msa_id, keys) = create_msa_with_keys(key_count);
+	assert_ok!(Msa::set_threshold_policy(RuntimeOrigin::signed(keys[0].clone()), threshold));
+	(msa_id, keys)
+}
+
+fn propose(key: &AccountId32, action: ThresholdAction<Test>) {
+	assert_ok!(Msa::propose_threshold_action(RuntimeOrigin::signed(key.clone()), action));
+}
+
+#[test]
+fn set_threshold_policy_stores_threshold_and_emits_event() {
+	new_test_ext().execute_with(|| {
+		// arrange & act
+		let (msa_id, _) = create_msa_with_threshold(3, 2);
+
+		// assert
+		assert_eq!(ThresholdPolicies::<Test>::get(msa_id), Some(2));
+		System::assert_last_event(Event::ThresholdPolicySet { msa_id, threshold: 2 }.into());
+	})
+}
+
+#[test]
+fn set_threshold_policy_with_invalid_threshold_fails() {
+	new_test_ext().execute_with(|| {
+		let (_, keys) = create_msa_with_keys(2);
+
+		for threshold in [0, 1, 3] {
+			assert_noop!(
+				Msa::set_threshold_policy(RuntimeOrigin::signed(keys[0].clone()), threshold),
+				Error::<Test>::InvalidThresholdPolicy
+			);
+		}
+	})
+}
+
+#[test]
+fn set_threshold_policy_when_already_set_fails() {
+	new_test_ext().execute_with(|| {
+		let (_, keys) = create_msa_with_threshold(3, 2);
+
+		assert_noop!(
+			Msa::set_threshold_policy(RuntimeOrigin::signed(keys[1].clone()), 3),
+			Error::<Test>::ThresholdApprovalRequired
+		);
+	})
+}
+
+#[test]
+fn single_key_actions_require_approval_with_a_threshold_policy() {
+	new_test_ext().execute_with(|| {
+		let (_, keys) = create_msa_with_threshold(3, 2);
+
+		assert_noop!(
+			Msa::delete_msa_public_key(RuntimeOrigin::signed(keys[0].clone()), keys[1].clone()),
+			Error::<Test>::ThresholdApprovalRequired
+		);
+		assert_noop!(
+			CheckFreeExtrinsicUse::<Test>::validate_key_delete(&keys[0], &keys[1]),
+			InvalidTransaction::Custom(ValidityError::ThresholdApprovalRequired as u8)
+		);
+		assert_noop!(
+			Msa::set_provider_metadata(
+				RuntimeOrigin::signed(keys[0].clone()),
+				vec![],
+				vec![],
+				vec![],
+				None,
+				vec![]
+			),
+			Error::<Test>::ThresholdApprovalRequired
+		);
+	})
+}
+
+#[test]
+fn revoking_a_delegation_requires_approval_with_a_threshold_policy() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, _) = create_provider_with_name("Foo");
+		let (msa_id, keys) = create_msa_with_keys(2);
+		assert_ok!(Msa::add_provider(
+			ProviderId(provider_msa_id),
+			DelegatorId(msa_id),
+			vec![],
+			None
+		));
+		assert_ok!(Msa::set_threshold_policy(RuntimeOrigin::signed(keys[0].clone()), 2));
+
+		assert_noop!(
+			Msa::revoke_delegation_by_delegator(
+				RuntimeOrigin::signed(keys[0].clone()),
+				provider_msa_id
+			),
+			Error::<Test>::ThresholdApprovalRequired
+		);
+		assert_noop!(
+			CheckFreeExtrinsicUse::<Test>::validate_delegation_by_delegator(
+				&keys[0],
+				&provider_msa_id
+			),
+			InvalidTransaction::Custom(ValidityError::ThresholdApprovalRequired as u8)
+		);
+
+		propose(&keys[0], ThresholdAction::RevokeDelegationByDelegator { provider_msa_id });
+		assert_ok!(Msa::approve_threshold_action(RuntimeOrigin::signed(keys[1].clone()), 0));
+		let delegation = DelegatorAndProviderToDelegation::<Test>::get(
+			DelegatorId(msa_id),
+			ProviderId(provider_msa_id),
+		)
+		.unwrap();
+		assert_eq!(delegation.revoked_at, System::block_number());
+	})
+}
+
+#[test]
+fn delete_public_key_executes_once_threshold_is_met() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (msa_id, keys) = create_msa_with_threshold(4, 3);
+
+		// act
+		propose(&keys[0], ThresholdAction::DeletePublicKey { key: keys[3].clone() });
+		assert_ok!(Msa::approve_threshold_action(RuntimeOrigin::signed(keys[1].clone()), 0));
+
+		// assert: still waiting for a third key
+		assert_eq!(PublicKeyToMsaId::<Test>::get(&keys[3]), Some(msa_id));
+		assert_eq!(ThresholdProposals::<Test>::get(msa_id, 0).unwrap().approvals.len(), 2);
+
+		// act
+		assert_ok!(Msa::approve_threshold_action(RuntimeOrigin::signed(keys[2].clone()), 0));
+
+		// assert
+		assert_eq!(PublicKeyToMsaId::<Test>::get(&keys[3]), None);
+		assert_eq!(PublicKeyCountForMsaId::<Test>::get(msa_id), 3);
+		assert_eq!(ThresholdProposals::<Test>::get(msa_id, 0), None);
+		System::assert_last_event(Event::ThresholdActionExecuted { msa_id, proposal_id: 0 }.into());
+	})
+}
+
+#[test]
+fn approving_twice_with_the_same_key_fails() {
+	new_test_ext().execute_with(|| {
+		let (_, keys) = create_msa_with_threshold(3, 2);
+		propose(&keys[0], ThresholdAction::SetThreshold { threshold: None });
+
+		assert_noop!(
+			Msa::approve_threshold_action(RuntimeOrigin::signed(keys[0].clone()), 0),
+			Error::<Test>::ThresholdProposalAlreadyApproved
+		);
+	})
+}
+
+#[test]
+fn approvals_of_deleted_keys_do_not_count() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (msa_id, keys) = create_msa_with_threshold(4, 2);
+		propose(&keys[3], ThresholdAction::SetThreshold { threshold: None });
+		propose(&keys[0], ThresholdAction::DeletePublicKey { key: keys[3].clone() });
+		assert_ok!(Msa::approve_threshold_action(RuntimeOrigin::signed(keys[1].clone()), 1));
+
+		// act
+		assert_ok!(Msa::approve_threshold_action(RuntimeOrigin::signed(keys[2].clone()), 0));
+
+		// assert
+		assert_eq!(ThresholdPolicies::<Test>::get(msa_id), Some(2));
+		assert_eq!(
+			ThresholdProposals::<Test>::get(msa_id, 0).unwrap().approvals.into_inner(),
+			vec![keys[2].clone()]
+		);
+	})
+}
+
+#[test]
+fn deleting_a_key_below_the_threshold_fails() {
+	new_test_ext().execute_with(|| {
+		let (_, keys) = create_msa_with_threshold(2, 2);
+		propose(&keys[0], ThresholdAction::DeletePublicKey { key: keys[1].clone() });
+
+		assert_noop!(
+			Msa::approve_threshold_action(RuntimeOrigin::signed(keys[1].clone()), 0),
+			Error::<Test>::ThresholdActionNotAllowed
+		);
+	})
+}
+
+#[test]
+fn set_threshold_action_changes_and_removes_the_policy() {
+	new_test_ext().execute_with(|| {
+		let (msa_id, keys) = create_msa_with_threshold(3, 2);
+
+		propose(&keys[0], ThresholdAction::SetThreshold { threshold: Some(3) });
+		assert_ok!(Msa::approve_threshold_action(RuntimeOrigin::signed(keys[1].clone()), 0));
+		assert_eq!(ThresholdPolicies::<Test>::get(msa_id), Some(3));
+
+		propose(&keys[0], ThresholdAction::SetThreshold { threshold: None });
+		assert_ok!(Msa::approve_threshold_action(RuntimeOrigin::signed(keys[1].clone()), 1));
+		assert_ok!(Msa::approve_threshold_action(RuntimeOrigin::signed(keys[2].clone()), 1));
+		assert_eq!(ThresholdPolicies::<Test>::get(msa_id), None);
+	})
+}
+
+#[test]
+fn retire_msa_action_requires_every_key_to_approve() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (msa_id, keys) = create_msa_with_threshold(3, 2);
+		propose(&keys[0], ThresholdAction::RetireMsa);
+
+		// act & assert
+		assert_noop!(
+			Msa::approve_threshold_action(RuntimeOrigin::signed(keys[1].clone()), 0),
+			Error::<Test>::ThresholdActionNotAllowed
+		);
+
+		propose(&keys[0], ThresholdAction::DeletePublicKey { key: keys[2].clone() });
+		assert_ok!(Msa::approve_threshold_action(RuntimeOrigin::signed(keys[1].clone()), 1));
+		assert_ok!(Msa::approve_threshold_action(RuntimeOrigin::signed(keys[1].clone()), 0));
+
+		assert_eq!(PublicKeyCountForMsaId::<Test>::get(msa_id), 0);
+		assert_eq!(ThresholdPolicies::<Test>::get(msa_id), None);
+		assert!(RetiredMsaPurgeQueue::<Test>::contains_key(msa_id));
+	})
+}
+
+#[test]
+fn cancel_threshold_action_by_the_proposer_removes_the_proposal() {
+	new_test_ext().execute_with(|| {
+		let (msa_id, keys) = create_msa_with_threshold(3, 2);
+		propose(&keys[0], ThresholdAction::SetThreshold { threshold: None });
+
+		assert_ok!(Msa::cancel_threshold_action(RuntimeOrigin::signed(keys[0].clone()), 0));
+
+		assert_eq!(ThresholdProposals::<Test>::get(msa_id, 0), None);
+		System::assert_last_event(
+			Event::ThresholdActionCancelled { msa_id, proposal_id: 0 }.into(),
+		);
+		assert_noop!(
+			Msa::approve_threshold_action(RuntimeOrigin::signed(keys[1].clone()), 0),
+			Error::<Test>::ThresholdProposalNotFound
+		);
+	})
+}
+
+#[test]
+fn cancel_threshold_action_by_other_keys_requires_the_threshold() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (msa_id, keys) = create_msa_with_threshold(3, 2);
+		propose(&keys[0], ThresholdAction::DeletePublicKey { key: keys[2].clone() });
+
+		// act
+		assert_ok!(Msa::cancel_threshold_action(RuntimeOrigin::signed(keys[2].clone()), 0));
+
+		// assert
+		assert!(ThresholdProposals::<Test>::contains_key(msa_id, 0));
+		System::assert_last_event(
+			Event::ThresholdActionRejected { msa_id, proposal_id: 0, key: keys[2].clone() }.into(),
+		);
+		assert_noop!(
+			Msa::cancel_threshold_action(RuntimeOrigin::signed(keys[2].clone()), 0),
+			Error::<Test>::ThresholdProposalAlreadyRejected
+		);
+
+		assert_ok!(Msa::cancel_threshold_action(RuntimeOrigin::signed(keys[1].clone()), 0));
+		assert_eq!(ThresholdProposals::<Test>::get(msa_id, 0), None);
+		assert_eq!(ThresholdProposalCount::<Test>::get(msa_id), 0);
+		System::assert_last_event(
+			Event::ThresholdActionCancelled { msa_id, proposal_id: 0 }.into(),
+		);
+	})
+}
+
+#[test]
+fn a_single_key_cannot_veto_its_own_removal() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (msa_id, keys) = create_msa_with_threshold(3, 2);
+		propose(&keys[0], ThresholdAction::DeletePublicKey { key: keys[2].clone() });
+
+		// act
+		assert_ok!(Msa::cancel_threshold_action(RuntimeOrigin::signed(keys[2].clone()), 0));
+		assert_ok!(Msa::approve_threshold_action(RuntimeOrigin::signed(keys[1].clone()), 0));
+
+		// assert
+		assert_eq!(PublicKeyToMsaId::<Test>::get(&keys[2]), None);
+		assert_eq!(ThresholdProposalCount::<Test>::get(msa_id), 0);
+	})
+}
+
+#[test]
+fn propose_threshold_action_without_policy_fails() {
+	new_test_ext().execute_with(|| {
+		let (_, keys) = create_msa_with_keys(2);
+
+		assert_noop!(
+			Msa::propose_threshold_action(
+				RuntimeOrigin::signed(keys[0].clone()),
+				ThresholdAction::RetireMsa
+			),
+			Error::<Test>::ThresholdPolicyNotSet
+		);
+	})
+}
+
+/// Builds an `AddPublicKey` action for a new key, signed by that key.
+fn add_public_key_action(
+	msa_id: MessageSourceId,
+	scope: Option<KeyScope<MaxSchemaGrantsPerDelegation>>,
+) -> (AccountId32, ThresholdAction<Test>) {
+	let (new_key_pair, _) = sr25519::Pair::generate();
+	let new_key = AccountId32::from(new_key_pair.public());
+	let add_key_payload =
+		AddKeyData { msa_id, expiration: 10, new_public_key: new_key.clone(), scope };
+	let new_key_owner_proof: MultiSignature =
+		new_key_pair.sign(&wrap_binary_data(add_key_payload.signed_payload())).into();
+	(new_key, ThresholdAction::AddPublicKey { add_key_payload, new_key_owner_proof })
+}
+
+#[test]
+fn adding_a_key_requires_approval_with_a_threshold_policy() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (msa_id, owner_key_pair) = create_account();
+		let owner_key = AccountId32::from(owner_key_pair.public());
+		let (second_key_pair, _) = sr25519::Pair::generate();
+		assert_ok!(Msa::add_key(msa_id, &second_key_pair.public().into(), EMPTY_FUNCTION));
+		assert_ok!(Msa::set_threshold_policy(RuntimeOrigin::signed(owner_key.clone()), 2));
+
+		let (new_key_pair, _) = sr25519::Pair::generate();
+		let add_key_payload = AddKeyData {
+			msa_id,
+			expiration: 10,
+			new_public_key: new_key_pair.public().into(),
+			scope: None,
+		};
+		let encoded_payload = wrap_binary_data(add_key_payload.signed_payload());
+		let owner_proof: MultiSignature = owner_key_pair.sign(&encoded_payload).into();
+		let new_key_proof: MultiSignature = new_key_pair.sign(&encoded_payload).into();
+
+		// act & assert
+		assert_noop!(
+			Msa::add_public_key_to_msa(
+				RuntimeOrigin::signed(owner_key.clone()),
+				owner_key,
+				owner_proof,
+				new_key_proof,
+				add_key_payload
+			),
+			Error::<Test>::ThresholdApprovalRequired
+		);
+	})
+}
+
+#[test]
+fn add_public_key_action_executes_once_threshold_is_met() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (msa_id, keys) = create_msa_with_threshold(3, 2);
+		let (new_key, action) = add_public_key_action(msa_id, None);
+
+		// act
+		propose(&keys[0], action);
+		assert_ok!(Msa::approve_threshold_action(RuntimeOrigin::signed(keys[1].clone()), 0));
+
+		// assert
+		assert_eq!(PublicKeyToMsaId::<Test>::get(&new_key), Some(msa_id));
+		assert_eq!(PublicKeyCountForMsaId::<Test>::get(msa_id), 4);
+	})
+}
+
+#[test]
+fn add_public_key_action_with_an_invalid_new_key_proof_fails() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (msa_id, keys) = create_msa_with_threshold(3, 2);
+		let (_, action) = add_public_key_action(msa_id, None);
+		let ThresholdAction::AddPublicKey { mut add_key_payload, new_key_owner_proof } = action
+		else {
+			panic!("expected an AddPublicKey action");
+		};
+		let (other_key_pair, _) = sr25519::Pair::generate();
+		add_key_payload.new_public_key = other_key_pair.public().into();
+		propose(&keys[0], ThresholdAction::AddPublicKey { add_key_payload, new_key_owner_proof });
+
+		// act & assert
+		assert_noop!(
+			Msa::approve_threshold_action(RuntimeOrigin::signed(keys[1].clone()), 0),
+			Error::<Test>::NewKeyOwnershipInvalidSignature
+		);
+	})
+}
+
+#[test]
+fn add_public_key_action_is_limited_by_scoped_approvers() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		set_schema_count::<Test>(1);
+		let (msa_id, keys) = create_msa_with_threshold(3, 2);
+		let scope =
+			KeyScope { can_manage_msa: true, schema_ids: Some(BoundedVec::truncate_from(vec![1])) };
+		PublicKeyScopes::<Test>::insert(&keys[1], scope.clone());
+		let (_, unscoped_action) = add_public_key_action(msa_id, None);
+		let (scoped_key, scoped_action) = add_public_key_action(msa_id, Some(scope));
+		propose(&keys[0], unscoped_action);
+		propose(&keys[0], scoped_action);
+
+		// act & assert
+		assert_noop!(
+			Msa::approve_threshold_action(RuntimeOrigin::signed(keys[1].clone()), 0),
+			Error::<Test>::KeyScopeRestricted
+		);
+		assert_ok!(Msa::approve_threshold_action(RuntimeOrigin::signed(keys[1].clone()), 1));
+		assert_eq!(PublicKeyToMsaId::<Test>::get(&scoped_key), Some(msa_id));
+	})
+}
+
+#[test]
+fn other_sensitive_actions_require_approval_with_a_threshold_policy() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (provider_msa_id, provider_key) = create_provider_with_name("Foo");
+		let provider_key = AccountId32::from(provider_key);
+		let (second_key_pair, _) = sr25519::Pair::generate();
+		assert_ok!(Msa::add_key(provider_msa_id, &second_key_pair.public().into(), EMPTY_FUNCTION));
+		assert_ok!(Msa::set_threshold_policy(RuntimeOrigin::signed(provider_key.clone()), 2));
+		let (guardian_msa_id, _) = create_account();
+		let origin = || RuntimeOrigin::signed(provider_key.clone());
+
+		// act & assert
+		assert_noop!(
+			Msa::set_recovery_config(
+				origin(),
+				BoundedVec::truncate_from(vec![guardian_msa_id]),
+				1,
+				None
+			),
+			Error::<Test>::ThresholdApprovalRequired
+		);
+		assert_noop!(
+			Msa::remove_recovery_config(origin()),
+			Error::<Test>::ThresholdApprovalRequired
+		);
+		assert_noop!(
+			Msa::set_schema_grant_permissions(
+				origin(),
+				guardian_msa_id,
+				1,
+				SchemaPermissions { create: true, update: false, delete: false }
+			),
+			Error::<Test>::ThresholdApprovalRequired
+		);
+		assert_noop!(
+			Msa::revoke_schema_permissions(origin(), guardian_msa_id, vec![1]),
+			Error::<Test>::ThresholdApprovalRequired
+		);
+		assert_noop!(Msa::retire_provider(origin()), Error::<Test>::ThresholdApprovalRequired);
+		assert_noop!(
+			Msa::propose_to_change_provider_name(origin(), Vec::from("Bar")),
+			Error::<Test>::ThresholdApprovalRequired
+		);
+	})
+}
+
+#[test]
+fn retire_provider_and_recovery_actions_execute_once_threshold_is_met() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (provider_msa_id, provider_key) = create_provider_with_name("Foo");
+		let provider_key = AccountId32::from(provider_key);
+		let (second_key_pair, _) = sr25519::Pair::generate();
+		let second_key = AccountId32::from(second_key_pair.public());
+		assert_ok!(Msa::add_key(provider_msa_id, &second_key, EMPTY_FUNCTION));
+		assert_ok!(Msa::set_threshold_policy(RuntimeOrigin::signed(provider_key.clone()), 2));
+		let (guardian_msa_id, _) = create_account();
+
+		// act
+		propose(
+			&provider_key,
+			ThresholdAction::SetRecoveryConfig {
+				guardians: BoundedVec::truncate_from(vec![guardian_msa_id]),
+				threshold: 1,
+				commitment: None,
+			},
+		);
+		propose(&provider_key, ThresholdAction::RetireProvider);
+		assert_ok!(Msa::approve_threshold_action(RuntimeOrigin::signed(second_key.clone()), 0));
+		assert_ok!(Msa::approve_threshold_action(RuntimeOrigin::signed(second_key), 1));
+
+		// assert
+		assert!(RecoveryConfigs::<Test>::contains_key(provider_msa_id));
+		assert!(ProviderRetirements::<Test>::contains_key(ProviderId(provider_msa_id)));
+	})
+}
+
+#[test]
+fn proposing_more_than_the_max_open_proposals_fails() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (msa_id, keys) = create_msa_with_threshold(3, 2);
+		propose(&keys[0], ThresholdAction::SetThreshold { threshold: Some(3) });
+		propose(&keys[0], ThresholdAction::SetThreshold { threshold: None });
+
+		// act & assert
+		assert_noop!(
+			Msa::propose_threshold_action(
+				RuntimeOrigin::signed(keys[1].clone()),
+				ThresholdAction::RetireMsa
+			),
+			Error::<Test>::ExceedsMaxThresholdProposals
+		);
+
+		assert_ok!(Msa::cancel_threshold_action(RuntimeOrigin::signed(keys[0].clone()), 0));
+		assert_ok!(Msa::approve_threshold_action(RuntimeOrigin::signed(keys[1].clone()), 1));
+		assert_eq!(ThresholdProposalCount::<Test>::get(msa_id), 0);
+	})
+}
@@ -7,17 +7,23 @@ use parity_scale_codec::{Decode, Encode};
 use core::fmt::Debug;
 
 pub use common_primitives::msa::{
-	Delegation, DelegatorId, KeyInfoResponse, MessageSourceId, ProviderId,
+	Delegation, DelegatorId, KeyInfoResponse, KeyScope, MessageSourceId, ProviderId,
 };
-use common_primitives::{node::BlockNumber, schema::SchemaId};
+use common_primitives::{
+	node::BlockNumber,
+	schema::SchemaId,
+	signatures::{eip_712_encode_array, eip_712_encode_uint, eip_712_hash_struct, EIP712Encode},
+};
+use sp_runtime::traits::{Convert, UniqueSaturatedInto};
 
 use scale_info::TypeInfo;
+use sp_core::H256;
 
 /// Dispatch Empty
 pub const EMPTY_FUNCTION: fn(MessageSourceId) -> DispatchResult = |_| Ok(());
 
 /// A type definition for the payload of adding an MSA key - `pallet_msa::add_public_key_to_msa`
-#[derive(TypeInfo, RuntimeDebugNoBound, Clone, Decode, Encode, PartialEq, Eq)]
+#[derive(TypeInfo, RuntimeDebugNoBound, Clone, Decode, Encode, PartialEq, Eq, MaxEncodedLen)]
 #[scale_info(skip_type_params(T))]
 pub struct AddKeyData<T: Config> {
 	/// Message Source Account identifier
@@ -26,6 +32,310 @@ pub struct AddKeyData<T: Config> {
 	pub expiration: BlockNumberFor<T>,
 	/// The public key to be added.
 	pub new_public_key: T::AccountId,
+	/// The restrictions on what the new key may do, if any.
+	pub scope: Option<KeyScope<T::MaxSchemaGrantsPerDelegation>>,
+}
+
+impl<T: Config> AddKeyData<T> {
+	/// The bytes the MSA owner and the new key sign to authorize this payload.
+	/// A payload without `scope` is encoded as it was before that field existed,
+	/// so that signatures over such payloads remain valid.
+	pub fn signed_payload(&self) -> Vec<u8> {
+		match self.scope {
+			Some(_) => self.encode(),
+			None => (self.msa_id, &self.expiration, &self.new_public_key).encode(),
+		}
+	}
+}
+
+impl<T: Config> EIP712Encode for AddKeyData<T> {
+	/// A payload without `scope` is encoded as a scope that may manage the MSA and write every schema
+	fn eip_712_hash_struct(&self) -> [u8; 32] {
+		let (can_manage_msa, schema_ids) = match &self.scope {
+			Some(scope) => (scope.can_manage_msa, scope.schema_ids.clone()),
+			None => (true, None),
+		};
+		let encoded_schema_ids: Vec<[u8; 32]> = schema_ids
+			.iter()
+			.flatten()
+			.map(|id| eip_712_encode_uint((*id).into()))
+			.collect();
+		eip_712_hash_struct(
+			b"AddKeyData(uint64 msaId,uint32 expiration,bytes32 newPublicKey,bool canManageMsa,bool allSchemas,uint16[] schemaIds)",
+			&[
+				eip_712_encode_uint(self.msa_id),
+				eip_712_encode_uint(self.expiration.unique_saturated_into()),
+				eip_712_encode_account::<T>(&self.new_public_key),
+				eip_712_encode_uint(can_manage_msa.into()),
+				eip_712_encode_uint(schema_ids.is_none().into()),
+				eip_712_encode_array(&encoded_schema_ids),
+			],
+		)
+	}
+}
+
+/// A type definition for the payload of rotating an MSA key - `pallet_msa::rotate_msa_public_key`
+#[derive(TypeInfo, RuntimeDebugNoBound, Clone, Decode, Encode, PartialEq, Eq)]
+#[scale_info(skip_type_params(T))]
+pub struct RotateKeyData<T: Config> {
+	/// Message Source Account identifier
+	pub msa_id: MessageSourceId,
+	/// The block number at which the signed proof for rotate_msa_public_key expires.
+	pub expiration: BlockNumberFor<T>,
+	/// The public key to be added.
+	pub new_public_key: T::AccountId,
+	/// The public key to be replaced.
+	pub old_public_key: T::AccountId,
+}
+
+impl<T: Config> EIP712Encode for RotateKeyData<T> {
+	fn eip_712_hash_struct(&self) -> [u8; 32] {
+		eip_712_hash_struct(
+			b"RotateKeyData(uint64 msaId,uint32 expiration,bytes32 newPublicKey,bytes32 oldPublicKey)",
+			&[
+				eip_712_encode_uint(self.msa_id),
+				eip_712_encode_uint(self.expiration.unique_saturated_into()),
+				eip_712_encode_account::<T>(&self.new_public_key),
+				eip_712_encode_account::<T>(&self.old_public_key),
+			],
+		)
+	}
+}
+
+/// A type definition for the payload of merging two MSAs - `pallet_msa::merge_msa`
+#[derive(TypeInfo, RuntimeDebugNoBound, Clone, Decode, Encode, PartialEq, Eq)]
+#[scale_info(skip_type_params(T))]
+pub struct MergeMsaData<T: Config> {
+	/// The MSA whose keys and data are moved and which is then retired
+	pub source_msa_id: MessageSourceId,
+	/// A key of the source MSA
+	pub source_public_key: T::AccountId,
+	/// The MSA the keys and data are moved to
+	pub target_msa_id: MessageSourceId,
+	/// A key of the target MSA
+	pub target_public_key: T::AccountId,
+	/// The block number at which the signed proofs for merge_msa expire.
+	pub expiration: BlockNumberFor<T>,
+}
+
+impl<T: Config> EIP712Encode for MergeMsaData<T> {
+	fn eip_712_hash_struct(&self) -> [u8; 32] {
+		eip_712_hash_struct(
+			b"MergeMsaData(uint64 sourceMsaId,bytes32 sourcePublicKey,uint64 targetMsaId,bytes32 targetPublicKey,uint32 expiration)",
+			&[
+				eip_712_encode_uint(self.source_msa_id),
+				eip_712_encode_account::<T>(&self.source_public_key),
+				eip_712_encode_uint(self.target_msa_id),
+				eip_712_encode_account::<T>(&self.target_public_key),
+				eip_712_encode_uint(self.expiration.unique_saturated_into()),
+			],
+		)
+	}
+}
+
+/// Encodes a public key as an EIP-712 `bytes32` value
+fn eip_712_encode_account<T: Config>(key: &T::AccountId) -> [u8; 32] {
+	T::ConvertIntoAccountId32::convert(key.clone()).into()
+}
+
+/// The guardians an MSA has registered to recover its control keys
+#[derive(
+	TypeInfo, RuntimeDebugNoBound, PartialEqNoBound, EqNoBound, Clone, Decode, Encode, MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct RecoveryConfig<T: Config> {
+	/// The MSAs (recovery providers or guardians) that may initiate and approve a recovery
+	pub guardians: BoundedVec<MessageSourceId, T::MaxRecoveryGuardians>,
+	/// The number of guardian approvals required to complete a recovery
+	pub threshold: u32,
+	/// The hash of a secret that must be revealed to initiate a recovery, if any
+	pub commitment: Option<H256>,
+}
+
+/// A recovery of an MSA that is waiting for guardian approvals and the recovery delay
+#[derive(
+	TypeInfo, RuntimeDebugNoBound, PartialEqNoBound, EqNoBound, Clone, Decode, Encode, MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct ActiveRecovery<T: Config> {
+	/// The key that will be added to the MSA when the recovery completes
+	pub new_key: T::AccountId,
+	/// The key of the MSA that `new_key` replaces, if any
+	pub replaced_key: Option<T::AccountId>,
+	/// The block at which the recovery was initiated
+	pub initiated_at: BlockNumberFor<T>,
+	/// The guardians that have approved the recovery
+	pub approvals: BoundedVec<MessageSourceId, T::MaxRecoveryGuardians>,
+}
+
+/// A delegation requested by a Provider that the Delegator has not yet accepted or rejected
+#[derive(
+	TypeInfo, RuntimeDebugNoBound, PartialEqNoBound, EqNoBound, Clone, Decode, Encode, MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct DelegationRequest<T: Config> {
+	/// The schemas the Provider is requesting to be granted
+	pub schema_ids: BoundedVec<SchemaId, T::MaxSchemaGrantsPerDelegation>,
+	/// The block at which the request was made
+	pub requested_at: BlockNumberFor<T>,
+	/// The block after which the request can no longer be accepted
+	pub expiration: BlockNumberFor<T>,
+	/// The block at which the delegation expires once accepted, if any
+	pub delegation_expires_at: Option<BlockNumberFor<T>>,
+	/// The account that holds the deposit of the request
+	pub depositor: T::AccountId,
+	/// The amount held for the request
+	pub deposit: BalanceOf<T>,
+}
+
+/// A subset of the schema grants of a parent Provider sub-delegated to another Provider
+#[derive(
+	TypeInfo, RuntimeDebugNoBound, PartialEqNoBound, EqNoBound, Clone, Decode, Encode, MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct SubDelegation<T: Config> {
+	/// The Provider that sub-delegated its grants
+	pub parent_provider_id: ProviderId,
+	/// The schemas of the parent's grants that are sub-delegated
+	pub schema_ids: BoundedVec<SchemaId, T::MaxSchemaGrantsPerDelegation>,
+	/// The block at which the sub-delegation was revoked, or zero while it is active
+	pub revoked_at: BlockNumberFor<T>,
+}
+
+/// A type definition for the payload of answering a delegation request - `pallet_msa::answer_delegation_request`
+#[derive(TypeInfo, RuntimeDebugNoBound, Clone, Decode, Encode, PartialEq, Eq)]
+#[scale_info(skip_type_params(T))]
+pub struct DelegationRequestAnswer<T: Config> {
+	/// The Provider that requested the delegation
+	pub provider_msa_id: MessageSourceId,
+	/// The block at which the answered request was made
+	pub requested_at: BlockNumberFor<T>,
+	/// Whether the Delegator accepts the request
+	pub accept: bool,
+	/// The block number at which the signed proof for answer_delegation_request expires.
+	pub expiration: BlockNumberFor<T>,
+}
+
+impl<T: Config> EIP712Encode for DelegationRequestAnswer<T> {
+	fn eip_712_hash_struct(&self) -> [u8; 32] {
+		eip_712_hash_struct(
+			b"DelegationRequestAnswer(uint64 providerMsaId,uint32 requestedAt,bool accept,uint32 expiration)",
+			&[
+				eip_712_encode_uint(self.provider_msa_id),
+				eip_712_encode_uint(self.requested_at.unique_saturated_into()),
+				eip_712_encode_uint(self.accept.into()),
+				eip_712_encode_uint(self.expiration.unique_saturated_into()),
+			],
+		)
+	}
+}
+
+/// [`Config::MaxPublicKeysPerMsa`] as a `Get<u32>`, to bound lists of the keys of an MSA
+pub struct MaxPublicKeysPerMsaBound<T>(PhantomData<T>);
+
+impl<T: Config> Get<u32> for MaxPublicKeysPerMsaBound<T> {
+	fn get() -> u32 {
+		T::MaxPublicKeysPerMsa::get().into()
+	}
+}
+
+/// An action on an MSA with a threshold policy, which a threshold of its keys must approve
+#[derive(
+	TypeInfo, RuntimeDebugNoBound, PartialEqNoBound, EqNoBound, Clone, Decode, Encode, MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+pub enum ThresholdAction<T: Config> {
+	/// Remove a key of the MSA, as [`delete_msa_public_key`](Pallet::delete_msa_public_key) does
+	DeletePublicKey {
+		/// The key to remove
+		key: T::AccountId,
+	},
+	/// Retire the MSA, as [`retire_msa`](Pallet::retire_msa) does.
+	/// Every remaining key of the MSA must have approved the action.
+	RetireMsa,
+	/// Publish Provider metadata, as [`set_provider_metadata`](Pallet::set_provider_metadata) does
+	SetProviderMetadata {
+		/// The metadata to publish
+		metadata:
+			ProviderMetadata<T::MaxProviderMetadataFieldSize, T::MaxSchemaGrantsPerDelegation>,
+	},
+	/// Revoke a delegation to a Provider, as [`revoke_delegation_by_delegator`](Pallet::revoke_delegation_by_delegator) does
+	RevokeDelegationByDelegator {
+		/// The Provider MSA of the delegation
+		provider_msa_id: MessageSourceId,
+	},
+	/// Revoke a delegation from a Delegator, as [`revoke_delegation_by_provider`](Pallet::revoke_delegation_by_provider) does
+	RevokeDelegationByProvider {
+		/// The Delegator MSA of the delegation
+		delegator_msa_id: MessageSourceId,
+	},
+	/// Change the threshold of the MSA, or remove its threshold policy when `None`
+	SetThreshold {
+		/// The new number of keys that must approve an action
+		threshold: Option<u8>,
+	},
+	/// Add a key to the MSA, as [`add_public_key_to_msa`](Pallet::add_public_key_to_msa) does.
+	/// The approvals stand in for the proof of the MSA owner, while the new key must still sign
+	/// `add_key_payload`, and the `scope` of the payload must be within the scope of every
+	/// scoped key that approved the action.
+	AddPublicKey {
+		/// The payload signed by the new key
+		add_key_payload: AddKeyData<T>,
+		/// The signature of the new key over `add_key_payload`
+		new_key_owner_proof: MultiSignature,
+	},
+	/// Register or replace the recovery guardians of the MSA, as [`set_recovery_config`](Pallet::set_recovery_config) does
+	SetRecoveryConfig {
+		/// The MSAs that may recover the MSA
+		guardians: BoundedVec<MessageSourceId, T::MaxRecoveryGuardians>,
+		/// The number of guardians that must approve a recovery
+		threshold: u32,
+		/// The hash of the secret that must be revealed to initiate a recovery, if any
+		commitment: Option<H256>,
+	},
+	/// Remove the recovery guardians of the MSA, as [`remove_recovery_config`](Pallet::remove_recovery_config) does
+	RemoveRecoveryConfig,
+	/// Set the permissions of a granted schema, as [`set_schema_grant_permissions`](Pallet::set_schema_grant_permissions) does
+	SetSchemaGrantPermissions {
+		/// The Provider MSA of the delegation
+		provider_msa_id: MessageSourceId,
+		/// The granted schema
+		schema_id: SchemaId,
+		/// The actions the Provider may take with the schema
+		permissions: SchemaPermissions,
+	},
+	/// Revoke schema grants of a delegation, as [`revoke_schema_permissions`](Pallet::revoke_schema_permissions) does
+	RevokeSchemaPermissions {
+		/// The Provider MSA of the delegation
+		provider_msa_id: MessageSourceId,
+		/// The schemas to revoke
+		schema_ids: BoundedVec<SchemaId, T::MaxSchemaGrantsPerDelegation>,
+	},
+	/// Start the retirement of the Provider of the MSA, as [`retire_provider`](Pallet::retire_provider) does
+	RetireProvider,
+	/// Propose to governance a new name for the Provider of the MSA, as
+	/// [`propose_to_change_provider_name`](Pallet::propose_to_change_provider_name) does.
+	/// The first key that approved the action and still belongs to the MSA proposes it to governance.
+	ChangeProviderName {
+		/// The new name of the Provider
+		provider_name: BoundedVec<u8, T::MaxProviderNameSize>,
+	},
+}
+
+/// An action proposed by a key of an MSA with a threshold policy, waiting for approvals
+#[derive(
+	TypeInfo, RuntimeDebugNoBound, PartialEqNoBound, EqNoBound, Clone, Decode, Encode, MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct ThresholdProposal<T: Config> {
+	/// The action to execute once approved
+	pub action: ThresholdAction<T>,
+	/// The block at which the action was proposed
+	pub proposed_at: BlockNumberFor<T>,
+	/// The keys of the MSA that have approved the action, starting with the proposer
+	pub approvals: BoundedVec<T::AccountId, MaxPublicKeysPerMsaBound<T>>,
+	/// The keys of the MSA, other than the proposer, that have asked to cancel the action
+	pub rejections: BoundedVec<T::AccountId, MaxPublicKeysPerMsaBound<T>>,
 }
 
 /// Structure that is signed for granting permissions to a Provider
@@ -38,6 +348,8 @@ pub struct AddProvider {
 	pub schema_ids: Vec<SchemaId>,
 	/// The block number at which the proof for grant_delegation expires.
 	pub expiration: BlockNumber,
+	/// The block number after which the delegation is treated as revoked, if any.
+	pub expires_at: Option<BlockNumber>,
 }
 
 impl AddProvider {
@@ -52,7 +364,40 @@ impl AddProvider {
 			None => Vec::default(),
 		};
 
-		Self { authorized_msa_id, schema_ids, expiration }
+		Self { authorized_msa_id, schema_ids, expiration, expires_at: None }
+	}
+
+	/// Sets the block number after which the delegation is treated as revoked
+	pub fn with_expires_at(mut self, expires_at: BlockNumber) -> Self {
+		self.expires_at = Some(expires_at);
+		self
+	}
+
+	/// The bytes the delegator signs to authorize this payload.
+	/// A payload without `expires_at` is encoded as it was before that field existed,
+	/// so that signatures over such payloads remain valid.
+	pub fn signed_payload(&self) -> Vec<u8> {
+		match self.expires_at {
+			Some(_) => self.encode(),
+			None => (self.authorized_msa_id, &self.schema_ids, self.expiration).encode(),
+		}
+	}
+}
+
+impl EIP712Encode for AddProvider {
+	/// A payload without `expires_at` is encoded with an `expiresAt` of zero
+	fn eip_712_hash_struct(&self) -> [u8; 32] {
+		let schema_ids: Vec<[u8; 32]> =
+			self.schema_ids.iter().map(|id| eip_712_encode_uint((*id).into())).collect();
+		eip_712_hash_struct(
+			b"AddProvider(uint64 authorizedMsaId,uint16[] schemaIds,uint32 expiration,uint32 expiresAt)",
+			&[
+				eip_712_encode_uint(self.authorized_msa_id),
+				eip_712_encode_array(&schema_ids),
+				eip_712_encode_uint(self.expiration.into()),
+				eip_712_encode_uint(self.expires_at.unwrap_or_default().into()),
+			],
+		)
 	}
 }
 
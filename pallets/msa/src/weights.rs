@@ -45,6 +45,34 @@ pub trait WeightInfo {
 	fn create_provider_via_governance() -> Weight;
 	fn propose_to_be_provider() -> Weight;
 	fn revoke_schema_permissions(s: u32, ) -> Weight;
+	fn set_schema_grant_permissions() -> Weight;
+	fn set_recovery_config(g: u32, ) -> Weight;
+	fn remove_recovery_config() -> Weight;
+	fn initiate_recovery() -> Weight;
+	fn approve_recovery() -> Weight;
+	fn cancel_recovery() -> Weight;
+	fn complete_recovery() -> Weight;
+	fn rotate_msa_public_key() -> Weight;
+	fn set_provider_metadata(s: u32, ) -> Weight;
+	fn propose_to_change_provider_name() -> Weight;
+	fn change_provider_name_via_governance() -> Weight;
+	fn retire_provider() -> Weight;
+	fn complete_provider_retirement() -> Weight;
+	fn purge_retired_msa(n: u32, ) -> Weight;
+	fn revoke_retired_provider_delegations(n: u32, ) -> Weight;
+	fn index_provider_delegators(n: u32, ) -> Weight;
+	fn request_delegation(s: u32, ) -> Weight;
+	fn cancel_delegation_request() -> Weight;
+	fn answer_delegation_request() -> Weight;
+	fn set_threshold_policy() -> Weight;
+	fn propose_threshold_action() -> Weight;
+	fn approve_threshold_action() -> Weight;
+	fn cancel_threshold_action() -> Weight;
+	fn grant_sub_delegation(s: u32, ) -> Weight;
+	fn revoke_sub_delegation() -> Weight;
+	fn merge_msa(k: u32, ) -> Weight;
+	fn merge_queued_msa(n: u32, ) -> Weight;
+	fn remove_expired_delegation_request() -> Weight;
 }
 
 /// Weights for `pallet_msa` using the Substrate node and recommended hardware.
@@ -249,6 +277,547 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::DelegatorAndProviderToDelegation` (r:1 w:0)
+	/// Proof: `Msa::DelegatorAndProviderToDelegation` (`max_values`: None, `max_size`: Some(217), added: 2692, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::DelegationSchemaPermissions` (r:1 w:1)
+	/// Proof: `Msa::DelegationSchemaPermissions` (`max_values`: None, `max_size`: Some(183), added: 2658, mode: `MaxEncodedLen`)
+	fn set_schema_grant_permissions() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `340`
+		//  Estimated: `4177`
+		// Minimum execution time: 15_112_000 picoseconds.
+		Weight::from_parts(15_640_000, 4177)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ActiveRecoveries` (r:1 w:0)
+	/// Proof: `Msa::ActiveRecoveries` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyCountForMsaId` (r:10 w:0)
+	/// Proof: `Msa::PublicKeyCountForMsaId` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::RecoveryConfigs` (r:0 w:1)
+	/// Proof: `Msa::RecoveryConfigs` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// The range of component `g` is `[0, 10]`.
+	fn set_recovery_config(g: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `67 + g * (39 ±0)`
+		//  Estimated: `3598 + g * (2492 ±0)`
+		// Minimum execution time: 12_387_000 picoseconds.
+		Weight::from_parts(12_904_000, 3598)
+			// Standard Error: 445_428
+			.saturating_add(Weight::from_parts(3_118_000, 0).saturating_mul(g.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(g.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2492).saturating_mul(g.into()))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::RecoveryConfigs` (r:1 w:1)
+	/// Proof: `Msa::RecoveryConfigs` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ActiveRecoveries` (r:1 w:0)
+	/// Proof: `Msa::ActiveRecoveries` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn remove_recovery_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `252`
+		//  Estimated: `3599`
+		// Minimum execution time: 12_697_000 picoseconds.
+		Weight::from_parts(13_227_000, 3599)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:2 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyCountForMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyCountForMsaId` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::RecoveryConfigs` (r:1 w:0)
+	/// Proof: `Msa::RecoveryConfigs` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Msa::ActiveRecoveries` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn initiate_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `364`
+		//  Estimated: `3599`
+		// Minimum execution time: 20_653_000 picoseconds.
+		Weight::from_parts(21_514_000, 3599)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::RecoveryConfigs` (r:1 w:0)
+	/// Proof: `Msa::RecoveryConfigs` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Msa::ActiveRecoveries` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn approve_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3599`
+		// Minimum execution time: 15_151_000 picoseconds.
+		Weight::from_parts(15_783_000, 3599)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Msa::ActiveRecoveries` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn cancel_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `232`
+		//  Estimated: `3598`
+		// Minimum execution time: 11_173_000 picoseconds.
+		Weight::from_parts(11_639_000, 3598)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Msa::ActiveRecoveries` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::RecoveryConfigs` (r:1 w:0)
+	/// Proof: `Msa::RecoveryConfigs` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:1)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyCountForMsaId` (r:1 w:1)
+	/// Proof: `Msa::PublicKeyCountForMsaId` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	fn complete_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3599`
+		// Minimum execution time: 18_625_000 picoseconds.
+		Weight::from_parts(19_402_000, 3599)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Msa::PayloadSignatureRegistryList` (r:4 w:4)
+	/// Proof: `Msa::PayloadSignatureRegistryList` (`max_values`: Some(50000), `max_size`: Some(144), added: 2124, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PayloadSignatureRegistryPointer` (r:1 w:1)
+	/// Proof: `Msa::PayloadSignatureRegistryPointer` (`max_values`: Some(1), `max_size`: Some(140), added: 635, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyToMsaId` (r:2 w:2)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn rotate_msa_public_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1504`
+		//  Estimated: `9981`
+		// Minimum execution time: 133_840_000 picoseconds.
+		Weight::from_parts(139_417_000, 9981)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:1 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::CurrentSchemaIdentifierMaximum` (r:1 w:0)
+	/// Proof: `Schemas::CurrentSchemaIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToMetadata` (r:0 w:1)
+	/// Proof: `Msa::ProviderToMetadata` (`max_values`: None, `max_size`: Some(884), added: 3359, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 30]`.
+	fn set_provider_metadata(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `147`
+		//  Estimated: `4008`
+		// Minimum execution time: 13_643_000 picoseconds.
+		Weight::from_parts(14_212_000, 4008)
+			// Standard Error: 2_118
+			.saturating_add(Weight::from_parts(14_830, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:1 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Council::ProposalOf` (r:1 w:1)
+	/// Proof: `Council::ProposalOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Proposals` (r:1 w:1)
+	/// Proof: `Council::Proposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::ProposalCount` (r:1 w:1)
+	/// Proof: `Council::ProposalCount` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Voting` (r:0 w:1)
+	/// Proof: `Council::Voting` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn propose_to_change_provider_name() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `213`
+		//  Estimated: `4107`
+		// Minimum execution time: 19_904_000 picoseconds.
+		Weight::from_parts(20_734_000, 4107)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:1 w:1)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	fn change_provider_name_via_governance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `134`
+		//  Estimated: `4008`
+		// Minimum execution time: 10_758_000 picoseconds.
+		Weight::from_parts(11_207_000, 4008)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:1 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderRetirements` (r:1 w:1)
+	/// Proof: `Msa::ProviderRetirements` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	fn retire_provider() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `134`
+		//  Estimated: `4008`
+		// Minimum execution time: 11_137_000 picoseconds.
+		Weight::from_parts(11_602_000, 4008)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::ProviderRetirements` (r:1 w:0)
+	/// Proof: `Msa::ProviderRetirements` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:1 w:1)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToMetadata` (r:0 w:1)
+	/// Proof: `Msa::ProviderToMetadata` (`max_values`: None, `max_size`: Some(884), added: 3359, mode: `MaxEncodedLen`)
+	fn complete_provider_retirement() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `163`
+		//  Estimated: `3498`
+		// Minimum execution time: 10_541_000 picoseconds.
+		Weight::from_parts(10_981_000, 3498)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Msa::RetiredMsaPurgeQueue` (r:1 w:1)
+	/// Proof: `Msa::RetiredMsaPurgeQueue` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::RecoveryConfigs` (r:0 w:1)
+	/// Proof: `Msa::RecoveryConfigs` (`max_values`: None, `max_size`: Some(131), added: 2606, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ActiveRecoveries` (r:0 w:1)
+	/// Proof: `Msa::ActiveRecoveries` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::DelegatorAndProviderToDelegation` (r:1 w:0)
+	/// Proof: `Msa::DelegatorAndProviderToDelegation` (`max_values`: None, `max_size`: Some(217), added: 2692, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::DelegationSchemaPermissions` (r:1 w:0)
+	/// Proof: `Msa::DelegationSchemaPermissions` (`max_values`: None, `max_size`: Some(377), added: 2852, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 1000]`.
+	fn purge_retired_msa(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `141`
+		//  Estimated: `3842 + n * (2692 ±0)`
+		// Minimum execution time: 11_909_000 picoseconds.
+		Weight::from_parts(12_406_000, 3842)
+			// Standard Error: 271_931
+			.saturating_add(Weight::from_parts(1_903_522, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2692).saturating_mul(n.into()))
+	}
+	/// Storage: `Msa::ProviderToDelegatorsMigrationCursor` (r:1 w:1)
+	/// Proof: `Msa::ProviderToDelegatorsMigrationCursor` (`max_values`: Some(1), `max_size`: Some(130), added: 625, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::DelegatorAndProviderToDelegation` (r:1001 w:0)
+	/// Proof: `Msa::DelegatorAndProviderToDelegation` (`max_values`: None, `max_size`: Some(217), added: 2692, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToDelegators` (r:0 w:1000)
+	/// Proof: `Msa::ProviderToDelegators` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 1000]`.
+	fn index_provider_delegators(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `3682 + n * (2692 ±0)`
+		// Minimum execution time: 5_665_000 picoseconds.
+		Weight::from_parts(5_902_000, 3682)
+			// Standard Error: 488_285
+			.saturating_add(Weight::from_parts(3_418_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2692).saturating_mul(n.into()))
+	}
+	/// Storage: `Msa::ProviderRetirements` (r:1 w:1)
+	/// Proof: `Msa::ProviderRetirements` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToDelegators` (r:1001 w:1000)
+	/// Proof: `Msa::ProviderToDelegators` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::DelegatorAndProviderToDelegation` (r:1000 w:1000)
+	/// Proof: `Msa::DelegatorAndProviderToDelegation` (`max_values`: None, `max_size`: Some(217), added: 2692, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderRevocationQueue` (r:0 w:1)
+	/// Proof: `Msa::ProviderRevocationQueue` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::DelegationSchemaPermissions` (r:0 w:1000)
+	/// Proof: `Msa::DelegationSchemaPermissions` (`max_values`: None, `max_size`: Some(183), added: 2658, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 1000]`.
+	fn revoke_retired_provider_delegations(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  ``176 + n * (118 ±0)``
+		//  Estimated: `3497 + n * (2692 ±0)`
+		// Minimum execution time: 9_995_000 picoseconds.
+		Weight::from_parts(10_412_000, 3497)
+			// Standard Error: 1_405_142
+			.saturating_add(Weight::from_parts(9_836_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2692).saturating_mul(n.into()))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyCountForMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyCountForMsaId` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:1 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderRetirements` (r:1 w:0)
+	/// Proof: `Msa::ProviderRetirements` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::CurrentSchemaIdentifierMaximum` (r:1 w:0)
+	/// Proof: `Schemas::CurrentSchemaIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PendingDelegationRequests` (r:1 w:1)
+	/// Proof: `Msa::PendingDelegationRequests` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 30]`.
+	fn request_delegation(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `295`
+		//  Estimated: `4066`
+		// Minimum execution time: 20_972_000 picoseconds.
+		Weight::from_parts(21_846_113, 4066)
+			// Standard Error: 11_887
+			.saturating_add(Weight::from_parts(83_214, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PendingDelegationRequests` (r:1 w:1)
+	/// Proof: `Msa::PendingDelegationRequests` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	fn cancel_delegation_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `224`
+		//  Estimated: `4066`
+		// Minimum execution time: 13_347_000 picoseconds.
+		Weight::from_parts(13_904_000, 4066)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::PayloadSignatureRegistryList` (r:2 w:2)
+	/// Proof: `Msa::PayloadSignatureRegistryList` (`max_values`: Some(50000), `max_size`: Some(144), added: 2124, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PayloadSignatureRegistryPointer` (r:1 w:1)
+	/// Proof: `Msa::PayloadSignatureRegistryPointer` (`max_values`: Some(1), `max_size`: Some(140), added: 635, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyScopes` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyScopes` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PendingDelegationRequests` (r:1 w:1)
+	/// Proof: `Msa::PendingDelegationRequests` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:1 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderRetirements` (r:1 w:0)
+	/// Proof: `Msa::ProviderRetirements` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::DelegatorAndProviderToDelegation` (r:1 w:1)
+	/// Proof: `Msa::DelegatorAndProviderToDelegation` (`max_values`: None, `max_size`: Some(217), added: 2692, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToDelegators` (r:0 w:1)
+	/// Proof: `Msa::ProviderToDelegators` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::CurrentSchemaIdentifierMaximum` (r:1 w:0)
+	/// Proof: `Schemas::CurrentSchemaIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	fn answer_delegation_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1432`
+		//  Estimated: `6531`
+		// Minimum execution time: 94_478_000 picoseconds.
+		Weight::from_parts(98_415_000, 6531)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Msa::PendingDelegationRequests` (r:1 w:1)
+	/// Proof: `Msa::PendingDelegationRequests` (`max_values`: None, `max_size`: Some(154), added: 2629, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PendingDelegationRequestCount` (r:1 w:1)
+	/// Proof: `Msa::PendingDelegationRequestCount` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn remove_expired_delegation_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `371`
+		//  Estimated: `4119`
+		// Minimum execution time: 27_951_000 picoseconds.
+		Weight::from_parts(29_116_000, 4119)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyScopes` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyScopes` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ThresholdPolicies` (r:1 w:1)
+	/// Proof: `Msa::ThresholdPolicies` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyCountForMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyCountForMsaId` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	fn set_threshold_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `176`
+		//  Estimated: `3568`
+		// Minimum execution time: 14_699_000 picoseconds.
+		Weight::from_parts(15_312_000, 3568)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyScopes` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyScopes` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ThresholdPolicies` (r:1 w:0)
+	/// Proof: `Msa::ThresholdPolicies` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::NextThresholdProposalId` (r:1 w:1)
+	/// Proof: `Msa::NextThresholdProposalId` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ThresholdProposals` (r:0 w:1)
+	/// Proof: `Msa::ThresholdProposals` (`max_values`: None, `max_size`: Some(1702), added: 4177, mode: `MaxEncodedLen`)
+	fn propose_threshold_action() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `214`
+		//  Estimated: `3568`
+		// Minimum execution time: 19_079_000 picoseconds.
+		Weight::from_parts(19_874_000, 3568)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:26 w:25)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyScopes` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyScopes` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ThresholdPolicies` (r:1 w:1)
+	/// Proof: `Msa::ThresholdPolicies` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ThresholdProposals` (r:1 w:1)
+	/// Proof: `Msa::ThresholdProposals` (`max_values`: None, `max_size`: Some(1702), added: 4177, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyCountForMsaId` (r:1 w:1)
+	/// Proof: `Msa::PublicKeyCountForMsaId` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:1 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyScopes` (r:0 w:25)
+	/// Proof: `Msa::PublicKeyScopes` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::RetiredMsaPurgeQueue` (r:0 w:1)
+	/// Proof: `Msa::RetiredMsaPurgeQueue` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn approve_threshold_action() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3318`
+		//  Estimated: `66973`
+		// Minimum execution time: 231_967_000 picoseconds.
+		Weight::from_parts(241_633_000, 66973)
+			.saturating_add(T::DbWeight::get().reads(32_u64))
+			.saturating_add(T::DbWeight::get().writes(54_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyScopes` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyScopes` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ThresholdProposals` (r:1 w:1)
+	/// Proof: `Msa::ThresholdProposals` (`max_values`: None, `max_size`: Some(1702), added: 4177, mode: `MaxEncodedLen`)
+	fn cancel_threshold_action() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1906`
+		//  Estimated: `5167`
+		// Minimum execution time: 15_865_000 picoseconds.
+		Weight::from_parts(16_527_000, 5167)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:2 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderRetirements` (r:2 w:0)
+	/// Proof: `Msa::ProviderRetirements` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::CurrentSchemaIdentifierMaximum` (r:1 w:0)
+	/// Proof: `Schemas::CurrentSchemaIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::SubDelegations` (r:1 w:1)
+	/// Proof: `Msa::SubDelegations` (`max_values`: None, `max_size`: Some(1185), added: 3660, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 30]`.
+	fn grant_sub_delegation(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `5150`
+		// Minimum execution time: 23_345_000 picoseconds.
+		Weight::from_parts(24_318_000, 5150)
+			// Standard Error: 13_058
+			.saturating_add(Weight::from_parts(91_406, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::SubDelegations` (r:1 w:1)
+	/// Proof: `Msa::SubDelegations` (`max_values`: None, `max_size`: Some(1185), added: 3660, mode: `MaxEncodedLen`)
+	fn revoke_sub_delegation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `301`
+		//  Estimated: `5150`
+		// Minimum execution time: 14_521_000 picoseconds.
+		Weight::from_parts(15_127_000, 5150)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::PayloadSignatureRegistryList` (r:4 w:4)
+	/// Proof: `Msa::PayloadSignatureRegistryList` (`max_values`: Some(50000), `max_size`: Some(144), added: 2124, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PayloadSignatureRegistryPointer` (r:1 w:1)
+	/// Proof: `Msa::PayloadSignatureRegistryPointer` (`max_values`: Some(1), `max_size`: Some(140), added: 635, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyToMsaId` (r:3 w:1)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyScopes` (r:2 w:0)
+	/// Proof: `Msa::PublicKeyScopes` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ThresholdPolicies` (r:2 w:0)
+	/// Proof: `Msa::ThresholdPolicies` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:1 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyCountForMsaId` (r:2 w:2)
+	/// Proof: `Msa::PublicKeyCountForMsaId` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::RecoveryConfigs` (r:0 w:1)
+	/// Proof: `Msa::RecoveryConfigs` (`max_values`: None, `max_size`: Some(131), added: 2606, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ActiveRecoveries` (r:0 w:1)
+	/// Proof: `Msa::ActiveRecoveries` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::MsaRedirects` (r:0 w:1)
+	/// Proof: `Msa::MsaRedirects` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::MsaMergeQueue` (r:0 w:1)
+	/// Proof: `Msa::MsaMergeQueue` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// The range of component `k` is `[1, 25]`.
+	fn merge_msa(k: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1688`
+		//  Estimated: `10074 + k * (2523 ±0)`
+		// Minimum execution time: 139_404_000 picoseconds.
+		Weight::from_parts(145_213_000, 10074)
+			// Standard Error: 588_285
+			.saturating_add(Weight::from_parts(4_118_000, 0).saturating_mul(k.into()))
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(k.into())))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(k.into())))
+			.saturating_add(Weight::from_parts(0, 2523).saturating_mul(k.into()))
+	}
+	/// Storage: `Msa::MsaRedirects` (r:1 w:0)
+	/// Proof: `Msa::MsaRedirects` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::DelegatorAndProviderToDelegation` (r:2 w:1)
+	/// Proof: `Msa::DelegatorAndProviderToDelegation` (`max_values`: None, `max_size`: Some(217), added: 2692, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::DelegationSchemaPermissions` (r:1 w:1)
+	/// Proof: `Msa::DelegationSchemaPermissions` (`max_values`: None, `max_size`: Some(377), added: 2852, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToDelegators` (r:0 w:2)
+	/// Proof: `Msa::ProviderToDelegators` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::MsaMergeQueue` (r:0 w:1)
+	/// Proof: `Msa::MsaMergeQueue` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::RetiredMsaPurgeQueue` (r:0 w:1)
+	/// Proof: `Msa::RetiredMsaPurgeQueue` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 1000]`.
+	fn merge_queued_msa(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `176`
+		//  Estimated: `3842 + n * (2852 ±0)`
+		// Minimum execution time: 12_755_000 picoseconds.
+		Weight::from_parts(13_287_000, 3842)
+			// Standard Error: 514_874
+			.saturating_add(Weight::from_parts(3_604_118, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2852).saturating_mul(n.into()))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -452,45 +1021,586 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-}
-
-
-#[cfg(test)]
-mod tests {
-  use frame_support::{traits::Get, weights::Weight, dispatch::DispatchClass};
-  use common_runtime::constants::{MAXIMUM_BLOCK_WEIGHT, NORMAL_DISPATCH_RATIO};
-  use common_runtime::weights::extrinsic_weights::ExtrinsicBaseWeight;
-
-  #[allow(dead_code)]
-  struct BlockWeights;
-  impl Get<frame_system::limits::BlockWeights> for BlockWeights {
-  	fn get() -> frame_system::limits::BlockWeights {
-  		frame_system::limits::BlockWeights::builder()
-  			.base_block(Weight::zero())
-  			.for_class(DispatchClass::all(), |weights| {
-  				weights.base_extrinsic = ExtrinsicBaseWeight::get().into();
-  			})
-  			.for_class(DispatchClass::non_mandatory(), |weights| {
-  				weights.max_total = Some(NORMAL_DISPATCH_RATIO * MAXIMUM_BLOCK_WEIGHT);
-  			})
-  			.build_or_panic()
-  	}
-  }
-
-	#[test]
-	fn test_create() {
-		assert!(
-			BlockWeights::get()
-				.per_class
-				.get(frame_support::dispatch::DispatchClass::Normal)
-				.max_extrinsic
-				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
-				.proof_size()
-				> 4008
-		);
-	}
-	#[test]
-	fn test_create_sponsored_account_with_delegation() {
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::DelegatorAndProviderToDelegation` (r:1 w:0)
+	/// Proof: `Msa::DelegatorAndProviderToDelegation` (`max_values`: None, `max_size`: Some(217), added: 2692, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::DelegationSchemaPermissions` (r:1 w:1)
+	/// Proof: `Msa::DelegationSchemaPermissions` (`max_values`: None, `max_size`: Some(183), added: 2658, mode: `MaxEncodedLen`)
+	fn set_schema_grant_permissions() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `340`
+		//  Estimated: `4177`
+		// Minimum execution time: 15_112_000 picoseconds.
+		Weight::from_parts(15_640_000, 4177)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ActiveRecoveries` (r:1 w:0)
+	/// Proof: `Msa::ActiveRecoveries` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyCountForMsaId` (r:10 w:0)
+	/// Proof: `Msa::PublicKeyCountForMsaId` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::RecoveryConfigs` (r:0 w:1)
+	/// Proof: `Msa::RecoveryConfigs` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// The range of component `g` is `[0, 10]`.
+	fn set_recovery_config(g: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `67 + g * (39 ±0)`
+		//  Estimated: `3598 + g * (2492 ±0)`
+		// Minimum execution time: 12_387_000 picoseconds.
+		Weight::from_parts(12_904_000, 3598)
+			// Standard Error: 445_428
+			.saturating_add(Weight::from_parts(3_118_000, 0).saturating_mul(g.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(g.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2492).saturating_mul(g.into()))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::RecoveryConfigs` (r:1 w:1)
+	/// Proof: `Msa::RecoveryConfigs` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ActiveRecoveries` (r:1 w:0)
+	/// Proof: `Msa::ActiveRecoveries` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn remove_recovery_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `252`
+		//  Estimated: `3599`
+		// Minimum execution time: 12_697_000 picoseconds.
+		Weight::from_parts(13_227_000, 3599)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:2 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyCountForMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyCountForMsaId` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::RecoveryConfigs` (r:1 w:0)
+	/// Proof: `Msa::RecoveryConfigs` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Msa::ActiveRecoveries` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn initiate_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `364`
+		//  Estimated: `3599`
+		// Minimum execution time: 20_653_000 picoseconds.
+		Weight::from_parts(21_514_000, 3599)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::RecoveryConfigs` (r:1 w:0)
+	/// Proof: `Msa::RecoveryConfigs` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Msa::ActiveRecoveries` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn approve_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3599`
+		// Minimum execution time: 15_151_000 picoseconds.
+		Weight::from_parts(15_783_000, 3599)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Msa::ActiveRecoveries` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn cancel_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `232`
+		//  Estimated: `3598`
+		// Minimum execution time: 11_173_000 picoseconds.
+		Weight::from_parts(11_639_000, 3598)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Msa::ActiveRecoveries` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::RecoveryConfigs` (r:1 w:0)
+	/// Proof: `Msa::RecoveryConfigs` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:1)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyCountForMsaId` (r:1 w:1)
+	/// Proof: `Msa::PublicKeyCountForMsaId` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	fn complete_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3599`
+		// Minimum execution time: 18_625_000 picoseconds.
+		Weight::from_parts(19_402_000, 3599)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Msa::PayloadSignatureRegistryList` (r:4 w:4)
+	/// Proof: `Msa::PayloadSignatureRegistryList` (`max_values`: Some(50000), `max_size`: Some(144), added: 2124, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PayloadSignatureRegistryPointer` (r:1 w:1)
+	/// Proof: `Msa::PayloadSignatureRegistryPointer` (`max_values`: Some(1), `max_size`: Some(140), added: 635, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyToMsaId` (r:2 w:2)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn rotate_msa_public_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1504`
+		//  Estimated: `9981`
+		// Minimum execution time: 133_840_000 picoseconds.
+		Weight::from_parts(139_417_000, 9981)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:1 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::CurrentSchemaIdentifierMaximum` (r:1 w:0)
+	/// Proof: `Schemas::CurrentSchemaIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToMetadata` (r:0 w:1)
+	/// Proof: `Msa::ProviderToMetadata` (`max_values`: None, `max_size`: Some(884), added: 3359, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 30]`.
+	fn set_provider_metadata(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `147`
+		//  Estimated: `4008`
+		// Minimum execution time: 13_643_000 picoseconds.
+		Weight::from_parts(14_212_000, 4008)
+			// Standard Error: 2_118
+			.saturating_add(Weight::from_parts(14_830, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:1 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Council::ProposalOf` (r:1 w:1)
+	/// Proof: `Council::ProposalOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Proposals` (r:1 w:1)
+	/// Proof: `Council::Proposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::ProposalCount` (r:1 w:1)
+	/// Proof: `Council::ProposalCount` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Voting` (r:0 w:1)
+	/// Proof: `Council::Voting` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn propose_to_change_provider_name() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `213`
+		//  Estimated: `4107`
+		// Minimum execution time: 19_904_000 picoseconds.
+		Weight::from_parts(20_734_000, 4107)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:1 w:1)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	fn change_provider_name_via_governance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `134`
+		//  Estimated: `4008`
+		// Minimum execution time: 10_758_000 picoseconds.
+		Weight::from_parts(11_207_000, 4008)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:1 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderRetirements` (r:1 w:1)
+	/// Proof: `Msa::ProviderRetirements` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	fn retire_provider() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `134`
+		//  Estimated: `4008`
+		// Minimum execution time: 11_137_000 picoseconds.
+		Weight::from_parts(11_602_000, 4008)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::ProviderRetirements` (r:1 w:0)
+	/// Proof: `Msa::ProviderRetirements` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:1 w:1)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToMetadata` (r:0 w:1)
+	/// Proof: `Msa::ProviderToMetadata` (`max_values`: None, `max_size`: Some(884), added: 3359, mode: `MaxEncodedLen`)
+	fn complete_provider_retirement() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `163`
+		//  Estimated: `3498`
+		// Minimum execution time: 10_541_000 picoseconds.
+		Weight::from_parts(10_981_000, 3498)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Msa::RetiredMsaPurgeQueue` (r:1 w:1)
+	/// Proof: `Msa::RetiredMsaPurgeQueue` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::RecoveryConfigs` (r:0 w:1)
+	/// Proof: `Msa::RecoveryConfigs` (`max_values`: None, `max_size`: Some(131), added: 2606, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ActiveRecoveries` (r:0 w:1)
+	/// Proof: `Msa::ActiveRecoveries` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::DelegatorAndProviderToDelegation` (r:1 w:0)
+	/// Proof: `Msa::DelegatorAndProviderToDelegation` (`max_values`: None, `max_size`: Some(217), added: 2692, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::DelegationSchemaPermissions` (r:1 w:0)
+	/// Proof: `Msa::DelegationSchemaPermissions` (`max_values`: None, `max_size`: Some(377), added: 2852, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 1000]`.
+	fn purge_retired_msa(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `141`
+		//  Estimated: `3842 + n * (2692 ±0)`
+		// Minimum execution time: 11_909_000 picoseconds.
+		Weight::from_parts(12_406_000, 3842)
+			// Standard Error: 271_931
+			.saturating_add(Weight::from_parts(1_903_522, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2692).saturating_mul(n.into()))
+	}
+	/// Storage: `Msa::ProviderToDelegatorsMigrationCursor` (r:1 w:1)
+	/// Proof: `Msa::ProviderToDelegatorsMigrationCursor` (`max_values`: Some(1), `max_size`: Some(130), added: 625, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::DelegatorAndProviderToDelegation` (r:1001 w:0)
+	/// Proof: `Msa::DelegatorAndProviderToDelegation` (`max_values`: None, `max_size`: Some(217), added: 2692, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToDelegators` (r:0 w:1000)
+	/// Proof: `Msa::ProviderToDelegators` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 1000]`.
+	fn index_provider_delegators(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `3682 + n * (2692 ±0)`
+		// Minimum execution time: 5_665_000 picoseconds.
+		Weight::from_parts(5_902_000, 3682)
+			// Standard Error: 488_285
+			.saturating_add(Weight::from_parts(3_418_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2692).saturating_mul(n.into()))
+	}
+	/// Storage: `Msa::ProviderRetirements` (r:1 w:1)
+	/// Proof: `Msa::ProviderRetirements` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToDelegators` (r:1001 w:1000)
+	/// Proof: `Msa::ProviderToDelegators` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::DelegatorAndProviderToDelegation` (r:1000 w:1000)
+	/// Proof: `Msa::DelegatorAndProviderToDelegation` (`max_values`: None, `max_size`: Some(217), added: 2692, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderRevocationQueue` (r:0 w:1)
+	/// Proof: `Msa::ProviderRevocationQueue` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::DelegationSchemaPermissions` (r:0 w:1000)
+	/// Proof: `Msa::DelegationSchemaPermissions` (`max_values`: None, `max_size`: Some(183), added: 2658, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 1000]`.
+	fn revoke_retired_provider_delegations(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  ``176 + n * (118 ±0)``
+		//  Estimated: `3497 + n * (2692 ±0)`
+		// Minimum execution time: 9_995_000 picoseconds.
+		Weight::from_parts(10_412_000, 3497)
+			// Standard Error: 1_405_142
+			.saturating_add(Weight::from_parts(9_836_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2692).saturating_mul(n.into()))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyCountForMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyCountForMsaId` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:1 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderRetirements` (r:1 w:0)
+	/// Proof: `Msa::ProviderRetirements` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::CurrentSchemaIdentifierMaximum` (r:1 w:0)
+	/// Proof: `Schemas::CurrentSchemaIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PendingDelegationRequests` (r:1 w:1)
+	/// Proof: `Msa::PendingDelegationRequests` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 30]`.
+	fn request_delegation(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `295`
+		//  Estimated: `4066`
+		// Minimum execution time: 20_972_000 picoseconds.
+		Weight::from_parts(21_846_113, 4066)
+			// Standard Error: 11_887
+			.saturating_add(Weight::from_parts(83_214, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PendingDelegationRequests` (r:1 w:1)
+	/// Proof: `Msa::PendingDelegationRequests` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	fn cancel_delegation_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `224`
+		//  Estimated: `4066`
+		// Minimum execution time: 13_347_000 picoseconds.
+		Weight::from_parts(13_904_000, 4066)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::PayloadSignatureRegistryList` (r:2 w:2)
+	/// Proof: `Msa::PayloadSignatureRegistryList` (`max_values`: Some(50000), `max_size`: Some(144), added: 2124, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PayloadSignatureRegistryPointer` (r:1 w:1)
+	/// Proof: `Msa::PayloadSignatureRegistryPointer` (`max_values`: Some(1), `max_size`: Some(140), added: 635, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyScopes` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyScopes` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PendingDelegationRequests` (r:1 w:1)
+	/// Proof: `Msa::PendingDelegationRequests` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:1 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderRetirements` (r:1 w:0)
+	/// Proof: `Msa::ProviderRetirements` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::DelegatorAndProviderToDelegation` (r:1 w:1)
+	/// Proof: `Msa::DelegatorAndProviderToDelegation` (`max_values`: None, `max_size`: Some(217), added: 2692, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToDelegators` (r:0 w:1)
+	/// Proof: `Msa::ProviderToDelegators` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::CurrentSchemaIdentifierMaximum` (r:1 w:0)
+	/// Proof: `Schemas::CurrentSchemaIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	fn answer_delegation_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1432`
+		//  Estimated: `6531`
+		// Minimum execution time: 94_478_000 picoseconds.
+		Weight::from_parts(98_415_000, 6531)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Msa::PendingDelegationRequests` (r:1 w:1)
+	/// Proof: `Msa::PendingDelegationRequests` (`max_values`: None, `max_size`: Some(154), added: 2629, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PendingDelegationRequestCount` (r:1 w:1)
+	/// Proof: `Msa::PendingDelegationRequestCount` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn remove_expired_delegation_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `371`
+		//  Estimated: `4119`
+		// Minimum execution time: 27_951_000 picoseconds.
+		Weight::from_parts(29_116_000, 4119)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyScopes` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyScopes` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ThresholdPolicies` (r:1 w:1)
+	/// Proof: `Msa::ThresholdPolicies` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyCountForMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyCountForMsaId` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	fn set_threshold_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `176`
+		//  Estimated: `3568`
+		// Minimum execution time: 14_699_000 picoseconds.
+		Weight::from_parts(15_312_000, 3568)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyScopes` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyScopes` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ThresholdPolicies` (r:1 w:0)
+	/// Proof: `Msa::ThresholdPolicies` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::NextThresholdProposalId` (r:1 w:1)
+	/// Proof: `Msa::NextThresholdProposalId` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ThresholdProposals` (r:0 w:1)
+	/// Proof: `Msa::ThresholdProposals` (`max_values`: None, `max_size`: Some(1702), added: 4177, mode: `MaxEncodedLen`)
+	fn propose_threshold_action() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `214`
+		//  Estimated: `3568`
+		// Minimum execution time: 19_079_000 picoseconds.
+		Weight::from_parts(19_874_000, 3568)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:26 w:25)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyScopes` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyScopes` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ThresholdPolicies` (r:1 w:1)
+	/// Proof: `Msa::ThresholdPolicies` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ThresholdProposals` (r:1 w:1)
+	/// Proof: `Msa::ThresholdProposals` (`max_values`: None, `max_size`: Some(1702), added: 4177, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyCountForMsaId` (r:1 w:1)
+	/// Proof: `Msa::PublicKeyCountForMsaId` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:1 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyScopes` (r:0 w:25)
+	/// Proof: `Msa::PublicKeyScopes` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::RetiredMsaPurgeQueue` (r:0 w:1)
+	/// Proof: `Msa::RetiredMsaPurgeQueue` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn approve_threshold_action() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3318`
+		//  Estimated: `66973`
+		// Minimum execution time: 231_967_000 picoseconds.
+		Weight::from_parts(241_633_000, 66973)
+			.saturating_add(RocksDbWeight::get().reads(32_u64))
+			.saturating_add(RocksDbWeight::get().writes(54_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyScopes` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyScopes` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ThresholdProposals` (r:1 w:1)
+	/// Proof: `Msa::ThresholdProposals` (`max_values`: None, `max_size`: Some(1702), added: 4177, mode: `MaxEncodedLen`)
+	fn cancel_threshold_action() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1906`
+		//  Estimated: `5167`
+		// Minimum execution time: 15_865_000 picoseconds.
+		Weight::from_parts(16_527_000, 5167)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:2 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderRetirements` (r:2 w:0)
+	/// Proof: `Msa::ProviderRetirements` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::CurrentSchemaIdentifierMaximum` (r:1 w:0)
+	/// Proof: `Schemas::CurrentSchemaIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::SubDelegations` (r:1 w:1)
+	/// Proof: `Msa::SubDelegations` (`max_values`: None, `max_size`: Some(1185), added: 3660, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 30]`.
+	fn grant_sub_delegation(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `5150`
+		// Minimum execution time: 23_345_000 picoseconds.
+		Weight::from_parts(24_318_000, 5150)
+			// Standard Error: 13_058
+			.saturating_add(Weight::from_parts(91_406, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::SubDelegations` (r:1 w:1)
+	/// Proof: `Msa::SubDelegations` (`max_values`: None, `max_size`: Some(1185), added: 3660, mode: `MaxEncodedLen`)
+	fn revoke_sub_delegation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `301`
+		//  Estimated: `5150`
+		// Minimum execution time: 14_521_000 picoseconds.
+		Weight::from_parts(15_127_000, 5150)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::PayloadSignatureRegistryList` (r:4 w:4)
+	/// Proof: `Msa::PayloadSignatureRegistryList` (`max_values`: Some(50000), `max_size`: Some(144), added: 2124, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PayloadSignatureRegistryPointer` (r:1 w:1)
+	/// Proof: `Msa::PayloadSignatureRegistryPointer` (`max_values`: Some(1), `max_size`: Some(140), added: 635, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyToMsaId` (r:3 w:1)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyScopes` (r:2 w:0)
+	/// Proof: `Msa::PublicKeyScopes` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ThresholdPolicies` (r:2 w:0)
+	/// Proof: `Msa::ThresholdPolicies` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:1 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyCountForMsaId` (r:2 w:2)
+	/// Proof: `Msa::PublicKeyCountForMsaId` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::RecoveryConfigs` (r:0 w:1)
+	/// Proof: `Msa::RecoveryConfigs` (`max_values`: None, `max_size`: Some(131), added: 2606, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ActiveRecoveries` (r:0 w:1)
+	/// Proof: `Msa::ActiveRecoveries` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::MsaRedirects` (r:0 w:1)
+	/// Proof: `Msa::MsaRedirects` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::MsaMergeQueue` (r:0 w:1)
+	/// Proof: `Msa::MsaMergeQueue` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// The range of component `k` is `[1, 25]`.
+	fn merge_msa(k: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1688`
+		//  Estimated: `10074 + k * (2523 ±0)`
+		// Minimum execution time: 139_404_000 picoseconds.
+		Weight::from_parts(145_213_000, 10074)
+			// Standard Error: 588_285
+			.saturating_add(Weight::from_parts(4_118_000, 0).saturating_mul(k.into()))
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(k.into())))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(k.into())))
+			.saturating_add(Weight::from_parts(0, 2523).saturating_mul(k.into()))
+	}
+	/// Storage: `Msa::MsaRedirects` (r:1 w:0)
+	/// Proof: `Msa::MsaRedirects` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::DelegatorAndProviderToDelegation` (r:2 w:1)
+	/// Proof: `Msa::DelegatorAndProviderToDelegation` (`max_values`: None, `max_size`: Some(217), added: 2692, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::DelegationSchemaPermissions` (r:1 w:1)
+	/// Proof: `Msa::DelegationSchemaPermissions` (`max_values`: None, `max_size`: Some(377), added: 2852, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToDelegators` (r:0 w:2)
+	/// Proof: `Msa::ProviderToDelegators` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::MsaMergeQueue` (r:0 w:1)
+	/// Proof: `Msa::MsaMergeQueue` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::RetiredMsaPurgeQueue` (r:0 w:1)
+	/// Proof: `Msa::RetiredMsaPurgeQueue` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 1000]`.
+	fn merge_queued_msa(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `176`
+		//  Estimated: `3842 + n * (2852 ±0)`
+		// Minimum execution time: 12_755_000 picoseconds.
+		Weight::from_parts(13_287_000, 3842)
+			// Standard Error: 514_874
+			.saturating_add(Weight::from_parts(3_604_118, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2852).saturating_mul(n.into()))
+	}
+}
+
+
+#[cfg(test)]
+mod tests {
+  use frame_support::{traits::Get, weights::Weight, dispatch::DispatchClass};
+  use common_runtime::constants::{MAXIMUM_BLOCK_WEIGHT, NORMAL_DISPATCH_RATIO};
+  use common_runtime::weights::extrinsic_weights::ExtrinsicBaseWeight;
+
+  #[allow(dead_code)]
+  struct BlockWeights;
+  impl Get<frame_system::limits::BlockWeights> for BlockWeights {
+  	fn get() -> frame_system::limits::BlockWeights {
+  		frame_system::limits::BlockWeights::builder()
+  			.base_block(Weight::zero())
+  			.for_class(DispatchClass::all(), |weights| {
+  				weights.base_extrinsic = ExtrinsicBaseWeight::get().into();
+  			})
+  			.for_class(DispatchClass::non_mandatory(), |weights| {
+  				weights.max_total = Some(NORMAL_DISPATCH_RATIO * MAXIMUM_BLOCK_WEIGHT);
+  			})
+  			.build_or_panic()
+  	}
+  }
+
+	#[test]
+	fn test_create() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 4008
+		);
+	}
+	#[test]
+	fn test_create_sponsored_account_with_delegation() {
 		assert!(
 			BlockWeights::get()
 				.per_class
@@ -621,4 +1731,340 @@ mod tests {
 				> 4177
 		);
 	}
+	#[test]
+	fn test_set_schema_grant_permissions() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 4177
+		);
+	}
+	#[test]
+	fn test_set_recovery_config() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3598
+		);
+	}
+	#[test]
+	fn test_remove_recovery_config() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3599
+		);
+	}
+	#[test]
+	fn test_initiate_recovery() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3599
+		);
+	}
+	#[test]
+	fn test_approve_recovery() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3599
+		);
+	}
+	#[test]
+	fn test_cancel_recovery() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3598
+		);
+	}
+	#[test]
+	fn test_complete_recovery() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3599
+		);
+	}
+	#[test]
+	fn test_rotate_msa_public_key() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 9981
+		);
+	}
+	#[test]
+	fn test_set_provider_metadata() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 4008
+		);
+	}
+	#[test]
+	fn test_propose_to_change_provider_name() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 4107
+		);
+	}
+	#[test]
+	fn test_change_provider_name_via_governance() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 4008
+		);
+	}
+	#[test]
+	fn test_retire_provider() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 4008
+		);
+	}
+	#[test]
+	fn test_complete_provider_retirement() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3498
+		);
+	}
+	#[test]
+	fn test_purge_retired_msa() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3842
+		);
+	}
+	#[test]
+	fn test_index_provider_delegators() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3682
+		);
+	}
+	#[test]
+	fn test_revoke_retired_provider_delegations() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3497
+		);
+	}
+	#[test]
+	fn test_request_delegation() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 4066
+		);
+	}
+	#[test]
+	fn test_cancel_delegation_request() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 4066
+		);
+	}
+	#[test]
+	fn test_answer_delegation_request() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 6531
+		);
+	}
+	#[test]
+	fn test_set_threshold_policy() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3568
+		);
+	}
+	#[test]
+	fn test_propose_threshold_action() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3568
+		);
+	}
+	#[test]
+	fn test_approve_threshold_action() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 66973
+		);
+	}
+	#[test]
+	fn test_cancel_threshold_action() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 5167
+		);
+	}
+	#[test]
+	fn test_grant_sub_delegation() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 5150
+		);
+	}
+	#[test]
+	fn test_revoke_sub_delegation() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 5150
+		);
+	}
+	#[test]
+	fn test_merge_msa() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 10074
+		);
+	}
+	#[test]
+	fn test_merge_queued_msa() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3842
+		);
+	}
+	#[test]
+	fn test_remove_expired_delegation_request() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 4119
+		);
+	}
 }
@@ -32,6 +32,164 @@ fn generate_schema<T: Config>(
 	json.try_into().unwrap()
 }
 
+/// Generates a record whose fields all have defaults, so that records of any size are fully
+/// compatible with each other
+fn generate_avro_record_schema<T: Config>(
+	size: usize,
+) -> BoundedVec<u8, T::SchemaModelMaxBytesBoundedVecLimit> {
+	let mut json: Vec<u8> = vec![];
+	json.extend(b"{\"type\":\"record\",\"name\":\"benchmark\",\"fields\":[");
+	for i in 0..size {
+		let mut item: Vec<u8> = vec![];
+		item.extend(b"{\"name\":\"field");
+		let mut buff = [0u8; 30];
+		item.extend(i.numtoa(10, &mut buff));
+		item.extend(b"\",\"type\":\"int\",\"default\":0},");
+		if item.len() + json.len() + 2 < size {
+			json.extend(item);
+		} else {
+			break
+		}
+	}
+	if json.last() == Some(&b',') {
+		json.pop(); // removing last ,
+	}
+	json.extend(b"]}");
+	json.try_into().unwrap()
+}
+/// Generates a JSON Schema object with as many properties as fit in `size`
+fn generate_json_schema_model<T: Config>(
+	size: usize,
+) -> BoundedVec<u8, T::SchemaModelMaxBytesBoundedVecLimit> {
+	let mut json: Vec<u8> = vec![];
+	json.extend(b"{\"type\":\"object\",\"properties\":{");
+	for i in 0..size {
+		let mut item: Vec<u8> = vec![];
+		item.extend(b"\"key");
+		let mut buff = [0u8; 30];
+		item.extend(i.numtoa(10, &mut buff));
+		item.extend(b"\":{\"type\":\"string\",\"maxLength\":8},");
+		if item.len() + json.len() + 2 < size {
+			json.extend(item);
+		} else {
+			break
+		}
+	}
+	if json.last() == Some(&b',') {
+		json.pop(); // removing last ,
+	}
+	json.extend(b"}}");
+	json.try_into().unwrap()
+}
+
+/// Appends a length delimited protobuf field
+fn protobuf_bytes_field(encoded: &mut Vec<u8>, number: u8, bytes: &[u8]) {
+	protobuf_varint(encoded, ((number as u64) << 3) | 2);
+	protobuf_varint(encoded, bytes.len() as u64);
+	encoded.extend_from_slice(bytes);
+}
+
+/// Appends a protobuf varint
+fn protobuf_varint(encoded: &mut Vec<u8>, mut value: u64) {
+	while value >= 0x80 {
+		encoded.push((value as u8) | 0x80);
+		value >>= 7;
+	}
+	encoded.push(value as u8);
+}
+
+/// Generates a `FileDescriptorSet` with a message of as many fields as fit in `size`. The fields
+/// have no type, so that their relative type name is looked up in every scope for both messages
+/// and enums, which is the most expensive field to validate.
+fn generate_protobuf_model<T: Config>(
+	size: usize,
+) -> BoundedVec<u8, T::SchemaModelMaxBytesBoundedVecLimit> {
+	let mut enum_value = vec![];
+	protobuf_bytes_field(&mut enum_value, 1, b"V");
+	let mut declared_enum = vec![];
+	protobuf_bytes_field(&mut declared_enum, 1, b"E");
+	protobuf_bytes_field(&mut declared_enum, 2, &enum_value);
+	// the file, its message and the set add at most 24 bytes of names and lengths
+	let mut message = vec![];
+	protobuf_bytes_field(&mut message, 1, b"A");
+	for i in 1..size {
+		let mut field = vec![];
+		let mut name = b"f".to_vec();
+		let mut buff = [0u8; 30];
+		name.extend(i.numtoa(10, &mut buff));
+		protobuf_bytes_field(&mut field, 1, &name);
+		protobuf_varint(&mut field, 3 << 3);
+		protobuf_varint(&mut field, i as u64);
+		protobuf_bytes_field(&mut field, 6, b"E");
+		if message.len() + field.len() + 2 + declared_enum.len() + 24 >= size {
+			break
+		}
+		protobuf_bytes_field(&mut message, 2, &field);
+	}
+	let mut file = vec![];
+	protobuf_bytes_field(&mut file, 1, b"a.proto");
+	protobuf_bytes_field(&mut file, 4, &message);
+	protobuf_bytes_field(&mut file, 5, &declared_enum);
+	let mut set = vec![];
+	protobuf_bytes_field(&mut set, 1, &file);
+	set.try_into().unwrap()
+}
+
+/// Generates a Parquet model with as many columns as fit in `size`
+fn generate_parquet_model<T: Config>(
+	size: usize,
+) -> BoundedVec<u8, T::SchemaModelMaxBytesBoundedVecLimit> {
+	let mut json: Vec<u8> = vec![];
+	json.extend(b"[");
+	for i in 0..size {
+		let mut item: Vec<u8> = vec![];
+		item.extend(b"{\"name\":\"column");
+		let mut buff = [0u8; 30];
+		item.extend(i.numtoa(10, &mut buff));
+		item.extend(
+			b"\",\"column_type\":{\"DECIMAL\":{\"scale\":2,\"precision\":10}},\"compression\":\"GZIP\",\"bloom_filter\":false},",
+		);
+		if item.len() + json.len() + 1 < size {
+			json.extend(item);
+		} else {
+			break
+		}
+	}
+	if json.last() == Some(&b',') {
+		json.pop(); // removing last ,
+	}
+	json.extend(b"]");
+	json.try_into().unwrap()
+}
+
+fn create_provider<T: Config>(key: &T::AccountId, msa_id: MessageSourceId) -> MessageSourceId {
+	assert_ok!(T::MsaBenchmarkHelper::add_key(msa_id, key.clone()));
+	assert_ok!(T::ProviderBenchmarkHelper::create(msa_id, b"provider".to_vec()));
+	T::MsaInfoProvider::ensure_valid_msa_key(key).unwrap()
+}
+
+fn generate_namespace() -> SchemaNamePayload {
+	SchemaNamePayload::try_from(vec![b'a'; NAMESPACE_MAX as usize]).expect("should resolve")
+}
+
+fn generate_schema_name() -> SchemaNamePayload {
+	let namespace = vec![b'a'; NAMESPACE_MAX as usize];
+	let descriptor = vec![b'b'; DESCRIPTOR_MIN as usize];
+	let name: Vec<u8> = namespace.into_iter().chain(vec![b'.']).chain(descriptor).collect();
+	SchemaNamePayload::try_from(name).expect("should resolve")
+}
+
+/// Claims the namespace for a provider other than `delegate_key` and adds the MSA of
+/// `delegate_key` as a delegate, which is the most expensive way to administer a namespace
+fn claim_namespace_with_delegate<T: Config>(delegate_key: &T::AccountId) {
+	let delegate_msa_id = create_provider::<T>(delegate_key, 1);
+	let owner_msa_id = delegate_msa_id + 1;
+	assert_ok!(T::ProviderBenchmarkHelper::create(owner_msa_id, b"owner".to_vec()));
+	let namespace = SchemaName::try_parse_namespace::<T>(generate_namespace()).unwrap();
+	NamespaceOwners::<T>::insert(&namespace, owner_msa_id);
+	NamespaceDelegates::<T>::insert(&namespace, delegate_msa_id, owner_msa_id);
+}
+
 benchmarks! {
 	create_schema {
 		let m in (T::MinSchemaModelSizeBytes::get() + 8) .. (T::SchemaModelMaxBytesBoundedVecLimit::get() - 1);
@@ -152,7 +310,7 @@ benchmarks! {
 		let name:Vec<u8>= namespace.into_iter().chain(vec![b'.'].into_iter()).chain(descriptor.into_iter()).collect();
 		let schema_name = SchemaNamePayload::try_from(name).expect("should resolve");
 		assert_ok!(SchemasPallet::<T>::set_max_schema_model_bytes(RawOrigin::Root.into(), T::SchemaModelMaxBytesBoundedVecLimit::get()));
-		assert_ok!(SchemasPallet::<T>::add_schema(model, ModelType::AvroBinary, PayloadLocation::OnChain, BoundedVec::default(), None));
+		assert_ok!(SchemasPallet::<T>::add_schema(model, ModelType::AvroBinary, PayloadLocation::OnChain, BoundedVec::default(), None, None));
 	}: _(RawOrigin::Signed(sender), schema_id, schema_name)
 	verify {
 		assert_eq!(T::ProposalProvider::proposal_count(), 1);
@@ -166,7 +324,7 @@ benchmarks! {
 		let name:Vec<u8>= namespace.into_iter().chain(vec![b'.'].into_iter()).chain(descriptor.into_iter()).collect();
 		let schema_name = SchemaNamePayload::try_from(name).expect("should resolve");
 		assert_ok!(SchemasPallet::<T>::set_max_schema_model_bytes(RawOrigin::Root.into(), T::SchemaModelMaxBytesBoundedVecLimit::get()));
-		assert_ok!(SchemasPallet::<T>::add_schema(model, ModelType::AvroBinary, PayloadLocation::OnChain, BoundedVec::default(), None));
+		assert_ok!(SchemasPallet::<T>::add_schema(model, ModelType::AvroBinary, PayloadLocation::OnChain, BoundedVec::default(), None, None));
 	}: _(RawOrigin::Root, schema_id, schema_name.clone())
 	verify {
 		let versions = SchemasPallet::<T>::get_schema_versions(schema_name.into_inner());
@@ -174,6 +332,154 @@ benchmarks! {
 		ensure!(versions.unwrap().len() == 1, "Version should be added!");
 	}
 
+	propose_to_set_schema_status {
+		let sender: T::AccountId = whitelisted_caller();
+		let schema_id = 1;
+		let model = generate_schema::<T>(100 as usize);
+		assert_ok!(SchemasPallet::<T>::set_max_schema_model_bytes(RawOrigin::Root.into(), T::SchemaModelMaxBytesBoundedVecLimit::get()));
+		assert_ok!(SchemasPallet::<T>::add_schema(model, ModelType::AvroBinary, PayloadLocation::OnChain, BoundedVec::default(), None, None));
+	}: _(RawOrigin::Signed(sender), schema_id, SchemaStatus::Retired)
+	verify {
+		assert_eq!(T::ProposalProvider::proposal_count(), 1);
+	}
+
+	set_schema_status_via_governance {
+		let schema_id = 1;
+		let model = generate_schema::<T>(100 as usize);
+		assert_ok!(SchemasPallet::<T>::set_max_schema_model_bytes(RawOrigin::Root.into(), T::SchemaModelMaxBytesBoundedVecLimit::get()));
+		assert_ok!(SchemasPallet::<T>::add_schema(model, ModelType::AvroBinary, PayloadLocation::OnChain, BoundedVec::default(), None, None));
+	}: _(RawOrigin::Root, schema_id, SchemaStatus::Retired)
+	verify {
+		ensure!(SchemaStatuses::<T>::get(schema_id) == SchemaStatus::Retired, "Schema should be retired");
+	}
+
+	create_schema_via_governance_v3 {
+		let m in (T::MinSchemaModelSizeBytes::get() + 8) .. (T::SchemaModelMaxBytesBoundedVecLimit::get() - 1);
+		let sender: T::AccountId = whitelisted_caller();
+		let namespace  = vec![b'a'; NAMESPACE_MIN as usize];
+		let descriptor  = vec![b'b'; DESCRIPTOR_MAX as usize];
+		let name:Vec<u8>= namespace.into_iter().chain(vec![b'.'].into_iter()).chain(descriptor.into_iter()).collect();
+		let bounded_name: SchemaNamePayload = BoundedVec::try_from(name).expect("should resolve");
+		let parsed_name = SchemaName::try_parse::<T>(bounded_name.clone(), true).expect("should parse");
+		assert_ok!(SchemasPallet::<T>::set_max_schema_model_bytes(RawOrigin::Root.into(), T::SchemaModelMaxBytesBoundedVecLimit::get()));
+		let schema_input = generate_avro_record_schema::<T>(m as usize);
+		assert_ok!(SchemasPallet::<T>::add_schema(schema_input.clone(), ModelType::AvroBinary, PayloadLocation::OnChain, BoundedVec::default(), Some(parsed_name), None));
+	}: _(RawOrigin::Root, sender.clone(), schema_input, ModelType::AvroBinary, PayloadLocation::OnChain, BoundedVec::default(), Some(bounded_name), Some(SchemaCompatibilityMode::Full))
+	verify {
+		ensure!(SchemaInfos::<T>::get(2).unwrap().compatibility == Some(SchemaCompatibilityMode::Full), "Created schema should record the compatibility");
+	}
+
+	propose_to_claim_namespace {
+		let sender: T::AccountId = whitelisted_caller();
+		create_provider::<T>(&sender, 1);
+	}: _(RawOrigin::Signed(sender), generate_namespace())
+	verify {
+		assert_eq!(T::ProposalProvider::proposal_count(), 1);
+	}
+
+	claim_namespace_via_governance {
+		let owner_msa_id: MessageSourceId = 1;
+		assert_ok!(T::ProviderBenchmarkHelper::create(owner_msa_id, b"owner".to_vec()));
+		let namespace = generate_namespace();
+	}: _(RawOrigin::Root, owner_msa_id, namespace.clone())
+	verify {
+		let namespace = SchemaName::try_parse_namespace::<T>(namespace).unwrap();
+		ensure!(NamespaceOwners::<T>::get(namespace) == Some(owner_msa_id), "Namespace should be claimed");
+	}
+
+	add_namespace_delegate {
+		let sender: T::AccountId = whitelisted_caller();
+		let owner_msa_id = create_provider::<T>(&sender, 1);
+		let delegate_msa_id = owner_msa_id + 1;
+		assert_ok!(T::ProviderBenchmarkHelper::create(delegate_msa_id, b"delegate".to_vec()));
+		let namespace = SchemaName::try_parse_namespace::<T>(generate_namespace()).unwrap();
+		NamespaceOwners::<T>::insert(&namespace, owner_msa_id);
+	}: _(RawOrigin::Signed(sender), generate_namespace(), delegate_msa_id)
+	verify {
+		ensure!(NamespaceDelegates::<T>::contains_key(namespace, delegate_msa_id), "Delegate should be added");
+	}
+
+	remove_namespace_delegate {
+		let sender: T::AccountId = whitelisted_caller();
+		let owner_msa_id = create_provider::<T>(&sender, 1);
+		let delegate_msa_id = owner_msa_id + 1;
+		let namespace = SchemaName::try_parse_namespace::<T>(generate_namespace()).unwrap();
+		NamespaceOwners::<T>::insert(&namespace, owner_msa_id);
+		NamespaceDelegates::<T>::insert(&namespace, delegate_msa_id, owner_msa_id);
+	}: _(RawOrigin::Signed(sender), generate_namespace(), delegate_msa_id)
+	verify {
+		ensure!(!NamespaceDelegates::<T>::contains_key(namespace, delegate_msa_id), "Delegate should be removed");
+	}
+
+	create_schema_in_namespace {
+		let m in (T::MinSchemaModelSizeBytes::get() + 8) .. (T::SchemaModelMaxBytesBoundedVecLimit::get() - 1);
+		let sender: T::AccountId = whitelisted_caller();
+		claim_namespace_with_delegate::<T>(&sender);
+		assert_ok!(SchemasPallet::<T>::set_max_schema_model_bytes(RawOrigin::Root.into(), T::SchemaModelMaxBytesBoundedVecLimit::get()));
+		let schema_input = generate_schema::<T>(m as usize);
+	}: _(RawOrigin::Signed(sender), schema_input, ModelType::AvroBinary, PayloadLocation::OnChain, BoundedVec::default(), generate_schema_name(), None)
+	verify {
+		ensure!(SchemaInfos::<T>::get(1).is_some(), "Created schema should exist");
+	}
+
+	create_schema_name_in_namespace {
+		let sender: T::AccountId = whitelisted_caller();
+		let schema_id = 1;
+		let model = generate_schema::<T>(100 as usize);
+		claim_namespace_with_delegate::<T>(&sender);
+		assert_ok!(SchemasPallet::<T>::set_max_schema_model_bytes(RawOrigin::Root.into(), T::SchemaModelMaxBytesBoundedVecLimit::get()));
+		assert_ok!(SchemasPallet::<T>::add_schema(model, ModelType::AvroBinary, PayloadLocation::OnChain, BoundedVec::default(), None, None));
+	}: _(RawOrigin::Signed(sender), schema_id, generate_schema_name())
+	verify {
+		let versions = SchemasPallet::<T>::get_schema_versions(generate_schema_name().into_inner());
+		ensure!(versions.unwrap().len() == 1, "Version should be added!");
+	}
+
+	release_namespace_via_governance {
+		let owner_msa_id: MessageSourceId = 1;
+		let namespace = SchemaName::try_parse_namespace::<T>(generate_namespace()).unwrap();
+		NamespaceOwners::<T>::insert(&namespace, owner_msa_id);
+		OwnedNamespaces::<T>::insert(owner_msa_id, &namespace, ());
+	}: _(RawOrigin::Root, generate_namespace())
+	verify {
+		ensure!(!NamespaceOwners::<T>::contains_key(&namespace), "Namespace should be released");
+		ensure!(!OwnedNamespaces::<T>::contains_key(owner_msa_id, &namespace), "Namespace should be released");
+	}
+
+	transfer_namespace_via_governance {
+		let previous_owner_msa_id: MessageSourceId = 1;
+		let owner_msa_id: MessageSourceId = 2;
+		assert_ok!(T::ProviderBenchmarkHelper::create(owner_msa_id, b"owner".to_vec()));
+		let namespace = SchemaName::try_parse_namespace::<T>(generate_namespace()).unwrap();
+		NamespaceOwners::<T>::insert(&namespace, previous_owner_msa_id);
+		OwnedNamespaces::<T>::insert(previous_owner_msa_id, &namespace, ());
+	}: _(RawOrigin::Root, generate_namespace(), owner_msa_id)
+	verify {
+		ensure!(NamespaceOwners::<T>::get(&namespace) == Some(owner_msa_id), "Namespace should be transferred");
+		ensure!(OwnedNamespaces::<T>::contains_key(owner_msa_id, &namespace), "Namespace should be transferred");
+	}
+
+	validate_json_schema_model {
+		let m in (T::MinSchemaModelSizeBytes::get() + 8) .. (T::SchemaModelMaxBytesBoundedVecLimit::get() - 1);
+		let model = generate_json_schema_model::<T>(m as usize);
+	}: {
+		assert_ok!(SchemasPallet::<T>::ensure_valid_model(&ModelType::JsonSchema, &model));
+	}
+
+	validate_protobuf_model {
+		let m in (T::MinSchemaModelSizeBytes::get() + 8) .. (T::SchemaModelMaxBytesBoundedVecLimit::get() - 1);
+		let model = generate_protobuf_model::<T>(m as usize);
+	}: {
+		assert_ok!(SchemasPallet::<T>::ensure_valid_model(&ModelType::Protobuf, &model));
+	}
+
+	validate_parquet_model {
+		let m in (T::MinSchemaModelSizeBytes::get() + 8) .. (T::SchemaModelMaxBytesBoundedVecLimit::get() - 1);
+		let model = generate_parquet_model::<T>(m as usize);
+	}: {
+		assert_ok!(SchemasPallet::<T>::ensure_valid_model(&ModelType::Parquet, &model));
+	}
+
 	impl_benchmark_test_suite!(
 		SchemasPallet,
 		crate::tests::mock::new_test_ext(),
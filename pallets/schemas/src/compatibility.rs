@@ -0,0 +1,233 @@
+//! Avro schema resolution checks for new versions of a named schema.
+//!
+//! This follows the schema resolution rules of the Avro specification
+//! (<https://avro.apache.org/docs/current/spec.html#Schema+Resolution>) on the JSON form of the
+//! models, since `apache_avro` is not available in the runtime. Off-chain code can run the same
+//! check with `common_helpers::avro::is_compatible_schema`.
+use common_primitives::schema::SchemaCompatibilityMode;
+use serde_json::{from_slice, Value};
+use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
+
+/// Primitive and complex Avro type names which can not be used as the name of a named type
+const KNOWN_TYPES: [&str; 14] = [
+	"null", "boolean", "int", "long", "float", "double", "bytes", "string", "record", "error",
+	"enum", "array", "map", "fixed",
+];
+
+/// Checks that the `new_model` is compatible with the `previous_model` in the given `mode`.
+/// Models which are not valid JSON are never compatible.
+pub fn is_compatible(
+	previous_model: &[u8],
+	new_model: &[u8],
+	mode: SchemaCompatibilityMode,
+) -> bool {
+	let (Ok(previous), Ok(new)) =
+		(from_slice::<Value>(previous_model), from_slice::<Value>(new_model))
+	else {
+		return false
+	};
+	match mode {
+		SchemaCompatibilityMode::Backward =>
+			Resolver::new(&previous, &new).can_read(&previous, &new),
+		SchemaCompatibilityMode::Forward =>
+			Resolver::new(&new, &previous).can_read(&new, &previous),
+		SchemaCompatibilityMode::Full =>
+			Resolver::new(&previous, &new).can_read(&previous, &new) &&
+				Resolver::new(&new, &previous).can_read(&new, &previous),
+	}
+}
+
+/// Resolves a writer schema against a reader schema
+struct Resolver<'a> {
+	/// Named types of the writer schema
+	writer_names: BTreeMap<&'a str, &'a Value>,
+	/// Named types of the reader schema
+	reader_names: BTreeMap<&'a str, &'a Value>,
+	/// Pairs of named writer and reader types which are already being checked
+	checked: BTreeSet<(&'a str, &'a str)>,
+}
+
+impl<'a> Resolver<'a> {
+	fn new(writer: &'a Value, reader: &'a Value) -> Self {
+		let mut writer_names = BTreeMap::new();
+		let mut reader_names = BTreeMap::new();
+		collect_names(writer, &mut writer_names);
+		collect_names(reader, &mut reader_names);
+		Self { writer_names, reader_names, checked: BTreeSet::new() }
+	}
+
+	/// Returns true if data written with `writer` can be read with `reader`
+	fn can_read(&mut self, writer: &'a Value, reader: &'a Value) -> bool {
+		let writer = resolve(writer, &self.writer_names);
+		let reader = resolve(reader, &self.reader_names);
+
+		// every branch of a writer union must be readable
+		if let Value::Array(branches) = writer {
+			return branches.iter().all(|branch| self.can_read(branch, reader))
+		}
+		// the writer must be readable by some branch of a reader union
+		if let Value::Array(branches) = reader {
+			return branches.iter().any(|branch| self.can_read(writer, branch))
+		}
+
+		let (Some(writer_type), Some(reader_type)) = (type_name(writer), type_name(reader)) else {
+			return false
+		};
+		match writer_type {
+			"record" | "error" =>
+				writer_type == reader_type && self.can_read_record(writer, reader),
+			"enum" =>
+				reader_type == "enum" && same_name(writer, reader) && can_read_enum(writer, reader),
+			"fixed" =>
+				reader_type == "fixed" &&
+					same_name(writer, reader) &&
+					writer.get("size") == reader.get("size"),
+			"array" => match (writer.get("items"), reader.get("items")) {
+				(Some(writer_items), Some(reader_items)) if reader_type == "array" =>
+					self.can_read(writer_items, reader_items),
+				_ => false,
+			},
+			"map" => match (writer.get("values"), reader.get("values")) {
+				(Some(writer_values), Some(reader_values)) if reader_type == "map" =>
+					self.can_read(writer_values, reader_values),
+				_ => false,
+			},
+			_ => can_promote(writer_type, reader_type),
+		}
+	}
+
+	/// Every reader field must either be readable from the writer field of the same name or
+	/// have a default value
+	fn can_read_record(&mut self, writer: &'a Value, reader: &'a Value) -> bool {
+		if !same_name(writer, reader) {
+			return false
+		}
+		let (Some(writer_name), Some(reader_name)) = (name_of(writer), name_of(reader)) else {
+			return false
+		};
+		// recursive records are assumed readable while they are being checked
+		if !self.checked.insert((writer_name, reader_name)) {
+			return true
+		}
+
+		let writer_fields: BTreeMap<&str, &Value> = fields(writer)
+			.filter_map(|field| Some((field.get("name")?.as_str()?, field.get("type")?)))
+			.collect();
+		for reader_field in fields(reader) {
+			let Some(reader_field_type) = reader_field.get("type") else { return false };
+			let writer_field_type =
+				names_with_aliases(reader_field).find_map(|name| writer_fields.get(name).copied());
+			let readable = match writer_field_type {
+				Some(writer_field_type) => self.can_read(writer_field_type, reader_field_type),
+				None => reader_field.get("default").is_some(),
+			};
+			if !readable {
+				return false
+			}
+		}
+		true
+	}
+}
+
+/// Registers the named types (records, enums and fixed) defined in `schema`
+fn collect_names<'a>(schema: &'a Value, names: &mut BTreeMap<&'a str, &'a Value>) {
+	match schema {
+		Value::Array(branches) => branches.iter().for_each(|branch| collect_names(branch, names)),
+		Value::Object(object) => {
+			if let Some(name) = name_of(schema) {
+				names.insert(name, schema);
+			}
+			if let Some(inner @ (Value::Object(_) | Value::Array(_))) = object.get("type") {
+				collect_names(inner, names);
+			}
+			for key in ["items", "values"] {
+				if let Some(inner) = object.get(key) {
+					collect_names(inner, names);
+				}
+			}
+			fields(schema)
+				.filter_map(|field| field.get("type"))
+				.for_each(|field_type| collect_names(field_type, names));
+		},
+		_ => {},
+	}
+}
+
+/// Replaces a reference to a named type with its definition and unwraps nested type definitions
+fn resolve<'a>(schema: &'a Value, names: &BTreeMap<&'a str, &'a Value>) -> &'a Value {
+	match schema {
+		Value::String(name) => names.get(short_name(name)).copied().unwrap_or(schema),
+		Value::Object(object) => match object.get("type") {
+			Some(inner @ (Value::Object(_) | Value::Array(_))) => resolve(inner, names),
+			Some(Value::String(name)) if !is_known_type(name) => resolve(&object["type"], names),
+			_ => schema,
+		},
+		_ => schema,
+	}
+}
+
+fn type_name(schema: &Value) -> Option<&str> {
+	match schema {
+		Value::String(name) => Some(name),
+		Value::Object(object) => object.get("type")?.as_str(),
+		_ => None,
+	}
+}
+
+fn is_known_type(name: &str) -> bool {
+	KNOWN_TYPES.contains(&name)
+}
+
+/// Primitive types which the reader can read from the writer type
+fn can_promote(writer_type: &str, reader_type: &str) -> bool {
+	writer_type == reader_type ||
+		matches!(
+			(writer_type, reader_type),
+			("int", "long" | "float" | "double") |
+				("long", "float" | "double") |
+				("float", "double") |
+				("string", "bytes") |
+				("bytes", "string")
+		)
+}
+
+fn can_read_enum(writer: &Value, reader: &Value) -> bool {
+	reader.get("default").is_some() || symbols(writer).is_subset(&symbols(reader))
+}
+
+fn symbols(schema: &Value) -> BTreeSet<&str> {
+	schema
+		.get("symbols")
+		.and_then(Value::as_array)
+		.into_iter()
+		.flatten()
+		.filter_map(Value::as_str)
+		.collect()
+}
+
+/// Named types match when the reader name or one of its aliases is the writer name
+fn same_name(writer: &Value, reader: &Value) -> bool {
+	match name_of(writer) {
+		Some(writer_name) => names_with_aliases(reader).any(|name| short_name(name) == writer_name),
+		None => false,
+	}
+}
+
+/// The unqualified name of a named type
+fn name_of(schema: &Value) -> Option<&str> {
+	schema.get("name")?.as_str().map(short_name)
+}
+
+fn names_with_aliases(schema: &Value) -> impl Iterator<Item = &str> {
+	let name = schema.get("name").and_then(Value::as_str);
+	let aliases = schema.get("aliases").and_then(Value::as_array).into_iter().flatten();
+	name.into_iter().chain(aliases.filter_map(Value::as_str))
+}
+
+fn fields(schema: &Value) -> impl Iterator<Item = &Value> {
+	schema.get("fields").and_then(Value::as_array).into_iter().flatten()
+}
+
+fn short_name(name: &str) -> &str {
+	name.rsplit('.').next().unwrap_or(name)
+}
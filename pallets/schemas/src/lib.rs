@@ -26,17 +26,22 @@
 )]
 
 use common_primitives::{
+	capacity::TargetValidator,
+	json_schema::validate_json_schema_model,
+	msa::{MessageSourceId, MsaDataMerger, MsaDataPurger, MsaValidator},
 	node::ProposalProvider,
-	parquet::ParquetModel,
+	parquet::validation::parse_parquet_model,
+	protobuf::validate_protobuf_model,
 	schema::{
-		ModelType, PayloadLocation, SchemaId, SchemaProvider, SchemaResponse, SchemaSetting,
-		SchemaSettings, SchemaValidator,
+		ModelType, PayloadLocation, SchemaCompatibilityMode, SchemaId, SchemaProvider,
+		SchemaResponse, SchemaSetting, SchemaSettings, SchemaStatus, SchemaValidator,
 	},
 };
 use frame_support::{
 	dispatch::{DispatchResult, PostDispatchInfo},
 	ensure,
 	traits::{BuildGenesisConfig, Get},
+	weights::Weight,
 };
 use sp_runtime::{traits::Dispatchable, BoundedVec, DispatchError};
 use sp_std::{boxed::Box, vec::Vec};
@@ -47,7 +52,9 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 #[cfg(feature = "runtime-benchmarks")]
-use common_primitives::benchmarks::SchemaBenchmarkHelper;
+use common_primitives::benchmarks::{
+	MsaBenchmarkHelper, RegisterProviderBenchmarkHelper, SchemaBenchmarkHelper,
+};
 use common_primitives::schema::{SchemaInfoResponse, SchemaVersionResponse};
 /// migration module
 pub mod migration;
@@ -58,6 +65,7 @@ pub mod weights;
 pub use types::*;
 pub use weights::*;
 
+mod compatibility;
 mod serde;
 
 const LOG_TARGET: &str = "runtime::schemas";
@@ -102,6 +110,20 @@ pub mod pallet {
 		/// Maximum number of schema settings that can be registered per schema (if any)
 		#[pallet::constant]
 		type MaxSchemaSettingsPerSchema: Get<u32>;
+
+		/// A type that will supply MSA related information
+		type MsaInfoProvider: MsaValidator<AccountId = Self::AccountId>;
+
+		/// A type that checks that an MSA is a registered provider that may own a namespace
+		type ProviderValidator: TargetValidator;
+
+		/// A set of helper functions for benchmarking.
+		#[cfg(feature = "runtime-benchmarks")]
+		type MsaBenchmarkHelper: MsaBenchmarkHelper<Self::AccountId>;
+
+		/// A helper to register providers for benchmarking.
+		#[cfg(feature = "runtime-benchmarks")]
+		type ProviderBenchmarkHelper: RegisterProviderBenchmarkHelper;
 	}
 
 	#[pallet::event]
@@ -129,6 +151,58 @@ pub mod pallet {
 			/// ASCII string in bytes of the assigned name
 			name: Vec<u8>,
 		},
+
+		/// Emitted when the status of a schema is changed
+		SchemaStatusUpdated {
+			/// Schema ID whose status is changed
+			schema_id: SchemaId,
+			/// The new status of the schema
+			status: SchemaStatus,
+		},
+
+		/// Emitted when a namespace is claimed by a provider
+		NamespaceClaimed {
+			/// ASCII string in bytes of the claimed namespace
+			namespace: Vec<u8>,
+			/// MSA Id of the provider owning the namespace
+			owner_msa_id: MessageSourceId,
+		},
+
+		/// Emitted when the owner of a namespace adds a delegate
+		NamespaceDelegateAdded {
+			/// ASCII string in bytes of the namespace
+			namespace: Vec<u8>,
+			/// MSA Id of the provider allowed to administer the namespace
+			delegate_msa_id: MessageSourceId,
+		},
+
+		/// Emitted when the owner of a namespace removes a delegate
+		NamespaceDelegateRemoved {
+			/// ASCII string in bytes of the namespace
+			namespace: Vec<u8>,
+			/// MSA Id of the provider no longer allowed to administer the namespace
+			delegate_msa_id: MessageSourceId,
+		},
+
+		/// Emitted when a namespace is released by governance or because the MSA of its owner
+		/// is retired
+		NamespaceReleased {
+			/// ASCII string in bytes of the released namespace
+			namespace: Vec<u8>,
+			/// MSA Id of the provider that owned the namespace
+			owner_msa_id: MessageSourceId,
+		},
+
+		/// Emitted when a namespace is given to another provider by governance or because the
+		/// MSA of its owner is merged into another MSA
+		NamespaceTransferred {
+			/// ASCII string in bytes of the namespace
+			namespace: Vec<u8>,
+			/// MSA Id of the provider that owned the namespace
+			previous_owner_msa_id: MessageSourceId,
+			/// MSA Id of the provider now owning the namespace
+			owner_msa_id: MessageSourceId,
+		},
 	}
 
 	#[derive(PartialEq, Eq)] // for testing
@@ -178,6 +252,33 @@ pub mod pallet {
 
 		/// SchemaId has a name already
 		SchemaIdAlreadyHasName,
+
+		/// Schema is not compatible with the previous version of the schema name
+		IncompatibleSchemaVersion,
+
+		/// The namespace is owned by a provider, so it can only be changed by its administrators
+		NamespaceClaimed,
+
+		/// The namespace is not owned by any provider
+		NamespaceNotClaimed,
+
+		/// The key is not associated with an MSA
+		InvalidMessageSourceAccount,
+
+		/// The MSA is not a registered provider
+		InvalidNamespaceProvider,
+
+		/// The MSA of the caller is not the owner of the namespace
+		NotNamespaceOwner,
+
+		/// The MSA of the caller is neither the owner nor a delegate of the namespace
+		NotNamespaceAdministrator,
+
+		/// The MSA is already an administrator of the namespace
+		NamespaceDelegateAlreadyExists,
+
+		/// The MSA is not a delegate of the namespace
+		NamespaceDelegateNotFound,
 	}
 
 	#[pallet::pallet]
@@ -230,6 +331,50 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Storage for the status of schemas set by governance
+	/// - Key: Schema Id
+	/// - Value: [`SchemaStatus`](SchemaStatus), [`SchemaStatus::Active`] when not set
+	#[pallet::storage]
+	pub(super) type SchemaStatuses<T: Config> =
+		StorageMap<_, Twox64Concat, SchemaId, SchemaStatus, ValueQuery>;
+
+	/// Storage for the providers owning namespaces
+	/// - Key: Namespace
+	/// - Value: MSA Id of the owner
+	#[pallet::storage]
+	pub(super) type NamespaceOwners<T: Config> =
+		StorageMap<_, Blake2_128Concat, SchemaNamespace, MessageSourceId, OptionQuery>;
+
+	/// Storage for the namespaces owned by each provider, so that they can be released or
+	/// transferred with the MSA of the provider
+	/// - Keys: MSA Id of the owner, Namespace
+	/// - Value: Unit, present when the MSA owns the namespace
+	#[pallet::storage]
+	pub(super) type OwnedNamespaces<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		MessageSourceId,
+		Blake2_128Concat,
+		SchemaNamespace,
+		(),
+		OptionQuery,
+	>;
+
+	/// Storage for the providers the owner of a namespace allows to administer it
+	/// - Keys: Namespace, MSA Id of the delegate
+	/// - Value: MSA Id of the owner that added the delegate. Delegates added by a previous
+	/// owner of the namespace do not administer it.
+	#[pallet::storage]
+	pub(super) type NamespaceDelegates<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		SchemaNamespace,
+		Twox64Concat,
+		MessageSourceId,
+		MessageSourceId,
+		OptionQuery,
+	>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		/// Maximum schema size in bytes at genesis
@@ -258,9 +403,12 @@ pub mod pallet {
 			// Load in the Genesis Schemas
 			for schema in self.initial_schemas.iter() {
 				let model: BoundedVec<u8, T::SchemaModelMaxBytesBoundedVecLimit> =
-					BoundedVec::try_from(schema.model.clone().into_bytes()).expect(
-						"Genesis Schema Model larger than SchemaModelMaxBytesBoundedVecLimit",
-					);
+					BoundedVec::try_from(schema.model_bytes().expect("Bad Genesis Schema Model"))
+						.expect(
+							"Genesis Schema Model larger than SchemaModelMaxBytesBoundedVecLimit",
+						);
+				Pallet::<T>::ensure_valid_model(&schema.model_type, &model)
+					.expect("Invalid Genesis Schema Model");
 				let name_payload: SchemaNamePayload =
 					BoundedVec::try_from(schema.name.clone().into_bytes())
 						.expect("Genesis Schema Name larger than SCHEMA_NAME_BYTES_MAX");
@@ -283,6 +431,7 @@ pub mod pallet {
 					schema.payload_location,
 					settings,
 					parsed_name,
+					None,
 				)
 				.expect("Failed to set Schema in Genesis!");
 			}
@@ -307,7 +456,10 @@ pub mod pallet {
 		/// * [`Error::SchemaCountOverflow`] - The schema count has exceeded its bounds
 		///
 		#[pallet::call_index(0)]
-		#[pallet::weight(T::WeightInfo::create_schema(model.len() as u32))]
+		#[pallet::weight(
+			T::WeightInfo::create_schema(model.len() as u32)
+				.saturating_add(Pallet::<T>::model_validation_weight(model_type, model.len() as u32))
+		)]
 		#[allow(deprecated)]
 		#[deprecated(
 			note = "please use `create_schema_v3` since `create_schema` has been deprecated."
@@ -326,6 +478,7 @@ pub mod pallet {
 				payload_location,
 				BoundedVec::default(),
 				None,
+				None,
 			)?;
 
 			Self::deposit_event(Event::SchemaCreated { key: sender, schema_id });
@@ -402,7 +555,10 @@ pub mod pallet {
 		/// * [`Error::InvalidSchema`] - Schema is malformed in some way
 		/// * [`Error::SchemaCountOverflow`] - The schema count has exceeded its bounds
 		#[pallet::call_index(3)]
-		#[pallet::weight(T::WeightInfo::create_schema_via_governance(model.len() as u32+ settings.len() as u32))]
+		#[pallet::weight(
+			T::WeightInfo::create_schema_via_governance(model.len() as u32 + settings.len() as u32)
+				.saturating_add(Pallet::<T>::model_validation_weight(model_type, model.len() as u32))
+		)]
 		#[allow(deprecated)]
 		#[deprecated(
 			note = "please use `create_schema_via_governance_v2` since `create_schema_via_governance` has been deprecated."
@@ -417,7 +573,7 @@ pub mod pallet {
 		) -> DispatchResult {
 			T::CreateSchemaViaGovernanceOrigin::ensure_origin(origin)?;
 			let (schema_id, _) =
-				Self::create_schema_for(model, model_type, payload_location, settings, None)?;
+				Self::create_schema_for(model, model_type, payload_location, settings, None, None)?;
 
 			Self::deposit_event(Event::SchemaCreated { key: creator_key, schema_id });
 			Ok(())
@@ -440,7 +596,10 @@ pub mod pallet {
 		/// * [`Error::InvalidSetting`] - Invalid setting is provided
 		///
 		#[pallet::call_index(4)]
-		#[pallet::weight(T::WeightInfo::create_schema_v2(model.len() as u32 + settings.len() as u32))]
+		#[pallet::weight(
+			T::WeightInfo::create_schema_v2(model.len() as u32 + settings.len() as u32)
+				.saturating_add(Pallet::<T>::model_validation_weight(model_type, model.len() as u32))
+		)]
 		#[allow(deprecated)]
 		#[deprecated(
 			note = "please use `create_schema_v3` since `create_schema_v2` has been deprecated."
@@ -455,7 +614,7 @@ pub mod pallet {
 			let sender = ensure_signed(origin)?;
 
 			let (schema_id, _) =
-				Self::create_schema_for(model, model_type, payload_location, settings, None)?;
+				Self::create_schema_for(model, model_type, payload_location, settings, None, None)?;
 
 			Self::deposit_event(Event::SchemaCreated { key: sender, schema_id });
 			Ok(())
@@ -463,6 +622,9 @@ pub mod pallet {
 
 		/// Propose to create a schema.  Creates a proposal for council approval to create a schema
 		///
+		/// # Errors
+		/// * [`Error::NamespaceClaimed`] - The namespace of the schema name is owned by a provider
+		///
 		#[pallet::call_index(5)]
 		#[pallet::weight(
 			match schema_name {
@@ -479,6 +641,9 @@ pub mod pallet {
 			schema_name: Option<SchemaNamePayload>,
 		) -> DispatchResult {
 			let proposer = ensure_signed(origin)?;
+			if let Some(name_payload) = &schema_name {
+				Self::ensure_unclaimed_namespace(name_payload)?;
+			}
 
 			let proposal: Box<T::Proposal> = Box::new(
 				(Call::<T>::create_schema_via_governance_v2 {
@@ -513,13 +678,15 @@ pub mod pallet {
 		/// * [`Error::InvalidSchemaNamespaceLength`] - The schema namespace has invalid length
 		/// * [`Error::InvalidSchemaDescriptorLength`] - The schema descriptor has invalid length
 		/// * [`Error::ExceedsMaxNumberOfVersions`] - The schema name reached max number of versions
+		/// * [`Error::NamespaceClaimed`] - The namespace of the schema name was claimed by a provider
 		///
 		#[pallet::call_index(6)]
 		#[pallet::weight(
 			match schema_name {
-				Some(_) => T::WeightInfo::create_schema_via_governance_v2(model.len() as u32+ settings.len() as u32),
+				Some(_) => T::WeightInfo::create_schema_via_governance_v2(model.len() as u32+ settings.len() as u32)
+					.saturating_add(Pallet::<T>::namespace_owner_read_weight()),
 				None => T::WeightInfo::create_schema_via_governance(model.len() as u32+ settings.len() as u32)
-			}
+			}.saturating_add(Pallet::<T>::model_validation_weight(model_type, model.len() as u32))
 		)]
 		pub fn create_schema_via_governance_v2(
 			origin: OriginFor<T>,
@@ -531,12 +698,16 @@ pub mod pallet {
 			schema_name: Option<SchemaNamePayload>,
 		) -> DispatchResult {
 			T::CreateSchemaViaGovernanceOrigin::ensure_origin(origin)?;
+			if let Some(name_payload) = &schema_name {
+				Self::ensure_unclaimed_namespace(name_payload)?;
+			}
 			let (schema_id, schema_name) = Self::create_schema_for(
 				model,
 				model_type,
 				payload_location,
 				settings,
 				schema_name,
+				None,
 			)?;
 
 			Self::deposit_event(Event::SchemaCreated { key: creator_key, schema_id });
@@ -572,13 +743,14 @@ pub mod pallet {
 		/// * [`Error::InvalidSchemaNamespaceLength`] - The schema namespace has invalid length
 		/// * [`Error::InvalidSchemaDescriptorLength`] - The schema descriptor has invalid length
 		/// * [`Error::ExceedsMaxNumberOfVersions`] - The schema name reached max number of versions
+		/// * [`Error::NamespaceClaimed`] - The namespace of the schema name is owned by a provider
 		///
 		#[pallet::call_index(7)]
 		#[pallet::weight(
 			match schema_name {
 				Some(_) => T::WeightInfo::create_schema_v3(model.len() as u32 + settings.len() as u32),
 				None => T::WeightInfo::create_schema_v2(model.len() as u32 + settings.len() as u32)
-			}
+			}.saturating_add(Pallet::<T>::model_validation_weight(model_type, model.len() as u32))
 		)]
 		pub fn create_schema_v3(
 			origin: OriginFor<T>,
@@ -589,6 +761,9 @@ pub mod pallet {
 			schema_name: Option<SchemaNamePayload>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
+			if let Some(name_payload) = &schema_name {
+				Self::ensure_unclaimed_namespace(name_payload)?;
+			}
 
 			let (schema_id, schema_name) = Self::create_schema_for(
 				model,
@@ -596,6 +771,7 @@ pub mod pallet {
 				payload_location,
 				settings,
 				schema_name,
+				None,
 			)?;
 
 			Self::deposit_event(Event::SchemaCreated { key: sender, schema_id });
@@ -621,6 +797,7 @@ pub mod pallet {
 		/// * [`Error::ExceedsMaxNumberOfVersions`] - The schema name reached max number of versions
 		/// * [`Error::SchemaIdDoesNotExist`] - The schema id does not exist
 		/// * [`Error::SchemaIdAlreadyHasName`] - The schema id already has a name
+		/// * [`Error::NamespaceClaimed`] - The namespace is owned by a provider
 		#[pallet::call_index(8)]
 		#[pallet::weight(T::WeightInfo::propose_to_create_schema_name())]
 		pub fn propose_to_create_schema_name(
@@ -630,7 +807,11 @@ pub mod pallet {
 		) -> DispatchResult {
 			let proposer = ensure_signed(origin)?;
 
-			let _ = Self::parse_and_verify_schema_name(schema_id, &schema_name)?;
+			let parsed_name = Self::parse_and_verify_schema_name(schema_id, &schema_name)?;
+			ensure!(
+				!NamespaceOwners::<T>::contains_key(&parsed_name.namespace),
+				Error::<T>::NamespaceClaimed
+			);
 
 			let proposal: Box<T::Proposal> = Box::new(
 				(Call::<T>::create_schema_name_via_governance { schema_id, schema_name }).into(),
@@ -656,36 +837,529 @@ pub mod pallet {
 		/// * [`Error::InvalidSchemaNamespaceLength`] - The schema namespace has invalid length
 		/// * [`Error::InvalidSchemaDescriptorLength`] - The schema descriptor has invalid length
 		/// * [`Error::ExceedsMaxNumberOfVersions`] - The schema name reached max number of versions
+		/// * [`Error::NamespaceClaimed`] - The namespace of the schema name was claimed by a provider
 		/// * [`Error::SchemaIdDoesNotExist`] - The schema id does not exist
 		/// * [`Error::SchemaIdAlreadyHasName`] - The schema id already has a name
 		///
 		#[pallet::call_index(9)]
-		#[pallet::weight(T::WeightInfo::create_schema_name_via_governance())]
+		#[pallet::weight(
+			T::WeightInfo::create_schema_name_via_governance()
+				.saturating_add(Pallet::<T>::namespace_owner_read_weight())
+		)]
 		pub fn create_schema_name_via_governance(
 			origin: OriginFor<T>,
 			schema_id: SchemaId,
 			schema_name: SchemaNamePayload,
 		) -> DispatchResult {
 			T::CreateSchemaViaGovernanceOrigin::ensure_origin(origin)?;
+			Self::ensure_unclaimed_namespace(&schema_name)?;
 
 			let parsed_name = Self::parse_and_verify_schema_name(schema_id, &schema_name)?;
-			SchemaNameToIds::<T>::try_mutate(
-				&parsed_name.namespace,
-				&parsed_name.descriptor,
-				|schema_version_id| -> DispatchResult {
-					schema_version_id.add::<T>(schema_id)?;
+			Self::add_schema_name(schema_id, parsed_name)
+		}
 
-					Self::deposit_event(Event::SchemaNameCreated {
-						schema_id,
-						name: parsed_name.get_combined_name(),
-					});
-					Ok(())
-				},
-			)
+		/// Propose to change the status of a schema. Creates a proposal for council approval to
+		/// change the status of a schema
+		///
+		/// # Errors
+		/// * [`Error::SchemaIdDoesNotExist`] - The schema id does not exist
+		///
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::propose_to_set_schema_status())]
+		pub fn propose_to_set_schema_status(
+			origin: OriginFor<T>,
+			schema_id: SchemaId,
+			status: SchemaStatus,
+		) -> DispatchResult {
+			let proposer = ensure_signed(origin)?;
+			ensure!(SchemaInfos::<T>::contains_key(schema_id), Error::<T>::SchemaIdDoesNotExist);
+
+			let proposal: Box<T::Proposal> = Box::new(
+				(Call::<T>::set_schema_status_via_governance { schema_id, status }).into(),
+			);
+			T::ProposalProvider::propose_with_simple_majority(proposer, proposal)?;
+			Ok(())
+		}
+
+		/// Changes the status of a schema by means of council approval.
+		/// New messages and stateful storage writes are rejected for a [`SchemaStatus::Retired`]
+		/// schema, while a [`SchemaStatus::Deprecated`] schema may still be used.
+		///
+		/// # Events
+		/// * [`Event::SchemaStatusUpdated`]
+		///
+		/// # Errors
+		/// * [`Error::SchemaIdDoesNotExist`] - The schema id does not exist
+		///
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::set_schema_status_via_governance())]
+		pub fn set_schema_status_via_governance(
+			origin: OriginFor<T>,
+			schema_id: SchemaId,
+			status: SchemaStatus,
+		) -> DispatchResult {
+			T::CreateSchemaViaGovernanceOrigin::ensure_origin(origin)?;
+			ensure!(SchemaInfos::<T>::contains_key(schema_id), Error::<T>::SchemaIdDoesNotExist);
+
+			SchemaStatuses::<T>::insert(schema_id, status);
+			Self::deposit_event(Event::SchemaStatusUpdated { schema_id, status });
+			Ok(())
+		}
+
+		/// Propose to create a schema which may declare the compatibility it has with the previous
+		/// version of the schema name. Creates a proposal for council approval to create a schema
+		///
+		/// # Errors
+		/// * [`Error::InvalidSetting`] - A compatibility mode is provided for a non Avro schema
+		/// * [`Error::NamespaceClaimed`] - The namespace of the schema name is owned by a provider
+		///
+		#[pallet::call_index(12)]
+		#[pallet::weight(
+			match schema_name {
+				Some(_) => T::WeightInfo::propose_to_create_schema_v2(model.len() as u32),
+				None => T::WeightInfo::propose_to_create_schema(model.len() as u32)
+			}
+		)]
+		pub fn propose_to_create_schema_v3(
+			origin: OriginFor<T>,
+			model: BoundedVec<u8, T::SchemaModelMaxBytesBoundedVecLimit>,
+			model_type: ModelType,
+			payload_location: PayloadLocation,
+			settings: BoundedVec<SchemaSetting, T::MaxSchemaSettingsPerSchema>,
+			schema_name: Option<SchemaNamePayload>,
+			compatibility: Option<SchemaCompatibilityMode>,
+		) -> DispatchResult {
+			let proposer = ensure_signed(origin)?;
+			ensure!(
+				compatibility.is_none() || model_type == ModelType::AvroBinary,
+				Error::<T>::InvalidSetting
+			);
+			if let Some(name_payload) = &schema_name {
+				Self::ensure_unclaimed_namespace(name_payload)?;
+			}
+
+			let proposal: Box<T::Proposal> = Box::new(
+				(Call::<T>::create_schema_via_governance_v3 {
+					creator_key: proposer.clone(),
+					model,
+					model_type,
+					payload_location,
+					settings,
+					schema_name,
+					compatibility,
+				})
+				.into(),
+			);
+			T::ProposalProvider::propose_with_simple_majority(proposer, proposal)?;
+			Ok(())
+		}
+
+		/// Create a schema by means of council approval. When a compatibility mode is provided
+		/// for a named schema, the schema must be compatible with the previous version of the
+		/// schema name in that mode, and the mode is recorded in the [`SchemaInfo`]. The previous
+		/// version is not known before dispatch, so its model is charged at the maximum size.
+		///
+		/// # Events
+		/// * [`Event::SchemaCreated`]
+		/// * [`Event::SchemaNameCreated`]
+		///
+		/// # Errors
+		/// * [`Error::LessThanMinSchemaModelBytes`] - The schema's length is less than the minimum schema length
+		/// * [`Error::ExceedsMaxSchemaModelBytes`] - The schema's length is greater than the maximum schema length
+		/// * [`Error::InvalidSchema`] - Schema is malformed in some way
+		/// * [`Error::SchemaCountOverflow`] - The schema count has exceeded its bounds
+		/// * [`Error::InvalidSetting`] - Invalid setting is provided
+		/// * [`Error::InvalidSchemaNameEncoding`] - The schema name has invalid encoding
+		/// * [`Error::InvalidSchemaNameCharacters`] - The schema name has invalid characters
+		/// * [`Error::InvalidSchemaNameStructure`] - The schema name has invalid structure
+		/// * [`Error::InvalidSchemaNameLength`] - The schema name has invalid length
+		/// * [`Error::InvalidSchemaNamespaceLength`] - The schema namespace has invalid length
+		/// * [`Error::InvalidSchemaDescriptorLength`] - The schema descriptor has invalid length
+		/// * [`Error::ExceedsMaxNumberOfVersions`] - The schema name reached max number of versions
+		/// * [`Error::NamespaceClaimed`] - The namespace of the schema name was claimed by a provider
+		/// * [`Error::IncompatibleSchemaVersion`] - The schema is not compatible with the previous version
+		///
+		#[pallet::call_index(13)]
+		#[pallet::weight(
+			match (schema_name, compatibility) {
+				(Some(_), Some(_)) => T::WeightInfo::create_schema_via_governance_v3(model.len() as u32 + settings.len() as u32)
+					.saturating_add(Pallet::<T>::schema_compatibility_check_weight())
+					.saturating_add(Pallet::<T>::namespace_owner_read_weight()),
+				(Some(_), None) => T::WeightInfo::create_schema_via_governance_v2(model.len() as u32 + settings.len() as u32)
+					.saturating_add(Pallet::<T>::namespace_owner_read_weight()),
+				(None, _) => T::WeightInfo::create_schema_via_governance(model.len() as u32 + settings.len() as u32)
+			}.saturating_add(Pallet::<T>::model_validation_weight(model_type, model.len() as u32))
+		)]
+		pub fn create_schema_via_governance_v3(
+			origin: OriginFor<T>,
+			creator_key: T::AccountId,
+			model: BoundedVec<u8, T::SchemaModelMaxBytesBoundedVecLimit>,
+			model_type: ModelType,
+			payload_location: PayloadLocation,
+			settings: BoundedVec<SchemaSetting, T::MaxSchemaSettingsPerSchema>,
+			schema_name: Option<SchemaNamePayload>,
+			compatibility: Option<SchemaCompatibilityMode>,
+		) -> DispatchResult {
+			T::CreateSchemaViaGovernanceOrigin::ensure_origin(origin)?;
+			if let Some(name_payload) = &schema_name {
+				Self::ensure_unclaimed_namespace(name_payload)?;
+			}
+			let (schema_id, schema_name) = Self::create_schema_for(
+				model,
+				model_type,
+				payload_location,
+				settings,
+				schema_name,
+				compatibility,
+			)?;
+
+			Self::deposit_event(Event::SchemaCreated { key: creator_key, schema_id });
+			if let Some(inner_name) = schema_name {
+				Self::deposit_event(Event::SchemaNameCreated {
+					schema_id,
+					name: inner_name.get_combined_name(),
+				});
+			}
+			Ok(())
+		}
+
+		/// Propose that a provider owns a namespace. Creates a proposal for council approval to
+		/// give the MSA of the caller the namespace.
+		///
+		/// # Errors
+		/// * [`Error::InvalidMessageSourceAccount`] - The caller does not have an MSA
+		/// * [`Error::InvalidNamespaceProvider`] - The MSA of the caller is not a registered provider
+		/// * [`Error::InvalidSchemaNameStructure`] - The payload is not a namespace
+		/// * [`Error::NamespaceClaimed`] - The namespace is already owned by a provider
+		///
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::propose_to_claim_namespace())]
+		pub fn propose_to_claim_namespace(
+			origin: OriginFor<T>,
+			namespace: SchemaNamePayload,
+		) -> DispatchResult {
+			let proposer = ensure_signed(origin)?;
+			let owner_msa_id = Self::ensure_provider_key(&proposer)?;
+
+			let parsed_namespace = SchemaName::try_parse_namespace::<T>(namespace.clone())?;
+			ensure!(
+				!NamespaceOwners::<T>::contains_key(&parsed_namespace),
+				Error::<T>::NamespaceClaimed
+			);
+
+			let proposal: Box<T::Proposal> = Box::new(
+				(Call::<T>::claim_namespace_via_governance { owner_msa_id, namespace }).into(),
+			);
+			T::ProposalProvider::propose_with_simple_majority(proposer, proposal)?;
+			Ok(())
+		}
+
+		/// Gives a namespace to a provider by means of council approval. The owner and its
+		/// delegates may then create schemas and versions in the namespace without a proposal.
+		///
+		/// # Events
+		/// * [`Event::NamespaceClaimed`]
+		///
+		/// # Errors
+		/// * [`Error::InvalidNamespaceProvider`] - The MSA is not a registered provider
+		/// * [`Error::InvalidSchemaNameStructure`] - The payload is not a namespace
+		/// * [`Error::NamespaceClaimed`] - The namespace is already owned by a provider
+		///
+		#[pallet::call_index(15)]
+		// Indexing the namespace under its owner is not covered by the generated weight
+		#[pallet::weight(
+			T::WeightInfo::claim_namespace_via_governance()
+				.saturating_add(T::DbWeight::get().writes(1))
+		)]
+		pub fn claim_namespace_via_governance(
+			origin: OriginFor<T>,
+			owner_msa_id: MessageSourceId,
+			namespace: SchemaNamePayload,
+		) -> DispatchResult {
+			T::CreateSchemaViaGovernanceOrigin::ensure_origin(origin)?;
+			ensure!(
+				T::ProviderValidator::validate(owner_msa_id),
+				Error::<T>::InvalidNamespaceProvider
+			);
+
+			let parsed_namespace = SchemaName::try_parse_namespace::<T>(namespace)?;
+			NamespaceOwners::<T>::try_mutate(&parsed_namespace, |owner| -> DispatchResult {
+				ensure!(owner.is_none(), Error::<T>::NamespaceClaimed);
+				*owner = Some(owner_msa_id);
+				Ok(())
+			})?;
+			OwnedNamespaces::<T>::insert(owner_msa_id, &parsed_namespace, ());
+
+			Self::deposit_event(Event::NamespaceClaimed {
+				namespace: parsed_namespace.into_inner(),
+				owner_msa_id,
+			});
+			Ok(())
+		}
+
+		/// Allows another provider to administer a namespace owned by the MSA of the caller
+		///
+		/// # Events
+		/// * [`Event::NamespaceDelegateAdded`]
+		///
+		/// # Errors
+		/// * [`Error::InvalidMessageSourceAccount`] - The caller does not have an MSA
+		/// * [`Error::InvalidNamespaceProvider`] - The caller or the delegate is not a registered provider
+		/// * [`Error::NamespaceNotClaimed`] - The namespace is not owned by any provider
+		/// * [`Error::NotNamespaceOwner`] - The MSA of the caller does not own the namespace
+		/// * [`Error::NamespaceDelegateAlreadyExists`] - The delegate already administers the namespace
+		///
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::add_namespace_delegate())]
+		pub fn add_namespace_delegate(
+			origin: OriginFor<T>,
+			namespace: SchemaNamePayload,
+			delegate_msa_id: MessageSourceId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let parsed_namespace = SchemaName::try_parse_namespace::<T>(namespace)?;
+			let owner_msa_id = Self::ensure_namespace_owner(&sender, &parsed_namespace)?;
+			ensure!(
+				T::ProviderValidator::validate(delegate_msa_id),
+				Error::<T>::InvalidNamespaceProvider
+			);
+			ensure!(
+				delegate_msa_id != owner_msa_id &&
+					NamespaceDelegates::<T>::get(&parsed_namespace, delegate_msa_id) !=
+						Some(owner_msa_id),
+				Error::<T>::NamespaceDelegateAlreadyExists
+			);
+
+			NamespaceDelegates::<T>::insert(&parsed_namespace, delegate_msa_id, owner_msa_id);
+			Self::deposit_event(Event::NamespaceDelegateAdded {
+				namespace: parsed_namespace.into_inner(),
+				delegate_msa_id,
+			});
+			Ok(())
+		}
+
+		/// Stops a provider from administering a namespace owned by the MSA of the caller
+		///
+		/// # Events
+		/// * [`Event::NamespaceDelegateRemoved`]
+		///
+		/// # Errors
+		/// * [`Error::InvalidMessageSourceAccount`] - The caller does not have an MSA
+		/// * [`Error::InvalidNamespaceProvider`] - The caller is not a registered provider
+		/// * [`Error::NamespaceNotClaimed`] - The namespace is not owned by any provider
+		/// * [`Error::NotNamespaceOwner`] - The MSA of the caller does not own the namespace
+		/// * [`Error::NamespaceDelegateNotFound`] - The MSA is not a delegate of the namespace
+		///
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::remove_namespace_delegate())]
+		pub fn remove_namespace_delegate(
+			origin: OriginFor<T>,
+			namespace: SchemaNamePayload,
+			delegate_msa_id: MessageSourceId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let parsed_namespace = SchemaName::try_parse_namespace::<T>(namespace)?;
+			Self::ensure_namespace_owner(&sender, &parsed_namespace)?;
+			ensure!(
+				NamespaceDelegates::<T>::take(&parsed_namespace, delegate_msa_id).is_some(),
+				Error::<T>::NamespaceDelegateNotFound
+			);
+
+			Self::deposit_event(Event::NamespaceDelegateRemoved {
+				namespace: parsed_namespace.into_inner(),
+				delegate_msa_id,
+			});
+			Ok(())
+		}
+
+		/// Creates a named schema in a namespace administered by the MSA of the caller, without
+		/// council approval. When a compatibility mode is provided, the schema must be compatible
+		/// with the previous version of the schema name in that mode.
+		///
+		/// # Events
+		/// * [`Event::SchemaCreated`]
+		/// * [`Event::SchemaNameCreated`]
+		///
+		/// # Errors
+		/// * [`Error::LessThanMinSchemaModelBytes`] - The schema's length is less than the minimum schema length
+		/// * [`Error::ExceedsMaxSchemaModelBytes`] - The schema's length is greater than the maximum schema length
+		/// * [`Error::InvalidSchema`] - Schema is malformed in some way
+		/// * [`Error::SchemaCountOverflow`] - The schema count has exceeded its bounds
+		/// * [`Error::InvalidSetting`] - Invalid setting is provided
+		/// * [`Error::InvalidSchemaNameEncoding`] - The schema name has invalid encoding
+		/// * [`Error::InvalidSchemaNameCharacters`] - The schema name has invalid characters
+		/// * [`Error::InvalidSchemaNameStructure`] - The schema name has invalid structure
+		/// * [`Error::InvalidSchemaNameLength`] - The schema name has invalid length
+		/// * [`Error::InvalidSchemaNamespaceLength`] - The schema namespace has invalid length
+		/// * [`Error::InvalidSchemaDescriptorLength`] - The schema descriptor has invalid length
+		/// * [`Error::ExceedsMaxNumberOfVersions`] - The schema name reached max number of versions
+		/// * [`Error::IncompatibleSchemaVersion`] - The schema is not compatible with the previous version
+		/// * [`Error::InvalidMessageSourceAccount`] - The caller does not have an MSA
+		/// * [`Error::InvalidNamespaceProvider`] - The caller is not a registered provider
+		/// * [`Error::NamespaceNotClaimed`] - The namespace is not owned by any provider
+		/// * [`Error::NotNamespaceAdministrator`] - The MSA of the caller does not administer the namespace
+		///
+		#[pallet::call_index(18)]
+		#[pallet::weight(
+			T::WeightInfo::create_schema_in_namespace(model.len() as u32 + settings.len() as u32)
+				.saturating_add(Pallet::<T>::model_validation_weight(
+					model_type,
+					model.len() as u32,
+				))
+				.saturating_add(match compatibility {
+					Some(_) => Pallet::<T>::previous_schema_version_weight(),
+					None => Weight::zero(),
+				})
+		)]
+		pub fn create_schema_in_namespace(
+			origin: OriginFor<T>,
+			model: BoundedVec<u8, T::SchemaModelMaxBytesBoundedVecLimit>,
+			model_type: ModelType,
+			payload_location: PayloadLocation,
+			settings: BoundedVec<SchemaSetting, T::MaxSchemaSettingsPerSchema>,
+			schema_name: SchemaNamePayload,
+			compatibility: Option<SchemaCompatibilityMode>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let parsed_name = SchemaName::try_parse::<T>(schema_name.clone(), true)?;
+			Self::ensure_namespace_administrator(&sender, &parsed_name.namespace)?;
+
+			let (schema_id, _) = Self::create_schema_for(
+				model,
+				model_type,
+				payload_location,
+				settings,
+				Some(schema_name),
+				compatibility,
+			)?;
+
+			Self::deposit_event(Event::SchemaCreated { key: sender, schema_id });
+			Self::deposit_event(Event::SchemaNameCreated {
+				schema_id,
+				name: parsed_name.get_combined_name(),
+			});
+			Ok(())
+		}
+
+		/// Assigns a name in a namespace administered by the MSA of the caller to a schema
+		/// without any name, without council approval.
+		///
+		/// # Events
+		/// * [`Event::SchemaNameCreated`]
+		///
+		/// # Errors
+		/// * [`Error::InvalidSchemaNameEncoding`] - The schema name has invalid encoding
+		/// * [`Error::InvalidSchemaNameCharacters`] - The schema name has invalid characters
+		/// * [`Error::InvalidSchemaNameStructure`] - The schema name has invalid structure
+		/// * [`Error::InvalidSchemaNameLength`] - The schema name has invalid length
+		/// * [`Error::InvalidSchemaNamespaceLength`] - The schema namespace has invalid length
+		/// * [`Error::InvalidSchemaDescriptorLength`] - The schema descriptor has invalid length
+		/// * [`Error::ExceedsMaxNumberOfVersions`] - The schema name reached max number of versions
+		/// * [`Error::SchemaIdDoesNotExist`] - The schema id does not exist
+		/// * [`Error::SchemaIdAlreadyHasName`] - The schema id already has a name
+		/// * [`Error::InvalidMessageSourceAccount`] - The caller does not have an MSA
+		/// * [`Error::InvalidNamespaceProvider`] - The caller is not a registered provider
+		/// * [`Error::NamespaceNotClaimed`] - The namespace is not owned by any provider
+		/// * [`Error::NotNamespaceAdministrator`] - The MSA of the caller does not administer the namespace
+		///
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::create_schema_name_in_namespace())]
+		pub fn create_schema_name_in_namespace(
+			origin: OriginFor<T>,
+			schema_id: SchemaId,
+			schema_name: SchemaNamePayload,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let parsed_name = Self::parse_and_verify_schema_name(schema_id, &schema_name)?;
+			Self::ensure_namespace_administrator(&sender, &parsed_name.namespace)?;
+
+			Self::add_schema_name(schema_id, parsed_name)
+		}
+
+		/// Takes a namespace from its owner by means of council approval, so that it can be
+		/// claimed again. The delegates of the owner no longer administer the namespace.
+		///
+		/// # Events
+		/// * [`Event::NamespaceReleased`]
+		///
+		/// # Errors
+		/// * [`Error::InvalidSchemaNameStructure`] - The payload is not a namespace
+		/// * [`Error::NamespaceNotClaimed`] - The namespace is not owned by any provider
+		///
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::release_namespace_via_governance())]
+		pub fn release_namespace_via_governance(
+			origin: OriginFor<T>,
+			namespace: SchemaNamePayload,
+		) -> DispatchResult {
+			T::CreateSchemaViaGovernanceOrigin::ensure_origin(origin)?;
+			let parsed_namespace = SchemaName::try_parse_namespace::<T>(namespace)?;
+			let owner_msa_id = NamespaceOwners::<T>::get(&parsed_namespace)
+				.ok_or(Error::<T>::NamespaceNotClaimed)?;
+
+			Self::release_namespace(parsed_namespace, owner_msa_id);
+			Ok(())
+		}
+
+		/// Gives a claimed namespace to another provider by means of council approval. The
+		/// delegates of the previous owner no longer administer the namespace.
+		///
+		/// # Events
+		/// * [`Event::NamespaceTransferred`]
+		///
+		/// # Errors
+		/// * [`Error::InvalidNamespaceProvider`] - The MSA is not a registered provider
+		/// * [`Error::InvalidSchemaNameStructure`] - The payload is not a namespace
+		/// * [`Error::NamespaceNotClaimed`] - The namespace is not owned by any provider
+		/// * [`Error::NamespaceClaimed`] - The namespace is already owned by the provider
+		///
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::transfer_namespace_via_governance())]
+		pub fn transfer_namespace_via_governance(
+			origin: OriginFor<T>,
+			namespace: SchemaNamePayload,
+			owner_msa_id: MessageSourceId,
+		) -> DispatchResult {
+			T::CreateSchemaViaGovernanceOrigin::ensure_origin(origin)?;
+			ensure!(
+				T::ProviderValidator::validate(owner_msa_id),
+				Error::<T>::InvalidNamespaceProvider
+			);
+			let parsed_namespace = SchemaName::try_parse_namespace::<T>(namespace)?;
+			let previous_owner_msa_id = NamespaceOwners::<T>::get(&parsed_namespace)
+				.ok_or(Error::<T>::NamespaceNotClaimed)?;
+			ensure!(previous_owner_msa_id != owner_msa_id, Error::<T>::NamespaceClaimed);
+
+			Self::transfer_namespace(parsed_namespace, previous_owner_msa_id, owner_msa_id);
+			Ok(())
 		}
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// The weight of checking a new schema version against the previous version, which the
+		/// generated weights do not cover. The previous version is not known before dispatch, so
+		/// its model is charged at the maximum size.
+		fn schema_compatibility_check_weight() -> Weight {
+			Weight::from_parts(40_118, 0)
+				.saturating_mul(T::SchemaModelMaxBytesBoundedVecLimit::get().into())
+		}
+
+		/// The weight of reading the previous version of a schema name and checking a new version
+		/// against it, which the generated weight of
+		/// [`create_schema_in_namespace`](Pallet::create_schema_in_namespace) does not cover
+		fn previous_schema_version_weight() -> Weight {
+			T::DbWeight::get()
+				.reads(2)
+				.saturating_add(Weight::from_parts(5_700_000, 2_492 + 67_989))
+				.saturating_add(Self::schema_compatibility_check_weight())
+		}
+
+		/// The weight of checking whether the namespace of a schema name is owned, which the
+		/// generated weights of the governance calls do not cover
+		fn namespace_owner_read_weight() -> Weight {
+			T::DbWeight::get().reads(1).saturating_add(Weight::from_parts(0, 2_530))
+		}
+
 		/// Set the schema count to something in particular.
 		#[cfg(any(feature = "std", feature = "runtime-benchmarks", test))]
 		pub fn set_schema_count(n: SchemaId) {
@@ -700,6 +1374,7 @@ pub mod pallet {
 			payload_location: PayloadLocation,
 			settings: BoundedVec<SchemaSetting, T::MaxSchemaSettingsPerSchema>,
 			schema_name_option: Option<SchemaName>,
+			compatibility: Option<SchemaCompatibilityMode>,
 		) -> Result<SchemaId, DispatchError> {
 			let schema_id = Self::get_next_schema_id()?;
 			let has_name = schema_name_option.is_some();
@@ -721,8 +1396,13 @@ pub mod pallet {
 				)?;
 			};
 
-			let schema_info =
-				SchemaInfo { model_type, payload_location, settings: set_settings, has_name };
+			let schema_info = SchemaInfo {
+				model_type,
+				payload_location,
+				settings: set_settings,
+				has_name,
+				compatibility,
+			};
 			<CurrentSchemaIdentifierMaximum<T>>::set(schema_id);
 			<SchemaInfos<T>>::insert(schema_id, schema_info);
 			<SchemaPayloads<T>>::insert(schema_id, model);
@@ -743,6 +1423,7 @@ pub mod pallet {
 						model_type: schema_info.model_type,
 						payload_location: schema_info.payload_location,
 						settings,
+						status: SchemaStatuses::<T>::get(schema_id),
 					};
 					Some(response)
 				},
@@ -764,13 +1445,25 @@ pub mod pallet {
 					model_type: schema_info.model_type,
 					payload_location: schema_info.payload_location,
 					settings,
+					status: SchemaStatuses::<T>::get(schema_id),
 				};
 				return Some(response);
 			}
 			None
 		}
 
-		/// Ensures that a given u8 Vector conforms to a recognized Parquet shape
+		/// The weight of validating a model of the given type and length on top of the
+		/// `create_schema*` weights, which are benchmarked with Avro models
+		pub fn model_validation_weight(model_type: &ModelType, model_len: u32) -> Weight {
+			match model_type {
+				ModelType::AvroBinary => Weight::zero(),
+				ModelType::JsonSchema => T::WeightInfo::validate_json_schema_model(model_len),
+				ModelType::Protobuf => T::WeightInfo::validate_protobuf_model(model_len),
+				ModelType::Parquet => T::WeightInfo::validate_parquet_model(model_len),
+			}
+		}
+
+		/// Ensures that a given u8 Vector is a valid model of the given model type
 		///
 		/// # Errors
 		/// * [`Error::InvalidSchema`]
@@ -782,11 +1475,14 @@ pub mod pallet {
 		) -> DispatchResult {
 			match model_type {
 				&ModelType::Parquet => {
-					serde_json::from_slice::<ParquetModel>(model)
-						.map_err(|_| Error::<T>::InvalidSchema)?;
+					parse_parquet_model(model).map_err(|_| Error::<T>::InvalidSchema)?;
 				},
 				&ModelType::AvroBinary => serde::validate_json_model(model.clone().into_inner())
 					.map_err(|_| Error::<T>::InvalidSchema)?,
+				&ModelType::JsonSchema =>
+					validate_json_schema_model(model).map_err(|_| Error::<T>::InvalidSchema)?,
+				&ModelType::Protobuf =>
+					validate_protobuf_model(model).map_err(|_| Error::<T>::InvalidSchema)?,
 			};
 			Ok(())
 		}
@@ -816,12 +1512,14 @@ pub mod pallet {
 		/// * [`Error::InvalidSchema`] - Schema is malformed in some way
 		/// * [`Error::SchemaCountOverflow`] - The schema count has exceeded its bounds
 		/// * [`Error::InvalidSetting`] - Invalid setting is provided
+		/// * [`Error::IncompatibleSchemaVersion`] - The schema is not compatible with the previous version
 		pub fn create_schema_for(
 			model: BoundedVec<u8, T::SchemaModelMaxBytesBoundedVecLimit>,
 			model_type: ModelType,
 			payload_location: PayloadLocation,
 			settings: BoundedVec<SchemaSetting, T::MaxSchemaSettingsPerSchema>,
 			optional_schema_name: Option<SchemaNamePayload>,
+			compatibility: Option<SchemaCompatibilityMode>,
 		) -> Result<(SchemaId, Option<SchemaName>), DispatchError> {
 			Self::ensure_valid_model(&model_type, &model)?;
 			ensure!(
@@ -838,6 +1536,11 @@ pub mod pallet {
 					payload_location == PayloadLocation::Itemized,
 				Error::<T>::InvalidSetting
 			);
+			// compatibility can only be checked between Avro models
+			ensure!(
+				compatibility.is_none() || model_type == ModelType::AvroBinary,
+				Error::<T>::InvalidSetting
+			);
 			let schema_name = match optional_schema_name {
 				None => None,
 				Some(name_payload) => {
@@ -845,12 +1548,16 @@ pub mod pallet {
 					Some(parsed_name)
 				},
 			};
+			if let (Some(mode), Some(parsed_name)) = (compatibility, &schema_name) {
+				Self::ensure_compatible_with_previous_version(parsed_name, &model, mode)?;
+			}
 			let schema_id = Self::add_schema(
 				model,
 				model_type,
 				payload_location,
 				settings,
 				schema_name.clone(),
+				compatibility,
 			)?;
 			Ok((schema_id, schema_name))
 		}
@@ -862,16 +1569,150 @@ pub mod pallet {
 			let parsed_name = SchemaName::try_parse::<T>(bounded_name, false).ok()?;
 			let versions: Vec<_> = match parsed_name.descriptor_exists() {
 				true => SchemaNameToIds::<T>::get(&parsed_name.namespace, &parsed_name.descriptor)
-					.convert_to_response(&parsed_name),
+					.convert_to_response::<T>(&parsed_name),
 				false => SchemaNameToIds::<T>::iter_prefix(&parsed_name.namespace)
 					.flat_map(|(descriptor, val)| {
-						val.convert_to_response(&parsed_name.new_with_descriptor(descriptor))
+						val.convert_to_response::<T>(&parsed_name.new_with_descriptor(descriptor))
 					})
 					.collect(),
 			};
 			Some(versions)
 		}
 
+		/// Ensures that a new version of a schema name is compatible with its latest version in the
+		/// given mode. The first version of a schema name has nothing to be compatible with.
+		///
+		/// # Errors
+		/// * [`Error::IncompatibleSchemaVersion`] - The schema is not compatible with the previous version
+		fn ensure_compatible_with_previous_version(
+			schema_name: &SchemaName,
+			model: &BoundedVec<u8, T::SchemaModelMaxBytesBoundedVecLimit>,
+			mode: SchemaCompatibilityMode,
+		) -> DispatchResult {
+			let versions =
+				SchemaNameToIds::<T>::get(&schema_name.namespace, &schema_name.descriptor);
+			if let Some(previous_schema_id) = versions.ids.last() {
+				let previous_model = match (
+					SchemaInfos::<T>::get(previous_schema_id),
+					SchemaPayloads::<T>::get(previous_schema_id),
+				) {
+					(Some(info), Some(payload)) if info.model_type == ModelType::AvroBinary =>
+						payload,
+					_ => return Err(Error::<T>::IncompatibleSchemaVersion.into()),
+				};
+				ensure!(
+					compatibility::is_compatible(&previous_model, model, mode),
+					Error::<T>::IncompatibleSchemaVersion
+				);
+			}
+			Ok(())
+		}
+
+		/// Adds a name to a schema without any name
+		fn add_schema_name(schema_id: SchemaId, parsed_name: SchemaName) -> DispatchResult {
+			SchemaNameToIds::<T>::try_mutate(
+				&parsed_name.namespace,
+				&parsed_name.descriptor,
+				|schema_version_id| -> DispatchResult {
+					schema_version_id.add::<T>(schema_id)?;
+
+					Self::deposit_event(Event::SchemaNameCreated {
+						schema_id,
+						name: parsed_name.get_combined_name(),
+					});
+					Ok(())
+				},
+			)
+		}
+
+		/// Ensures that the namespace of a schema name is not owned by a provider
+		///
+		/// # Errors
+		/// * [`Error::NamespaceClaimed`] - The namespace is owned by a provider
+		fn ensure_unclaimed_namespace(schema_name: &SchemaNamePayload) -> DispatchResult {
+			let parsed_name = SchemaName::try_parse::<T>(schema_name.clone(), true)?;
+			ensure!(
+				!NamespaceOwners::<T>::contains_key(&parsed_name.namespace),
+				Error::<T>::NamespaceClaimed
+			);
+			Ok(())
+		}
+
+		/// Removes the owner of a namespace
+		fn release_namespace(namespace: SchemaNamespace, owner_msa_id: MessageSourceId) {
+			NamespaceOwners::<T>::remove(&namespace);
+			OwnedNamespaces::<T>::remove(owner_msa_id, &namespace);
+			Self::deposit_event(Event::NamespaceReleased {
+				namespace: namespace.into_inner(),
+				owner_msa_id,
+			});
+		}
+
+		/// Gives a namespace to another owner
+		fn transfer_namespace(
+			namespace: SchemaNamespace,
+			previous_owner_msa_id: MessageSourceId,
+			owner_msa_id: MessageSourceId,
+		) {
+			NamespaceOwners::<T>::insert(&namespace, owner_msa_id);
+			OwnedNamespaces::<T>::remove(previous_owner_msa_id, &namespace);
+			OwnedNamespaces::<T>::insert(owner_msa_id, &namespace, ());
+			Self::deposit_event(Event::NamespaceTransferred {
+				namespace: namespace.into_inner(),
+				previous_owner_msa_id,
+				owner_msa_id,
+			});
+		}
+
+		/// Returns the MSA of a key that may manage it, when that MSA is a registered provider
+		///
+		/// # Errors
+		/// * [`Error::InvalidMessageSourceAccount`] - The key does not have an MSA
+		/// * [`Error::InvalidNamespaceProvider`] - The MSA is not a registered provider
+		fn ensure_provider_key(key: &T::AccountId) -> Result<MessageSourceId, DispatchError> {
+			let msa_id = T::MsaInfoProvider::ensure_valid_msa_key(key)
+				.map_err(|_| Error::<T>::InvalidMessageSourceAccount)?;
+			T::MsaInfoProvider::ensure_key_can_manage_msa(key)?;
+			ensure!(T::ProviderValidator::validate(msa_id), Error::<T>::InvalidNamespaceProvider);
+			Ok(msa_id)
+		}
+
+		/// Returns the MSA of the key when it owns the namespace
+		///
+		/// # Errors
+		/// * [`Error::NamespaceNotClaimed`] - The namespace is not owned by any provider
+		/// * [`Error::NotNamespaceOwner`] - The MSA of the key does not own the namespace
+		fn ensure_namespace_owner(
+			key: &T::AccountId,
+			namespace: &SchemaNamespace,
+		) -> Result<MessageSourceId, DispatchError> {
+			let msa_id = Self::ensure_provider_key(key)?;
+			let owner_msa_id =
+				NamespaceOwners::<T>::get(namespace).ok_or(Error::<T>::NamespaceNotClaimed)?;
+			ensure!(owner_msa_id == msa_id, Error::<T>::NotNamespaceOwner);
+			Ok(msa_id)
+		}
+
+		/// Returns the MSA of the key when it owns the namespace or is one of its delegates
+		///
+		/// # Errors
+		/// * [`Error::NamespaceNotClaimed`] - The namespace is not owned by any provider
+		/// * [`Error::NotNamespaceAdministrator`] - The MSA of the key does not administer the namespace
+		fn ensure_namespace_administrator(
+			key: &T::AccountId,
+			namespace: &SchemaNamespace,
+		) -> Result<MessageSourceId, DispatchError> {
+			let msa_id = Self::ensure_provider_key(key)?;
+			let owner_msa_id =
+				NamespaceOwners::<T>::get(namespace).ok_or(Error::<T>::NamespaceNotClaimed)?;
+			ensure!(
+				owner_msa_id == msa_id ||
+					NamespaceDelegates::<T>::get(namespace, msa_id) == Some(owner_msa_id),
+				Error::<T>::NotNamespaceAdministrator
+			);
+			Ok(msa_id)
+		}
+
 		/// Parses the schema name and makes sure the schema does not have a name
 		fn parse_and_verify_schema_name(
 			schema_id: SchemaId,
@@ -905,11 +1746,55 @@ impl<T: Config> SchemaBenchmarkHelper for Pallet<T> {
 		let model: BoundedVec<u8, T::SchemaModelMaxBytesBoundedVecLimit> =
 			model.try_into().unwrap();
 		Self::ensure_valid_model(&model_type, &model)?;
-		Self::add_schema(model, model_type, payload_location, BoundedVec::default(), None)?;
+		Self::add_schema(model, model_type, payload_location, BoundedVec::default(), None, None)?;
 		Ok(())
 	}
 }
 
+impl<T: Config> MsaDataPurger for Pallet<T> {
+	/// Releases the namespaces owned by `msa_id`, as many as fit in `remaining_weight`
+	fn purge_msa_data(msa_id: MessageSourceId, remaining_weight: Weight) -> (Weight, bool) {
+		let read_weight = T::DbWeight::get().reads(1);
+		let release_weight = T::WeightInfo::release_namespace_via_governance();
+		let mut used_weight = Weight::zero();
+		loop {
+			if remaining_weight.any_lt(used_weight.saturating_add(read_weight)) {
+				return (used_weight, false)
+			}
+			let Some(namespace) = OwnedNamespaces::<T>::iter_key_prefix(msa_id).next() else {
+				return (used_weight.saturating_add(read_weight), true)
+			};
+			if remaining_weight.any_lt(used_weight.saturating_add(release_weight)) {
+				return (used_weight.saturating_add(read_weight), false)
+			}
+			Self::release_namespace(namespace, msa_id);
+			used_weight = used_weight.saturating_add(release_weight);
+		}
+	}
+}
+
+impl<T: Config> MsaDataMerger for Pallet<T> {
+	/// Gives the namespaces owned by `source_msa_id` to `target_msa_id`. The delegates added by
+	/// the source MSA no longer administer them.
+	fn merge_msa_data(
+		source_msa_id: MessageSourceId,
+		target_msa_id: MessageSourceId,
+		limit: u32,
+	) -> (u32, bool) {
+		let mut namespaces: Vec<SchemaNamespace> =
+			OwnedNamespaces::<T>::iter_key_prefix(source_msa_id)
+				.take(limit.saturating_add(1) as usize)
+				.collect();
+		let complete = namespaces.len() <= limit as usize;
+		namespaces.truncate(limit as usize);
+		let moved = namespaces.len() as u32;
+		for namespace in namespaces {
+			Self::transfer_namespace(namespace, source_msa_id, target_msa_id);
+		}
+		(moved, complete)
+	}
+}
+
 impl<T: Config> SchemaValidator<SchemaId> for Pallet<T> {
 	fn are_all_schema_ids_valid(schema_ids: &Vec<SchemaId>) -> bool {
 		let latest_issue_schema_id = CurrentSchemaIdentifierMaximum::<T>::get();
@@ -1,2 +1,4 @@
 /// migrations to v3
 pub mod v4;
+/// migrations to v5
+pub mod v5;
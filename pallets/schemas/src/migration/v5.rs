@@ -0,0 +1,96 @@
+use crate::{pallet::SchemaInfos, Config, Pallet, SchemaInfo, LOG_TARGET};
+use common_primitives::schema::{ModelType, PayloadLocation, SchemaSettings};
+use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade, weights::Weight};
+use log;
+use sp_runtime::Saturating;
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// [`SchemaInfo`] as stored before the compatibility mode was added
+#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq, MaxEncodedLen)]
+pub struct SchemaInfoV4 {
+	/// The type of model (AvroBinary, Parquet, etc.)
+	pub model_type: ModelType,
+	/// The payload location
+	pub payload_location: PayloadLocation,
+	/// additional control settings for the schema
+	pub settings: SchemaSettings,
+	/// Defines if a schema has a name or not
+	pub has_name: bool,
+}
+
+/// migration to v5 implementation
+pub struct MigrateToV5<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for MigrateToV5<T> {
+	fn on_runtime_upgrade() -> Weight {
+		migrate_to_v5::<T>()
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		log::info!(target: LOG_TARGET, "Running pre_upgrade...");
+		let count = SchemaInfos::<T>::iter_keys().count() as u64;
+		log::info!(target: LOG_TARGET, "Found {:?} schemas", count);
+		Ok(count.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		log::info!(target: LOG_TARGET, "Running post_upgrade...");
+		let expected_count: u64 =
+			Decode::decode(&mut state.as_slice()).map_err(|_| "Unable to decode the count")?;
+		let onchain_version = Pallet::<T>::on_chain_storage_version();
+		assert_eq!(onchain_version, StorageVersion::new(5));
+		// every schema info should decode in the new format
+		let count = SchemaInfos::<T>::iter_values().count() as u64;
+		assert_eq!(count, expected_count, "Not every schema info was migrated");
+		log::info!(target: LOG_TARGET, "Finished post_upgrade");
+		Ok(())
+	}
+}
+
+/// migrating to v5
+pub fn migrate_to_v5<T: Config>() -> Weight {
+	log::info!(target: LOG_TARGET, "Running storage migration...");
+	let onchain_version = Pallet::<T>::on_chain_storage_version();
+	let current_version = Pallet::<T>::in_code_storage_version();
+	log::info!(target: LOG_TARGET, "onchain_version= {:?}, current_version={:?}", onchain_version, current_version);
+
+	if onchain_version < 5 {
+		let mut reads = 1u64;
+		let mut writes = 0u64;
+
+		SchemaInfos::<T>::translate::<SchemaInfoV4, _>(|_, old| {
+			reads.saturating_inc();
+			writes.saturating_inc();
+			Some(SchemaInfo {
+				model_type: old.model_type,
+				payload_location: old.payload_location,
+				settings: old.settings,
+				has_name: old.has_name,
+				compatibility: None,
+			})
+		});
+
+		// Set storage version to `5`.
+		StorageVersion::new(5).put::<Pallet<T>>();
+		writes.saturating_inc();
+
+		log::info!(target: LOG_TARGET, "Storage migrated to version 5  read={:?}, write={:?}", reads, writes);
+		let bytes = reads.saturating_mul(SchemaInfo::max_encoded_len() as u64);
+		let weights = T::DbWeight::get().reads_writes(reads, writes).add_proof_size(bytes);
+		log::info!(target: LOG_TARGET, "Migration Calculated weights={:?}",weights);
+		weights
+	} else {
+		log::info!(
+			target: LOG_TARGET,
+			"Migration did not execute. This probably should be removed onchain:{:?}, current:{:?}",
+			onchain_version,
+			current_version
+		);
+		T::DbWeight::get().reads(1)
+	}
+}
@@ -9,14 +9,17 @@
 //! Custom APIs for [Schemas](../pallet_schemas/index.html)
 
 use common_helpers::{avro, rpc::map_rpc_result};
-use common_primitives::schema::*;
+use common_primitives::{
+	json_schema::validate_json_schema_model, parquet::validation::parse_parquet_model,
+	protobuf::validate_protobuf_model, schema::*,
+};
 use jsonrpsee::{
 	core::{async_trait, RpcResult},
 	proc_macros::rpc,
 	types::error::ErrorObject,
 };
 use pallet_schemas_runtime_api::SchemasRuntimeApi;
-use sp_api::ProvideRuntimeApi;
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_runtime::traits::Block as BlockT;
 use sp_std::vec::Vec;
@@ -48,17 +51,30 @@ impl From<SchemaRpcError> for i32 {
 /// Recurrency Schema Custom RPC API
 #[rpc(client, server)]
 pub trait SchemasApi<BlockHash> {
-	/// retrieving schema by schema id
+	/// retrieving schema by schema id, with a warning when the schema is deprecated
 	#[method(name = "schemas_getBySchemaId")]
-	fn get_by_schema_id(&self, schema_id: SchemaId) -> RpcResult<Option<SchemaResponse>>;
+	fn get_by_schema_id(
+		&self,
+		schema_id: SchemaId,
+	) -> RpcResult<Option<WithSchemaWarning<SchemaResponse>>>;
 
 	/// validates a schema model and returns `true` if the model is correct.
+	/// Models are checked as Avro schemas unless another `model_type` is given. Protobuf models
+	/// are serialized `FileDescriptorSet`s.
 	#[method(name = "schemas_checkSchemaValidity")]
-	fn check_schema_validity(&self, model: Vec<u8>, at: Option<BlockHash>) -> RpcResult<bool>;
+	fn check_schema_validity(
+		&self,
+		model: Vec<u8>,
+		at: Option<BlockHash>,
+		model_type: Option<ModelType>,
+	) -> RpcResult<bool>;
 
-	/// returns an array of schema versions
+	/// returns an array of schema versions, with a warning on each deprecated version
 	#[method(name = "schemas_getVersions")]
-	fn get_versions(&self, schema_name: String) -> RpcResult<Option<Vec<SchemaVersionResponse>>>;
+	fn get_versions(
+		&self,
+		schema_name: String,
+	) -> RpcResult<Option<Vec<WithSchemaWarning<SchemaVersionResponse>>>>;
 }
 
 /// The client handler for the API used by Recurrency Service RPC with `jsonrpsee`
@@ -85,29 +101,101 @@ where
 		&self,
 		model: Vec<u8>,
 		_at: Option<<Block as BlockT>::Hash>,
+		model_type: Option<ModelType>,
 	) -> RpcResult<bool> {
-		let validated_schema = avro::validate_raw_avro_schema(&model);
-		match validated_schema {
-			Ok(_) => Ok(true),
-			Err(e) => Err(ErrorObject::owned(
-				SchemaRpcError::SchemaValidationError.into(),
-				"Unable to validate schema",
-				Some(format!("{:?}", e)),
-			)),
+		match model_type {
+			Some(ModelType::Parquet) => match parse_parquet_model(&model) {
+				Ok(_) => Ok(true),
+				Err(e) => Err(ErrorObject::owned(
+					SchemaRpcError::SchemaValidationError.into(),
+					"Unable to validate schema",
+					Some(e),
+				)),
+			},
+			Some(ModelType::JsonSchema) => match validate_json_schema_model(&model) {
+				Ok(_) => Ok(true),
+				Err(e) => Err(ErrorObject::owned(
+					SchemaRpcError::SchemaValidationError.into(),
+					"Unable to validate schema",
+					Some(e),
+				)),
+			},
+			Some(ModelType::Protobuf) => match validate_protobuf_model(&model) {
+				Ok(_) => Ok(true),
+				Err(e) => Err(ErrorObject::owned(
+					SchemaRpcError::SchemaValidationError.into(),
+					"Unable to validate schema",
+					Some(e),
+				)),
+			},
+			Some(ModelType::AvroBinary) | None => {
+				let validated_schema = avro::validate_raw_avro_schema(&model);
+				match validated_schema {
+					Ok(_) => Ok(true),
+					Err(e) => Err(ErrorObject::owned(
+						SchemaRpcError::SchemaValidationError.into(),
+						"Unable to validate schema",
+						Some(format!("{:?}", e)),
+					)),
+				}
+			},
 		}
 	}
 
-	fn get_by_schema_id(&self, schema_id: SchemaId) -> RpcResult<Option<SchemaResponse>> {
+	fn get_by_schema_id(
+		&self,
+		schema_id: SchemaId,
+	) -> RpcResult<Option<WithSchemaWarning<SchemaResponse>>> {
 		let api = self.client.runtime_api();
 		let at = self.client.info().best_hash;
-		let schema_api_result = api.get_by_schema_id(at, schema_id);
-		map_rpc_result(schema_api_result)
+		let api_version = api.api_version::<dyn SchemasRuntimeApi<Block>>(at);
+		let schema_api_result = match api_version {
+			// Runtimes before version 3 have no schema status
+			Ok(Some(version)) if version < 3 => {
+				#[allow(deprecated)]
+				let schema = api.get_by_schema_id_before_version_3(at, schema_id);
+				schema.map(|schema| schema.map(SchemaResponse::from))
+			},
+			_ => api.get_by_schema_id(at, schema_id),
+		};
+		map_rpc_result(schema_api_result.map(|schema| {
+			schema.map(|schema| {
+				let status = schema.status;
+				WithSchemaWarning::new(schema, schema_id, status)
+			})
+		}))
 	}
 
-	fn get_versions(&self, schema_name: String) -> RpcResult<Option<Vec<SchemaVersionResponse>>> {
+	fn get_versions(
+		&self,
+		schema_name: String,
+	) -> RpcResult<Option<Vec<WithSchemaWarning<SchemaVersionResponse>>>> {
 		let api = self.client.runtime_api();
 		let at = self.client.info().best_hash;
-		let schema_api_result = api.get_schema_versions_by_name(at, schema_name.into_bytes());
-		map_rpc_result(schema_api_result)
+		let api_version = api.api_version::<dyn SchemasRuntimeApi<Block>>(at);
+		let schema_api_result = match api_version {
+			// Runtimes before version 3 have no schema status
+			Ok(Some(version)) if version < 3 => {
+				#[allow(deprecated)]
+				let versions = api.get_schema_versions_by_name_before_version_3(at, schema_name.into_bytes());
+				versions.map(|versions| {
+					versions.map(|versions| {
+						versions.into_iter().map(SchemaVersionResponse::from).collect()
+					})
+				})
+			},
+			_ => api.get_schema_versions_by_name(at, schema_name.into_bytes()),
+		};
+		map_rpc_result(schema_api_result.map(|versions| {
+			versions.map(|versions| {
+				versions
+					.into_iter()
+					.map(|version| {
+						let (schema_id, status) = (version.schema_id, version.status);
+						WithSchemaWarning::new(version, schema_id, status)
+					})
+					.collect()
+			})
+		}))
 	}
 }
@@ -9,21 +9,25 @@ use pallet_schemas_runtime_api::SchemasRuntimeApi;
 use std::sync::Arc;
 
 const SUCCESSFUL_SCHEMA_ID: u16 = 1;
+const DEPRECATED_SCHEMA_ID: u16 = 2;
 const SUCCESSFUL_SCHEMA_NAME: &str = "namespace.descriptor";
 
 sp_api::mock_impl_runtime_apis! {
 	impl SchemasRuntimeApi<Block> for TestRuntimeApi {
 		fn get_by_schema_id(schema_id: SchemaId) -> Option<SchemaResponse> {
-			match schema_id {
-				SUCCESSFUL_SCHEMA_ID => Some(SchemaResponse {
-					schema_id,
-					model: b"schema".to_vec(),
-					model_type: ModelType::AvroBinary,
-					payload_location: PayloadLocation::OnChain,
-					settings: Vec::new(),
-				}),
-				_ => None,
-			}
+			let status = match schema_id {
+				SUCCESSFUL_SCHEMA_ID => SchemaStatus::Active,
+				DEPRECATED_SCHEMA_ID => SchemaStatus::Deprecated,
+				_ => return None,
+			};
+			Some(SchemaResponse {
+				schema_id,
+				model: b"schema".to_vec(),
+				model_type: ModelType::AvroBinary,
+				payload_location: PayloadLocation::OnChain,
+				settings: Vec::new(),
+				status,
+			})
 		}
 
 		fn get_schema_versions_by_name(schema_name: Vec<u8>) -> Option<Vec<SchemaVersionResponse>> {
@@ -34,12 +38,14 @@ sp_api::mock_impl_runtime_apis! {
 						SchemaVersionResponse {
 							schema_id: 1,
 							schema_version: 1,
-							schema_name: successful_name_bytes.clone()
+							schema_name: successful_name_bytes.clone(),
+							status: SchemaStatus::Active,
 						},
 						SchemaVersionResponse {
 							schema_id: 10,
 							schema_version: 2,
-							schema_name: successful_name_bytes.clone()
+							schema_name: successful_name_bytes.clone(),
+							status: SchemaStatus::Deprecated,
 						},
 					]
 				)
@@ -50,8 +56,12 @@ sp_api::mock_impl_runtime_apis! {
 	}
 }
 
-type SchemaResult = Result<Option<SchemaResponse>, jsonrpsee::types::ErrorObjectOwned>;
-type VersionResult = Result<Option<Vec<SchemaVersionResponse>>, jsonrpsee::types::ErrorObjectOwned>;
+type SchemaResult =
+	Result<Option<WithSchemaWarning<SchemaResponse>>, jsonrpsee::types::ErrorObjectOwned>;
+type VersionResult = Result<
+	Option<Vec<WithSchemaWarning<SchemaVersionResponse>>>,
+	jsonrpsee::types::ErrorObjectOwned,
+>;
 
 #[tokio::test]
 async fn get_schema_with_non_existent_schema_id_should_return_none() {
@@ -77,11 +87,25 @@ async fn get_schema_with_success() {
 
 	assert_eq!(true, result.is_ok());
 	let response = result.unwrap().unwrap();
+	assert_eq!(None, response.warning);
+	let response = response.response;
 	assert_eq!(1, response.schema_id);
 	assert_eq!(ModelType::AvroBinary, response.model_type);
 	assert_eq!(PayloadLocation::OnChain, response.payload_location);
 }
 
+#[tokio::test]
+async fn get_deprecated_schema_should_warn() {
+	let client = Arc::new(TestApi {});
+	let api = SchemasHandler::new(client);
+
+	let result: SchemaResult = api.get_by_schema_id(DEPRECATED_SCHEMA_ID);
+
+	let response = result.unwrap().unwrap();
+	assert_eq!(SchemaStatus::Deprecated, response.response.status);
+	assert!(response.warning.is_some());
+}
+
 #[tokio::test]
 async fn get_schema_versions_with_success() {
 	let client = Arc::new(TestApi {});
@@ -92,6 +116,8 @@ async fn get_schema_versions_with_success() {
 	assert_eq!(true, result.is_ok());
 	let response = result.unwrap().unwrap();
 	assert_eq!(response.len(), 2);
+	assert_eq!(None, response[0].warning);
+	assert!(response[1].warning.is_some());
 }
 
 #[tokio::test]
@@ -113,6 +139,7 @@ async fn check_schema_validity_success() {
 		.as_bytes()
 		.to_vec(),
 		None,
+		None,
 	);
 
 	assert_eq!(true, result.is_ok());
@@ -134,7 +161,87 @@ async fn check_schema_validity_fail() {
 		.as_bytes()
 		.to_vec(),
 		None,
+		None,
 	);
 
 	assert_eq!(false, result.is_ok());
 }
+
+#[tokio::test]
+async fn check_schema_validity_parquet_success() {
+	let client = Arc::new(TestApi {});
+	let api = SchemasHandler::new(client);
+
+	let result = api.check_schema_validity(
+		r#"[{"name": "url", "column_type": "STRING", "compression": "GZIP", "bloom_filter": false}]"#
+			.as_bytes()
+			.to_vec(),
+		None,
+		Some(ModelType::Parquet),
+	);
+
+	assert_eq!(true, result.is_ok());
+	assert_eq!(true, result.unwrap());
+}
+
+#[tokio::test]
+async fn check_schema_validity_parquet_fail_returns_structured_error() {
+	let client = Arc::new(TestApi {});
+	let api = SchemasHandler::new(client);
+
+	let result = api.check_schema_validity(
+		r#"[
+			{"name": "url", "column_type": "STRING", "compression": "GZIP", "bloom_filter": false},
+			{"name": "url", "column_type": "BOOLEAN", "compression": "GZIP", "bloom_filter": false}
+		]"#
+		.as_bytes()
+		.to_vec(),
+		None,
+		Some(ModelType::Parquet),
+	);
+
+	let error = result.unwrap_err();
+	assert_eq!(error.code(), i32::from(SchemaRpcError::SchemaValidationError));
+	assert_eq!(
+		error.data().map(|data| data.get()),
+		Some(r#"{"DuplicateColumnName":{"column":"url"}}"#)
+	);
+}
+
+#[tokio::test]
+async fn check_schema_validity_json_schema_fail_returns_structured_error() {
+	let client = Arc::new(TestApi {});
+	let api = SchemasHandler::new(client);
+
+	let result = api.check_schema_validity(
+		r#"{"type": "object", "properties": {"url": {"type": "text"}}}"#.as_bytes().to_vec(),
+		None,
+		Some(ModelType::JsonSchema),
+	);
+
+	let error = result.unwrap_err();
+	assert_eq!(error.code(), i32::from(SchemaRpcError::SchemaValidationError));
+	assert_eq!(
+		error.data().map(|data| data.get()),
+		Some(r#"{"InvalidKeyword":{"path":"/properties/url","keyword":"type"}}"#)
+	);
+}
+
+#[tokio::test]
+async fn check_schema_validity_protobuf() {
+	let client = Arc::new(TestApi {});
+	let api = SchemasHandler::new(client);
+	// FileDescriptorSet { file: [{ name: "a.proto", message_type: [{ name: "A" }] }] }
+	let model = vec![
+		0x0a, 0x0e, 0x0a, 0x07, b'a', b'.', b'p', b'r', b'o', b't', b'o', 0x22, 0x03, 0x0a, 0x01,
+		b'A',
+	];
+
+	let result = api.check_schema_validity(model, None, Some(ModelType::Protobuf));
+	assert_eq!(true, result.unwrap());
+
+	let result =
+		api.check_schema_validity(b"not a descriptor".to_vec(), None, Some(ModelType::Protobuf));
+	let error = result.unwrap_err();
+	assert_eq!(error.data().map(|data| data.get()), Some(r#""Malformed""#));
+}
@@ -26,14 +26,20 @@ sp_api::decl_runtime_apis! {
 	/// - MUST be incremented if anything changes
 	/// - Also update in js/api-augment
 	/// - See: https://paritytech.github.io/polkadot/doc/polkadot_primitives/runtime_api/index.html
-	#[api_version(2)]
+	#[api_version(3)]
 
 	/// Runtime API definition for [Schemas](../pallet_schemas/index.html)
 	pub trait SchemasRuntimeApi
 	{
+		/// Fetch the schema by id
+		#[changed_in(3)]
+		fn get_by_schema_id(schema_id: SchemaId) -> Option<SchemaResponseV2>;
 		/// Fetch the schema by id
 		fn get_by_schema_id(schema_id: SchemaId) -> Option<SchemaResponse>;
 		/// Fetch the schema versions by name
+		#[changed_in(3)]
+		fn get_schema_versions_by_name(schema_name: Vec<u8>) -> Option<Vec<SchemaVersionResponseV2>>;
+		/// Fetch the schema versions by name
 		fn get_schema_versions_by_name(schema_name: Vec<u8>) -> Option<Vec<SchemaVersionResponse>>;
 	}
 }
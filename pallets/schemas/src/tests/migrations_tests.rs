@@ -1,13 +1,15 @@
 use crate::{
-	migration::v4,
+	migration::{v4, v5},
 	tests::mock::{
 		create_bounded_schema_vec, new_test_ext, sudo_set_max_schema_size, test_public,
 		RuntimeOrigin, SchemasPallet, Test,
 	},
+	SchemaInfos,
 };
 use common_primitives::{node::AccountId, schema::*};
 use frame_support::{
-	assert_ok, pallet_prelude::StorageVersion, traits::GetStorageVersion, BoundedVec,
+	assert_ok, pallet_prelude::StorageVersion, storage::unhashed, traits::GetStorageVersion,
+	BoundedVec,
 };
 
 #[test]
@@ -33,9 +35,50 @@ fn schemas_migration_to_v4_should_work_as_expected() {
 
 		// Assert
 		let current_version = SchemasPallet::in_code_storage_version();
-		assert_eq!(current_version, StorageVersion::new(4));
+		assert_eq!(current_version, StorageVersion::new(5));
 
 		let known_schemas = v4::get_known_schemas::<Test>();
 		assert_eq!(known_schemas.len(), 0);
 	});
 }
+
+#[test]
+fn schemas_migration_to_v5_should_add_compatibility_to_schema_infos() {
+	new_test_ext().execute_with(|| {
+		// Arrange
+		sudo_set_max_schema_size();
+		let sender: AccountId = test_public(5);
+		for _ in 0..5 {
+			assert_ok!(SchemasPallet::create_schema_v3(
+				RuntimeOrigin::signed(sender.clone()),
+				create_bounded_schema_vec(r#"{"latitude": 48.858093,"longitude": 2.294694}"#),
+				ModelType::AvroBinary,
+				PayloadLocation::OnChain,
+				BoundedVec::default(),
+				None,
+			));
+		}
+		let old_schema_info = v5::SchemaInfoV4 {
+			model_type: ModelType::AvroBinary,
+			payload_location: PayloadLocation::OnChain,
+			settings: SchemaSettings::all_disabled(),
+			has_name: false,
+		};
+		for schema_id in 1..=5 {
+			unhashed::put(&SchemaInfos::<Test>::hashed_key_for(schema_id), &old_schema_info);
+		}
+		StorageVersion::new(4).put::<SchemasPallet>();
+
+		// Act
+		let _ = v5::migrate_to_v5::<Test>();
+
+		// Assert
+		assert_eq!(SchemasPallet::on_chain_storage_version(), StorageVersion::new(5));
+		for schema_id in 1..=5 {
+			let schema_info = SchemaInfos::<Test>::get(schema_id).expect("should migrate");
+			assert_eq!(schema_info.model_type, ModelType::AvroBinary);
+			assert_eq!(schema_info.payload_location, PayloadLocation::OnChain);
+			assert_eq!(schema_info.compatibility, None);
+		}
+	});
+}
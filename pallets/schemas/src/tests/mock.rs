@@ -8,14 +8,19 @@ use frame_support::{
 use frame_system::EnsureRoot;
 use parity_scale_codec::MaxEncodedLen;
 
-use common_primitives::node::AccountId;
+use common_primitives::{
+	capacity::TargetValidator,
+	msa::{MessageSourceId, MsaValidator},
+	node::AccountId,
+	schema::SchemaId,
+};
 use common_runtime::constants::DAYS;
 use pallet_collective::{self, Members};
 use smallvec::smallvec;
 use sp_core::{parameter_types, Encode, H256};
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup},
-	AccountId32, BuildStorage, DispatchError, Perbill,
+	AccountId32, BuildStorage, DispatchError, DispatchResult, Perbill,
 };
 
 use crate as pallet_schemas;
@@ -111,6 +116,48 @@ impl pallet_schemas::ProposalProvider<AccountId, RuntimeCall> for CouncilProposa
 	}
 }
 
+/// A key of [`test_public`] without any MSA
+pub const NO_MSA_KEY: u8 = 200;
+/// The MSA of this key of [`test_public`] is not a registered provider
+pub const NON_PROVIDER_KEY: u8 = 201;
+/// A key of [`test_public`] whose scope does not allow it to manage its MSA
+pub const SCOPED_KEY: u8 = 202;
+
+/// The MSA of a key is its first byte, so `test_public(n)` is a key of MSA `n`
+pub struct MsaInfoHandler;
+
+impl MsaValidator for MsaInfoHandler {
+	type AccountId = AccountId;
+
+	fn ensure_valid_msa_key(key: &Self::AccountId) -> Result<MessageSourceId, DispatchError> {
+		let bytes: &[u8; 32] = key.as_ref();
+		if bytes[0] == NO_MSA_KEY {
+			return Err(DispatchError::Other("no msa"))
+		}
+		Ok(bytes[0] as MessageSourceId)
+	}
+
+	fn ensure_key_can_manage_msa(key: &Self::AccountId) -> DispatchResult {
+		if *key == test_public(SCOPED_KEY) {
+			return Err(DispatchError::Other("scoped key"))
+		}
+		Ok(())
+	}
+
+	fn ensure_key_allows_schema(_key: &Self::AccountId, _schema_id: SchemaId) -> DispatchResult {
+		Ok(())
+	}
+}
+
+/// Every MSA but the one of [`NON_PROVIDER_KEY`] is a registered provider
+pub struct ProviderHandler;
+
+impl TargetValidator for ProviderHandler {
+	fn validate(target: MessageSourceId) -> bool {
+		target != NON_PROVIDER_KEY as MessageSourceId
+	}
+}
+
 impl pallet_schemas::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
@@ -130,6 +177,12 @@ impl pallet_schemas::Config for Test {
 		EnsureRoot<AccountId>,
 		pallet_collective::EnsureProportionMoreThan<AccountId, CouncilCollective, 1, 2>,
 	>;
+	type MsaInfoProvider = MsaInfoHandler;
+	type ProviderValidator = ProviderHandler;
+	#[cfg(feature = "runtime-benchmarks")]
+	type MsaBenchmarkHelper = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type ProviderBenchmarkHelper = ();
 }
 
 impl frame_system::Config for Test {
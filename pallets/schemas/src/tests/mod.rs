@@ -1,5 +1,8 @@
 mod deprecated_tests;
 mod migrations_tests;
 pub mod mock;
+mod namespace_tests;
 mod other_tests;
+mod schema_compatibility_tests;
+mod schema_status_tests;
 mod serde_tests;
@@ -0,0 +1,752 @@
+use common_primitives::{
+	msa::{MessageSourceId, MsaDataMerger, MsaDataPurger},
+	schema::{ModelType, PayloadLocation, SchemaCompatibilityMode},
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::{GetDispatchInfo, RawOrigin},
+	weights::Weight,
+	BoundedVec,
+};
+use sp_runtime::{
+	DispatchError::{self, BadOrigin},
+	DispatchResult,
+};
+
+use crate::{
+	Error, Event as AnnouncementEvent, NamespaceDelegates, NamespaceOwners, OwnedNamespaces,
+	SchemaName, SchemaNamePayload,
+};
+
+use super::mock::*;
+
+const OWNER: u8 = 1;
+const DELEGATE: u8 = 2;
+const OTHER: u8 = 3;
+
+fn payload(name: &str) -> SchemaNamePayload {
+	SchemaNamePayload::try_from(name.to_string().into_bytes()).expect("should work")
+}
+
+fn model() -> BoundedVec<u8, <Test as crate::Config>::SchemaModelMaxBytesBoundedVecLimit> {
+	create_bounded_schema_vec(r#"{"name": "Doe", "type": "lost"}"#)
+}
+
+/// Claims `protocol` for the MSA of `OWNER`
+fn claim_protocol_namespace() {
+	assert_ok!(SchemasPallet::claim_namespace_via_governance(
+		RawOrigin::Root.into(),
+		OWNER as MessageSourceId,
+		payload("protocol"),
+	));
+}
+
+fn create_schema_in_namespace(key: u8, name: &str) -> DispatchResult {
+	SchemasPallet::create_schema_in_namespace(
+		test_origin_signed(key),
+		model(),
+		ModelType::AvroBinary,
+		PayloadLocation::OnChain,
+		BoundedVec::default(),
+		payload(name),
+		None,
+	)
+}
+
+#[test]
+fn claim_namespace_via_governance_sets_owner_and_emits_event() {
+	new_test_ext().execute_with(|| {
+		// act
+		assert_ok!(SchemasPallet::claim_namespace_via_governance(
+			RawOrigin::Root.into(),
+			OWNER as MessageSourceId,
+			payload("Protocol"),
+		));
+
+		// assert
+		let namespace = SchemaName::try_parse_namespace::<Test>(payload("protocol")).unwrap();
+		assert_eq!(NamespaceOwners::<Test>::get(namespace), Some(OWNER as MessageSourceId));
+		System::assert_last_event(
+			AnnouncementEvent::NamespaceClaimed {
+				namespace: b"protocol".to_vec(),
+				owner_msa_id: OWNER as MessageSourceId,
+			}
+			.into(),
+		);
+	})
+}
+
+#[test]
+fn claim_namespace_via_governance_with_invalid_input_fails() {
+	new_test_ext().execute_with(|| {
+		claim_protocol_namespace();
+
+		assert_noop!(
+			SchemasPallet::claim_namespace_via_governance(
+				test_origin_signed(OWNER),
+				OWNER as MessageSourceId,
+				payload("other")
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			SchemasPallet::claim_namespace_via_governance(
+				RawOrigin::Root.into(),
+				NON_PROVIDER_KEY as MessageSourceId,
+				payload("other")
+			),
+			Error::<Test>::InvalidNamespaceProvider
+		);
+		assert_noop!(
+			SchemasPallet::claim_namespace_via_governance(
+				RawOrigin::Root.into(),
+				OTHER as MessageSourceId,
+				payload("other.descriptor")
+			),
+			Error::<Test>::InvalidSchemaNameStructure
+		);
+		assert_noop!(
+			SchemasPallet::claim_namespace_via_governance(
+				RawOrigin::Root.into(),
+				OTHER as MessageSourceId,
+				payload("protocol")
+			),
+			Error::<Test>::NamespaceClaimed
+		);
+	})
+}
+
+#[test]
+fn propose_to_claim_namespace_creates_council_proposal() {
+	new_test_ext().execute_with(|| {
+		// act
+		assert_ok!(SchemasPallet::propose_to_claim_namespace(
+			test_origin_signed(OWNER),
+			payload("protocol")
+		));
+
+		// assert
+		let proposed_events = System::events()
+			.iter()
+			.filter(|event| {
+				matches!(
+					event.event,
+					RuntimeEvent::Council(pallet_collective::Event::Proposed { .. })
+				)
+			})
+			.count();
+		assert_eq!(proposed_events, 1);
+	})
+}
+
+#[test]
+fn propose_to_claim_namespace_requires_unclaimed_namespace_and_provider() {
+	new_test_ext().execute_with(|| {
+		claim_protocol_namespace();
+
+		assert_noop!(
+			SchemasPallet::propose_to_claim_namespace(
+				test_origin_signed(OTHER),
+				payload("protocol")
+			),
+			Error::<Test>::NamespaceClaimed
+		);
+		assert_noop!(
+			SchemasPallet::propose_to_claim_namespace(
+				test_origin_signed(NO_MSA_KEY),
+				payload("other")
+			),
+			Error::<Test>::InvalidMessageSourceAccount
+		);
+		assert_noop!(
+			SchemasPallet::propose_to_claim_namespace(
+				test_origin_signed(NON_PROVIDER_KEY),
+				payload("other")
+			),
+			Error::<Test>::InvalidNamespaceProvider
+		);
+		assert_noop!(
+			SchemasPallet::propose_to_claim_namespace(
+				test_origin_signed(SCOPED_KEY),
+				payload("other")
+			),
+			DispatchError::Other("scoped key")
+		);
+	})
+}
+
+#[test]
+fn add_and_remove_namespace_delegate_by_owner_succeeds() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		claim_protocol_namespace();
+		let namespace = SchemaName::try_parse_namespace::<Test>(payload("protocol")).unwrap();
+
+		// act
+		assert_ok!(SchemasPallet::add_namespace_delegate(
+			test_origin_signed(OWNER),
+			payload("protocol"),
+			DELEGATE as MessageSourceId
+		));
+
+		// assert
+		assert!(NamespaceDelegates::<Test>::contains_key(&namespace, DELEGATE as MessageSourceId));
+		System::assert_last_event(
+			AnnouncementEvent::NamespaceDelegateAdded {
+				namespace: b"protocol".to_vec(),
+				delegate_msa_id: DELEGATE as MessageSourceId,
+			}
+			.into(),
+		);
+
+		// act
+		assert_ok!(SchemasPallet::remove_namespace_delegate(
+			test_origin_signed(OWNER),
+			payload("protocol"),
+			DELEGATE as MessageSourceId
+		));
+
+		// assert
+		assert!(!NamespaceDelegates::<Test>::contains_key(&namespace, DELEGATE as MessageSourceId));
+		System::assert_last_event(
+			AnnouncementEvent::NamespaceDelegateRemoved {
+				namespace: b"protocol".to_vec(),
+				delegate_msa_id: DELEGATE as MessageSourceId,
+			}
+			.into(),
+		);
+	})
+}
+
+#[test]
+fn namespace_delegates_are_managed_only_by_owner() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		claim_protocol_namespace();
+		assert_ok!(SchemasPallet::add_namespace_delegate(
+			test_origin_signed(OWNER),
+			payload("protocol"),
+			DELEGATE as MessageSourceId
+		));
+
+		// act & assert
+		assert_noop!(
+			SchemasPallet::add_namespace_delegate(
+				test_origin_signed(DELEGATE),
+				payload("protocol"),
+				OTHER as MessageSourceId
+			),
+			Error::<Test>::NotNamespaceOwner
+		);
+		assert_noop!(
+			SchemasPallet::remove_namespace_delegate(
+				test_origin_signed(OTHER),
+				payload("protocol"),
+				DELEGATE as MessageSourceId
+			),
+			Error::<Test>::NotNamespaceOwner
+		);
+		assert_noop!(
+			SchemasPallet::add_namespace_delegate(
+				test_origin_signed(OWNER),
+				payload("unclaimed"),
+				DELEGATE as MessageSourceId
+			),
+			Error::<Test>::NamespaceNotClaimed
+		);
+		assert_noop!(
+			SchemasPallet::add_namespace_delegate(
+				test_origin_signed(OWNER),
+				payload("protocol"),
+				DELEGATE as MessageSourceId
+			),
+			Error::<Test>::NamespaceDelegateAlreadyExists
+		);
+		assert_noop!(
+			SchemasPallet::add_namespace_delegate(
+				test_origin_signed(OWNER),
+				payload("protocol"),
+				NON_PROVIDER_KEY as MessageSourceId
+			),
+			Error::<Test>::InvalidNamespaceProvider
+		);
+		assert_noop!(
+			SchemasPallet::remove_namespace_delegate(
+				test_origin_signed(OWNER),
+				payload("protocol"),
+				OTHER as MessageSourceId
+			),
+			Error::<Test>::NamespaceDelegateNotFound
+		);
+	})
+}
+
+#[test]
+fn create_schema_in_namespace_by_owner_and_delegate_succeeds() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		sudo_set_max_schema_size();
+		claim_protocol_namespace();
+		assert_ok!(SchemasPallet::add_namespace_delegate(
+			test_origin_signed(OWNER),
+			payload("protocol"),
+			DELEGATE as MessageSourceId
+		));
+
+		// act
+		assert_ok!(create_schema_in_namespace(OWNER, "protocol.post"));
+		assert_ok!(create_schema_in_namespace(DELEGATE, "protocol.post"));
+
+		// assert
+		System::assert_last_event(
+			AnnouncementEvent::SchemaNameCreated { schema_id: 2, name: b"protocol.post".to_vec() }
+				.into(),
+		);
+		let versions =
+			SchemasPallet::get_schema_versions("protocol.post".as_bytes().to_vec()).unwrap();
+		assert_eq!(versions.len(), 2);
+		assert_eq!(versions[1].schema_id, 2);
+	})
+}
+
+#[test]
+fn create_schema_in_namespace_by_non_administrator_fails() {
+	new_test_ext().execute_with(|| {
+		sudo_set_max_schema_size();
+		claim_protocol_namespace();
+
+		assert_noop!(
+			create_schema_in_namespace(OTHER, "protocol.post"),
+			Error::<Test>::NotNamespaceAdministrator
+		);
+		assert_noop!(
+			create_schema_in_namespace(OWNER, "unclaimed.post"),
+			Error::<Test>::NamespaceNotClaimed
+		);
+		assert_noop!(
+			create_schema_in_namespace(NO_MSA_KEY, "protocol.post"),
+			Error::<Test>::InvalidMessageSourceAccount
+		);
+	})
+}
+
+#[test]
+fn create_schema_name_in_namespace_by_owner_succeeds() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		sudo_set_max_schema_size();
+		claim_protocol_namespace();
+		assert_ok!(SchemasPallet::create_schema_v3(
+			test_origin_signed(OTHER),
+			model(),
+			ModelType::AvroBinary,
+			PayloadLocation::OnChain,
+			BoundedVec::default(),
+			None,
+		));
+
+		// act
+		assert_noop!(
+			SchemasPallet::create_schema_name_in_namespace(
+				test_origin_signed(OTHER),
+				1,
+				payload("protocol.post")
+			),
+			Error::<Test>::NotNamespaceAdministrator
+		);
+		assert_ok!(SchemasPallet::create_schema_name_in_namespace(
+			test_origin_signed(OWNER),
+			1,
+			payload("protocol.post")
+		));
+
+		// assert
+		System::assert_last_event(
+			AnnouncementEvent::SchemaNameCreated { schema_id: 1, name: b"protocol.post".to_vec() }
+				.into(),
+		);
+	})
+}
+
+#[test]
+fn schemas_in_claimed_namespace_require_namespace_administrator() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		sudo_set_max_schema_size();
+		claim_protocol_namespace();
+		assert_ok!(SchemasPallet::create_schema_v3(
+			test_origin_signed(OTHER),
+			model(),
+			ModelType::AvroBinary,
+			PayloadLocation::OnChain,
+			BoundedVec::default(),
+			None,
+		));
+
+		// act & assert
+		assert_noop!(
+			SchemasPallet::create_schema_v3(
+				test_origin_signed(OTHER),
+				model(),
+				ModelType::AvroBinary,
+				PayloadLocation::OnChain,
+				BoundedVec::default(),
+				Some(payload("protocol.post")),
+			),
+			Error::<Test>::NamespaceClaimed
+		);
+		assert_noop!(
+			SchemasPallet::propose_to_create_schema_v2(
+				test_origin_signed(OTHER),
+				model(),
+				ModelType::AvroBinary,
+				PayloadLocation::OnChain,
+				BoundedVec::default(),
+				Some(payload("protocol.post")),
+			),
+			Error::<Test>::NamespaceClaimed
+		);
+		assert_noop!(
+			SchemasPallet::propose_to_create_schema_v3(
+				test_origin_signed(OTHER),
+				model(),
+				ModelType::AvroBinary,
+				PayloadLocation::OnChain,
+				BoundedVec::default(),
+				Some(payload("protocol.post")),
+				None,
+			),
+			Error::<Test>::NamespaceClaimed
+		);
+		assert_noop!(
+			SchemasPallet::propose_to_create_schema_name(
+				test_origin_signed(OTHER),
+				1,
+				payload("protocol.post")
+			),
+			Error::<Test>::NamespaceClaimed
+		);
+		assert_ok!(SchemasPallet::propose_to_create_schema_name(
+			test_origin_signed(OTHER),
+			1,
+			payload("unclaimed.post")
+		));
+	})
+}
+
+#[test]
+fn release_namespace_via_governance_removes_owner_and_delegates() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		sudo_set_max_schema_size();
+		claim_protocol_namespace();
+		assert_ok!(SchemasPallet::add_namespace_delegate(
+			test_origin_signed(OWNER),
+			payload("protocol"),
+			DELEGATE as MessageSourceId
+		));
+		let namespace = SchemaName::try_parse_namespace::<Test>(payload("protocol")).unwrap();
+
+		// act
+		assert_ok!(SchemasPallet::release_namespace_via_governance(
+			RawOrigin::Root.into(),
+			payload("protocol"),
+		));
+
+		// assert
+		assert_eq!(NamespaceOwners::<Test>::get(&namespace), None);
+		assert!(!OwnedNamespaces::<Test>::contains_key(OWNER as MessageSourceId, &namespace));
+		System::assert_last_event(
+			AnnouncementEvent::NamespaceReleased {
+				namespace: b"protocol".to_vec(),
+				owner_msa_id: OWNER as MessageSourceId,
+			}
+			.into(),
+		);
+
+		// the namespace can be claimed again, and the delegates of the previous owner are gone
+		assert_ok!(SchemasPallet::claim_namespace_via_governance(
+			RawOrigin::Root.into(),
+			OTHER as MessageSourceId,
+			payload("protocol"),
+		));
+		assert_noop!(
+			create_schema_in_namespace(DELEGATE, "protocol.post"),
+			Error::<Test>::NotNamespaceAdministrator
+		);
+		assert_ok!(create_schema_in_namespace(OTHER, "protocol.post"));
+	})
+}
+
+#[test]
+fn release_namespace_via_governance_with_invalid_input_fails() {
+	new_test_ext().execute_with(|| {
+		claim_protocol_namespace();
+
+		assert_noop!(
+			SchemasPallet::release_namespace_via_governance(
+				test_origin_signed(OWNER),
+				payload("protocol"),
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			SchemasPallet::release_namespace_via_governance(
+				RawOrigin::Root.into(),
+				payload("unclaimed"),
+			),
+			Error::<Test>::NamespaceNotClaimed
+		);
+	})
+}
+
+#[test]
+fn transfer_namespace_via_governance_changes_owner() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		sudo_set_max_schema_size();
+		claim_protocol_namespace();
+		assert_ok!(SchemasPallet::add_namespace_delegate(
+			test_origin_signed(OWNER),
+			payload("protocol"),
+			DELEGATE as MessageSourceId
+		));
+		let namespace = SchemaName::try_parse_namespace::<Test>(payload("protocol")).unwrap();
+
+		// act
+		assert_ok!(SchemasPallet::transfer_namespace_via_governance(
+			RawOrigin::Root.into(),
+			payload("protocol"),
+			OTHER as MessageSourceId,
+		));
+
+		// assert
+		assert_eq!(NamespaceOwners::<Test>::get(&namespace), Some(OTHER as MessageSourceId));
+		assert!(!OwnedNamespaces::<Test>::contains_key(OWNER as MessageSourceId, &namespace));
+		assert!(OwnedNamespaces::<Test>::contains_key(OTHER as MessageSourceId, &namespace));
+		System::assert_last_event(
+			AnnouncementEvent::NamespaceTransferred {
+				namespace: b"protocol".to_vec(),
+				previous_owner_msa_id: OWNER as MessageSourceId,
+				owner_msa_id: OTHER as MessageSourceId,
+			}
+			.into(),
+		);
+		assert_noop!(
+			create_schema_in_namespace(OWNER, "protocol.post"),
+			Error::<Test>::NotNamespaceAdministrator
+		);
+		assert_noop!(
+			create_schema_in_namespace(DELEGATE, "protocol.post"),
+			Error::<Test>::NotNamespaceAdministrator
+		);
+		assert_ok!(create_schema_in_namespace(OTHER, "protocol.post"));
+
+		// the new owner may add the delegate of the previous owner again
+		assert_ok!(SchemasPallet::add_namespace_delegate(
+			test_origin_signed(OTHER),
+			payload("protocol"),
+			DELEGATE as MessageSourceId
+		));
+		assert_ok!(create_schema_in_namespace(DELEGATE, "protocol.post"));
+	})
+}
+
+#[test]
+fn transfer_namespace_via_governance_with_invalid_input_fails() {
+	new_test_ext().execute_with(|| {
+		claim_protocol_namespace();
+
+		assert_noop!(
+			SchemasPallet::transfer_namespace_via_governance(
+				test_origin_signed(OWNER),
+				payload("protocol"),
+				OTHER as MessageSourceId,
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			SchemasPallet::transfer_namespace_via_governance(
+				RawOrigin::Root.into(),
+				payload("unclaimed"),
+				OTHER as MessageSourceId,
+			),
+			Error::<Test>::NamespaceNotClaimed
+		);
+		assert_noop!(
+			SchemasPallet::transfer_namespace_via_governance(
+				RawOrigin::Root.into(),
+				payload("protocol"),
+				NON_PROVIDER_KEY as MessageSourceId,
+			),
+			Error::<Test>::InvalidNamespaceProvider
+		);
+		assert_noop!(
+			SchemasPallet::transfer_namespace_via_governance(
+				RawOrigin::Root.into(),
+				payload("protocol"),
+				OWNER as MessageSourceId,
+			),
+			Error::<Test>::NamespaceClaimed
+		);
+	})
+}
+
+#[test]
+fn purge_msa_data_releases_owned_namespaces() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		claim_protocol_namespace();
+		assert_ok!(SchemasPallet::claim_namespace_via_governance(
+			RawOrigin::Root.into(),
+			OWNER as MessageSourceId,
+			payload("social"),
+		));
+
+		// act
+		let (_, complete) = <SchemasPallet as MsaDataPurger>::purge_msa_data(
+			OWNER as MessageSourceId,
+			Weight::zero(),
+		);
+
+		// assert
+		assert!(!complete);
+		assert_eq!(OwnedNamespaces::<Test>::iter_prefix(OWNER as MessageSourceId).count(), 2);
+
+		// act
+		let (_, complete) =
+			<SchemasPallet as MsaDataPurger>::purge_msa_data(OWNER as MessageSourceId, Weight::MAX);
+
+		// assert
+		assert!(complete);
+		assert_eq!(OwnedNamespaces::<Test>::iter_prefix(OWNER as MessageSourceId).count(), 0);
+		assert_eq!(NamespaceOwners::<Test>::iter().count(), 0);
+	})
+}
+
+#[test]
+fn merge_msa_data_transfers_owned_namespaces() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		claim_protocol_namespace();
+		assert_ok!(SchemasPallet::claim_namespace_via_governance(
+			RawOrigin::Root.into(),
+			OWNER as MessageSourceId,
+			payload("social"),
+		));
+
+		// act
+		let (moved, complete) = <SchemasPallet as MsaDataMerger>::merge_msa_data(
+			OWNER as MessageSourceId,
+			OTHER as MessageSourceId,
+			1,
+		);
+
+		// assert
+		assert_eq!((moved, complete), (1, false));
+
+		// act
+		let (moved, complete) = <SchemasPallet as MsaDataMerger>::merge_msa_data(
+			OWNER as MessageSourceId,
+			OTHER as MessageSourceId,
+			10,
+		);
+
+		// assert
+		assert_eq!((moved, complete), (1, true));
+		assert_eq!(OwnedNamespaces::<Test>::iter_prefix(OWNER as MessageSourceId).count(), 0);
+		assert_eq!(OwnedNamespaces::<Test>::iter_prefix(OTHER as MessageSourceId).count(), 2);
+		assert!(NamespaceOwners::<Test>::iter().all(|(_, owner)| owner == OTHER as MessageSourceId));
+	})
+}
+
+#[test]
+fn governance_schema_names_proposed_before_a_claim_fail_once_the_namespace_is_claimed() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		sudo_set_max_schema_size();
+		assert_ok!(SchemasPallet::propose_to_create_schema_v2(
+			test_origin_signed(OTHER),
+			model(),
+			ModelType::AvroBinary,
+			PayloadLocation::OnChain,
+			BoundedVec::default(),
+			Some(payload("protocol.post")),
+		));
+		assert_ok!(SchemasPallet::create_schema_via_governance(
+			RawOrigin::Root.into(),
+			test_public(OTHER),
+			model(),
+			ModelType::AvroBinary,
+			PayloadLocation::OnChain,
+			BoundedVec::default(),
+		));
+		assert_ok!(SchemasPallet::propose_to_create_schema_name(
+			test_origin_signed(OTHER),
+			1,
+			payload("protocol.post"),
+		));
+		claim_protocol_namespace();
+
+		// act and assert
+		assert_noop!(
+			SchemasPallet::create_schema_via_governance_v2(
+				RawOrigin::Root.into(),
+				test_public(OTHER),
+				model(),
+				ModelType::AvroBinary,
+				PayloadLocation::OnChain,
+				BoundedVec::default(),
+				Some(payload("protocol.post")),
+			),
+			Error::<Test>::NamespaceClaimed
+		);
+		assert_noop!(
+			SchemasPallet::create_schema_via_governance_v3(
+				RawOrigin::Root.into(),
+				test_public(OTHER),
+				model(),
+				ModelType::AvroBinary,
+				PayloadLocation::OnChain,
+				BoundedVec::default(),
+				Some(payload("protocol.post")),
+				None,
+			),
+			Error::<Test>::NamespaceClaimed
+		);
+		assert_noop!(
+			SchemasPallet::create_schema_name_via_governance(
+				RawOrigin::Root.into(),
+				1,
+				payload("protocol.post"),
+			),
+			Error::<Test>::NamespaceClaimed
+		);
+	})
+}
+
+#[test]
+fn create_schema_in_namespace_weight_includes_the_compatibility_check() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let weight_of = |compatibility: Option<SchemaCompatibilityMode>| {
+			crate::Call::<Test>::create_schema_in_namespace {
+				model: model(),
+				model_type: ModelType::AvroBinary,
+				payload_location: PayloadLocation::OnChain,
+				settings: BoundedVec::default(),
+				schema_name: payload("protocol.post"),
+				compatibility,
+			}
+			.get_dispatch_info()
+			.weight
+		};
+
+		// act
+		let unchecked_weight = weight_of(None);
+		let checked_weight = weight_of(Some(SchemaCompatibilityMode::Full));
+
+		// assert
+		assert!(checked_weight.ref_time() > unchecked_weight.ref_time());
+	});
+}
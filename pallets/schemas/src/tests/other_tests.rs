@@ -8,11 +8,16 @@ use common_primitives::{
 		ParquetModel,
 	},
 	schema::{
-		ModelType, PayloadLocation, SchemaId, SchemaSetting, SchemaVersion, SchemaVersionResponse,
+		ModelType, PayloadLocation, SchemaId, SchemaSetting, SchemaStatus, SchemaVersion,
+		SchemaVersionResponse,
 	},
 };
 use frame_support::{
-	assert_noop, assert_ok, dispatch::RawOrigin, traits::ChangeMembers, weights::Weight, BoundedVec,
+	assert_noop, assert_ok,
+	dispatch::{GetDispatchInfo, RawOrigin},
+	traits::ChangeMembers,
+	weights::Weight,
+	BoundedVec,
 };
 use pallet_collective::ProposalOf;
 use parity_scale_codec::Encode;
@@ -20,7 +25,7 @@ use serial_test::serial;
 use sp_runtime::{BuildStorage, DispatchError::BadOrigin};
 
 use crate::{
-	CurrentSchemaIdentifierMaximum, Error, Event as AnnouncementEvent,
+	CurrentSchemaIdentifierMaximum, Error, Event as AnnouncementEvent, GenesisSchema,
 	GovernanceSchemaModelMaxBytes, SchemaDescriptor, SchemaName, SchemaNamePayload,
 	SchemaNamespace, SchemaVersionId, MAX_NUMBER_OF_VERSIONS,
 };
@@ -137,6 +142,20 @@ fn reject_incorrect_parquet_model() {
 	})
 }
 
+#[test]
+fn reject_parquet_model_with_duplicate_columns() {
+	new_test_ext().execute_with(|| {
+		let test_str_raw = r#"[{"name": "Foo", "column_type": "BOOLEAN", "compression": "UNCOMPRESSED", "bloom_filter": true}, {"name": "Foo", "column_type": "STRING", "compression": "GZIP", "bloom_filter": false}]"#;
+		assert_noop!(
+			SchemasPallet::ensure_valid_model(
+				&ModelType::Parquet,
+				&create_bounded_schema_vec(test_str_raw)
+			),
+			Error::<Test>::InvalidSchema
+		);
+	})
+}
+
 #[test]
 fn serialize_parquet_model() {
 	new_test_ext().execute_with(|| {
@@ -230,6 +249,45 @@ fn dsnp_broadcast() {
 	assert_ok!(result);
 }
 
+/// FileDescriptorSet { file: [{ name: "a.proto", message_type: [{ name: "A" }] }] }
+const PROTOBUF_DESCRIPTOR_SET_HEX: &str = "0x0a0e0a07612e70726f746f22030a0141";
+
+#[test]
+fn validate_json_schema_model() {
+	new_test_ext().execute_with(|| {
+		let valid = r#"{"$schema": "https://json-schema.org/draft/2020-12/schema", "type": "object", "properties": {"url": {"type": "string"}}, "required": ["url"]}"#;
+		let invalid = r#"{"type": "object", "required": "url"}"#;
+
+		assert_ok!(SchemasPallet::ensure_valid_model(
+			&ModelType::JsonSchema,
+			&create_bounded_schema_vec(valid)
+		));
+		assert_noop!(
+			SchemasPallet::ensure_valid_model(&ModelType::JsonSchema, &create_bounded_schema_vec(invalid)),
+			Error::<Test>::InvalidSchema
+		);
+	})
+}
+
+#[test]
+fn validate_protobuf_model() {
+	new_test_ext().execute_with(|| {
+		let valid = hex::decode(&PROTOBUF_DESCRIPTOR_SET_HEX[2..]).unwrap();
+
+		assert_ok!(SchemasPallet::ensure_valid_model(
+			&ModelType::Protobuf,
+			&BoundedVec::try_from(valid).unwrap()
+		));
+		assert_noop!(
+			SchemasPallet::ensure_valid_model(
+				&ModelType::Protobuf,
+				&create_bounded_schema_vec(r#"{"name": "A"}"#)
+			),
+			Error::<Test>::InvalidSchema
+		);
+	})
+}
+
 #[test]
 fn schema_name_try_parse_with_strict_invalid_names_should_fail() {
 	new_test_ext().execute_with(|| {
@@ -432,19 +490,21 @@ fn schema_version_id_add_should_work() {
 		assert_ok!(val.add::<Test>(schema_id_1));
 		assert_ok!(val.add::<Test>(schema_id_2));
 
-		let response = val.convert_to_response(&schema_name);
+		let response = val.convert_to_response::<Test>(&schema_name);
 		assert_eq!(
 			response,
 			vec![
 				SchemaVersionResponse {
 					schema_id: schema_id_1,
 					schema_version: 1,
-					schema_name: schema_name.clone().get_combined_name()
+					schema_name: schema_name.clone().get_combined_name(),
+					status: SchemaStatus::Active,
 				},
 				SchemaVersionResponse {
 					schema_id: schema_id_2,
 					schema_version: 2,
-					schema_name: schema_name.get_combined_name()
+					schema_name: schema_name.get_combined_name(),
+					status: SchemaStatus::Active,
 				},
 			]
 		);
@@ -539,7 +599,8 @@ fn create_schema_v3_happy_path() {
 			Some(vec![SchemaVersionResponse {
 				schema_id: 1,
 				schema_name: schema_name.into_inner(),
-				schema_version: 1
+				schema_version: 1,
+				status: SchemaStatus::Active,
 			}])
 		);
 	})
@@ -595,17 +656,20 @@ fn create_schema_v3_increments_schema_id_and_version_for_same_name() {
 				SchemaVersionResponse {
 					schema_id: 1,
 					schema_name: schema_name.clone().into_inner(),
-					schema_version: 1
+					schema_version: 1,
+					status: SchemaStatus::Active,
 				},
 				SchemaVersionResponse {
 					schema_id: 2,
 					schema_name: schema_name.clone().into_inner(),
-					schema_version: 2
+					schema_version: 2,
+					status: SchemaStatus::Active,
 				},
 				SchemaVersionResponse {
 					schema_id: 3,
 					schema_name: schema_name.into_inner(),
-					schema_version: 3
+					schema_version: 3,
+					status: SchemaStatus::Active,
 				}
 			])
 		);
@@ -656,12 +720,14 @@ fn get_schema_versions_for_namespace_should_return_all_descriptors() {
 				SchemaVersionResponse {
 					schema_id: 1,
 					schema_name: schema_name_1.into_inner(),
-					schema_version: 1
+					schema_version: 1,
+					status: SchemaStatus::Active,
 				},
 				SchemaVersionResponse {
 					schema_id: 2,
 					schema_name: schema_name_2.into_inner(),
-					schema_version: 1
+					schema_version: 1,
+					status: SchemaStatus::Active,
 				},
 			])
 		);
@@ -700,7 +766,8 @@ fn create_schema_via_governance_v2_happy_path() {
 			Some(vec![SchemaVersionResponse {
 				schema_id: 1,
 				schema_name: schema_name.into_inner(),
-				schema_version: 1
+				schema_version: 1,
+				status: SchemaStatus::Active,
 			}])
 		);
 	})
@@ -1047,7 +1114,8 @@ fn propose_to_create_schema_name_happy_path() {
 			Some(vec![SchemaVersionResponse {
 				schema_id: 1,
 				schema_name: schema_name.into_inner(),
-				schema_version: 1
+				schema_version: 1,
+				status: SchemaStatus::Active,
 			}])
 		);
 	})
@@ -1080,3 +1148,54 @@ fn genesis_config_build_genesis_schemas() {
 		assert!(res.is_some());
 	});
 }
+
+#[test]
+fn genesis_config_build_decodes_protobuf_schemas() {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	crate::GenesisConfig::<Test> {
+		initial_schemas: vec![GenesisSchema {
+			model_type: ModelType::Protobuf,
+			payload_location: PayloadLocation::IPFS,
+			model: PROTOBUF_DESCRIPTOR_SET_HEX.to_string(),
+			name: "test.protobuf".to_string(),
+			settings: vec![],
+		}],
+		..Default::default()
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	let mut ext: sp_io::TestExternalities = t.into();
+
+	ext.execute_with(|| {
+		let res = SchemasPallet::get_schema_by_id(1).unwrap();
+		assert_eq!(res.model_type, ModelType::Protobuf);
+		assert_eq!(res.model, hex::decode(&PROTOBUF_DESCRIPTOR_SET_HEX[2..]).unwrap());
+	});
+}
+
+#[test]
+fn create_schema_v3_weight_includes_validating_non_avro_models() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let weight_of = |model_type: ModelType| {
+			crate::Call::<Test>::create_schema_v3 {
+				model: create_bounded_schema_vec(r#"{"type": "object"}"#),
+				model_type,
+				payload_location: PayloadLocation::OnChain,
+				settings: BoundedVec::default(),
+				schema_name: None,
+			}
+			.get_dispatch_info()
+			.weight
+		};
+
+		// act
+		let avro_weight = weight_of(ModelType::AvroBinary);
+
+		// assert
+		for model_type in [ModelType::JsonSchema, ModelType::Protobuf, ModelType::Parquet] {
+			assert!(weight_of(model_type).ref_time() > avro_weight.ref_time());
+		}
+	});
+}
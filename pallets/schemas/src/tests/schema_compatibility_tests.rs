@@ -0,0 +1,228 @@
+use common_primitives::schema::{ModelType, PayloadLocation, SchemaCompatibilityMode};
+use frame_support::{assert_noop, assert_ok, dispatch::RawOrigin, BoundedVec};
+
+use crate::{compatibility, Error, SchemaInfos, SchemaNamePayload};
+
+use super::mock::*;
+
+const PREVIOUS_VERSION: &str =
+	r#"{"type": "record", "name": "post", "fields": [{"name": "id", "type": "int"}]}"#;
+const WITH_OPTIONAL_FIELD: &str = r#"{"type": "record", "name": "post", "fields": [{"name": "id", "type": "int"}, {"name": "url", "type": "string", "default": ""}]}"#;
+const WITH_REQUIRED_FIELD: &str = r#"{"type": "record", "name": "post", "fields": [{"name": "id", "type": "int"}, {"name": "url", "type": "string"}]}"#;
+const WITHOUT_FIELDS: &str = r#"{"type": "record", "name": "post", "fields": []}"#;
+const WITH_PROMOTED_FIELD: &str =
+	r#"{"type": "record", "name": "post", "fields": [{"name": "id", "type": "long"}]}"#;
+const WITH_CHANGED_FIELD: &str =
+	r#"{"type": "record", "name": "post", "fields": [{"name": "id", "type": "string"}]}"#;
+const WITH_NULLABLE_FIELD: &str =
+	r#"{"type": "record", "name": "post", "fields": [{"name": "id", "type": ["null", "int"]}]}"#;
+const RENAMED: &str =
+	r#"{"type": "record", "name": "reply", "fields": [{"name": "id", "type": "int"}]}"#;
+
+fn schema_name() -> SchemaNamePayload {
+	SchemaNamePayload::try_from("namespace.descriptor".to_string().into_bytes())
+		.expect("should work")
+}
+
+fn create_schema_version(
+	model: &str,
+	compatibility: Option<SchemaCompatibilityMode>,
+) -> sp_runtime::DispatchResult {
+	SchemasPallet::create_schema_via_governance_v3(
+		RawOrigin::Root.into(),
+		test_public(1),
+		create_bounded_schema_vec(model),
+		ModelType::AvroBinary,
+		PayloadLocation::OnChain,
+		BoundedVec::default(),
+		Some(schema_name()),
+		compatibility,
+	)
+}
+
+#[test]
+fn is_compatible_follows_avro_schema_resolution() {
+	use SchemaCompatibilityMode::*;
+	let cases = [
+		(WITH_OPTIONAL_FIELD, Full, true),
+		(WITH_REQUIRED_FIELD, Backward, false),
+		(WITH_REQUIRED_FIELD, Forward, true),
+		(WITHOUT_FIELDS, Backward, true),
+		(WITHOUT_FIELDS, Forward, false),
+		(WITH_PROMOTED_FIELD, Backward, true),
+		(WITH_PROMOTED_FIELD, Forward, false),
+		(WITH_CHANGED_FIELD, Backward, false),
+		(WITH_NULLABLE_FIELD, Backward, true),
+		(WITH_NULLABLE_FIELD, Forward, false),
+	];
+
+	for (new_version, mode, expected) in cases {
+		// the on-chain check should agree with apache_avro
+		let previous_schema =
+			common_helpers::avro::fingerprint_raw_schema(PREVIOUS_VERSION).unwrap().0;
+		let new_schema = common_helpers::avro::fingerprint_raw_schema(new_version).unwrap().0;
+		assert_eq!(
+			common_helpers::avro::is_compatible_schema(&previous_schema, &new_schema, mode),
+			expected,
+			"apache_avro {:?} {}",
+			mode,
+			new_version
+		);
+		assert_eq!(
+			compatibility::is_compatible(PREVIOUS_VERSION.as_bytes(), new_version.as_bytes(), mode),
+			expected,
+			"{:?} {}",
+			mode,
+			new_version
+		);
+	}
+}
+
+#[test]
+fn is_compatible_resolves_named_and_recursive_types() {
+	let previous = r#"{"type": "record", "name": "node", "fields": [{"name": "kind", "type": {"type": "enum", "name": "kind", "symbols": ["A", "B"]}}, {"name": "next", "type": ["null", "node"]}]}"#;
+	let with_symbol = r#"{"type": "record", "name": "node", "fields": [{"name": "kind", "type": {"type": "enum", "name": "kind", "symbols": ["A", "B", "C"]}}, {"name": "next", "type": ["null", "node"]}]}"#;
+
+	assert!(compatibility::is_compatible(
+		previous.as_bytes(),
+		with_symbol.as_bytes(),
+		SchemaCompatibilityMode::Backward
+	));
+	assert!(!compatibility::is_compatible(
+		previous.as_bytes(),
+		with_symbol.as_bytes(),
+		SchemaCompatibilityMode::Forward
+	));
+	assert!(!compatibility::is_compatible(
+		PREVIOUS_VERSION.as_bytes(),
+		RENAMED.as_bytes(),
+		SchemaCompatibilityMode::Backward
+	));
+}
+
+#[test]
+fn create_schema_via_governance_v3_with_compatible_version_records_compatibility() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		assert_ok!(SchemasPallet::set_max_schema_model_bytes(RawOrigin::Root.into(), 1000));
+		assert_ok!(create_schema_version(PREVIOUS_VERSION, None));
+
+		// act
+		assert_ok!(create_schema_version(WITH_OPTIONAL_FIELD, Some(SchemaCompatibilityMode::Full)));
+
+		// assert
+		assert_eq!(SchemaInfos::<Test>::get(1).unwrap().compatibility, None);
+		assert_eq!(
+			SchemaInfos::<Test>::get(2).unwrap().compatibility,
+			Some(SchemaCompatibilityMode::Full)
+		);
+		let versions =
+			SchemasPallet::get_schema_versions("namespace.descriptor".as_bytes().to_vec()).unwrap();
+		assert_eq!(versions.len(), 2);
+	})
+}
+
+#[test]
+fn create_schema_via_governance_v3_with_incompatible_version_fails() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		assert_ok!(SchemasPallet::set_max_schema_model_bytes(RawOrigin::Root.into(), 1000));
+		assert_ok!(create_schema_version(PREVIOUS_VERSION, None));
+
+		// act & assert
+		assert_noop!(
+			create_schema_version(WITH_REQUIRED_FIELD, Some(SchemaCompatibilityMode::Backward)),
+			Error::<Test>::IncompatibleSchemaVersion
+		);
+		assert_noop!(
+			create_schema_version(
+				r#"{"name": "Doe", "type": "lost"}"#,
+				Some(SchemaCompatibilityMode::Forward)
+			),
+			Error::<Test>::IncompatibleSchemaVersion
+		);
+		assert_ok!(create_schema_version(WITH_REQUIRED_FIELD, None));
+	})
+}
+
+#[test]
+fn create_schema_via_governance_v3_for_first_version_has_nothing_to_check() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(SchemasPallet::set_max_schema_model_bytes(RawOrigin::Root.into(), 1000));
+
+		assert_ok!(create_schema_version(PREVIOUS_VERSION, Some(SchemaCompatibilityMode::Full)));
+
+		assert_eq!(
+			SchemaInfos::<Test>::get(1).unwrap().compatibility,
+			Some(SchemaCompatibilityMode::Full)
+		);
+	})
+}
+
+#[test]
+fn create_schema_with_compatibility_for_non_avro_model_fails() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(SchemasPallet::set_max_schema_model_bytes(RawOrigin::Root.into(), 1000));
+		let model = create_bounded_schema_vec(
+			r#"[{"name": "Foo", "column_type": "BOOLEAN", "compression": "UNCOMPRESSED", "bloom_filter": true}]"#,
+		);
+
+		assert_noop!(
+			SchemasPallet::create_schema_via_governance_v3(
+				RawOrigin::Root.into(),
+				test_public(1),
+				model.clone(),
+				ModelType::Parquet,
+				PayloadLocation::IPFS,
+				BoundedVec::default(),
+				Some(schema_name()),
+				Some(SchemaCompatibilityMode::Backward),
+			),
+			Error::<Test>::InvalidSetting
+		);
+		assert_noop!(
+			SchemasPallet::propose_to_create_schema_v3(
+				test_origin_signed(1),
+				model,
+				ModelType::Parquet,
+				PayloadLocation::IPFS,
+				BoundedVec::default(),
+				Some(schema_name()),
+				Some(SchemaCompatibilityMode::Backward),
+			),
+			Error::<Test>::InvalidSetting
+		);
+	})
+}
+
+#[test]
+fn propose_to_create_schema_v3_creates_council_proposal() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		assert_ok!(SchemasPallet::set_max_schema_model_bytes(RawOrigin::Root.into(), 1000));
+
+		// act
+		assert_ok!(SchemasPallet::propose_to_create_schema_v3(
+			test_origin_signed(5),
+			create_bounded_schema_vec(WITH_OPTIONAL_FIELD),
+			ModelType::AvroBinary,
+			PayloadLocation::OnChain,
+			BoundedVec::default(),
+			Some(schema_name()),
+			Some(SchemaCompatibilityMode::Full),
+		));
+
+		// assert
+		let proposed_events = System::events()
+			.iter()
+			.filter(|event| {
+				matches!(
+					event.event,
+					RuntimeEvent::Council(pallet_collective::Event::Proposed { .. })
+				)
+			})
+			.count();
+		assert_eq!(proposed_events, 1);
+		assert_eq!(SchemaInfos::<Test>::get(1), None);
+	})
+}
@@ -0,0 +1,134 @@
+use common_primitives::schema::{ModelType, PayloadLocation, SchemaId, SchemaStatus};
+use frame_support::{assert_noop, assert_ok, dispatch::RawOrigin, BoundedVec};
+use sp_runtime::DispatchError::BadOrigin;
+
+use crate::{Error, Event as AnnouncementEvent, SchemaNamePayload};
+
+use super::mock::*;
+
+/// Creates a schema named `namespace.descriptor` and returns its id
+fn create_named_schema() -> SchemaId {
+	sudo_set_max_schema_size();
+	let schema_name = SchemaNamePayload::try_from("namespace.descriptor".to_string().into_bytes())
+		.expect("should work");
+	assert_ok!(SchemasPallet::create_schema_v3(
+		test_origin_signed(1),
+		create_bounded_schema_vec(r#"{"name": "Doe", "type": "lost"}"#),
+		ModelType::AvroBinary,
+		PayloadLocation::OnChain,
+		BoundedVec::default(),
+		Some(schema_name),
+	));
+	1
+}
+
+#[test]
+fn new_schema_is_active() {
+	new_test_ext().execute_with(|| {
+		let schema_id = create_named_schema();
+
+		let schema = SchemasPallet::get_schema_by_id(schema_id).unwrap();
+		assert_eq!(schema.status, SchemaStatus::Active);
+	})
+}
+
+#[test]
+fn set_schema_status_via_governance_updates_responses_and_emits_event() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let schema_id = create_named_schema();
+
+		// act
+		assert_ok!(SchemasPallet::set_schema_status_via_governance(
+			RawOrigin::Root.into(),
+			schema_id,
+			SchemaStatus::Deprecated
+		));
+
+		// assert
+		System::assert_last_event(
+			AnnouncementEvent::SchemaStatusUpdated { schema_id, status: SchemaStatus::Deprecated }
+				.into(),
+		);
+		assert_eq!(
+			SchemasPallet::get_schema_by_id(schema_id).unwrap().status,
+			SchemaStatus::Deprecated
+		);
+		assert_eq!(
+			SchemasPallet::get_schema_info_by_id(schema_id).unwrap().status,
+			SchemaStatus::Deprecated
+		);
+		let versions =
+			SchemasPallet::get_schema_versions("namespace.descriptor".as_bytes().to_vec()).unwrap();
+		assert_eq!(versions[0].status, SchemaStatus::Deprecated);
+	})
+}
+
+#[test]
+fn set_schema_status_via_governance_requires_governance_origin() {
+	new_test_ext().execute_with(|| {
+		let schema_id = create_named_schema();
+
+		assert_noop!(
+			SchemasPallet::set_schema_status_via_governance(
+				test_origin_signed(1),
+				schema_id,
+				SchemaStatus::Retired
+			),
+			BadOrigin
+		);
+	})
+}
+
+#[test]
+fn set_schema_status_of_non_existing_schema_fails() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			SchemasPallet::set_schema_status_via_governance(
+				RawOrigin::Root.into(),
+				1,
+				SchemaStatus::Retired
+			),
+			Error::<Test>::SchemaIdDoesNotExist
+		);
+		assert_noop!(
+			SchemasPallet::propose_to_set_schema_status(
+				test_origin_signed(1),
+				1,
+				SchemaStatus::Retired
+			),
+			Error::<Test>::SchemaIdDoesNotExist
+		);
+	})
+}
+
+#[test]
+fn propose_to_set_schema_status_creates_council_proposal() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let schema_id = create_named_schema();
+
+		// act
+		assert_ok!(SchemasPallet::propose_to_set_schema_status(
+			test_origin_signed(5),
+			schema_id,
+			SchemaStatus::Retired
+		));
+
+		// assert
+		let proposed_events = System::events()
+			.iter()
+			.filter(|event| {
+				matches!(
+					event.event,
+					RuntimeEvent::Council(pallet_collective::Event::Proposed { .. })
+				)
+			})
+			.count();
+		assert_eq!(proposed_events, 1);
+		assert_eq!(
+			SchemasPallet::get_schema_by_id(schema_id).unwrap().status,
+			SchemaStatus::Active
+		);
+	})
+}
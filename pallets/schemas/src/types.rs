@@ -1,8 +1,8 @@
 //! Types for the Schema Pallet
-use crate::{Config, Error};
+use crate::{Config, Error, SchemaStatuses};
 use common_primitives::schema::{
-	ModelType, PayloadLocation, SchemaId, SchemaSetting, SchemaSettings, SchemaVersion,
-	SchemaVersionResponse,
+	ModelType, PayloadLocation, SchemaCompatibilityMode, SchemaId, SchemaSetting, SchemaSettings,
+	SchemaVersion, SchemaVersionResponse,
 };
 use frame_support::{ensure, pallet_prelude::ConstU32, traits::StorageVersion, BoundedVec};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
@@ -15,7 +15,7 @@ use frame_support::traits::Len;
 use sp_std::{vec, vec::*};
 
 /// Current storage version of the schemas pallet.
-pub const SCHEMA_STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+pub const SCHEMA_STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
 
 /// The maximum size of schema name including all parts
 pub const SCHEMA_NAME_BYTES_MAX: u32 = 32; // Hard limit of 32 bytes
@@ -46,7 +46,7 @@ pub struct GenesisSchema {
 	pub model_type: ModelType,
 	/// The payload location
 	pub payload_location: PayloadLocation,
-	/// The Payload Model
+	/// The Payload Model. Protobuf descriptor sets are binary, so they are hex encoded
 	pub model: String,
 	/// Schema Full Name: {Namespace}.{Descriptor}
 	pub name: String,
@@ -54,6 +54,16 @@ pub struct GenesisSchema {
 	pub settings: Vec<SchemaSetting>,
 }
 
+impl GenesisSchema {
+	/// The bytes of the model, decoding the hex of Protobuf models
+	pub fn model_bytes(&self) -> Result<Vec<u8>, hex::FromHexError> {
+		match self.model_type {
+			ModelType::Protobuf => hex::decode(self.model.trim_start_matches("0x")),
+			_ => Ok(self.model.clone().into_bytes()),
+		}
+	}
+}
+
 #[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq, MaxEncodedLen)]
 /// A structure defining a Schema information (excluding the payload)
 pub struct SchemaInfo {
@@ -65,6 +75,8 @@ pub struct SchemaInfo {
 	pub settings: SchemaSettings,
 	/// Defines if a schema has a name or not
 	pub has_name: bool,
+	/// The compatibility with the previous version of the schema name declared at creation
+	pub compatibility: Option<SchemaCompatibilityMode>,
 }
 
 #[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq, MaxEncodedLen)]
@@ -143,6 +155,15 @@ impl SchemaName {
 		Ok(SchemaName { namespace, descriptor })
 	}
 
+	/// parses and verifies a payload holding only a namespace
+	pub fn try_parse_namespace<T: Config>(
+		payload: SchemaNamePayload,
+	) -> Result<SchemaNamespace, DispatchError> {
+		let parsed_name = Self::try_parse::<T>(payload, false)?;
+		ensure!(!parsed_name.descriptor_exists(), Error::<T>::InvalidSchemaNameStructure);
+		Ok(parsed_name.namespace)
+	}
+
 	/// get the combined name namespace.descriptor
 	pub fn get_combined_name(&self) -> Vec<u8> {
 		vec![
@@ -177,7 +198,10 @@ impl SchemaVersionId {
 	}
 
 	/// convert into a response vector
-	pub fn convert_to_response(&self, schema_name: &SchemaName) -> Vec<SchemaVersionResponse> {
+	pub fn convert_to_response<T: Config>(
+		&self,
+		schema_name: &SchemaName,
+	) -> Vec<SchemaVersionResponse> {
 		self.ids
 			.iter()
 			.enumerate()
@@ -185,6 +209,7 @@ impl SchemaVersionId {
 				schema_name: schema_name.get_combined_name(),
 				schema_id: *schema_id,
 				schema_version: (index + 1) as SchemaVersion,
+				status: SchemaStatuses::<T>::get(schema_id),
 			})
 			.collect()
 	}
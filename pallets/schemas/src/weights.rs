@@ -43,6 +43,20 @@ pub trait WeightInfo {
 	fn propose_to_create_schema_v2(m: u32, ) -> Weight;
 	fn propose_to_create_schema_name() -> Weight;
 	fn create_schema_name_via_governance() -> Weight;
+	fn propose_to_set_schema_status() -> Weight;
+	fn set_schema_status_via_governance() -> Weight;
+	fn create_schema_via_governance_v3(m: u32, ) -> Weight;
+	fn propose_to_claim_namespace() -> Weight;
+	fn claim_namespace_via_governance() -> Weight;
+	fn add_namespace_delegate() -> Weight;
+	fn remove_namespace_delegate() -> Weight;
+	fn create_schema_in_namespace(m: u32, ) -> Weight;
+	fn create_schema_name_in_namespace() -> Weight;
+	fn validate_json_schema_model(m: u32, ) -> Weight;
+	fn validate_protobuf_model(m: u32, ) -> Weight;
+	fn validate_parquet_model(m: u32, ) -> Weight;
+	fn release_namespace_via_governance() -> Weight;
+	fn transfer_namespace_via_governance() -> Weight;
 }
 
 /// Weights for `pallet_schemas` using the Substrate node and recommended hardware.
@@ -240,6 +254,267 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Schemas::SchemaInfos` (r:1 w:0)
+	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(15), added: 2490, mode: `MaxEncodedLen`)
+	/// Storage: `Council::Members` (r:1 w:0)
+	/// Proof: `Council::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::ProposalOf` (r:1 w:1)
+	/// Proof: `Council::ProposalOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Proposals` (r:1 w:1)
+	/// Proof: `Council::Proposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::ProposalCount` (r:1 w:1)
+	/// Proof: `Council::ProposalCount` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Voting` (r:0 w:1)
+	/// Proof: `Council::Voting` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn propose_to_set_schema_status() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `429`
+		//  Estimated: `4389`
+		// Minimum execution time: 21_956_000 picoseconds.
+		Weight::from_parts(22_871_000, 4389)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Schemas::SchemaInfos` (r:1 w:0)
+	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(15), added: 2490, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaStatuses` (r:0 w:1)
+	/// Proof: `Schemas::SchemaStatuses` (`max_values`: None, `max_size`: Some(11), added: 2486, mode: `MaxEncodedLen`)
+	fn set_schema_status_via_governance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `169`
+		//  Estimated: `3480`
+		// Minimum execution time: 9_479_000 picoseconds.
+		Weight::from_parts(9_874_000, 3480)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Schemas::GovernanceSchemaModelMaxBytes` (r:1 w:0)
+	/// Proof: `Schemas::GovernanceSchemaModelMaxBytes` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaNameToIds` (r:1 w:1)
+	/// Proof: `Schemas::SchemaNameToIds` (`max_values`: None, `max_size`: Some(602), added: 3077, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaInfos` (r:1 w:1)
+	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaPayloads` (r:1 w:1)
+	/// Proof: `Schemas::SchemaPayloads` (`max_values`: None, `max_size`: Some(65514), added: 67989, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::CurrentSchemaIdentifierMaximum` (r:1 w:1)
+	/// Proof: `Schemas::CurrentSchemaIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[16, 65499]`.
+	fn create_schema_via_governance_v3(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `65720`
+		//  Estimated: `68979`
+		// Minimum execution time: 29_994_000 picoseconds.
+		Weight::from_parts(31_244_000, 68979)
+			// Standard Error: 13_053
+			.saturating_add(Weight::from_parts(91_372, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyScopes` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyScopes` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:1 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderRetirements` (r:1 w:0)
+	/// Proof: `Msa::ProviderRetirements` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NamespaceOwners` (r:1 w:0)
+	/// Proof: `Schemas::NamespaceOwners` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Council::Members` (r:1 w:0)
+	/// Proof: `Council::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::ProposalOf` (r:1 w:1)
+	/// Proof: `Council::ProposalOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Proposals` (r:1 w:1)
+	/// Proof: `Council::Proposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::ProposalCount` (r:1 w:1)
+	/// Proof: `Council::ProposalCount` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Voting` (r:0 w:1)
+	/// Proof: `Council::Voting` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn propose_to_claim_namespace() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `4477`
+		// Minimum execution time: 30_222_000 picoseconds.
+		Weight::from_parts(31_482_000, 4477)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:1 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderRetirements` (r:1 w:0)
+	/// Proof: `Msa::ProviderRetirements` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NamespaceOwners` (r:1 w:1)
+	/// Proof: `Schemas::NamespaceOwners` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	fn claim_namespace_via_governance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `247`
+		//  Estimated: `3520`
+		// Minimum execution time: 14_278_000 picoseconds.
+		Weight::from_parts(14_873_000, 3520)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyScopes` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyScopes` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:2 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderRetirements` (r:2 w:0)
+	/// Proof: `Msa::ProviderRetirements` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NamespaceOwners` (r:1 w:0)
+	/// Proof: `Schemas::NamespaceOwners` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NamespaceDelegates` (r:1 w:1)
+	/// Proof: `Schemas::NamespaceDelegates` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
+	fn add_namespace_delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `391`
+		//  Estimated: `6006`
+		// Minimum execution time: 26_033_000 picoseconds.
+		Weight::from_parts(27_118_000, 6006)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyScopes` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyScopes` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:1 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderRetirements` (r:1 w:0)
+	/// Proof: `Msa::ProviderRetirements` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NamespaceOwners` (r:1 w:0)
+	/// Proof: `Schemas::NamespaceOwners` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NamespaceDelegates` (r:1 w:1)
+	/// Proof: `Schemas::NamespaceDelegates` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
+	fn remove_namespace_delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `430`
+		//  Estimated: `3568`
+		// Minimum execution time: 22_694_000 picoseconds.
+		Weight::from_parts(23_640_000, 3568)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyScopes` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyScopes` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:1 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderRetirements` (r:1 w:0)
+	/// Proof: `Msa::ProviderRetirements` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NamespaceOwners` (r:1 w:0)
+	/// Proof: `Schemas::NamespaceOwners` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NamespaceDelegates` (r:1 w:0)
+	/// Proof: `Schemas::NamespaceDelegates` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::GovernanceSchemaModelMaxBytes` (r:1 w:0)
+	/// Proof: `Schemas::GovernanceSchemaModelMaxBytes` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::CurrentSchemaIdentifierMaximum` (r:1 w:1)
+	/// Proof: `Schemas::CurrentSchemaIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaNameToIds` (r:1 w:1)
+	/// Proof: `Schemas::SchemaNameToIds` (`max_values`: None, `max_size`: Some(602), added: 3077, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaInfos` (r:0 w:1)
+	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaPayloads` (r:0 w:1)
+	/// Proof: `Schemas::SchemaPayloads` (`max_values`: None, `max_size`: Some(65514), added: 67989, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[16, 65499]`.
+	fn create_schema_in_namespace(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `521`
+		//  Estimated: `4562`
+		// Minimum execution time: 34_763_000 picoseconds.
+		Weight::from_parts(36_212_000, 4562)
+			// Standard Error: 4_986
+			.saturating_add(Weight::from_parts(34_906, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyScopes` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyScopes` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:1 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderRetirements` (r:1 w:0)
+	/// Proof: `Msa::ProviderRetirements` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NamespaceOwners` (r:1 w:0)
+	/// Proof: `Schemas::NamespaceOwners` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NamespaceDelegates` (r:1 w:0)
+	/// Proof: `Schemas::NamespaceDelegates` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaInfos` (r:1 w:0)
+	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaNameToIds` (r:1 w:1)
+	/// Proof: `Schemas::SchemaNameToIds` (`max_values`: None, `max_size`: Some(602), added: 3077, mode: `MaxEncodedLen`)
+	fn create_schema_name_in_namespace() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `4562`
+		// Minimum execution time: 29_236_000 picoseconds.
+		Weight::from_parts(30_455_000, 4562)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// The range of component `m` is `[16, 65499]`.
+	fn validate_json_schema_model(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 2_796_000 picoseconds.
+		Weight::from_parts(2_913_000, 0)
+			// Standard Error: 7_481
+			.saturating_add(Weight::from_parts(52_370, 0).saturating_mul(m.into()))
+	}
+	/// The range of component `m` is `[16, 65499]`.
+	fn validate_protobuf_model(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 1_426_000 picoseconds.
+		Weight::from_parts(1_486_000, 0)
+			// Standard Error: 4_134
+			.saturating_add(Weight::from_parts(28_941, 0).saturating_mul(m.into()))
+	}
+	/// The range of component `m` is `[16, 65499]`.
+	fn validate_parquet_model(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 2_115_000 picoseconds.
+		Weight::from_parts(2_204_000, 0)
+			// Standard Error: 6_801
+			.saturating_add(Weight::from_parts(47_608, 0).saturating_mul(m.into()))
+	}
+	/// Storage: `Schemas::NamespaceOwners` (r:1 w:1)
+	/// Proof: `Schemas::NamespaceOwners` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::OwnedNamespaces` (r:0 w:1)
+	/// Proof: `Schemas::OwnedNamespaces` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
+	fn release_namespace_via_governance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `161`
+		//  Estimated: `3520`
+		// Minimum execution time: 13_019_000 picoseconds.
+		Weight::from_parts(13_562_000, 3520)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:1 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderRetirements` (r:1 w:0)
+	/// Proof: `Msa::ProviderRetirements` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NamespaceOwners` (r:1 w:1)
+	/// Proof: `Schemas::NamespaceOwners` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::OwnedNamespaces` (r:0 w:2)
+	/// Proof: `Schemas::OwnedNamespaces` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
+	fn transfer_namespace_via_governance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `261`
+		//  Estimated: `3520`
+		// Minimum execution time: 17_196_000 picoseconds.
+		Weight::from_parts(17_913_000, 3520)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -436,6 +711,267 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Schemas::SchemaInfos` (r:1 w:0)
+	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(15), added: 2490, mode: `MaxEncodedLen`)
+	/// Storage: `Council::Members` (r:1 w:0)
+	/// Proof: `Council::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::ProposalOf` (r:1 w:1)
+	/// Proof: `Council::ProposalOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Proposals` (r:1 w:1)
+	/// Proof: `Council::Proposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::ProposalCount` (r:1 w:1)
+	/// Proof: `Council::ProposalCount` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Voting` (r:0 w:1)
+	/// Proof: `Council::Voting` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn propose_to_set_schema_status() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `429`
+		//  Estimated: `4389`
+		// Minimum execution time: 21_956_000 picoseconds.
+		Weight::from_parts(22_871_000, 4389)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Schemas::SchemaInfos` (r:1 w:0)
+	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(15), added: 2490, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaStatuses` (r:0 w:1)
+	/// Proof: `Schemas::SchemaStatuses` (`max_values`: None, `max_size`: Some(11), added: 2486, mode: `MaxEncodedLen`)
+	fn set_schema_status_via_governance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `169`
+		//  Estimated: `3480`
+		// Minimum execution time: 9_479_000 picoseconds.
+		Weight::from_parts(9_874_000, 3480)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Schemas::GovernanceSchemaModelMaxBytes` (r:1 w:0)
+	/// Proof: `Schemas::GovernanceSchemaModelMaxBytes` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaNameToIds` (r:1 w:1)
+	/// Proof: `Schemas::SchemaNameToIds` (`max_values`: None, `max_size`: Some(602), added: 3077, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaInfos` (r:1 w:1)
+	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaPayloads` (r:1 w:1)
+	/// Proof: `Schemas::SchemaPayloads` (`max_values`: None, `max_size`: Some(65514), added: 67989, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::CurrentSchemaIdentifierMaximum` (r:1 w:1)
+	/// Proof: `Schemas::CurrentSchemaIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[16, 65499]`.
+	fn create_schema_via_governance_v3(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `65720`
+		//  Estimated: `68979`
+		// Minimum execution time: 29_994_000 picoseconds.
+		Weight::from_parts(31_244_000, 68979)
+			// Standard Error: 13_053
+			.saturating_add(Weight::from_parts(91_372, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyScopes` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyScopes` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:1 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderRetirements` (r:1 w:0)
+	/// Proof: `Msa::ProviderRetirements` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NamespaceOwners` (r:1 w:0)
+	/// Proof: `Schemas::NamespaceOwners` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Council::Members` (r:1 w:0)
+	/// Proof: `Council::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::ProposalOf` (r:1 w:1)
+	/// Proof: `Council::ProposalOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Proposals` (r:1 w:1)
+	/// Proof: `Council::Proposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::ProposalCount` (r:1 w:1)
+	/// Proof: `Council::ProposalCount` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Voting` (r:0 w:1)
+	/// Proof: `Council::Voting` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn propose_to_claim_namespace() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `4477`
+		// Minimum execution time: 30_222_000 picoseconds.
+		Weight::from_parts(31_482_000, 4477)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:1 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderRetirements` (r:1 w:0)
+	/// Proof: `Msa::ProviderRetirements` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NamespaceOwners` (r:1 w:1)
+	/// Proof: `Schemas::NamespaceOwners` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	fn claim_namespace_via_governance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `247`
+		//  Estimated: `3520`
+		// Minimum execution time: 14_278_000 picoseconds.
+		Weight::from_parts(14_873_000, 3520)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyScopes` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyScopes` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:2 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderRetirements` (r:2 w:0)
+	/// Proof: `Msa::ProviderRetirements` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NamespaceOwners` (r:1 w:0)
+	/// Proof: `Schemas::NamespaceOwners` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NamespaceDelegates` (r:1 w:1)
+	/// Proof: `Schemas::NamespaceDelegates` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
+	fn add_namespace_delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `391`
+		//  Estimated: `6006`
+		// Minimum execution time: 26_033_000 picoseconds.
+		Weight::from_parts(27_118_000, 6006)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyScopes` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyScopes` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:1 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderRetirements` (r:1 w:0)
+	/// Proof: `Msa::ProviderRetirements` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NamespaceOwners` (r:1 w:0)
+	/// Proof: `Schemas::NamespaceOwners` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NamespaceDelegates` (r:1 w:1)
+	/// Proof: `Schemas::NamespaceDelegates` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
+	fn remove_namespace_delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `430`
+		//  Estimated: `3568`
+		// Minimum execution time: 22_694_000 picoseconds.
+		Weight::from_parts(23_640_000, 3568)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyScopes` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyScopes` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:1 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderRetirements` (r:1 w:0)
+	/// Proof: `Msa::ProviderRetirements` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NamespaceOwners` (r:1 w:0)
+	/// Proof: `Schemas::NamespaceOwners` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NamespaceDelegates` (r:1 w:0)
+	/// Proof: `Schemas::NamespaceDelegates` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::GovernanceSchemaModelMaxBytes` (r:1 w:0)
+	/// Proof: `Schemas::GovernanceSchemaModelMaxBytes` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::CurrentSchemaIdentifierMaximum` (r:1 w:1)
+	/// Proof: `Schemas::CurrentSchemaIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaNameToIds` (r:1 w:1)
+	/// Proof: `Schemas::SchemaNameToIds` (`max_values`: None, `max_size`: Some(602), added: 3077, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaInfos` (r:0 w:1)
+	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaPayloads` (r:0 w:1)
+	/// Proof: `Schemas::SchemaPayloads` (`max_values`: None, `max_size`: Some(65514), added: 67989, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[16, 65499]`.
+	fn create_schema_in_namespace(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `521`
+		//  Estimated: `4562`
+		// Minimum execution time: 34_763_000 picoseconds.
+		Weight::from_parts(36_212_000, 4562)
+			// Standard Error: 4_986
+			.saturating_add(Weight::from_parts(34_906, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyScopes` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyScopes` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:1 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderRetirements` (r:1 w:0)
+	/// Proof: `Msa::ProviderRetirements` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NamespaceOwners` (r:1 w:0)
+	/// Proof: `Schemas::NamespaceOwners` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NamespaceDelegates` (r:1 w:0)
+	/// Proof: `Schemas::NamespaceDelegates` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaInfos` (r:1 w:0)
+	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaNameToIds` (r:1 w:1)
+	/// Proof: `Schemas::SchemaNameToIds` (`max_values`: None, `max_size`: Some(602), added: 3077, mode: `MaxEncodedLen`)
+	fn create_schema_name_in_namespace() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `4562`
+		// Minimum execution time: 29_236_000 picoseconds.
+		Weight::from_parts(30_455_000, 4562)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// The range of component `m` is `[16, 65499]`.
+	fn validate_json_schema_model(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 2_796_000 picoseconds.
+		Weight::from_parts(2_913_000, 0)
+			// Standard Error: 7_481
+			.saturating_add(Weight::from_parts(52_370, 0).saturating_mul(m.into()))
+	}
+	/// The range of component `m` is `[16, 65499]`.
+	fn validate_protobuf_model(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 1_426_000 picoseconds.
+		Weight::from_parts(1_486_000, 0)
+			// Standard Error: 4_134
+			.saturating_add(Weight::from_parts(28_941, 0).saturating_mul(m.into()))
+	}
+	/// The range of component `m` is `[16, 65499]`.
+	fn validate_parquet_model(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 2_115_000 picoseconds.
+		Weight::from_parts(2_204_000, 0)
+			// Standard Error: 6_801
+			.saturating_add(Weight::from_parts(47_608, 0).saturating_mul(m.into()))
+	}
+	/// Storage: `Schemas::NamespaceOwners` (r:1 w:1)
+	/// Proof: `Schemas::NamespaceOwners` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::OwnedNamespaces` (r:0 w:1)
+	/// Proof: `Schemas::OwnedNamespaces` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
+	fn release_namespace_via_governance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `161`
+		//  Estimated: `3520`
+		// Minimum execution time: 13_019_000 picoseconds.
+		Weight::from_parts(13_562_000, 3520)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Msa::ProviderToRegistryEntry` (r:1 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntry` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderRetirements` (r:1 w:0)
+	/// Proof: `Msa::ProviderRetirements` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NamespaceOwners` (r:1 w:1)
+	/// Proof: `Schemas::NamespaceOwners` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::OwnedNamespaces` (r:0 w:2)
+	/// Proof: `Schemas::OwnedNamespaces` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
+	fn transfer_namespace_via_governance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `261`
+		//  Estimated: `3520`
+		// Minimum execution time: 17_196_000 picoseconds.
+		Weight::from_parts(17_913_000, 3520)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
 }
 
 
@@ -569,4 +1105,172 @@ mod tests {
 				> 4562
 		);
 	}
+	#[test]
+	fn test_propose_to_set_schema_status() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 4389
+		);
+	}
+	#[test]
+	fn test_set_schema_status_via_governance() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3480
+		);
+	}
+	#[test]
+	fn test_create_schema_via_governance_v3() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 68979
+		);
+	}
+	#[test]
+	fn test_propose_to_claim_namespace() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 4477
+		);
+	}
+	#[test]
+	fn test_claim_namespace_via_governance() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3520
+		);
+	}
+	#[test]
+	fn test_add_namespace_delegate() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 6006
+		);
+	}
+	#[test]
+	fn test_remove_namespace_delegate() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3568
+		);
+	}
+	#[test]
+	fn test_create_schema_in_namespace() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 4562
+		);
+	}
+	#[test]
+	fn test_create_schema_name_in_namespace() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 4562
+		);
+	}
+	#[test]
+	fn test_validate_json_schema_model() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 0
+		);
+	}
+	#[test]
+	fn test_validate_protobuf_model() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 0
+		);
+	}
+	#[test]
+	fn test_validate_parquet_model() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 0
+		);
+	}
+	#[test]
+	fn test_release_namespace_via_governance() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3520
+		);
+	}
+	#[test]
+	fn test_transfer_namespace_via_governance() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3520
+		);
+	}
 }
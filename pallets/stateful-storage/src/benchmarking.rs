@@ -4,6 +4,7 @@ use crate::{types::ItemAction, Pallet as StatefulStoragePallet};
 use common_primitives::{
 	schema::{ModelType, PayloadLocation},
 	stateful_storage::{PageHash, PageId},
+	utils::wrap_binary_data,
 };
 use frame_benchmarking::{benchmarks, whitelisted_caller};
 use frame_support::assert_ok;
@@ -92,7 +93,7 @@ benchmarks! {
 		T::SchemaBenchmarkHelper::set_schema_count(schema_id - 1);
 		assert_ok!(create_schema::<T>(PayloadLocation::Itemized));
 		assert_ok!(T::MsaBenchmarkHelper::add_key(provider_msa_id.into(), caller.clone()));
-		assert_ok!(T::MsaBenchmarkHelper::set_delegation_relationship(provider_msa_id.into(), delegator_msa_id.into(), [schema_id].to_vec()));
+		assert_ok!(T::MsaBenchmarkHelper::set_sub_delegated_relationship(provider_msa_id.into(), delegator_msa_id.into(), [schema_id].to_vec()));
 
 		let actions = itemized_actions_populate::<T>(num_of_items, T::MaxItemizedBlobSizeBytes::get() as usize, 0);
 	}: {
@@ -118,7 +119,7 @@ benchmarks! {
 		T::SchemaBenchmarkHelper::set_schema_count(schema_id - 1);
 		assert_ok!(create_schema::<T>(PayloadLocation::Itemized));
 		assert_ok!(T::MsaBenchmarkHelper::add_key(provider_msa_id.into(), caller.clone()));
-		assert_ok!(T::MsaBenchmarkHelper::set_delegation_relationship(provider_msa_id.into(), delegator_msa_id.into(), [schema_id].to_vec()));
+		assert_ok!(T::MsaBenchmarkHelper::set_sub_delegated_relationship(provider_msa_id.into(), delegator_msa_id.into(), [schema_id].to_vec()));
 
 		for _ in 0..num_of_existing_items {
 			let actions = itemized_actions_populate::<T>(1, T::MaxItemizedBlobSizeBytes::get() as usize, 0);
@@ -159,7 +160,7 @@ benchmarks! {
 		T::SchemaBenchmarkHelper::set_schema_count(schema_id - 1);
 		assert_ok!(create_schema::<T>(PayloadLocation::Paginated));
 		assert_ok!(T::MsaBenchmarkHelper::add_key(provider_msa_id.into(), caller.clone()));
-		assert_ok!(T::MsaBenchmarkHelper::set_delegation_relationship(provider_msa_id.into(), delegator_msa_id.into(), [schema_id].to_vec()));
+		assert_ok!(T::MsaBenchmarkHelper::set_sub_delegated_relationship(provider_msa_id.into(), delegator_msa_id.into(), [schema_id].to_vec()));
 
 		let key = (schema_id, page_id);
 		StatefulChildTree::<T::KeyHasher>::write(&delegator_msa_id,
@@ -190,7 +191,7 @@ benchmarks! {
 		T::SchemaBenchmarkHelper::set_schema_count(schema_id - 1);
 		assert_ok!(create_schema::<T>(PayloadLocation::Paginated));
 		assert_ok!(T::MsaBenchmarkHelper::add_key(provider_msa_id.into(), caller.clone()));
-		assert_ok!(T::MsaBenchmarkHelper::set_delegation_relationship(provider_msa_id.into(), delegator_msa_id.into(), [schema_id].to_vec()));
+		assert_ok!(T::MsaBenchmarkHelper::set_sub_delegated_relationship(provider_msa_id.into(), delegator_msa_id.into(), [schema_id].to_vec()));
 
 		let key = (schema_id, page_id);
 		StatefulChildTree::<T::KeyHasher>::write(&delegator_msa_id,
@@ -394,6 +395,26 @@ benchmarks! {
 		assert!(page_result.is_none());
 	}
 
+	purge_msa_pages {
+		let n in 0 .. 1000;
+		let msa_id = 1u64;
+		let schema_id = constants::PAGINATED_SCHEMA;
+		let payload = vec![0u8; T::MaxPaginatedPageSizeBytes::get() as usize];
+		for page_id in 0 .. n {
+			let key: PaginatedKey = (schema_id, page_id as PageId);
+			StatefulChildTree::<T::KeyHasher>::write(&msa_id,
+				PALLET_STORAGE_PREFIX,
+				PAGINATED_STORAGE_PREFIX,
+				&key, payload.clone()
+			);
+		}
+	}: {
+		StatefulStoragePallet::<T>::purge_msa_data(msa_id, Weight::MAX);
+	}
+	verify {
+		assert!(get_paginated_page::<T>(msa_id, schema_id, 0).is_none());
+	}
+
 	impl_benchmark_test_suite!(StatefulStoragePallet,
 		crate::tests::mock::new_test_ext_keystore(),
 		crate::tests::mock::Test);
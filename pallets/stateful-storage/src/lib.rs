@@ -35,7 +35,7 @@ mod test_common;
 mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 use common_primitives::benchmarks::{MsaBenchmarkHelper, SchemaBenchmarkHelper};
-use sp_std::prelude::*;
+use sp_std::{prelude::*, vec};
 
 mod stateful_child_tree;
 pub mod types;
@@ -44,21 +44,26 @@ pub mod weights;
 use crate::{stateful_child_tree::StatefulChildTree, types::*};
 use common_primitives::{
 	msa::{
-		DelegatorId, MessageSourceId, MsaLookup, MsaValidator, ProviderId, SchemaGrantValidator,
+		DelegatorId, MessageSourceId, MsaDataMerger, MsaDataPurger, MsaLookup, MsaValidator,
+		ProviderId, SchemaGrantPermission, SchemaGrantValidator,
 	},
-	node::Verify,
-	schema::{PayloadLocation, SchemaId, SchemaInfoResponse, SchemaProvider, SchemaSetting},
+	schema::{
+		PayloadLocation, SchemaId, SchemaInfoResponse, SchemaProvider, SchemaSetting, SchemaStatus,
+	},
+	signatures::{self, EIP712Domain, EIP712Encode},
 	stateful_storage::{
 		ItemizedStoragePageResponse, ItemizedStorageResponse, PageHash, PageId,
 		PaginatedStorageResponse,
 	},
-	utils::wrap_binary_data,
 };
 use frame_support::{dispatch::DispatchResult, ensure, pallet_prelude::*, traits::Get};
 use frame_system::pallet_prelude::*;
 pub use pallet::*;
 use sp_core::{bounded::BoundedVec, crypto::AccountId32};
-use sp_runtime::{traits::Convert, DispatchError, MultiSignature};
+use sp_runtime::{
+	traits::{Convert, SaturatedConversion},
+	DispatchError, MultiSignature,
+};
 pub use weights::*;
 
 #[frame_support::pallet]
@@ -120,6 +125,10 @@ pub mod pallet {
 		/// to make sure a signed payload would not be replayable.
 		#[pallet::constant]
 		type MortalityWindowSize: Get<u32>;
+
+		/// The chain id and verifying contract of the EIP-712 domain that Ethereum keys sign typed data in
+		#[pallet::constant]
+		type Eip712Domain: Get<EIP712Domain>;
 	}
 
 	// Simple declaration of the `Pallet` type. It is placeholder we use to implement traits and
@@ -127,6 +136,14 @@ pub mod pallet {
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
+	/// The paginated schema whose pages an unfinished merge is moving, and whether the MSA merged
+	/// into already had pages of it before the merge
+	/// - Key: Merged MSA Id
+	/// - Value: (Schema Id, whether the pages of the schema are deleted instead of moved)
+	#[pallet::storage]
+	pub type PaginatedMergeCursors<T: Config> =
+		StorageMap<_, Twox64Concat, MessageSourceId, (SchemaId, bool), OptionQuery>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Page would exceed the highest allowable PageId
@@ -167,6 +184,12 @@ pub mod pallet {
 
 		/// The submitted proof expiration block is too far in the future
 		ProofNotYetValid,
+
+		/// The key is scoped and its scope does not allow access to the schema
+		KeyScopeRestricted,
+
+		/// The schema is retired and its storage can no longer be created or updated
+		SchemaRetired,
 	}
 
 	#[pallet::event]
@@ -239,6 +262,8 @@ pub mod pallet {
 		#[pallet::weight(
 			T::WeightInfo::apply_item_actions_delete(actions.len() as u32)
 			.max(T::WeightInfo::apply_item_actions_add(Pallet::<T>::sum_add_actions_bytes(actions)))
+			.saturating_add(Pallet::<T>::key_scopes_read_weight())
+			.saturating_add(Pallet::<T>::sub_delegation_weight())
 		)]
 		pub fn apply_item_actions(
 			origin: OriginFor<T>,
@@ -252,13 +277,25 @@ pub mod pallet {
 		) -> DispatchResult {
 			let key = ensure_signed(origin)?;
 			let is_pruning = actions.iter().any(|a| matches!(a, ItemAction::Delete { .. }));
-			let caller_msa_id = Self::check_msa_and_grants(key, state_owner_msa_id, schema_id)?;
+			let is_adding = actions.iter().any(|a| matches!(a, ItemAction::Add { .. }));
+			let required_permissions = match (is_adding, is_pruning) {
+				(true, true) => vec![SchemaGrantPermission::Create, SchemaGrantPermission::Delete],
+				(false, true) => vec![SchemaGrantPermission::Delete],
+				_ => vec![SchemaGrantPermission::Create],
+			};
+			let caller_msa_id = Self::check_msa_and_grants(
+				key,
+				state_owner_msa_id,
+				schema_id,
+				&required_permissions,
+			)?;
 			let caller_is_state_owner = caller_msa_id == state_owner_msa_id;
 			Self::check_schema_for_write(
 				schema_id,
 				PayloadLocation::Itemized,
 				caller_is_state_owner,
 				is_pruning,
+				is_adding,
 			)?;
 			Self::update_itemized(state_owner_msa_id, schema_id, target_hash, actions)?;
 			Ok(())
@@ -275,7 +312,11 @@ pub mod pallet {
 		/// * [`Event::PaginatedPageUpdated`]
 		///
 		#[pallet::call_index(1)]
-		#[pallet::weight(T::WeightInfo::upsert_page(payload.len() as u32))]
+		#[pallet::weight(
+			T::WeightInfo::upsert_page(payload.len() as u32)
+				.saturating_add(Pallet::<T>::key_scopes_read_weight())
+				.saturating_add(Pallet::<T>::sub_delegation_weight())
+		)]
 		pub fn upsert_page(
 			origin: OriginFor<T>,
 			#[pallet::compact] state_owner_msa_id: MessageSourceId,
@@ -286,14 +327,24 @@ pub mod pallet {
 		) -> DispatchResult {
 			let provider_key = ensure_signed(origin)?;
 			ensure!(page_id <= T::MaxPaginatedPageId::get(), Error::<T>::PageIdExceedsMaxAllowed);
-			let caller_msa_id =
-				Self::check_msa_and_grants(provider_key, state_owner_msa_id, schema_id)?;
+			let required_permission =
+				match Self::get_paginated_page_for(state_owner_msa_id, schema_id, page_id)? {
+					Some(_) => SchemaGrantPermission::Update,
+					None => SchemaGrantPermission::Create,
+				};
+			let caller_msa_id = Self::check_msa_and_grants(
+				provider_key,
+				state_owner_msa_id,
+				schema_id,
+				&[required_permission],
+			)?;
 			let caller_is_state_owner = caller_msa_id == state_owner_msa_id;
 			Self::check_schema_for_write(
 				schema_id,
 				PayloadLocation::Paginated,
 				caller_is_state_owner,
 				false,
+				true,
 			)?;
 			Self::update_paginated(
 				state_owner_msa_id,
@@ -316,7 +367,11 @@ pub mod pallet {
 		/// * [`Event::PaginatedPageDeleted`]
 		///
 		#[pallet::call_index(2)]
-		#[pallet::weight(T::WeightInfo::delete_page())]
+		#[pallet::weight(
+			T::WeightInfo::delete_page()
+				.saturating_add(Pallet::<T>::key_scopes_read_weight())
+				.saturating_add(Pallet::<T>::sub_delegation_weight())
+		)]
 		pub fn delete_page(
 			origin: OriginFor<T>,
 			#[pallet::compact] state_owner_msa_id: MessageSourceId,
@@ -326,14 +381,19 @@ pub mod pallet {
 		) -> DispatchResult {
 			let provider_key = ensure_signed(origin)?;
 			ensure!(page_id <= T::MaxPaginatedPageId::get(), Error::<T>::PageIdExceedsMaxAllowed);
-			let caller_msa_id =
-				Self::check_msa_and_grants(provider_key, state_owner_msa_id, schema_id)?;
+			let caller_msa_id = Self::check_msa_and_grants(
+				provider_key,
+				state_owner_msa_id,
+				schema_id,
+				&[SchemaGrantPermission::Delete],
+			)?;
 			let caller_is_state_owner = caller_msa_id == state_owner_msa_id;
 			Self::check_schema_for_write(
 				schema_id,
 				PayloadLocation::Paginated,
 				caller_is_state_owner,
 				true,
+				false,
 			)?;
 			Self::delete_paginated(state_owner_msa_id, schema_id, page_id, target_hash)?;
 			Ok(())
@@ -351,6 +411,7 @@ pub mod pallet {
 		#[pallet::weight(
 		T::WeightInfo::apply_item_actions_with_signature_v2_delete(payload.actions.len() as u32)
 		.max(T::WeightInfo::apply_item_actions_with_signature_v2_add(Pallet::<T>::sum_add_actions_bytes(&payload.actions)))
+		.saturating_add(Pallet::<T>::key_scopes_read_weight())
 		)]
 		#[allow(deprecated)]
 		#[deprecated(note = "please use `apply_item_actions_with_signature_v2` instead")]
@@ -363,17 +424,19 @@ pub mod pallet {
 			ensure_signed(origin)?;
 
 			let is_pruning = payload.actions.iter().any(|a| matches!(a, ItemAction::Delete { .. }));
+			let is_adding = payload.actions.iter().any(|a| matches!(a, ItemAction::Add { .. }));
 			Self::check_payload_expiration(
 				frame_system::Pallet::<T>::block_number(),
 				payload.expiration,
 			)?;
-			Self::check_signature(&proof, &delegator_key.clone(), payload.encode())?;
-			Self::check_msa(delegator_key, payload.msa_id)?;
+			Self::check_signature(&proof, &delegator_key, &payload)?;
+			Self::check_msa(delegator_key, payload.msa_id, payload.schema_id)?;
 			Self::check_schema_for_write(
 				payload.schema_id,
 				PayloadLocation::Itemized,
 				true,
 				is_pruning,
+				is_adding,
 			)?;
 			Self::update_itemized(
 				payload.msa_id,
@@ -391,7 +454,10 @@ pub mod pallet {
 		/// * [`Event::PaginatedPageUpdated`]
 		///
 		#[pallet::call_index(4)]
-		#[pallet::weight(T::WeightInfo::upsert_page_with_signature_v2(payload.payload.len() as u32))]
+		#[pallet::weight(
+			T::WeightInfo::upsert_page_with_signature_v2(payload.payload.len() as u32)
+				.saturating_add(Pallet::<T>::key_scopes_read_weight())
+		)]
 		#[allow(deprecated)]
 		#[deprecated(note = "please use `upsert_page_with_signature_v2` instead")]
 		pub fn upsert_page_with_signature(
@@ -409,13 +475,14 @@ pub mod pallet {
 				frame_system::Pallet::<T>::block_number(),
 				payload.expiration,
 			)?;
-			Self::check_signature(&proof, &delegator_key.clone(), payload.encode())?;
-			Self::check_msa(delegator_key, payload.msa_id)?;
+			Self::check_signature(&proof, &delegator_key, &payload)?;
+			Self::check_msa(delegator_key, payload.msa_id, payload.schema_id)?;
 			Self::check_schema_for_write(
 				payload.schema_id,
 				PayloadLocation::Paginated,
 				true,
 				false,
+				true,
 			)?;
 			Self::update_paginated(
 				payload.msa_id,
@@ -434,7 +501,10 @@ pub mod pallet {
 		/// * [`Event::PaginatedPageDeleted`]
 		///
 		#[pallet::call_index(5)]
-		#[pallet::weight(T::WeightInfo::delete_page_with_signature_v2())]
+		#[pallet::weight(
+			T::WeightInfo::delete_page_with_signature_v2()
+				.saturating_add(Pallet::<T>::key_scopes_read_weight())
+		)]
 		#[allow(deprecated)]
 		#[deprecated(note = "please use `delete_page_with_signature_v2` instead")]
 		pub fn delete_page_with_signature(
@@ -452,13 +522,14 @@ pub mod pallet {
 				frame_system::Pallet::<T>::block_number(),
 				payload.expiration,
 			)?;
-			Self::check_signature(&proof, &delegator_key.clone(), payload.encode())?;
-			Self::check_msa(delegator_key, payload.msa_id)?;
+			Self::check_signature(&proof, &delegator_key, &payload)?;
+			Self::check_msa(delegator_key, payload.msa_id, payload.schema_id)?;
 			Self::check_schema_for_write(
 				payload.schema_id,
 				PayloadLocation::Paginated,
 				true,
 				true,
+				false,
 			)?;
 			Self::delete_paginated(
 				payload.msa_id,
@@ -481,6 +552,7 @@ pub mod pallet {
 		#[pallet::weight(
 		T::WeightInfo::apply_item_actions_with_signature_v2_delete(payload.actions.len() as u32)
 		.max(T::WeightInfo::apply_item_actions_with_signature_v2_add(Pallet::<T>::sum_add_actions_bytes(&payload.actions)))
+		.saturating_add(Pallet::<T>::key_scopes_read_weight())
 		)]
 		pub fn apply_item_actions_with_signature_v2(
 			origin: OriginFor<T>,
@@ -491,18 +563,21 @@ pub mod pallet {
 			ensure_signed(origin)?;
 
 			let is_pruning = payload.actions.iter().any(|a| matches!(a, ItemAction::Delete { .. }));
+			let is_adding = payload.actions.iter().any(|a| matches!(a, ItemAction::Add { .. }));
 			Self::check_payload_expiration(
 				frame_system::Pallet::<T>::block_number(),
 				payload.expiration,
 			)?;
-			Self::check_signature(&proof, &delegator_key.clone(), payload.encode())?;
+			Self::check_signature(&proof, &delegator_key, &payload)?;
 			let state_owner_msa_id = T::MsaInfoProvider::ensure_valid_msa_key(&delegator_key)
 				.map_err(|_| Error::<T>::InvalidMessageSourceAccount)?;
+			Self::check_key_scope(&delegator_key, payload.schema_id)?;
 			Self::check_schema_for_write(
 				payload.schema_id,
 				PayloadLocation::Itemized,
 				true,
 				is_pruning,
+				is_adding,
 			)?;
 			Self::update_itemized(
 				state_owner_msa_id,
@@ -520,7 +595,10 @@ pub mod pallet {
 		/// * [`Event::PaginatedPageUpdated`]
 		///
 		#[pallet::call_index(7)]
-		#[pallet::weight(T::WeightInfo::upsert_page_with_signature_v2(payload.payload.len() as u32))]
+		#[pallet::weight(
+			T::WeightInfo::upsert_page_with_signature_v2(payload.payload.len() as u32)
+				.saturating_add(Pallet::<T>::key_scopes_read_weight())
+		)]
 		pub fn upsert_page_with_signature_v2(
 			origin: OriginFor<T>,
 			delegator_key: T::AccountId,
@@ -536,14 +614,16 @@ pub mod pallet {
 				frame_system::Pallet::<T>::block_number(),
 				payload.expiration,
 			)?;
-			Self::check_signature(&proof, &delegator_key.clone(), payload.encode())?;
+			Self::check_signature(&proof, &delegator_key, &payload)?;
 			let state_owner_msa_id = T::MsaInfoProvider::ensure_valid_msa_key(&delegator_key)
 				.map_err(|_| Error::<T>::InvalidMessageSourceAccount)?;
+			Self::check_key_scope(&delegator_key, payload.schema_id)?;
 			Self::check_schema_for_write(
 				payload.schema_id,
 				PayloadLocation::Paginated,
 				true,
 				false,
+				true,
 			)?;
 			Self::update_paginated(
 				state_owner_msa_id,
@@ -562,7 +642,10 @@ pub mod pallet {
 		/// * [`Event::PaginatedPageDeleted`]
 		///
 		#[pallet::call_index(8)]
-		#[pallet::weight(T::WeightInfo::delete_page_with_signature_v2())]
+		#[pallet::weight(
+			T::WeightInfo::delete_page_with_signature_v2()
+				.saturating_add(Pallet::<T>::key_scopes_read_weight())
+		)]
 		pub fn delete_page_with_signature_v2(
 			origin: OriginFor<T>,
 			delegator_key: T::AccountId,
@@ -578,14 +661,16 @@ pub mod pallet {
 				frame_system::Pallet::<T>::block_number(),
 				payload.expiration,
 			)?;
-			Self::check_signature(&proof, &delegator_key.clone(), payload.encode())?;
+			Self::check_signature(&proof, &delegator_key, &payload)?;
 			let state_owner_msa_id = T::MsaInfoProvider::ensure_valid_msa_key(&delegator_key)
 				.map_err(|_| Error::<T>::InvalidMessageSourceAccount)?;
+			Self::check_key_scope(&delegator_key, payload.schema_id)?;
 			Self::check_schema_for_write(
 				payload.schema_id,
 				PayloadLocation::Paginated,
 				true,
 				true,
+				false,
 			)?;
 			Self::delete_paginated(
 				state_owner_msa_id,
@@ -599,6 +684,28 @@ pub mod pallet {
 }
 
 impl<T: Config> Pallet<T> {
+	/// The weight of checking the scopes of the key acting for the MSA, which the generated
+	/// weights do not cover
+	fn key_scopes_read_weight() -> Weight {
+		T::DbWeight::get().reads(1).saturating_add(Weight::from_parts(0, 2_578))
+	}
+
+	/// The weight of resolving a delegation through sub-delegations, which the generated weights
+	/// do not cover: up to 8 more delegations and 9 sub-delegations and Provider retirements are
+	/// read
+	fn sub_delegation_weight() -> Weight {
+		T::DbWeight::get()
+			.reads(8 + 9 + 9)
+			.saturating_add(Weight::from_parts(9_000_000, 8 * 2_692 + 9 * (3_660 + 2_495)))
+	}
+
+	/// The weight of purging `pages` pages of an MSA
+	fn purge_msa_pages_weight(pages: u32) -> Weight {
+		// Removing the cursor of an abandoned paginated merge is not covered by the generated
+		// weight
+		T::WeightInfo::purge_msa_pages(pages).saturating_add(T::DbWeight::get().writes(1))
+	}
+
 	/// Sums the total bytes of each item actions
 	pub fn sum_add_actions_bytes(
 		actions: &BoundedVec<
@@ -678,19 +785,28 @@ impl<T: Config> Pallet<T> {
 
 	/// Verify the `signature` was signed by `signer` on `payload` by a wallet
 	/// Note the `wrap_binary_data` follows the Polkadot wallet pattern of wrapping with `<Byte>` tags.
+	/// Ethereum keys instead sign the EIP-712 typed data of `payload`.
 	///
 	/// # Errors
 	/// * [`Error::InvalidSignature`]
 	///
-	pub fn check_signature(
+	pub fn check_signature<P: Encode + EIP712Encode>(
 		signature: &MultiSignature,
 		signer: &T::AccountId,
-		payload: Vec<u8>,
+		payload: &P,
 	) -> DispatchResult {
 		let key = T::ConvertIntoAccountId32::convert((*signer).clone());
-		let wrapped_payload = wrap_binary_data(payload);
 
-		ensure!(signature.verify(&wrapped_payload[..], &key), Error::<T>::InvalidSignature);
+		ensure!(
+			signatures::verify_signature(
+				signature,
+				&key,
+				payload,
+				payload.encode(),
+				&T::Eip712Domain::get()
+			),
+			Error::<T>::InvalidSignature
+		);
 
 		Ok(())
 	}
@@ -730,15 +846,22 @@ impl<T: Config> Pallet<T> {
 	/// * [`Error::InvalidSchemaId`]
 	/// * [`Error::SchemaPayloadLocationMismatch`]
 	/// * [`Error::UnsupportedOperationForSchema`]
+	/// * [`Error::SchemaRetired`]
 	///
 	fn check_schema_for_write(
 		schema_id: SchemaId,
 		expected_payload_location: PayloadLocation,
 		is_payload_signed: bool,
 		is_deleting: bool,
+		is_writing: bool,
 	) -> DispatchResult {
 		let schema = Self::check_schema_for_read(schema_id, expected_payload_location)?;
 
+		// Retired schemas take no new data, but users may still delete what they stored.
+		if is_writing {
+			ensure!(schema.status != SchemaStatus::Retired, Error::<T>::SchemaRetired);
+		}
+
 		// Ensure that the schema allows signed payloads.
 		// If so, calling extrinsic must be of signature type.
 		if schema.settings.contains(&SchemaSetting::SignatureRequired) {
@@ -753,46 +876,68 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
-	/// Checks that existence of Msa for certain key and if the grant is valid when the caller Msa
-	/// is different from the state owner Msa
+	/// Checks that existence of Msa for certain key and if the grant is valid for all of the
+	/// `required_permissions` when the caller Msa is different from the state owner Msa
 	///
 	/// # Errors
 	/// * [`Error::InvalidMessageSourceAccount`]
+	/// * [`Error::KeyScopeRestricted`]
 	/// * [`Error::UnauthorizedDelegate`]
 	///
 	fn check_msa_and_grants(
 		key: T::AccountId,
 		state_owner_msa_id: MessageSourceId,
 		schema_id: SchemaId,
+		required_permissions: &[SchemaGrantPermission],
 	) -> Result<MessageSourceId, DispatchError> {
 		let caller_msa_id = T::MsaInfoProvider::ensure_valid_msa_key(&key)
 			.map_err(|_| Error::<T>::InvalidMessageSourceAccount)?;
+		Self::check_key_scope(&key, schema_id)?;
 
 		// if caller and owner are the same no delegation is needed
 		if caller_msa_id != state_owner_msa_id {
 			let current_block = frame_system::Pallet::<T>::block_number();
-			T::SchemaGrantValidator::ensure_valid_schema_grant(
-				ProviderId(caller_msa_id),
-				DelegatorId(state_owner_msa_id),
-				schema_id,
-				current_block,
-			)
-			.map_err(|_| Error::<T>::UnauthorizedDelegate)?;
+			for permission in required_permissions.iter() {
+				T::SchemaGrantValidator::ensure_valid_schema_grant(
+					ProviderId(caller_msa_id),
+					DelegatorId(state_owner_msa_id),
+					schema_id,
+					current_block,
+					*permission,
+				)
+				.map_err(|_| Error::<T>::UnauthorizedDelegate)?;
+			}
 		}
 
 		Ok(caller_msa_id)
 	}
 
-	/// Verifies if the key has an Msa and if it matches with expected one
+	/// Verifies if the key has an Msa, if it matches with expected one and if its scope
+	/// allows access to the schema
 	///
 	/// # Errors
 	/// * [`Error::InvalidMessageSourceAccount`]
+	/// * [`Error::KeyScopeRestricted`]
 	///
-	fn check_msa(key: T::AccountId, expected_msa_id: MessageSourceId) -> DispatchResult {
+	fn check_msa(
+		key: T::AccountId,
+		expected_msa_id: MessageSourceId,
+		schema_id: SchemaId,
+	) -> DispatchResult {
 		let state_owner_msa_id = T::MsaInfoProvider::ensure_valid_msa_key(&key)
 			.map_err(|_| Error::<T>::InvalidMessageSourceAccount)?;
 		ensure!(state_owner_msa_id == expected_msa_id, Error::<T>::InvalidMessageSourceAccount);
-		Ok(())
+		Self::check_key_scope(&key, schema_id)
+	}
+
+	/// Verifies that the scope of the key, if any, allows access to the schema
+	///
+	/// # Errors
+	/// * [`Error::KeyScopeRestricted`]
+	///
+	fn check_key_scope(key: &T::AccountId, schema_id: SchemaId) -> DispatchResult {
+		T::MsaInfoProvider::ensure_key_allows_schema(key, schema_id)
+			.map_err(|_| Error::<T>::KeyScopeRestricted.into())
 	}
 
 	/// Updates an itemized storage by applying provided actions and deposit events
@@ -967,4 +1112,212 @@ impl<T: Config> Pallet<T> {
 		)
 		.map_err(|_| Error::<T>::CorruptedState)?)
 	}
+
+	/// Moves up to `limit` itemized pages of `source_msa_id` to `target_msa_id`. The pages of
+	/// schemas that `target_msa_id` already has a page for are deleted instead.
+	/// Returns the number of pages moved or deleted, and whether none is left.
+	fn merge_itemized_pages(
+		source_msa_id: MessageSourceId,
+		target_msa_id: MessageSourceId,
+		limit: u32,
+	) -> (u32, bool) {
+		let pages: Vec<(ItemizedKey, ItemizedPage<T>)> =
+			StatefulChildTree::<T::KeyHasher>::prefix_iterator::<ItemizedPage<T>, ItemizedKey, _>(
+				&source_msa_id,
+				PALLET_STORAGE_PREFIX,
+				ITEMIZED_STORAGE_PREFIX,
+				&(),
+			)
+			.take(limit.saturating_add(1) as usize)
+			.collect();
+		let complete = pages.len() <= limit as usize;
+
+		let mut merged = 0u32;
+		for (key, page) in pages.into_iter().take(limit as usize) {
+			let schema_id = key.0;
+			let content_hash = page.get_hash();
+			if let Ok(None) = Self::get_itemized_page_for(target_msa_id, schema_id) {
+				StatefulChildTree::<T::KeyHasher>::write(
+					&target_msa_id,
+					PALLET_STORAGE_PREFIX,
+					ITEMIZED_STORAGE_PREFIX,
+					&key,
+					&page,
+				);
+				Self::deposit_event(Event::ItemizedPageUpdated {
+					msa_id: target_msa_id,
+					schema_id,
+					prev_content_hash: PageHash::default(),
+					curr_content_hash: content_hash,
+				});
+			}
+			StatefulChildTree::<T::KeyHasher>::kill(
+				&source_msa_id,
+				PALLET_STORAGE_PREFIX,
+				ITEMIZED_STORAGE_PREFIX,
+				&key,
+			);
+			Self::deposit_event(Event::ItemizedPageDeleted {
+				msa_id: source_msa_id,
+				schema_id,
+				prev_content_hash: content_hash,
+			});
+			merged = merged.saturating_add(1);
+		}
+		(merged, complete)
+	}
+
+	/// Whether `msa_id` stores any paginated page of `schema_id`
+	fn has_paginated_pages(msa_id: MessageSourceId, schema_id: SchemaId) -> bool {
+		let prefix: PaginatedPrefixKey = (schema_id,);
+		StatefulChildTree::<T::KeyHasher>::prefix_iterator::<
+			PaginatedPage<T>,
+			PaginatedKey,
+			PaginatedPrefixKey,
+		>(&msa_id, PALLET_STORAGE_PREFIX, PAGINATED_STORAGE_PREFIX, &prefix)
+		.next()
+		.is_some()
+	}
+
+	/// Moves up to `limit` paginated pages of `source_msa_id` to `target_msa_id`. The pages of
+	/// schemas that `target_msa_id` already had pages for are deleted instead. The schema being
+	/// moved is kept in [`PaginatedMergeCursors`], so that a schema with more pages than `limit`
+	/// is moved over several calls without mistaking its moved pages for a collision.
+	/// Returns the number of pages moved or deleted, and whether none is left.
+	fn merge_paginated_pages(
+		source_msa_id: MessageSourceId,
+		target_msa_id: MessageSourceId,
+		limit: u32,
+	) -> (u32, bool) {
+		let pages: Vec<(PaginatedKey, PaginatedPage<T>)> =
+			StatefulChildTree::<T::KeyHasher>::prefix_iterator::<
+				PaginatedPage<T>,
+				PaginatedKey,
+				_,
+			>(&source_msa_id, PALLET_STORAGE_PREFIX, PAGINATED_STORAGE_PREFIX, &())
+			.take(limit.saturating_add(1) as usize)
+			.collect();
+		let complete = pages.len() <= limit as usize;
+
+		let mut cursor = PaginatedMergeCursors::<T>::get(source_msa_id);
+		let mut merged = 0u32;
+		for (key, page) in pages.into_iter().take(limit as usize) {
+			let schema_id = key.0;
+			let collides = match cursor {
+				Some((cursor_schema_id, collides)) if cursor_schema_id == schema_id => collides,
+				_ => {
+					let collides = Self::has_paginated_pages(target_msa_id, schema_id);
+					cursor = Some((schema_id, collides));
+					collides
+				},
+			};
+
+			let content_hash = page.get_hash();
+			if !collides {
+				StatefulChildTree::<T::KeyHasher>::write(
+					&target_msa_id,
+					PALLET_STORAGE_PREFIX,
+					PAGINATED_STORAGE_PREFIX,
+					&key,
+					&page,
+				);
+				Self::deposit_event(Event::PaginatedPageUpdated {
+					msa_id: target_msa_id,
+					schema_id,
+					page_id: key.1,
+					prev_content_hash: PageHash::default(),
+					curr_content_hash: content_hash,
+				});
+			}
+			StatefulChildTree::<T::KeyHasher>::kill(
+				&source_msa_id,
+				PALLET_STORAGE_PREFIX,
+				PAGINATED_STORAGE_PREFIX,
+				&key,
+			);
+			Self::deposit_event(Event::PaginatedPageDeleted {
+				msa_id: source_msa_id,
+				schema_id,
+				page_id: key.1,
+				prev_content_hash: content_hash,
+			});
+			merged = merged.saturating_add(1);
+		}
+
+		match (complete, cursor) {
+			(false, Some(cursor)) => PaginatedMergeCursors::<T>::insert(source_msa_id, cursor),
+			_ => PaginatedMergeCursors::<T>::remove(source_msa_id),
+		}
+		(merged, complete)
+	}
+}
+
+impl<T: Config> MsaDataMerger for Pallet<T> {
+	/// Moves the itemized and then the paginated pages of `source_msa_id` to `target_msa_id`.
+	/// The pages of a schema that `target_msa_id` already stores pages for are deleted instead,
+	/// so that the data of the two MSAs is never mixed.
+	fn merge_msa_data(
+		source_msa_id: MessageSourceId,
+		target_msa_id: MessageSourceId,
+		limit: u32,
+	) -> (u32, bool) {
+		let (itemized_merged, itemized_complete) =
+			Self::merge_itemized_pages(source_msa_id, target_msa_id, limit);
+		if !itemized_complete {
+			return (itemized_merged, false)
+		}
+		let (paginated_merged, paginated_complete) = Self::merge_paginated_pages(
+			source_msa_id,
+			target_msa_id,
+			limit.saturating_sub(itemized_merged),
+		);
+		(itemized_merged.saturating_add(paginated_merged), paginated_complete)
+	}
+}
+
+impl<T: Config> MsaDataPurger for Pallet<T> {
+	/// Deletes the itemized and then the paginated pages stored for `msa_id`, as many as fit in
+	/// `remaining_weight`
+	fn purge_msa_data(msa_id: MessageSourceId, remaining_weight: Weight) -> (Weight, bool) {
+		let base_weight = Self::purge_msa_pages_weight(0);
+		let page_weight = Self::purge_msa_pages_weight(1).saturating_sub(base_weight);
+		if remaining_weight.any_lt(base_weight.saturating_add(page_weight)) {
+			return (Weight::zero(), false)
+		}
+
+		let available_weight = remaining_weight.saturating_sub(base_weight);
+		let limit: u32 = [
+			available_weight.ref_time().checked_div(page_weight.ref_time()),
+			available_weight.proof_size().checked_div(page_weight.proof_size()),
+		]
+		.into_iter()
+		.flatten()
+		.min()
+		.unwrap_or(u64::MAX)
+		.saturated_into();
+
+		let (itemized_deleted, itemized_complete) = StatefulChildTree::<T::KeyHasher>::clear(
+			&msa_id,
+			PALLET_STORAGE_PREFIX,
+			ITEMIZED_STORAGE_PREFIX,
+			limit,
+		);
+		if !itemized_complete {
+			return (Self::purge_msa_pages_weight(itemized_deleted), false)
+		}
+		let (paginated_deleted, paginated_complete) = StatefulChildTree::<T::KeyHasher>::clear(
+			&msa_id,
+			PALLET_STORAGE_PREFIX,
+			PAGINATED_STORAGE_PREFIX,
+			limit.saturating_sub(itemized_deleted),
+		);
+		if paginated_complete {
+			// left behind by a merge that was abandoned
+			PaginatedMergeCursors::<T>::remove(msa_id);
+		}
+		(
+			Self::purge_msa_pages_weight(itemized_deleted.saturating_add(paginated_deleted)),
+			paginated_complete,
+		)
+	}
 }
@@ -376,6 +376,20 @@ impl<H: MultipartKeyStorageHasher> StatefulChildTree<H> {
 		child::kill(child_trie_info, &keys.hash());
 	}
 
+	/// Kills up to `limit` nodes of a child tree
+	///
+	/// Returns the number of nodes killed, and whether the child tree is now empty
+	pub fn clear(
+		msa_id: &MessageSourceId,
+		pallet_name: &[u8],
+		storage_name: &[u8],
+		limit: u32,
+	) -> (u32, bool) {
+		let child_trie_info = &Self::get_child_tree_for_storage(*msa_id, pallet_name, storage_name);
+		let result = child::clear_storage(child_trie_info, Some(limit), None);
+		(result.unique, result.maybe_cursor.is_none())
+	}
+
 	/// These hashes should be consistent across the chain so we are hardcoding them
 	fn get_child_tree_for_storage(
 		msa_id: MessageSourceId,
@@ -27,6 +27,9 @@ pub mod constants {
 	pub const PAGINATED_SIGNED_SCHEMA: SchemaId = 106;
 	pub const PAGINATED_APPEND_ONLY_SCHEMA: SchemaId = 107;
 	pub const UNDELEGATED_PAGINATED_SCHEMA: SchemaId = 108;
+	/// Retired
+	pub const ITEMIZED_RETIRED_SCHEMA: SchemaId = 109;
+	pub const PAGINATED_RETIRED_SCHEMA: SchemaId = 110;
 }
 
 #[cfg(test)]
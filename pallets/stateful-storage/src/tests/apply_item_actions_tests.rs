@@ -64,6 +64,30 @@ fn apply_item_actions_with_invalid_schema_id_should_fail() {
 	});
 }
 
+#[test]
+fn apply_item_actions_with_retired_schema_should_fail() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let msa_id = 1;
+		let caller_1 = test_public(msa_id);
+		let schema_id = ITEMIZED_RETIRED_SCHEMA;
+		let payload = vec![1; 5];
+		let actions = vec![ItemAction::Add { data: payload.try_into().unwrap() }];
+
+		// act
+		assert_err!(
+			StatefulStoragePallet::apply_item_actions(
+				RuntimeOrigin::signed(caller_1),
+				msa_id,
+				schema_id,
+				NONEXISTENT_PAGE_HASH,
+				BoundedVec::try_from(actions).unwrap(),
+			),
+			Error::<Test>::SchemaRetired
+		)
+	});
+}
+
 #[test]
 fn apply_item_actions_with_invalid_schema_location_should_fail() {
 	new_test_ext().execute_with(|| {
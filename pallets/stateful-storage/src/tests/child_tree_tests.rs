@@ -128,3 +128,41 @@ fn child_tree_iterator() {
 		assert_eq!(r3.len(), 0, "iterator over another storage shoudl return empty items");
 	});
 }
+
+#[test]
+fn child_tree_clear() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let pallet_name: &[u8] = b"test-pallet";
+		let storage_name_1: &[u8] = b"storage1";
+		let storage_name_2: &[u8] = b"storage2";
+		let msa_id = 1;
+		for page_id in 1u8..=3u8 {
+			<StatefulChildTree>::write(
+				&msa_id,
+				pallet_name,
+				storage_name_1,
+				&(2u16, page_id),
+				page_id,
+			);
+		}
+		<StatefulChildTree>::write(&msa_id, pallet_name, storage_name_2, &(2u16, 1u8), 1u8);
+
+		// act
+		let result = <StatefulChildTree>::clear(&msa_id, pallet_name, storage_name_1, 10);
+
+		// assert
+		assert_eq!(result, (3, true));
+		let remaining = <StatefulChildTree>::prefix_iterator::<u8, (u16, u8), _>(
+			&msa_id,
+			pallet_name,
+			storage_name_1,
+			&(),
+		);
+		assert_eq!(remaining.count(), 0);
+		let other: Option<u8> =
+			<StatefulChildTree>::try_read(&msa_id, pallet_name, storage_name_2, &(2u16, 1u8))
+				.unwrap();
+		assert_eq!(other, Some(1u8), "clearing one storage should not touch another");
+	});
+}
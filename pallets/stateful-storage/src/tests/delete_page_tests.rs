@@ -213,6 +213,45 @@ fn delete_existing_page_succeeds() {
 	})
 }
 
+#[test]
+fn delete_existing_page_of_retired_schema_succeeds() {
+	new_test_ext().execute_with(|| {
+		// setup
+		let msa_id = 1;
+		let caller_1 = test_public(msa_id);
+		let schema_id = PAGINATED_RETIRED_SCHEMA;
+		let page_id = 11;
+		let page: PaginatedPage<Test> = generate_page(None, None);
+		let page_hash = page.get_hash();
+		let keys = (schema_id, page_id);
+
+		<StatefulChildTree>::write(
+			&msa_id,
+			PALLET_STORAGE_PREFIX,
+			PAGINATED_STORAGE_PREFIX,
+			&keys,
+			&page,
+		);
+
+		assert_ok!(StatefulStoragePallet::delete_page(
+			RuntimeOrigin::signed(caller_1),
+			msa_id,
+			schema_id,
+			page_id,
+			page_hash
+		));
+
+		let page: Option<PaginatedPage<Test>> = <StatefulChildTree>::try_read(
+			&msa_id,
+			PALLET_STORAGE_PREFIX,
+			PAGINATED_STORAGE_PREFIX,
+			&keys,
+		)
+		.unwrap();
+		assert_eq!(page, None);
+	})
+}
+
 #[test]
 #[allow(deprecated)]
 fn delete_page_with_signature_having_page_id_out_of_bounds_should_fail() {
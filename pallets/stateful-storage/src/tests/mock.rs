@@ -8,13 +8,14 @@ use crate::test_common::{
 use common_primitives::{
 	msa::{
 		Delegation, DelegationValidator, DelegatorId, MessageSourceId, MsaLookup, MsaValidator,
-		ProviderId, ProviderLookup, SchemaGrantValidator,
+		ProviderId, ProviderLookup, SchemaGrantPermission, SchemaGrantValidator,
 	},
 	node::AccountId,
 	schema::{
 		ModelType, PayloadLocation, SchemaId, SchemaInfoResponse, SchemaProvider, SchemaResponse,
-		SchemaSetting,
+		SchemaSetting, SchemaStatus,
 	},
+	signatures::EIP712Domain,
 };
 use frame_support::{
 	dispatch::DispatchResult,
@@ -141,6 +142,20 @@ impl MsaValidator for MsaInfoHandler {
 
 		Ok(MessageSourceId::decode(&mut key.as_slice()).unwrap())
 	}
+
+	fn ensure_key_can_manage_msa(_key: &Self::AccountId) -> DispatchResult {
+		Ok(())
+	}
+
+	fn ensure_key_allows_schema(key: &Self::AccountId, schema_id: SchemaId) -> DispatchResult {
+		if *key == get_scoped_signature_account().public().into() &&
+			schema_id != constants::ITEMIZED_SCHEMA
+		{
+			return Err(DispatchError::Other("key scope restricted"))
+		}
+
+		Ok(())
+	}
 }
 
 impl ProviderLookup for DelegationInfoHandler {
@@ -184,6 +199,7 @@ impl<BlockNumber> SchemaGrantValidator<BlockNumber> for SchemaGrantValidationHan
 		delegator: DelegatorId,
 		schema_id: SchemaId,
 		_block_number: BlockNumber,
+		_permission: SchemaGrantPermission,
 	) -> DispatchResult {
 		if schema_id == constants::UNDELEGATED_PAGINATED_SCHEMA ||
 			schema_id == constants::UNDELEGATED_ITEMIZED_APPEND_ONLY_SCHEMA ||
@@ -211,6 +227,7 @@ impl SchemaProvider<u16> for SchemaHandler {
 					model_type: ModelType::AvroBinary,
 					payload_location: PayloadLocation::Itemized,
 					settings: Vec::new(),
+					status: SchemaStatus::Active,
 				}),
 			constants::ITEMIZED_APPEND_ONLY_SCHEMA |
 			constants::UNDELEGATED_ITEMIZED_APPEND_ONLY_SCHEMA => Some(SchemaResponse {
@@ -219,6 +236,7 @@ impl SchemaProvider<u16> for SchemaHandler {
 				model_type: ModelType::AvroBinary,
 				payload_location: PayloadLocation::Itemized,
 				settings: Vec::try_from(vec![SchemaSetting::AppendOnly]).unwrap(),
+				status: SchemaStatus::Active,
 			}),
 			constants::ITEMIZED_SIGNATURE_REQUIRED_SCHEMA => Some(SchemaResponse {
 				schema_id,
@@ -226,6 +244,7 @@ impl SchemaProvider<u16> for SchemaHandler {
 				model_type: ModelType::AvroBinary,
 				payload_location: PayloadLocation::Itemized,
 				settings: Vec::try_from(vec![SchemaSetting::SignatureRequired]).unwrap(),
+				status: SchemaStatus::Active,
 			}),
 			constants::PAGINATED_SCHEMA | constants::UNDELEGATED_PAGINATED_SCHEMA =>
 				Some(SchemaResponse {
@@ -234,6 +253,7 @@ impl SchemaProvider<u16> for SchemaHandler {
 					model_type: ModelType::AvroBinary,
 					payload_location: PayloadLocation::Paginated,
 					settings: Vec::new(),
+					status: SchemaStatus::Active,
 				}),
 			constants::PAGINATED_SIGNED_SCHEMA => Some(SchemaResponse {
 				schema_id,
@@ -241,6 +261,7 @@ impl SchemaProvider<u16> for SchemaHandler {
 				model_type: ModelType::AvroBinary,
 				payload_location: PayloadLocation::Paginated,
 				settings: Vec::try_from(vec![SchemaSetting::SignatureRequired]).unwrap(),
+				status: SchemaStatus::Active,
 			}),
 			constants::PAGINATED_APPEND_ONLY_SCHEMA => Some(SchemaResponse {
 				schema_id,
@@ -248,6 +269,23 @@ impl SchemaProvider<u16> for SchemaHandler {
 				model_type: ModelType::AvroBinary,
 				payload_location: PayloadLocation::Paginated,
 				settings: Vec::try_from(vec![SchemaSetting::AppendOnly]).unwrap(),
+				status: SchemaStatus::Active,
+			}),
+			constants::ITEMIZED_RETIRED_SCHEMA => Some(SchemaResponse {
+				schema_id,
+				model: r#"schema"#.to_string().as_bytes().to_vec(),
+				model_type: ModelType::AvroBinary,
+				payload_location: PayloadLocation::Itemized,
+				settings: Vec::new(),
+				status: SchemaStatus::Retired,
+			}),
+			constants::PAGINATED_RETIRED_SCHEMA => Some(SchemaResponse {
+				schema_id,
+				model: r#"schema"#.to_string().as_bytes().to_vec(),
+				model_type: ModelType::AvroBinary,
+				payload_location: PayloadLocation::Paginated,
+				settings: Vec::new(),
+				status: SchemaStatus::Retired,
 			}),
 			INVALID_SCHEMA_ID => None,
 
@@ -257,6 +295,7 @@ impl SchemaProvider<u16> for SchemaHandler {
 				model_type: ModelType::AvroBinary,
 				payload_location: PayloadLocation::OnChain,
 				settings: Vec::from(vec![SchemaSetting::AppendOnly]),
+				status: SchemaStatus::Active,
 			}),
 		}
 	}
@@ -268,6 +307,7 @@ impl SchemaProvider<u16> for SchemaHandler {
 				settings: schema.settings,
 				model_type: schema.model_type,
 				payload_location: schema.payload_location,
+				status: schema.status,
 			})
 		})
 	}
@@ -361,6 +401,12 @@ impl sp_std::fmt::Debug for MaxItemizedBlobSizeBytes {
 	}
 }
 
+// Needs parameter_types! for the EIP712Domain
+parameter_types! {
+	pub const TestEip712Domain: EIP712Domain =
+		EIP712Domain { chain_id: 0x190f_1b44, verifying_contract: [0xCC; 20] };
+}
+
 impl pallet_stateful_storage::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type MsaInfoProvider = MsaInfoHandler;
@@ -382,6 +428,7 @@ impl pallet_stateful_storage::Config for Test {
 	type ConvertIntoAccountId32 = ConvertInto;
 	/// The number of blocks per virtual bucket
 	type MortalityWindowSize = StatefulMortalityWindowSize;
+	type Eip712Domain = TestEip712Domain;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -399,6 +446,11 @@ pub fn get_invalid_msa_signature_account() -> sr25519::Pair {
 	sr25519::Pair::from_seed_slice([1; 32].as_slice()).unwrap()
 }
 
+/// Key whose scope only allows access to `ITEMIZED_SCHEMA`
+pub fn get_scoped_signature_account() -> sr25519::Pair {
+	sr25519::Pair::from_seed_slice([2; 32].as_slice()).unwrap()
+}
+
 fn get_signature_benchmarks_public_account() -> sr25519::Public {
 	sr25519::Pair::from_string(BENCHMARK_SIGNATURE_ACCOUNT_SEED, None)
 		.unwrap()
@@ -3,15 +3,20 @@ use crate::{
 	test_common::{constants::*, test_utility::*},
 	tests::mock::*,
 	types::*,
-	Config, Error,
+	Config, Error, PaginatedMergeCursors,
+};
+use common_primitives::{
+	msa::{MessageSourceId, MsaDataMerger},
+	stateful_storage::PageId,
+	utils::wrap_binary_data,
 };
-use common_primitives::utils::wrap_binary_data;
 use frame_support::{assert_err, assert_ok};
 use parity_scale_codec::Encode;
 #[allow(unused_imports)]
 use pretty_assertions::{assert_eq, assert_ne, assert_str_eq};
 use sp_core::Pair;
 use sp_runtime::MultiSignature;
+use std::ops::Range;
 
 #[test]
 fn is_empty_false_for_non_empty_page() {
@@ -297,3 +302,82 @@ fn signature_v2_replay_on_deleted_page_check() {
 		));
 	})
 }
+
+/// Writes paginated pages `page_ids` of `PAGINATED_SCHEMA` for `msa_id`, with payload `id`
+fn write_paginated_pages(msa_id: MessageSourceId, page_ids: Range<PageId>, id: u8) {
+	for page_id in page_ids {
+		let page: PaginatedPage<Test> = generate_page(None, Some(id));
+		<StatefulChildTree>::write(
+			&msa_id,
+			PALLET_STORAGE_PREFIX,
+			PAGINATED_STORAGE_PREFIX,
+			&(PAGINATED_SCHEMA, page_id),
+			&page,
+		);
+	}
+}
+
+#[test]
+fn merge_msa_data_moves_a_schema_with_more_pages_than_the_limit_over_several_calls() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (source_msa_id, target_msa_id) = (1, 2);
+		write_paginated_pages(source_msa_id, 0..3, 1);
+
+		// act
+		let first_call = StatefulStoragePallet::merge_msa_data(source_msa_id, target_msa_id, 2);
+		let second_call = StatefulStoragePallet::merge_msa_data(source_msa_id, target_msa_id, 2);
+
+		// assert
+		assert_eq!(first_call, (2, false));
+		assert_eq!(second_call, (1, true));
+		for page_id in 0..3 {
+			let page = StatefulStoragePallet::get_paginated_page_for(
+				target_msa_id,
+				PAGINATED_SCHEMA,
+				page_id,
+			)
+			.unwrap();
+			assert_eq!(page.map(|page| page.data), Some(generate_payload_bytes(Some(1))));
+		}
+		assert_eq!(PaginatedMergeCursors::<Test>::get(source_msa_id), None);
+	})
+}
+
+#[test]
+fn merge_msa_data_drops_every_page_of_a_colliding_schema_over_several_calls() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let (source_msa_id, target_msa_id) = (1, 2);
+		write_paginated_pages(source_msa_id, 0..3, 1);
+		write_paginated_pages(target_msa_id, 0..1, 2);
+
+		// act
+		let first_call = StatefulStoragePallet::merge_msa_data(source_msa_id, target_msa_id, 2);
+		let second_call = StatefulStoragePallet::merge_msa_data(source_msa_id, target_msa_id, 2);
+
+		// assert
+		assert_eq!(first_call, (2, false));
+		assert_eq!(second_call, (1, true));
+		for page_id in 0..3 {
+			let expected_data = match page_id {
+				0 => Some(generate_payload_bytes(Some(2))),
+				_ => None,
+			};
+			let target_page = StatefulStoragePallet::get_paginated_page_for(
+				target_msa_id,
+				PAGINATED_SCHEMA,
+				page_id,
+			)
+			.unwrap();
+			assert_eq!(target_page.map(|page| page.data), expected_data);
+			let source_page = StatefulStoragePallet::get_paginated_page_for(
+				source_msa_id,
+				PAGINATED_SCHEMA,
+				page_id,
+			)
+			.unwrap();
+			assert!(source_page.is_none());
+		}
+	})
+}
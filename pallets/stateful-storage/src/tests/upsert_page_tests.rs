@@ -99,6 +99,30 @@ fn upsert_page_with_invalid_schema_id_errors() {
 	})
 }
 
+#[test]
+fn upsert_page_with_retired_schema_errors() {
+	new_test_ext().execute_with(|| {
+		// setup
+		let msa_id = 1;
+		let caller_1 = test_public(msa_id);
+		let schema_id = PAGINATED_RETIRED_SCHEMA;
+		let page_id = 1;
+		let payload = generate_payload_bytes::<PaginatedPageSize>(Some(100));
+
+		assert_err!(
+			StatefulStoragePallet::upsert_page(
+				RuntimeOrigin::signed(caller_1),
+				msa_id.into(),
+				schema_id,
+				page_id,
+				hash_payload(&payload),
+				payload
+			),
+			Error::<Test>::SchemaRetired
+		)
+	})
+}
+
 #[test]
 fn upsert_page_with_invalid_schema_payload_location_errors() {
 	new_test_ext().execute_with(|| {
@@ -989,6 +1013,36 @@ fn upsert_page_with_signature_v2_having_invalid_hash_should_fail() {
 	})
 }
 
+#[test]
+fn upsert_page_with_signature_v2_with_key_scoped_to_other_schemas_should_fail() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let caller_1 = test_public(1);
+		let pair = get_scoped_signature_account();
+		let delegator_key = pair.public();
+		let payload = PaginatedUpsertSignaturePayloadV2 {
+			payload: generate_payload_bytes::<PaginatedPageSize>(Some(100)),
+			target_hash: PageHash::default(),
+			expiration: 10,
+			schema_id: PAGINATED_SCHEMA,
+			page_id: 1,
+		};
+		let encode_data_new_key_data = wrap_binary_data(payload.encode());
+		let owner_signature: MultiSignature = pair.sign(&encode_data_new_key_data).into();
+
+		// act
+		assert_err!(
+			StatefulStoragePallet::upsert_page_with_signature_v2(
+				RuntimeOrigin::signed(caller_1),
+				delegator_key.into(),
+				owner_signature,
+				payload
+			),
+			Error::<Test>::KeyScopeRestricted
+		)
+	})
+}
+
 #[test]
 fn upsert_page_with_signature_v2_having_valid_inputs_should_work() {
 	new_test_ext().execute_with(|| {
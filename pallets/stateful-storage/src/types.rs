@@ -3,6 +3,10 @@ use crate::Config;
 use common_primitives::{
 	msa::MessageSourceId,
 	schema::SchemaId,
+	signatures::{
+		eip_712_encode_array, eip_712_encode_bytes, eip_712_encode_uint, eip_712_hash_struct,
+		EIP712Encode,
+	},
 	stateful_storage::{PageHash, PageId, PageNonce},
 };
 use frame_support::pallet_prelude::*;
@@ -10,6 +14,7 @@ use frame_system::pallet_prelude::*;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_core::bounded::BoundedVec;
+use sp_runtime::traits::UniqueSaturatedInto;
 use sp_std::{
 	cmp::*,
 	collections::btree_map::BTreeMap,
@@ -65,6 +70,36 @@ pub enum ItemAction<DataSize: Get<u32> + Clone + sp_std::fmt::Debug + PartialEq>
 	},
 }
 
+impl<DataSize: Get<u32> + Clone + sp_std::fmt::Debug + PartialEq> EIP712Encode
+	for ItemAction<DataSize>
+{
+	fn eip_712_hash_struct(&self) -> [u8; 32] {
+		let (action_type, data, index): (&[u8], &[u8], u16) = match self {
+			ItemAction::Add { data } => (b"Add".as_slice(), &data[..], 0),
+			ItemAction::Delete { index } => (b"Delete".as_slice(), &[], *index),
+		};
+		eip_712_hash_struct(
+			ITEM_ACTION_EIP_712_TYPE,
+			&[
+				eip_712_encode_bytes(action_type),
+				eip_712_encode_bytes(data),
+				eip_712_encode_uint(index.into()),
+			],
+		)
+	}
+}
+
+/// The EIP-712 type of an [`ItemAction`]
+const ITEM_ACTION_EIP_712_TYPE: &[u8] = b"ItemAction(string actionType,bytes data,uint16 index)";
+
+/// Encodes a list of [`ItemAction`] as an EIP-712 `ItemAction[]` value
+fn eip_712_encode_actions<DataSize: Get<u32> + Clone + sp_std::fmt::Debug + PartialEq>(
+	actions: &[ItemAction<DataSize>],
+) -> [u8; 32] {
+	let actions: Vec<[u8; 32]> = actions.iter().map(|a| a.eip_712_hash_struct()).collect();
+	eip_712_encode_array(&actions)
+}
+
 /// This header is used to specify the byte size of an item stored inside the buffer
 /// All items will require this header to be inserted before the item data
 #[derive(Encode, Decode, PartialEq, MaxEncodedLen, Debug)]
@@ -113,6 +148,25 @@ pub struct ItemizedSignaturePayload<T: Config> {
 	>,
 }
 
+impl<T: Config> EIP712Encode for ItemizedSignaturePayload<T> {
+	fn eip_712_hash_struct(&self) -> [u8; 32] {
+		eip_712_hash_struct(
+			&[
+				b"ItemizedSignaturePayload(uint64 msaId,uint16 schemaId,uint32 targetHash,uint32 expiration,ItemAction[] actions)".as_slice(),
+				ITEM_ACTION_EIP_712_TYPE,
+			]
+			.concat(),
+			&[
+				eip_712_encode_uint(self.msa_id),
+				eip_712_encode_uint(self.schema_id.into()),
+				eip_712_encode_uint(self.target_hash.into()),
+				eip_712_encode_uint(self.expiration.unique_saturated_into()),
+				eip_712_encode_actions(&self.actions),
+			],
+		)
+	}
+}
+
 /// Payload containing all necessary fields to verify Itemized related signatures
 #[derive(Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, RuntimeDebugNoBound, Clone)]
 #[scale_info(skip_type_params(T))]
@@ -135,6 +189,24 @@ pub struct ItemizedSignaturePayloadV2<T: Config> {
 	>,
 }
 
+impl<T: Config> EIP712Encode for ItemizedSignaturePayloadV2<T> {
+	fn eip_712_hash_struct(&self) -> [u8; 32] {
+		eip_712_hash_struct(
+			&[
+				b"ItemizedSignaturePayloadV2(uint16 schemaId,uint32 targetHash,uint32 expiration,ItemAction[] actions)".as_slice(),
+				ITEM_ACTION_EIP_712_TYPE,
+			]
+			.concat(),
+			&[
+				eip_712_encode_uint(self.schema_id.into()),
+				eip_712_encode_uint(self.target_hash.into()),
+				eip_712_encode_uint(self.expiration.unique_saturated_into()),
+				eip_712_encode_actions(&self.actions),
+			],
+		)
+	}
+}
+
 /// Warning: This struct is `deprecated`. please use `PaginatedUpsertSignaturePayloadV2` instead
 /// Payload containing all necessary fields to verify signatures to upsert a Paginated storage
 #[derive(Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, RuntimeDebugNoBound, Clone)]
@@ -163,6 +235,22 @@ pub struct PaginatedUpsertSignaturePayload<T: Config> {
 	pub payload: BoundedVec<u8, <T as Config>::MaxPaginatedPageSizeBytes>,
 }
 
+impl<T: Config> EIP712Encode for PaginatedUpsertSignaturePayload<T> {
+	fn eip_712_hash_struct(&self) -> [u8; 32] {
+		eip_712_hash_struct(
+			b"PaginatedUpsertSignaturePayload(uint64 msaId,uint16 schemaId,uint16 pageId,uint32 targetHash,uint32 expiration,bytes payload)",
+			&[
+				eip_712_encode_uint(self.msa_id),
+				eip_712_encode_uint(self.schema_id.into()),
+				eip_712_encode_uint(self.page_id.into()),
+				eip_712_encode_uint(self.target_hash.into()),
+				eip_712_encode_uint(self.expiration.unique_saturated_into()),
+				eip_712_encode_bytes(&self.payload),
+			],
+		)
+	}
+}
+
 /// Payload containing all necessary fields to verify signatures to upsert a Paginated storage
 #[derive(Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, RuntimeDebugNoBound, Clone)]
 #[scale_info(skip_type_params(T))]
@@ -186,6 +274,21 @@ pub struct PaginatedUpsertSignaturePayloadV2<T: Config> {
 	pub payload: BoundedVec<u8, <T as Config>::MaxPaginatedPageSizeBytes>,
 }
 
+impl<T: Config> EIP712Encode for PaginatedUpsertSignaturePayloadV2<T> {
+	fn eip_712_hash_struct(&self) -> [u8; 32] {
+		eip_712_hash_struct(
+			b"PaginatedUpsertSignaturePayloadV2(uint16 schemaId,uint16 pageId,uint32 targetHash,uint32 expiration,bytes payload)",
+			&[
+				eip_712_encode_uint(self.schema_id.into()),
+				eip_712_encode_uint(self.page_id.into()),
+				eip_712_encode_uint(self.target_hash.into()),
+				eip_712_encode_uint(self.expiration.unique_saturated_into()),
+				eip_712_encode_bytes(&self.payload),
+			],
+		)
+	}
+}
+
 /// Warning: This struct is `deprecated`. please use `PaginatedDeleteSignaturePayloadV2` instead
 /// Payload containing all necessary fields to verify signatures to delete a Paginated storage
 #[derive(Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, RuntimeDebugNoBound, Clone)]
@@ -211,6 +314,21 @@ pub struct PaginatedDeleteSignaturePayload<T: Config> {
 	pub expiration: BlockNumberFor<T>,
 }
 
+impl<T: Config> EIP712Encode for PaginatedDeleteSignaturePayload<T> {
+	fn eip_712_hash_struct(&self) -> [u8; 32] {
+		eip_712_hash_struct(
+			b"PaginatedDeleteSignaturePayload(uint64 msaId,uint16 schemaId,uint16 pageId,uint32 targetHash,uint32 expiration)",
+			&[
+				eip_712_encode_uint(self.msa_id),
+				eip_712_encode_uint(self.schema_id.into()),
+				eip_712_encode_uint(self.page_id.into()),
+				eip_712_encode_uint(self.target_hash.into()),
+				eip_712_encode_uint(self.expiration.unique_saturated_into()),
+			],
+		)
+	}
+}
+
 /// Payload containing all necessary fields to verify signatures to delete a Paginated storage
 #[derive(Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, RuntimeDebugNoBound, Clone)]
 #[scale_info(skip_type_params(T))]
@@ -231,6 +349,20 @@ pub struct PaginatedDeleteSignaturePayloadV2<T: Config> {
 	pub expiration: BlockNumberFor<T>,
 }
 
+impl<T: Config> EIP712Encode for PaginatedDeleteSignaturePayloadV2<T> {
+	fn eip_712_hash_struct(&self) -> [u8; 32] {
+		eip_712_hash_struct(
+			b"PaginatedDeleteSignaturePayloadV2(uint16 schemaId,uint16 pageId,uint32 targetHash,uint32 expiration)",
+			&[
+				eip_712_encode_uint(self.schema_id.into()),
+				eip_712_encode_uint(self.page_id.into()),
+				eip_712_encode_uint(self.target_hash.into()),
+				eip_712_encode_uint(self.expiration.unique_saturated_into()),
+			],
+		)
+	}
+}
+
 /// A generic page of data which supports both Itemized and Paginated
 #[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Debug, Default)]
 #[scale_info(skip_type_params(PageDataSize))]
@@ -41,6 +41,7 @@ pub trait WeightInfo {
 	fn apply_item_actions_with_signature_v2_delete(n: u32, ) -> Weight;
 	fn upsert_page_with_signature_v2(s: u32, ) -> Weight;
 	fn delete_page_with_signature_v2() -> Weight;
+	fn purge_msa_pages(n: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_stateful_storage` using the Substrate node and recommended hardware.
@@ -188,6 +189,22 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: UNKNOWN KEY `0xbd1557c8db6bd8599a811a7175fbc2fc6400` (r:1001 w:1000)
+	/// Proof: UNKNOWN KEY `0xbd1557c8db6bd8599a811a7175fbc2fc6400` (r:1001 w:1000)
+	/// The range of component `n` is `[0, 1000]`.
+	fn purge_msa_pages(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `35 + n * (2118 ±0)`
+		//  Estimated: `1990 + n * (4593 ±0)`
+		// Minimum execution time: 6_155_000 picoseconds.
+		Weight::from_parts(6_412_000, 1990)
+			// Standard Error: 267_571
+			.saturating_add(Weight::from_parts(1_873_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 4593).saturating_mul(n.into()))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -334,6 +351,22 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: UNKNOWN KEY `0xbd1557c8db6bd8599a811a7175fbc2fc6400` (r:1001 w:1000)
+	/// Proof: UNKNOWN KEY `0xbd1557c8db6bd8599a811a7175fbc2fc6400` (r:1001 w:1000)
+	/// The range of component `n` is `[0, 1000]`.
+	fn purge_msa_pages(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `35 + n * (2118 ±0)`
+		//  Estimated: `1990 + n * (4593 ±0)`
+		// Minimum execution time: 6_155_000 picoseconds.
+		Weight::from_parts(6_412_000, 1990)
+			// Standard Error: 267_571
+			.saturating_add(Weight::from_parts(1_873_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 4593).saturating_mul(n.into()))
+	}
 }
 
 
@@ -455,4 +488,16 @@ mod tests {
 				> 6855
 		);
 	}
+	#[test]
+	fn test_purge_msa_pages() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 1990
+		);
+	}
 }
@@ -1,5 +1,8 @@
 use crate::prod_or_testnet_or_local;
-use common_primitives::node::{Balance, BlockNumber};
+use common_primitives::{
+	node::{Balance, BlockNumber},
+	signatures::EIP712Domain,
+};
 use parity_scale_codec::{Encode, MaxEncodedLen};
 
 use frame_support::{
@@ -90,13 +93,51 @@ pub type FrameSystemMaxConsumers = ConstU32<16>;
 pub type MsaMaxPublicKeysPerMsa = ConstU8<25>;
 /// The maximum size of the provider name (in bytes)
 pub type MsaMaxProviderNameSize = ConstU32<16>;
+/// The maximum size of each provider metadata text field (in bytes)
+pub type MsaMaxProviderMetadataFieldSize = ConstU32<256>;
 /// The number of blocks per virtual bucket
 pub type MSAMortalityWindowSize = ConstU32<100>;
 /// The upper limit on total stored signatures.
 /// Set to an average of 50 signatures per block
 pub type MSAMaxSignaturesStored = ConstU32<50_000>;
+/// The maximum number of recovery guardians per MSA
+pub type MsaMaxRecoveryGuardians = ConstU32<10>;
+/// The number of blocks a recovery must wait before it can complete
+pub type MsaRecoveryDelay = ConstU32<{ 7 * DAYS }>;
+/// The number of blocks a retiring provider keeps its delegations before its retirement takes effect
+pub type MsaProviderRetirementGracePeriod = ConstU32<{ 30 * DAYS }>;
+/// The maximum number of sub-delegation links between a provider and the root delegation
+pub type MsaMaxSubDelegationDepth = ConstU32<3>;
+/// The maximum number of providers that may sub-delegate to a single provider
+pub type MsaMaxSubDelegationsPerProvider = ConstU32<16>;
+/// The maximum number of sub-delegation links examined when resolving a single delegation
+pub type MsaMaxSubDelegationLookups = ConstU32<8>;
+/// The amount a provider holds for each of its pending delegation requests
+pub type MsaDelegationRequestDeposit = ConstU128<{ 10 * currency::CENTS }>;
+/// The maximum number of pending delegation requests to a single delegator
+pub type MsaMaxPendingDelegationRequests = ConstU32<16>;
+/// The maximum number of blocks a delegation request may stay pending
+pub type MsaMaxDelegationRequestLifetime = ConstU32<{ 30 * DAYS }>;
+/// The maximum number of actions waiting for approvals on a single MSA with a threshold policy
+pub type MsaMaxThresholdProposalsPerMsa = ConstU32<8>;
 // -end- MSA Pallet ---
 
+// --- EIP-712 ---
+/// The EIP-155 chain id that Ethereum keys sign Recurrency typed data for
+pub const EIP712_CHAIN_ID: u64 = prod_or_testnet_or_local!(0x190f_1b44, 0x190f_1b45, 0x190f_1b46);
+/// The verifying contract of Recurrency typed data. There is no contract on chain, so each
+/// network uses its own fixed address.
+pub const EIP712_VERIFYING_CONTRACT: [u8; 20] =
+	prod_or_testnet_or_local!([0xCC; 20], [0xCD; 20], [0xCE; 20]);
+parameter_types! {
+	/// The EIP-712 domain of Recurrency typed data
+	pub const ChainEip712Domain: EIP712Domain = EIP712Domain {
+		chain_id: EIP712_CHAIN_ID,
+		verifying_contract: EIP712_VERIFYING_CONTRACT,
+	};
+}
+// -end- EIP-712 ---
+
 // --- Schemas Pallet ---
 parameter_types! {
 	/// The maximum length of a schema model (in bytes)
@@ -409,6 +450,14 @@ parameter_types! {
 	// 1:50 Capacity:Token, must be declared this way instead of using `from_rational` because of
 	//  ```error[E0015]: cannot call non-const fn `Perbill::from_rational::<u32>` in constant functions```
 	pub const CapacityPerToken: Perbill = Perbill::from_percent(2);
+	// 0.38% of the boosted amount per Reward Era, roughly 10% per year with two-week eras
+	pub const CapacityRewardCap: Permill = Permill::from_parts(3_800);
 }
 
+pub type CapacityRewardEraLength =
+	ConstU32<{ prod_or_testnet_or_local!(14 * DAYS, 1 * HOURS, 50 * MINUTES) }>;
+pub type CapacityProviderBoostHistoryLimit = ConstU32<30>; // 30 Reward Eras
+pub type CapacityRewardPoolPerEra = ConstU128<{ 2_000_000 * currency::DOLLARS }>;
+pub type CapacityMaxRetargetsPerRewardEra = ConstU32<2>;
+
 // -end- Capacity Pallet ---
@@ -19,14 +19,16 @@ use super::{
 	AccountId, Balance, Block, Executive, InherentDataExt, Runtime, RuntimeCall,
 	RuntimeGenesisConfig, SessionKeys, System, TransactionPayment, VERSION,
 };
-use crate::{RecurrencyTxPayment, Handles, Messages, Msa, Schemas, StatefulStorage};
+use crate::{Capacity, RecurrencyTxPayment, Handles, Messages, Msa, Schemas, StatefulStorage};
 
 use common_primitives::{
+	capacity::{CapacityDetailsResponse, EpochNumber, StakingTargetResponse, UnlockChunkResponse},
 	handles::{BaseHandle, DisplayHandle, HandleResponse, PresumptiveSuffixesResponse},
 	messages::MessageResponse,
 	msa::{
-		DelegationResponse, DelegationValidator, DelegatorId, MessageSourceId, ProviderId,
-		SchemaGrant, SchemaGrantValidator,
+		DelegationRequestResponse, DelegationResponse, DelegationValidator, DelegatorId,
+		DelegatorsPaginationRequest, DelegatorsPaginationResponse, KeyOwnerResponse,
+		MessageSourceId, ProviderId, ProviderInfoResponse, SchemaGrant,
 	},
 	node::{BlockNumber, Index},
 	rpc::RpcEvent,
@@ -243,7 +245,7 @@ impl_runtime_apis! {
 	impl pallet_msa_runtime_api::MsaRuntimeApi<Block, AccountId> for Runtime {
 		fn has_delegation(delegator: DelegatorId, provider: ProviderId, block_number: BlockNumber, schema_id: Option<SchemaId>) -> bool {
 			match schema_id {
-				Some(sid) => Msa::ensure_valid_schema_grant(provider, delegator, sid, block_number).is_ok(),
+				Some(sid) => Msa::ensure_schema_granted(provider, delegator, sid, block_number).is_ok(),
 				None => Msa::ensure_valid_delegation(provider, delegator, Some(block_number)).is_ok(),
 			}
 		}
@@ -264,6 +266,30 @@ impl_runtime_apis! {
 				Err(_) => vec![],
 			}
 		}
+
+		fn get_provider_info(provider: ProviderId) -> Option<ProviderInfoResponse> {
+			Msa::get_provider_info(provider)
+		}
+
+		fn get_delegators_by_provider(provider: ProviderId, request: DelegatorsPaginationRequest) -> DelegatorsPaginationResponse<SchemaId, BlockNumber> {
+			Msa::get_delegators_by_provider(provider, request)
+		}
+
+		fn get_delegation_requests(delegator: DelegatorId) -> Vec<DelegationRequestResponse<SchemaId, BlockNumber>> {
+			Msa::get_delegation_requests(delegator)
+		}
+
+		fn get_msa_ids_for_keys(keys: Vec<AccountId>) -> Vec<Option<MessageSourceId>> {
+			Msa::get_msa_ids_for_keys(keys)
+		}
+
+		fn get_key_owners(keys: Vec<AccountId>) -> Vec<Option<KeyOwnerResponse>> {
+			Msa::get_key_owners(keys)
+		}
+
+		fn get_msa_redirect(msa_id: MessageSourceId) -> Option<MessageSourceId> {
+			Msa::get_msa_redirect(msa_id)
+		}
 	}
 
 	impl pallet_stateful_storage_runtime_api::StatefulStorageRuntimeApi<Block> for Runtime {
@@ -293,6 +319,20 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_capacity_runtime_api::CapacityRuntimeApi<Block, AccountId, Balance> for Runtime {
+		fn get_capacity_for_provider(provider: MessageSourceId) -> Option<CapacityDetailsResponse<Balance, EpochNumber>> {
+			Capacity::get_capacity_for_provider(provider)
+		}
+
+		fn get_stakes_for_account(staker: AccountId) -> Vec<StakingTargetResponse<Balance>> {
+			Capacity::get_stakes_for_account(&staker)
+		}
+
+		fn get_unlock_chunks(staker: AccountId) -> Vec<UnlockChunkResponse<Balance, EpochNumber>> {
+			Capacity::get_unlock_chunks(&staker)
+		}
+	}
+
 	#[cfg(feature = "try-runtime")]
 	impl frame_try_runtime::TryRuntime<Block> for Runtime {
 		fn on_runtime_upgrade(checks: UpgradeCheckSelect) -> (Weight, Weight) {
@@ -263,6 +263,8 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 				matches!(
 					c,
 					RuntimeCall::Capacity(pallet_capacity::Call::stake { .. }) |
+						RuntimeCall::Capacity(pallet_capacity::Call::provider_boost { .. }) |
+						RuntimeCall::Capacity(pallet_capacity::Call::change_staking_target { .. }) |
 						RuntimeCall::CollatorSelection(
 							pallet_collator_selection::Call::set_candidacy_bond { .. }
 						)
@@ -335,7 +337,11 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
-	(pallet_schemas::migration::v4::MigrateToV4<Runtime>,),
+	(
+		pallet_schemas::migration::v5::MigrateToV5<Runtime>,
+		pallet_capacity::migration::v4::MigrationToV4<Runtime>,
+		pallet_msa::migration::v2::MigrateToV2<Runtime>,
+	),
 >;
 
 pub mod apis;
@@ -375,10 +381,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("recurrency"),
 	impl_name: create_runtime_str!("recurrency"),
 	authoring_version: 1,
-	spec_version: 110,
+	spec_version: 111,
 	impl_version: 0,
 	apis: apis::RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -389,10 +395,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("recurrency-testnet"),
 	impl_name: create_runtime_str!("recurrency"),
 	authoring_version: 1,
-	spec_version: 110,
+	spec_version: 111,
 	impl_version: 0,
 	apis: apis::RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -500,7 +506,10 @@ impl frame_system::Config for Runtime {
 
 impl pallet_msa::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type WeightInfo = pallet_msa::weights::SubstrateWeight<Runtime>;
+	// The currency that delegation request deposits are held in
+	type Currency = Balances;
 	// The conversion to a 32 byte AccountId
 	type ConvertIntoAccountId32 = ConvertInto;
 	// The maximum number of public keys per MSA
@@ -509,12 +518,18 @@ impl pallet_msa::Config for Runtime {
 	type MaxSchemaGrantsPerDelegation = MaxSchemaGrants;
 	// The maximum provider name size (in bytes)
 	type MaxProviderNameSize = MsaMaxProviderNameSize;
+	type MaxProviderMetadataFieldSize = MsaMaxProviderMetadataFieldSize;
 	// The type that provides schema related info
 	type SchemaValidator = Schemas;
 	// The type that provides `Handle` related info for a given `MesssageSourceAccount`
 	type HandleProvider = Handles;
+	// Delete the stateful storage, then the handle and then the namespaces of retired MSAs
+	type MsaDataPurger = (StatefulStorage, (Handles, Schemas));
+	type MsaDataMerger = (StatefulStorage, (Handles, Schemas));
 	// The number of blocks per virtual bucket
 	type MortalityWindowSize = MSAMortalityWindowSize;
+	// The EIP-712 domain Ethereum keys sign typed data in
+	type Eip712Domain = ChainEip712Domain;
 	// The maximum number of signatures that can be stored in the payload signature registry
 	type MaxSignaturesStored = MSAMaxSignaturesStored;
 	// The proposal type
@@ -526,6 +541,25 @@ impl pallet_msa::Config for Runtime {
 		EnsureRoot<AccountId>,
 		pallet_collective::EnsureMembers<AccountId, CouncilCollective, 1>,
 	>;
+	// The maximum number of recovery guardians per MSA
+	type MaxRecoveryGuardians = MsaMaxRecoveryGuardians;
+	// The number of blocks a recovery must wait before it can complete
+	type RecoveryDelay = MsaRecoveryDelay;
+	type ProviderRetirementGracePeriod = MsaProviderRetirementGracePeriod;
+	// The maximum number of sub-delegation links between a provider and the root delegation
+	type MaxSubDelegationDepth = MsaMaxSubDelegationDepth;
+	// The maximum number of providers that may sub-delegate to a single provider
+	type MaxSubDelegationsPerProvider = MsaMaxSubDelegationsPerProvider;
+	// The maximum number of sub-delegation links examined when resolving a single delegation
+	type MaxSubDelegationLookups = MsaMaxSubDelegationLookups;
+	// The amount a provider holds for each pending delegation request
+	type DelegationRequestDeposit = MsaDelegationRequestDeposit;
+	// The maximum number of pending delegation requests to a single delegator
+	type MaxPendingDelegationRequests = MsaMaxPendingDelegationRequests;
+	// The maximum number of blocks a delegation request may stay pending
+	type MaxDelegationRequestLifetime = MsaMaxDelegationRequestLifetime;
+	// The maximum number of actions waiting for approvals on a single MSA
+	type MaxThresholdProposalsPerMsa = MsaMaxThresholdProposalsPerMsa;
 }
 
 impl pallet_capacity::Config for Runtime {
@@ -543,6 +577,13 @@ impl pallet_capacity::Config for Runtime {
 	type EpochNumber = u32;
 	type CapacityPerToken = CapacityPerToken;
 	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type RewardEra = u32;
+	type EraLength = CapacityRewardEraLength;
+	type ProviderBoostHistoryLimit = CapacityProviderBoostHistoryLimit;
+	type RewardsProvider = Capacity;
+	type RewardPoolPerEra = CapacityRewardPoolPerEra;
+	type RewardPercentCap = CapacityRewardCap;
+	type MaxRetargetsPerRewardEra = CapacityMaxRetargetsPerRewardEra;
 }
 
 impl pallet_schemas::Config for Runtime {
@@ -565,6 +606,14 @@ impl pallet_schemas::Config for Runtime {
 	>;
 	// Maximum number of schema grants that are allowed per schema
 	type MaxSchemaSettingsPerSchema = MaxSchemaSettingsPerSchema;
+	// The type that supplies MSA info
+	type MsaInfoProvider = Msa;
+	// The type that checks that namespace owners are registered providers
+	type ProviderValidator = Msa;
+	#[cfg(feature = "runtime-benchmarks")]
+	type MsaBenchmarkHelper = Msa;
+	#[cfg(feature = "runtime-benchmarks")]
+	type ProviderBenchmarkHelper = Msa;
 }
 
 // One storage item; key size is 32; value is size 4+4+16+32 bytes = 56 bytes.
@@ -1147,6 +1196,8 @@ impl pallet_stateful_storage::Config for Runtime {
 	type ConvertIntoAccountId32 = ConvertInto;
 	/// The number of blocks per virtual bucket
 	type MortalityWindowSize = StatefulMortalityWindowSize;
+	// The EIP-712 domain Ethereum keys sign typed data in
+	type Eip712Domain = ChainEip712Domain;
 
 	/// A set of helper functions for benchmarking.
 	#[cfg(feature = "runtime-benchmarks")]
@@ -1170,6 +1221,8 @@ impl pallet_handles::Config for Runtime {
 	type ConvertIntoAccountId32 = ConvertInto;
 	// The number of blocks per virtual bucket
 	type MortalityWindowSize = MSAMortalityWindowSize;
+	// The EIP-712 domain Ethereum keys sign typed data in
+	type Eip712Domain = ChainEip712Domain;
 	/// A set of helper functions for benchmarking.
 	#[cfg(feature = "runtime-benchmarks")]
 	type MsaBenchmarkHelper = Msa;
@@ -1243,7 +1296,7 @@ construct_runtime!(
 		Proxy: pallet_proxy = 43,
 
 		// Recurrency related pallets
-		Msa: pallet_msa::{Pallet, Call, Storage, Event<T>} = 60,
+		Msa: pallet_msa::{Pallet, Call, Storage, Event<T>, HoldReason} = 60,
 		Messages: pallet_messages::{Pallet, Call, Storage, Event<T>} = 61,
 		Schemas: pallet_schemas::{Pallet, Call, Storage, Event<T>, Config<T>} = 62,
 		StatefulStorage: pallet_stateful_storage::{Pallet, Call, Storage, Event<T>} = 63,